@@ -0,0 +1,102 @@
+//! This module implements the iterators of arrays.
+//!
+//! They are returned by the `keys()`, `values()` and `entries()` methods of arrays, and walk
+//! any array-like object up to its current length, so elements pushed during the iteration are visited.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-array-iterator-objects
+
+use crate::{
+    builtins::{
+        array::{construct_array, length_of_array_like, new_array},
+        map::iterator::{create_iter_result, iterator, IterationKind, ITERATOR_PROTOTYPE},
+        object::{Object, PROTOTYPE},
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Creates a new iterator over the elements of `array`, which can be any object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createarrayiterator
+pub(crate) fn create_array_iterator(
+    ctx: &Interpreter,
+    array: &Value,
+    kind: IterationKind,
+) -> Value {
+    let prototype = ctx
+        .realm
+        .global_obj
+        .get_field_slice("Array")
+        .get_field_slice(PROTOTYPE)
+        .get_internal_slot(ITERATOR_PROTOTYPE);
+
+    let iterator = to_value(Object::create(prototype));
+    iterator.set_internal_slot("IteratedArrayLike", array.clone());
+    iterator.set_internal_slot("ArrayLikeNextIndex", to_value(0));
+    iterator.set_internal_slot("ArrayLikeIterationKind", to_value(kind.as_str()));
+    iterator
+}
+
+/// `%ArrayIteratorPrototype%.next()`
+///
+/// Returns the next element of the iterated object, or a result with `done` set to `true`
+/// once the index reached its length.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
+pub(crate) fn next(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let kind = this.get_internal_slot("ArrayLikeIterationKind");
+    let kind = match from_value::<String>(kind) {
+        Ok(ref kind) if kind == IterationKind::Key.as_str() => IterationKind::Key,
+        Ok(ref kind) if kind == IterationKind::Value.as_str() => IterationKind::Value,
+        Ok(ref kind) if kind == IterationKind::KeyAndValue.as_str() => IterationKind::KeyAndValue,
+        _ => {
+            return ctx.throw_type_error(format!(
+                "next method called on incompatible receiver {}",
+                this
+            ))
+        }
+    };
+
+    // The iterated object is removed once the iterator is done, so it stays done
+    let array = this.get_internal_slot("IteratedArrayLike");
+    if array.is_undefined() {
+        return Ok(create_iter_result(ctx, undefined(), true));
+    }
+
+    let index = from_value::<usize>(this.get_internal_slot("ArrayLikeNextIndex"))
+        .map_err(|error| error.to_type_error(ctx))?;
+    if index >= length_of_array_like(&array, ctx)? {
+        this.set_internal_slot("IteratedArrayLike", undefined());
+        return Ok(create_iter_result(ctx, undefined(), true));
+    }
+
+    this.set_internal_slot("ArrayLikeNextIndex", to_value(index + 1));
+    let result = match kind {
+        IterationKind::Key => to_value(index),
+        IterationKind::Value => ctx.get_property(&array, &to_value(index.to_string()))?,
+        IterationKind::KeyAndValue => {
+            let value = ctx.get_property(&array, &to_value(index.to_string()))?;
+            let entry = new_array(ctx)?;
+            construct_array(&entry, &[to_value(index), value])?
+        }
+    };
+    Ok(create_iter_result(ctx, result, false))
+}
+
+/// Creates the prototype of the iterators of arrays.
+pub(crate) fn create_prototype(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    make_builtin_fn!(next, named "next", of prototype);
+    make_builtin_fn!(iterator, keyed well_known_symbol(global, "iterator"), of prototype);
+    prototype
+}
@@ -12,19 +12,31 @@
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod iterator;
+
+use self::iterator::create_array_iterator;
 use crate::{
     builtins::{
+        array_buffer::relative_index,
+        map::iterator::{IterationKind, ITERATOR_PROTOTYPE},
         object::{Object, ObjectInternalMethods, ObjectKind, INSTANCE_PROTOTYPE, PROTOTYPE},
         property::Property,
-        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+        symbol::well_known_symbol,
+        value::{from_value, same_value, to_value, undefined, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
 use gc::Gc;
 use std::borrow::Borrow;
-use std::cmp::{max, min};
+use std::cmp::{max, min, Ordering};
 use std::ops::Deref;
 
+/// The largest length of an array-like object, `2^53 - 1`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// The largest length of an array, `2^32 - 1`.
+const MAX_ARRAY_LENGTH: usize = u32::MAX as usize;
+
 /// Creates a new `Array` instance.
 pub(crate) fn new_array(interpreter: &Interpreter) -> ResultValue {
     let array = ValueData::new_obj(Some(
@@ -44,7 +56,12 @@ pub(crate) fn new_array(interpreter: &Interpreter) -> ResultValue {
             .borrow()
             .get_field_slice(PROTOTYPE),
     );
-    array.borrow().set_field_slice("length", to_value(0));
+    let length = Property::new()
+        .value(to_value(0))
+        .writable(true)
+        .configurable(false)
+        .enumerable(false);
+    array.set_prop_slice("length", length);
     Ok(array)
 }
 
@@ -96,6 +113,216 @@ pub(crate) fn add_to_array_object(array_ptr: &Value, add_values: &[Value]) -> Re
     Ok(array_ptr.clone())
 }
 
+/// Checks whether `value` is an `Array` object.
+pub(crate) fn is_array_object(value: &Value) -> bool {
+    value
+        .as_object()
+        .map_or(false, |obj| obj.kind == ObjectKind::Array)
+}
+
+/// The abstract operation `IsArray`, which looks through proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isarray
+pub(crate) fn is_array_value(value: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    let target = match value.as_object() {
+        Some(ref obj) => match obj.kind {
+            ObjectKind::Array => return Ok(true),
+            ObjectKind::Proxy(Some(ref data)) => data.target.clone(),
+            ObjectKind::Proxy(None) => {
+                return Err(ctx.construct_type_error(
+                    "Cannot perform 'IsArray' on a proxy that has been revoked",
+                ))
+            }
+            _ => return Ok(false),
+        },
+        None => return Ok(false),
+    };
+    is_array_value(&target, ctx)
+}
+
+/// Gets the property key of the element at `index`.
+fn index_key(index: usize) -> Value {
+    to_value(index.to_string())
+}
+
+/// The abstract operation `ToString`, which throws a `TypeError` for symbols.
+fn string_of(value: &Value, ctx: &mut Interpreter) -> Result<String, Value> {
+    if value.is_symbol() {
+        ctx.throw_type_error("Cannot convert a Symbol value to a string")?;
+    }
+    Ok(ctx.to_string(value).to_string())
+}
+
+/// The abstract operation `LengthOfArrayLike`.
+///
+/// Gets the `length` of an object, clamped to the length of an array-like object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-lengthofarraylike
+pub(crate) fn length_of_array_like(obj: &Value, ctx: &mut Interpreter) -> Result<usize, Value> {
+    let length = ctx.get_property(obj, &to_value("length"))?;
+    let length = ctx.to_integer_or_infinity(&length);
+    Ok(length.clamp(0.0, MAX_SAFE_INTEGER) as usize)
+}
+
+/// The abstract operation `ArrayCreate`, creating an array with the given `length` and no elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraycreate
+fn array_create(length: usize, ctx: &mut Interpreter) -> ResultValue {
+    if length > MAX_ARRAY_LENGTH {
+        return ctx.throw_range_error("Invalid array length");
+    }
+    let array = new_array(ctx)?;
+    array.set_field_slice("length", to_value(length));
+    Ok(array)
+}
+
+/// Creates a new object with `constructor`, like the `Array` statics do with their `this` value.
+///
+/// Arrays are created without any element when `constructor` is `Array` or isn't a constructor.
+fn construct_or_create_array(
+    constructor: &Value,
+    length: Option<usize>,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let intrinsic = ctx.realm.global_obj.get_field_slice("Array");
+    let is_constructor = constructor
+        .as_object()
+        .map_or(false, |obj| obj.is_constructor());
+    if !is_constructor || same_value(constructor, &intrinsic, true) {
+        return array_create(length.unwrap_or(0), ctx);
+    }
+
+    let args: Vec<Value> = length.into_iter().map(to_value).collect();
+    ctx.construct(constructor, &args, constructor)
+}
+
+/// The abstract operation `ArraySpeciesCreate`.
+///
+/// Creates the result of the array methods with the `Symbol.species` constructor of `original`,
+/// so subclasses of `Array` return instances of themselves.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arrayspeciescreate
+fn array_species_create(original: &Value, length: usize, ctx: &mut Interpreter) -> ResultValue {
+    if !is_array_object(original) {
+        return array_create(length, ctx);
+    }
+
+    let mut constructor = ctx.get_property(original, &to_value("constructor"))?;
+    if constructor.is_object() {
+        let species = well_known_symbol(&ctx.realm.global_obj, "species");
+        constructor = ctx.get_property(&constructor, &species)?;
+        if constructor.is_null() {
+            constructor = undefined();
+        }
+    }
+    if constructor.is_undefined() {
+        return array_create(length, ctx);
+    }
+
+    let is_constructor = constructor
+        .as_object()
+        .map_or(false, |obj| obj.is_constructor());
+    if !is_constructor {
+        return ctx.throw_type_error(format!(
+            "object.constructor[Symbol.species] is not a constructor: {}",
+            constructor
+        ));
+    }
+    construct_or_create_array(&constructor, Some(length), ctx)
+}
+
+/// The abstract operation `CreateDataPropertyOrThrow` for the element at `index`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdatapropertyorthrow
+fn create_element_or_throw(
+    obj: &Value,
+    index: usize,
+    value: Value,
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    if ctx.create_data_property(obj, &index_key(index), value)? {
+        Ok(())
+    } else {
+        Err(ctx.construct_type_error(format!(
+            "Cannot add property {}, object is not extensible",
+            index
+        )))
+    }
+}
+
+/// The abstract operation `Set( O, P, V, true )`, throwing a `TypeError` if the assignment fails.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
+fn set_or_throw(
+    obj: &Value,
+    key: &Value,
+    value: Value,
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    if ctx.set_property(obj, key, value)? {
+        Ok(())
+    } else {
+        Err(ctx.construct_type_error(format!(
+            "Cannot assign to read only property '{}' of {}",
+            key, obj
+        )))
+    }
+}
+
+/// The abstract operation `DeletePropertyOrThrow`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+fn delete_or_throw(obj: &Value, key: &Value, ctx: &mut Interpreter) -> Result<(), Value> {
+    if ctx.delete_property(obj, key)? {
+        Ok(())
+    } else {
+        Err(ctx.construct_type_error(format!("Cannot delete property '{}' of {}", key, obj)))
+    }
+}
+
+/// Moves the element at index `from` to index `to`, deleting `to` if `from` is a hole.
+fn move_element(obj: &Value, from: usize, to: usize, ctx: &mut Interpreter) -> Result<(), Value> {
+    let from = index_key(from);
+    let to = index_key(to);
+    if ctx.has_property(obj, &from)? {
+        let value = ctx.get_property(obj, &from)?;
+        set_or_throw(obj, &to, value, ctx)
+    } else {
+        delete_or_throw(obj, &to, ctx)
+    }
+}
+
+/// Gets the callback argument of an array method, or throws a `TypeError` if it's not callable.
+fn callback_arg(args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let callback = args.get(0).cloned().unwrap_or_else(undefined);
+    if callback.is_function() {
+        Ok(callback)
+    } else {
+        ctx.throw_type_error(format!("{} is not a function", callback))
+    }
+}
+
 /// Create a new array
 pub fn make_array(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // Make a new Object which will internally represent the Array (mapping
@@ -116,17 +343,18 @@ pub fn make_array(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Re
     // add our arguments in
     let mut length = args.len() as i32;
     match args.len() {
-        1 if args[0].is_integer() => {
-            length = from_value::<i32>(args[0].clone()).expect("Could not convert argument to i32");
+        1 if args[0].is_integer() || args[0].is_double() => {
+            let number = args[0].to_number();
+            // The length must be an integer that we can represent
+            if number < 0.0 || number.fract() != 0.0 || number > f64::from(i32::max_value()) {
+                return ctx.throw_range_error("Invalid array length");
+            }
+            length = number as i32;
             // TODO: It should not create an array of undefineds, but an empty array ("holy" array in V8) with length `n`.
             for n in 0..length {
                 this.set_field_slice(&n.to_string(), Gc::new(ValueData::Undefined));
             }
         }
-        1 if args[0].is_double() => {
-            // TODO: throw `RangeError`.
-            panic!("RangeError: invalid array length");
-        }
         _ => {
             for (n, value) in args.iter().enumerate() {
                 this.set_field_slice(&n.to_string(), value.clone());
@@ -192,31 +420,34 @@ pub fn is_array(_this: &mut Value, args: &[Value], _interpreter: &mut Interprete
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
-pub fn concat(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    if args.is_empty() {
-        // If concat is called with no arguments, it returns the original array
-        return Ok(this.clone());
-    }
-
-    // Make a new array (using this object as the prototype basis for the new
-    // one)
-    let mut new_values: Vec<Value> = Vec::new();
-
-    let this_length: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not convert argument to i32");
-    for n in 0..this_length {
-        new_values.push(this.get_field_slice(&n.to_string()));
-    }
-
-    for concat_array in args {
-        let concat_length: i32 = from_value(concat_array.get_field_slice("length"))
-            .expect("Could not convert argument to i32");
-        for n in 0..concat_length {
-            new_values.push(concat_array.get_field_slice(&n.to_string()));
+pub fn concat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let array = array_species_create(&obj, 0, ctx)?;
+
+    let mut n = 0;
+    for item in Some(&obj).into_iter().chain(args) {
+        // `Symbol.isConcatSpreadable` isn't supported, so only arrays are spread
+        if is_array_object(item) {
+            let length = length_of_array_like(item, ctx)?;
+            if (n + length) as f64 > MAX_SAFE_INTEGER {
+                return ctx.throw_type_error("Invalid array length");
+            }
+            for k in 0..length {
+                let key = index_key(k);
+                if ctx.has_property(item, &key)? {
+                    let value = ctx.get_property(item, &key)?;
+                    create_element_or_throw(&array, n, value, ctx)?;
+                }
+                n += 1;
+            }
+        } else {
+            create_element_or_throw(&array, n, item.clone(), ctx)?;
+            n += 1;
         }
     }
 
-    construct_array(this, &new_values)
+    set_or_throw(&array, &to_value("length"), to_value(n), ctx)?;
+    Ok(array)
 }
 
 /// `Array.prototype.push( ...items )`
@@ -269,27 +500,25 @@ pub fn pop(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.foreach
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/forEach
-pub fn for_each(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
-    if args.is_empty() {
-        return Err(to_value(
-            "Missing argument for Array.prototype.forEach".to_string(),
-        ));
-    }
-
-    let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
+pub fn for_each(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let callback = callback_arg(args, ctx)?;
     let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
 
-    let length: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not get `length` property.");
-
-    for i in 0..length {
-        let element = this.get_field_slice(&i.to_string());
-        let arguments = [element, to_value(i), this.clone()];
-
-        interpreter.call(callback_arg, &mut this_arg, &arguments)?;
+    for k in 0..length {
+        let key = index_key(k);
+        if ctx.has_property(&obj, &key)? {
+            let element = ctx.get_property(&obj, &key)?;
+            ctx.call(
+                &callback,
+                &mut this_arg,
+                &[element, to_value(k), obj.clone()],
+            )?;
+        }
     }
 
-    Ok(Gc::new(ValueData::Undefined))
+    Ok(undefined())
 }
 
 /// `Array.prototype.join( separator )`
@@ -304,19 +533,22 @@ pub fn for_each(this: &mut Value, args: &[Value], interpreter: &mut Interpreter)
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.join
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/join
-pub fn join(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let separator = if args.is_empty() {
-        String::from(",")
-    } else {
-        args.get(0).expect("Could not get argument").to_string()
+pub fn join(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let separator = match args.first() {
+        Some(separator) if !separator.is_undefined() => string_of(separator, ctx)?,
+        _ => String::from(","),
     };
 
     let mut elem_strs: Vec<String> = Vec::new();
-    let length: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not convert argument to i32");
-    for n in 0..length {
-        let elem_str: String = this.get_field_slice(&n.to_string()).to_string();
-        elem_strs.push(elem_str);
+    for k in 0..length {
+        let element = ctx.get_property(&obj, &index_key(k))?;
+        if element.is_null_or_undefined() {
+            elem_strs.push(String::new());
+        } else {
+            elem_strs.push(string_of(&element, ctx)?);
+        }
     }
 
     Ok(to_value(elem_strs.join(&separator)))
@@ -356,7 +588,7 @@ pub fn to_string(this: &mut Value, _args: &[Value], _ctx: &mut Interpreter) -> R
     let join_result = _ctx.call(&method, this, &arguments);
     let match_string = match join_result {
         Ok(v) => match *v {
-            ValueData::String(ref s) => (*s).clone(),
+            ValueData::String(ref s) => s.to_string(),
             _ => "".to_string(),
         },
         Err(v) => format!("error: {}", v),
@@ -505,9 +737,8 @@ pub fn unshift(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultV
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/every
 pub fn every(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
     if args.is_empty() {
-        return Err(to_value(
-            "missing callback when calling function Array.prototype.every".to_string(),
-        ));
+        return interpreter
+            .throw_type_error("missing callback when calling function Array.prototype.every");
     }
     let callback = &args[0];
     let mut this_arg = if args.len() > 1 {
@@ -544,33 +775,26 @@ pub fn every(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) ->
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.map
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/map
-pub fn map(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
-    if args.is_empty() {
-        return Err(to_value(
-            "missing argument 0 when calling function Array.prototype.map",
-        ));
-    }
-
-    let callback = args.get(0).cloned().unwrap_or_else(undefined);
-    let mut this_val = args.get(1).cloned().unwrap_or_else(undefined);
-
-    let length: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not get `length` property.");
-
-    let new = new_array(&interpreter)?;
-
-    let values = (0..length)
-        .map(|idx| {
-            let element = this.get_field_slice(&idx.to_string());
-            let args = [element, to_value(idx), new.clone()];
-
-            interpreter
-                .call(&callback, &mut this_val, &args)
-                .unwrap_or_else(|_| undefined())
-        })
-        .collect::<Vec<Value>>();
+pub fn map(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let callback = callback_arg(args, ctx)?;
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
 
-    construct_array(&new, &values)
+    let array = array_species_create(&obj, length, ctx)?;
+    for k in 0..length {
+        let key = index_key(k);
+        if ctx.has_property(&obj, &key)? {
+            let element = ctx.get_property(&obj, &key)?;
+            let mapped = ctx.call(
+                &callback,
+                &mut this_arg,
+                &[element, to_value(k), obj.clone()],
+            )?;
+            create_element_or_throw(&array, k, mapped, ctx)?;
+        }
+    }
+    Ok(array)
 }
 
 /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -598,7 +822,7 @@ pub fn index_of(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Result
         return Ok(to_value(-1));
     }
 
-    let search_element = args[0].clone();
+    let search_element = args.get(0).cloned().unwrap_or_else(undefined);
     let len: i32 = from_value(this.get_field_slice("length"))
         .expect("Expected array property \"length\" is not set.");
 
@@ -653,7 +877,7 @@ pub fn last_index_of(this: &mut Value, args: &[Value], _: &mut Interpreter) -> R
         return Ok(to_value(-1));
     }
 
-    let search_element = args[0].clone();
+    let search_element = args.get(0).cloned().unwrap_or_else(undefined);
     let len: i32 = from_value(this.get_field_slice("length"))
         .expect("Expected array property \"length\" is not set.");
 
@@ -698,9 +922,8 @@ pub fn last_index_of(this: &mut Value, args: &[Value], _: &mut Interpreter) -> R
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/find
 pub fn find(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
     if args.is_empty() {
-        return Err(to_value(
-            "missing callback when calling function Array.prototype.find".to_string(),
-        ));
+        return interpreter
+            .throw_type_error("missing callback when calling function Array.prototype.find");
     }
     let callback = &args[0];
     let mut this_arg = if args.len() > 1 {
@@ -734,9 +957,7 @@ pub fn find(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) ->
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findIndex
 pub fn find_index(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
     if args.is_empty() {
-        return Err(to_value(
-            "Missing argument for Array.prototype.findIndex".to_string(),
-        ));
+        return interpreter.throw_type_error("Missing argument for Array.prototype.findIndex");
     }
 
     let predicate_arg = args.get(0).expect("Could not get `predicate` argument.");
@@ -847,42 +1068,24 @@ pub fn includes_value(this: &mut Value, args: &[Value], _: &mut Interpreter) ->
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.slice
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
-pub fn slice(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
-    let new_array = new_array(interpreter)?;
-    let len: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not convert argument to i32");
-
-    let start = match args.get(0) {
-        Some(v) => from_value(v.clone()).expect("failed to parse argument for Array method"),
-        None => 0,
-    };
-    let end = match args.get(1) {
-        Some(v) => from_value(v.clone()).expect("failed to parse argument for Array method"),
-        None => len,
-    };
-
-    let from = if start < 0 {
-        max(len.wrapping_add(start), 0)
-    } else {
-        min(start, len)
-    };
-    let to = if end < 0 {
-        max(len.wrapping_add(end), 0)
-    } else {
-        min(end, len)
-    };
-
-    let span = max(to.wrapping_sub(from), 0);
-    let mut new_array_len: i32 = 0;
-    for i in from..from.wrapping_add(span) {
-        new_array.set_field_slice(
-            &new_array_len.to_string(),
-            this.get_field_slice(&i.to_string()),
-        );
-        new_array_len = new_array_len.wrapping_add(1);
+pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let start = relative_index(args.get(0), 0, length, ctx);
+    let end = relative_index(args.get(1), length, length, ctx);
+
+    let count = end.saturating_sub(start);
+    let array = array_species_create(&obj, count, ctx)?;
+    for (n, k) in (start..end).enumerate() {
+        let key = index_key(k);
+        if ctx.has_property(&obj, &key)? {
+            let value = ctx.get_property(&obj, &key)?;
+            create_element_or_throw(&array, n, value, ctx)?;
+        }
     }
-    new_array.set_field_slice("length", to_value(new_array_len));
-    Ok(new_array)
+
+    set_or_throw(&array, &to_value("length"), to_value(count), ctx)?;
+    Ok(array)
 }
 
 /// `Array.prototype.filter( callback, [ thisArg ] )`
@@ -896,40 +1099,26 @@ pub fn slice(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) ->
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.filter
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter
-pub fn filter(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
-    if args.is_empty() {
-        return Err(to_value(
-            "missing argument 0 when calling function Array.prototype.filter",
-        ));
-    }
-
-    let callback = args.get(0).cloned().unwrap_or_else(undefined);
-    let mut this_val = args.get(1).cloned().unwrap_or_else(undefined);
-
-    let length: i32 =
-        from_value(this.get_field_slice("length")).expect("Could not get `length` property.");
-
-    let new = new_array(&interpreter)?;
-
-    let values = (0..length)
-        .filter_map(|idx| {
-            let element = this.get_field_slice(&idx.to_string());
-
-            let args = [element.clone(), to_value(idx), new.clone()];
-
-            let callback_result = interpreter
-                .call(&callback, &mut this_val, &args)
-                .unwrap_or_else(|_| undefined());
+pub fn filter(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let callback = callback_arg(args, ctx)?;
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
 
-            if callback_result.is_true() {
-                Some(element)
-            } else {
-                None
+    let array = array_species_create(&obj, 0, ctx)?;
+    let mut to = 0;
+    for k in 0..length {
+        let key = index_key(k);
+        if ctx.has_property(&obj, &key)? {
+            let element = ctx.get_property(&obj, &key)?;
+            let args = [element.clone(), to_value(k), obj.clone()];
+            if ctx.call(&callback, &mut this_arg, &args)?.is_true() {
+                create_element_or_throw(&array, to, element, ctx)?;
+                to += 1;
             }
-        })
-        .collect::<Vec<Value>>();
-
-    construct_array(&new, &values)
+        }
+    }
+    Ok(array)
 }
 
 /// Array.prototype.some ( callbackfn [ , thisArg ] )
@@ -949,9 +1138,8 @@ pub fn filter(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/some
 pub fn some(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
     if args.is_empty() {
-        return Err(to_value(
-            "missing callback when calling function Array.prototype.some".to_string(),
-        ));
+        return interpreter
+            .throw_type_error("missing callback when calling function Array.prototype.some");
     }
     let callback = &args[0];
     let mut this_arg = if args.len() > 1 {
@@ -978,6 +1166,693 @@ pub fn some(this: &mut Value, args: &[Value], interpreter: &mut Interpreter) ->
     Ok(to_value(false))
 }
 
+/// `Array.prototype.sort( comparefn )`
+///
+/// The sort method sorts the elements of the array in place, and returns the array.
+/// The sort is stable, `undefined` elements are moved to the end and holes after them.
+/// Without `comparefn`, the elements are compared by their string value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+pub fn sort(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let compare_fn = args.get(0).cloned().unwrap_or_else(undefined);
+    if !compare_fn.is_undefined() && !compare_fn.is_function() {
+        return ctx
+            .throw_type_error("The comparison function must be either a function or undefined");
+    }
+
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let mut items = Vec::new();
+    for k in 0..length {
+        let key = index_key(k);
+        if ctx.has_property(&obj, &key)? {
+            items.push(ctx.get_property(&obj, &key)?);
+        }
+    }
+
+    let sorted = merge_sort(items, &mut |x, y| sort_compare(x, y, &compare_fn, ctx))?;
+    let item_count = sorted.len();
+    for (k, item) in sorted.into_iter().enumerate() {
+        set_or_throw(&obj, &index_key(k), item, ctx)?;
+    }
+    for k in item_count..length {
+        delete_or_throw(&obj, &index_key(k), ctx)?;
+    }
+    Ok(obj)
+}
+
+/// The abstract operation `SortCompare`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-sortcompare
+fn sort_compare(
+    x: &Value,
+    y: &Value,
+    compare_fn: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Ordering, Value> {
+    match (x.is_undefined(), y.is_undefined()) {
+        (true, true) => return Ok(Ordering::Equal),
+        (true, false) => return Ok(Ordering::Greater),
+        (false, true) => return Ok(Ordering::Less),
+        (false, false) => {}
+    }
+
+    if compare_fn.is_undefined() {
+        let x = ctx.value_to_js_string(x);
+        let y = ctx.value_to_js_string(y);
+        return Ok(x.cmp(&y));
+    }
+
+    let result = ctx.call(compare_fn, &mut undefined(), &[x.clone(), y.clone()])?;
+    let result = ctx.value_to_rust_number(&result);
+    // A `NaN` result is treated like `+0`
+    Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+}
+
+/// Sorts `items` with a merge sort, which is stable and tolerates inconsistent comparisons.
+///
+/// The first error thrown by `compare` stops the sort.
+pub(crate) fn merge_sort<F>(mut items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>, Value>
+where
+    F: FnMut(&Value, &Value) -> Result<Ordering, Value>,
+{
+    if items.len() <= 1 {
+        return Ok(items);
+    }
+
+    let right = items.split_off(items.len() / 2);
+    let left = merge_sort(items, compare)?;
+    let right = merge_sort(right, compare)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+        // Equal elements are taken from the left first, to keep their order
+        if compare(x, y)? == Ordering::Greater {
+            merged.extend(right.next());
+        } else {
+            merged.extend(left.next());
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+/// `Array.prototype.reduce( callbackfn [ , initialValue ] )`
+///
+/// The reduce method calls the callback function on each element of the array, in ascending order,
+/// passing in the return value from the calculation on the preceding element.
+/// Without `initialValue`, the first element is used as the initial accumulator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reduce
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reduce
+pub fn reduce(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    reduce_elements(&obj, args, 0..length, ctx)
+}
+
+/// `Array.prototype.reduceRight( callbackfn [ , initialValue ] )`
+///
+/// The reduceRight method works like `reduce`, but visits the elements in descending order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reduceright
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reduceRight
+pub fn reduce_right(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    reduce_elements(&obj, args, (0..length).rev(), ctx)
+}
+
+/// Reduces the elements of `obj` visited in the order of `indices`, skipping the holes.
+fn reduce_elements<I>(
+    obj: &Value,
+    args: &[Value],
+    mut indices: I,
+    ctx: &mut Interpreter,
+) -> ResultValue
+where
+    I: Iterator<Item = usize>,
+{
+    let callback = callback_arg(args, ctx)?;
+
+    let mut accumulator = match args.get(1) {
+        Some(initial_value) => initial_value.clone(),
+        None => loop {
+            match indices.next() {
+                Some(k) => {
+                    let key = index_key(k);
+                    if ctx.has_property(obj, &key)? {
+                        break ctx.get_property(obj, &key)?;
+                    }
+                }
+                None => return ctx.throw_type_error("Reduce of empty array with no initial value"),
+            }
+        },
+    };
+
+    for k in indices {
+        let key = index_key(k);
+        if ctx.has_property(obj, &key)? {
+            let value = ctx.get_property(obj, &key)?;
+            let args = [accumulator, value, to_value(k), obj.clone()];
+            accumulator = ctx.call(&callback, &mut undefined(), &args)?;
+        }
+    }
+    Ok(accumulator)
+}
+
+/// `Array.prototype.splice( start, deleteCount, ...items )`
+///
+/// The splice method removes `deleteCount` elements from `start`, and inserts `items` in their place.
+/// It returns an array of the deleted elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+pub fn splice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let start = relative_index(
+        Some(&args.get(0).cloned().unwrap_or_else(undefined)),
+        0,
+        length,
+        ctx,
+    );
+
+    let items = args.get(2..).unwrap_or(&[]);
+    let delete_count = match args.len() {
+        0 => 0,
+        1 => length - start,
+        _ => {
+            let delete_count = ctx.to_integer_or_infinity(&args[1]);
+            delete_count.max(0.0).min((length - start) as f64) as usize
+        }
+    };
+    if (length + items.len() - delete_count) as f64 > MAX_SAFE_INTEGER {
+        return ctx.throw_type_error("Invalid array length");
+    }
+
+    let deleted = array_species_create(&obj, delete_count, ctx)?;
+    for k in 0..delete_count {
+        let key = index_key(start + k);
+        if ctx.has_property(&obj, &key)? {
+            let value = ctx.get_property(&obj, &key)?;
+            create_element_or_throw(&deleted, k, value, ctx)?;
+        }
+    }
+    set_or_throw(&deleted, &to_value("length"), to_value(delete_count), ctx)?;
+
+    let new_length = length - delete_count + items.len();
+    if items.len() < delete_count {
+        for k in start..(length - delete_count) {
+            move_element(&obj, k + delete_count, k + items.len(), ctx)?;
+        }
+        for k in (new_length..length).rev() {
+            delete_or_throw(&obj, &index_key(k), ctx)?;
+        }
+    } else if items.len() > delete_count {
+        for k in (start..(length - delete_count)).rev() {
+            move_element(&obj, k + delete_count, k + items.len(), ctx)?;
+        }
+    }
+
+    for (k, item) in items.iter().enumerate() {
+        set_or_throw(&obj, &index_key(start + k), item.clone(), ctx)?;
+    }
+    set_or_throw(&obj, &to_value("length"), to_value(new_length), ctx)?;
+    Ok(deleted)
+}
+
+/// `Array.prototype.flat( [ depth ] )`
+///
+/// The flat method creates a new array with the elements of the nested arrays
+/// concatenated into it, up to `depth` levels deep (1 by default).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flat
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flat
+pub fn flat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let depth = match args.get(0) {
+        Some(depth) if !depth.is_undefined() => ctx.to_integer_or_infinity(depth).max(0.0),
+        _ => 1.0,
+    };
+
+    let array = array_species_create(&obj, 0, ctx)?;
+    flatten_into_array(&array, &obj, length, 0, depth, None, ctx)?;
+    Ok(array)
+}
+
+/// `Array.prototype.flatMap( mapperFunction [ , thisArg ] )`
+///
+/// The flatMap method maps each element with the mapper function, then flattens the result one level deep.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flatmap
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flatMap
+pub fn flat_map(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let mapper = callback_arg(args, ctx)?;
+    let this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    let array = array_species_create(&obj, 0, ctx)?;
+    flatten_into_array(
+        &array,
+        &obj,
+        length,
+        0,
+        1.0,
+        Some((&mapper, &this_arg)),
+        ctx,
+    )?;
+    Ok(array)
+}
+
+/// The abstract operation `FlattenIntoArray`.
+///
+/// Adds the elements of `source` to `target` from index `start`, and returns the index after the last one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-flattenintoarray
+fn flatten_into_array(
+    target: &Value,
+    source: &Value,
+    source_length: usize,
+    start: usize,
+    depth: f64,
+    mapper: Option<(&Value, &Value)>,
+    ctx: &mut Interpreter,
+) -> Result<usize, Value> {
+    let mut target_index = start;
+    for source_index in 0..source_length {
+        let key = index_key(source_index);
+        if !ctx.has_property(source, &key)? {
+            continue;
+        }
+
+        let mut element = ctx.get_property(source, &key)?;
+        if let Some((mapper, this_arg)) = mapper {
+            let args = [element, to_value(source_index), source.clone()];
+            element = ctx.call(mapper, &mut this_arg.clone(), &args)?;
+        }
+
+        if depth > 0.0 && is_array_object(&element) {
+            let length = length_of_array_like(&element, ctx)?;
+            target_index = flatten_into_array(
+                target,
+                &element,
+                length,
+                target_index,
+                depth - 1.0,
+                None,
+                ctx,
+            )?;
+        } else {
+            if target_index as f64 >= MAX_SAFE_INTEGER {
+                return Err(ctx.construct_type_error("Invalid array length"));
+            }
+            create_element_or_throw(target, target_index, element, ctx)?;
+            target_index += 1;
+        }
+    }
+    Ok(target_index)
+}
+
+/// `Array.prototype.copyWithin( target, start [ , end ] )`
+///
+/// The copyWithin method copies the elements from `start` to `end` over the elements from `target`,
+/// without changing the length of the array. The ranges may overlap.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+pub fn copy_within(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let to = relative_index(
+        Some(&args.get(0).cloned().unwrap_or_else(undefined)),
+        0,
+        length,
+        ctx,
+    );
+    let from = relative_index(
+        Some(&args.get(1).cloned().unwrap_or_else(undefined)),
+        0,
+        length,
+        ctx,
+    );
+    let end = relative_index(args.get(2), length, length, ctx);
+
+    let count = min(end.saturating_sub(from), length - to);
+    if from < to && to < from + count {
+        // Copy backwards, so the overlapping elements are read before being overwritten
+        for k in (0..count).rev() {
+            move_element(&obj, from + k, to + k, ctx)?;
+        }
+    } else {
+        for k in 0..count {
+            move_element(&obj, from + k, to + k, ctx)?;
+        }
+    }
+    Ok(obj)
+}
+
+/// `Array.prototype.findLast( predicate [ , thisArg ] )`
+///
+/// The findLast method calls the predicate on the elements in descending order,
+/// and returns the first element for which it returns a truthy value, or `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlast
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+pub fn find_last(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    match find_last_element(this, args, ctx)? {
+        Some((_, element)) => Ok(element),
+        None => Ok(undefined()),
+    }
+}
+
+/// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+///
+/// The findLastIndex method calls the predicate on the elements in descending order,
+/// and returns the index of the first element for which it returns a truthy value, or `-1`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlastindex
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+pub fn find_last_index(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    match find_last_element(this, args, ctx)? {
+        Some((index, _)) => Ok(to_value(index)),
+        None => Ok(to_value(-1)),
+    }
+}
+
+/// Finds the last element matching the predicate of `findLast` and `findLastIndex`, with its index.
+///
+/// Unlike most array methods, the predicate is called for the holes too.
+fn find_last_element(
+    this: &Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> Result<Option<(usize, Value)>, Value> {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let predicate = callback_arg(args, ctx)?;
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    for k in (0..length).rev() {
+        let element = ctx.get_property(&obj, &index_key(k))?;
+        let args = [element.clone(), to_value(k), obj.clone()];
+        if ctx.call(&predicate, &mut this_arg, &args)?.is_true() {
+            return Ok(Some((k, element)));
+        }
+    }
+    Ok(None)
+}
+
+/// `Array.prototype.at( index )`
+///
+/// The at method returns the element at `index`, counting from the end of the array for negative indices.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.at
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+pub fn at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+    let relative = ctx.to_integer_or_infinity(&args.get(0).cloned().unwrap_or_else(undefined));
+
+    let index = if relative >= 0.0 {
+        relative
+    } else {
+        length as f64 + relative
+    };
+    if index < 0.0 || index >= length as f64 {
+        return Ok(undefined());
+    }
+    ctx.get_property(&obj, &index_key(index as usize))
+}
+
+/// `Array.prototype.keys()`
+///
+/// The keys method returns an iterator over the indices of the array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.keys
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/keys
+pub fn keys(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    Ok(create_array_iterator(ctx, &obj, IterationKind::Key))
+}
+
+/// `Array.prototype.values()`
+///
+/// The values method returns an iterator over the elements of the array.
+/// It is also the `[Symbol.iterator]` method of arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.values
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values
+pub fn values(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    Ok(create_array_iterator(ctx, &obj, IterationKind::Value))
+}
+
+/// `Array.prototype.entries()`
+///
+/// The entries method returns an iterator over the `[index, element]` pairs of the array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.prototype.entries
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/entries
+pub fn entries(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    Ok(create_array_iterator(ctx, &obj, IterationKind::KeyAndValue))
+}
+
+/// `Array.prototype.toLocaleString( [locales [, options]] )`
+///
+/// The toLocaleString method joins the result of the `toLocaleString` method of each element
+/// with commas, `undefined` and `null` elements becoming empty strings.
+///
+/// The locales and options are passed on to the `toLocaleString` method of the elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-array.prototype.tolocalestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toLocaleString
+pub fn to_locale_string(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let length = length_of_array_like(&obj, ctx)?;
+
+    let mut strings = Vec::with_capacity(length);
+    for k in 0..length {
+        let mut element = ctx.get_property(&obj, &index_key(k))?;
+        if element.is_null_or_undefined() {
+            strings.push(String::new());
+            continue;
+        }
+
+        let element_obj = ctx.to_object(&element)?;
+        let method = ctx.get_property(&element_obj, &to_value("toLocaleString"))?;
+        let string = ctx.call(&method, &mut element, args.get(..2).unwrap_or(args))?;
+        strings.push(ctx.value_to_rust_string(&string));
+    }
+    Ok(to_value(strings.join(",")))
+}
+
+/// `Array.from( items [ , mapfn [ , thisArg ] ] )`
+///
+/// The from function creates a new array from an iterable object, or else from an array-like object,
+/// optionally mapping each element with `mapfn`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.from
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+pub fn from(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let items = args.get(0).cloned().unwrap_or_else(undefined);
+    let map_fn = match args.get(1) {
+        Some(map_fn) if !map_fn.is_undefined() => {
+            if !map_fn.is_function() {
+                return ctx.throw_type_error(format!("{} is not a function", map_fn));
+            }
+            Some(map_fn.clone())
+        }
+        _ => None,
+    };
+    let mut this_arg = args.get(2).cloned().unwrap_or_else(undefined);
+
+    let using_iterator = if items.is_null_or_undefined() {
+        undefined()
+    } else {
+        let items = ctx.to_object(&items)?;
+        ctx.get_property(
+            &items,
+            &well_known_symbol(&ctx.realm.global_obj, "iterator"),
+        )?
+    };
+
+    if !using_iterator.is_null_or_undefined() {
+        if !using_iterator.is_function() {
+            return ctx.throw_type_error(format!("{} is not a function", using_iterator));
+        }
+        let array = construct_or_create_array(this, None, ctx)?;
+        let iterator = ctx.call(&using_iterator, &mut items.clone(), &[])?;
+        if !iterator.is_object() {
+            return ctx.throw_type_error("Result of the Symbol.iterator method is not an object");
+        }
+        let next = ctx.get_property(&iterator, &to_value("next"))?;
+
+        let mut k = 0;
+        loop {
+            let result = ctx.call(&next, &mut iterator.clone(), &[])?;
+            if !result.is_object() {
+                return ctx
+                    .throw_type_error(format!("Iterator result {} is not an object", result));
+            }
+            if ctx.get_property(&result, &to_value("done"))?.is_true() {
+                set_or_throw(&array, &to_value("length"), to_value(k), ctx)?;
+                return Ok(array);
+            }
+
+            let value = ctx.get_property(&result, &to_value("value"))?;
+            let value = match map_fn {
+                Some(ref map_fn) => ctx.call(map_fn, &mut this_arg, &[value, to_value(k)]),
+                None => Ok(value),
+            };
+            if let Err(error) =
+                value.and_then(|value| create_element_or_throw(&array, k, value, ctx))
+            {
+                return Err(iterator_close(&iterator, error, ctx));
+            }
+            k += 1;
+        }
+    }
+
+    let array_like = ctx.to_object(&items)?;
+    let length = length_of_array_like(&array_like, ctx)?;
+    let array = construct_or_create_array(this, Some(length), ctx)?;
+    for k in 0..length {
+        let value = ctx.get_property(&array_like, &index_key(k))?;
+        let value = match map_fn {
+            Some(ref map_fn) => ctx.call(map_fn, &mut this_arg, &[value, to_value(k)])?,
+            None => value,
+        };
+        create_element_or_throw(&array, k, value, ctx)?;
+    }
+    set_or_throw(&array, &to_value("length"), to_value(length), ctx)?;
+    Ok(array)
+}
+
+/// The abstract operation `IteratorClose`, for an iteration which ended with `error`.
+///
+/// Returns the error to throw, which is the original one even when the `return` method throws.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+fn iterator_close(iterator: &Value, error: Value, ctx: &mut Interpreter) -> Value {
+    if let Ok(method) = ctx.get_property(iterator, &to_value("return")) {
+        if method.is_function() {
+            let _ = ctx.call(&method, &mut iterator.clone(), &[]);
+        }
+    }
+    error
+}
+
+/// `Array.of( ...items )`
+///
+/// The of function creates a new array with its arguments as elements.
+/// Unlike `Array( length )`, a single number argument is an element.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-array.of
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/of
+pub fn of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let array = construct_or_create_array(this, Some(args.len()), ctx)?;
+    for (k, item) in args.iter().enumerate() {
+        create_element_or_throw(&array, k, item.clone(), ctx)?;
+    }
+    set_or_throw(&array, &to_value("length"), to_value(args.len()), ctx)?;
+    Ok(array)
+}
+
+/// `get Array [ @@species ]`
+///
+/// The constructor used by the array methods to create their result, `this` by default.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-array-@@species
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@species
+pub fn get_species(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+    Ok(this.clone())
+}
+
 /// Create a new `Array` object.
 pub fn create(global: &Value) -> Value {
     // Create prototype
@@ -1006,11 +1881,39 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(find_index, named "findIndex", with length 1, of prototype);
     make_builtin_fn!(slice, named "slice", with length 2, of prototype);
     make_builtin_fn!(some, named "some", with length 2, of prototype);
+    make_builtin_fn!(sort, named "sort", with length 1, of prototype);
+    make_builtin_fn!(reduce, named "reduce", with length 1, of prototype);
+    make_builtin_fn!(reduce_right, named "reduceRight", with length 1, of prototype);
+    make_builtin_fn!(splice, named "splice", with length 2, of prototype);
+    make_builtin_fn!(flat, named "flat", of prototype);
+    make_builtin_fn!(flat_map, named "flatMap", with length 1, of prototype);
+    make_builtin_fn!(copy_within, named "copyWithin", with length 2, of prototype);
+    make_builtin_fn!(find_last, named "findLast", with length 1, of prototype);
+    make_builtin_fn!(find_last_index, named "findLastIndex", with length 1, of prototype);
+    make_builtin_fn!(at, named "at", with length 1, of prototype);
+    make_builtin_fn!(keys, named "keys", of prototype);
+    make_builtin_fn!(values, named "values", of prototype);
+    make_builtin_fn!(entries, named "entries", of prototype);
+    make_builtin_fn!(to_locale_string, named "toLocaleString", of prototype);
+
+    // `Array.prototype[Symbol.iterator]` is the same function as `values`
+    prototype.set_symbol_prop(
+        &well_known_symbol(global, "iterator"),
+        Property::default()
+            .value(prototype.get_field_slice("values"))
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+    prototype.set_internal_slot(ITERATOR_PROTOTYPE, iterator::create_prototype(global));
 
     let array = make_constructor_fn!(make_array, make_array, global, prototype);
 
     // Static Methods
     make_builtin_fn!(is_array, named "isArray", with length 1, of array);
+    make_builtin_fn!(from, named "from", with length 1, of array);
+    make_builtin_fn!(of, named "of", of array);
+    make_getter_fn!(get_species, keyed well_known_symbol(global, "species"), of array);
 
     array
 }
@@ -85,6 +85,27 @@ fn join() {
     // Many
     let many = forward(&mut engine, "many.join('.')");
     assert_eq!(many, String::from("a.b.c"));
+    // The elements are converted with `ToString`, null and undefined are empty
+    assert_eq!(forward(&mut engine, "[[1, 2], 3].join()"), "1,2,3");
+    assert_eq!(
+        forward(&mut engine, "[null, undefined, 1].join('-')"),
+        "--1"
+    );
+    assert_eq!(forward(&mut engine, "[1, 2].join(undefined)"), "1,2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype.join.call({ length: 2, 0: 'x' })"
+        ),
+        "x,"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { [Symbol()].join() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
 }
 
 #[test]
@@ -497,10 +518,7 @@ fn fill() {
         String::from("4,4,4")
     );
 
-    assert_eq!(
-        forward(&mut engine, "a.fill().join()"),
-        String::from("undefined,undefined,undefined")
-    );
+    assert_eq!(forward(&mut engine, "a.fill().join()"), String::from(",,"));
 
     // test object reference
     forward(&mut engine, "a = (new Array(3)).fill({});");
@@ -653,6 +671,17 @@ fn for_each() {
     assert_eq!(forward(&mut engine, "sum"), "14");
     assert_eq!(forward(&mut engine, "indexSum"), "6");
     assert_eq!(forward(&mut engine, "listLengthSum"), "16");
+
+    // Holes are skipped
+    let holes = r#"
+        var visited = '';
+        var sparse = [1, 2, 3];
+        sparse[5] = 6;
+        delete sparse[1];
+        sparse.forEach(function (item, index) { visited += index + ':' + item + ' '; });
+        visited
+        "#;
+    assert_eq!(forward(&mut engine, holes), "0:1 2:3 5:6 ");
 }
 
 #[test]
@@ -813,3 +842,476 @@ fn call_array_constructor_with_one_argument() {
     let result = forward(&mut engine, "one.length");
     assert_eq!(result, "1");
 }
+
+#[test]
+fn sort() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var numbers = [10, 9, 1, undefined, 100];
+        numbers.sort();
+
+        var people = [
+            { name: "a", age: 30 },
+            { name: "b", age: 20 },
+            { name: "c", age: 30 },
+            { name: "d", age: 20 }
+        ];
+        people.sort(function (x, y) { return x.age - y.age; });
+        var names = people.map(function (person) { return person.name; });
+
+        var holes = [3];
+        holes[3] = 1;
+        holes[5] = undefined;
+        holes.sort();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "numbers.slice(0, 4).join()"),
+        "1,10,100,9"
+    );
+    assert_eq!(forward(&mut engine, "numbers[4]"), "undefined");
+    assert_eq!(forward(&mut engine, "names.join()"), "b,d,a,c");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[3, 1, 2].sort(function (x, y) { return y - x; }).join()"
+        ),
+        "3,2,1"
+    );
+    assert_eq!(forward(&mut engine, "holes.length"), "6");
+    assert_eq!(forward(&mut engine, "holes[0] + holes[1]"), "4");
+    assert_eq!(forward(&mut engine, "2 in holes"), "true");
+    assert_eq!(forward(&mut engine, "3 in holes"), "false");
+    assert_eq!(
+        forward(&mut engine, "try { [].sort(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { [2, 1].sort(function () { throw 'stop'; }) } catch (e) { e }"
+        ),
+        "stop"
+    );
+}
+
+#[test]
+fn reduce() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        function add(sum, value) { return sum + value; }
+        function collect(log, value, index) { return log + index + ':' + value + ' '; }
+
+        var sparse = [];
+        sparse[1] = 'b';
+        sparse[3] = 'd';
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "[1, 2, 3].reduce(add)"), "6");
+    assert_eq!(forward(&mut engine, "[1, 2, 3].reduce(add, 10)"), "16");
+    assert_eq!(forward(&mut engine, "['a', 'b'].reduceRight(add)"), "ba");
+    assert_eq!(
+        forward(&mut engine, "sparse.reduce(collect, '')"),
+        "1:b 3:d "
+    );
+    assert_eq!(
+        forward(&mut engine, "sparse.reduceRight(collect, '')"),
+        "3:d 1:b "
+    );
+    assert_eq!(forward(&mut engine, "sparse.reduce(add)"), "bd");
+    assert_eq!(forward(&mut engine, "[].reduce(add, 'empty')"), "empty");
+    assert_eq!(
+        forward(&mut engine, "try { [].reduce(add) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { [1].reduceRight() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var arrayLike = { length: 2, 0: 'x', 1: 'y', reduce: Array.prototype.reduce }; arrayLike.reduce(add)"
+        ),
+        "xy"
+    );
+}
+
+#[test]
+fn splice() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = [1, 2, 3, 4, 5];
+        var removed = array.splice(1, 2);
+
+        var grown = [1, 2, 3];
+        var none = grown.splice(-1, 0, 'a', 'b');
+
+        var rest = [1, 2, 3, 4];
+        var tail = rest.splice(2);
+
+        var replaced = [1, 2, 3];
+        replaced.splice(0, 2, 'x');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "removed.join()"), "2,3");
+    assert_eq!(forward(&mut engine, "array.join()"), "1,4,5");
+    assert_eq!(forward(&mut engine, "array.length"), "3");
+    assert_eq!(forward(&mut engine, "3 in array"), "false");
+    assert_eq!(forward(&mut engine, "none.length"), "0");
+    assert_eq!(forward(&mut engine, "grown.join()"), "1,2,a,b,3");
+    assert_eq!(forward(&mut engine, "tail.join()"), "3,4");
+    assert_eq!(forward(&mut engine, "rest.join()"), "1,2");
+    assert_eq!(forward(&mut engine, "replaced.join()"), "x,3");
+    assert_eq!(forward(&mut engine, "[1, 2].splice().length"), "0");
+}
+
+#[test]
+fn flat() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var nested = [1, [2, [3, [4]]], 5];
+        var sparse = [1];
+        sparse[2] = [2];
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "nested.flat().length"), "4");
+    assert_eq!(forward(&mut engine, "nested.flat()[2][0]"), "3");
+    assert_eq!(forward(&mut engine, "nested.flat(2).length"), "5");
+    assert_eq!(
+        forward(&mut engine, "nested.flat(1 / 0).join()"),
+        "1,2,3,4,5"
+    );
+    assert_eq!(forward(&mut engine, "nested.flat(-1).length"), "3");
+    assert_eq!(forward(&mut engine, "sparse.flat().join()"), "1,2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[1, 2].flatMap(function (x) { return [x, [x * 10]]; }).length"
+        ),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "['a b', 'c'].flatMap(function (x, i) { return x + i; }).join()"
+        ),
+        "a b0,c1"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { [1].flatMap() } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn copy_within() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var sparse = [1, 2, 3];
+        sparse[5] = 6;
+        sparse.copyWithin(0, 3, 5);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(0, 3).join()"),
+        "4,5,3,4,5"
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(1, 0).join()"),
+        "1,1,2,3,4"
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(-2, -4, -3).join()"),
+        "1,2,3,2,5"
+    );
+    assert_eq!(forward(&mut engine, "0 in sparse"), "false");
+    assert_eq!(forward(&mut engine, "sparse[2]"), "3");
+    assert_eq!(forward(&mut engine, "sparse.length"), "6");
+}
+
+#[test]
+fn find_last() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = [1, 12, 3, 14, 5];
+        function big(x) { return x > 10; }
+        function huge(x) { return x > 100; }
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "array.findLast(big)"), "14");
+    assert_eq!(forward(&mut engine, "array.findLastIndex(big)"), "3");
+    assert_eq!(forward(&mut engine, "array.findLast(huge)"), "undefined");
+    assert_eq!(forward(&mut engine, "array.findLastIndex(huge)"), "-1");
+    assert_eq!(
+        forward(&mut engine, "try { array.findLast() } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn at() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = ['a', 'b', 'c'];
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "array.at(0)"), "a");
+    assert_eq!(forward(&mut engine, "array.at()"), "a");
+    assert_eq!(forward(&mut engine, "array.at(-1)"), "c");
+    assert_eq!(forward(&mut engine, "array.at(1.7)"), "b");
+    assert_eq!(forward(&mut engine, "array.at(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "array.at(-4)"), "undefined");
+}
+
+#[test]
+fn iterators() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = ['a', 'b'];
+        var keys = array.keys();
+        var entries = array.entries();
+        var values = array[Symbol.iterator]();
+        values.next();
+        array.push('c');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "keys.next().value"), "0");
+    assert_eq!(forward(&mut engine, "keys.next().value"), "1");
+    assert_eq!(forward(&mut engine, "entries.next().value.join()"), "0,a");
+    assert_eq!(forward(&mut engine, "values.next().value"), "b");
+    assert_eq!(forward(&mut engine, "values.next().value"), "c");
+    assert_eq!(forward(&mut engine, "values.next().done"), "true");
+
+    // Once done, the iterator stays done
+    forward(&mut engine, "array.push('d')");
+    assert_eq!(forward(&mut engine, "values.next().done"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype[Symbol.iterator] === Array.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "values[Symbol.iterator]() === values"),
+        "true"
+    );
+}
+
+#[test]
+fn to_locale_string() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "[1, null, 2, undefined].toLocaleString()"),
+        "1,,2,"
+    );
+    assert_eq!(forward(&mut engine, "[].toLocaleString()"), "");
+}
+
+#[test]
+fn from() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map([['a', 1], ['b', 2]]);
+        var set = new Set([1, 2, 2, 3]);
+        var arrayLike = { length: 3, 0: 'x', 2: 'z' };
+        function double(x) { return x * 2; }
+
+        var calls = 0;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () {
+                    calls = calls + 1;
+                    return { done: calls > 2, value: calls };
+                }
+            };
+        };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "Array.from(set).join()"), "1,2,3");
+    assert_eq!(
+        forward(&mut engine, "Array.from(set, double).join()"),
+        "2,4,6"
+    );
+    assert_eq!(forward(&mut engine, "Array.from(map)[1].join()"), "b,2");
+    assert_eq!(forward(&mut engine, "Array.from(map.keys()).join()"), "a,b");
+    assert_eq!(forward(&mut engine, "Array.from(iterable).join()"), "1,2");
+    assert_eq!(forward(&mut engine, "Array.from(arrayLike).length"), "3");
+    assert_eq!(
+        forward(&mut engine, "Array.from(arrayLike)[1]"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut engine, "Array.from(arrayLike)[2]"), "z");
+    assert_eq!(forward(&mut engine, "1 in Array.from(arrayLike)"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.from({ length: 2 }, function (x, i) { return i; }).join()"
+        ),
+        "0,1"
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.isArray(Array.from([1]))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Array.from([], 1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { Array.from(null) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Uint8Array.arrayFrom = Array.from; try { Uint8Array.arrayFrom([1, 2]) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn of() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Array.of(7).length"), "1");
+    assert_eq!(forward(&mut engine, "Array.of(7)[0]"), "7");
+    assert_eq!(
+        forward(&mut engine, "Array.of(1, 'a', true).join()"),
+        "1,a,true"
+    );
+    assert_eq!(forward(&mut engine, "Array.of().length"), "0");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Int8Array.arrayOf = Array.of; try { Int8Array.arrayOf(1, 2) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn species() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = [1, 2, 3];
+        array.constructor = {};
+        array.constructor[Symbol.species] = Uint8Array;
+
+        var plain = [1, 2, 3];
+        plain.constructor = {};
+        plain.constructor[Symbol.species] = null;
+
+        var invalid = [1, 2, 3];
+        invalid.constructor = {};
+        invalid.constructor[Symbol.species] = 'nope';
+
+        function double(x) { return x * 2; }
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Array[Symbol.species] === Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "array.map(double)"),
+        "Uint8Array(3) [ 2, 4, 6 ]"
+    );
+
+    // Typed arrays have a fixed length, so only `map` can fill the species
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { array.filter(double) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { array.slice(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.isArray(plain.filter(double))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { invalid.map(double) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { invalid.splice(0) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn generic_array_likes() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var arrayLike = { length: 3, 0: 'c', 1: 'a', 2: 'b' };
+        arrayLike.sort = Array.prototype.sort;
+        arrayLike.slice = Array.prototype.slice;
+        arrayLike.sort();
+        var concatenated = [1].concat([2, 3], 4, arrayLike);
+        var sliced = arrayLike.slice(1);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "arrayLike[0] + arrayLike[1] + arrayLike[2]"),
+        "abc"
+    );
+    assert_eq!(forward(&mut engine, "concatenated.length"), "5");
+    assert_eq!(
+        forward(&mut engine, "concatenated[4] === arrayLike"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Array.isArray(sliced)"), "true");
+    assert_eq!(forward(&mut engine, "sliced.join()"), "b,c");
+}
+
+#[test]
+fn length_of_large_indices() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var a = [];
+        a[4294967294] = 1;
+        var b = [];
+        b[4294967295] = 1;
+        var c = [1, 2];
+        c[2147483648] = 3;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "a.length"), "4294967295");
+    // 2^32 - 1 isn't an array index.
+    assert_eq!(forward(&mut engine, "b.length"), "0");
+    assert_eq!(forward(&mut engine, "b[4294967295]"), "1");
+    assert_eq!(forward(&mut engine, "c.length"), "2147483649");
+}
@@ -0,0 +1,328 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! The `ArrayBuffer` object is used to represent a generic, fixed-length raw binary data buffer.
+//! Its contents can't be manipulated directly, but through a typed array or a `DataView`.
+//!
+//! A buffer created with a `maxByteLength` option is resizable, the views which track its
+//! length follow its size.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// The biggest buffer that can be allocated, bigger lengths throw a `RangeError`.
+const MAX_BYTE_LENGTH: usize = u32::MAX as usize;
+
+/// The data of an `ArrayBuffer` object.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ArrayBufferData {
+    /// The contents of the buffer.
+    pub bytes: Vec<u8>,
+    /// The maximum length of a resizable buffer, `None` for a fixed-length buffer.
+    pub max_byte_length: Option<usize>,
+}
+
+/// Creates a new `ArrayBuffer` object holding `bytes`.
+pub fn new_array_buffer(ctx: &Interpreter, bytes: Vec<u8>) -> Value {
+    let prototype = ctx
+        .realm
+        .global_obj
+        .get_field_slice("ArrayBuffer")
+        .get_field_slice(PROTOTYPE);
+
+    ValueData::new_obj_from_prototype(
+        prototype,
+        ObjectKind::ArrayBuffer(ArrayBufferData {
+            bytes,
+            max_byte_length: None,
+        }),
+    )
+}
+
+/// Allocates a new zeroed `ArrayBuffer` of `byte_length` bytes, throwing a `RangeError`
+/// if it's too big.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+pub(crate) fn allocate_array_buffer(byte_length: usize, ctx: &mut Interpreter) -> ResultValue {
+    check_allocation(byte_length, ctx)?;
+    Ok(new_array_buffer(ctx, vec![0; byte_length]))
+}
+
+/// Calls `f` with the contents of the `ArrayBuffer` object `buffer`,
+/// or returns `None` if it's not an `ArrayBuffer`.
+///
+/// The buffer is borrowed during the call, so `f` must not run any JavaScript code.
+pub fn with_array_buffer_bytes<R, F: FnOnce(&mut Vec<u8>) -> R>(buffer: &Value, f: F) -> Option<R> {
+    match **buffer {
+        ValueData::Object(ref obj) => match obj.borrow_mut().kind {
+            ObjectKind::ArrayBuffer(ref mut data) => Some(f(&mut data.bytes)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Gets the current length of the `ArrayBuffer` object `buffer`, 0 if it's not one.
+pub(crate) fn byte_length_of(buffer: &Value) -> usize {
+    with_array_buffer_bytes(buffer, |bytes| bytes.len()).unwrap_or(0)
+}
+
+/// Resolves a relative index argument, which counts from the end when it's negative,
+/// clamped to `0..=len`.
+pub(crate) fn relative_index(
+    value: Option<&Value>,
+    default: usize,
+    len: usize,
+    ctx: &mut Interpreter,
+) -> usize {
+    let value = match value {
+        Some(value) if !value.is_undefined() => value,
+        _ => return default,
+    };
+
+    let relative = ctx.to_integer_or_infinity(value);
+    if relative < 0.0 {
+        (len as f64 + relative).max(0.0) as usize
+    } else {
+        relative.min(len as f64) as usize
+    }
+}
+
+/// Gets the length and the maximum length of an `ArrayBuffer` object,
+/// or throws a `TypeError` if `this` is not one.
+fn this_array_buffer(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<(usize, Option<usize>), Value> {
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::ArrayBuffer(ref data) = obj.borrow().kind {
+            return Ok((data.bytes.len(), data.max_byte_length));
+        }
+    }
+
+    Err(ctx.construct_type_error(format!(
+        "Method ArrayBuffer.prototype.{} called on incompatible receiver {}",
+        method, this
+    )))
+}
+
+/// Checks that a buffer of `length` bytes can be allocated.
+fn check_allocation(length: usize, ctx: &mut Interpreter) -> Result<(), Value> {
+    if length > MAX_BYTE_LENGTH {
+        return Err(ctx.construct_range_error("Array buffer allocation failed"));
+    }
+    Ok(())
+}
+
+/// Create a new `ArrayBuffer`.
+///
+/// The first argument is the length of the buffer in bytes, the second an optional
+/// object whose `maxByteLength` property makes the buffer resizable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/ArrayBuffer
+pub fn make_array_buffer(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let byte_length = ctx.to_index(&args.get(0).cloned().unwrap_or_else(undefined))?;
+
+    let max_byte_length = match args.get(1) {
+        Some(options) if options.is_object() => {
+            let max = ctx.get_property(options, &to_value("maxByteLength"))?;
+            if max.is_undefined() {
+                None
+            } else {
+                Some(ctx.to_index(&max)?)
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(max) = max_byte_length {
+        if byte_length > max {
+            return ctx.throw_range_error("byteLength exceeds maxByteLength");
+        }
+        check_allocation(max, ctx)?;
+    }
+    check_allocation(byte_length, ctx)?;
+
+    this.set_kind(ObjectKind::ArrayBuffer(ArrayBufferData {
+        bytes: vec![0; byte_length],
+        max_byte_length,
+    }));
+
+    Ok(this.clone())
+}
+
+/// `ArrayBuffer` can only be called as a constructor.
+pub fn call_array_buffer(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor ArrayBuffer requires 'new'")
+}
+
+/// `ArrayBuffer.isView( arg )`
+///
+/// The `isView()` method determines whether the argument is a typed array or a `DataView`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+pub fn is_view(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    let is_view = args
+        .get(0)
+        .and_then(|arg| arg.as_object())
+        .map_or(false, |obj| match obj.kind {
+            ObjectKind::TypedArray(_) | ObjectKind::DataView(_) => true,
+            _ => false,
+        });
+    Ok(to_value(is_view))
+}
+
+/// `get ArrayBuffer.prototype.byteLength`
+///
+/// The `byteLength` accessor property represents the current length of the buffer in bytes.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+pub fn byte_length(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (len, _) = this_array_buffer(this, "byteLength", ctx)?;
+    Ok(to_value(len))
+}
+
+/// `get ArrayBuffer.prototype.maxByteLength`
+///
+/// The `maxByteLength` accessor property is the length a resizable buffer can grow to,
+/// or the length of a fixed-length buffer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength
+pub fn max_byte_length(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (len, max) = this_array_buffer(this, "maxByteLength", ctx)?;
+    Ok(to_value(max.unwrap_or(len)))
+}
+
+/// `get ArrayBuffer.prototype.resizable`
+///
+/// The `resizable` accessor property tells whether the buffer can be resized.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable
+pub fn resizable(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (_, max) = this_array_buffer(this, "resizable", ctx)?;
+    Ok(to_value(max.is_some()))
+}
+
+/// `ArrayBuffer.prototype.resize( newLength )`
+///
+/// The `resize()` method resizes a resizable buffer, new bytes are zeroed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize
+pub fn resize(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (_, max) = this_array_buffer(this, "resize", ctx)?;
+    let max = match max {
+        Some(max) => max,
+        None => {
+            return ctx.throw_type_error(
+                "Method ArrayBuffer.prototype.resize called on a fixed-length buffer",
+            )
+        }
+    };
+
+    let new_length = ctx.to_index(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    if new_length > max {
+        return ctx.throw_range_error("ArrayBuffer.prototype.resize: Invalid length parameter");
+    }
+
+    with_array_buffer_bytes(this, |bytes| bytes.resize(new_length, 0));
+    Ok(undefined())
+}
+
+/// `ArrayBuffer.prototype.slice( begin [, end] )`
+///
+/// The `slice()` method returns a new fixed-length `ArrayBuffer` with a copy of the bytes
+/// from `begin` up to `end`, exclusive. Negative indices count from the end of the buffer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (len, _) = this_array_buffer(this, "slice", ctx)?;
+    let first = relative_index(args.get(0), 0, len, ctx);
+    let last = relative_index(args.get(1), len, len, ctx);
+
+    // Reading the arguments may have resized the buffer
+    let bytes = with_array_buffer_bytes(this, |bytes| {
+        let last = last.min(bytes.len());
+        let first = first.min(last);
+        bytes[first..last].to_vec()
+    })
+    .unwrap_or_default();
+
+    Ok(new_array_buffer(ctx, bytes))
+}
+
+/// Create a new `ArrayBuffer` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(byte_length, named "byteLength", of prototype);
+    make_getter_fn!(max_byte_length, named "maxByteLength", of prototype);
+    make_getter_fn!(resizable, named "resizable", of prototype);
+    make_builtin_fn!(resize, named "resize", with length 1, of prototype);
+    make_builtin_fn!(slice, named "slice", with length 2, of prototype);
+
+    let array_buffer =
+        make_constructor_fn!(make_array_buffer, call_array_buffer, global, prototype);
+    array_buffer.set_field_slice("length", to_value(1_i32));
+    make_builtin_fn!(is_view, named "isView", with length 1, of array_buffer);
+    array_buffer
+}
+
+/// Initialise the `ArrayBuffer` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("ArrayBuffer", create(global));
+}
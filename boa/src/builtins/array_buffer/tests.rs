@@ -0,0 +1,148 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "8");
+    assert_eq!(forward(&mut engine, "buffer.resizable"), "false");
+    assert_eq!(forward(&mut engine, "buffer.maxByteLength"), "8");
+    assert_eq!(forward(&mut engine, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(&mut engine, "try { ArrayBuffer(8) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(-1) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn slice() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var bytes = new Uint8Array([1, 2, 3, 4, 5]);
+        var sliced = bytes.buffer.slice(1, -1);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "sliced.byteLength"), "3");
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(sliced).join()"),
+        "2,3,4"
+    );
+    assert_eq!(
+        forward(&mut engine, "bytes.buffer.slice(3).byteLength"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "bytes.buffer.slice(4, 2).byteLength"),
+        "0"
+    );
+
+    // The slice is a copy of the bytes
+    forward(&mut engine, "new Uint8Array(sliced)[0] = 42");
+    assert_eq!(forward(&mut engine, "bytes[1]"), "2");
+}
+
+#[test]
+fn resizable() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+        var tracking = new Uint8Array(buffer);
+        var fixed = new Uint8Array(buffer, 0, 2);
+        tracking.fill(7);
+        buffer.resize(4);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut engine, "buffer.maxByteLength"), "8");
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "4");
+    assert_eq!(forward(&mut engine, "tracking.join()"), "7,7,0,0");
+    assert_eq!(forward(&mut engine, "fixed.length"), "2");
+
+    // Shrinking the buffer puts the fixed-length view out of bounds
+    forward(&mut engine, "buffer.resize(1)");
+    assert_eq!(forward(&mut engine, "tracking.length"), "1");
+    assert_eq!(forward(&mut engine, "fixed.length"), "0");
+    assert_eq!(forward(&mut engine, "fixed[0]"), "undefined");
+
+    assert_eq!(
+        forward(&mut engine, "try { buffer.resize(9) } catch (e) { e.name }"),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(2).resize(1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(4, { maxByteLength: 2 }) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn is_view() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new Int16Array(2))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "ArrayBuffer.isView(new DataView(new ArrayBuffer(2)))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new ArrayBuffer(2))"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn rust_api() {
+    use crate::builtins::{
+        array_buffer::new_array_buffer, typed_array::with_typed_array_bytes, value::to_value,
+    };
+    use crate::forward_val;
+
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let buffer = new_array_buffer(&engine, vec![1, 2, 3, 4]);
+    engine.realm.global_obj.set_field_slice("payload", buffer);
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(payload).join()"),
+        "1,2,3,4"
+    );
+
+    let view = forward_val(&mut engine, "new Uint16Array(payload, 2)").unwrap();
+    let sum = with_typed_array_bytes(&view, |bytes| {
+        bytes[0] = 0xff;
+        bytes.iter().map(|byte| u32::from(*byte)).sum::<u32>()
+    });
+    assert_eq!(sum, Some(0xff + 4));
+    assert_eq!(forward(&mut engine, "new Uint8Array(payload)[2]"), "255");
+    assert_eq!(with_typed_array_bytes(&to_value(1), |_| ()), None);
+}
@@ -0,0 +1,321 @@
+//! This module implements the `Class` trait, to expose Rust types to scripts as classes.
+//!
+//! The instances of a class are objects of the kind `ObjectKind::Class`, keeping a value of the Rust
+//! type which the garbage collector traces, so it can hold the values it uses, like callbacks.
+//! The constructor builds that value from the arguments of `new`, and the methods and accessors of
+//! the prototype get a mutable reference to it. A class is registered in a realm with
+//! `Realm::register_global_class`, which keeps its prototype so Rust code can create instances with
+//! `new_instance`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        function::{set_function_name_and_length, FunctionBody, NativeFunctionData},
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        property::Property,
+        value::{inspect::describe, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{BorrowMutError, Finalize, Gc, GcCell, Trace};
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    marker::PhantomData,
+};
+
+/// A method of the instances of the class `T`.
+pub type ClassMethod<T> = fn(&mut T, &[Value], &mut Interpreter) -> ResultValue;
+
+/// The getter of an accessor of the instances of the class `T`.
+pub type ClassGetter<T> = fn(&mut T, &mut Interpreter) -> ResultValue;
+
+/// The setter of an accessor of the instances of the class `T`.
+pub type ClassSetter<T> = fn(&mut T, Value, &mut Interpreter) -> Result<(), Value>;
+
+/// A Rust type which can be exposed to scripts as a class.
+///
+/// The values of the instances are traced, so the values they keep stay alive with them.
+pub trait Class: Trace + Debug + Any + Sized {
+    /// The name of the constructor, and of the global it's registered as.
+    const NAME: &'static str;
+
+    /// The number of arguments the constructor expects, its `length` property.
+    const LENGTH: usize = 0;
+
+    /// Builds the Rust value of a new instance from the arguments of `new`.
+    fn constructor(args: &[Value], ctx: &mut Interpreter) -> Result<Self, Value>;
+
+    /// Adds the methods and accessors of the prototype, and the static methods of the class.
+    fn init(class: &mut ClassBuilder<'_, Self>);
+}
+
+/// The Rust value of an instance of a class, as a trait object.
+pub trait ClassData: Trace + Debug {
+    /// Gets the value, to downcast it to the type of the class.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Class> ClassData for T {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// The Rust value of an instance of a class, shared by the copies of the object.
+///
+/// The value is borrowed like a `GcCell` while a method runs, which keeps it traced.
+#[derive(Trace, Finalize, Debug, Clone)]
+pub struct ClassInstance {
+    /// The value of the instance.
+    data: Gc<GcCell<Box<dyn ClassData>>>,
+    /// The type of the value, known even while it's borrowed.
+    #[unsafe_ignore_trace]
+    type_id: TypeId,
+}
+
+impl ClassInstance {
+    /// Creates the value of an instance.
+    pub fn new<T: Class>(instance: T) -> Self {
+        Self {
+            data: Gc::new(GcCell::new(Box::new(instance))),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+}
+
+/// Adds the properties of a class to its constructor and prototype, see `Class::init`.
+#[derive(Debug)]
+pub struct ClassBuilder<'a, T> {
+    constructor: &'a Value,
+    prototype: &'a Value,
+    class: PhantomData<T>,
+}
+
+impl<'a, T: Class> ClassBuilder<'a, T> {
+    /// Gets the constructor of the class.
+    pub fn constructor(&self) -> &Value {
+        self.constructor
+    }
+
+    /// Gets the prototype of the instances.
+    pub fn prototype(&self) -> &Value {
+        self.prototype
+    }
+
+    /// Adds a method to the prototype.
+    ///
+    /// Calling the method on a value which isn't an instance of the class throws a `TypeError`.
+    pub fn method(&mut self, name: &str, length: usize, method: ClassMethod<T>) -> &mut Self {
+        let body = FunctionBody::closure(
+            move |this, args, description: &String, ctx| {
+                with_this(this, description, ctx, |instance, ctx| {
+                    method(instance, args, ctx)
+                })
+            },
+            method_description::<T>(name),
+        );
+        self.prototype.set_method(name, length, body);
+        self
+    }
+
+    /// Adds an accessor property to the prototype, with a getter, a setter or both.
+    ///
+    /// Like the accessors of the built-in objects, the property is configurable but not enumerable.
+    pub fn accessor(
+        &mut self,
+        name: &str,
+        getter: Option<ClassGetter<T>>,
+        setter: Option<ClassSetter<T>>,
+    ) -> &mut Self {
+        let description = method_description::<T>(name);
+        let get = getter.map_or_else(undefined, |getter| {
+            let body = FunctionBody::closure(
+                move |this, _, description: &String, ctx| {
+                    with_this(this, description, ctx, |instance, ctx| {
+                        getter(instance, ctx)
+                    })
+                },
+                description.clone(),
+            );
+            ValueData::new_builtin_func(&format!("get {}", name), 0, body)
+        });
+        let set = setter.map_or_else(undefined, |setter| {
+            let body = FunctionBody::closure(
+                move |this, args, description: &String, ctx| {
+                    let value = args.first().cloned().unwrap_or_else(undefined);
+                    with_this(this, description, ctx, |instance, ctx| {
+                        setter(instance, value, ctx)
+                    })?;
+                    Ok(undefined())
+                },
+                description,
+            );
+            ValueData::new_builtin_func(&format!("set {}", name), 1, body)
+        });
+        self.prototype.set_prop_slice(
+            name,
+            Property::default()
+                .get(get)
+                .set(set)
+                .enumerable(false)
+                .configurable(true),
+        );
+        self
+    }
+
+    /// Adds a data property to the prototype, shared by all the instances.
+    pub fn property(&mut self, name: &str, value: Value) -> &mut Self {
+        self.prototype.set_field_slice(name, value);
+        self
+    }
+
+    /// Adds a static method to the constructor.
+    pub fn static_method(
+        &mut self,
+        name: &str,
+        length: usize,
+        method: NativeFunctionData,
+    ) -> &mut Self {
+        self.constructor
+            .set_method(name, length, FunctionBody::BuiltIn(method));
+        self
+    }
+
+    /// Adds a static data property to the constructor.
+    pub fn static_property(&mut self, name: &str, value: Value) -> &mut Self {
+        self.constructor.set_field_slice(name, value);
+        self
+    }
+}
+
+/// Create the constructor of the class `T`, and return it with the prototype of its instances.
+pub(crate) fn create<T: Class>(global: &Value) -> (Value, Value) {
+    let prototype = ValueData::new_obj(Some(global));
+    let instance_prototype = prototype.clone();
+
+    let construct_body: NativeFunctionData = construct::<T>;
+    let call_body: NativeFunctionData = call::<T>;
+    let constructor = make_constructor_fn!(construct_body, call_body, global, prototype);
+    set_function_name_and_length(&constructor, to_value(T::NAME), T::LENGTH as f64);
+
+    T::init(&mut ClassBuilder {
+        constructor: &constructor,
+        prototype: &instance_prototype,
+        class: PhantomData,
+    });
+    (constructor, instance_prototype)
+}
+
+/// `new T(...args)`, builds the Rust value of the instance with `Class::constructor`.
+fn construct<T: Class>(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let instance = T::constructor(args, ctx)?;
+    this.set_kind(ObjectKind::Class(ClassInstance::new(instance)));
+    Ok(this.clone())
+}
+
+/// `T(...args)`, which throws a `TypeError` like the constructors of class declarations.
+fn call<T: Class>(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error(format!(
+        "Class constructor {} cannot be invoked without 'new'",
+        T::NAME
+    ))
+}
+
+/// Creates an instance of the class `T` from its Rust value, without calling its constructor.
+///
+/// Throws a `TypeError` if the class isn't registered in the realm of the interpreter.
+pub fn new_instance<T: Class>(instance: T, ctx: &mut Interpreter) -> ResultValue {
+    let prototype = match ctx.realm.class_prototype::<T>() {
+        Some(prototype) => prototype,
+        None => return ctx.throw_type_error(format!("Class {} is not registered", T::NAME)),
+    };
+    let mut object = Object::create(prototype);
+    object.kind = ObjectKind::Class(ClassInstance::new(instance));
+    Ok(to_value(object))
+}
+
+/// Runs `f` with the Rust value of an instance of the class `T`, like an argument of a method.
+///
+/// Throws a `TypeError` if the value isn't an instance of the class, or if the instance is already
+/// in use by a method which is still running.
+pub fn with_instance<T, R, F>(value: &Value, ctx: &mut Interpreter, f: F) -> Result<R, Value>
+where
+    T: Class,
+    F: FnOnce(&mut T, &mut Interpreter) -> Result<R, Value>,
+{
+    match try_with_instance(value, ctx, f) {
+        Some(Ok(result)) => result,
+        Some(Err(_)) => Err(ctx.construct_type_error(format!(
+            "{} is already in use by a running method",
+            describe(value)
+        ))),
+        None => Err(ctx.construct_type_error(format!(
+            "{} is not an instance of {}",
+            describe(value),
+            T::NAME
+        ))),
+    }
+}
+
+/// Runs `f` with the Rust value of `this`, or throws a `TypeError` naming the method if `this` isn't
+/// an instance of the class `T` or is already in use by a method which is still running.
+fn with_this<T, R, F>(this: &Value, method: &str, ctx: &mut Interpreter, f: F) -> Result<R, Value>
+where
+    T: Class,
+    F: FnOnce(&mut T, &mut Interpreter) -> Result<R, Value>,
+{
+    match try_with_instance(this, ctx, f) {
+        Some(Ok(result)) => result,
+        Some(Err(_)) => Err(ctx.construct_type_error(format!(
+            "Method {} called on {} while another of its methods is running",
+            method,
+            describe(this)
+        ))),
+        None => Err(ctx.construct_type_error(format!(
+            "Method {} called on incompatible receiver {}",
+            method,
+            describe(this)
+        ))),
+    }
+}
+
+/// Runs `f` with the Rust value of `value`, or returns `None` if it isn't an instance of `T`.
+///
+/// Only the value is borrowed during the call, not the object, so `f` can run scripts using the
+/// object. The value is borrowed mutably though, so it's an error to use it again before `f`
+/// returns.
+fn try_with_instance<T, R, F>(
+    value: &Value,
+    ctx: &mut Interpreter,
+    f: F,
+) -> Option<Result<R, BorrowMutError>>
+where
+    T: Class,
+    F: FnOnce(&mut T, &mut Interpreter) -> R,
+{
+    let instance = match **value {
+        ValueData::Object(ref object) => match object.borrow().kind {
+            ObjectKind::Class(ref instance) if instance.type_id == TypeId::of::<T>() => {
+                instance.data.clone()
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let result = instance.try_borrow_mut().map(|mut data| {
+        let data = data
+            .as_any_mut()
+            .downcast_mut()
+            .expect("checked the type of the instance");
+        f(data, ctx)
+    });
+    Some(result)
+}
+
+/// The name of a method in the errors it throws, like `Point.prototype.translate`.
+fn method_description<T: Class>(name: &str) -> String {
+    format!("{}.prototype.{}", T::NAME, name)
+}
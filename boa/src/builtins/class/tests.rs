@@ -0,0 +1,251 @@
+use super::{new_instance, with_instance, Class, ClassBuilder};
+use crate::{
+    builtins::{
+        object::Object,
+        value::{to_value, undefined, ResultValue, Value},
+    },
+    exec::{Executor, Interpreter},
+    forward, forward_val,
+    realm::Realm,
+};
+use gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    fn translate(&mut self, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        self.x += ctx.to_number(&args.first().cloned().unwrap_or_else(undefined))?;
+        self.y += ctx.to_number(&args.get(1).cloned().unwrap_or_else(undefined))?;
+        Ok(undefined())
+    }
+
+    fn equals(&mut self, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let other = args.first().cloned().unwrap_or_else(undefined);
+        let (x, y) = with_instance(&other, ctx, |other: &mut Point, _| Ok((other.x, other.y)))?;
+        Ok(to_value(self.x == x && self.y == y))
+    }
+
+    fn visit(&mut self, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let callback = args.first().cloned().unwrap_or_else(undefined);
+        ctx.call(&callback, &mut undefined(), &[])
+    }
+
+    fn get_x(&mut self, _: &mut Interpreter) -> ResultValue {
+        Ok(to_value(self.x))
+    }
+
+    fn set_x(&mut self, value: Value, ctx: &mut Interpreter) -> Result<(), Value> {
+        self.x = ctx.to_number(&value)?;
+        Ok(())
+    }
+
+    fn get_y(&mut self, _: &mut Interpreter) -> ResultValue {
+        Ok(to_value(self.y))
+    }
+
+    fn origin(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        new_instance(Point { x: 0.0, y: 0.0 }, ctx)
+    }
+}
+
+impl Class for Point {
+    const NAME: &'static str = "Point";
+    const LENGTH: usize = 2;
+
+    fn constructor(args: &[Value], ctx: &mut Interpreter) -> Result<Self, Value> {
+        let x = ctx.to_number(&args.first().cloned().unwrap_or_else(undefined))?;
+        let y = ctx.to_number(&args.get(1).cloned().unwrap_or_else(undefined))?;
+        Ok(Point { x, y })
+    }
+
+    fn init(class: &mut ClassBuilder<'_, Self>) {
+        class
+            .method("translate", 2, Point::translate)
+            .method("equals", 1, Point::equals)
+            .method("visit", 1, Point::visit)
+            .accessor("x", Some(Point::get_x), Some(Point::set_x))
+            .accessor("y", Some(Point::get_y), None)
+            .property("dimensions", to_value(2))
+            .static_method("origin", 0, Point::origin)
+            .static_property("unit", to_value("px"));
+    }
+}
+
+fn engine() -> Interpreter {
+    Executor::new(Realm::create().register_global_class::<Point>())
+}
+
+#[test]
+fn constructor_and_prototype() {
+    let mut engine = engine();
+    forward(&mut engine, "var p = new Point(1, 2);");
+    assert_eq!(forward(&mut engine, "Point.name"), "Point");
+    assert_eq!(forward(&mut engine, "Point.length"), "2");
+    assert_eq!(
+        forward(&mut engine, "Point.prototype.isPrototypeOf(p)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(p) === Point.prototype"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "p.constructor === Point"), "true");
+    assert_eq!(forward(&mut engine, "p.dimensions"), "2");
+    assert_eq!(forward(&mut engine, "Point.unit"), "px");
+    assert_eq!(
+        forward(&mut engine, "try { Point(1, 2) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Point(Symbol()) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn methods_and_accessors() {
+    let mut engine = engine();
+    forward(&mut engine, "var p = new Point(1, 2); p.translate(2, 3);");
+    assert_eq!(forward(&mut engine, "p.x + ',' + p.y"), "3,5");
+    forward(&mut engine, "p.x = 10; p.y = 20;");
+    assert_eq!(forward(&mut engine, "p.x + ',' + p.y"), "10,5");
+    assert_eq!(forward(&mut engine, "p.translate.name"), "translate");
+    assert_eq!(forward(&mut engine, "p.translate.length"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Point.prototype, 'x').get.name"
+        ),
+        "get x"
+    );
+    assert_eq!(forward(&mut engine, "Object.keys(p).length"), "0");
+    assert_eq!(forward(&mut engine, "p.equals(new Point(10, 5))"), "true");
+    assert_eq!(forward(&mut engine, "p.equals(new Point(1, 5))"), "false");
+}
+
+#[test]
+fn incompatible_receivers() {
+    let mut engine = engine();
+    forward(&mut engine, "var p = new Point(1, 2);");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { p.translate.call({}, 1, 1) } catch (e) { e.name + ': ' + e.message }"
+        ),
+        "TypeError: Method Point.prototype.translate called on incompatible receiver {}"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.getOwnPropertyDescriptor(Point.prototype, 'x').get.call(1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { p.equals(1) } catch (e) { e.message }"),
+        "1 is not an instance of Point"
+    );
+    // The instance isn't borrowed while its method runs scripts.
+    assert_eq!(
+        forward(
+            &mut engine,
+            "p.visit(function () { return p.dimensions + ':' + p.visit.name })"
+        ),
+        "2:visit"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "p.visit(function () { try { return p.x } catch (e) { return e.name + ': ' + e.message } })"
+        ),
+        "TypeError: Method Point.prototype.x called on Point {} while another of its methods is running"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "p.visit(function () { try { return new Point(1, 2).equals(p) } catch (e) { return e.message } })"
+        ),
+        "Point {} is already in use by a running method"
+    );
+    assert_eq!(forward(&mut engine, "p.x"), "1");
+}
+
+#[test]
+fn copies_share_the_instance() {
+    let mut engine = engine();
+    let point = forward_val(&mut engine, "var p = new Point(1, 2); p").unwrap();
+    let copy = Object::from(&point).unwrap();
+    forward(&mut engine, "p.translate(1, 1)");
+    assert_eq!(forward(&mut engine, "p.x + ',' + p.y"), "2,3");
+    let copy = to_value(copy);
+    let x = with_instance(&copy, &mut engine, |copy: &mut Point, _| Ok(copy.x));
+    assert_eq!(x.expect("an instance"), 2.0);
+}
+
+#[test]
+fn instances_from_rust() {
+    let mut engine = engine();
+    forward(&mut engine, "var o = Point.origin();");
+    gc::force_collect();
+    assert_eq!(
+        forward(&mut engine, "Point.prototype.isPrototypeOf(o)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "o.x + ',' + o.y"), "0,0");
+
+    let mut unregistered = Executor::new(Realm::create());
+    let error = new_instance(Point { x: 0.0, y: 0.0 }, &mut unregistered)
+        .expect_err("the class isn't registered");
+    assert_eq!(
+        error.get_field_slice("message").to_string(),
+        "Class Point is not registered"
+    );
+}
+
+/// A class keeping a value of the script, like a callback.
+#[derive(Debug, Trace, Finalize)]
+struct Holder {
+    value: Value,
+}
+
+impl Holder {
+    fn value(&mut self, _: &[Value], _: &mut Interpreter) -> ResultValue {
+        Ok(self.value.clone())
+    }
+}
+
+impl Class for Holder {
+    const NAME: &'static str = "Holder";
+    const LENGTH: usize = 1;
+
+    fn constructor(args: &[Value], _: &mut Interpreter) -> Result<Self, Value> {
+        let value = args.first().cloned().unwrap_or_else(undefined);
+        Ok(Holder { value })
+    }
+
+    fn init(class: &mut ClassBuilder<'_, Self>) {
+        class.method("value", 0, Holder::value);
+    }
+}
+
+#[test]
+fn instances_keep_their_values() {
+    let mut engine = Executor::new(Realm::create().register_global_class::<Holder>());
+    forward(
+        &mut engine,
+        "var h = new Holder({ answer: 42 }); var w = new WeakRef(h.value());",
+    );
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "h.value().answer"), "42");
+    // Collecting the instance releases its value, instead of leaving it rooted.
+    forward(&mut engine, "h = undefined;");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "w.deref()"), "undefined");
+}
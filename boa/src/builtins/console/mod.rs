@@ -11,16 +11,22 @@
 //! [spec]: https://console.spec.whatwg.org/
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Console
 
-#![allow(clippy::print_stdout)]
-
 #[cfg(test)]
 mod tests;
 
+pub mod sink;
+
+use self::sink::LogLevel;
 use crate::{
     builtins::{
-        object::InternalState,
+        array::is_array_value,
+        json,
+        map::iterator::IterationKind,
+        object::{enumerable_own_property_names, InternalState},
         value::{
-            display_obj, from_value, to_value, undefined, FromValue, ResultValue, Value, ValueData,
+            from_value,
+            inspect::{inspect, InspectOptions},
+            to_value, undefined, FromValue, ResultValue, Value, ValueData,
         },
     },
     exec::Interpreter,
@@ -38,42 +44,76 @@ pub struct ConsoleState {
 
 impl InternalState for ConsoleState {}
 
-/// This represents the different types of log messages.
-#[derive(Debug)]
-pub enum LogMessage {
-    Log(String),
-    Info(String),
-    Warn(String),
-    Error(String),
-}
-
 /// Helper function that returns the argument at a specified index.
 fn get_arg_at_index<T: FromValue + Default>(args: &[Value], index: usize) -> Option<T> {
     args.get(index)
         .cloned()
-        .map(|s| from_value::<T>(s).expect("Convert error"))
+        .and_then(|s| from_value::<T>(s).ok())
+}
+
+/// Helper function that returns the object holding the console state.
+///
+/// The console methods don't depend on their receiver, so a detached method
+/// (`const log = console.log; log()`) uses the global `console` object.
+fn console_object(this: &Value, ctx: &mut Interpreter) -> Result<Value, Value> {
+    if this.has_internal_state_of::<ConsoleState>() {
+        return Ok(this.clone());
+    }
+
+    let console = ctx.realm.global_obj.get_field_slice("console");
+    if console.has_internal_state_of::<ConsoleState>() {
+        Ok(console)
+    } else {
+        Err(ctx.construct_type_error("Illegal invocation of a console method"))
+    }
 }
 
-/// Helper function for logging messages.
-pub fn logger(msg: LogMessage, console_state: &ConsoleState) {
+/// Helper function for logging messages, which writes them to the console sink of the interpreter
+/// with the indentation of the current groups.
+pub fn logger(level: LogLevel, message: &str, console_state: &ConsoleState, ctx: &mut Interpreter) {
     let indent = 2 * console_state.groups.len();
+    ctx.console_sink().write(level, indent, message);
+}
 
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{:>width$}", msg, width = indent);
-        }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{:>width$}", msg, width = indent);
-        }
+/// Helper function that formats `args` and logs them with the console object of `this`.
+fn log_formatted(
+    this: &Value,
+    level: LogLevel,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    let message = formatter(args, ctx)?;
+    console_object(this, ctx)?.with_internal_state_ref(|state| logger(level, &message, state, ctx));
+    Ok(())
+}
+
+/// Helper function that renders an argument which isn't consumed by a format specifier, strings
+/// as they are and other values with `inspect`.
+fn display_arg(value: &Value) -> String {
+    match **value {
+        ValueData::String(ref string) => string.to_string(),
+        _ => inspect(value, &InspectOptions::default()),
     }
 }
 
 /// This represents the `console` formatter.
-pub fn formatter(data: &[Value]) -> String {
+///
+/// The format specifiers are only applied when the first argument is a string.
+///
+/// More information:
+///  - [WHATWG `console` specification][spec]
+///
+/// [spec]: https://console.spec.whatwg.org/#formatter
+pub fn formatter(data: &[Value], ctx: &mut Interpreter) -> Result<String, Value> {
+    if data.first().map_or(false, |target| !target.is_string()) {
+        let args: Vec<String> = data.iter().map(display_arg).collect();
+        return Ok(args.join(" "));
+    }
+
     let target = get_arg_at_index::<String>(data, 0).unwrap_or_default();
     match data.len() {
-        0 => String::new(),
-        1 => target,
+        0 => Ok(String::new()),
+        1 => Ok(target),
         _ => {
             let mut formatted = String::new();
             let mut arg_index = 1;
@@ -94,10 +134,28 @@ pub fn formatter(data: &[Value]) -> String {
                             formatted.push_str(&format!("{number:.prec$}", number = arg, prec = 6));
                             arg_index += 1
                         }
-                        /* object, FIXME: how to render this properly? */
-                        'o' | 'O' => {
-                            let arg = data.get(arg_index).cloned().unwrap_or_default();
-                            formatted.push_str(&format!("{}", arg));
+                        /* object, with its hidden properties */
+                        'o' => {
+                            let arg = data.get(arg_index).cloned().unwrap_or_else(undefined);
+                            let options = InspectOptions {
+                                depth: Some(4),
+                                show_hidden: true,
+                                ..InspectOptions::default()
+                            };
+                            formatted.push_str(&inspect(&arg, &options));
+                            arg_index += 1
+                        }
+                        /* object, with its enumerable properties */
+                        'O' => {
+                            let arg = data.get(arg_index).cloned().unwrap_or_else(undefined);
+                            formatted.push_str(&inspect(&arg, &InspectOptions::default()));
+                            arg_index += 1
+                        }
+                        /* JSON */
+                        'j' => {
+                            let arg = data.get(arg_index).cloned().unwrap_or_else(undefined);
+                            let json = json::stringify(&mut undefined(), &[arg], ctx)?;
+                            formatted.push_str(&json.to_string());
                             arg_index += 1
                         }
                         /* string */
@@ -107,8 +165,9 @@ pub fn formatter(data: &[Value]) -> String {
                             formatted.push_str(&arg);
                             arg_index += 1
                         }
+                        /* CSS, which can't be applied to text, so the argument is dropped */
+                        'c' => arg_index += 1,
                         '%' => formatted.push('%'),
-                        /* TODO: %c is not implemented */
                         c => {
                             formatted.push('%');
                             formatted.push(c);
@@ -121,10 +180,11 @@ pub fn formatter(data: &[Value]) -> String {
 
             /* unformatted data */
             for rest in data.iter().skip(arg_index) {
-                formatted.push_str(&format!(" {}", rest))
+                formatted.push(' ');
+                formatted.push_str(&display_arg(rest));
             }
 
-            formatted
+            Ok(formatted)
         }
     }
 }
@@ -140,7 +200,7 @@ pub fn formatter(data: &[Value]) -> String {
 ///
 /// [spec]: https://console.spec.whatwg.org/#assert
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/assert
-pub fn assert(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn assert(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let assertion = get_arg_at_index::<bool>(args, 0).unwrap_or_default();
 
     if !assertion {
@@ -155,9 +215,7 @@ pub fn assert(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVa
             args[0] = to_value::<String>(concat);
         }
 
-        this.with_internal_state_ref(|state| {
-            logger(LogMessage::Error(formatter(&args[..])), state)
-        });
+        log_formatted(this, LogLevel::Error, &args, ctx)?;
     }
 
     Ok(undefined())
@@ -173,8 +231,8 @@ pub fn assert(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVa
 ///
 /// [spec]: https://console.spec.whatwg.org/#clear
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/clear
-pub fn clear(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+pub fn clear(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         state.groups.clear();
     });
 
@@ -191,8 +249,8 @@ pub fn clear(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://console.spec.whatwg.org/#debug
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/debug
-pub fn debug(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_ref(|state| logger(LogMessage::Log(formatter(&args[..])), state));
+pub fn debug(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    log_formatted(this, LogLevel::Debug, args, ctx)?;
     Ok(undefined())
 }
 
@@ -206,8 +264,8 @@ pub fn debug(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVal
 ///
 /// [spec]: https://console.spec.whatwg.org/#error
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/error
-pub fn error(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_ref(|state| logger(LogMessage::Error(formatter(&args[..])), state));
+pub fn error(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    log_formatted(this, LogLevel::Error, args, ctx)?;
     Ok(undefined())
 }
 
@@ -221,8 +279,8 @@ pub fn error(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVal
 ///
 /// [spec]: https://console.spec.whatwg.org/#info
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/info
-pub fn info(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_ref(|state| logger(LogMessage::Info(formatter(&args[..])), state));
+pub fn info(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    log_formatted(this, LogLevel::Info, args, ctx)?;
     Ok(undefined())
 }
 
@@ -236,8 +294,8 @@ pub fn info(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValu
 ///
 /// [spec]: https://console.spec.whatwg.org/#log
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/log
-pub fn log(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_ref(|state| logger(LogMessage::Log(formatter(&args[..])), state));
+pub fn log(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    log_formatted(this, LogLevel::Log, args, ctx)?;
     Ok(undefined())
 }
 
@@ -251,16 +309,13 @@ pub fn log(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://console.spec.whatwg.org/#trace
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/trace
-pub fn trace(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn trace(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     if !args.is_empty() {
-        this.with_internal_state_ref(|state| logger(LogMessage::Log(formatter(&args[..])), state));
+        log_formatted(this, LogLevel::Log, args, ctx)?;
 
         /* TODO: get and print stack trace */
-        this.with_internal_state_ref(|state| {
-            logger(
-                LogMessage::Log("Not implemented: <stack trace>".to_string()),
-                state,
-            )
+        console_object(this, ctx)?.with_internal_state_ref(|state| {
+            logger(LogLevel::Log, "Not implemented: <stack trace>", state, ctx)
         });
     }
 
@@ -277,8 +332,8 @@ pub fn trace(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVal
 ///
 /// [spec]: https://console.spec.whatwg.org/#warn
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/warn
-pub fn warn(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_ref(|state| logger(LogMessage::Warn(formatter(&args[..])), state));
+pub fn warn(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    log_formatted(this, LogLevel::Warn, args, ctx)?;
     Ok(undefined())
 }
 
@@ -292,15 +347,15 @@ pub fn warn(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValu
 ///
 /// [spec]: https://console.spec.whatwg.org/#count
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/count
-pub fn count(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn count(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let label = get_arg_at_index::<String>(args, 0).unwrap_or_else(|| "default".to_string());
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         let msg = format!("count {}:", &label);
         let c = state.count_map.entry(label).or_insert(0);
         *c += 1;
 
-        logger(LogMessage::Info(format!("{} {}", msg, c)), state);
+        logger(LogLevel::Info, &format!("{} {}", msg, c), state, ctx);
     });
 
     Ok(undefined())
@@ -316,13 +371,13 @@ pub fn count(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVal
 ///
 /// [spec]: https://console.spec.whatwg.org/#countreset
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/countReset
-pub fn count_reset(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn count_reset(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let label = get_arg_at_index::<String>(args, 0).unwrap_or_else(|| "default".to_string());
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         state.count_map.remove(&label);
 
-        logger(LogMessage::Warn(format!("countReset {}", label)), state);
+        logger(LogLevel::Warn, &format!("countReset {}", label), state, ctx);
     });
 
     Ok(undefined())
@@ -346,14 +401,16 @@ fn system_time_in_ms() -> u128 {
 ///
 /// [spec]: https://console.spec.whatwg.org/#time
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/time
-pub fn time(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn time(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let label = get_arg_at_index::<String>(args, 0).unwrap_or_else(|| "default".to_string());
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         if state.timer_map.get(&label).is_some() {
             logger(
-                LogMessage::Warn(format!("Timer '{}' already exist", label)),
+                LogLevel::Warn,
+                &format!("Timer '{}' already exist", label),
                 state,
+                ctx,
             );
         } else {
             let time = system_time_in_ms();
@@ -374,21 +431,23 @@ pub fn time(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValu
 ///
 /// [spec]: https://console.spec.whatwg.org/#timelog
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/timeLog
-pub fn time_log(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn time_log(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let label = get_arg_at_index::<String>(args, 0).unwrap_or_else(|| "default".to_string());
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         if let Some(t) = state.timer_map.get(&label) {
             let time = system_time_in_ms();
             let mut concat = format!("{}: {} ms", label, time - t);
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.to_string();
             }
-            logger(LogMessage::Log(concat), state);
+            logger(LogLevel::Log, &concat, state, ctx);
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
+                LogLevel::Warn,
+                &format!("Timer '{}' doesn't exist", label),
                 state,
+                ctx,
             );
         }
     });
@@ -406,20 +465,24 @@ pub fn time_log(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Result
 ///
 /// [spec]: https://console.spec.whatwg.org/#timeend
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/timeEnd
-pub fn time_end(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn time_end(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let label = get_arg_at_index::<String>(args, 0).unwrap_or_else(|| "default".to_string());
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         if let Some(t) = state.timer_map.remove(&label) {
             let time = system_time_in_ms();
             logger(
-                LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
+                LogLevel::Info,
+                &format!("{}: {} ms - timer removed", label, time - t),
                 state,
+                ctx,
             );
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
+                LogLevel::Warn,
+                &format!("Timer '{}' doesn't exist", label),
                 state,
+                ctx,
             );
         }
     });
@@ -437,11 +500,16 @@ pub fn time_end(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Result
 ///
 /// [spec]: https://console.spec.whatwg.org/#group
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/group
-pub fn group(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let group_label = formatter(args);
+pub fn group(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let group_label = formatter(args, ctx)?;
 
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
-        logger(LogMessage::Info(format!("group: {}", &group_label)), state);
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
+        logger(
+            LogLevel::Info,
+            &format!("group: {}", &group_label),
+            state,
+            ctx,
+        );
         state.groups.push(group_label);
     });
 
@@ -458,17 +526,60 @@ pub fn group(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultVal
 ///
 /// [spec]: https://console.spec.whatwg.org/#groupend
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/groupEnd
-pub fn group_end(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
+pub fn group_end(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    console_object(this, ctx)?.with_internal_state_mut(|state: &mut ConsoleState| {
         state.groups.pop();
     });
 
     Ok(undefined())
 }
 
+/// Helper function that reads the options of `console.dir`, like `{ depth: 0, colors: true }`.
+fn inspect_options(options: &Value, ctx: &mut Interpreter) -> Result<InspectOptions, Value> {
+    let mut inspect_options = InspectOptions::default();
+    if !options.is_object() {
+        return Ok(inspect_options);
+    }
+
+    let depth = ctx.get_property(options, &to_value("depth"))?;
+    if depth.is_null() {
+        inspect_options.depth = None;
+    } else if !depth.is_undefined() {
+        let depth = ctx.to_number(&depth)?;
+        inspect_options.depth = if depth.is_finite() {
+            Some(depth.max(0.0) as usize)
+        } else {
+            None
+        };
+    }
+    let max_array_length = ctx.get_property(options, &to_value("maxArrayLength"))?;
+    if max_array_length.is_null() {
+        inspect_options.max_array_length = None;
+    } else if !max_array_length.is_undefined() {
+        let max_array_length = ctx.to_number(&max_array_length)?;
+        inspect_options.max_array_length = if max_array_length.is_finite() {
+            Some(max_array_length.max(0.0) as usize)
+        } else {
+            None
+        };
+    }
+    for (key, option) in &mut [
+        ("colors", &mut inspect_options.colors),
+        ("sorted", &mut inspect_options.sorted),
+        ("showHidden", &mut inspect_options.show_hidden),
+    ] {
+        let value = ctx.get_property(options, &to_value(*key))?;
+        if !value.is_undefined() {
+            **option = value.is_true();
+        }
+    }
+    Ok(inspect_options)
+}
+
 /// `console.dir(item, options)`
 ///
-/// Prints info about item
+/// Prints info about item, with the `inspect` options `depth`, `colors`, `maxArrayLength`,
+/// `sorted` and `showHidden`.
 ///
 /// More information:
 ///  - [MDN documentation][mdn]
@@ -476,14 +587,142 @@ pub fn group_end(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultVa
 ///
 /// [spec]: https://console.spec.whatwg.org/#dir
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/dir
-pub fn dir(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.with_internal_state_mut(|state: &mut ConsoleState| {
-        logger(
-            LogMessage::Info(display_obj(args.get(0).unwrap_or(&undefined()), true)),
-            state,
+pub fn dir(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let item = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = inspect_options(&args.get(1).cloned().unwrap_or_else(undefined), ctx)?;
+    let message = inspect(&item, &options);
+    console_object(this, ctx)?
+        .with_internal_state_ref(|state| logger(LogLevel::Info, &message, state, ctx));
+
+    Ok(undefined())
+}
+
+/// Helper function that renders a value in a cell of `console.table`.
+fn table_cell(value: &Value, ctx: &mut Interpreter) -> Result<String, Value> {
+    Ok(match **value {
+        ValueData::String(ref string) => format!("'{}'", string),
+        ValueData::Object(_) if value.is_function() => String::from("[Function]"),
+        ValueData::Object(_) if is_array_value(value, ctx)? => String::from("[Array]"),
+        ValueData::Object(_) => String::from("[Object]"),
+        _ => value.to_string(),
+    })
+}
+
+/// Helper function that draws the rows of `console.table`, the first one being the header.
+fn render_table(rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+                + 2
+        })
+        .collect();
+    let border = |left: &str, middle: &str, right: &str| {
+        let lines: Vec<String> = widths.iter().map(|width| "─".repeat(*width)).collect();
+        format!("{}{}{}", left, lines.join(middle), right)
+    };
+    let line = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let space = width - cell.chars().count();
+                format!(
+                    "{}{}{}",
+                    " ".repeat(space / 2),
+                    cell,
+                    " ".repeat(space - space / 2)
+                )
+            })
+            .collect();
+        format!("│{}│", cells.join("│"))
+    };
+
+    let mut lines = vec![border("┌", "┬", "┐"), line(&rows[0]), border("├", "┼", "┤")];
+    lines.extend(rows[1..].iter().map(|row| line(row)));
+    lines.push(border("└", "┴", "┘"));
+    lines.join("\n")
+}
+
+/// `console.table(tabularData, properties)`
+///
+/// Prints the rows of tabular data as a table, with a column for each of their properties, or only
+/// for the given properties.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [WHATWG `console` specification][spec]
+///
+/// [spec]: https://console.spec.whatwg.org/#table
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/table
+pub fn table(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = args.get(0).cloned().unwrap_or_else(undefined);
+    if !data.is_object() {
+        log_formatted(this, LogLevel::Log, args, ctx)?;
+        return Ok(undefined());
+    }
+
+    let properties = match args.get(1) {
+        Some(properties) if properties.is_object() => {
+            let properties = ctx.create_list_from_array_like(properties)?;
+            Some(
+                properties
+                    .iter()
+                    .map(|property| ctx.value_to_rust_string(property))
+                    .collect::<Vec<_>>(),
+            )
+        }
+        _ => None,
+    };
+    let mut columns = properties.clone().unwrap_or_default();
+    let mut has_values = false;
+    let mut rows = Vec::new();
+    for index in enumerable_own_property_names(&data, IterationKind::Key, ctx)? {
+        let row = ctx.get_property(&data, &index)?;
+        let mut cells = FxHashMap::default();
+        let mut value = None;
+        if row.is_object() && !row.is_function() {
+            for key in enumerable_own_property_names(&row, IterationKind::Key, ctx)? {
+                let column = ctx.value_to_rust_string(&key);
+                match properties {
+                    Some(ref properties) if !properties.contains(&column) => continue,
+                    None if !columns.contains(&column) => columns.push(column.clone()),
+                    _ => {}
+                }
+                let cell = ctx.get_property(&row, &key)?;
+                cells.insert(column, table_cell(&cell, ctx)?);
+            }
+        } else {
+            has_values = true;
+            value = Some(table_cell(&row, ctx)?);
+        }
+        rows.push((ctx.value_to_rust_string(&index), cells, value));
+    }
+
+    let mut header = vec![String::from("(index)")];
+    header.extend(columns.iter().cloned());
+    if has_values {
+        header.push(String::from("Values"));
+    }
+    let mut lines = vec![header];
+    for (index, mut cells, value) in rows {
+        let mut line = vec![index];
+        line.extend(
+            columns
+                .iter()
+                .map(|column| cells.remove(column).unwrap_or_default()),
         );
-    });
+        if has_values {
+            line.push(value.unwrap_or_default());
+        }
+        lines.push(line);
+    }
 
+    let message = render_table(&lines);
+    console_object(this, ctx)?
+        .with_internal_state_ref(|state| logger(LogLevel::Log, &message, state, ctx));
     Ok(undefined())
 }
 
@@ -510,6 +749,7 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(time_end, named "timeEnd", of console);
     make_builtin_fn!(dir, named "dir", of console);
     make_builtin_fn!(dir, named "dirxml", of console);
+    make_builtin_fn!(table, named "table", of console);
 
     console.set_internal_state(ConsoleState::default());
 
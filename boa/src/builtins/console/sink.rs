@@ -0,0 +1,114 @@
+//! This module implements the sinks receiving the messages of the `console` object.
+//!
+//! The interpreter uses the `StdoutSink` by default. Hosts can replace it with
+//! `Interpreter::set_console_sink`, for example to capture the logs of scripts.
+
+#![allow(clippy::print_stdout)]
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+/// The log level of a `console` message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+/// A receiver of the messages of the `console` object.
+pub trait ConsoleSink: Debug {
+    /// Writes a formatted message, indented by `indent` spaces because of the open `console.group`s.
+    fn write(&mut self, level: LogLevel, indent: usize, message: &str);
+}
+
+/// Indents every line of `message` by `indent` spaces.
+///
+/// Sinks use it to show the open `console.group`s, like the default ones do.
+pub fn indent_lines(indent: usize, message: &str) -> String {
+    let padding = " ".repeat(indent);
+    message
+        .split('\n')
+        .map(|line| format!("{}{}", padding, line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes the messages to the standard output, and the errors to the standard error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutSink;
+
+impl ConsoleSink for StdoutSink {
+    fn write(&mut self, level: LogLevel, indent: usize, message: &str) {
+        let message = indent_lines(indent, message);
+        match level {
+            LogLevel::Error => eprintln!("{}", message),
+            LogLevel::Debug | LogLevel::Log | LogLevel::Info | LogLevel::Warn => {
+                println!("{}", message)
+            }
+        }
+    }
+}
+
+/// A message written to a `BufferSink`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleEntry {
+    pub level: LogLevel,
+    pub indent: usize,
+    pub message: String,
+}
+
+/// Keeps the messages in memory.
+///
+/// The clones of a `BufferSink` share their messages, so the host can keep one to read the
+/// messages written by the interpreter.
+#[derive(Debug, Clone, Default)]
+pub struct BufferSink {
+    entries: Rc<RefCell<Vec<ConsoleEntry>>>,
+}
+
+impl BufferSink {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the messages written so far.
+    pub fn entries(&self) -> Vec<ConsoleEntry> {
+        self.entries.borrow().clone()
+    }
+
+    /// Removes and returns the messages written so far.
+    pub fn take(&self) -> Vec<ConsoleEntry> {
+        self.entries.replace(Vec::new())
+    }
+
+    /// Gets the messages written so far as text, one per line, like the `StdoutSink` prints them.
+    pub fn contents(&self) -> String {
+        self.entries
+            .borrow()
+            .iter()
+            .map(|entry| indent_lines(entry.indent, &entry.message))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl ConsoleSink for BufferSink {
+    fn write(&mut self, level: LogLevel, indent: usize, message: &str) {
+        self.entries.borrow_mut().push(ConsoleEntry {
+            level,
+            indent,
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Discards the messages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullSink;
+
+impl ConsoleSink for NullSink {
+    fn write(&mut self, _: LogLevel, _: usize, _: &str) {}
+}
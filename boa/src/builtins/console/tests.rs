@@ -1,33 +1,54 @@
-use crate::builtins::{console::formatter, value::ValueData};
+use super::{
+    formatter,
+    sink::{BufferSink, ConsoleEntry, LogLevel},
+};
+use crate::{
+    builtins::value::ValueData,
+    exec::{Executor, Interpreter},
+    forward,
+    realm::Realm,
+};
 use gc::Gc;
 
+fn engine_with(sink: &BufferSink) -> Interpreter {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    engine.set_console_sink(sink.clone());
+    engine
+}
+
+fn format(data: &[crate::builtins::value::Value]) -> String {
+    let mut engine = engine_with(&BufferSink::new());
+    formatter(data, &mut engine).expect("formatting failed")
+}
+
 #[test]
 fn formatter_no_args_is_empty_string() {
-    assert_eq!(formatter(&[]), "")
+    assert_eq!(format(&[]), "")
 }
 
 #[test]
 fn formatter_empty_format_string_is_empty_string() {
-    let val = Gc::new(ValueData::String("".to_string()));
-    let res = formatter(&[val]);
+    let val = Gc::new(ValueData::String("".into()));
+    let res = format(&[val]);
     assert_eq!(res, "");
 }
 
 #[test]
 fn formatter_format_without_args_renders_verbatim() {
-    let val = [Gc::new(ValueData::String("%d %s %% %f".to_string()))];
-    let res = formatter(&val);
+    let val = [Gc::new(ValueData::String("%d %s %% %f".into()))];
+    let res = format(&val);
     assert_eq!(res, "%d %s %% %f");
 }
 
 #[test]
 fn formatter_empty_format_string_concatenates_rest_of_args() {
     let val = [
-        Gc::new(ValueData::String("".to_string())),
-        Gc::new(ValueData::String("to powinno zostać".to_string())),
-        Gc::new(ValueData::String("połączone".to_string())),
+        Gc::new(ValueData::String("".into())),
+        Gc::new(ValueData::String("to powinno zostać".into())),
+        Gc::new(ValueData::String("połączone".into())),
     ];
-    let res = formatter(&val);
+    let res = format(&val);
     assert_eq!(res, " to powinno zostać połączone");
 }
 
@@ -35,23 +56,23 @@ fn formatter_empty_format_string_concatenates_rest_of_args() {
 fn formatter_utf_8_checks() {
     let val = [
         Gc::new(ValueData::String(
-            "Są takie chwile %dą %są tu%sów %привет%ź".to_string(),
+            "Są takie chwile %dą %są tu%sów %привет%ź".into(),
         )),
         Gc::new(ValueData::Integer(123)),
         Gc::new(ValueData::Rational(1.23)),
-        Gc::new(ValueData::String("ł".to_string())),
+        Gc::new(ValueData::String("ł".into())),
     ];
-    let res = formatter(&val);
+    let res = format(&val);
     assert_eq!(res, "Są takie chwile 123ą 1.23ą tułów %привет%ź");
 }
 
 #[test]
 fn formatter_trailing_format_leader_renders() {
     let val = [
-        Gc::new(ValueData::String("%%%%%".to_string())),
-        Gc::new(ValueData::String("|".to_string())),
+        Gc::new(ValueData::String("%%%%%".into())),
+        Gc::new(ValueData::String("|".into())),
     ];
-    let res = formatter(&val);
+    let res = format(&val);
     assert_eq!(res, "%%% |")
 }
 
@@ -59,9 +80,145 @@ fn formatter_trailing_format_leader_renders() {
 #[allow(clippy::approx_constant)]
 fn formatter_float_format_works() {
     let val = [
-        Gc::new(ValueData::String("%f".to_string())),
+        Gc::new(ValueData::String("%f".into())),
         Gc::new(ValueData::Rational(3.1415)),
     ];
-    let res = formatter(&val);
+    let res = format(&val);
     assert_eq!(res, "3.141500")
 }
+
+#[test]
+fn formatter_object_and_css_formats() {
+    let sink = BufferSink::new();
+    let mut engine = engine_with(&sink);
+    forward(
+        &mut engine,
+        r#"
+        console.log("%j|%c%s", { a: [1, "b"] }, "color: red", "styled");
+        console.log("%O", { a: 1 });
+        "#,
+    );
+    let entries = sink.take();
+    assert_eq!(entries[0].message, r#"{"a":[1,"b"]}|styled"#);
+    assert_eq!(entries[1].message, "{ a: 1 }");
+}
+
+#[test]
+fn log_and_dir_inspect_values() {
+    let sink = BufferSink::new();
+    let mut engine = engine_with(&sink);
+    forward(
+        &mut engine,
+        r#"
+        var deep = { a: { b: { c: { d: {} } } } };
+        var holey = [1];
+        holey[2] = 3;
+        console.log({ s: "x" }, "raw", holey);
+        console.log("%s:", "label", ["y"]);
+        console.log("%o", [1]);
+        console.dir(deep);
+        console.dir(deep, { depth: 0 });
+        console.dir(deep, { depth: null });
+        "#,
+    );
+    let messages: Vec<String> = sink.take().into_iter().map(|e| e.message).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "{ s: 'x' } raw [ 1, <1 empty item>, 3 ]",
+            "label: [ 'y' ]",
+            "[ 1, [length]: 1 ]",
+            "{ a: { b: { c: [Object] } } }",
+            "{ a: [Object] }",
+            "{ a: { b: { c: { d: {} } } } }",
+        ]
+    );
+}
+
+#[test]
+fn formatter_json_throws_on_cycles() {
+    let sink = BufferSink::new();
+    let mut engine = engine_with(&sink);
+    let cycle = r#"
+        var a = {};
+        a.self = a;
+        try { console.log("%j", a) } catch (e) { e.name }
+        "#;
+    assert_eq!(forward(&mut engine, cycle), "TypeError");
+    assert!(sink.entries().is_empty());
+}
+
+#[test]
+fn sink_receives_levels_and_group_indentation() {
+    let sink = BufferSink::new();
+    let mut engine = engine_with(&sink);
+    forward(
+        &mut engine,
+        r#"
+        console.log("a %d", 1);
+        console.group("g");
+        console.warn("b");
+        console.groupEnd();
+        console.error("c");
+        console.debug("d");
+        console.assert(false, "e");
+        "#,
+    );
+    let entry = |level, indent, message: &str| ConsoleEntry {
+        level,
+        indent,
+        message: message.to_string(),
+    };
+    assert_eq!(
+        sink.entries(),
+        vec![
+            entry(LogLevel::Log, 0, "a 1"),
+            entry(LogLevel::Info, 0, "group: g"),
+            entry(LogLevel::Warn, 2, "b"),
+            entry(LogLevel::Error, 0, "c"),
+            entry(LogLevel::Debug, 0, "d"),
+            entry(LogLevel::Error, 0, "Assertion failed: e"),
+        ]
+    );
+    assert_eq!(
+        sink.contents(),
+        "a 1\ngroup: g\n  b\nc\nd\nAssertion failed: e"
+    );
+}
+
+#[test]
+fn table() {
+    let sink = BufferSink::new();
+    let mut engine = engine_with(&sink);
+    forward(
+        &mut engine,
+        r#"
+        console.table([{ a: 1 }, { a: "Z", b: "Y" }, { c: [1] }, 3]);
+        console.table({ x: { a: 1, b: 2 } }, ["b"]);
+        console.table("not tabular");
+        "#,
+    );
+    let messages: Vec<String> = sink.take().into_iter().map(|e| e.message).collect();
+    assert_eq!(
+        messages[0],
+        "\
+┌─────────┬─────┬─────┬─────────┬────────┐
+│ (index) │  a  │  b  │    c    │ Values │
+├─────────┼─────┼─────┼─────────┼────────┤
+│    0    │  1  │     │         │        │
+│    1    │ 'Z' │ 'Y' │         │        │
+│    2    │     │     │ [Array] │        │
+│    3    │     │     │         │   3    │
+└─────────┴─────┴─────┴─────────┴────────┘"
+    );
+    assert_eq!(
+        messages[1],
+        "\
+┌─────────┬───┐
+│ (index) │ b │
+├─────────┼───┤
+│    x    │ 2 │
+└─────────┴───┘"
+    );
+    assert_eq!(messages[2], "not tabular");
+}
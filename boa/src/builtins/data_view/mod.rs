@@ -0,0 +1,514 @@
+//! This module implements the global `DataView` object.
+//!
+//! The `DataView` object is a low-level interface for reading and writing numbers of
+//! different types in an `ArrayBuffer`, in either byte order.
+//! Multi-byte values are big-endian unless the `littleEndian` argument is `true`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::{self, with_array_buffer_bytes},
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        typed_array::TypedArrayKind,
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// The internal slots of a `DataView`.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct DataViewData {
+    /// The `ArrayBuffer` viewed.
+    pub buffer: Value,
+    /// The offset of the view in the buffer, in bytes.
+    pub byte_offset: usize,
+    /// The length of the view, or `None` when it tracks the length of a resizable buffer.
+    pub byte_length: Option<usize>,
+}
+
+impl DataViewData {
+    /// The current length of the view, or `None` when it's out of the bounds of its buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    fn view_byte_length(&self) -> Option<usize> {
+        let buffer_length = array_buffer::byte_length_of(&self.buffer);
+        match self.byte_length {
+            _ if self.byte_offset > buffer_length => None,
+            None => Some(buffer_length - self.byte_offset),
+            Some(length) if self.byte_offset + length > buffer_length => None,
+            Some(length) => Some(length),
+        }
+    }
+}
+
+/// Gets the internal slots of a `DataView`, or throws a `TypeError` if `this` is not one.
+fn this_data_view(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<DataViewData, Value> {
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::DataView(ref data) = obj.borrow().kind {
+            return Ok(data.clone());
+        }
+    }
+
+    Err(ctx.construct_type_error(format!(
+        "Method DataView.prototype.{} called on incompatible receiver {}",
+        method, this
+    )))
+}
+
+/// Gets the byte offset of a `kind` value in the buffer, checking that it's in the bounds of the view.
+fn view_index(
+    data: &DataViewData,
+    request_index: &Value,
+    kind: TypedArrayKind,
+    ctx: &mut Interpreter,
+) -> Result<usize, Value> {
+    let index = ctx.to_index(request_index)?;
+    match data.view_byte_length() {
+        Some(length) if index.saturating_add(kind.element_size()) <= length => {
+            Ok(data.byte_offset + index)
+        }
+        Some(_) => Err(ctx.construct_range_error("Offset is outside the bounds of the DataView")),
+        None => Err(ctx.construct_type_error("DataView is out of the bounds of its buffer")),
+    }
+}
+
+/// `GetViewValue`, reads a `kind` value at the byte offset of the first argument.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+fn get_view_value(
+    this: &Value,
+    args: &[Value],
+    kind: TypedArrayKind,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let data = this_data_view(this, method, ctx)?;
+    let request_index = args.get(0).cloned().unwrap_or_else(undefined);
+    let little_endian = args.get(1).map_or(false, |value| value.is_true());
+    let index = view_index(&data, &request_index, kind, ctx)?;
+
+    let value = with_array_buffer_bytes(&data.buffer, |bytes| {
+        kind.read(&bytes[index..], little_endian)
+    })
+    .unwrap_or(0.0);
+    Ok(to_value(value))
+}
+
+/// `SetViewValue`, writes the second argument as a `kind` value at the byte offset of the first.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+fn set_view_value(
+    this: &Value,
+    args: &[Value],
+    kind: TypedArrayKind,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let data = this_data_view(this, method, ctx)?;
+    let request_index = args.get(0).cloned().unwrap_or_else(undefined);
+    let value = match args.get(1) {
+        Some(value) if !value.is_undefined() => ctx.value_to_rust_number(value),
+        _ => f64::NAN,
+    };
+    let little_endian = args.get(2).map_or(false, |value| value.is_true());
+    let index = view_index(&data, &request_index, kind, ctx)?;
+
+    with_array_buffer_bytes(&data.buffer, |bytes| {
+        kind.write(&mut bytes[index..], value, little_endian)
+    });
+    Ok(undefined())
+}
+
+/// Create a new `DataView`.
+///
+/// The arguments are the `ArrayBuffer` to view, and the optional offset and length of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView
+pub fn make_data_view(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let buffer = args.get(0).cloned().unwrap_or_else(undefined);
+    let resizable = buffer.as_object().and_then(|obj| match obj.kind {
+        ObjectKind::ArrayBuffer(ref data) => Some(data.max_byte_length.is_some()),
+        _ => None,
+    });
+    let resizable = match resizable {
+        Some(resizable) => resizable,
+        None => {
+            return ctx
+                .throw_type_error("First argument to DataView constructor must be an ArrayBuffer")
+        }
+    };
+
+    let byte_offset = ctx.to_index(&args.get(1).cloned().unwrap_or_else(undefined))?;
+    let length = args.get(2).cloned().unwrap_or_else(undefined);
+    let view_length = if length.is_undefined() {
+        None
+    } else {
+        Some(ctx.to_index(&length)?)
+    };
+
+    // Reading the arguments may have resized the buffer
+    let buffer_length = array_buffer::byte_length_of(&buffer);
+    if byte_offset > buffer_length {
+        return ctx.throw_range_error(format!(
+            "Start offset {} is outside the bounds of the buffer",
+            byte_offset
+        ));
+    }
+    let byte_length = match view_length {
+        Some(length) if byte_offset.saturating_add(length) > buffer_length => {
+            return ctx.throw_range_error(format!("Invalid DataView length {}", length));
+        }
+        Some(length) => Some(length),
+        None if resizable => None,
+        None => Some(buffer_length - byte_offset),
+    };
+
+    this.set_kind(ObjectKind::DataView(DataViewData {
+        buffer,
+        byte_offset,
+        byte_length,
+    }));
+
+    Ok(this.clone())
+}
+
+/// `DataView` can only be called as a constructor.
+pub fn call_data_view(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor DataView requires 'new'")
+}
+
+/// `get DataView.prototype.buffer`
+///
+/// The `buffer` accessor property represents the `ArrayBuffer` viewed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer
+pub fn buffer(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(this_data_view(this, "buffer", ctx)?.buffer.clone())
+}
+
+/// `get DataView.prototype.byteLength`
+///
+/// The `byteLength` accessor property represents the length of the view in bytes.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength
+pub fn byte_length(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    match this_data_view(this, "byteLength", ctx)?.view_byte_length() {
+        Some(length) => Ok(to_value(length)),
+        None => ctx.throw_type_error("DataView is out of the bounds of its buffer"),
+    }
+}
+
+/// `get DataView.prototype.byteOffset`
+///
+/// The `byteOffset` accessor property represents the offset of the view in its buffer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset
+pub fn byte_offset(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_data_view(this, "byteOffset", ctx)?;
+    match data.view_byte_length() {
+        Some(_) => Ok(to_value(data.byte_offset)),
+        None => ctx.throw_type_error("DataView is out of the bounds of its buffer"),
+    }
+}
+
+/// `DataView.prototype.getInt8( byteOffset )`
+///
+/// The `getInt8()` method reads a signed 8-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint8
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt8
+pub fn get_int8(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Int8, "getInt8", ctx)
+}
+
+/// `DataView.prototype.setInt8( byteOffset, value )`
+///
+/// The `setInt8()` method writes a signed 8-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint8
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt8
+pub fn set_int8(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Int8, "setInt8", ctx)
+}
+
+/// `DataView.prototype.getUint8( byteOffset )`
+///
+/// The `getUint8()` method reads an unsigned 8-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint8
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint8
+pub fn get_uint8(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Uint8, "getUint8", ctx)
+}
+
+/// `DataView.prototype.setUint8( byteOffset, value )`
+///
+/// The `setUint8()` method writes an unsigned 8-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint8
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint8
+pub fn set_uint8(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Uint8, "setUint8", ctx)
+}
+
+/// `DataView.prototype.getInt16( byteOffset [, littleEndian ] )`
+///
+/// The `getInt16()` method reads a signed 16-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint16
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16
+pub fn get_int16(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Int16, "getInt16", ctx)
+}
+
+/// `DataView.prototype.setInt16( byteOffset, value [, littleEndian ] )`
+///
+/// The `setInt16()` method writes a signed 16-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint16
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16
+pub fn set_int16(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Int16, "setInt16", ctx)
+}
+
+/// `DataView.prototype.getUint16( byteOffset [, littleEndian ] )`
+///
+/// The `getUint16()` method reads an unsigned 16-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint16
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16
+pub fn get_uint16(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Uint16, "getUint16", ctx)
+}
+
+/// `DataView.prototype.setUint16( byteOffset, value [, littleEndian ] )`
+///
+/// The `setUint16()` method writes an unsigned 16-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint16
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16
+pub fn set_uint16(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Uint16, "setUint16", ctx)
+}
+
+/// `DataView.prototype.getInt32( byteOffset [, littleEndian ] )`
+///
+/// The `getInt32()` method reads a signed 32-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32
+pub fn get_int32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Int32, "getInt32", ctx)
+}
+
+/// `DataView.prototype.setInt32( byteOffset, value [, littleEndian ] )`
+///
+/// The `setInt32()` method writes a signed 32-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32
+pub fn set_int32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Int32, "setInt32", ctx)
+}
+
+/// `DataView.prototype.getUint32( byteOffset [, littleEndian ] )`
+///
+/// The `getUint32()` method reads an unsigned 32-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32
+pub fn get_uint32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Uint32, "getUint32", ctx)
+}
+
+/// `DataView.prototype.setUint32( byteOffset, value [, littleEndian ] )`
+///
+/// The `setUint32()` method writes an unsigned 32-bit integer at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32
+pub fn set_uint32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Uint32, "setUint32", ctx)
+}
+
+/// `DataView.prototype.getFloat32( byteOffset [, littleEndian ] )`
+///
+/// The `getFloat32()` method reads a 32-bit float at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32
+pub fn get_float32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Float32, "getFloat32", ctx)
+}
+
+/// `DataView.prototype.setFloat32( byteOffset, value [, littleEndian ] )`
+///
+/// The `setFloat32()` method writes a 32-bit float at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32
+pub fn set_float32(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Float32, "setFloat32", ctx)
+}
+
+/// `DataView.prototype.getFloat64( byteOffset [, littleEndian ] )`
+///
+/// The `getFloat64()` method reads a 64-bit float at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat64
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64
+pub fn get_float64(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_view_value(this, args, TypedArrayKind::Float64, "getFloat64", ctx)
+}
+
+/// `DataView.prototype.setFloat64( byteOffset, value [, littleEndian ] )`
+///
+/// The `setFloat64()` method writes a 64-bit float at the specified byte offset of the view.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat64
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64
+pub fn set_float64(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_view_value(this, args, TypedArrayKind::Float64, "setFloat64", ctx)
+}
+
+/// Create a new `DataView` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(buffer, named "buffer", of prototype);
+    make_getter_fn!(byte_length, named "byteLength", of prototype);
+    make_getter_fn!(byte_offset, named "byteOffset", of prototype);
+    make_builtin_fn!(get_int8, named "getInt8", with length 1, of prototype);
+    make_builtin_fn!(get_uint8, named "getUint8", with length 1, of prototype);
+    make_builtin_fn!(get_int16, named "getInt16", with length 1, of prototype);
+    make_builtin_fn!(get_uint16, named "getUint16", with length 1, of prototype);
+    make_builtin_fn!(get_int32, named "getInt32", with length 1, of prototype);
+    make_builtin_fn!(get_uint32, named "getUint32", with length 1, of prototype);
+    make_builtin_fn!(get_float32, named "getFloat32", with length 1, of prototype);
+    make_builtin_fn!(get_float64, named "getFloat64", with length 1, of prototype);
+    make_builtin_fn!(set_int8, named "setInt8", with length 2, of prototype);
+    make_builtin_fn!(set_uint8, named "setUint8", with length 2, of prototype);
+    make_builtin_fn!(set_int16, named "setInt16", with length 2, of prototype);
+    make_builtin_fn!(set_uint16, named "setUint16", with length 2, of prototype);
+    make_builtin_fn!(set_int32, named "setInt32", with length 2, of prototype);
+    make_builtin_fn!(set_uint32, named "setUint32", with length 2, of prototype);
+    make_builtin_fn!(set_float32, named "setFloat32", with length 2, of prototype);
+    make_builtin_fn!(set_float64, named "setFloat64", with length 2, of prototype);
+
+    let data_view = make_constructor_fn!(make_data_view, call_data_view, global, prototype);
+    data_view.set_field_slice("length", to_value(1_i32));
+    data_view
+}
+
+/// Initialise the `DataView` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("DataView", create(global));
+}
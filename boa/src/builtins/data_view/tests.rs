@@ -0,0 +1,111 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer, 2, 4);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "view.byteLength"), "4");
+    assert_eq!(
+        forward(&mut engine, "new DataView(buffer, 3).byteLength"),
+        "5"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new DataView([]) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView(buffer, 9) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView(buffer, 4, 5) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { DataView(buffer) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn endianness() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer);
+        var bytes = new Uint8Array(buffer);
+        view.setUint16(0, 0x0102);
+        view.setUint16(2, 0x0102, true);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "bytes.join()"), "1,2,2,1,0,0,0,0");
+    assert_eq!(forward(&mut engine, "view.getUint16(0)"), "258");
+    assert_eq!(forward(&mut engine, "view.getUint16(0, true)"), "513");
+    assert_eq!(forward(&mut engine, "view.getInt8(0)"), "1");
+
+    forward(&mut engine, "view.setInt32(4, -2, true)");
+    assert_eq!(forward(&mut engine, "view.getInt32(4, true)"), "-2");
+    assert_eq!(
+        forward(&mut engine, "view.getUint32(4, true)"),
+        "4294967294"
+    );
+    assert_eq!(forward(&mut engine, "view.getInt16(4)"), "-257");
+
+    forward(&mut engine, "view.setFloat64(0, 1.5)");
+    assert_eq!(forward(&mut engine, "view.getFloat64(0)"), "1.5");
+    assert_eq!(forward(&mut engine, "bytes[0]"), "63");
+    forward(&mut engine, "view.setFloat32(0, 0.25, true)");
+    assert_eq!(forward(&mut engine, "view.getFloat32(0, true)"), "0.25");
+}
+
+#[test]
+fn bounds() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(4), 1);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "view.getUint16(1)"), "0");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.getUint16(2) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.setFloat64(0, 1) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { view.getInt8(-1) } catch (e) { e.name }"),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { DataView.prototype.getInt8.call({}, 0) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
@@ -0,0 +1,38 @@
+//! This module implements the clock used by `Date` objects to get the current time.
+//!
+//! The interpreter uses the `SystemClock` by default. Hosts can replace it with
+//! `Interpreter::set_clock`, for example to make scripts reproducible.
+
+use std::{
+    fmt::Debug,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A source of the current time.
+pub trait Clock: Debug {
+    /// Returns the current time, as a number of milliseconds since `1970-01-01T00:00:00Z`.
+    fn now(&self) -> f64;
+}
+
+/// The clock of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_millis() as f64,
+            Err(error) => -(error.duration().as_millis() as f64),
+        }
+    }
+}
+
+/// A clock stopped at a fixed time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedClock(pub f64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> f64 {
+        self.0
+    }
+}
@@ -0,0 +1,1168 @@
+//! This module implements the global `Date` object.
+//!
+//! A `Date` object represents a single moment in time, stored as a number of milliseconds since
+//! `1970-01-01T00:00:00Z`. It has getters and setters both in UTC and in the local time zone.
+//!
+//! The current time comes from the `Clock` of the interpreter, and the local time zone is its
+//! `TimeZone`. Both can be replaced by the host, with `Interpreter::set_clock` and `Interpreter::set_time_zone`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-date-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date
+
+pub mod clock;
+mod parse;
+#[cfg(test)]
+mod tests;
+pub(crate) mod time;
+pub mod time_zone;
+
+use self::time::{make_day, make_time, time_clip, DateFields, MONTHS, MS_PER_MINUTE, WEEK_DAYS};
+#[cfg(feature = "intl")]
+use crate::builtins::intl::date_time_format::{self, DateTimeFields};
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Converts a value to a number, where `undefined` is `NaN`.
+fn to_number(value: &Value, ctx: &mut Interpreter) -> f64 {
+    if value.is_undefined() {
+        f64::NAN
+    } else {
+        ctx.value_to_rust_number(value)
+    }
+}
+
+/// Gets the time value of a `Date` object, or returns `None` if `value` is not one.
+pub(crate) fn time_value_of(value: &Value) -> Option<f64> {
+    match **value {
+        ValueData::Object(ref obj) => match obj.borrow().kind {
+            ObjectKind::Date(t) => Some(t),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Gets the time value of a `Date` object, or throws a `TypeError` if `this` is not one.
+fn this_time_value(this: &Value, ctx: &mut Interpreter) -> Result<f64, Value> {
+    match time_value_of(this) {
+        Some(t) => Ok(t),
+        None => ctx
+            .throw_type_error("this is not a Date object.")
+            .map(|_| 0.0),
+    }
+}
+
+/// Converts the UTC time `t` to the local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-localtime
+fn local_time(t: f64, ctx: &Interpreter) -> f64 {
+    t + ctx.time_zone().offset(t)
+}
+
+/// Converts the local time `t` to UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-utc-t
+fn utc(t: f64, ctx: &Interpreter) -> f64 {
+    if t.is_finite() {
+        t - ctx.time_zone().offset_from_local(t)
+    } else {
+        f64::NAN
+    }
+}
+
+/// Converts a year to a full year, as done by the `Date` constructor and `Date.UTC`:
+/// the years from `0` to `99` are in the twentieth century.
+fn full_year(year: f64) -> f64 {
+    if year.is_finite() && (0.0..=99.0).contains(&year.trunc()) {
+        1900.0 + year.trunc()
+    } else {
+        year
+    }
+}
+
+/// Computes a time value from the `year, month [, date [, hours [, minutes [, seconds [, ms ]]]]]`
+/// arguments of the `Date` constructor and `Date.UTC`.
+fn time_from_args(args: &[Value], ctx: &mut Interpreter) -> f64 {
+    let mut fields = [f64::NAN, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    for (i, field) in fields.iter_mut().enumerate() {
+        if let Some(arg) = args.get(i) {
+            *field = to_number(arg, ctx);
+        }
+    }
+
+    time::make_date(
+        make_day(full_year(fields[0]), fields[1], fields[2]),
+        make_time(fields[3], fields[4], fields[5], fields[6]),
+    )
+}
+
+/// Formats a year with at least four digits, as done by `toString` and `toUTCString`.
+fn format_year(year: f64) -> String {
+    if year < 0.0 {
+        format!("-{:04}", -year)
+    } else {
+        format!("{:04}", year)
+    }
+}
+
+/// Formats the date part of `toString`, like `Tue Feb 01 2022`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-datestring
+fn date_string(fields: DateFields) -> String {
+    format!(
+        "{} {} {:02} {}",
+        WEEK_DAYS[fields.week_day as usize],
+        MONTHS[fields.month as usize],
+        fields.date,
+        format_year(fields.year)
+    )
+}
+
+/// Formats the time part of `toString`, like `00:00:00 GMT+0100 (CET)`, for the UTC time `t`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-timestring
+fn time_string(t: f64, ctx: &Interpreter) -> String {
+    let offset = ctx.time_zone().offset(t);
+    let fields = DateFields::new(t + offset);
+    let offset_minutes = (offset / MS_PER_MINUTE).abs();
+
+    format!(
+        "{:02}:{:02}:{:02} GMT{}{:02}{:02} ({})",
+        fields.hours,
+        fields.minutes,
+        fields.seconds,
+        if offset < 0.0 { '-' } else { '+' },
+        (offset_minutes / 60.0).trunc(),
+        offset_minutes % 60.0,
+        ctx.time_zone().name(t)
+    )
+}
+
+/// Formats the UTC time `t` in the date time string format, like `2022-02-01T00:00:00.000Z`,
+/// or returns `None` if it's not a valid time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+pub(crate) fn iso_string(t: f64) -> Option<String> {
+    if !t.is_finite() {
+        return None;
+    }
+
+    let fields = DateFields::new(t);
+    let year = if (0.0..=9999.0).contains(&fields.year) {
+        format!("{:04}", fields.year)
+    } else if fields.year < 0.0 {
+        format!("-{:06}", -fields.year)
+    } else {
+        format!("+{:06}", fields.year)
+    };
+
+    Some(format!(
+        "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        fields.month + 1.0,
+        fields.date,
+        fields.hours,
+        fields.minutes,
+        fields.seconds,
+        fields.milliseconds
+    ))
+}
+
+/// Gets a field of a `Date` object, in local time or in UTC.
+fn get_field(
+    this: &Value,
+    ctx: &mut Interpreter,
+    local: bool,
+    field: fn(DateFields) -> f64,
+) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value(f64::NAN));
+    }
+
+    let t = if local { local_time(t, ctx) } else { t };
+    Ok(to_value(field(DateFields::new(t))))
+}
+
+/// Sets fields of a `Date` object, in local time or in UTC, and returns the new time value.
+///
+/// The fields are, in order, the year, month, date, hours, minutes, seconds and milliseconds.
+/// The arguments set up to `count` fields from the one at `first`, and the missing ones are kept.
+fn set_fields(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+    local: bool,
+    first: usize,
+    count: usize,
+) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+
+    let mut values = Vec::with_capacity(count);
+    for i in 0..count {
+        match args.get(i) {
+            Some(arg) => values.push(Some(to_number(arg, ctx))),
+            None if i == 0 => values.push(Some(f64::NAN)),
+            None => values.push(None),
+        }
+    }
+
+    // Only the year can be set on an invalid date, which then starts from `+0`
+    let t = match t {
+        t if t.is_nan() && first != 0 => return Ok(to_value(f64::NAN)),
+        t if t.is_nan() => 0.0,
+        t if local => local_time(t, ctx),
+        t => t,
+    };
+
+    let fields = DateFields::new(t);
+    let mut all = [
+        fields.year,
+        fields.month,
+        fields.date,
+        fields.hours,
+        fields.minutes,
+        fields.seconds,
+        fields.milliseconds,
+    ];
+    for (i, value) in values.into_iter().enumerate() {
+        if let Some(value) = value {
+            all[first + i] = value;
+        }
+    }
+
+    let new_time = time::make_date(
+        make_day(all[0], all[1], all[2]),
+        make_time(all[3], all[4], all[5], all[6]),
+    );
+    let new_time = time_clip(if local { utc(new_time, ctx) } else { new_time });
+    this.set_kind(ObjectKind::Date(new_time));
+    Ok(to_value(new_time))
+}
+
+/// Create a new `Date`.
+///
+/// Without arguments, the date is the current time. With one argument, it's either the date of
+/// another `Date` object, a date string or a number of milliseconds since the epoch. Otherwise the
+/// arguments are the year, month, date, hours, minutes, seconds and milliseconds, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-constructor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
+pub fn make_date(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = match args.len() {
+        0 => time_clip(ctx.clock().now()),
+        1 => {
+            let value = &args[0];
+            if let Some(t) = time_value_of(value) {
+                t
+            } else {
+                let primitive = ctx.to_primitive(&mut value.clone(), None);
+                if let ValueData::String(ref string) = *primitive {
+                    parse::parse(&string.to_string(), ctx.time_zone())
+                } else {
+                    time_clip(to_number(&primitive, ctx))
+                }
+            }
+        }
+        _ => {
+            let local = time_from_args(args, ctx);
+            time_clip(utc(local, ctx))
+        }
+    };
+
+    this.set_kind(ObjectKind::Date(t));
+    Ok(this.clone())
+}
+
+/// `Date()`
+///
+/// When called as a function, `Date` ignores its arguments and returns the current time as a string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-constructor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
+pub fn call_date(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = time_clip(ctx.clock().now());
+    let fields = DateFields::new(local_time(t, ctx));
+    Ok(to_value(format!(
+        "{} {}",
+        date_string(fields),
+        time_string(t, ctx)
+    )))
+}
+
+/// `Date.now()`
+///
+/// The `now()` method returns the number of milliseconds elapsed since the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.now
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now
+pub fn now(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(time_clip(ctx.clock().now())))
+}
+
+/// `Date.parse( dateString )`
+///
+/// The `parse()` method parses a string representation of a date, and returns the number of
+/// milliseconds since the epoch, or `NaN` if the string is not a valid date.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.parse
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/parse
+pub fn parse(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = ctx.value_to_rust_string(&args.get(0).cloned().unwrap_or_else(undefined));
+    Ok(to_value(parse::parse(&string, ctx.time_zone())))
+}
+
+/// `Date.UTC( year [, month [, date [, hours [, minutes [, seconds [, ms ]]]]]] )`
+///
+/// The `UTC()` method is like the `Date` constructor with several arguments, but in UTC,
+/// and it returns the number of milliseconds since the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.utc
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC
+pub fn utc_time(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(time_clip(time_from_args(args, ctx))))
+}
+
+/// `Date.prototype.getDate()`
+///
+/// The `getDate()` method returns the day of the month, from `1` to `31`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getdate
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate
+pub fn get_date(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.date)
+}
+
+/// `Date.prototype.getDay()`
+///
+/// The `getDay()` method returns the day of the week, from `0` for Sunday to `6`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getday
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay
+pub fn get_day(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.week_day)
+}
+
+/// `Date.prototype.getFullYear()`
+///
+/// The `getFullYear()` method returns the year, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getfullyear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear
+pub fn get_full_year(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.year)
+}
+
+/// `Date.prototype.getHours()`
+///
+/// The `getHours()` method returns the hour, from `0` to `23`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gethours
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours
+pub fn get_hours(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.hours)
+}
+
+/// `Date.prototype.getMilliseconds()`
+///
+/// The `getMilliseconds()` method returns the milliseconds, from `0` to `999`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmilliseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds
+pub fn get_milliseconds(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.milliseconds)
+}
+
+/// `Date.prototype.getMinutes()`
+///
+/// The `getMinutes()` method returns the minutes, from `0` to `59`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getminutes
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes
+pub fn get_minutes(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.minutes)
+}
+
+/// `Date.prototype.getMonth()`
+///
+/// The `getMonth()` method returns the month, from `0` for January to `11`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmonth
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth
+pub fn get_month(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.month)
+}
+
+/// `Date.prototype.getSeconds()`
+///
+/// The `getSeconds()` method returns the seconds, from `0` to `59`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds
+pub fn get_seconds(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, true, |fields| fields.seconds)
+}
+
+/// `Date.prototype.getTime()`
+///
+/// The `getTime()` method returns the number of milliseconds since the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gettime
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTime
+pub fn get_time(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(this_time_value(this, ctx)?))
+}
+
+/// `Date.prototype.getTimezoneOffset()`
+///
+/// The `getTimezoneOffset()` method returns the difference, in minutes, between UTC and the local time.
+/// It's positive west of Greenwich.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gettimezoneoffset
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset
+pub fn get_timezone_offset(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value(f64::NAN));
+    }
+    Ok(to_value((t - local_time(t, ctx)) / MS_PER_MINUTE))
+}
+
+/// `Date.prototype.getUTCDate()`
+///
+/// The `getUTCDate()` method returns the day of the month, from `1` to `31`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcdate
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDate
+pub fn get_utc_date(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.date)
+}
+
+/// `Date.prototype.getUTCDay()`
+///
+/// The `getUTCDay()` method returns the day of the week, from `0` for Sunday to `6`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcday
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDay
+pub fn get_utc_day(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.week_day)
+}
+
+/// `Date.prototype.getUTCFullYear()`
+///
+/// The `getUTCFullYear()` method returns the year, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcfullyear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCFullYear
+pub fn get_utc_full_year(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.year)
+}
+
+/// `Date.prototype.getUTCHours()`
+///
+/// The `getUTCHours()` method returns the hour, from `0` to `23`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutchours
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCHours
+pub fn get_utc_hours(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.hours)
+}
+
+/// `Date.prototype.getUTCMilliseconds()`
+///
+/// The `getUTCMilliseconds()` method returns the milliseconds, from `0` to `999`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcmilliseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMilliseconds
+pub fn get_utc_milliseconds(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.milliseconds)
+}
+
+/// `Date.prototype.getUTCMinutes()`
+///
+/// The `getUTCMinutes()` method returns the minutes, from `0` to `59`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcminutes
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMinutes
+pub fn get_utc_minutes(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.minutes)
+}
+
+/// `Date.prototype.getUTCMonth()`
+///
+/// The `getUTCMonth()` method returns the month, from `0` for January to `11`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcmonth
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMonth
+pub fn get_utc_month(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.month)
+}
+
+/// `Date.prototype.getUTCSeconds()`
+///
+/// The `getUTCSeconds()` method returns the seconds, from `0` to `59`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCSeconds
+pub fn get_utc_seconds(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    get_field(this, ctx, false, |fields| fields.seconds)
+}
+
+/// `Date.prototype.setDate( date )`
+///
+/// The `setDate()` method sets the day of the month, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setdate
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
+pub fn set_date(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 2, 1)
+}
+
+/// `Date.prototype.setFullYear( year [, month [, date ]] )`
+///
+/// The `setFullYear()` method sets the year, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setfullyear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear
+pub fn set_full_year(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 0, 3)
+}
+
+/// `Date.prototype.setHours( hours [, minutes [, seconds [, ms ]]] )`
+///
+/// The `setHours()` method sets the hours, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.sethours
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
+pub fn set_hours(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 3, 4)
+}
+
+/// `Date.prototype.setMilliseconds( ms )`
+///
+/// The `setMilliseconds()` method sets the milliseconds, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmilliseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMilliseconds
+pub fn set_milliseconds(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 6, 1)
+}
+
+/// `Date.prototype.setMinutes( minutes [, seconds [, ms ]] )`
+///
+/// The `setMinutes()` method sets the minutes, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setminutes
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMinutes
+pub fn set_minutes(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 4, 3)
+}
+
+/// `Date.prototype.setMonth( month [, date ] )`
+///
+/// The `setMonth()` method sets the month, from `0` for January to `11`, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmonth
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
+pub fn set_month(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 1, 2)
+}
+
+/// `Date.prototype.setSeconds( seconds [, ms ] )`
+///
+/// The `setSeconds()` method sets the seconds, in local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setSeconds
+pub fn set_seconds(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, true, 5, 2)
+}
+
+/// `Date.prototype.setTime( time )`
+///
+/// The `setTime()` method sets the number of milliseconds since the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.settime
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime
+pub fn set_time(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_time_value(this, ctx)?;
+    let t = time_clip(to_number(
+        &args.get(0).cloned().unwrap_or_else(undefined),
+        ctx,
+    ));
+    this.set_kind(ObjectKind::Date(t));
+    Ok(to_value(t))
+}
+
+/// `Date.prototype.setUTCDate( date )`
+///
+/// The `setUTCDate()` method sets the day of the month, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcdate
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCDate
+pub fn set_utc_date(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 2, 1)
+}
+
+/// `Date.prototype.setUTCFullYear( year [, month [, date ]] )`
+///
+/// The `setUTCFullYear()` method sets the year, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcfullyear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear
+pub fn set_utc_full_year(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 0, 3)
+}
+
+/// `Date.prototype.setUTCHours( hours [, minutes [, seconds [, ms ]]] )`
+///
+/// The `setUTCHours()` method sets the hours, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutchours
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCHours
+pub fn set_utc_hours(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 3, 4)
+}
+
+/// `Date.prototype.setUTCMilliseconds( ms )`
+///
+/// The `setUTCMilliseconds()` method sets the milliseconds, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcmilliseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMilliseconds
+pub fn set_utc_milliseconds(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    set_fields(this, args, ctx, false, 6, 1)
+}
+
+/// `Date.prototype.setUTCMinutes( minutes [, seconds [, ms ]] )`
+///
+/// The `setUTCMinutes()` method sets the minutes, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcminutes
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMinutes
+pub fn set_utc_minutes(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 4, 3)
+}
+
+/// `Date.prototype.setUTCMonth( month [, date ] )`
+///
+/// The `setUTCMonth()` method sets the month, from `0` for January to `11`, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcmonth
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMonth
+pub fn set_utc_month(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 1, 2)
+}
+
+/// `Date.prototype.setUTCSeconds( seconds [, ms ] )`
+///
+/// The `setUTCSeconds()` method sets the seconds, in UTC.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setutcseconds
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCSeconds
+pub fn set_utc_seconds(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    set_fields(this, args, ctx, false, 5, 2)
+}
+
+/// `Date.prototype.toDateString()`
+///
+/// The `toDateString()` method returns the date part of the date, in local time, like `Tue Feb 01 2022`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.todatestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString
+pub fn to_date_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    Ok(to_value(date_string(DateFields::new(local_time(t, ctx)))))
+}
+
+/// `Date.prototype.toISOString()`
+///
+/// The `toISOString()` method returns the date in the date time string format, in UTC,
+/// like `2022-02-01T00:00:00.000Z`. It throws a `RangeError` for invalid dates.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString
+pub fn to_iso_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    match iso_string(t) {
+        Some(string) => Ok(to_value(string)),
+        None => ctx.throw_range_error("Invalid time value"),
+    }
+}
+
+/// `Date.prototype.toJSON( key )`
+///
+/// The `toJSON()` method returns the date as a string, with `toISOString()`, or `null` for invalid dates.
+/// It works on any object with a `toISOString()` method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tojson
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toJSON
+pub fn to_json(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut object = ctx.to_object(this)?;
+    let primitive = ctx.to_primitive(&mut object, Some("number"));
+    if primitive.is_number() && !primitive.to_number().is_finite() {
+        return Ok(to_value(None::<()>));
+    }
+
+    let to_iso_string = object.get_field_slice("toISOString");
+    if !to_iso_string.is_function() {
+        return ctx.throw_type_error("toISOString is not a function");
+    }
+    ctx.call(&to_iso_string, &mut object, &[])
+}
+
+/// `Date.prototype.toLocaleDateString( [locales [, options]] )`
+///
+/// The `toLocaleDateString()` method returns the date part of the date, in local time,
+/// in the format of the locale, like `2/1/2022` in `en-US`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
+pub fn to_locale_date_string(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    locale_string(t, args, true, false, ctx)
+}
+
+/// `Date.prototype.toLocaleString( [locales [, options]] )`
+///
+/// The `toLocaleString()` method returns the date, in local time, in the format of the locale,
+/// like `2/1/2022, 12:00:00 AM` in `en-US`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
+pub fn to_locale_string(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    locale_string(t, args, true, true, ctx)
+}
+
+/// `Date.prototype.toLocaleTimeString( [locales [, options]] )`
+///
+/// The `toLocaleTimeString()` method returns the time part of the date, in local time,
+/// in the format of the locale, like `12:00:00 AM` in `en-US`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
+pub fn to_locale_time_string(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    locale_string(t, args, false, true, ctx)
+}
+
+/// Formats the date part, the time part or both of the finite time value `t`, with an
+/// `Intl.DateTimeFormat` of the locales and options in `args`.
+#[cfg(feature = "intl")]
+fn locale_string(
+    t: f64,
+    args: &[Value],
+    date: bool,
+    time: bool,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let fields = match (date, time) {
+        (true, true) => DateTimeFields::All,
+        (true, false) => DateTimeFields::Date,
+        _ => DateTimeFields::Time,
+    };
+    date_time_format::to_locale_string(t, args, fields, ctx)
+}
+
+/// Formats the date part, the time part or both of the finite time value `t`, in local time,
+/// in the `en-US` format.
+#[cfg(not(feature = "intl"))]
+fn locale_string(
+    t: f64,
+    _: &[Value],
+    date: bool,
+    time: bool,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let fields = DateFields::new(local_time(t, ctx));
+    let date_string = format!("{}/{}/{}", fields.month + 1.0, fields.date, fields.year);
+    let hours = if fields.hours % 12.0 == 0.0 {
+        12.0
+    } else {
+        fields.hours % 12.0
+    };
+    let time_string = format!(
+        "{}:{:02}:{:02} {}",
+        hours,
+        fields.minutes,
+        fields.seconds,
+        if fields.hours < 12.0 { "AM" } else { "PM" }
+    );
+    Ok(to_value(match (date, time) {
+        (true, true) => format!("{}, {}", date_string, time_string),
+        (true, false) => date_string,
+        _ => time_string,
+    }))
+}
+
+/// `Date.prototype.toString()`
+///
+/// The `toString()` method returns the date, in local time, like `Tue Feb 01 2022 00:00:00 GMT+0100 (CET)`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    Ok(to_value(format!(
+        "{} {}",
+        date_string(DateFields::new(local_time(t, ctx))),
+        time_string(t, ctx)
+    )))
+}
+
+/// `Date.prototype.toTimeString()`
+///
+/// The `toTimeString()` method returns the time part of the date, in local time, like `00:00:00 GMT+0100 (CET)`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.totimestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString
+pub fn to_time_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+    Ok(to_value(time_string(t, ctx)))
+}
+
+/// `Date.prototype.toUTCString()`
+///
+/// The `toUTCString()` method returns the date, in UTC, in the RFC 7231 format, like `Tue, 01 Feb 2022 00:00:00 GMT`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toutcstring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toUTCString
+pub fn to_utc_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let t = this_time_value(this, ctx)?;
+    if t.is_nan() {
+        return Ok(to_value("Invalid Date"));
+    }
+
+    let fields = DateFields::new(t);
+    Ok(to_value(format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        WEEK_DAYS[fields.week_day as usize],
+        fields.date,
+        MONTHS[fields.month as usize],
+        format_year(fields.year),
+        fields.hours,
+        fields.minutes,
+        fields.seconds
+    )))
+}
+
+/// `Date.prototype.valueOf()`
+///
+/// The `valueOf()` method returns the number of milliseconds since the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date.prototype.valueof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/valueOf
+pub fn value_of(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(this_time_value(this, ctx)?))
+}
+
+/// Create a new `Date` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(get_date, named "getDate", of prototype);
+    make_builtin_fn!(get_day, named "getDay", of prototype);
+    make_builtin_fn!(get_full_year, named "getFullYear", of prototype);
+    make_builtin_fn!(get_hours, named "getHours", of prototype);
+    make_builtin_fn!(get_milliseconds, named "getMilliseconds", of prototype);
+    make_builtin_fn!(get_minutes, named "getMinutes", of prototype);
+    make_builtin_fn!(get_month, named "getMonth", of prototype);
+    make_builtin_fn!(get_seconds, named "getSeconds", of prototype);
+    make_builtin_fn!(get_time, named "getTime", of prototype);
+    make_builtin_fn!(get_timezone_offset, named "getTimezoneOffset", of prototype);
+    make_builtin_fn!(get_utc_date, named "getUTCDate", of prototype);
+    make_builtin_fn!(get_utc_day, named "getUTCDay", of prototype);
+    make_builtin_fn!(get_utc_full_year, named "getUTCFullYear", of prototype);
+    make_builtin_fn!(get_utc_hours, named "getUTCHours", of prototype);
+    make_builtin_fn!(get_utc_milliseconds, named "getUTCMilliseconds", of prototype);
+    make_builtin_fn!(get_utc_minutes, named "getUTCMinutes", of prototype);
+    make_builtin_fn!(get_utc_month, named "getUTCMonth", of prototype);
+    make_builtin_fn!(get_utc_seconds, named "getUTCSeconds", of prototype);
+    make_builtin_fn!(set_date, named "setDate", with length 1, of prototype);
+    make_builtin_fn!(set_full_year, named "setFullYear", with length 3, of prototype);
+    make_builtin_fn!(set_hours, named "setHours", with length 4, of prototype);
+    make_builtin_fn!(set_milliseconds, named "setMilliseconds", with length 1, of prototype);
+    make_builtin_fn!(set_minutes, named "setMinutes", with length 3, of prototype);
+    make_builtin_fn!(set_month, named "setMonth", with length 2, of prototype);
+    make_builtin_fn!(set_seconds, named "setSeconds", with length 2, of prototype);
+    make_builtin_fn!(set_time, named "setTime", with length 1, of prototype);
+    make_builtin_fn!(set_utc_date, named "setUTCDate", with length 1, of prototype);
+    make_builtin_fn!(set_utc_full_year, named "setUTCFullYear", with length 3, of prototype);
+    make_builtin_fn!(set_utc_hours, named "setUTCHours", with length 4, of prototype);
+    make_builtin_fn!(set_utc_milliseconds, named "setUTCMilliseconds", with length 1, of prototype);
+    make_builtin_fn!(set_utc_minutes, named "setUTCMinutes", with length 3, of prototype);
+    make_builtin_fn!(set_utc_month, named "setUTCMonth", with length 2, of prototype);
+    make_builtin_fn!(set_utc_seconds, named "setUTCSeconds", with length 2, of prototype);
+    make_builtin_fn!(to_date_string, named "toDateString", of prototype);
+    make_builtin_fn!(to_iso_string, named "toISOString", of prototype);
+    make_builtin_fn!(to_json, named "toJSON", with length 1, of prototype);
+    make_builtin_fn!(to_locale_date_string, named "toLocaleDateString", of prototype);
+    make_builtin_fn!(to_locale_string, named "toLocaleString", of prototype);
+    make_builtin_fn!(to_locale_time_string, named "toLocaleTimeString", of prototype);
+    make_builtin_fn!(to_string, named "toString", of prototype);
+    make_builtin_fn!(to_time_string, named "toTimeString", of prototype);
+    make_builtin_fn!(to_utc_string, named "toUTCString", of prototype);
+    make_builtin_fn!(value_of, named "valueOf", of prototype);
+
+    let date = make_constructor_fn!(make_date, call_date, global, prototype);
+    date.set_field_slice("length", to_value(7));
+
+    make_builtin_fn!(now, named "now", of date);
+    make_builtin_fn!(parse, named "parse", with length 1, of date);
+    make_builtin_fn!(utc_time, named "UTC", with length 7, of date);
+
+    date
+}
+
+/// Initialise the `Date` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Date", create(global));
+}
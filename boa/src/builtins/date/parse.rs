@@ -0,0 +1,379 @@
+//! This module implements the parsing of date strings, used by `Date.parse` and the `Date` constructor.
+//!
+//! The [date time string format][iso] of the specification, a simplification of ISO 8601, is parsed first.
+//! Other strings go through a more tolerant parser, which accepts the [RFC 2822][rfc] format,
+//! the formats of `Date.prototype.toString` and `Date.prototype.toUTCString`, and the common
+//! `Month day, year` and `month/day/year` variations.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-date.parse
+//! [iso]: https://tc39.es/ecma262/#sec-date-time-string-format
+//! [rfc]: https://tools.ietf.org/html/rfc2822#section-3.3
+
+use super::{
+    time::{
+        make_date, make_day, make_time, time_clip, MONTHS, MS_PER_HOUR, MS_PER_MINUTE, WEEK_DAYS,
+    },
+    time_zone::TimeZone,
+};
+
+/// Parses a date string into a time value, or `NaN` if it's not a valid date.
+///
+/// Dates without an offset are in the local time zone, except for the date-only forms of the
+/// date time string format, which are in UTC.
+pub(crate) fn parse(string: &str, time_zone: &dyn TimeZone) -> f64 {
+    let string = string.trim();
+    let parsed = parse_iso(string).or_else(|| parse_legacy(string));
+
+    match parsed {
+        Some(date) => {
+            let local = make_date(
+                make_day(date.year, date.month, date.day),
+                make_time(date.hours, date.minutes, date.seconds, date.milliseconds),
+            );
+            let offset = match date.offset {
+                Some(offset) => offset,
+                None => time_zone.offset_from_local(local),
+            };
+            time_clip(local - offset)
+        }
+        None => f64::NAN,
+    }
+}
+
+/// The fields of a parsed date, with months going from `0` to `11`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ParsedDate {
+    year: f64,
+    month: f64,
+    day: f64,
+    hours: f64,
+    minutes: f64,
+    seconds: f64,
+    milliseconds: f64,
+    /// The offset from UTC, in milliseconds, or `None` for the local time.
+    offset: Option<f64>,
+}
+
+impl ParsedDate {
+    /// Checks the ranges of the fields.
+    fn validate(self) -> Option<Self> {
+        let days_in_month =
+            make_day(self.year, self.month + 1.0, 1.0) - make_day(self.year, self.month, 1.0);
+        let is_midnight = self.minutes == 0.0 && self.seconds == 0.0 && self.milliseconds == 0.0;
+
+        let valid = (0.0..12.0).contains(&self.month)
+            && self.day >= 1.0
+            && self.day <= days_in_month
+            && (self.hours < 24.0 || (self.hours == 24.0 && is_midnight))
+            && self.minutes < 60.0
+            && self.seconds < 60.0;
+        if valid {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+/// A cursor over the bytes of a date string.
+#[derive(Debug)]
+struct Cursor<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn next_if(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads exactly `len` digits.
+    fn digits(&mut self, len: usize) -> Option<f64> {
+        let digits = self.input.get(self.position..self.position + len)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.position += len;
+        Some(
+            digits
+                .iter()
+                .fold(0.0, |value, digit| value * 10.0 + f64::from(digit - b'0')),
+        )
+    }
+
+    /// Reads one or more digits, returning their value and their count.
+    fn number(&mut self) -> Option<(f64, usize)> {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        let len = self.position - start;
+        if len == 0 {
+            return None;
+        }
+        self.position = start;
+        Some((self.digits(len)?, len))
+    }
+}
+
+/// Parses the date time string format: `YYYY-MM-DDTHH:mm:ss.sssZ` and its shorter forms.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_iso(string: &str) -> Option<ParsedDate> {
+    let mut cursor = Cursor::new(string);
+
+    let year = match cursor.peek()? {
+        sign @ b'+' | sign @ b'-' => {
+            cursor.position += 1;
+            let year = cursor.digits(6)?;
+            if sign == b'-' {
+                // `-000000` is not a valid year
+                if year == 0.0 {
+                    return None;
+                }
+                -year
+            } else {
+                year
+            }
+        }
+        _ => cursor.digits(4)?,
+    };
+    let mut date = ParsedDate {
+        year,
+        month: 0.0,
+        day: 1.0,
+        hours: 0.0,
+        minutes: 0.0,
+        seconds: 0.0,
+        milliseconds: 0.0,
+        offset: Some(0.0),
+    };
+
+    if cursor.next_if(b'-') {
+        date.month = cursor.digits(2)? - 1.0;
+        if cursor.next_if(b'-') {
+            date.day = cursor.digits(2)?;
+        }
+    }
+
+    if cursor.next_if(b'T') || cursor.next_if(b't') {
+        date.hours = cursor.digits(2)?;
+        if !cursor.next_if(b':') {
+            return None;
+        }
+        date.minutes = cursor.digits(2)?;
+        if cursor.next_if(b':') {
+            date.seconds = cursor.digits(2)?;
+            if cursor.next_if(b'.') {
+                let (fraction, len) = cursor.number()?;
+                date.milliseconds = (fraction / 10f64.powi(len as i32 - 3)).trunc();
+            }
+        }
+
+        // Date-time forms without an offset are in the local time zone
+        date.offset = match cursor.peek() {
+            Some(b'Z') | Some(b'z') => {
+                cursor.position += 1;
+                Some(0.0)
+            }
+            Some(sign @ b'+') | Some(sign @ b'-') => {
+                cursor.position += 1;
+                let hours = cursor.digits(2)?;
+                if !cursor.next_if(b':') {
+                    return None;
+                }
+                let minutes = cursor.digits(2)?;
+                if hours > 23.0 || minutes > 59.0 {
+                    return None;
+                }
+                let offset = hours * MS_PER_HOUR + minutes * MS_PER_MINUTE;
+                Some(if sign == b'-' { -offset } else { offset })
+            }
+            _ => None,
+        };
+    }
+
+    if cursor.is_done() {
+        date.validate()
+    } else {
+        None
+    }
+}
+
+/// Returns the offset of a time zone name accepted by RFC 2822, in hours.
+fn zone_offset(name: &str) -> Option<f64> {
+    let offset = match name {
+        "z" | "ut" | "utc" | "gmt" => 0.0,
+        "edt" => -4.0,
+        "est" | "cdt" => -5.0,
+        "cst" | "mdt" => -6.0,
+        "mst" | "pdt" => -7.0,
+        "pst" => -8.0,
+        _ => return None,
+    };
+    Some(offset)
+}
+
+/// Finds the index of the name abbreviated as `names[index]` that `word` starts with,
+/// like `feb` or `february` for `Feb`.
+fn find_name(names: &[&str], word: &str) -> Option<usize> {
+    if word.len() < 3 {
+        return None;
+    }
+    names
+        .iter()
+        .position(|name| word.starts_with(&name.to_ascii_lowercase()))
+}
+
+/// Parses the other common date formats, like `Tue, 01 Feb 2022 00:00:00 GMT`,
+/// `Tue Feb 01 2022 00:00:00 GMT+0100 (CET)` or `2/1/2022 10:00 PM`.
+fn parse_legacy(string: &str) -> Option<ParsedDate> {
+    let mut cursor = Cursor::new(string);
+
+    let mut month = None;
+    let mut numbers: Vec<(f64, usize)> = Vec::new();
+    let mut time: Option<(f64, f64, f64, f64)> = None;
+    let mut offset = None;
+    let mut pm = None;
+
+    while let Some(byte) = cursor.peek() {
+        match byte {
+            b'(' => {
+                // Skip comments, like the time zone name of `toString`
+                let mut depth = 0;
+                while let Some(byte) = cursor.peek() {
+                    cursor.position += 1;
+                    match byte {
+                        b'(' => depth += 1,
+                        b')' if depth == 1 => break,
+                        b')' => depth -= 1,
+                        _ => {}
+                    }
+                }
+            }
+            b'a'..=b'z' | b'A'..=b'Z' => {
+                let start = cursor.position;
+                while cursor.peek().filter(u8::is_ascii_alphabetic).is_some() {
+                    cursor.position += 1;
+                }
+                let word = string[start..cursor.position].to_ascii_lowercase();
+
+                if let Some(hours) = zone_offset(&word) {
+                    offset = Some(hours * MS_PER_HOUR);
+                } else if word == "am" || word == "pm" {
+                    pm = Some(word == "pm");
+                } else if let Some(index) = find_name(&MONTHS, &word) {
+                    month = Some(index);
+                } else if find_name(&WEEK_DAYS, &word).is_none() {
+                    return None;
+                }
+            }
+            b'+' | b'-' if time.is_some() || offset.is_some() => {
+                // A numeric offset, like `+0100`, `+01:00` or `-5`
+                cursor.position += 1;
+                let (value, len) = cursor.number()?;
+                let (hours, minutes) = if cursor.next_if(b':') {
+                    (value, cursor.digits(2)?)
+                } else if len <= 2 {
+                    (value, 0.0)
+                } else {
+                    ((value / 100.0).trunc(), value % 100.0)
+                };
+                let value = hours * MS_PER_HOUR + minutes * MS_PER_MINUTE;
+                offset = Some(if byte == b'-' { -value } else { value });
+            }
+            b'0'..=b'9' => {
+                let number = cursor.number()?;
+                if cursor.next_if(b':') {
+                    let hours = number.0;
+                    let minutes = cursor.digits(2)?;
+                    let mut seconds = 0.0;
+                    let mut milliseconds = 0.0;
+                    if cursor.next_if(b':') {
+                        seconds = cursor.digits(2)?;
+                        if cursor.next_if(b'.') {
+                            let (fraction, len) = cursor.number()?;
+                            milliseconds = (fraction / 10f64.powi(len as i32 - 3)).trunc();
+                        }
+                    }
+                    time = Some((hours, minutes, seconds, milliseconds));
+                } else {
+                    numbers.push(number);
+                }
+            }
+            b'-' if !numbers.is_empty() => cursor.position += 1,
+            b' ' | b'\t' | b'\n' | b'\r' | b',' | b'/' | b'.' => cursor.position += 1,
+            _ => return None,
+        }
+    }
+
+    // Two digit years are in the twentieth century, or in this one below 50
+    let full_year = |(year, len): (f64, usize)| match len {
+        1 | 2 if year < 50.0 => year + 2000.0,
+        1 | 2 => year + 1900.0,
+        _ => year,
+    };
+    let (year, month, day) = match (month, numbers.as_slice()) {
+        (Some(month), &[first, second]) => {
+            if first.1 > 2 {
+                (full_year(first), month as f64, second.0)
+            } else {
+                (full_year(second), month as f64, first.0)
+            }
+        }
+        (None, &[first, second, third]) => {
+            if first.1 > 2 {
+                (full_year(first), second.0 - 1.0, third.0)
+            } else {
+                (full_year(third), first.0 - 1.0, second.0)
+            }
+        }
+        _ => return None,
+    };
+
+    let (mut hours, minutes, seconds, milliseconds) = time.unwrap_or((0.0, 0.0, 0.0, 0.0));
+    match pm {
+        Some(_) if !(1.0..=12.0).contains(&hours) => return None,
+        Some(true) if hours < 12.0 => hours += 12.0,
+        Some(false) if hours == 12.0 => hours = 0.0,
+        _ => {}
+    }
+
+    ParsedDate {
+        year,
+        month,
+        day,
+        hours,
+        minutes,
+        seconds,
+        milliseconds,
+        offset,
+    }
+    .validate()
+}
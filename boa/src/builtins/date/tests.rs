@@ -0,0 +1,335 @@
+use super::{
+    clock::FixedClock,
+    time_zone::{PosixTimeZone, TimeZone, TzifTimeZone},
+};
+use crate::exec::{Executor, Interpreter};
+use crate::forward;
+use crate::realm::Realm;
+
+/// The time zone of Paris, with daylight saving time from the last Sunday of March
+/// to the last Sunday of October.
+const PARIS: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+
+/// `2022-02-01T00:00:00.000Z`
+const FEBRUARY_FIRST: f64 = 1_643_673_600_000.0;
+
+fn engine_in(time_zone: &str) -> Interpreter {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    engine.set_time_zone(PosixTimeZone::parse(time_zone).expect("invalid time zone"));
+    engine.set_clock(FixedClock(FEBRUARY_FIRST));
+    engine
+}
+
+#[test]
+fn construct_in_local_time() {
+    let mut engine = engine_in(PARIS);
+    let init = r#"
+        var winter = new Date(2022, 1, 1);
+        var summer = new Date(2022, 6, 1, 12, 30, 15, 250);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+
+    assert_eq!(forward(&mut engine, "winter.getTime()"), "1643670000000");
+    assert_eq!(
+        forward(&mut engine, "winter.toISOString()"),
+        "2022-01-31T23:00:00.000Z"
+    );
+    assert_eq!(
+        forward(&mut engine, "winter.toString()"),
+        "Tue Feb 01 2022 00:00:00 GMT+0100 (CET)"
+    );
+    assert_eq!(forward(&mut engine, "winter.getTimezoneOffset()"), "-60");
+    assert_eq!(
+        forward(&mut engine, "summer.toString()"),
+        "Fri Jul 01 2022 12:30:15 GMT+0200 (CEST)"
+    );
+    assert_eq!(forward(&mut engine, "summer.getTimezoneOffset()"), "-120");
+    assert_eq!(forward(&mut engine, "summer.getMilliseconds()"), "250");
+    assert_eq!(forward(&mut engine, "summer.getUTCHours()"), "10");
+    assert_eq!(forward(&mut engine, "summer.getDay()"), "5");
+
+    // Years from 0 to 99 are in the twentieth century
+    assert_eq!(
+        forward(&mut engine, "new Date(99, 0).getFullYear()"),
+        "1999"
+    );
+    assert_eq!(forward(&mut engine, "new Date(new Date(0)).getTime()"), "0");
+    assert_eq!(
+        forward(&mut engine, "new Date(8.64e15 + 1).getTime()"),
+        "NaN"
+    );
+}
+
+#[test]
+fn current_time_comes_from_the_clock() {
+    let mut engine = engine_in(PARIS);
+
+    assert_eq!(forward(&mut engine, "Date.now()"), "1643673600000");
+    assert_eq!(
+        forward(&mut engine, "new Date().getTime()"),
+        "1643673600000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date(2000, 1)"),
+        "Tue Feb 01 2022 01:00:00 GMT+0100 (CET)"
+    );
+}
+
+#[test]
+fn parse() {
+    let mut engine = engine_in(PARIS);
+
+    // Date-only forms are in UTC, date-time forms without an offset in local time
+    assert_eq!(
+        forward(&mut engine, "Date.parse('2022-02-01')"),
+        "1643673600000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('2022-02-01T00:00')"),
+        "1643670000000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('2022-02-01T00:00:00.000+01:00')"),
+        "1643670000000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('2022-02-01T00:00:00.5Z')"),
+        "1643673600500"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('+275760-09-13T00:00:00.000Z')"),
+        "8640000000000000"
+    );
+    assert_eq!(forward(&mut engine, "Date.parse('-000000-01-01')"), "NaN");
+    assert_eq!(forward(&mut engine, "Date.parse('2022-02-30')"), "NaN");
+
+    // RFC 2822 and the formats of `toString` and `toUTCString`
+    assert_eq!(
+        forward(&mut engine, "Date.parse('Tue, 01 Feb 2022 00:00:00 GMT')"),
+        "1643673600000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('1 Feb 2022 00:00 -0500')"),
+        "1643691600000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('Tue, 01 Feb 2022 00:00:00 EST')"),
+        "1643691600000"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Date.parse('Tue Feb 01 2022 00:00:00 GMT+0100 (Central European Time)')"
+        ),
+        "1643670000000"
+    );
+
+    // Other common formats, in local time
+    assert_eq!(
+        forward(&mut engine, "Date.parse('February 1, 2022')"),
+        "1643670000000"
+    );
+    assert_eq!(
+        forward(&mut engine, "Date.parse('2/1/2022 1:00 AM')"),
+        "1643673600000"
+    );
+    assert_eq!(forward(&mut engine, "Date.parse('not a date')"), "NaN");
+    assert_eq!(
+        forward(&mut engine, "new Date('2022-02-01').getTime()"),
+        "1643673600000"
+    );
+}
+
+#[test]
+fn daylight_saving_transitions() {
+    let mut engine = engine_in(PARIS);
+
+    // 02:30 is skipped on March 27, and uses the offset from before the transition
+    assert_eq!(
+        forward(&mut engine, "new Date(2022, 2, 27, 2, 30).toISOString()"),
+        "2022-03-27T01:30:00.000Z"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Date(2022, 2, 27, 2, 30).getHours()"),
+        "3"
+    );
+
+    // 02:30 happens twice on October 30, and the first one is used
+    assert_eq!(
+        forward(&mut engine, "new Date(2022, 9, 30, 2, 30).toISOString()"),
+        "2022-10-30T00:30:00.000Z"
+    );
+}
+
+#[test]
+fn setters() {
+    let mut engine = engine_in(PARIS);
+    let init = r#"
+        var date = new Date(Date.UTC(2020, 1, 29, 12, 30));
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+
+    // Fields overflow into the next ones
+    assert_eq!(
+        forward(&mut engine, "date.setUTCFullYear(2021)"),
+        "1614601800000"
+    );
+    assert_eq!(forward(&mut engine, "date.getUTCMonth()"), "2");
+    assert_eq!(forward(&mut engine, "date.getUTCDate()"), "1");
+    forward(&mut engine, "date.setMonth(13, 5)");
+    assert_eq!(
+        forward(&mut engine, "date.toString()"),
+        "Sat Feb 05 2022 13:30:00 GMT+0100 (CET)"
+    );
+    forward(&mut engine, "date.setHours(0, 0, 0, 0)");
+    assert_eq!(
+        forward(&mut engine, "date.toISOString()"),
+        "2022-02-04T23:00:00.000Z"
+    );
+    forward(&mut engine, "date.setUTCMinutes(90)");
+    assert_eq!(
+        forward(&mut engine, "date.toUTCString()"),
+        "Sat, 05 Feb 2022 00:30:00 GMT"
+    );
+
+    // Only the year can be set on an invalid date
+    assert_eq!(forward(&mut engine, "date.setTime(NaN)"), "NaN");
+    assert_eq!(forward(&mut engine, "date.setDate(1)"), "NaN");
+    assert_eq!(
+        forward(&mut engine, "date.setFullYear(2022)"),
+        "1640991600000"
+    );
+    assert_eq!(forward(&mut engine, "date.setSeconds()"), "NaN");
+}
+
+#[test]
+fn formatting() {
+    let mut engine = engine_in("EST5EDT");
+    let init = r#"
+        var date = new Date(2022, 1, 1, 13, 5, 9);
+        var invalid = new Date(NaN);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+
+    assert_eq!(
+        forward(&mut engine, "date.toDateString()"),
+        "Tue Feb 01 2022"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toTimeString()"),
+        "13:05:09 GMT-0500 (EST)"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toUTCString()"),
+        "Tue, 01 Feb 2022 18:05:09 GMT"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toJSON()"),
+        "2022-02-01T18:05:09.000Z"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toLocaleString()"),
+        "2/1/2022, 1:05:09 PM"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Date(Date.UTC(-1, 0)).toISOString()"),
+        "-000001-01-01T00:00:00.000Z"
+    );
+    // Dates are converted to strings by `toString`, which `Date.parse` can read back
+    assert_eq!(forward(&mut engine, "Date.parse(date)"), "1643738709000");
+
+    assert_eq!(forward(&mut engine, "invalid.toString()"), "Invalid Date");
+    assert_eq!(forward(&mut engine, "invalid.toJSON()"), "null");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { invalid.toISOString() } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var other = { getTime: date.getTime }; try { other.getTime() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn posix_time_zones() {
+    const HOUR: f64 = 3_600_000.0;
+
+    // The United States rules are used by default
+    let new_york = PosixTimeZone::parse("EST5EDT").unwrap();
+    assert_eq!(new_york.offset(FEBRUARY_FIRST), -5.0 * HOUR);
+    assert_eq!(
+        new_york.offset(FEBRUARY_FIRST + 150.0 * 24.0 * HOUR),
+        -4.0 * HOUR
+    );
+    assert_eq!(new_york.name(FEBRUARY_FIRST), "EST");
+
+    // Daylight saving time spanning the new year
+    let sydney = PosixTimeZone::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    assert_eq!(sydney.offset(FEBRUARY_FIRST), 11.0 * HOUR);
+    assert_eq!(sydney.name(FEBRUARY_FIRST + 150.0 * 24.0 * HOUR), "AEST");
+
+    let tehran = PosixTimeZone::parse("<+0330>-3:30").unwrap();
+    assert_eq!(tehran.offset(FEBRUARY_FIRST), 3.5 * HOUR);
+    assert_eq!(tehran.name(FEBRUARY_FIRST), "+0330");
+
+    assert!(PosixTimeZone::parse("").is_none());
+    assert!(PosixTimeZone::parse("CET-1CEST,M13.5.0,M10.5.0").is_none());
+}
+
+/// Builds a version 2 TZif file, with the transitions of Paris in 2022 and the rules of `PARIS` after.
+fn paris_tzif() -> Vec<u8> {
+    fn header(bytes: &mut Vec<u8>, counts: [u32; 6]) {
+        bytes.extend_from_slice(b"TZif2");
+        bytes.extend_from_slice(&[0; 15]);
+        for count in counts.iter() {
+            bytes.extend_from_slice(&count.to_be_bytes());
+        }
+    }
+
+    let mut bytes = Vec::new();
+    // The version 1 data, with a single local time type
+    header(&mut bytes, [0, 0, 0, 0, 1, 4]);
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    bytes.extend_from_slice(b"UTC\0");
+
+    header(&mut bytes, [0, 0, 0, 2, 2, 9]);
+    bytes.extend_from_slice(&1_648_342_800_i64.to_be_bytes());
+    bytes.extend_from_slice(&1_667_091_600_i64.to_be_bytes());
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&3600_i32.to_be_bytes());
+    bytes.extend_from_slice(&[0, 0]);
+    bytes.extend_from_slice(&7200_i32.to_be_bytes());
+    bytes.extend_from_slice(&[1, 4]);
+    bytes.extend_from_slice(b"CET\0CEST\0");
+    bytes.extend_from_slice(b"\n");
+    bytes.extend_from_slice(PARIS.as_bytes());
+    bytes.extend_from_slice(b"\n");
+    bytes
+}
+
+#[test]
+fn tzif_time_zones() {
+    const HOUR: f64 = 3_600_000.0;
+    const DAY: f64 = 24.0 * HOUR;
+
+    let paris = TzifTimeZone::parse(&paris_tzif()).unwrap();
+    assert_eq!(paris.offset(FEBRUARY_FIRST), HOUR);
+    assert_eq!(paris.name(FEBRUARY_FIRST), "CET");
+    assert_eq!(paris.offset(FEBRUARY_FIRST + 150.0 * DAY), 2.0 * HOUR);
+    assert_eq!(paris.name(FEBRUARY_FIRST + 150.0 * DAY), "CEST");
+    assert_eq!(paris.offset(FEBRUARY_FIRST + 300.0 * DAY), HOUR);
+
+    // After the last transition, the footer is used
+    assert_eq!(paris.offset(FEBRUARY_FIRST + 515.0 * DAY), 2.0 * HOUR);
+    assert_eq!(paris.offset(FEBRUARY_FIRST + 665.0 * DAY), HOUR);
+
+    assert!(TzifTimeZone::parse(b"TZif2").is_none());
+    assert!(TzifTimeZone::parse(&paris_tzif()[1..]).is_none());
+}
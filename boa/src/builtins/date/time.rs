@@ -0,0 +1,194 @@
+//! This module implements the abstract operations on time values used by `Date` objects.
+//!
+//! A time value is a number of milliseconds since the epoch, `1970-01-01T00:00:00Z`,
+//! ignoring leap seconds, or `NaN` for invalid dates.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-time-values-and-time-range
+
+/// The number of milliseconds in a second.
+pub(crate) const MS_PER_SECOND: f64 = 1000.0;
+
+/// The number of milliseconds in a minute.
+pub(crate) const MS_PER_MINUTE: f64 = 60_000.0;
+
+/// The number of milliseconds in an hour.
+pub(crate) const MS_PER_HOUR: f64 = 3_600_000.0;
+
+/// The number of milliseconds in a day.
+pub(crate) const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// The largest absolute time value, 100,000,000 days on either side of the epoch.
+const MAX_TIME: f64 = 8.64e15;
+
+/// The names of the days of the week, starting with Sunday.
+pub(crate) const WEEK_DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// The names of the months.
+pub(crate) const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The fields of a time value, in the time zone it was split in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DateFields {
+    pub(crate) year: f64,
+    /// From `0` for January to `11` for December.
+    pub(crate) month: f64,
+    /// From `1` to `31`.
+    pub(crate) date: f64,
+    pub(crate) hours: f64,
+    pub(crate) minutes: f64,
+    pub(crate) seconds: f64,
+    pub(crate) milliseconds: f64,
+    /// From `0` for Sunday to `6` for Saturday.
+    pub(crate) week_day: f64,
+}
+
+impl DateFields {
+    /// Splits the finite time value `t` into its fields.
+    pub(crate) fn new(t: f64) -> Self {
+        let days = day(t);
+        let (year, month, date) = civil_from_days(days as i64);
+        let time = time_within_day(t);
+
+        Self {
+            year: year as f64,
+            month: (month - 1) as f64,
+            date: date as f64,
+            hours: (time / MS_PER_HOUR).floor(),
+            minutes: (time / MS_PER_MINUTE).floor() % 60.0,
+            seconds: (time / MS_PER_SECOND).floor() % 60.0,
+            milliseconds: time % MS_PER_SECOND,
+            week_day: (days + 4.0).rem_euclid(7.0) + 0.0,
+        }
+    }
+}
+
+/// The number of the day of `t`, counted from the epoch.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-day
+pub(crate) fn day(t: f64) -> f64 {
+    (t / MS_PER_DAY).floor()
+}
+
+/// The number of milliseconds since the start of the day of `t`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-day
+pub(crate) fn time_within_day(t: f64) -> f64 {
+    // Adding `+0` turns `-0` into `+0`
+    t.rem_euclid(MS_PER_DAY) + 0.0
+}
+
+/// Converts a number of days since the epoch to a `(year, month, day)` date,
+/// with months going from `1` to `12`.
+///
+/// This is the `civil_from_days` algorithm of Howard Hinnant, for the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a `(year, month, day)` date, with months going from `1` to `12`,
+/// to a number of days since the epoch.
+///
+/// This is the `days_from_civil` algorithm of Howard Hinnant, for the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Computes a number of milliseconds from hours, minutes, seconds and milliseconds.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-maketime
+pub(crate) fn make_time(hours: f64, minutes: f64, seconds: f64, milliseconds: f64) -> f64 {
+    if !(hours.is_finite()
+        && minutes.is_finite()
+        && seconds.is_finite()
+        && milliseconds.is_finite())
+    {
+        return f64::NAN;
+    }
+
+    hours.trunc() * MS_PER_HOUR
+        + minutes.trunc() * MS_PER_MINUTE
+        + seconds.trunc() * MS_PER_SECOND
+        + milliseconds.trunc()
+}
+
+/// Computes a number of days since the epoch from a year, month and date.
+///
+/// Months and dates outside of their range overflow into the next or previous ones.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makeday
+pub(crate) fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    if !(year.is_finite() && month.is_finite() && date.is_finite()) {
+        return f64::NAN;
+    }
+
+    let year = year.trunc() + (month.trunc() / 12.0).floor();
+    // Years this far away are out of the time range anyway, and would overflow below
+    if year.abs() > 400_000.0 {
+        return f64::NAN;
+    }
+    let month = month.trunc().rem_euclid(12.0);
+
+    days_from_civil(year as i64, month as i64 + 1, 1) as f64 + date.trunc() - 1.0
+}
+
+/// Computes a time value from a number of days and a number of milliseconds.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makedate
+pub(crate) fn make_date(day: f64, time: f64) -> f64 {
+    let tv = day * MS_PER_DAY + time;
+    if tv.is_finite() {
+        tv
+    } else {
+        f64::NAN
+    }
+}
+
+/// Restricts a time value to the valid range, returning `NaN` outside of it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-timeclip
+pub(crate) fn time_clip(time: f64) -> f64 {
+    if !time.is_finite() || time.abs() > MAX_TIME {
+        f64::NAN
+    } else {
+        // Adding `+0` turns `-0` into `+0`
+        time.trunc() + 0.0
+    }
+}
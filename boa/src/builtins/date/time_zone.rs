@@ -0,0 +1,702 @@
+//! This module implements the time zones used by `Date` objects to convert between UTC and local time.
+//!
+//! The interpreter uses the time zone of the system by default, as found by `from_env`:
+//! the `TZ` environment variable when it's set, `/etc/localtime` otherwise.
+//! Hosts can replace it with `Interpreter::set_time_zone`.
+//!
+//! Both the compiled files of the TZ database (the [TZif format][tzif]) and the
+//! [POSIX `TZ` strings][posix] are supported.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-local-time-zone-adjustment
+//! [tzif]: https://datatracker.ietf.org/doc/html/rfc8536
+//! [posix]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html#tag_08_03
+
+use super::time::{make_day, DateFields, MS_PER_DAY, MS_PER_HOUR, MS_PER_MINUTE, MS_PER_SECOND};
+use std::{env, fmt::Debug, fs, path::Path};
+
+/// The directory of the TZ database, unless overridden by the `TZDIR` environment variable.
+const DEFAULT_TZ_DIR: &str = "/usr/share/zoneinfo";
+
+/// The TZif file of the local time zone of the system.
+const LOCALTIME_FILE: &str = "/etc/localtime";
+
+/// A time zone, mapping UTC times to local times.
+///
+/// All times are numbers of milliseconds since `1970-01-01T00:00:00Z`.
+pub trait TimeZone: Debug {
+    /// Returns the offset of the local time from UTC at the UTC time `t`, in milliseconds.
+    ///
+    /// The offset is positive east of Greenwich, so the local time is `t + offset(t)`.
+    fn offset(&self, t: f64) -> f64;
+
+    /// Returns the abbreviation of the time zone at the UTC time `t`, like `CET` or `CEST`.
+    fn name(&self, t: f64) -> String;
+
+    /// Returns the identifier of the time zone in the TZ database, like `Europe/Paris`, if it's known.
+    ///
+    /// This is the time zone reported by `Intl.DateTimeFormat.prototype.resolvedOptions`.
+    fn identifier(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the offset of the local time `local` from UTC, in milliseconds.
+    ///
+    /// A local time repeated by a backward transition uses the offset before the transition, and so does
+    /// a local time skipped by a forward transition, as required by the specification.
+    fn offset_from_local(&self, local: f64) -> f64 {
+        let before = self.offset(local - MS_PER_DAY);
+        let after = self.offset(local + MS_PER_DAY);
+
+        if self.offset(local - before) == before {
+            before
+        } else if self.offset(local - after) == after {
+            after
+        } else {
+            before
+        }
+    }
+}
+
+/// The Coordinated Universal Time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Utc;
+
+impl TimeZone for Utc {
+    fn offset(&self, _: f64) -> f64 {
+        0.0
+    }
+
+    fn name(&self, _: f64) -> String {
+        String::from("UTC")
+    }
+
+    fn identifier(&self) -> Option<String> {
+        Some(String::from("UTC"))
+    }
+}
+
+/// Finds the time zone of the system.
+///
+/// Like the C library, this uses the `TZ` environment variable when it's set:
+///  - an empty value means UTC;
+///  - a value starting with `:`, an absolute path or the name of a TZ database entry, like
+///    `Europe/Paris`, is the file of the time zone, looked for in the `TZDIR` directory
+///    or in `/usr/share/zoneinfo`;
+///  - anything else is parsed as a POSIX `TZ` string, like `CET-1CEST,M3.5.0,M10.5.0/3`.
+///
+/// When `TZ` isn't set, this reads `/etc/localtime`. UTC is used if nothing works.
+pub fn from_env() -> Box<dyn TimeZone> {
+    let tz = match env::var("TZ") {
+        Ok(tz) => tz,
+        Err(_) => {
+            return match read_tzif(Path::new(LOCALTIME_FILE)) {
+                Some(zone) => Box::new(zone),
+                None => Box::new(Utc),
+            }
+        }
+    };
+
+    let file = tz.trim_start_matches(':');
+    if !file.is_empty() && !file.split('/').any(|part| part == "..") {
+        let path = if Path::new(file).is_absolute() {
+            Path::new(file).to_path_buf()
+        } else {
+            let dir = env::var("TZDIR").unwrap_or_else(|_| String::from(DEFAULT_TZ_DIR));
+            Path::new(&dir).join(file)
+        };
+        if let Some(zone) = read_tzif(&path) {
+            return Box::new(zone);
+        }
+    }
+
+    match PosixTimeZone::parse(&tz) {
+        Some(zone) => Box::new(zone),
+        None => Box::new(Utc),
+    }
+}
+
+/// Reads a TZif file, or returns `None` if it can't be read or parsed.
+fn read_tzif(path: &Path) -> Option<TzifTimeZone> {
+    fs::read(path)
+        .ok()
+        .and_then(|bytes| TzifTimeZone::parse(&bytes))
+}
+
+/// Returns `true` if `year` is a leap year.
+fn is_leap_year(year: f64) -> bool {
+    make_day(year, 2.0, 1.0) - make_day(year, 1.0, 1.0) == 29.0
+}
+
+/// The day of a daylight saving time transition, in a POSIX `TZ` string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransitionDay {
+    /// `Jn`: the day `n`, from `1` to `365`, never counting February 29.
+    Julian(u16),
+
+    /// `n`: the day `n`, from `0` to `365`, counting February 29.
+    Day(u16),
+
+    /// `Mm.w.d`: the day `d` of the week `w` of the month `m`, where the week `5` is the last one.
+    MonthWeekDay { month: u8, week: u8, week_day: u8 },
+}
+
+impl TransitionDay {
+    /// Returns the number of the day of this transition in `year`, counted from the epoch.
+    fn day_in(self, year: f64) -> f64 {
+        match self {
+            Self::Julian(day) => {
+                let leap_day = if is_leap_year(year) && day >= 60 {
+                    1.0
+                } else {
+                    0.0
+                };
+                make_day(year, 0.0, 1.0) + f64::from(day) - 1.0 + leap_day
+            }
+            Self::Day(day) => make_day(year, 0.0, 1.0) + f64::from(day),
+            Self::MonthWeekDay {
+                month,
+                week,
+                week_day,
+            } => {
+                let month = f64::from(month) - 1.0;
+                let first = make_day(year, month, 1.0);
+                let first_week_day = (first + 4.0).rem_euclid(7.0);
+                let mut day = first
+                    + (f64::from(week_day) - first_week_day).rem_euclid(7.0)
+                    + 7.0 * (f64::from(week) - 1.0);
+
+                let next_month = make_day(year, month + 1.0, 1.0);
+                while day >= next_month {
+                    day -= 7.0;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// The daylight saving time of a POSIX `TZ` string.
+#[derive(Debug, Clone, PartialEq)]
+struct DaylightSaving {
+    /// The abbreviation of the daylight saving time.
+    name: String,
+
+    /// The offset of the daylight saving time from UTC, in milliseconds.
+    offset: f64,
+
+    /// The day the daylight saving time starts.
+    start: TransitionDay,
+
+    /// The local standard time of the start, in milliseconds.
+    start_time: f64,
+
+    /// The day the daylight saving time ends.
+    end: TransitionDay,
+
+    /// The local daylight saving time of the end, in milliseconds.
+    end_time: f64,
+}
+
+/// A time zone described by a POSIX `TZ` string, like `CET-1CEST,M3.5.0,M10.5.0/3`.
+///
+/// Note that the offsets of POSIX `TZ` strings are positive west of Greenwich,
+/// so `CET-1` is one hour ahead of UTC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PosixTimeZone {
+    /// The abbreviation of the standard time.
+    std_name: String,
+
+    /// The offset of the standard time from UTC, in milliseconds.
+    std_offset: f64,
+
+    /// The daylight saving time, if any.
+    dst: Option<DaylightSaving>,
+}
+
+impl PosixTimeZone {
+    /// Parses a POSIX `TZ` string, or returns `None` if it's invalid.
+    ///
+    /// When the daylight saving time rules are omitted, the United States rules are used.
+    pub fn parse(tz: &str) -> Option<Self> {
+        let mut parser = PosixParser {
+            input: tz.as_bytes(),
+            position: 0,
+        };
+
+        let std_name = parser.name()?;
+        let std_offset = -parser.time()?;
+        if parser.is_done() {
+            return Some(Self {
+                std_name,
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let dst_name = parser.name()?;
+        let dst_offset = match parser.peek() {
+            Some(b',') | None => std_offset + MS_PER_HOUR,
+            Some(_) => -parser.time()?,
+        };
+
+        let (start, start_time, end, end_time) = if parser.is_done() {
+            (
+                TransitionDay::MonthWeekDay {
+                    month: 3,
+                    week: 2,
+                    week_day: 0,
+                },
+                2.0 * MS_PER_HOUR,
+                TransitionDay::MonthWeekDay {
+                    month: 11,
+                    week: 1,
+                    week_day: 0,
+                },
+                2.0 * MS_PER_HOUR,
+            )
+        } else {
+            parser.expect(b',')?;
+            let (start, start_time) = parser.rule()?;
+            parser.expect(b',')?;
+            let (end, end_time) = parser.rule()?;
+            (start, start_time, end, end_time)
+        };
+
+        if !parser.is_done() {
+            return None;
+        }
+
+        Some(Self {
+            std_name,
+            std_offset,
+            dst: Some(DaylightSaving {
+                name: dst_name,
+                offset: dst_offset,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// Returns the daylight saving time, if it's in effect at the UTC time `t`.
+    fn dst_at(&self, t: f64) -> Option<&DaylightSaving> {
+        let dst = self.dst.as_ref()?;
+        if !t.is_finite() {
+            return None;
+        }
+
+        let year = DateFields::new(t + self.std_offset).year;
+        let start = dst.start.day_in(year) * MS_PER_DAY + dst.start_time - self.std_offset;
+        let end = dst.end.day_in(year) * MS_PER_DAY + dst.end_time - dst.offset;
+
+        let in_dst = if start < end {
+            start <= t && t < end
+        } else {
+            // The daylight saving time spans the new year, like in the southern hemisphere
+            !(end <= t && t < start)
+        };
+        if in_dst {
+            Some(dst)
+        } else {
+            None
+        }
+    }
+}
+
+impl TimeZone for PosixTimeZone {
+    fn offset(&self, t: f64) -> f64 {
+        match self.dst_at(t) {
+            Some(dst) => dst.offset,
+            None => self.std_offset,
+        }
+    }
+
+    fn name(&self, t: f64) -> String {
+        match self.dst_at(t) {
+            Some(dst) => dst.name.clone(),
+            None => self.std_name.clone(),
+        }
+    }
+}
+
+/// A parser of POSIX `TZ` strings.
+#[derive(Debug)]
+struct PosixParser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl PosixParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Parses an abbreviation: at least three letters, or anything but `>` between `<` and `>`.
+    fn name(&mut self) -> Option<String> {
+        let quoted = self.expect(b'<').is_some();
+        let start = self.position;
+        while let Some(byte) = self.peek() {
+            let accepted = if quoted {
+                byte != b'>'
+            } else {
+                byte.is_ascii_alphabetic()
+            };
+            if !accepted {
+                break;
+            }
+            self.position += 1;
+        }
+        let name = String::from_utf8(self.input[start..self.position].to_vec()).ok()?;
+        if quoted {
+            self.expect(b'>')?;
+        }
+
+        if name.len() >= 3 {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
+    /// Parses an unsigned number.
+    fn number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.position])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    /// Parses a time, `[+-]hh[:mm[:ss]]`, in milliseconds.
+    fn time(&mut self) -> Option<f64> {
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                -1.0
+            }
+            Some(b'+') => {
+                self.position += 1;
+                1.0
+            }
+            _ => 1.0,
+        };
+
+        let mut time = f64::from(self.number()?) * MS_PER_HOUR;
+        if self.expect(b':').is_some() {
+            time += f64::from(self.number()?) * MS_PER_MINUTE;
+            if self.expect(b':').is_some() {
+                time += f64::from(self.number()?) * MS_PER_SECOND;
+            }
+        }
+        Some(sign * time)
+    }
+
+    /// Parses a transition rule, `day[/time]`, where the time defaults to `02:00:00`.
+    fn rule(&mut self) -> Option<(TransitionDay, f64)> {
+        let day = match self.peek()? {
+            b'J' => {
+                self.position += 1;
+                match self.number()? {
+                    day @ 1..=365 => TransitionDay::Julian(day as u16),
+                    _ => return None,
+                }
+            }
+            b'M' => {
+                self.position += 1;
+                let month = self.number()?;
+                self.expect(b'.')?;
+                let week = self.number()?;
+                self.expect(b'.')?;
+                let week_day = self.number()?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || week_day > 6 {
+                    return None;
+                }
+                TransitionDay::MonthWeekDay {
+                    month: month as u8,
+                    week: week as u8,
+                    week_day: week_day as u8,
+                }
+            }
+            _ => match self.number()? {
+                day @ 0..=365 => TransitionDay::Day(day as u16),
+                _ => return None,
+            },
+        };
+
+        let time = if self.expect(b'/').is_some() {
+            self.time()?
+        } else {
+            2.0 * MS_PER_HOUR
+        };
+        Some((day, time))
+    }
+}
+
+/// A local time type of a TZif file.
+#[derive(Debug, Clone, PartialEq)]
+struct LocalTimeType {
+    /// The offset from UTC, in milliseconds.
+    offset: f64,
+
+    /// The abbreviation of the time zone.
+    name: String,
+}
+
+/// A time zone read from a compiled file of the TZ database, in the TZif format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TzifTimeZone {
+    /// The UTC times of the transitions, in milliseconds, in ascending order.
+    transitions: Vec<f64>,
+
+    /// The index of the local time type starting at each transition.
+    transition_types: Vec<usize>,
+
+    /// The local time types.
+    types: Vec<LocalTimeType>,
+
+    /// The rule used after the last transition, from the footer of version 2+ files.
+    footer: Option<PosixTimeZone>,
+}
+
+impl TzifTimeZone {
+    /// Parses the content of a TZif file, or returns `None` if it's invalid.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut reader = TzifReader {
+            input: bytes,
+            position: 0,
+        };
+
+        let (version, counts) = reader.header()?;
+        if version == 0 {
+            return reader.data(counts, 4);
+        }
+
+        // Version 2+ files repeat the data with 64 bit times after the version 1 data
+        reader.skip(counts.data_len(4))?;
+        let (_, counts) = reader.header()?;
+        let mut zone = reader.data(counts, 8)?;
+
+        let footer = reader.rest();
+        if footer.len() >= 2 && footer[0] == b'\n' {
+            let end = footer[1..].iter().position(|&byte| byte == b'\n')? + 1;
+            zone.footer = std::str::from_utf8(&footer[1..end])
+                .ok()
+                .and_then(PosixTimeZone::parse);
+        }
+        Some(zone)
+    }
+
+    /// Returns the local time type in effect at the UTC time `t`.
+    fn type_at(&self, t: f64) -> Option<&LocalTimeType> {
+        let after_transitions = match self.transitions.last() {
+            Some(&last) => t >= last,
+            None => true,
+        };
+        if self.footer.is_some() && after_transitions {
+            return None;
+        }
+
+        let index = match self
+            .transitions
+            .iter()
+            .rposition(|&transition| t >= transition)
+        {
+            Some(index) => self.transition_types[index],
+            None => 0,
+        };
+        self.types.get(index)
+    }
+}
+
+impl TimeZone for TzifTimeZone {
+    fn offset(&self, t: f64) -> f64 {
+        match (self.type_at(t), &self.footer) {
+            (Some(local_time_type), _) => local_time_type.offset,
+            (None, Some(footer)) => footer.offset(t),
+            (None, None) => 0.0,
+        }
+    }
+
+    fn name(&self, t: f64) -> String {
+        match (self.type_at(t), &self.footer) {
+            (Some(local_time_type), _) => local_time_type.name.clone(),
+            (None, Some(footer)) => footer.name(t),
+            (None, None) => String::from("UTC"),
+        }
+    }
+}
+
+/// The counts of the header of a TZif file.
+#[derive(Debug, Clone, Copy)]
+struct TzifCounts {
+    is_ut: usize,
+    is_std: usize,
+    leap: usize,
+    time: usize,
+    types: usize,
+    chars: usize,
+}
+
+impl TzifCounts {
+    /// The length of the data following the header, with times of `time_len` bytes.
+    fn data_len(self, time_len: usize) -> usize {
+        self.time * time_len
+            + self.time
+            + self.types * 6
+            + self.chars
+            + self.leap * (time_len + 4)
+            + self.is_std
+            + self.is_ut
+    }
+}
+
+/// A reader of TZif files.
+#[derive(Debug)]
+struct TzifReader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> TzifReader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.input.get(self.position..self.position + len)?;
+        self.position += len;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.input[self.position..]
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        let bytes = self.take(4)?;
+        Some(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        let bytes = self.take(8)?;
+        let mut array = [0; 8];
+        array.copy_from_slice(bytes);
+        Some(i64::from_be_bytes(array))
+    }
+
+    fn count(&mut self) -> Option<usize> {
+        let count = self.i32()?;
+        if count >= 0 {
+            Some(count as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Reads a header, returning the version and the counts.
+    fn header(&mut self) -> Option<(u8, TzifCounts)> {
+        if self.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = match self.u8()? {
+            0 => 0,
+            byte @ b'2'..=b'9' => byte - b'0',
+            _ => return None,
+        };
+        self.skip(15)?;
+
+        Some((
+            version,
+            TzifCounts {
+                is_ut: self.count()?,
+                is_std: self.count()?,
+                leap: self.count()?,
+                time: self.count()?,
+                types: self.count()?,
+                chars: self.count()?,
+            },
+        ))
+    }
+
+    /// Reads the data following a header, with times of `time_len` bytes.
+    fn data(&mut self, counts: TzifCounts, time_len: usize) -> Option<TzifTimeZone> {
+        if counts.types == 0 {
+            return None;
+        }
+
+        let mut transitions = Vec::with_capacity(counts.time);
+        for _ in 0..counts.time {
+            let time = if time_len == 4 {
+                i64::from(self.i32()?)
+            } else {
+                self.i64()?
+            };
+            transitions.push(time as f64 * MS_PER_SECOND);
+        }
+
+        let mut transition_types = Vec::with_capacity(counts.time);
+        for _ in 0..counts.time {
+            let index = usize::from(self.u8()?);
+            if index >= counts.types {
+                return None;
+            }
+            transition_types.push(index);
+        }
+
+        let mut raw_types = Vec::with_capacity(counts.types);
+        for _ in 0..counts.types {
+            let offset = self.i32()?;
+            let _is_dst = self.u8()?;
+            let name_index = usize::from(self.u8()?);
+            raw_types.push((offset, name_index));
+        }
+
+        let names = self.take(counts.chars)?;
+        let types = raw_types
+            .into_iter()
+            .map(|(offset, name_index)| {
+                let name = names.get(name_index..)?;
+                let end = name.iter().position(|&byte| byte == 0)?;
+                Some(LocalTimeType {
+                    offset: f64::from(offset) * MS_PER_SECOND,
+                    name: String::from_utf8(name[..end].to_vec()).ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // Leap seconds are ignored, like they are by ECMAScript time values
+        self.skip(counts.leap * (time_len + 4) + counts.is_std + counts.is_ut)?;
+
+        Some(TzifTimeZone {
+            transitions,
+            transition_types,
+            types,
+            footer: None,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! The `AggregateError` object represents an error when several errors need to be wrapped in a single error.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{
+        array,
+        error::{
+            hidden_property, inherit_from_error, initialize_error, native_error_prototype,
+            new_error_object,
+        },
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use std::ops::Deref;
+
+/// Create a new `AggregateError` object.
+///
+/// The first argument is the list of errors, the `message` and `options` come after it.
+pub fn make_error(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    initialize_error(this, args.get(1), args.get(2), ctx);
+
+    let errors = args.get(0).cloned().unwrap_or_else(undefined);
+    let errors_list = match *errors.deref() {
+        ValueData::Object(ref obj) if obj.borrow().kind == ObjectKind::Array => {
+            let length: i32 = from_value(errors.get_field_slice("length")).unwrap_or(0);
+            (0..length)
+                .map(|idx| errors.get_field_slice(&idx.to_string()))
+                .collect::<Vec<Value>>()
+        }
+        _ => return ctx.throw_type_error(format!("{} is not iterable", errors)),
+    };
+
+    let errors_array = array::new_array(ctx)?;
+    array::add_to_array_object(&errors_array, &errors_list)?;
+    this.set_prop_slice("errors", hidden_property(errors_array));
+
+    Ok(this.clone())
+}
+
+/// Calling `AggregateError()` as a function creates a new error object, like `new AggregateError()`.
+pub fn call_error(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut error = new_error_object(ctx, "AggregateError");
+    make_error(&mut error, args, ctx)
+}
+
+/// Create a new `AggregateError` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = native_error_prototype(global, "AggregateError");
+
+    let error = make_constructor_fn!(make_error, call_error, global, prototype);
+    error.set_field_slice("length", to_value(2_i32));
+    inherit_from_error(global, &error);
+    error
+}
+
+/// Initialise the global object with the `AggregateError` object.
+pub fn init(global: &Value) {
+    global.set_field_slice("AggregateError", create(global));
+}
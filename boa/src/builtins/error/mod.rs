@@ -0,0 +1,212 @@
+//! This module implements the global `Error` object.
+//!
+//! Error objects are thrown when runtime errors occur.
+//! The Error object can also be used as a base object for user-defined exceptions.
+//!
+//! The native error constructors (`TypeError`, `RangeError`...) live in the submodules,
+//! their prototypes inherit from `Error.prototype`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
+
+#[cfg(test)]
+mod tests;
+
+pub mod aggregate;
+pub mod eval;
+pub mod range;
+pub mod reference;
+pub mod syntax;
+pub mod r#type;
+pub mod uri;
+
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, INSTANCE_PROTOTYPE, PROTOTYPE},
+        property::Property,
+        value::{to_value, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Creates a new, uninitialised error object whose prototype is `<name>.prototype`.
+///
+/// This is used when an error constructor is called as a function and by the engine
+/// when it needs to throw an error.
+pub(crate) fn new_error_object(ctx: &Interpreter, name: &str) -> Value {
+    let prototype = ctx
+        .realm
+        .global_obj
+        .get_field_slice(name)
+        .get_field_slice(PROTOTYPE);
+
+    let error = to_value(Object::create(prototype));
+    error.set_kind(ObjectKind::Error);
+    error
+}
+
+/// Initialises `this` as an error object with the given `message` and `options` arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-error-message
+pub(crate) fn initialize_error(
+    this: &Value,
+    message: Option<&Value>,
+    options: Option<&Value>,
+    ctx: &mut Interpreter,
+) {
+    // This value is used by console.log and other routines to match Object type
+    // to its Javascript Identifier (global constructor method name)
+    this.set_kind(ObjectKind::Error);
+
+    if let Some(message) = message {
+        if !message.is_undefined() {
+            let message = ctx.to_string(message);
+            this.set_prop_slice("message", hidden_property(message));
+        }
+    }
+
+    install_error_cause(this, options, ctx);
+    install_stack(this, ctx);
+}
+
+/// Creates an own `stack` property on the error, with the stack trace of the current call frames.
+///
+/// Like in V8, the stack starts with the string representation of the error,
+/// followed by one `    at fn (file:line:column)` line per frame.
+fn install_stack(this: &Value, ctx: &mut Interpreter) {
+    let mut stack = match to_string(&mut this.clone(), &[], ctx) {
+        Ok(header) => ctx.value_to_rust_string(&header),
+        Err(_) => String::from("Error"),
+    };
+
+    let trace = ctx.stack_trace();
+    if !trace.is_empty() {
+        stack.push('\n');
+        stack.push_str(&trace);
+    }
+
+    this.set_prop_slice("stack", hidden_property(to_value(stack)));
+}
+
+/// The abstract operation `InstallErrorCause`.
+///
+/// Creates an own `cause` property on the error when the options object has one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-installerrorcause
+fn install_error_cause(this: &Value, options: Option<&Value>, ctx: &mut Interpreter) {
+    if let Some(options) = options {
+        if options.is_object() && ctx.has_property(options, &to_value("cause")) == Ok(true) {
+            let cause = options.get_field_slice("cause");
+            this.set_prop_slice("cause", hidden_property(cause));
+        }
+    }
+}
+
+/// Builds a writable, configurable but non-enumerable data property, as used for `message` and `cause`.
+pub(crate) fn hidden_property(value: Value) -> Property {
+    Property::default()
+        .value(value)
+        .writable(true)
+        .enumerable(false)
+        .configurable(true)
+}
+
+/// Creates the prototype of a native error, which inherits from `Error.prototype`.
+pub(crate) fn native_error_prototype(global: &Value, name: &str) -> Value {
+    let error_prototype = global.get_field_slice("Error").get_field_slice(PROTOTYPE);
+
+    let prototype = to_value(Object::create(error_prototype));
+    prototype.set_field_slice("message", to_value(""));
+    prototype.set_field_slice("name", to_value(name));
+    prototype
+}
+
+/// Makes a native error constructor inherit from the `Error` constructor.
+pub(crate) fn inherit_from_error(global: &Value, constructor: &Value) {
+    constructor.set_internal_slot(INSTANCE_PROTOTYPE, global.get_field_slice("Error"));
+}
+
+/// Create a new error object.
+pub fn make_error(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    initialize_error(this, args.get(0), args.get(1), ctx);
+    Ok(this.clone())
+}
+
+/// Calling `Error()` as a function creates a new error object, like `new Error()`.
+pub fn call_error(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut error = new_error_object(ctx, "Error");
+    make_error(&mut error, args, ctx)
+}
+
+/// `Error.prototype.toString()`
+///
+/// The toString() method returns a string representing the specified Error object.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-error.prototype.tostring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/toString
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    if !this.is_object() {
+        return ctx.throw_type_error("Error.prototype.toString called on a non-object");
+    }
+
+    let name = this.get_field_slice("name");
+    let name = if name.is_undefined() {
+        String::from("Error")
+    } else {
+        ctx.value_to_rust_string(&name)
+    };
+
+    let message = this.get_field_slice("message");
+    let message = if message.is_undefined() {
+        String::new()
+    } else {
+        ctx.value_to_rust_string(&message)
+    };
+
+    if name.is_empty() {
+        Ok(to_value(message))
+    } else if message.is_empty() {
+        Ok(to_value(name))
+    } else {
+        Ok(to_value(format!("{}: {}", name, message)))
+    }
+}
+
+/// Create a new `Error` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    prototype.set_field_slice("message", to_value(""));
+    prototype.set_field_slice("name", to_value("Error"));
+    make_builtin_fn!(to_string, named "toString", of prototype);
+
+    let error = make_constructor_fn!(make_error, call_error, global, prototype);
+    error.set_field_slice("length", to_value(1_i32));
+    error
+}
+
+/// Initialise the global object with the `Error` object and the native error constructors.
+pub fn init(global: &Value) {
+    global.set_field_slice("Error", create(global));
+
+    eval::init(global);
+    range::init(global);
+    reference::init(global);
+    syntax::init(global);
+    r#type::init(global);
+    uri::init(global);
+    aggregate::init(global);
+}
@@ -0,0 +1,46 @@
+//! This module implements the global `SyntaxError` object.
+//!
+//! The `SyntaxError` object represents an error when trying to interpret syntactically invalid code.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError
+
+use crate::{
+    builtins::{
+        error::{inherit_from_error, initialize_error, native_error_prototype, new_error_object},
+        object::{Object, ObjectInternalMethods, PROTOTYPE},
+        value::{to_value, ResultValue, Value},
+    },
+    exec::Interpreter,
+};
+
+/// Create a new `SyntaxError` object.
+pub fn make_error(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    initialize_error(this, args.get(0), args.get(1), ctx);
+    Ok(this.clone())
+}
+
+/// Calling `SyntaxError()` as a function creates a new error object, like `new SyntaxError()`.
+pub fn call_error(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut error = new_error_object(ctx, "SyntaxError");
+    make_error(&mut error, args, ctx)
+}
+
+/// Create a new `SyntaxError` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = native_error_prototype(global, "SyntaxError");
+
+    let error = make_constructor_fn!(make_error, call_error, global, prototype);
+    error.set_field_slice("length", to_value(1_i32));
+    inherit_from_error(global, &error);
+    error
+}
+
+/// Initialise the global object with the `SyntaxError` object.
+pub fn init(global: &Value) {
+    global.set_field_slice("SyntaxError", create(global));
+}
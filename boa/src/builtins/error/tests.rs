@@ -0,0 +1,172 @@
+use super::*;
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct_and_call() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var constructed = new Error('boom');
+        var called = Error('bang');
+        var empty = new Error();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "constructed.message"), "boom");
+    assert_eq!(forward(&mut engine, "constructed.name"), "Error");
+    assert_eq!(forward(&mut engine, "called.message"), "bang");
+    assert_eq!(forward(&mut engine, "empty.message"), "");
+    assert_eq!(forward(&mut engine, "typeof Error"), "function");
+}
+
+#[test]
+fn to_string() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "new Error('boom').toString()"),
+        "Error: boom"
+    );
+    assert_eq!(forward(&mut engine, "new Error().toString()"), "Error");
+    assert_eq!(
+        forward(&mut engine, "new RangeError('out of range').toString()"),
+        "RangeError: out of range"
+    );
+}
+
+#[test]
+fn native_errors() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    for name in &[
+        "EvalError",
+        "RangeError",
+        "ReferenceError",
+        "SyntaxError",
+        "TypeError",
+        "URIError",
+    ] {
+        let constructed = format!("new {}('boom')", name);
+        let called = format!("{}('bang')", name);
+        assert_eq!(
+            forward(&mut engine, &format!("{}.name", constructed)),
+            *name
+        );
+        assert_eq!(
+            forward(&mut engine, &format!("{}.message", constructed)),
+            "boom"
+        );
+        assert_eq!(forward(&mut engine, &format!("{}.name", called)), *name);
+        assert_eq!(forward(&mut engine, &format!("{}.message", called)), "bang");
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!("Object.getPrototypeOf({}.prototype).name", name)
+            ),
+            "Error"
+        );
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!("{}.hasOwnProperty('message')", constructed)
+            ),
+            "true"
+        );
+    }
+}
+
+#[test]
+fn cause() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var withCause = new TypeError('boom', { cause: 'reason' });
+        var withoutCause = new TypeError('boom', {});
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "withCause.cause"), "reason");
+    assert_eq!(
+        forward(&mut engine, "withoutCause.hasOwnProperty('cause')"),
+        "false"
+    );
+}
+
+#[test]
+fn aggregate_error() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var aggregate = new AggregateError([new TypeError('a'), new RangeError('b')], 'many');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "aggregate.name"), "AggregateError");
+    assert_eq!(forward(&mut engine, "aggregate.message"), "many");
+    assert_eq!(forward(&mut engine, "aggregate.errors.length"), "2");
+    assert_eq!(
+        forward(&mut engine, "aggregate.errors[1].name"),
+        "RangeError"
+    );
+}
+
+#[test]
+fn throw_helpers() {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+
+    let error = engine
+        .throw_type_error("not a function")
+        .expect_err("throw_type_error must return an error");
+    assert_eq!(error.get_field_slice("name").to_string(), "TypeError");
+    assert_eq!(
+        error.get_field_slice("message").to_string(),
+        "not a function"
+    );
+
+    let error = engine
+        .throw_range_error("invalid length")
+        .expect_err("throw_range_error must return an error");
+    assert_eq!(error.get_field_slice("name").to_string(), "RangeError");
+    assert!(error.is_object());
+}
+
+#[test]
+fn stack() {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    let init = r#"function inner() {
+    return new Error('boom');
+}
+function outer() {
+    return inner();
+}
+var error = outer();
+"#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "error.stack"),
+        "Error: boom\n    at inner (<anonymous>:2:12)\n    at outer (<anonymous>:5:12)\n    at <anonymous>:7:13"
+    );
+    assert_eq!(
+        forward(&mut engine, "error.hasOwnProperty('stack')"),
+        "true"
+    );
+    assert!(engine.call_frames().is_empty());
+}
+
+#[test]
+fn stack_of_engine_errors() {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    engine.set_script_name("main.js");
+    let src = r#"function f() { return undefined.x; }
+try { f(); } catch (e) { e.stack }"#;
+    assert_eq!(
+        forward(&mut engine, src),
+        "TypeError: Cannot read property 'x' of undefined\n    at f (main.js:1:23)\n    at main.js:2:7"
+    );
+    assert_eq!(
+        forward(&mut engine, "try {\n  null.x;\n} catch (e) { e.stack }"),
+        "TypeError: Cannot read property 'x' of null\n    at main.js:2:3"
+    );
+}
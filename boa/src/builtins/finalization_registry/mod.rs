@@ -0,0 +1,236 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` object lets you request a callback when an object is garbage-collected.
+//!
+//! The registry doesn't keep its targets alive. Once a target has been collected, a cleanup job,
+//! calling the callback with the held value of the target, is queued by `Interpreter::run_jobs`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, WeakHandle, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::{Interpreter, Job},
+};
+use gc::{Finalize, Trace};
+
+/// A target registered in a `FinalizationRegistry` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct RegistryCell {
+    /// The registered object.
+    target: WeakHandle,
+
+    /// The value passed to the cleanup callback once the target has been collected.
+    held_value: Value,
+
+    /// The object used to unregister the target, if any.
+    unregister_token: Option<WeakHandle>,
+}
+
+/// The state of a `FinalizationRegistry` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    /// The function called with the held value of each collected target.
+    cleanup: Value,
+
+    /// The registered targets.
+    cells: Vec<RegistryCell>,
+}
+
+/// Calls `f` with the state of a `FinalizationRegistry` object, or returns `None` if `this` is not one.
+///
+/// The object is borrowed during the call, so `f` must not run any JavaScript code.
+fn with_registry<R, F: FnOnce(&mut FinalizationRegistry) -> R>(this: &Value, f: F) -> Option<R> {
+    match **this {
+        ValueData::Object(ref obj) => match obj.borrow_mut().kind {
+            ObjectKind::FinalizationRegistry(ref mut registry) => Some(f(registry)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Throws a `TypeError` for a `FinalizationRegistry.prototype` method called on something else.
+fn throw_incompatible_receiver(this: &Value, method: &str, ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error(format!(
+        "Method FinalizationRegistry.prototype.{} called on incompatible receiver {}",
+        method, this
+    ))
+}
+
+/// Queues a cleanup job for each collected target of the live `FinalizationRegistry` objects.
+pub(crate) fn enqueue_cleanup_jobs(ctx: &mut Interpreter) {
+    for registry in ctx.finalization_registries() {
+        let collected = with_registry(&registry, |registry| {
+            let mut held_values = Vec::new();
+            registry.cells.retain(|cell| {
+                if cell.target.is_alive() {
+                    true
+                } else {
+                    held_values.push(cell.held_value.clone());
+                    false
+                }
+            });
+            (registry.cleanup.clone(), held_values)
+        });
+
+        if let Some((cleanup, held_values)) = collected {
+            for held_value in held_values {
+                ctx.enqueue_job(Job::new(cleanup.clone(), undefined(), vec![held_value]));
+            }
+        }
+    }
+}
+
+/// Create a new `FinalizationRegistry`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+pub fn make_finalization_registry(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let cleanup = args.get(0).cloned().unwrap_or_else(undefined);
+    if !cleanup.is_function() {
+        return ctx.throw_type_error("FinalizationRegistry: cleanup must be callable");
+    }
+
+    this.set_kind(ObjectKind::FinalizationRegistry(FinalizationRegistry {
+        cleanup,
+        cells: Vec::new(),
+    }));
+
+    if let Some(handle) = WeakHandle::new(this) {
+        ctx.register_finalization_registry(handle);
+    }
+    Ok(this.clone())
+}
+
+/// `FinalizationRegistry` can only be called as a constructor.
+pub fn call_finalization_registry(
+    _: &mut Value,
+    _: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    ctx.throw_type_error("Constructor FinalizationRegistry requires 'new'")
+}
+
+/// `FinalizationRegistry.prototype.register( target, heldValue [, unregisterToken ] )`
+///
+/// The `register()` method registers an object with a `FinalizationRegistry` instance, so that
+/// the cleanup callback is called with `heldValue` once the object is garbage-collected.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+pub fn register(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    let held_value = args.get(1).cloned().unwrap_or_else(undefined);
+    let unregister_token = args.get(2).cloned().unwrap_or_else(undefined);
+
+    let target = match WeakHandle::new(&target) {
+        Some(target) => target,
+        None => {
+            return ctx.throw_type_error(
+                "FinalizationRegistry.prototype.register: target must be an object",
+            )
+        }
+    };
+    if target.points_to(&held_value) {
+        return ctx.throw_type_error(
+            "FinalizationRegistry.prototype.register: target and held value must not be the same",
+        );
+    }
+    let unregister_token = match WeakHandle::new(&unregister_token) {
+        Some(token) => Some(token),
+        None if unregister_token.is_undefined() => None,
+        None => {
+            return ctx.throw_type_error(
+                "FinalizationRegistry.prototype.register: unregisterToken must be an object",
+            )
+        }
+    };
+
+    let cell = RegistryCell {
+        target,
+        held_value,
+        unregister_token,
+    };
+    match with_registry(this, |registry| registry.cells.push(cell)) {
+        Some(()) => Ok(undefined()),
+        None => throw_incompatible_receiver(this, "register", ctx),
+    }
+}
+
+/// `FinalizationRegistry.prototype.unregister( unregisterToken )`
+///
+/// The `unregister()` method unregisters the targets registered with `unregisterToken`.
+///
+/// Returns `true` if at least one target has been unregistered.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+pub fn unregister(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let token = args.get(0).cloned().unwrap_or_else(undefined);
+    if !token.is_object() {
+        return ctx.throw_type_error(
+            "FinalizationRegistry.prototype.unregister: unregisterToken must be an object",
+        );
+    }
+
+    let removed = with_registry(this, |registry| {
+        let count = registry.cells.len();
+        registry.cells.retain(|cell| match cell.unregister_token {
+            Some(ref unregister_token) => !unregister_token.points_to(&token),
+            None => true,
+        });
+        registry.cells.len() != count
+    });
+    match removed {
+        Some(removed) => Ok(to_value(removed)),
+        None => throw_incompatible_receiver(this, "unregister", ctx),
+    }
+}
+
+/// Create a new `FinalizationRegistry` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(register, named "register", with length 2, of prototype);
+    make_builtin_fn!(unregister, named "unregister", with length 1, of prototype);
+
+    make_constructor_fn!(
+        make_finalization_registry,
+        call_finalization_registry,
+        global,
+        prototype
+    )
+}
+
+/// Initialise the `FinalizationRegistry` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("FinalizationRegistry", create(global));
+}
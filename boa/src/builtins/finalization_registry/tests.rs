@@ -0,0 +1,67 @@
+use crate::exec::{Executor, Interpreter};
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn cleanup_after_collection() {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(function (held) {
+            cleaned.push(held);
+        });
+        var first = {};
+        var second = {};
+        var token = {};
+        registry.register(first, 'first');
+        registry.register(second, 'second', token);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "cleaned.length"), "0");
+
+    // The callback runs in a job, once the collection has been noticed
+    forward(&mut engine, "first = null");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "cleaned.length"), "0");
+    engine.run_jobs().expect("the cleanup callback threw");
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "first");
+
+    // Unregistered targets are not cleaned up
+    assert_eq!(forward(&mut engine, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut engine, "registry.unregister(token)"), "false");
+    forward(&mut engine, "second = null");
+    gc::force_collect();
+    engine.run_jobs().expect("the cleanup callback threw");
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "first");
+}
+
+#[test]
+fn invalid_arguments() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var registry = new FinalizationRegistry(function () {});
+        var target = {};
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    for src in &[
+        "new FinalizationRegistry(1)",
+        "FinalizationRegistry(function () {})",
+        "registry.register(1, 'held')",
+        "registry.register(target, target)",
+        "registry.register(target, 'held', 1)",
+        "registry.unregister(1)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", src)
+            ),
+            "TypeError",
+            "{}",
+            src
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Objects wrap `Function`s and expose them via call/construct slots.
 //!
-//! `The `Function` object is used for matching text with a pattern.
+//! Every `Function` object is a function, and `Function.prototype` provides the
+//! `call`, `apply` and `bind` methods to all of them.
 //!
 //! More information:
 //!  - [ECMAScript reference][spec]
@@ -11,20 +12,36 @@
 //! [spec]: https://tc39.es/ecma262/#sec-function-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function
 
+#[cfg(test)]
+mod tests;
+
 use crate::{
     builtins::{
         array,
-        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        object::{
+            InternalObjectMethods, Object, ObjectInternalMethods, ObjectKind, INSTANCE_PROTOTYPE,
+            ORDINARY_INTERNAL_METHODS, PROTOTYPE,
+        },
         property::Property,
-        value::{to_value, undefined, ResultValue, Value, ValueData},
+        value::{same_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
     },
     environment::lexical_environment::{new_function_environment, Environment},
     exec::Executor,
-    syntax::ast::node::{FormalParameter, Node},
+    syntax::{
+        ast::{
+            node::{FormalParameter, Node, NodeKind},
+            pos::{Position, Span},
+        },
+        lexer::Lexer,
+        parser::Parser,
+    },
     Interpreter,
 };
-use gc::{unsafe_empty_trace, Finalize, Trace};
-use std::fmt::{self, Debug};
+use gc::{custom_trace, unsafe_empty_trace, Finalize, Gc, Trace};
+use std::{
+    fmt::{self, Debug},
+    rc::Rc,
+};
 
 /// _fn(this, arguments, ctx) -> ResultValue_ - The signature of a built-in function
 pub type NativeFunctionData = fn(&mut Value, &[Value], &mut Interpreter) -> ResultValue;
@@ -45,17 +62,65 @@ pub enum ThisMode {
     NonLexical,
 }
 
+/// A Rust closure serving as the body of a built-in function, see `FunctionBody::closure`.
+pub trait NativeClosure: Trace {
+    /// Calls the closure with the values it captured.
+    fn call(&self, this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue;
+}
+
+/// A closure with the values it captures, which are traced by the garbage collector.
+struct Closure<F, T> {
+    function: F,
+    captures: T,
+}
+
+impl<F, T: Trace> Finalize for Closure<F, T> {}
+
+/// The closure itself is `Copy`, so it can't hold any GC'd objects: only the captures are traced.
+unsafe impl<F, T: Trace> Trace for Closure<F, T> {
+    custom_trace!(this, mark(&this.captures));
+}
+
+impl<F, T> NativeClosure for Closure<F, T>
+where
+    F: Fn(&mut Value, &[Value], &T, &mut Interpreter) -> ResultValue + Copy,
+    T: Trace,
+{
+    fn call(&self, this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        (self.function)(this, args, &self.captures, ctx)
+    }
+}
+
 /// FunctionBody is specific to this interpreter, it will either be Rust code or JavaScript code (AST Node)
 #[derive(Clone, Finalize)]
 pub enum FunctionBody {
     BuiltIn(NativeFunctionData),
+    /// A Rust closure with the values it captures, created with `FunctionBody::closure`.
+    Closure(Gc<Box<dyn NativeClosure>>),
     Ordinary(Node),
 }
 
+impl FunctionBody {
+    /// Creates the body of a built-in function from a Rust closure, which gets the values it
+    /// captures as its third argument: _fn(this, arguments, captures, ctx) -> ResultValue_.
+    ///
+    /// The closure must be `Copy`, so it can't hold any `Value` itself: the garbage collector
+    /// couldn't see it. The values it needs are passed in `captures`, which are traced like the
+    /// rest of the function, and can be shared with the host in a `Gc<GcCell<_>>` to be mutated.
+    pub fn closure<F, T>(function: F, captures: T) -> Self
+    where
+        F: Fn(&mut Value, &[Value], &T, &mut Interpreter) -> ResultValue + Copy + 'static,
+        T: Trace + 'static,
+    {
+        let closure: Box<dyn NativeClosure> = Box::new(Closure { function, captures });
+        Self::Closure(Gc::new(closure))
+    }
+}
+
 impl Debug for FunctionBody {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::BuiltIn(_) => write!(f, "native code"),
+            Self::BuiltIn(_) | Self::Closure(_) => write!(f, "native code"),
             Self::Ordinary(node) => write!(f, "{}", node),
         }
     }
@@ -63,13 +128,17 @@ impl Debug for FunctionBody {
 
 /// `Trace` implementation for `FunctionBody`.
 ///
-/// This is indeed safe, but we need to mark this as an empty trace because neither
-// `NativeFunctionData` nor Node hold any GC'd objects, but Gc doesn't know that. So we need to
-/// signal it manually. `rust-gc` does not have a `Trace` implementation for `fn(_, _, _)`.
+/// Neither `NativeFunctionData` nor Node hold any GC'd objects, but Gc doesn't know that, so only
+/// the captures of closures are traced. `rust-gc` does not have a `Trace` implementation for
+/// `fn(_, _, _)`.
 ///
 /// <https://github.com/Manishearth/rust-gc/blob/master/gc/src/trace.rs>
 unsafe impl Trace for FunctionBody {
-    unsafe_empty_trace!();
+    custom_trace!(this, {
+        if let FunctionBody::Closure(ref closure) = *this {
+            mark(closure);
+        }
+    });
 }
 
 /// Signal what sort of function this is
@@ -99,6 +168,11 @@ pub struct Function {
     pub kind: FunctionKind,
     // Environment, built-in functions don't need Environments
     pub environment: Option<Environment>,
+    /// The source code the function was parsed from, built-in functions don't have one
+    #[unsafe_ignore_trace]
+    pub source: Option<Rc<str>>,
+    /// The span of the function in its source code
+    pub span: Span,
 }
 
 impl Function {
@@ -120,6 +194,8 @@ impl Function {
             params: parameter_list.into(),
             kind: FunctionKind::Ordinary,
             this_mode,
+            source: None,
+            span: Span::default(),
         }
     }
 
@@ -136,7 +212,28 @@ impl Function {
             this_mode: ThisMode::NonLexical,
             kind: FunctionKind::BuiltIn,
             environment: None,
+            source: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Sets the source code the function was parsed from, and the span of the function in it.
+    pub fn with_source(mut self, source: Option<Rc<str>>, span: Span) -> Self {
+        self.source = source;
+        self.span = span;
+        self
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        let source = self.source.as_ref()?;
+        // Nodes built by hand have an empty span at line 0
+        if self.span.start.line_number == 0 {
+            return None;
         }
+        let start = source_offset(source, self.span.start)?;
+        let end = source_offset(source, self.span.end)?;
+        source.get(start..end)
     }
 
     /// This will handle calls for both ordinary and built-in functions
@@ -153,6 +250,7 @@ impl Function {
         match self.kind {
             FunctionKind::BuiltIn => match &self.body {
                 FunctionBody::BuiltIn(func) => func(this_obj, args_list, interpreter),
+                FunctionBody::Closure(closure) => closure.call(this_obj, args_list, interpreter),
                 FunctionBody::Ordinary(_) => {
                     panic!("Builtin function should not have Ordinary Function body")
                 }
@@ -160,9 +258,10 @@ impl Function {
             FunctionKind::Ordinary => {
                 // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                 // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
+                // A call has no new target, only a construct does
                 let local_env = new_function_environment(
                     this.clone(),
-                    this_obj.clone(),
+                    undefined(),
                     Some(self.environment.as_ref().unwrap().clone()),
                 );
 
@@ -175,8 +274,9 @@ impl Function {
                         break;
                     }
 
-                    let value = args_list.get(i).expect("Could not get value");
-                    self.add_arguments_to_environment(param, value.clone(), &local_env);
+                    // Missing arguments are `undefined`
+                    let value = args_list.get(i).cloned().unwrap_or_else(undefined);
+                    self.add_arguments_to_environment(param, value, &local_env);
                 }
 
                 // Add arguments object
@@ -189,6 +289,7 @@ impl Function {
                     .initialize_binding("arguments", arguments_obj);
 
                 interpreter.realm.environment.push(local_env);
+                let caller_source = interpreter.replace_source_text(self.source.clone());
 
                 // Call body should be set before reaching here
                 let result = match &self.body {
@@ -197,6 +298,7 @@ impl Function {
                 };
 
                 // local_env gets dropped here, its no longer needed
+                interpreter.replace_source_text(caller_source);
                 interpreter.realm.environment.pop();
                 result
             }
@@ -213,6 +315,7 @@ impl Function {
         match self.kind {
             FunctionKind::BuiltIn => match &self.body {
                 FunctionBody::BuiltIn(func) => func(this_obj, args_list, interpreter),
+                FunctionBody::Closure(closure) => closure.call(this_obj, args_list, interpreter),
                 FunctionBody::Ordinary(_) => {
                     panic!("Builtin function should not have Ordinary Function body")
                 }
@@ -234,8 +337,9 @@ impl Function {
                         break;
                     }
 
-                    let value = args_list.get(i).expect("Could not get value");
-                    self.add_arguments_to_environment(param, value.clone(), &local_env);
+                    // Missing arguments are `undefined`
+                    let value = args_list.get(i).cloned().unwrap_or_else(undefined);
+                    self.add_arguments_to_environment(param, value, &local_env);
                 }
 
                 // Add arguments object
@@ -248,6 +352,7 @@ impl Function {
                     .initialize_binding("arguments", arguments_obj);
 
                 interpreter.realm.environment.push(local_env);
+                let caller_source = interpreter.replace_source_text(self.source.clone());
 
                 // Call body should be set before reaching here
                 let result = match &self.body {
@@ -256,6 +361,7 @@ impl Function {
                 };
 
                 // local_env gets dropped here, its no longer needed
+                interpreter.replace_source_text(caller_source);
                 interpreter.realm.environment.pop();
                 result
             }
@@ -273,7 +379,9 @@ impl Function {
     ) {
         // Create array of values
         let array = array::new_array(interpreter).unwrap();
-        array::add_to_array_object(&array, &args_list[index..]).unwrap();
+        // There may be fewer arguments than parameters
+        let rest = args_list.get(index..).unwrap_or(&[]);
+        array::add_to_array_object(&array, rest).unwrap();
 
         // Create binding
         local_env
@@ -313,16 +421,6 @@ impl Debug for Function {
     }
 }
 
-/// Function Prototype.
-///
-/// <https://tc39.es/ecma262/#sec-properties-of-the-function-prototype-object>
-pub fn create_function_prototype() {
-    let mut function_prototype: Object = Object::default();
-    // Set Kind to function (for historical & compatibility reasons)
-    // <https://tc39.es/ecma262/#sec-properties-of-the-function-prototype-object>
-    function_prototype.kind = ObjectKind::Function;
-}
-
 /// Arguments.
 ///
 /// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
@@ -334,7 +432,7 @@ pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
     let mut length = Property::default();
     length = length.writable(true).value(to_value(len));
     // Define length as a property
-    obj.define_own_property("length".to_string(), length);
+    obj.define_own_property(JsString::from("length"), length);
     let mut index: usize = 0;
     while index < len {
         let val = arguments_list.get(index).expect("Could not get argument");
@@ -345,25 +443,386 @@ pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
             .writable(true)
             .configurable(true);
 
-        obj.properties.insert(index.to_string(), prop);
+        obj.properties
+            .insert(JsString::from(index.to_string()), prop);
         index += 1;
     }
 
     to_value(obj)
 }
 
-/// Create new function `[[Construct]]`
+/// Gets the byte offset of a position in the source code.
+///
+/// Lines and columns start at 1, and columns count characters.
+fn source_offset(source: &str, pos: Position) -> Option<usize> {
+    let line_start = match pos.line_number {
+        0 => return None,
+        1 => 0,
+        line => source.match_indices('\n').nth((line - 2) as usize)?.0 + 1,
+    };
+    let column = pos.column_number.checked_sub(1)? as usize;
+    source[line_start..]
+        .char_indices()
+        .map(|(index, _)| line_start + index)
+        .chain(std::iter::once(source.len()))
+        .nth(column)
+}
+
+/// Defines the `name` and `length` properties of a function object.
+///
+/// Both are configurable, but neither writable nor enumerable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
+pub(crate) fn set_function_name_and_length(function: &Value, name: Value, length: f64) {
+    for (key, value) in [("length", to_value(length)), ("name", name)].iter() {
+        function.set_prop_slice(
+            key,
+            Property::default()
+                .value(value.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+        );
+    }
+}
+
+/// Gets the name of a function keyed by a symbol, like `[Symbol.iterator]`.
+pub(crate) fn symbol_function_name(key: &Value) -> String {
+    let description = key.get_internal_slot("Description");
+    if description.is_undefined() {
+        String::new()
+    } else {
+        format!("[{}]", description)
+    }
+}
+
+/// The number of parameters a function expects, up to the first one with a default value or the rest parameter.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-expectedargumentcount
+fn expected_argument_count(params: &[FormalParameter]) -> usize {
+    params
+        .iter()
+        .take_while(|param| param.init.is_none() && !param.is_rest_param)
+        .count()
+}
+
+/// Creates the function object of a function parsed from source code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryfunctioncreate
+pub(crate) fn create_function_object(
+    ctx: &Interpreter,
+    params: &[FormalParameter],
+    body: &Node,
+    this_mode: ThisMode,
+    name: &str,
+    scope: Environment,
+    span: Span,
+) -> Value {
+    let func = Function::create_ordinary(
+        params.to_vec(),
+        scope,
+        FunctionBody::Ordinary(body.clone()),
+        this_mode,
+    )
+    .with_source(ctx.source_text(), span);
+
+    let mut new_func = Object::function();
+    new_func.set_call(func);
+    new_func.set_internal_slot(
+        INSTANCE_PROTOTYPE,
+        ctx.realm
+            .global_obj
+            .get_field_slice("Function")
+            .get_field_slice(PROTOTYPE),
+    );
+    let val = to_value(new_func);
+    set_function_name_and_length(&val, to_value(name), expected_argument_count(params) as f64);
+    val
+}
+
+/// The data of bound function exotic objects, created by `Function.prototype.bind()`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct BoundFunctionData {
+    /// The wrapped function
+    pub target: Value,
+    /// The `this` value the target is always called with
+    pub this: Value,
+    /// The arguments passed before the ones of the call
+    pub args: Vec<Value>,
+}
+
+/// The internal methods of bound functions whose target isn't a constructor.
+static BOUND_FUNCTION_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    call: Some(bound_function_call),
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+/// The internal methods of bound functions whose target is a constructor.
+static BOUND_CONSTRUCTOR_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    call: Some(bound_function_call),
+    construct: Some(bound_function_construct),
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+/// Gets the data of a bound function.
+fn bound_function_data(function: &Value) -> BoundFunctionData {
+    match function.as_object().map(|obj| obj.kind.clone()) {
+        Some(ObjectKind::BoundFunction(ref data)) => data.clone(),
+        _ => panic!("Value is not a bound function"),
+    }
+}
+
+/// `[[Call]]` of bound functions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist
+fn bound_function_call(
+    function: &Value,
+    _: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let data = bound_function_data(function);
+    let mut bound_args = data.args.clone();
+    bound_args.extend_from_slice(args);
+    ctx.call(&data.target, &mut data.this.clone(), &bound_args)
+}
+
+/// `[[Construct]]` of bound functions, the bound `this` is ignored.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
 ///
-// This gets called when a new Function() is created.
-pub fn make_function(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.set_kind(ObjectKind::Function);
-    Ok(this.clone())
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget
+fn bound_function_construct(
+    function: &Value,
+    args: &[Value],
+    new_target: &Value,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let data = bound_function_data(function);
+    let target = data.target.clone();
+    let mut bound_args = data.args.clone();
+    bound_args.extend_from_slice(args);
+    let new_target = if same_value(function, new_target, false) {
+        target.clone()
+    } else {
+        new_target.clone()
+    };
+    ctx.construct(&target, &bound_args, &new_target)
 }
 
+/// Checks that the `this` value of a `Function.prototype` method is a function.
+fn this_function(this: &Value, method: &str, ctx: &mut Interpreter) -> Result<(), Value> {
+    if this.is_function() {
+        Ok(())
+    } else {
+        Err(ctx.construct_type_error(format!(
+            "Function.prototype.{} called on non-function {}",
+            method, this
+        )))
+    }
+}
+
+/// `Function.prototype.apply( thisArg, argsArray )`
+///
+/// Calls the function with the given `this` value, and the arguments of an array-like object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.apply
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply
+pub fn apply(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_function(this, "apply", ctx)?;
+    let mut this_arg = args.get(0).cloned().unwrap_or_else(undefined);
+    let arguments = match args.get(1) {
+        Some(array) if !array.is_null_or_undefined() => ctx.create_list_from_array_like(array)?,
+        _ => Vec::new(),
+    };
+    ctx.call(this, &mut this_arg, &arguments)
+}
+
+/// `Function.prototype.bind( thisArg, ...args )`
+///
+/// Creates a new function which calls this one with the given `this` value,
+/// and the given arguments before the ones it's called with.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+pub fn bind(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_function(this, "bind", ctx)?;
+    let target = this.clone();
+    let bound_args = args.get(1..).unwrap_or(&[]).to_vec();
+
+    let mut bound = Object::function();
+    bound.internal_methods = match target.as_object() {
+        Some(ref target) if target.is_constructor() => &BOUND_CONSTRUCTOR_INTERNAL_METHODS,
+        _ => &BOUND_FUNCTION_INTERNAL_METHODS,
+    };
+    bound.set_internal_slot(INSTANCE_PROTOTYPE, ctx.get_prototype_of(&target)?);
+    bound.kind = ObjectKind::BoundFunction(BoundFunctionData {
+        target: target.clone(),
+        this: args.get(0).cloned().unwrap_or_else(undefined),
+        args: bound_args.clone(),
+    });
+    let bound = to_value(bound);
+
+    let length_key = to_value("length");
+    let length = if ctx.get_own_property(&target, &length_key)?.is_some() {
+        match *ctx.get_property(&target, &length_key)? {
+            ValueData::Rational(length) if length == f64::INFINITY => length,
+            ValueData::Rational(length) if length == f64::NEG_INFINITY => 0.0,
+            ValueData::Rational(_) | ValueData::Integer(_) => {
+                let length = ctx.get_property(&target, &length_key)?;
+                (ctx.to_integer_or_infinity(&length) - bound_args.len() as f64).max(0.0)
+            }
+            _ => 0.0,
+        }
+    } else {
+        0.0
+    };
+    let name = match *ctx.get_property(&target, &to_value("name"))? {
+        ValueData::String(ref name) => format!("bound {}", name),
+        _ => String::from("bound "),
+    };
+    set_function_name_and_length(&bound, to_value(name), length);
+    Ok(bound)
+}
+
+/// `Function.prototype.call( thisArg, ...args )`
+///
+/// Calls the function with the given `this` value and arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.call
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call
+pub fn call(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_function(this, "call", ctx)?;
+    let mut this_arg = args.get(0).cloned().unwrap_or_else(undefined);
+    ctx.call(this, &mut this_arg, args.get(1..).unwrap_or(&[]))
+}
+
+/// `Function.prototype.toString()`
+///
+/// Returns the source text of functions parsed from source code, and a
+/// `function name() { [native code] }` placeholder for the others.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_function(this, "toString", ctx)?;
+    let source_text = this.as_object().and_then(|obj| {
+        obj.call
+            .as_ref()
+            .and_then(|func| func.source_text().map(String::from))
+    });
+    if let Some(source_text) = source_text {
+        return Ok(to_value(source_text));
+    }
+
+    let name = match *ctx.get_property(this, &to_value("name"))? {
+        ValueData::String(ref name) => name.to_string(),
+        _ => String::new(),
+    };
+    Ok(to_value(format!("function {}() {{ [native code] }}", name)))
+}
+
+/// `CreateDynamicFunction`, the `Function( ...params, body )` constructor.
+///
+/// Parses the parameters and the body into a new function, which is created in the global scope.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
+pub fn make_function(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (body, params) = match args.split_last() {
+        Some((body, params)) => (ctx.value_to_rust_string(body), params),
+        None => (String::new(), args),
+    };
+    let params = params
+        .iter()
+        .map(|param| ctx.value_to_rust_string(param))
+        .collect::<Vec<_>>()
+        .join(",");
+    let source = format!("function anonymous({}\n) {{\n{}\n}}", params, body);
+
+    let mut lexer = Lexer::new(&source);
+    if let Err(error) = lexer.lex() {
+        return ctx.throw_syntax_error(error.to_string());
+    }
+    let script = match Parser::new(&lexer.tokens).parse_all() {
+        Ok(script) => script,
+        Err(error) => return ctx.throw_syntax_error(error.to_string()),
+    };
+
+    // The parameters or the body could close the function early and add other statements
+    let function = match script.kind {
+        NodeKind::StatementList(ref list) if list.len() == 1 => &list[0],
+        _ => return ctx.throw_syntax_error("Invalid parameters or body of dynamic function"),
+    };
+    match function.kind {
+        NodeKind::FunctionDecl(_, ref params, ref body) => {
+            let caller_source = ctx.replace_source_text(Some(Rc::from(source.as_str())));
+            let scope = ctx.realm.environment.get_global_environment().clone();
+            let function = create_function_object(
+                ctx,
+                params,
+                body,
+                ThisMode::NonLexical,
+                "anonymous",
+                scope,
+                function.span,
+            );
+            ctx.replace_source_text(caller_source);
+            Ok(function)
+        }
+        _ => ctx.throw_syntax_error("Invalid parameters or body of dynamic function"),
+    }
+}
+
+/// Create a new `Function` object.
 pub fn create(global: &Value) -> Value {
     let prototype = ValueData::new_obj(Some(global));
-
-    make_constructor_fn!(make_function, make_function, global, prototype)
+    make_builtin_fn!(apply, named "apply", with length 2, of prototype);
+    make_builtin_fn!(bind, named "bind", with length 1, of prototype);
+    make_builtin_fn!(call, named "call", with length 1, of prototype);
+    make_builtin_fn!(to_string, named "toString", of prototype);
+
+    let function = make_constructor_fn!(make_function, make_function, global, prototype);
+    function.set_field_slice("length", to_value(1_i32));
+    function
 }
 
 /// Initialise the `Function` object on the global object.
@@ -17,9 +17,186 @@ fn check_arguments_object() {
     eprintln!("{}", forward(&mut engine, init));
     let expected_return_val = 100;
     let return_val = forward_val(&mut engine, "val").expect("value expected");
-    assert_eq!(return_val.is_integer(), true);
+    assert!(return_val.is_integer());
     assert_eq!(
         from_value::<i32>(return_val).expect("Could not convert value to i32"),
         expected_return_val
     );
 }
+
+#[test]
+fn call_and_apply() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "Array.prototype.join.call([1, 2], '-')"),
+        "1-2"
+    );
+    assert_eq!(forward(&mut engine, "Math.max.apply(null, [1, 5, 3])"), "5");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Math.max.apply(null, { length: 2, 0: 4, 1: 7 })"
+        ),
+        "7"
+    );
+    assert_eq!(
+        forward(&mut engine, "Math.max.apply(null, undefined)"),
+        "-Infinity"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Function.prototype.call.call(1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn bind() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        function add(a, b, c) {
+            return a + b + c;
+        }
+        var addOne = add.bind(null, 1);
+        var join = Array.prototype.join.bind([1, 2]);
+        var BoundArray = Array.bind(null, 1, 2);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "addOne(2, 3)"), "6");
+    assert_eq!(forward(&mut engine, "addOne.length"), "2");
+    assert_eq!(forward(&mut engine, "addOne.name"), "bound add");
+    assert_eq!(forward(&mut engine, "typeof addOne"), "function");
+    assert_eq!(forward(&mut engine, "join('-')"), "1-2");
+    assert_eq!(forward(&mut engine, "new BoundArray(3).join()"), "1,2,3");
+    assert_eq!(
+        forward(&mut engine, "add.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(addOne) === Function.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn to_string() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        function f(a, b) { return a; }
+        var arrow = (a) => a;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "f.toString()"),
+        "function f(a, b) { return a; }"
+    );
+    assert_eq!(
+        forward(&mut engine, "[].map.toString()"),
+        "function map() { [native code] }"
+    );
+    assert_eq!(
+        forward(&mut engine, "f.bind(null).toString()"),
+        "function bound f() { [native code] }"
+    );
+    assert_eq!(forward(&mut engine, "f.name"), "f");
+    assert_eq!(forward(&mut engine, "f.length"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Function.prototype.toString.call({}) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn function_constructor() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var add = new Function('a', 'b', 'return a + b');
+        var noArgs = Function('return 1');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut engine, "add.length"), "2");
+    assert_eq!(forward(&mut engine, "add.name"), "anonymous");
+    assert_eq!(forward(&mut engine, "noArgs()"), "1");
+    assert_eq!(
+        forward(&mut engine, "add.toString()"),
+        "function anonymous(a,b\n) {\nreturn a + b\n}"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { Function('}') } catch (e) { e.name }"),
+        "SyntaxError"
+    );
+}
+
+#[test]
+fn closures() {
+    use crate::builtins::{
+        function::FunctionBody,
+        value::{to_value, ResultValue, Value, ValueData},
+    };
+    use crate::exec::Interpreter;
+    use gc::{Gc, GcCell};
+
+    fn count(
+        _: &mut Value,
+        args: &[Value],
+        counter: &Gc<GcCell<i32>>,
+        _: &mut Interpreter,
+    ) -> ResultValue {
+        let step = args.first().map_or(1, |step| step.to_integer());
+        *counter.borrow_mut() += step;
+        Ok(to_value(*counter.borrow()))
+    }
+
+    let counter = Gc::new(GcCell::new(0));
+    let config = ValueData::new_obj(None);
+    config.set_field_slice("greeting", to_value("Hello"));
+    let realm = Realm::create()
+        .register_global_closure("count", count, counter.clone())
+        .register_global_closure(
+            "greet",
+            |_, args, config: &Value, ctx| {
+                let name = ctx.value_to_rust_string(&args.first().cloned().unwrap_or_default());
+                let greeting = config.get_field_slice("greeting");
+                Ok(to_value(format!("{}, {}!", greeting, name)))
+            },
+            config,
+        );
+    let mut engine: Interpreter = Executor::new(realm);
+
+    // The captured object is only reachable through the closure
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "greet('world')"), "Hello, world!");
+    assert_eq!(forward(&mut engine, "count(); count(); count(5)"), "7");
+    assert_eq!(*counter.borrow(), 7);
+
+    let object = ValueData::new_obj(None);
+    object.set_method(
+        "triple",
+        1,
+        FunctionBody::closure(
+            |_, args, factor: &i32, _| {
+                Ok(to_value(
+                    args.first().map_or(0, |x| x.to_integer()) * factor,
+                ))
+            },
+            3,
+        ),
+    );
+    engine.realm.global_obj.set_field_slice("object", object);
+    assert_eq!(forward(&mut engine, "object.triple(4)"), "12");
+    assert_eq!(forward(&mut engine, "object.triple.name"), "triple");
+    assert_eq!(forward(&mut engine, "object.triple.length"), "1");
+}
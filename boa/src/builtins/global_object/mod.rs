@@ -0,0 +1,428 @@
+//! This module implements the value and function properties of the global object.
+//!
+//! These are the `NaN`, `Infinity`, `undefined` and `globalThis` values, the number parsing
+//! functions `parseInt` and `parseFloat`, the `isNaN` and `isFinite` checks, and the URI
+//! coding functions, including the legacy `escape` and `unescape`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-global-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        number::unsigned_decimal_literal_len,
+        property::Property,
+        string::is_trimmable_whitespace,
+        value::{to_value, undefined, JsString, ResultValue, Value},
+    },
+    exec::Interpreter,
+};
+
+/// The characters which are reserved in URIs, `uriReserved` in the spec.
+const URI_RESERVED: &str = ";/?:@&=+$,";
+
+/// The punctuation which is never escaped in URIs, alongside the ASCII letters and digits.
+const URI_MARK: &str = "-_.!~*'()";
+
+/// Gets the string argument at `index`, as a Rust string.
+fn string_arg(args: &[Value], index: usize, ctx: &mut Interpreter) -> String {
+    ctx.value_to_rust_string(&args.get(index).cloned().unwrap_or_else(undefined))
+}
+
+/// Gets the string argument at `index`, keeping its UTF-16 code units.
+fn js_string_arg(args: &[Value], index: usize, ctx: &mut Interpreter) -> JsString {
+    ctx.value_to_js_string(&args.get(index).cloned().unwrap_or_else(undefined))
+}
+
+/// `parseInt( string, radix )`
+///
+/// Parses the integer at the start of a string, in the given radix or in base 10, or in
+/// base 16 if it starts with `0x`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-parseint-string-radix
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt
+pub fn parse_int(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let input = string_arg(args, 0, ctx);
+    let mut string = input.trim_start_matches(is_trimmable_whitespace);
+    let sign = if string.starts_with('-') { -1.0 } else { 1.0 };
+    if string.starts_with('-') || string.starts_with('+') {
+        string = &string[1..];
+    }
+
+    let mut radix = ctx.to_uint32(&args.get(1).cloned().unwrap_or_else(undefined)) as i32;
+    let mut strip_prefix = true;
+    if radix != 0 {
+        if !(2..=36).contains(&radix) {
+            return Ok(to_value(f64::NAN));
+        }
+        strip_prefix = radix == 16;
+    } else {
+        radix = 10;
+    }
+    if strip_prefix && (string.starts_with("0x") || string.starts_with("0X")) {
+        string = &string[2..];
+        radix = 16;
+    }
+
+    let radix = radix as u32;
+    let digits = string
+        .find(|c: char| !c.is_digit(radix))
+        .map_or(string, |end| &string[..end]);
+    if digits.is_empty() {
+        return Ok(to_value(f64::NAN));
+    }
+
+    // Decimal integers are parsed as a whole, so they are correctly rounded
+    let number = if radix == 10 {
+        digits.parse::<f64>().unwrap_or(f64::NAN)
+    } else {
+        digits.chars().fold(0.0, |number, digit| {
+            number * f64::from(radix) + f64::from(digit.to_digit(radix).expect("checked digit"))
+        })
+    };
+    Ok(to_value(sign * number))
+}
+
+/// `parseFloat( string )`
+///
+/// Parses the decimal number at the start of a string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-parsefloat-string
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseFloat
+pub fn parse_float(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let input = string_arg(args, 0, ctx);
+    let string = input.trim_start_matches(is_trimmable_whitespace);
+    let (sign, unsigned) = match string.as_bytes().first() {
+        Some(b'-') => (-1.0, &string[1..]),
+        Some(b'+') => (1.0, &string[1..]),
+        _ => (1.0, string),
+    };
+
+    if unsigned.starts_with("Infinity") {
+        return Ok(to_value(sign * f64::INFINITY));
+    }
+    let len = unsigned_decimal_literal_len(unsigned);
+    if len == 0 {
+        return Ok(to_value(f64::NAN));
+    }
+    let number = unsigned[..len].parse::<f64>().unwrap_or(f64::NAN);
+    Ok(to_value(sign * number))
+}
+
+/// `isNaN( number )`
+///
+/// Checks if the argument is `NaN` once converted to a number.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isnan-number
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/isNaN
+pub fn is_nan(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let number = ctx.to_number(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    Ok(to_value(number.is_nan()))
+}
+
+/// `isFinite( number )`
+///
+/// Checks if the argument is neither `NaN` nor infinite once converted to a number.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isfinite-number
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/isFinite
+pub fn is_finite(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let number = ctx.to_number(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    Ok(to_value(number.is_finite()))
+}
+
+/// Checks if an ASCII code unit is in `uriUnescaped`, which is never escaped.
+fn is_uri_unescaped(unit: u16) -> bool {
+    unit < 0x80 && {
+        let c = unit as u8 as char;
+        c.is_ascii_alphanumeric() || URI_MARK.contains(c)
+    }
+}
+
+/// `Encode`, escapes the UTF-8 bytes of the code points which aren't in the unescaped set.
+///
+/// Throws a `URIError` for lone surrogates, which can't be encoded in UTF-8.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encode
+fn encode(string: &JsString, extra_unescaped: &str, ctx: &mut Interpreter) -> ResultValue {
+    let mut encoded = String::with_capacity(string.len());
+    let mut index = 0;
+    while index < string.len() {
+        let unit = string[index];
+        if is_uri_unescaped(unit) || (unit < 0x80 && extra_unescaped.contains(unit as u8 as char)) {
+            encoded.push(unit as u8 as char);
+            index += 1;
+            continue;
+        }
+
+        let (code_point, units) = string.code_point_at(index).expect("index is in bounds");
+        let c = match std::char::from_u32(code_point) {
+            Some(c) => c,
+            None => return ctx.throw_uri_error("URI malformed"),
+        };
+        let mut bytes = [0; 4];
+        for byte in c.encode_utf8(&mut bytes).bytes() {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+        index += units;
+    }
+    Ok(to_value(encoded))
+}
+
+/// Parses the two hexadecimal digits of an escape sequence starting at `index`.
+fn hex_byte(units: &[u16], index: usize) -> Option<u8> {
+    let digits = units.get(index..index + 2)?;
+    let digit = |unit: u16| std::char::from_u32(u32::from(unit))?.to_digit(16);
+    Some((digit(digits[0])? * 16 + digit(digits[1])?) as u8)
+}
+
+/// `Decode`, unescapes the UTF-8 sequences of escaped bytes, except for the reserved characters.
+///
+/// Throws a `URIError` for malformed escape sequences and invalid UTF-8.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-decode
+fn decode(string: &JsString, reserved: &str, ctx: &mut Interpreter) -> ResultValue {
+    let mut decoded: Vec<u16> = Vec::with_capacity(string.len());
+    let mut index = 0;
+    while index < string.len() {
+        if string[index] != u16::from(b'%') {
+            decoded.push(string[index]);
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        let lead = match hex_byte(string, index + 1) {
+            Some(byte) => byte,
+            None => return ctx.throw_uri_error("URI malformed"),
+        };
+        index += 3;
+
+        if lead < 0x80 {
+            if reserved.contains(lead as char) {
+                decoded.extend_from_slice(&string[start..index]);
+            } else {
+                decoded.push(u16::from(lead));
+            }
+            continue;
+        }
+
+        // The number of leading ones is the length of the UTF-8 sequence
+        let len = (!lead).leading_zeros() as usize;
+        if len == 1 || len > 4 {
+            return ctx.throw_uri_error("URI malformed");
+        }
+        let mut bytes = vec![lead];
+        for _ in 1..len {
+            match (string.get(index), hex_byte(string, index + 1)) {
+                (Some(&percent), Some(byte))
+                    if percent == u16::from(b'%') && byte & 0xC0 == 0x80 =>
+                {
+                    bytes.push(byte)
+                }
+                _ => return ctx.throw_uri_error("URI malformed"),
+            }
+            index += 3;
+        }
+        // Overlong sequences and surrogates are rejected as invalid UTF-8
+        match std::str::from_utf8(&bytes) {
+            Ok(c) => decoded.extend(c.encode_utf16()),
+            Err(_) => return ctx.throw_uri_error("URI malformed"),
+        }
+    }
+    Ok(to_value(JsString::from(decoded)))
+}
+
+/// `encodeURI( uri )`
+///
+/// Escapes a complete URI, leaving its reserved characters and `#` as they are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeuri-uri
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+pub fn encode_uri(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    encode(&string, &format!("{}#", URI_RESERVED), ctx)
+}
+
+/// `encodeURIComponent( uriComponent )`
+///
+/// Escapes a component of a URI, including the reserved characters.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent
+pub fn encode_uri_component(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    encode(&string, "", ctx)
+}
+
+/// `decodeURI( encodedURI )`
+///
+/// Unescapes a complete URI, keeping the escape sequences of reserved characters and `#`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-decodeuri-encodeduri
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
+pub fn decode_uri(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    decode(&string, &format!("{}#", URI_RESERVED), ctx)
+}
+
+/// `decodeURIComponent( encodedURIComponent )`
+///
+/// Unescapes a component of a URI, including the reserved characters.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent
+pub fn decode_uri_component(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    decode(&string, "", ctx)
+}
+
+/// `escape( string )`
+///
+/// Escapes the code units of a string as `%XX` or `%uXXXX`, except for the ASCII letters,
+/// digits and `@*_+-./`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-escape-string
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape
+pub fn escape(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    let mut escaped = String::with_capacity(string.len());
+    for &unit in string.iter() {
+        match unit {
+            0..=0x7F
+                if (unit as u8).is_ascii_alphanumeric()
+                    || "@*_+-./".contains(unit as u8 as char) =>
+            {
+                escaped.push(unit as u8 as char)
+            }
+            0..=0xFF => escaped.push_str(&format!("%{:02X}", unit)),
+            _ => escaped.push_str(&format!("%u{:04X}", unit)),
+        }
+    }
+    Ok(to_value(escaped))
+}
+
+/// `unescape( string )`
+///
+/// Replaces the `%XX` and `%uXXXX` escape sequences of a string with their code units.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-unescape-string
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/unescape
+pub fn unescape(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let string = js_string_arg(args, 0, ctx);
+    let mut unescaped: Vec<u16> = Vec::with_capacity(string.len());
+    let mut index = 0;
+    while index < string.len() {
+        let unit = string[index];
+        index += 1;
+        if unit == u16::from(b'%') {
+            if string.get(index) == Some(&u16::from(b'u')) {
+                if let (Some(high), Some(low)) =
+                    (hex_byte(&string, index + 1), hex_byte(&string, index + 3))
+                {
+                    unescaped.push(u16::from(high) << 8 | u16::from(low));
+                    index += 5;
+                    continue;
+                }
+            } else if let Some(byte) = hex_byte(&string, index) {
+                unescaped.push(u16::from(byte));
+                index += 2;
+                continue;
+            }
+        }
+        unescaped.push(unit);
+    }
+    Ok(to_value(JsString::from(unescaped)))
+}
+
+/// Initialise the value and function properties of the global object.
+pub fn init(global: &Value) {
+    // The value properties can't be changed
+    for (name, value) in [
+        ("Infinity", to_value(f64::INFINITY)),
+        ("NaN", to_value(f64::NAN)),
+        ("undefined", undefined()),
+    ]
+    .iter()
+    {
+        global.set_prop_slice(
+            name,
+            Property::default()
+                .value(value.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+    }
+    global.set_prop_slice(
+        "globalThis",
+        Property::default()
+            .value(global.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    make_builtin_fn!(decode_uri, named "decodeURI", with length 1, of global);
+    make_builtin_fn!(decode_uri_component, named "decodeURIComponent", with length 1, of global);
+    make_builtin_fn!(encode_uri, named "encodeURI", with length 1, of global);
+    make_builtin_fn!(encode_uri_component, named "encodeURIComponent", with length 1, of global);
+    make_builtin_fn!(escape, named "escape", with length 1, of global);
+    make_builtin_fn!(is_finite, named "isFinite", with length 1, of global);
+    make_builtin_fn!(is_nan, named "isNaN", with length 1, of global);
+    make_builtin_fn!(parse_float, named "parseFloat", with length 1, of global);
+    make_builtin_fn!(parse_int, named "parseInt", with length 2, of global);
+    make_builtin_fn!(unescape, named "unescape", with length 1, of global);
+}
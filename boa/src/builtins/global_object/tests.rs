@@ -0,0 +1,142 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn value_properties() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "NaN"), "NaN");
+    assert_eq!(forward(&mut engine, "Infinity"), "Infinity");
+    assert_eq!(forward(&mut engine, "globalThis.Math === Math"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(globalThis, 'NaN').writable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(globalThis, 'undefined').configurable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn parse_int() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "parseInt('  42px')"), "42");
+    assert_eq!(forward(&mut engine, "parseInt('-0x1F')"), "-31");
+    assert_eq!(forward(&mut engine, "parseInt('0x1F', 16)"), "31");
+    assert_eq!(forward(&mut engine, "parseInt('0x1F', 10)"), "0");
+    assert_eq!(forward(&mut engine, "parseInt('101', 2)"), "5");
+    assert_eq!(forward(&mut engine, "parseInt('z', 36)"), "35");
+    assert_eq!(forward(&mut engine, "parseInt('12', 1)"), "NaN");
+    assert_eq!(forward(&mut engine, "parseInt('12', 37)"), "NaN");
+    assert_eq!(forward(&mut engine, "parseInt('abc')"), "NaN");
+    assert_eq!(forward(&mut engine, "1 / parseInt('-0')"), "-Infinity");
+    assert_eq!(forward(&mut engine, "Number.parseInt === parseInt"), "true");
+}
+
+#[test]
+fn parse_float() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "parseFloat('  3.14abc')"), "3.14");
+    assert_eq!(forward(&mut engine, "parseFloat('.5')"), "0.5");
+    assert_eq!(forward(&mut engine, "parseFloat('-1e3x')"), "-1000");
+    assert_eq!(forward(&mut engine, "parseFloat('1e')"), "1");
+    assert_eq!(
+        forward(&mut engine, "parseFloat('-Infinityx')"),
+        "-Infinity"
+    );
+    assert_eq!(forward(&mut engine, "parseFloat('.')"), "NaN");
+    assert_eq!(forward(&mut engine, "parseFloat('0x10')"), "0");
+    assert_eq!(
+        forward(&mut engine, "Number.parseFloat === parseFloat"),
+        "true"
+    );
+}
+
+#[test]
+fn is_nan_and_is_finite() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "isNaN('abc')"), "true");
+    assert_eq!(forward(&mut engine, "isNaN(' 12 ')"), "false");
+    assert_eq!(forward(&mut engine, "isNaN(undefined)"), "true");
+    assert_eq!(forward(&mut engine, "isFinite('0x10')"), "true");
+    assert_eq!(forward(&mut engine, "isFinite(Infinity)"), "false");
+    assert_eq!(forward(&mut engine, "isFinite(null)"), "true");
+    assert_eq!(
+        forward(&mut engine, "try { isNaN(Symbol()) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn uri_coding() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "encodeURI('http://a.b/c d?e=ü#f')"),
+        "http://a.b/c%20d?e=%C3%BC#f"
+    );
+    assert_eq!(
+        forward(&mut engine, "encodeURIComponent('a b&c/d')"),
+        "a%20b%26c%2Fd"
+    );
+    assert_eq!(
+        forward(&mut engine, "encodeURIComponent('😀')"),
+        "%F0%9F%98%80"
+    );
+    assert_eq!(forward(&mut engine, "decodeURI('%41%2F%C3%BC')"), "A%2Fü");
+    assert_eq!(
+        forward(&mut engine, "decodeURIComponent('%41%2F%C3%BC')"),
+        "A/ü"
+    );
+    assert_eq!(
+        forward(&mut engine, "decodeURIComponent('%F0%9F%98%80')"),
+        "😀"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { decodeURI('%E0%A4%A') } catch (e) { e.name }"
+        ),
+        "URIError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { decodeURI('%C0%80') } catch (e) { e.name }"
+        ),
+        "URIError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { encodeURI('\\uD800') } catch (e) { e.name }"
+        ),
+        "URIError"
+    );
+}
+
+#[test]
+fn escape_and_unescape() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "escape('a b+ü\\u0107')"),
+        "a%20b+%FC%u0107"
+    );
+    assert_eq!(
+        forward(&mut engine, "unescape('a%20b+%FC%u0107')"),
+        "a b+üć"
+    );
+    assert_eq!(forward(&mut engine, "unescape('%zz%u12')"), "%zz%u12");
+}
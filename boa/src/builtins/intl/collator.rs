@@ -0,0 +1,450 @@
+//! This module implements the `Intl.Collator` constructor.
+//!
+//! `Intl.Collator` objects compare strings in the order of their locale, which is an
+//! approximation of the [Unicode Collation Algorithm][uca] on the canonical decomposition of the
+//! strings: the strings are compared by their base letters first, then by their accents, and
+//! then by their case.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#collator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator
+//! [uca]: https://unicode.org/reports/tr10/
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, create_bound_method, get_bool_option,
+    get_option, new_intl_object, supported_locales_of, this_intl_data, IntlData, ResolvedLocale,
+};
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+use std::cmp::Ordering;
+use unicode_normalization::UnicodeNormalization;
+
+/// Which differences between strings make them compare as unequal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sensitivity {
+    /// Only the base letters, `a ≠ b` but `a = á = A`.
+    Base,
+    /// The base letters and the accents, `a ≠ á` but `a = A`.
+    Accent,
+    /// The base letters and the case, `a ≠ A` but `a = á`.
+    Case,
+    /// Everything, `a ≠ á ≠ A`.
+    Variant,
+}
+
+/// A collation element, with a weight for each level of comparison.
+///
+/// A zero weight is ignored at its level, like the primary weight of accents.
+#[derive(Debug, Clone, Copy)]
+struct CollationElement {
+    primary: u32,
+    secondary: u32,
+    tertiary: u32,
+}
+
+/// The groups of the primary weights, in the order of the weights.
+const WHITESPACE: u32 = 1 << 24;
+const PUNCTUATION: u32 = 2 << 24;
+const SYMBOL: u32 = 3 << 24;
+const DIGIT: u32 = 4 << 24;
+const LETTER: u32 = 5 << 24;
+
+/// The secondary weight of the characters without accent.
+const NO_ACCENT: u32 = 0x20;
+
+/// The tertiary weights.
+const LOWERCASE: u32 = 0x02;
+const EXPANSION: u32 = 0x04;
+const UPPERCASE: u32 = 0x08;
+const HIRAGANA: u32 = 0x0E;
+const KATAKANA: u32 = 0x0F;
+
+/// Checks whether `c` is a combining mark, which is an accent of the character before it.
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{3099}'..='\u{309A}'
+    )
+}
+
+/// Checks whether `c` is punctuation rather than a symbol, like `,` but not `+`.
+fn is_punctuation(c: char) -> bool {
+    if c.is_ascii() {
+        c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c)
+    } else {
+        matches!(
+            c,
+            '\u{A1}' | '\u{A7}' | '\u{AB}' | '\u{B6}' | '\u{B7}' | '\u{BB}' | '\u{BF}'
+        ) || matches!(c, '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205E}' | '\u{3001}'..='\u{303F}')
+    }
+}
+
+/// The resolved options of a collator.
+#[derive(Debug, Clone)]
+pub(crate) struct Collator {
+    locale: String,
+    usage_search: bool,
+    sensitivity: Sensitivity,
+    ignore_punctuation: bool,
+    numeric: bool,
+    /// The `caseFirst` option: `upper`, `lower` or `false`.
+    case_first: String,
+}
+
+impl Collator {
+    /// The abstract operation `InitializeCollator`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializecollator
+    pub(crate) fn new(
+        locales: &Value,
+        options: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<Self, Value> {
+        let requested = canonicalize_locale_list(locales, ctx)?;
+        let options = coerce_options_to_object(options, ctx)?;
+        let usage_search =
+            get_option(&options, "usage", &["sort", "search"], ctx)?.as_deref() == Some("search");
+        get_option(&options, "localeMatcher", &["lookup", "best fit"], ctx)?;
+        get_option(&options, "collation", &[], ctx)?;
+        let numeric = get_bool_option(&options, "numeric", ctx)?.map(|numeric| {
+            if numeric {
+                String::new()
+            } else {
+                "false".to_string()
+            }
+        });
+        let case_first = get_option(&options, "caseFirst", &["upper", "lower", "false"], ctx)?;
+
+        let mut resolved = ResolvedLocale::new(&requested);
+        let numeric = resolved.keyword("kn", numeric, |kn| kn.is_empty() || kn == "false");
+        let case_first = resolved.keyword("kf", case_first, |kf| {
+            matches!(kf, "upper" | "lower" | "false")
+        });
+
+        let sensitivity = match get_option(
+            &options,
+            "sensitivity",
+            &["base", "accent", "case", "variant"],
+            ctx,
+        )?
+        .as_deref()
+        {
+            Some("base") => Sensitivity::Base,
+            Some("accent") => Sensitivity::Accent,
+            Some("case") => Sensitivity::Case,
+            _ => Sensitivity::Variant,
+        };
+        let ignore_punctuation =
+            get_bool_option(&options, "ignorePunctuation", ctx)?.unwrap_or(false);
+
+        Ok(Self {
+            locale: resolved.locale(),
+            usage_search,
+            sensitivity,
+            ignore_punctuation,
+            numeric: numeric.as_deref() == Some(""),
+            case_first: case_first.unwrap_or_else(|| "false".to_string()),
+        })
+    }
+
+    /// Gets the collation elements of a string.
+    fn collation_elements(&self, string: &str) -> Vec<CollationElement> {
+        let (lowercase, uppercase) = if self.case_first == "upper" {
+            (UPPERCASE, LOWERCASE)
+        } else {
+            (LOWERCASE, UPPERCASE)
+        };
+        let element = |primary, tertiary| CollationElement {
+            primary,
+            secondary: NO_ACCENT,
+            tertiary,
+        };
+
+        let mut elements = Vec::new();
+        let mut chars = string.nfd().peekable();
+        while let Some(c) = chars.next() {
+            if is_combining_mark(c) {
+                elements.push(CollationElement {
+                    primary: 0,
+                    secondary: NO_ACCENT + 1 + c as u32,
+                    tertiary: 0,
+                });
+            } else if c.is_ascii_digit() && self.numeric {
+                // The digits of a number are compared by the value of the number
+                let mut digits = vec![c];
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                let start = digits
+                    .iter()
+                    .position(|d| *d != '0')
+                    .unwrap_or(digits.len());
+                let digits = &digits[start..];
+                elements.push(element(DIGIT | 0x100 | digits.len() as u32, lowercase));
+                for digit in digits {
+                    elements.push(element(DIGIT | (*digit as u32 - '0' as u32), lowercase));
+                }
+            } else if c.is_ascii_digit() {
+                elements.push(element(DIGIT | (c as u32 - '0' as u32), lowercase));
+            } else if c.is_alphabetic() {
+                let (base, tertiary) = match c {
+                    // Katakana is sorted with hiragana
+                    '\u{30A1}'..='\u{30F6}' => {
+                        (char::from_u32(c as u32 - 0x60).unwrap_or(c), KATAKANA)
+                    }
+                    '\u{3041}'..='\u{3096}' => (c, HIRAGANA),
+                    _ if c.is_uppercase() => (c.to_lowercase().next().unwrap_or(c), uppercase),
+                    _ => (c, lowercase),
+                };
+                match base {
+                    'ß' => {
+                        elements.push(element(LETTER | 's' as u32, EXPANSION));
+                        elements.push(element(LETTER | 's' as u32, EXPANSION));
+                    }
+                    'æ' | 'œ' => {
+                        let first = if base == 'æ' { 'a' } else { 'o' };
+                        elements.push(element(LETTER | first as u32, tertiary | EXPANSION));
+                        elements.push(element(LETTER | 'e' as u32, tertiary | EXPANSION));
+                    }
+                    // The letters with an accent which isn't decomposed
+                    'ø' | 'đ' | 'ł' => {
+                        let letter = match base {
+                            'ø' => 'o',
+                            'đ' => 'd',
+                            _ => 'l',
+                        };
+                        elements.push(element(LETTER | letter as u32, tertiary));
+                        elements.push(CollationElement {
+                            primary: 0,
+                            secondary: NO_ACCENT + 1 + 0x338,
+                            tertiary: 0,
+                        });
+                    }
+                    _ => elements.push(element(LETTER | base as u32, tertiary)),
+                }
+            } else if c.is_control() {
+                continue;
+            } else if c.is_whitespace() || is_punctuation(c) {
+                if !self.ignore_punctuation {
+                    let group = if c.is_whitespace() {
+                        WHITESPACE
+                    } else {
+                        PUNCTUATION
+                    };
+                    elements.push(element(group | c as u32, lowercase));
+                }
+            } else {
+                elements.push(element(SYMBOL | c as u32, lowercase));
+            }
+        }
+        elements
+    }
+
+    /// Compares two strings, following the sensitivity of the collator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-collator-comparestrings
+    pub(crate) fn compare(&self, x: &str, y: &str) -> Ordering {
+        let x_elements = self.collation_elements(x);
+        let y_elements = self.collation_elements(y);
+        let weights = |elements: &[CollationElement], level: fn(&CollationElement) -> u32| {
+            elements
+                .iter()
+                .map(level)
+                .filter(|weight| *weight != 0)
+                .collect::<Vec<_>>()
+        };
+        let compare_level = |level: fn(&CollationElement) -> u32| {
+            weights(&x_elements, level).cmp(&weights(&y_elements, level))
+        };
+
+        let mut ordering = compare_level(|e| e.primary);
+        if matches!(self.sensitivity, Sensitivity::Accent | Sensitivity::Variant) {
+            ordering = ordering.then_with(|| compare_level(|e| e.secondary));
+        }
+        if matches!(self.sensitivity, Sensitivity::Case | Sensitivity::Variant) {
+            ordering = ordering.then_with(|| compare_level(|e| e.tertiary));
+        }
+        if self.sensitivity == Sensitivity::Variant && !self.ignore_punctuation {
+            // The strings which are still equal are ordered by their canonical decomposition
+            ordering = ordering.then_with(|| x.nfd().cmp(y.nfd()));
+        }
+        ordering
+    }
+}
+
+/// The internal slots of `Intl.Collator` objects.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct CollatorData {
+    #[unsafe_ignore_trace]
+    collator: Collator,
+    /// The function returned by the `compare` getter, once it has been created.
+    bound_compare: Option<Value>,
+}
+
+/// Gets the collator of `this`, or throws a `TypeError`.
+fn this_collator(this: &Value, method: &str, ctx: &mut Interpreter) -> Result<CollatorData, Value> {
+    match this_intl_data(this, "Intl.Collator", method, ctx)? {
+        IntlData::Collator(ref data) => Ok(data.clone()),
+        _ => unreachable!("checked the kind of the object"),
+    }
+}
+
+/// Converts an ordering to the number returned by comparison functions.
+fn ordering_to_value(ordering: Ordering) -> Value {
+    to_value(match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    })
+}
+
+/// Compares two strings as `String.prototype.localeCompare( that [, locales [, options]] )` does,
+/// where `args` are the locales and the options.
+pub(crate) fn locale_compare(
+    x: &str,
+    y: &str,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let collator = Collator::new(&locales, &options, ctx)?;
+    Ok(ordering_to_value(collator.compare(x, y)))
+}
+
+/// `Intl.Collator( [locales [, options]] )`
+///
+/// Creates a collator, when it's called as a function too.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.collator
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator
+pub fn make_collator(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let collator = Collator::new(&locales, &options, ctx)?;
+    this.set_kind(ObjectKind::Intl(IntlData::Collator(CollatorData {
+        collator,
+        bound_compare: None,
+    })));
+    Ok(this.clone())
+}
+
+/// `Intl.Collator` called as a function, which creates a collator.
+pub fn call_collator(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut this = new_intl_object("Collator", ctx)?;
+    make_collator(&mut this, args, ctx)
+}
+
+/// The function returned by the `compare` getter.
+fn compare_strings(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_collator(this, "compare", ctx)?;
+    let x = ctx.value_to_rust_string(&args.get(0).cloned().unwrap_or_else(undefined));
+    let y = ctx.value_to_rust_string(&args.get(1).cloned().unwrap_or_else(undefined));
+    Ok(ordering_to_value(data.collator.compare(&x, &y)))
+}
+
+/// `get Intl.Collator.prototype.compare`
+///
+/// Gets a function comparing two strings with this collator, which is bound to it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.compare
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/compare
+pub fn get_compare(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_collator(this, "compare", ctx)?;
+    if let Some(ref bound_compare) = data.bound_compare {
+        return Ok(bound_compare.clone());
+    }
+    let bound_compare = create_bound_method(this, compare_strings, 2, ctx)?;
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::Intl(IntlData::Collator(ref mut data)) = obj.borrow_mut().kind {
+            data.bound_compare = Some(bound_compare.clone());
+        }
+    }
+    Ok(bound_compare)
+}
+
+/// `Intl.Collator.prototype.resolvedOptions()`
+///
+/// Gets the locale and the options used by this collator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.resolvedoptions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/resolvedOptions
+pub fn resolved_options(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collator = this_collator(this, "resolvedOptions", ctx)?
+        .collator
+        .clone();
+    let options = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    let entries = vec![
+        ("locale", to_value(collator.locale.clone())),
+        (
+            "usage",
+            to_value(if collator.usage_search {
+                "search"
+            } else {
+                "sort"
+            }),
+        ),
+        (
+            "sensitivity",
+            to_value(match collator.sensitivity {
+                Sensitivity::Base => "base",
+                Sensitivity::Accent => "accent",
+                Sensitivity::Case => "case",
+                Sensitivity::Variant => "variant",
+            }),
+        ),
+        ("ignorePunctuation", to_value(collator.ignore_punctuation)),
+        ("collation", to_value("default")),
+        ("numeric", to_value(collator.numeric)),
+        ("caseFirst", to_value(collator.case_first.clone())),
+    ];
+
+    for (key, value) in entries {
+        ctx.create_data_property(&options, &to_value(key), value)?;
+    }
+    Ok(options)
+}
+
+/// Create a new `Intl.Collator` constructor.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(get_compare, named "compare", of prototype);
+    make_builtin_fn!(resolved_options, named "resolvedOptions", of prototype);
+
+    let collator = make_constructor_fn!(make_collator, call_collator, global, prototype);
+    make_builtin_fn!(supported_locales_of, named "supportedLocalesOf", with length 1, of collator);
+    collator
+}
@@ -0,0 +1,739 @@
+//! This module implements the `Intl.DateTimeFormat` constructor.
+//!
+//! `Intl.DateTimeFormat` objects format dates and times in the patterns of their locale, in the
+//! local time zone or in UTC.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, create_bound_method, get_bool_option,
+    get_option,
+    locale_data::{DateOrder, LocaleData},
+    new_intl_object, supported_locales_of, this_intl_data, IntlData, ResolvedLocale,
+};
+use crate::{
+    builtins::{
+        date::{
+            time::{time_clip, DateFields},
+            time_value_of,
+            time_zone::TimeZone,
+        },
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// The style of a field of a formatted date, like `numeric` or `long`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldStyle {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl FieldStyle {
+    fn parse(value: &str) -> Self {
+        match value {
+            "2-digit" => Self::TwoDigit,
+            "narrow" => Self::Narrow,
+            "short" => Self::Short,
+            "long" => Self::Long,
+            _ => Self::Numeric,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
+    /// Checks whether the field is written as a number.
+    fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit)
+    }
+}
+
+/// The `dateStyle` and `timeStyle` options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    fn parse(value: &str) -> Self {
+        match value {
+            "full" => Self::Full,
+            "long" => Self::Long,
+            "medium" => Self::Medium,
+            _ => Self::Short,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+        }
+    }
+}
+
+/// The hour cycles, `h12` going from `12 AM` to `11 PM` and `h23` from `0:00` to `23:00`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+}
+
+impl HourCycle {
+    fn parse(value: &str) -> Self {
+        match value {
+            "h11" => Self::H11,
+            "h12" => Self::H12,
+            "h24" => Self::H24,
+            _ => Self::H23,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::H11 => "h11",
+            Self::H12 => "h12",
+            Self::H23 => "h23",
+            Self::H24 => "h24",
+        }
+    }
+
+    fn is_12_hour(self) -> bool {
+        matches!(self, Self::H11 | Self::H12)
+    }
+}
+
+/// Which fields `ToDateTimeOptions` requires, and which it adds when there are none.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DateTimeFields {
+    Date,
+    Time,
+    /// Both the date and the time fields, or any of them when required.
+    All,
+}
+
+/// The names of the options of the fields, in the order of the fields in the resolved options.
+const FIELDS: [&str; 7] = [
+    "weekday", "year", "month", "day", "hour", "minute", "second",
+];
+
+/// The values of the options of the fields.
+const FIELD_VALUES: [&[&str]; 7] = [
+    &["narrow", "short", "long"],
+    &["2-digit", "numeric"],
+    &["2-digit", "numeric", "narrow", "short", "long"],
+    &["2-digit", "numeric"],
+    &["2-digit", "numeric"],
+    &["2-digit", "numeric"],
+    &["2-digit", "numeric"],
+];
+
+/// The resolved options of a date and time format.
+#[derive(Debug, Clone)]
+pub(crate) struct DateTimeFormat {
+    locale: String,
+    data: &'static LocaleData,
+    /// Whether dates are formatted in UTC rather than in the local time zone.
+    utc: bool,
+    hour_cycle: Option<HourCycle>,
+    weekday: Option<FieldStyle>,
+    year: Option<FieldStyle>,
+    month: Option<FieldStyle>,
+    day: Option<FieldStyle>,
+    hour: Option<FieldStyle>,
+    minute: Option<FieldStyle>,
+    second: Option<FieldStyle>,
+    time_zone_name: bool,
+    date_style: Option<Style>,
+    time_style: Option<Style>,
+}
+
+impl DateTimeFormat {
+    /// The abstract operation `InitializeDateTimeFormat`, along with `ToDateTimeOptions`.
+    ///
+    /// The `required` fields are the ones which must be there, the `defaults` ones are added
+    /// when none of them is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializedatetimeformat
+    pub(crate) fn new(
+        locales: &Value,
+        options: &Value,
+        required: DateTimeFields,
+        defaults: DateTimeFields,
+        ctx: &mut Interpreter,
+    ) -> Result<Self, Value> {
+        let requested = canonicalize_locale_list(locales, ctx)?;
+        let options = coerce_options_to_object(options, ctx)?;
+        get_option(&options, "localeMatcher", &["lookup", "best fit"], ctx)?;
+        get_option(&options, "calendar", &[], ctx)?;
+        get_option(&options, "numberingSystem", &[], ctx)?;
+        let hour12 = get_bool_option(&options, "hour12", ctx)?;
+        let hour_cycle = get_option(&options, "hourCycle", &["h11", "h12", "h23", "h24"], ctx)?;
+
+        let utc = match get_option(&options, "timeZone", &[], ctx)? {
+            None => false,
+            Some(time_zone) => match time_zone.to_ascii_uppercase().as_str() {
+                "UTC" | "ETC/UTC" | "GMT" | "ETC/GMT" => true,
+                _ => {
+                    return Err(ctx.construct_range_error(format!(
+                        "Invalid time zone specified: {}",
+                        time_zone
+                    )))
+                }
+            },
+        };
+
+        let mut fields = [None; 7];
+        for (field, (name, values)) in fields
+            .iter_mut()
+            .zip(FIELDS.iter().zip(FIELD_VALUES.iter()))
+        {
+            *field = get_option(&options, name, values, ctx)?
+                .as_deref()
+                .map(FieldStyle::parse);
+        }
+        let time_zone_name = get_option(&options, "timeZoneName", &["short"], ctx)?.is_some();
+        get_option(&options, "formatMatcher", &["basic", "best fit"], ctx)?;
+        let styles = &["full", "long", "medium", "short"];
+        let date_style = get_option(&options, "dateStyle", styles, ctx)?
+            .as_deref()
+            .map(Style::parse);
+        let time_style = get_option(&options, "timeStyle", styles, ctx)?
+            .as_deref()
+            .map(Style::parse);
+
+        if date_style.is_some() || time_style.is_some() {
+            let style = if date_style.is_some() {
+                "dateStyle"
+            } else {
+                "timeStyle"
+            };
+            if let Some(index) = fields.iter().position(Option::is_some) {
+                return Err(ctx.construct_type_error(format!(
+                    "Can't set option {} when {} is used",
+                    FIELDS[index], style
+                )));
+            }
+            if time_zone_name {
+                return Err(ctx.construct_type_error(format!(
+                    "Can't set option timeZoneName when {} is used",
+                    style
+                )));
+            }
+            match required {
+                DateTimeFields::Date if time_style.is_some() => {
+                    return Err(ctx.construct_type_error("Invalid option : timeStyle"))
+                }
+                DateTimeFields::Time if date_style.is_some() => {
+                    return Err(ctx.construct_type_error("Invalid option : dateStyle"))
+                }
+                _ => {}
+            }
+        } else {
+            let has_date = fields[..4].iter().any(Option::is_some);
+            let has_time = fields[4..].iter().any(Option::is_some);
+            let needs_defaults = match required {
+                DateTimeFields::Date => !has_date,
+                DateTimeFields::Time => !has_time,
+                DateTimeFields::All => !has_date && !has_time,
+            };
+            if needs_defaults && defaults != DateTimeFields::Time {
+                fields[1] = Some(FieldStyle::Numeric);
+                fields[2] = Some(FieldStyle::Numeric);
+                fields[3] = Some(FieldStyle::Numeric);
+            }
+            if needs_defaults && defaults != DateTimeFields::Date {
+                fields[4] = Some(FieldStyle::Numeric);
+                fields[5] = Some(FieldStyle::Numeric);
+                fields[6] = Some(FieldStyle::Numeric);
+            }
+        }
+
+        let mut resolved = ResolvedLocale::new(&requested);
+        // The `hour12` option takes precedence over the hour cycles
+        let hour_cycle = if hour12.is_some() {
+            None
+        } else {
+            resolved.keyword("hc", hour_cycle, |hc| {
+                matches!(hc, "h11" | "h12" | "h23" | "h24")
+            })
+        };
+        let data = resolved.data();
+        let has_hour = fields[4].is_some() || time_style.is_some();
+        let hour_cycle = if !has_hour {
+            None
+        } else if let Some(hour12) = hour12 {
+            Some(if hour12 {
+                HourCycle::H12
+            } else {
+                HourCycle::H23
+            })
+        } else if let Some(hour_cycle) = hour_cycle {
+            Some(HourCycle::parse(&hour_cycle))
+        } else if data.hour12 {
+            Some(HourCycle::H12)
+        } else {
+            Some(HourCycle::H23)
+        };
+
+        let mut format = Self {
+            locale: resolved.locale(),
+            data,
+            utc,
+            hour_cycle,
+            weekday: fields[0],
+            year: fields[1],
+            month: fields[2],
+            day: fields[3],
+            hour: fields[4],
+            minute: fields[5],
+            second: fields[6],
+            time_zone_name,
+            date_style,
+            time_style,
+        };
+        format.expand_styles();
+        Ok(format)
+    }
+
+    /// Sets the fields of the `dateStyle` and `timeStyle` options.
+    fn expand_styles(&mut self) {
+        if let Some(date_style) = self.date_style {
+            let (weekday, month, year) = match date_style {
+                Style::Full => (
+                    Some(FieldStyle::Long),
+                    FieldStyle::Long,
+                    FieldStyle::Numeric,
+                ),
+                Style::Long => (None, FieldStyle::Long, FieldStyle::Numeric),
+                Style::Medium => (None, FieldStyle::Short, FieldStyle::Numeric),
+                Style::Short => (None, FieldStyle::Numeric, FieldStyle::TwoDigit),
+            };
+            self.weekday = weekday;
+            self.year = Some(year);
+            self.month = Some(month);
+            self.day = Some(FieldStyle::Numeric);
+        }
+        if let Some(time_style) = self.time_style {
+            self.hour = Some(FieldStyle::Numeric);
+            self.minute = Some(FieldStyle::TwoDigit);
+            if time_style != Style::Short {
+                self.second = Some(FieldStyle::TwoDigit);
+            }
+            self.time_zone_name = matches!(time_style, Style::Full | Style::Long);
+        }
+    }
+
+    /// Formats the date part of the date, like `2/1/2022` or `February 1, 2022`.
+    fn format_date(&self, fields: &DateFields) -> String {
+        let data = self.data;
+        let month_index = fields.month as usize;
+        let pad = |value: f64, style: FieldStyle| {
+            if style == FieldStyle::TwoDigit
+                || (data.two_digit_date && style == FieldStyle::Numeric)
+            {
+                format!("{:02}", value)
+            } else {
+                format!("{}", value)
+            }
+        };
+        let year = self.year.map(|style| match style {
+            FieldStyle::TwoDigit => format!("{:02}", fields.year.rem_euclid(100.0)),
+            _ => format!("{}", fields.year),
+        });
+
+        let date = match self.month {
+            Some(style) if !style.is_numeric() => {
+                let month = match style {
+                    FieldStyle::Narrow => data.narrow_months[month_index],
+                    FieldStyle::Short => data.short_months[month_index],
+                    _ => data.months[month_index],
+                };
+                let day = self.day.map(|_| format!("{}", fields.date));
+                match (data.cjk_date, data.date_order) {
+                    (true, _) => format!(
+                        "{}{}{}",
+                        year.map(|year| year + "年").unwrap_or_default(),
+                        month,
+                        day.map(|day| day + "日").unwrap_or_default()
+                    ),
+                    (false, DateOrder::MonthDayYear) => {
+                        let mut date = month.to_string();
+                        if let Some(ref day) = day {
+                            date.push(' ');
+                            date.push_str(day);
+                        }
+                        if let Some(year) = year {
+                            date.push_str(if day.is_some() { ", " } else { " " });
+                            date.push_str(&year);
+                        }
+                        date
+                    }
+                    (false, DateOrder::DayMonthYear) => {
+                        let mut date = String::new();
+                        if let Some(day) = day {
+                            date.push_str(&day);
+                            date.push_str(data.day_suffix);
+                            date.push(' ');
+                        }
+                        date.push_str(month);
+                        if let Some(year) = year {
+                            date.push(' ');
+                            date.push_str(&year);
+                        }
+                        date
+                    }
+                    (false, DateOrder::YearMonthDay) => {
+                        let parts: Vec<String> = year
+                            .into_iter()
+                            .chain(Some(month.to_string()))
+                            .chain(day)
+                            .collect();
+                        parts.join(" ")
+                    }
+                }
+            }
+            month => {
+                let month = month.map(|style| pad(fields.month + 1.0, style));
+                let day = self.day.map(|style| pad(fields.date, style));
+                let parts: Vec<String> = match data.date_order {
+                    DateOrder::MonthDayYear => vec![month, day, year],
+                    DateOrder::DayMonthYear => vec![day, month, year],
+                    DateOrder::YearMonthDay => vec![year, month, day],
+                }
+                .into_iter()
+                .flatten()
+                .collect();
+                parts.join(data.date_separator)
+            }
+        };
+
+        let weekday = match self.weekday {
+            Some(style) => {
+                let week_day = fields.week_day as usize;
+                match style {
+                    FieldStyle::Narrow => data.narrow_week_days[week_day],
+                    FieldStyle::Short => data.short_week_days[week_day],
+                    _ => data.week_days[week_day],
+                }
+            }
+            None => return date,
+        };
+        if date.is_empty() {
+            weekday.to_string()
+        } else if data.cjk_date && self.weekday == Some(FieldStyle::Long) {
+            date + weekday
+        } else if data.cjk_date {
+            format!("{}({})", date, weekday)
+        } else {
+            format!("{}{}{}", weekday, data.week_day_separator, date)
+        }
+    }
+
+    /// Formats the time part of the date, like `1:05:09 PM` or `13:05`.
+    fn format_time(&self, fields: &DateFields) -> String {
+        let data = self.data;
+        let mut parts = Vec::new();
+        if let (Some(style), Some(hour_cycle)) = (self.hour, self.hour_cycle) {
+            let hour = match hour_cycle {
+                HourCycle::H11 => fields.hours % 12.0,
+                HourCycle::H12 if fields.hours % 12.0 == 0.0 => 12.0,
+                HourCycle::H12 => fields.hours % 12.0,
+                HourCycle::H23 => fields.hours,
+                HourCycle::H24 if fields.hours == 0.0 => 24.0,
+                HourCycle::H24 => fields.hours,
+            };
+            let two_digit =
+                style == FieldStyle::TwoDigit || (!hour_cycle.is_12_hour() && data.two_digit_hour);
+            parts.push(if two_digit {
+                format!("{:02}", hour)
+            } else {
+                format!("{}", hour)
+            });
+        }
+        for (field, value) in [(self.minute, fields.minutes), (self.second, fields.seconds)].iter()
+        {
+            if let Some(style) = field {
+                if parts.is_empty() && *style == FieldStyle::Numeric {
+                    parts.push(format!("{}", value));
+                } else {
+                    parts.push(format!("{:02}", value));
+                }
+            }
+        }
+        let time = parts.join(":");
+
+        match self.hour_cycle {
+            Some(hour_cycle) if hour_cycle.is_12_hour() && self.hour.is_some() => {
+                let day_period = data.day_periods[if fields.hours < 12.0 { 0 } else { 1 }];
+                if data.day_period_first {
+                    format!("{}{}", day_period, time)
+                } else {
+                    format!("{} {}", time, day_period)
+                }
+            }
+            _ => time,
+        }
+    }
+
+    /// The abstract operation `FormatDateTime`, formatting the finite time value `t`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format(&self, t: f64, time_zone: &dyn TimeZone) -> String {
+        let (offset, time_zone_name) = if self.utc {
+            (0.0, String::from("UTC"))
+        } else {
+            (time_zone.offset(t), time_zone.name(t))
+        };
+        let fields = DateFields::new(t + offset);
+        let date = self.format_date(&fields);
+        let mut time = self.format_time(&fields);
+        if self.time_zone_name {
+            if !time.is_empty() {
+                time.push(' ');
+            }
+            time.push_str(&time_zone_name);
+        }
+
+        match (date.is_empty(), time.is_empty()) {
+            (_, true) => date,
+            (true, false) => time,
+            (false, false) => {
+                let separator = match self.date_style {
+                    Some(Style::Full) | Some(Style::Long) => self.data.long_date_time_separator,
+                    _ => self.data.date_time_separator,
+                };
+                format!("{}{}{}", date, separator, time)
+            }
+        }
+    }
+}
+
+/// The internal slots of `Intl.DateTimeFormat` objects.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct DateTimeFormatData {
+    #[unsafe_ignore_trace]
+    format: DateTimeFormat,
+    /// The function returned by the `format` getter, once it has been created.
+    bound_format: Option<Value>,
+}
+
+/// Gets the date and time format of `this`, or throws a `TypeError`.
+fn this_date_time_format(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<DateTimeFormatData, Value> {
+    match this_intl_data(this, "Intl.DateTimeFormat", method, ctx)? {
+        IntlData::DateTimeFormat(ref data) => Ok(data.clone()),
+        _ => unreachable!("checked the kind of the object"),
+    }
+}
+
+/// Formats the finite time value `t` as the `toLocaleString`, `toLocaleDateString` and
+/// `toLocaleTimeString` methods of `Date.prototype` do, with the `fields` they format by default.
+pub(crate) fn to_locale_string(
+    t: f64,
+    args: &[Value],
+    fields: DateTimeFields,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let format = DateTimeFormat::new(&locales, &options, fields, fields, ctx)?;
+    Ok(to_value(format.format(t, ctx.time_zone())))
+}
+
+/// `Intl.DateTimeFormat( [locales [, options]] )`
+///
+/// Creates a date and time format, when it's called as a function too.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
+pub fn make_date_time_format(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let format = DateTimeFormat::new(
+        &locales,
+        &options,
+        DateTimeFields::All,
+        DateTimeFields::Date,
+        ctx,
+    )?;
+    this.set_kind(ObjectKind::Intl(IntlData::DateTimeFormat(
+        DateTimeFormatData {
+            format,
+            bound_format: None,
+        },
+    )));
+    Ok(this.clone())
+}
+
+/// `Intl.DateTimeFormat` called as a function, which creates a date and time format.
+pub fn call_date_time_format(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut this = new_intl_object("DateTimeFormat", ctx)?;
+    make_date_time_format(&mut this, args, ctx)
+}
+
+/// The function returned by the `format` getter.
+///
+/// The date is converted to a number, and it defaults to the current time.
+fn format_date_time(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_date_time_format(this, "format", ctx)?;
+    let t = match args.get(0) {
+        Some(date) if !date.is_undefined() => match time_value_of(date) {
+            Some(t) => t,
+            None => time_clip(ctx.to_number(date)?),
+        },
+        _ => ctx.clock().now(),
+    };
+    if t.is_nan() {
+        return ctx.throw_range_error("Invalid time value");
+    }
+    Ok(to_value(data.format.format(t, ctx.time_zone())))
+}
+
+/// `get Intl.DateTimeFormat.prototype.format`
+///
+/// Gets a function formatting dates with this format, which is bound to it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+pub fn get_format(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_date_time_format(this, "format", ctx)?;
+    if let Some(ref bound_format) = data.bound_format {
+        return Ok(bound_format.clone());
+    }
+    let bound_format = create_bound_method(this, format_date_time, 1, ctx)?;
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::Intl(IntlData::DateTimeFormat(ref mut data)) = obj.borrow_mut().kind {
+            data.bound_format = Some(bound_format.clone());
+        }
+    }
+    Ok(bound_format)
+}
+
+/// `Intl.DateTimeFormat.prototype.resolvedOptions()`
+///
+/// Gets the locale and the options used by this format.
+///
+/// The time zone is `undefined` when the local time zone has no known identifier.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+pub fn resolved_options(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let format = this_date_time_format(this, "resolvedOptions", ctx)?
+        .format
+        .clone();
+    let time_zone = if format.utc {
+        Some(String::from("UTC"))
+    } else {
+        ctx.time_zone().identifier()
+    };
+    let options = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    let mut entries = vec![
+        ("locale", to_value(format.locale.clone())),
+        ("calendar", to_value("gregory")),
+        ("numberingSystem", to_value("latn")),
+        ("timeZone", time_zone.map_or_else(undefined, to_value)),
+    ];
+    if let Some(hour_cycle) = format.hour_cycle {
+        entries.push(("hourCycle", to_value(hour_cycle.name())));
+        entries.push(("hour12", to_value(hour_cycle.is_12_hour())));
+    }
+    if format.date_style.is_none() && format.time_style.is_none() {
+        let fields = [
+            format.weekday,
+            format.year,
+            format.month,
+            format.day,
+            format.hour,
+            format.minute,
+            format.second,
+        ];
+        for (name, field) in FIELDS.iter().zip(fields.iter()) {
+            if let Some(style) = field {
+                entries.push((name, to_value(style.name())));
+            }
+        }
+        if format.time_zone_name {
+            entries.push(("timeZoneName", to_value("short")));
+        }
+    }
+    if let Some(date_style) = format.date_style {
+        entries.push(("dateStyle", to_value(date_style.name())));
+    }
+    if let Some(time_style) = format.time_style {
+        entries.push(("timeStyle", to_value(time_style.name())));
+    }
+
+    for (key, value) in entries {
+        ctx.create_data_property(&options, &to_value(key), value)?;
+    }
+    Ok(options)
+}
+
+/// Create a new `Intl.DateTimeFormat` constructor.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(get_format, named "format", of prototype);
+    make_builtin_fn!(resolved_options, named "resolvedOptions", of prototype);
+
+    let date_time_format = make_constructor_fn!(
+        make_date_time_format,
+        call_date_time_format,
+        global,
+        prototype
+    );
+    make_builtin_fn!(supported_locales_of, named "supportedLocalesOf", with length 1, of date_time_format);
+    date_time_format
+}
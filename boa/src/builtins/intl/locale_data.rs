@@ -0,0 +1,553 @@
+//! The locale data embedded for the `Intl` objects.
+//!
+//! This is a small subset of the [Unicode CLDR][cldr], for the `en`, `de`, `fr` and `ja` languages,
+//! with the regional variant `en-GB`.
+//!
+//! [cldr]: https://cldr.unicode.org/
+
+/// The order of the fields of a numeric date, like `2/1/2022`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+/// The plural categories of a number, as selected by `Intl.PluralRules`.
+///
+/// Only the categories used by the embedded locales are listed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PluralCategory {
+    One,
+    Two,
+    Few,
+    Other,
+}
+
+impl PluralCategory {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// The operands of a formatted number used by the plural rules.
+///
+/// More information:
+///  - [Unicode Technical Standard #35][uts35]
+///
+/// [uts35]: https://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PluralOperands {
+    /// The absolute value of the number.
+    pub(crate) n: f64,
+    /// The integer digits of the number.
+    pub(crate) i: u64,
+    /// The number of visible fraction digits, with trailing zeros.
+    pub(crate) v: usize,
+}
+
+/// The names of a currency in a locale.
+#[derive(Debug)]
+pub(crate) struct CurrencyNames {
+    pub(crate) code: &'static str,
+    pub(crate) symbol: &'static str,
+    pub(crate) narrow_symbol: &'static str,
+    /// The name used with the plural category `one`.
+    pub(crate) one: &'static str,
+    /// The name used with the other plural categories.
+    pub(crate) other: &'static str,
+}
+
+/// The data of a locale.
+#[derive(Debug)]
+pub(crate) struct LocaleData {
+    pub(crate) decimal: &'static str,
+    pub(crate) group: &'static str,
+    pub(crate) minus_sign: &'static str,
+    pub(crate) plus_sign: &'static str,
+    pub(crate) infinity: &'static str,
+    pub(crate) nan: &'static str,
+    /// The pattern of percentages, where `{0}` is the number.
+    pub(crate) percent_pattern: &'static str,
+    /// The pattern of amounts of money, where `{0}` is the number and `{1}` the currency symbol.
+    pub(crate) currency_pattern: &'static str,
+    /// The pattern of amounts of money with the currency name, where `{0}` is the number and `{1}` the name.
+    pub(crate) currency_name_pattern: &'static str,
+    pub(crate) currencies: &'static [CurrencyNames],
+    pub(crate) plural_rule: fn(PluralOperands) -> PluralCategory,
+    pub(crate) ordinal_rule: fn(PluralOperands) -> PluralCategory,
+    pub(crate) plural_categories: &'static [&'static str],
+    pub(crate) ordinal_categories: &'static [&'static str],
+
+    pub(crate) months: [&'static str; 12],
+    pub(crate) short_months: [&'static str; 12],
+    pub(crate) narrow_months: [&'static str; 12],
+    /// The names of the days of the week, starting with Sunday.
+    pub(crate) week_days: [&'static str; 7],
+    pub(crate) short_week_days: [&'static str; 7],
+    pub(crate) narrow_week_days: [&'static str; 7],
+    /// The names of the periods of the day, before and after noon.
+    pub(crate) day_periods: [&'static str; 2],
+    /// Whether the period of the day is written before the time, like `午前9:00`.
+    pub(crate) day_period_first: bool,
+    /// Whether the locale uses a 12-hour clock by default.
+    pub(crate) hour12: bool,
+    /// Whether the hour is written with 2 digits in the 24-hour clock, like `09:00`.
+    pub(crate) two_digit_hour: bool,
+
+    pub(crate) date_order: DateOrder,
+    pub(crate) date_separator: &'static str,
+    /// Whether the numeric day and month are written with 2 digits, like `01/02/2022`.
+    pub(crate) two_digit_date: bool,
+    /// Whether the date is written with the year, month and day suffixes of Japanese, like `2022年2月1日`.
+    pub(crate) cjk_date: bool,
+    /// The punctuation after the day of a textual date, like the `.` of `1. Februar 2022`.
+    pub(crate) day_suffix: &'static str,
+    /// The separator of the weekday from the rest of the date.
+    pub(crate) week_day_separator: &'static str,
+    /// The separator of the date and time.
+    pub(crate) date_time_separator: &'static str,
+    /// The separator of a long date and the time, like ` at ` in English.
+    pub(crate) long_date_time_separator: &'static str,
+}
+
+/// The plural rule of the languages with only the `other` category, like Japanese.
+fn plural_other(_: PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+
+/// The cardinal rule of English and German: `one` for the integer `1`.
+fn plural_one_integer(operands: PluralOperands) -> PluralCategory {
+    if operands.i == 1 && operands.v == 0 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// The cardinal rule of French: `one` below `2`.
+fn plural_french(operands: PluralOperands) -> PluralCategory {
+    if operands.i == 0 || operands.i == 1 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// The ordinal rule of English, for `1st`, `2nd`, `3rd` and `4th`.
+fn ordinal_english(operands: PluralOperands) -> PluralCategory {
+    let n = operands.n;
+    if n.fract() != 0.0 {
+        return PluralCategory::Other;
+    }
+    match (n % 10.0, n % 100.0) {
+        (r, c) if r == 1.0 && c != 11.0 => PluralCategory::One,
+        (r, c) if r == 2.0 && c != 12.0 => PluralCategory::Two,
+        (r, c) if r == 3.0 && c != 13.0 => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// The ordinal rule of French, for `1er` and `2e`.
+fn ordinal_french(operands: PluralOperands) -> PluralCategory {
+    if operands.n == 1.0 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_SHORT_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const EN_WEEK_DAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const EN_SHORT_WEEK_DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const LATIN_NARROW_MONTHS: [&str; 12] =
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+
+const EN_CURRENCIES: &[CurrencyNames] = &[
+    CurrencyNames {
+        code: "USD",
+        symbol: "$",
+        narrow_symbol: "$",
+        one: "US dollar",
+        other: "US dollars",
+    },
+    CurrencyNames {
+        code: "EUR",
+        symbol: "€",
+        narrow_symbol: "€",
+        one: "euro",
+        other: "euros",
+    },
+    CurrencyNames {
+        code: "GBP",
+        symbol: "£",
+        narrow_symbol: "£",
+        one: "British pound",
+        other: "British pounds",
+    },
+    CurrencyNames {
+        code: "JPY",
+        symbol: "¥",
+        narrow_symbol: "¥",
+        one: "Japanese yen",
+        other: "Japanese yen",
+    },
+];
+
+const EN: LocaleData = LocaleData {
+    decimal: ".",
+    group: ",",
+    minus_sign: "-",
+    plus_sign: "+",
+    infinity: "∞",
+    nan: "NaN",
+    percent_pattern: "{0}%",
+    currency_pattern: "{1}{0}",
+    currency_name_pattern: "{0} {1}",
+    currencies: EN_CURRENCIES,
+    plural_rule: plural_one_integer,
+    ordinal_rule: ordinal_english,
+    plural_categories: &["one", "other"],
+    ordinal_categories: &["few", "one", "two", "other"],
+    months: EN_MONTHS,
+    short_months: EN_SHORT_MONTHS,
+    narrow_months: LATIN_NARROW_MONTHS,
+    week_days: EN_WEEK_DAYS,
+    short_week_days: EN_SHORT_WEEK_DAYS,
+    narrow_week_days: ["S", "M", "T", "W", "T", "F", "S"],
+    day_periods: ["AM", "PM"],
+    day_period_first: false,
+    hour12: true,
+    two_digit_hour: false,
+    date_order: DateOrder::MonthDayYear,
+    date_separator: "/",
+    two_digit_date: false,
+    cjk_date: false,
+    day_suffix: "",
+    week_day_separator: ", ",
+    date_time_separator: ", ",
+    long_date_time_separator: " at ",
+};
+
+const EN_GB: LocaleData = LocaleData {
+    currency_pattern: "{1}{0}",
+    day_periods: ["am", "pm"],
+    hour12: false,
+    two_digit_hour: true,
+    date_order: DateOrder::DayMonthYear,
+    two_digit_date: true,
+    week_day_separator: " ",
+    ..EN
+};
+
+const DE: LocaleData = LocaleData {
+    decimal: ",",
+    group: ".",
+    minus_sign: "-",
+    plus_sign: "+",
+    infinity: "∞",
+    nan: "NaN",
+    percent_pattern: "{0}\u{a0}%",
+    currency_pattern: "{0}\u{a0}{1}",
+    currency_name_pattern: "{0} {1}",
+    currencies: &[
+        CurrencyNames {
+            code: "USD",
+            symbol: "$",
+            narrow_symbol: "$",
+            one: "US-Dollar",
+            other: "US-Dollar",
+        },
+        CurrencyNames {
+            code: "EUR",
+            symbol: "€",
+            narrow_symbol: "€",
+            one: "Euro",
+            other: "Euro",
+        },
+        CurrencyNames {
+            code: "GBP",
+            symbol: "£",
+            narrow_symbol: "£",
+            one: "Britisches Pfund",
+            other: "Britische Pfund",
+        },
+        CurrencyNames {
+            code: "JPY",
+            symbol: "¥",
+            narrow_symbol: "¥",
+            one: "Japanischer Yen",
+            other: "Japanische Yen",
+        },
+    ],
+    plural_rule: plural_one_integer,
+    ordinal_rule: plural_other,
+    plural_categories: &["one", "other"],
+    ordinal_categories: &["other"],
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    short_months: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    narrow_months: LATIN_NARROW_MONTHS,
+    week_days: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+    short_week_days: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+    narrow_week_days: ["S", "M", "D", "M", "D", "F", "S"],
+    day_periods: ["AM", "PM"],
+    day_period_first: false,
+    hour12: false,
+    two_digit_hour: true,
+    date_order: DateOrder::DayMonthYear,
+    date_separator: ".",
+    two_digit_date: false,
+    cjk_date: false,
+    day_suffix: ".",
+    week_day_separator: ", ",
+    date_time_separator: ", ",
+    long_date_time_separator: " um ",
+};
+
+const FR: LocaleData = LocaleData {
+    decimal: ",",
+    group: "\u{202f}",
+    minus_sign: "-",
+    plus_sign: "+",
+    infinity: "∞",
+    nan: "NaN",
+    percent_pattern: "{0}\u{202f}%",
+    currency_pattern: "{0}\u{a0}{1}",
+    currency_name_pattern: "{0} {1}",
+    currencies: &[
+        CurrencyNames {
+            code: "USD",
+            symbol: "$US",
+            narrow_symbol: "$",
+            one: "dollar des États-Unis",
+            other: "dollars des États-Unis",
+        },
+        CurrencyNames {
+            code: "EUR",
+            symbol: "€",
+            narrow_symbol: "€",
+            one: "euro",
+            other: "euros",
+        },
+        CurrencyNames {
+            code: "GBP",
+            symbol: "£GB",
+            narrow_symbol: "£",
+            one: "livre sterling",
+            other: "livres sterling",
+        },
+        CurrencyNames {
+            code: "JPY",
+            symbol: "JPY",
+            narrow_symbol: "¥",
+            one: "yen japonais",
+            other: "yens japonais",
+        },
+    ],
+    plural_rule: plural_french,
+    ordinal_rule: ordinal_french,
+    plural_categories: &["one", "other"],
+    ordinal_categories: &["one", "other"],
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    narrow_months: LATIN_NARROW_MONTHS,
+    week_days: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+    short_week_days: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    narrow_week_days: ["D", "L", "M", "M", "J", "V", "S"],
+    day_periods: ["AM", "PM"],
+    day_period_first: false,
+    hour12: false,
+    two_digit_hour: true,
+    date_order: DateOrder::DayMonthYear,
+    date_separator: "/",
+    two_digit_date: true,
+    cjk_date: false,
+    day_suffix: "",
+    week_day_separator: " ",
+    date_time_separator: " ",
+    long_date_time_separator: " à ",
+};
+
+const JA: LocaleData = LocaleData {
+    decimal: ".",
+    group: ",",
+    minus_sign: "-",
+    plus_sign: "+",
+    infinity: "∞",
+    nan: "NaN",
+    percent_pattern: "{0}%",
+    currency_pattern: "{1}{0}",
+    currency_name_pattern: "{0} {1}",
+    currencies: &[
+        CurrencyNames {
+            code: "USD",
+            symbol: "$",
+            narrow_symbol: "$",
+            one: "米ドル",
+            other: "米ドル",
+        },
+        CurrencyNames {
+            code: "EUR",
+            symbol: "€",
+            narrow_symbol: "€",
+            one: "ユーロ",
+            other: "ユーロ",
+        },
+        CurrencyNames {
+            code: "GBP",
+            symbol: "£",
+            narrow_symbol: "£",
+            one: "英国ポンド",
+            other: "英国ポンド",
+        },
+        CurrencyNames {
+            code: "JPY",
+            symbol: "￥",
+            narrow_symbol: "￥",
+            one: "円",
+            other: "円",
+        },
+    ],
+    plural_rule: plural_other,
+    ordinal_rule: plural_other,
+    plural_categories: &["other"],
+    ordinal_categories: &["other"],
+    months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    short_months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    narrow_months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    week_days: [
+        "日曜日",
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+    ],
+    short_week_days: ["日", "月", "火", "水", "木", "金", "土"],
+    narrow_week_days: ["日", "月", "火", "水", "木", "金", "土"],
+    day_periods: ["午前", "午後"],
+    day_period_first: true,
+    hour12: false,
+    two_digit_hour: false,
+    date_order: DateOrder::YearMonthDay,
+    date_separator: "/",
+    two_digit_date: false,
+    cjk_date: true,
+    day_suffix: "",
+    week_day_separator: "",
+    date_time_separator: " ",
+    long_date_time_separator: " ",
+};
+
+/// The locales with embedded data, by their language tag.
+///
+/// The default locale is the first one.
+pub(crate) const AVAILABLE_LOCALES: &[(&str, &LocaleData)] = &[
+    ("en-US", &EN),
+    ("en", &EN),
+    ("en-GB", &EN_GB),
+    ("de", &DE),
+    ("fr", &FR),
+    ("ja", &JA),
+];
+
+/// Gets the data of an available locale.
+pub(crate) fn locale_data(tag: &str) -> Option<&'static LocaleData> {
+    AVAILABLE_LOCALES
+        .iter()
+        .find(|(available, _)| *available == tag)
+        .map(|(_, data)| *data)
+}
+
+/// The number of digits after the decimal separator of the amounts of a currency.
+///
+/// More information:
+///  - [ISO 4217][iso]
+///
+/// [iso]: https://www.six-group.com/en/products-services/financial-information/data-standards.html
+pub(crate) fn currency_digits(code: &str) -> usize {
+    match code {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
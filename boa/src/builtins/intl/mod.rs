@@ -0,0 +1,621 @@
+//! This module implements the global `Intl` object.
+//!
+//! The `Intl` object is the namespace of the ECMAScript Internationalization API, which provides
+//! language sensitive string comparison, number formatting, and date and time formatting.
+//!
+//! The locale data is embedded for a few locales, the other locales fall back to them or to the
+//! default locale, `en-US`. The whole module can be left out of size sensitive builds, by turning
+//! off the `intl` feature.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl
+
+pub mod collator;
+pub mod date_time_format;
+mod locale_data;
+pub mod number_format;
+pub mod plural_rules;
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    collator::CollatorData, date_time_format::DateTimeFormatData, number_format::NumberFormatData,
+    plural_rules::PluralRulesData,
+};
+
+use self::locale_data::{locale_data, LocaleData, AVAILABLE_LOCALES};
+use crate::{
+    builtins::{
+        array::{construct_array, length_of_array_like, new_array},
+        function::{self, NativeFunctionData},
+        object::{ObjectKind, INSTANCE_PROTOTYPE, PROTOTYPE},
+        property::Property,
+        symbol::well_known_symbol,
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+use std::fmt::{self, Display};
+
+/// The internal slots of the objects of the `Intl` constructors.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub enum IntlData {
+    Collator(CollatorData),
+    NumberFormat(NumberFormatData),
+    DateTimeFormat(DateTimeFormatData),
+    PluralRules(PluralRulesData),
+}
+
+impl IntlData {
+    /// The name of the constructor of the object.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Collator(_) => "Intl.Collator",
+            Self::NumberFormat(_) => "Intl.NumberFormat",
+            Self::DateTimeFormat(_) => "Intl.DateTimeFormat",
+            Self::PluralRules(_) => "Intl.PluralRules",
+        }
+    }
+}
+
+/// A structurally valid Unicode BCP 47 locale identifier, split into its subtags.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [Unicode Technical Standard #35][uts35]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-isstructurallyvalidlanguagetag
+/// [uts35]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LanguageTag {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+    /// The attributes of the `u` extension.
+    attributes: Vec<String>,
+    /// The keywords of the `u` extension, like `kn-true`, with an empty value for `true`.
+    keywords: Vec<(String, String)>,
+    /// The other extensions and the private use subtags, as written.
+    others: Vec<String>,
+}
+
+/// Checks whether `subtag` has between `min` and `max` ASCII characters matching `predicate`.
+fn subtag_is(subtag: &str, min: usize, max: usize, predicate: fn(&u8) -> bool) -> bool {
+    subtag.len() >= min && subtag.len() <= max && subtag.as_bytes().iter().all(predicate)
+}
+
+impl LanguageTag {
+    /// Parses and canonicalizes a language tag, or returns `None` if it isn't structurally valid.
+    pub(crate) fn parse(tag: &str) -> Option<Self> {
+        let lower = tag.to_ascii_lowercase();
+        let mut subtags = lower.split('-').peekable();
+
+        let language = subtags.next()?.to_string();
+        if !subtag_is(&language, 2, 3, u8::is_ascii_alphabetic)
+            && !subtag_is(&language, 5, 8, u8::is_ascii_alphabetic)
+        {
+            return None;
+        }
+        let mut result = Self {
+            language,
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            attributes: Vec::new(),
+            keywords: Vec::new(),
+            others: Vec::new(),
+        };
+
+        if let Some(script) = subtags.next_if(|s| subtag_is(s, 4, 4, u8::is_ascii_alphabetic)) {
+            let (first, rest) = script.split_at(1);
+            result.script = Some(first.to_ascii_uppercase() + rest);
+        }
+        if let Some(region) = subtags.next_if(|s| {
+            subtag_is(s, 2, 2, u8::is_ascii_alphabetic) || subtag_is(s, 3, 3, u8::is_ascii_digit)
+        }) {
+            result.region = Some(region.to_ascii_uppercase());
+        }
+        while let Some(variant) = subtags.next_if(|s| {
+            subtag_is(s, 5, 8, u8::is_ascii_alphanumeric)
+                || (subtag_is(s, 4, 4, u8::is_ascii_alphanumeric)
+                    && s.as_bytes()[0].is_ascii_digit())
+        }) {
+            if result.variants.iter().any(|v| v == variant) {
+                return None;
+            }
+            result.variants.push(variant.to_string());
+        }
+        result.variants.sort();
+
+        let mut singletons = Vec::new();
+        let mut others = Vec::new();
+        while let Some(singleton) = subtags.next() {
+            if !subtag_is(singleton, 1, 1, u8::is_ascii_alphanumeric)
+                || singletons.contains(&singleton)
+            {
+                return None;
+            }
+            singletons.push(singleton);
+
+            if singleton == "x" {
+                let private: Vec<&str> = subtags.by_ref().collect();
+                if private.is_empty()
+                    || !private
+                        .iter()
+                        .all(|s| subtag_is(s, 1, 8, u8::is_ascii_alphanumeric))
+                {
+                    return None;
+                }
+                result.others.push(format!("x-{}", private.join("-")));
+                break;
+            }
+
+            let mut extension = Vec::new();
+            while let Some(subtag) = subtags.next_if(|s| s.len() > 1) {
+                if !subtag_is(subtag, 2, 8, u8::is_ascii_alphanumeric) {
+                    return None;
+                }
+                extension.push(subtag);
+            }
+            if extension.is_empty() {
+                return None;
+            }
+            if singleton != "u" {
+                others.push(format!("{}-{}", singleton, extension.join("-")));
+                continue;
+            }
+
+            // The keywords repeating an earlier key are ignored
+            let mut duplicate_key = false;
+            for subtag in extension {
+                if subtag.len() == 2 {
+                    duplicate_key = result.keywords.iter().any(|(key, _)| key == subtag);
+                    if !duplicate_key {
+                        result.keywords.push((subtag.to_string(), String::new()));
+                    }
+                } else if duplicate_key {
+                    continue;
+                } else if let Some((_, value)) = result.keywords.last_mut() {
+                    if !value.is_empty() {
+                        value.push('-');
+                    }
+                    value.push_str(subtag);
+                } else {
+                    result.attributes.push(subtag.to_string());
+                }
+            }
+            for (_, value) in result.keywords.iter_mut() {
+                if value == "true" {
+                    value.clear();
+                }
+            }
+            result.keywords.sort();
+        }
+        others.sort();
+        others.append(&mut result.others);
+        result.others = others;
+        Some(result)
+    }
+
+    /// The tag without its extensions, like `en-US`.
+    pub(crate) fn base_name(&self) -> String {
+        let mut name = self.language.clone();
+        for subtag in self
+            .script
+            .iter()
+            .chain(self.region.iter())
+            .chain(self.variants.iter())
+        {
+            name.push('-');
+            name.push_str(subtag);
+        }
+        name
+    }
+
+    /// Gets the value of the keyword `key` of the `u` extension, where `true` is empty.
+    fn keyword(&self, key: &str) -> Option<&str> {
+        self.keywords
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.base_name())?;
+        let mut others = self.others.iter().peekable();
+        // The extensions are sorted by their singleton, and `u` comes after `a` to `t`
+        while let Some(other) = others.next_if(|other| other.as_str() < "u") {
+            write!(f, "-{}", other)?;
+        }
+        if !self.attributes.is_empty() || !self.keywords.is_empty() {
+            write!(f, "-u")?;
+            for attribute in self.attributes.iter() {
+                write!(f, "-{}", attribute)?;
+            }
+            for (key, value) in self.keywords.iter() {
+                write!(f, "-{}", key)?;
+                if !value.is_empty() {
+                    write!(f, "-{}", value)?;
+                }
+            }
+        }
+        for other in others {
+            write!(f, "-{}", other)?;
+        }
+        Ok(())
+    }
+}
+
+/// The abstract operation `CanonicalizeLocaleList`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizelocalelist
+pub(crate) fn canonicalize_locale_list(
+    locales: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Vec<LanguageTag>, Value> {
+    if locales.is_undefined() {
+        return Ok(Vec::new());
+    }
+    let list = if locales.is_string() {
+        vec![locales.clone()]
+    } else {
+        let object = ctx.to_object(locales)?;
+        let len = length_of_array_like(&object, ctx)?;
+        let mut list = Vec::with_capacity(len);
+        for index in 0..len {
+            let key = to_value(index.to_string());
+            if ctx.has_property(&object, &key)? {
+                list.push(ctx.get_property(&object, &key)?);
+            }
+        }
+        list
+    };
+
+    let mut seen: Vec<LanguageTag> = Vec::new();
+    for value in list {
+        if !value.is_string() && !value.is_object() {
+            return Err(ctx.construct_type_error("Language ID should be string or object."));
+        }
+        let tag = ctx.value_to_rust_string(&value);
+        match LanguageTag::parse(&tag) {
+            Some(tag) if !seen.contains(&tag) => seen.push(tag),
+            Some(_) => {}
+            None => {
+                return Err(ctx.construct_range_error(format!(
+                    "Incorrect locale information provided: {}",
+                    tag
+                )))
+            }
+        }
+    }
+    Ok(seen)
+}
+
+/// The abstract operation `LookupMatcher`, without the extensions.
+///
+/// Gets the available locale of the first requested locale which has one, like `de` for `de-AT`,
+/// along with the requested locale.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-lookupmatcher
+fn lookup_matcher(requested: &[LanguageTag]) -> Option<(&'static str, &LanguageTag)> {
+    requested.iter().find_map(|tag| {
+        let mut candidate = tag.base_name();
+        loop {
+            if let Some((available, _)) = AVAILABLE_LOCALES.iter().find(|(a, _)| *a == candidate) {
+                return Some((*available, tag));
+            }
+            candidate.truncate(candidate.rfind('-')?);
+        }
+    })
+}
+
+/// A locale picked among the available ones, along with the keywords of its `u` extension
+/// which are supported.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-resolvelocale
+#[derive(Debug)]
+pub(crate) struct ResolvedLocale {
+    base_name: &'static str,
+    data: &'static LocaleData,
+    requested: Option<LanguageTag>,
+    supported_keywords: Vec<(&'static str, String)>,
+}
+
+impl ResolvedLocale {
+    /// The abstract operation `ResolveLocale`, with the lookup matcher.
+    pub(crate) fn new(requested: &[LanguageTag]) -> Self {
+        let (base_name, requested) = match lookup_matcher(requested) {
+            Some((base_name, tag)) => (base_name, Some(tag.clone())),
+            None => (AVAILABLE_LOCALES[0].0, None),
+        };
+        Self {
+            base_name,
+            data: locale_data(base_name).expect("available locale"),
+            requested,
+            supported_keywords: Vec::new(),
+        }
+    }
+
+    /// Resolves the value of a relevant extension key, like `kn`, where `true` is the empty string.
+    ///
+    /// The value of the option takes precedence over the keyword of the requested locale, which
+    /// is kept in the resolved locale only when it's the value used.
+    pub(crate) fn keyword(
+        &mut self,
+        key: &'static str,
+        option: Option<String>,
+        is_supported: fn(&str) -> bool,
+    ) -> Option<String> {
+        let requested = self
+            .requested
+            .as_ref()
+            .and_then(|tag| tag.keyword(key))
+            .filter(|value| is_supported(value))
+            .map(str::to_string);
+        match (requested, option) {
+            (Some(requested), Some(ref option)) if requested == *option => {
+                self.supported_keywords.push((key, requested.clone()));
+                Some(requested)
+            }
+            (_, Some(option)) => Some(option),
+            (Some(requested), None) => {
+                self.supported_keywords.push((key, requested.clone()));
+                Some(requested)
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// The data of the locale.
+    pub(crate) fn data(&self) -> &'static LocaleData {
+        self.data
+    }
+
+    /// The resolved locale, with the supported keywords of its `u` extension.
+    pub(crate) fn locale(&self) -> String {
+        let mut locale = self.base_name.to_string();
+        if !self.supported_keywords.is_empty() {
+            locale.push_str("-u");
+            for (key, value) in self.supported_keywords.iter() {
+                locale.push('-');
+                locale.push_str(key);
+                if !value.is_empty() {
+                    locale.push('-');
+                    locale.push_str(value);
+                }
+            }
+        }
+        locale
+    }
+}
+
+/// The abstract operation `CoerceOptionsToObject`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
+pub(crate) fn coerce_options_to_object(options: &Value, ctx: &mut Interpreter) -> ResultValue {
+    if options.is_undefined() {
+        let object = ValueData::new_obj(None);
+        object.set_internal_slot(INSTANCE_PROTOTYPE, Value::new(ValueData::Null));
+        Ok(object)
+    } else {
+        ctx.to_object(options)
+    }
+}
+
+/// The abstract operation `GetOption` for string options, which must be one of `values`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_option(
+    options: &Value,
+    property: &str,
+    values: &[&str],
+    ctx: &mut Interpreter,
+) -> Result<Option<String>, Value> {
+    let value = ctx.get_property(options, &to_value(property))?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = ctx.value_to_rust_string(&value);
+    if !values.is_empty() && !values.contains(&value.as_str()) {
+        return Err(ctx.construct_range_error(format!(
+            "Value {} out of range for options property {}",
+            value, property
+        )));
+    }
+    Ok(Some(value))
+}
+
+/// The abstract operation `GetOption` for boolean options.
+pub(crate) fn get_bool_option(
+    options: &Value,
+    property: &str,
+    ctx: &mut Interpreter,
+) -> Result<Option<bool>, Value> {
+    let value = ctx.get_property(options, &to_value(property))?;
+    if value.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(value.is_true()))
+    }
+}
+
+/// The abstract operation `GetNumberOption`, for integers between `min` and `max`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getnumberoption
+pub(crate) fn get_number_option(
+    options: &Value,
+    property: &str,
+    min: usize,
+    max: usize,
+    ctx: &mut Interpreter,
+) -> Result<Option<usize>, Value> {
+    let value = ctx.get_property(options, &to_value(property))?;
+    default_number_option(&value, property, min, max, ctx)
+}
+
+/// The abstract operation `DefaultNumberOption`, for the value of the option `property`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-defaultnumberoption
+pub(crate) fn default_number_option(
+    value: &Value,
+    property: &str,
+    min: usize,
+    max: usize,
+    ctx: &mut Interpreter,
+) -> Result<Option<usize>, Value> {
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let number = ctx.to_number(value)?;
+    if number.is_nan() || number < min as f64 || number > max as f64 {
+        return Err(ctx.construct_range_error(format!("{} value is out of range.", property)));
+    }
+    Ok(Some(number.floor() as usize))
+}
+
+/// Creates an object of the `Intl` constructor `name`, with its prototype.
+///
+/// This is used by the constructors which create an object when they're called as functions.
+fn new_intl_object(name: &str, ctx: &mut Interpreter) -> ResultValue {
+    let constructor = ctx
+        .realm
+        .global_obj
+        .get_field_slice("Intl")
+        .get_field_slice(name);
+    let object = ValueData::new_obj(None);
+    object.set_internal_slot(INSTANCE_PROTOTYPE, constructor.get_field_slice(PROTOTYPE));
+    Ok(object)
+}
+
+/// Gets the internal slots of an object of the `Intl` constructors, or throws a `TypeError`
+/// if `this` isn't an object of the constructor of `name`.
+fn this_intl_data(
+    this: &Value,
+    name: &str,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<IntlData, Value> {
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::Intl(ref data) = obj.borrow().kind {
+            if data.name() == name {
+                return Ok(data.clone());
+            }
+        }
+    }
+    Err(ctx.construct_type_error(format!(
+        "Method {}.prototype.{} called on incompatible receiver {}",
+        name, method, this
+    )))
+}
+
+/// Creates an anonymous function calling the native `method` with `this` as its `this` value, like
+/// the `compare` function of a collator.
+fn create_bound_method(
+    this: &Value,
+    method: NativeFunctionData,
+    length: i32,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let mut native = make_builtin_fn!(@function method, named "", with length length);
+    let bound = function::bind(&mut native, std::slice::from_ref(this), ctx)?;
+    function::set_function_name_and_length(&bound, to_value(""), f64::from(length));
+    Ok(bound)
+}
+
+/// The `supportedLocalesOf( locales[, options] )` method of the `Intl` constructors.
+///
+/// Gets the requested locales which are supported, without falling back to the default locale.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-supportedlocales
+pub fn supported_locales_of(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let requested = canonicalize_locale_list(&args.get(0).cloned().unwrap_or_else(undefined), ctx)?;
+    let options = coerce_options_to_object(&args.get(1).cloned().unwrap_or_else(undefined), ctx)?;
+    get_option(&options, "localeMatcher", &["lookup", "best fit"], ctx)?;
+
+    let supported: Vec<Value> = requested
+        .iter()
+        .filter(|tag| lookup_matcher(std::slice::from_ref(tag)).is_some())
+        .map(|tag| to_value(tag.to_string()))
+        .collect();
+    let array = new_array(ctx)?;
+    construct_array(&array, &supported)
+}
+
+/// `Intl.getCanonicalLocales( locales )`
+///
+/// Gets the canonical language tags of the locales.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.getcanonicallocales
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/getCanonicalLocales
+pub fn get_canonical_locales(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let locales = canonicalize_locale_list(&args.get(0).cloned().unwrap_or_else(undefined), ctx)?;
+    let locales: Vec<Value> = locales
+        .iter()
+        .map(|tag| to_value(tag.to_string()))
+        .collect();
+    let array = new_array(ctx)?;
+    construct_array(&array, &locales)
+}
+
+/// Create a new `Intl` object.
+pub fn create(global: &Value) -> Value {
+    let intl = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(get_canonical_locales, named "getCanonicalLocales", with length 1, of intl);
+    intl.set_field_slice("Collator", collator::create(global));
+    intl.set_field_slice("DateTimeFormat", date_time_format::create(global));
+    intl.set_field_slice("NumberFormat", number_format::create(global));
+    intl.set_field_slice("PluralRules", plural_rules::create(global));
+    intl.set_symbol_prop(
+        &well_known_symbol(global, "toStringTag"),
+        Property::default()
+            .value(to_value("Intl"))
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    intl
+}
+
+/// Initialise the `Intl` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Intl", create(global));
+}
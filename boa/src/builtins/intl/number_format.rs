@@ -0,0 +1,601 @@
+//! This module implements the `Intl.NumberFormat` constructor.
+//!
+//! `Intl.NumberFormat` objects format numbers as decimals, percentages or amounts of money,
+//! with the separators of their locale.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, create_bound_method, default_number_option,
+    get_bool_option, get_number_option, get_option,
+    locale_data::{currency_digits, LocaleData, PluralCategory, PluralOperands},
+    new_intl_object, supported_locales_of, this_intl_data, IntlData, ResolvedLocale,
+};
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// How the number of digits of a formatted number is limited.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Rounding {
+    FractionDigits { min: usize, max: usize },
+    SignificantDigits { min: usize, max: usize },
+}
+
+/// The digit options of number formats and plural rules.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DigitOptions {
+    pub(crate) minimum_integer_digits: usize,
+    pub(crate) rounding: Rounding,
+}
+
+impl DigitOptions {
+    /// The abstract operation `SetNumberFormatDigitOptions`.
+    pub(crate) fn new(
+        options: &Value,
+        mnfd_default: usize,
+        mxfd_default: usize,
+        ctx: &mut Interpreter,
+    ) -> Result<Self, Value> {
+        let minimum_integer_digits =
+            get_number_option(options, "minimumIntegerDigits", 1, 21, ctx)?.unwrap_or(1);
+        let mnfd = ctx.get_property(options, &to_value("minimumFractionDigits"))?;
+        let mxfd = ctx.get_property(options, &to_value("maximumFractionDigits"))?;
+        let mnsd = ctx.get_property(options, &to_value("minimumSignificantDigits"))?;
+        let mxsd = ctx.get_property(options, &to_value("maximumSignificantDigits"))?;
+
+        let rounding = if !mnsd.is_undefined() || !mxsd.is_undefined() {
+            let min =
+                default_number_option(&mnsd, "minimumSignificantDigits", 1, 21, ctx)?.unwrap_or(1);
+            let max = default_number_option(&mxsd, "maximumSignificantDigits", min, 21, ctx)?
+                .unwrap_or(21);
+            Rounding::SignificantDigits { min, max }
+        } else if !mnfd.is_undefined() || !mxfd.is_undefined() {
+            let min = default_number_option(&mnfd, "minimumFractionDigits", 0, 20, ctx)?;
+            let max = default_number_option(&mxfd, "maximumFractionDigits", 0, 20, ctx)?;
+            match (min, max) {
+                (None, Some(max)) => Rounding::FractionDigits {
+                    min: mnfd_default.min(max),
+                    max,
+                },
+                (Some(min), None) => Rounding::FractionDigits {
+                    min,
+                    max: mxfd_default.max(min),
+                },
+                (Some(min), Some(max)) if min > max => {
+                    return Err(
+                        ctx.construct_range_error("maximumFractionDigits value is out of range.")
+                    )
+                }
+                (Some(min), Some(max)) => Rounding::FractionDigits { min, max },
+                (None, None) => unreachable!("one of the fraction digits options is defined"),
+            }
+        } else {
+            Rounding::FractionDigits {
+                min: mnfd_default,
+                max: mxfd_default,
+            }
+        };
+
+        Ok(Self {
+            minimum_integer_digits,
+            rounding,
+        })
+    }
+
+    /// Rounds the finite number `x`, which must not be negative, and splits it into its integer
+    /// and fraction digits.
+    ///
+    /// The digits are those of the shortest decimal which converts back to `x`, the ones
+    /// `Number.prototype.toString` shows, and ties are rounded away from zero on them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumberstring
+    pub(crate) fn round(&self, x: f64) -> (String, String) {
+        debug_assert!(x.is_finite() && x >= 0.0);
+        // `Display` writes the shortest round-trip digits, never in exponential notation
+        let shortest = x.to_string();
+        let mut point = shortest.find('.').unwrap_or(shortest.len());
+        let mut digits: Vec<u8> = shortest
+            .bytes()
+            .filter(|b| *b != b'.')
+            .map(|b| b - b'0')
+            .collect();
+
+        let (mut keep, min_fraction) = match self.rounding {
+            Rounding::FractionDigits { min, max } => (point + max, min),
+            Rounding::SignificantDigits { min, max } => {
+                match digits.iter().position(|d| *d != 0) {
+                    Some(first) => {
+                        // The digits kept after the point, at least `min` significant ones
+                        let keep = first + max;
+                        let min_fraction = (first + min).saturating_sub(point);
+                        (keep, min_fraction)
+                    }
+                    None => (point + min - 1, min - 1),
+                }
+            }
+        };
+
+        // Round half up at `keep`, carrying into the kept digits
+        if matches!(digits.get(keep), Some(digit) if *digit >= 5) {
+            let mut index = keep;
+            loop {
+                if index == 0 {
+                    digits.insert(0, 1);
+                    point += 1;
+                    keep += 1;
+                    break;
+                }
+                index -= 1;
+                if digits[index] == 9 {
+                    digits[index] = 0;
+                } else {
+                    digits[index] += 1;
+                    break;
+                }
+            }
+        }
+        digits.truncate(keep);
+        // Significant digits may end in the integer part, which is padded with zeros
+        digits.resize(digits.len().max(point), 0);
+
+        let to_string = |digits: &[u8]| digits.iter().map(|d| char::from(d + b'0')).collect();
+        let mut integer: String = to_string(&digits[..point]);
+        let mut fraction: String = to_string(&digits[point..]);
+
+        while fraction.len() > min_fraction && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        while fraction.len() < min_fraction {
+            fraction.push('0');
+        }
+        let integer_start = integer.find(|c| c != '0').unwrap_or(integer.len());
+        integer.drain(..integer_start);
+        while integer.len() < self.minimum_integer_digits {
+            integer.insert(0, '0');
+        }
+        (integer, fraction)
+    }
+
+    /// Gets the plural operands of the number `x` once it's rounded.
+    pub(crate) fn plural_operands(&self, x: f64) -> PluralOperands {
+        let (integer, fraction) = self.round(x.abs());
+        let n: f64 = format!("{}.{}", integer, fraction)
+            .trim_end_matches('.')
+            .parse()
+            .unwrap_or(0.0);
+        PluralOperands {
+            n,
+            i: integer.parse().unwrap_or(u64::MAX),
+            v: fraction.len(),
+        }
+    }
+}
+
+/// The style of a number format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Style {
+    Decimal,
+    Percent,
+    Currency,
+}
+
+/// How the currency of an amount of money is displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CurrencyDisplay {
+    Symbol,
+    NarrowSymbol,
+    Code,
+    Name,
+}
+
+/// When the sign of a formatted number is displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+}
+
+/// The resolved options of a number format.
+#[derive(Debug, Clone)]
+pub(crate) struct NumberFormat {
+    locale: String,
+    data: &'static LocaleData,
+    style: Style,
+    currency: Option<String>,
+    currency_display: CurrencyDisplay,
+    digits: DigitOptions,
+    use_grouping: bool,
+    sign_display: SignDisplay,
+}
+
+impl NumberFormat {
+    /// The abstract operation `InitializeNumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &Value,
+        options: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<Self, Value> {
+        let requested = canonicalize_locale_list(locales, ctx)?;
+        let options = coerce_options_to_object(options, ctx)?;
+        get_option(&options, "localeMatcher", &["lookup", "best fit"], ctx)?;
+        let numbering_system = get_option(&options, "numberingSystem", &[], ctx)?;
+        let mut resolved = ResolvedLocale::new(&requested);
+        // Only the Latin digits are supported
+        resolved.keyword("nu", numbering_system.filter(|nu| nu == "latn"), |nu| {
+            nu == "latn"
+        });
+
+        let style = match get_option(&options, "style", &["decimal", "percent", "currency"], ctx)?
+            .as_deref()
+        {
+            Some("percent") => Style::Percent,
+            Some("currency") => Style::Currency,
+            _ => Style::Decimal,
+        };
+        let currency = match get_option(&options, "currency", &[], ctx)? {
+            Some(currency) => {
+                if currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_alphabetic()) {
+                    return Err(
+                        ctx.construct_range_error(format!("Invalid currency code : {}", currency))
+                    );
+                }
+                Some(currency.to_ascii_uppercase())
+            }
+            None if style == Style::Currency => {
+                return Err(
+                    ctx.construct_type_error("Currency code is required with currency style.")
+                )
+            }
+            None => None,
+        };
+        let currency_display = match get_option(
+            &options,
+            "currencyDisplay",
+            &["code", "symbol", "narrowSymbol", "name"],
+            ctx,
+        )?
+        .as_deref()
+        {
+            Some("code") => CurrencyDisplay::Code,
+            Some("narrowSymbol") => CurrencyDisplay::NarrowSymbol,
+            Some("name") => CurrencyDisplay::Name,
+            _ => CurrencyDisplay::Symbol,
+        };
+
+        let (mnfd_default, mxfd_default) = match style {
+            Style::Currency => {
+                let digits = currency_digits(currency.as_deref().unwrap_or_default());
+                (digits, digits)
+            }
+            Style::Percent => (0, 0),
+            Style::Decimal => (0, 3),
+        };
+        let digits = DigitOptions::new(&options, mnfd_default, mxfd_default, ctx)?;
+        let use_grouping = get_bool_option(&options, "useGrouping", ctx)?.unwrap_or(true);
+        let sign_display = match get_option(
+            &options,
+            "signDisplay",
+            &["auto", "never", "always", "exceptZero"],
+            ctx,
+        )?
+        .as_deref()
+        {
+            Some("never") => SignDisplay::Never,
+            Some("always") => SignDisplay::Always,
+            Some("exceptZero") => SignDisplay::ExceptZero,
+            _ => SignDisplay::Auto,
+        };
+
+        Ok(Self {
+            locale: resolved.locale(),
+            data: resolved.data(),
+            style,
+            currency,
+            currency_display,
+            digits,
+            use_grouping,
+            sign_display,
+        })
+    }
+
+    /// Inserts the group separators in the integer digits of a number.
+    fn group(&self, integer: &str) -> String {
+        if !self.use_grouping {
+            return integer.to_string();
+        }
+        let mut grouped = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && index % 3 == integer.len() % 3 {
+                grouped.push_str(self.data.group);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// The abstract operation `FormatNumeric`, formatting the number `x`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, x: f64) -> String {
+        let data = self.data;
+        if x.is_nan() {
+            return self.apply_pattern(String::new(), data.nan.to_string(), None);
+        }
+
+        let x = if self.style == Style::Percent {
+            x * 100.0
+        } else {
+            x
+        };
+        let negative = x.is_sign_negative();
+        let (number, is_zero, operands) = if x.is_infinite() {
+            (data.infinity.to_string(), false, None)
+        } else {
+            let (integer, fraction) = self.digits.round(x.abs());
+            let is_zero = integer.bytes().chain(fraction.bytes()).all(|b| b == b'0');
+            let mut number = self.group(&integer);
+            if !fraction.is_empty() {
+                number.push_str(data.decimal);
+                number.push_str(&fraction);
+            }
+            (number, is_zero, Some(self.digits.plural_operands(x)))
+        };
+
+        let sign = match self.sign_display {
+            SignDisplay::Never => "",
+            SignDisplay::Auto if negative => data.minus_sign,
+            SignDisplay::Always if negative => data.minus_sign,
+            SignDisplay::Always => data.plus_sign,
+            SignDisplay::ExceptZero if is_zero => "",
+            SignDisplay::ExceptZero if negative => data.minus_sign,
+            SignDisplay::ExceptZero => data.plus_sign,
+            SignDisplay::Auto => "",
+        };
+        self.apply_pattern(sign.to_string(), number, operands)
+    }
+
+    /// Puts the sign and the formatted number in the pattern of the style.
+    fn apply_pattern(
+        &self,
+        sign: String,
+        number: String,
+        operands: Option<PluralOperands>,
+    ) -> String {
+        let data = self.data;
+        match self.style {
+            Style::Decimal => sign + &number,
+            Style::Percent => sign + &data.percent_pattern.replace("{0}", &number),
+            Style::Currency => {
+                let code = self.currency.as_deref().unwrap_or_default();
+                let names = data.currencies.iter().find(|names| names.code == code);
+                let (pattern, currency) = match (self.currency_display, names) {
+                    (CurrencyDisplay::Name, Some(names)) => {
+                        let one =
+                            matches!(operands.map(data.plural_rule), Some(PluralCategory::One));
+                        let name = if one { names.one } else { names.other };
+                        (data.currency_name_pattern, name.to_string())
+                    }
+                    (CurrencyDisplay::Name, None) => (data.currency_name_pattern, code.to_string()),
+                    (CurrencyDisplay::Symbol, Some(names)) => {
+                        (data.currency_pattern, names.symbol.to_string())
+                    }
+                    (CurrencyDisplay::NarrowSymbol, Some(names)) => {
+                        (data.currency_pattern, names.narrow_symbol.to_string())
+                    }
+                    // Codes are separated from the number, like `USD 1.00`
+                    _ if data.currency_pattern.starts_with("{1}") => {
+                        (data.currency_pattern, format!("{}\u{a0}", code))
+                    }
+                    _ => (data.currency_pattern, code.to_string()),
+                };
+                sign + &pattern.replace("{0}", &number).replace("{1}", &currency)
+            }
+        }
+    }
+}
+
+/// The internal slots of `Intl.NumberFormat` objects.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct NumberFormatData {
+    #[unsafe_ignore_trace]
+    format: NumberFormat,
+    /// The function returned by the `format` getter, once it has been created.
+    bound_format: Option<Value>,
+}
+
+/// Gets the number format of `this`, or throws a `TypeError`.
+fn this_number_format(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<NumberFormatData, Value> {
+    match this_intl_data(this, "Intl.NumberFormat", method, ctx)? {
+        IntlData::NumberFormat(ref data) => Ok(data.clone()),
+        _ => unreachable!("checked the kind of the object"),
+    }
+}
+
+/// Formats a number as `Number.prototype.toLocaleString( [locales [, options]] )` does.
+pub(crate) fn to_locale_string(x: f64, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let format = NumberFormat::new(&locales, &options, ctx)?;
+    Ok(to_value(format.format(x)))
+}
+
+/// `Intl.NumberFormat( [locales [, options]] )`
+///
+/// Creates a number format, when it's called as a function too.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+pub fn make_number_format(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let format = NumberFormat::new(&locales, &options, ctx)?;
+    this.set_kind(ObjectKind::Intl(IntlData::NumberFormat(NumberFormatData {
+        format,
+        bound_format: None,
+    })));
+    Ok(this.clone())
+}
+
+/// `Intl.NumberFormat` called as a function, which creates a number format.
+pub fn call_number_format(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut this = new_intl_object("NumberFormat", ctx)?;
+    make_number_format(&mut this, args, ctx)
+}
+
+/// The function returned by the `format` getter.
+fn format_number(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_number_format(this, "format", ctx)?;
+    let x = ctx.to_number(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    Ok(to_value(data.format.format(x)))
+}
+
+/// `get Intl.NumberFormat.prototype.format`
+///
+/// Gets a function formatting numbers with this number format, which is bound to it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+pub fn get_format(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_number_format(this, "format", ctx)?;
+    if let Some(ref bound_format) = data.bound_format {
+        return Ok(bound_format.clone());
+    }
+    let bound_format = create_bound_method(this, format_number, 1, ctx)?;
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::Intl(IntlData::NumberFormat(ref mut data)) = obj.borrow_mut().kind {
+            data.bound_format = Some(bound_format.clone());
+        }
+    }
+    Ok(bound_format)
+}
+
+/// `Intl.NumberFormat.prototype.resolvedOptions()`
+///
+/// Gets the locale and the options used by this number format.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+pub fn resolved_options(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let format = this_number_format(this, "resolvedOptions", ctx)?
+        .format
+        .clone();
+    let options = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    let mut entries = vec![
+        ("locale", to_value(format.locale.clone())),
+        ("numberingSystem", to_value("latn")),
+        (
+            "style",
+            to_value(match format.style {
+                Style::Decimal => "decimal",
+                Style::Percent => "percent",
+                Style::Currency => "currency",
+            }),
+        ),
+    ];
+    if let Some(currency) = format.currency {
+        entries.push(("currency", to_value(currency)));
+        entries.push((
+            "currencyDisplay",
+            to_value(match format.currency_display {
+                CurrencyDisplay::Symbol => "symbol",
+                CurrencyDisplay::NarrowSymbol => "narrowSymbol",
+                CurrencyDisplay::Code => "code",
+                CurrencyDisplay::Name => "name",
+            }),
+        ));
+    }
+    entries.extend(digit_options_entries(&format.digits));
+    entries.push(("useGrouping", to_value(format.use_grouping)));
+    entries.push(("notation", to_value("standard")));
+    entries.push((
+        "signDisplay",
+        to_value(match format.sign_display {
+            SignDisplay::Auto => "auto",
+            SignDisplay::Never => "never",
+            SignDisplay::Always => "always",
+            SignDisplay::ExceptZero => "exceptZero",
+        }),
+    ));
+
+    for (key, value) in entries {
+        ctx.create_data_property(&options, &to_value(key), value)?;
+    }
+    Ok(options)
+}
+
+/// The entries of the digit options in the resolved options of number formats and plural rules.
+pub(crate) fn digit_options_entries(digits: &DigitOptions) -> Vec<(&'static str, Value)> {
+    let mut entries = vec![(
+        "minimumIntegerDigits",
+        to_value(digits.minimum_integer_digits),
+    )];
+    match digits.rounding {
+        Rounding::FractionDigits { min, max } => {
+            entries.push(("minimumFractionDigits", to_value(min)));
+            entries.push(("maximumFractionDigits", to_value(max)));
+        }
+        Rounding::SignificantDigits { min, max } => {
+            entries.push(("minimumSignificantDigits", to_value(min)));
+            entries.push(("maximumSignificantDigits", to_value(max)));
+        }
+    }
+    entries
+}
+
+/// Create a new `Intl.NumberFormat` constructor.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(get_format, named "format", of prototype);
+    make_builtin_fn!(resolved_options, named "resolvedOptions", of prototype);
+
+    let number_format =
+        make_constructor_fn!(make_number_format, call_number_format, global, prototype);
+    make_builtin_fn!(supported_locales_of, named "supportedLocalesOf", with length 1, of number_format);
+    number_format
+}
@@ -0,0 +1,191 @@
+//! This module implements the `Intl.PluralRules` constructor.
+//!
+//! `Intl.PluralRules` objects select the plural category of numbers in their locale, like `one`
+//! or `other`, to pick the right form of the words counting them.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#pluralrules-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules
+
+use super::{
+    canonicalize_locale_list, coerce_options_to_object, get_option,
+    locale_data::LocaleData,
+    number_format::{digit_options_entries, DigitOptions},
+    supported_locales_of, this_intl_data, IntlData, ResolvedLocale,
+};
+use crate::{
+    builtins::{
+        array::{construct_array, new_array},
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// The resolved options of plural rules.
+#[derive(Debug, Clone)]
+pub(crate) struct PluralRules {
+    locale: String,
+    data: &'static LocaleData,
+    ordinal: bool,
+    digits: DigitOptions,
+}
+
+impl PluralRules {
+    /// The abstract operation `InitializePluralRules`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializepluralrules
+    fn new(locales: &Value, options: &Value, ctx: &mut Interpreter) -> Result<Self, Value> {
+        let requested = canonicalize_locale_list(locales, ctx)?;
+        let options = coerce_options_to_object(options, ctx)?;
+        get_option(&options, "localeMatcher", &["lookup", "best fit"], ctx)?;
+        let ordinal = get_option(&options, "type", &["cardinal", "ordinal"], ctx)?.as_deref()
+            == Some("ordinal");
+        let digits = DigitOptions::new(&options, 0, 3, ctx)?;
+        let resolved = ResolvedLocale::new(&requested);
+
+        Ok(Self {
+            locale: resolved.locale(),
+            data: resolved.data(),
+            ordinal,
+            digits,
+        })
+    }
+
+    /// The abstract operation `ResolvePlural`, selecting the plural category of `x`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
+    fn select(&self, x: f64) -> &'static str {
+        if !x.is_finite() {
+            return "other";
+        }
+        let operands = self.digits.plural_operands(x);
+        let rule = if self.ordinal {
+            self.data.ordinal_rule
+        } else {
+            self.data.plural_rule
+        };
+        rule(operands).name()
+    }
+}
+
+/// The internal slots of `Intl.PluralRules` objects.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct PluralRulesData {
+    #[unsafe_ignore_trace]
+    rules: PluralRules,
+}
+
+/// Gets the plural rules of `this`, or throws a `TypeError`.
+fn this_plural_rules(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<PluralRules, Value> {
+    match this_intl_data(this, "Intl.PluralRules", method, ctx)? {
+        IntlData::PluralRules(ref data) => Ok(data.rules.clone()),
+        _ => unreachable!("checked the kind of the object"),
+    }
+}
+
+/// `new Intl.PluralRules( [locales [, options]] )`
+///
+/// Creates plural rules.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules
+pub fn make_plural_rules(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let locales = args.get(0).cloned().unwrap_or_else(undefined);
+    let options = args.get(1).cloned().unwrap_or_else(undefined);
+    let rules = PluralRules::new(&locales, &options, ctx)?;
+    this.set_kind(ObjectKind::Intl(IntlData::PluralRules(PluralRulesData {
+        rules,
+    })));
+    Ok(this.clone())
+}
+
+/// `Intl.PluralRules` called as a function, which throws a `TypeError`.
+pub fn call_plural_rules(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor Intl.PluralRules requires 'new'")
+}
+
+/// `Intl.PluralRules.prototype.select( number )`
+///
+/// Gets the plural category of the number, like `one` or `other`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/select
+pub fn select(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let rules = this_plural_rules(this, "select", ctx)?;
+    let x = ctx.to_number(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    Ok(to_value(rules.select(x)))
+}
+
+/// `Intl.PluralRules.prototype.resolvedOptions()`
+///
+/// Gets the locale and the options used by these plural rules, with their plural categories.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+pub fn resolved_options(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let rules = this_plural_rules(this, "resolvedOptions", ctx)?;
+    let categories = if rules.ordinal {
+        rules.data.ordinal_categories
+    } else {
+        rules.data.plural_categories
+    };
+    let categories: Vec<Value> = categories.iter().map(|c| to_value(*c)).collect();
+    let plural_categories = new_array(ctx)?;
+    construct_array(&plural_categories, &categories)?;
+
+    let options = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    let mut entries = vec![
+        ("locale", to_value(rules.locale.clone())),
+        (
+            "type",
+            to_value(if rules.ordinal { "ordinal" } else { "cardinal" }),
+        ),
+    ];
+    entries.extend(digit_options_entries(&rules.digits));
+    entries.push(("pluralCategories", plural_categories));
+
+    for (key, value) in entries {
+        ctx.create_data_property(&options, &to_value(key), value)?;
+    }
+    Ok(options)
+}
+
+/// Create a new `Intl.PluralRules` constructor.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(select, named "select", with length 1, of prototype);
+    make_builtin_fn!(resolved_options, named "resolvedOptions", of prototype);
+
+    let plural_rules =
+        make_constructor_fn!(make_plural_rules, call_plural_rules, global, prototype);
+    make_builtin_fn!(supported_locales_of, named "supportedLocalesOf", with length 1, of plural_rules);
+    plural_rules
+}
@@ -0,0 +1,508 @@
+use super::LanguageTag;
+use crate::builtins::date::{clock::FixedClock, time_zone::PosixTimeZone};
+use crate::exec::{Executor, Interpreter};
+use crate::forward;
+use crate::realm::Realm;
+
+/// The time zone of New York, with daylight saving time from the second Sunday of March
+/// to the first Sunday of November.
+const NEW_YORK: &str = "EST5EDT,M3.2.0,M11.1.0";
+
+/// `2022-02-01T18:05:09.000Z`
+const FEBRUARY_FIRST: f64 = 1_643_738_709_000.0;
+
+fn engine() -> Interpreter {
+    let realm = Realm::create();
+    let mut engine: Interpreter = Executor::new(realm);
+    engine.set_time_zone(PosixTimeZone::parse(NEW_YORK).expect("invalid time zone"));
+    engine.set_clock(FixedClock(FEBRUARY_FIRST));
+    engine
+}
+
+#[test]
+fn language_tags() {
+    let tag = LanguageTag::parse("EN-latn-us-u-KN-true-ca-gregory-x-Private").expect("valid tag");
+    assert_eq!(tag.base_name(), "en-Latn-US");
+    assert_eq!(tag.to_string(), "en-Latn-US-u-ca-gregory-kn-x-private");
+    assert!(LanguageTag::parse("en-").is_none());
+    assert!(LanguageTag::parse("e").is_none());
+    assert!(LanguageTag::parse("de-1996-1996").is_none());
+
+    let mut engine = engine();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Intl.getCanonicalLocales(['EN-us', 'en-US', 'de']).join()"
+        ),
+        "en-US,de"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Intl.getCanonicalLocales('en_US') } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Intl.NumberFormat.supportedLocalesOf(['de-AT', 'tlh', 'ja-JP']).join()"
+        ),
+        "de-AT,ja-JP"
+    );
+    assert_eq!(forward(&mut engine, "Intl[Symbol.toStringTag]"), "Intl");
+}
+
+#[test]
+fn number_format() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en').format(1234567.891)"
+        ),
+        "1,234,567.891"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('de').format(1234567.891)"
+        ),
+        "1.234.567,891"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Intl.NumberFormat('fr').format(1234.5)"),
+        "1\u{202f}234,5"
+    );
+    assert_eq!(
+        forward(&mut engine, "Intl.NumberFormat('en').format(-0.0001)"),
+        "-0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { maximumFractionDigits: 2 }).format(1.005)"
+        ),
+        "1.01"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en').format(123456789012345680000)"
+        ),
+        "123,456,789,012,345,680,000"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { maximumFractionDigits: 20 }).format(0.1)"
+        ),
+        "0.1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { minimumFractionDigits: 20 }).format(0.1)"
+        ),
+        "0.10000000000000000000"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { minimumFractionDigits: 2, useGrouping: false }).format(1234.5)"
+        ),
+        "1234.50"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { maximumSignificantDigits: 3 }).format(123456)"
+        ),
+        "123,000"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "(2.5).toLocaleString('en', { maximumFractionDigits: 0 })"
+        ),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Intl.NumberFormat('en', { minimumFractionDigits: 3, maximumFractionDigits: 1 }) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn number_format_styles() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { style: 'percent' }).format(0.256)"
+        ),
+        "26%"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('de', { style: 'percent' }).format(0.256)"
+        ),
+        "26\u{a0}%"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'usd' }).format(-1234.5)"
+        ),
+        "-$1,234.50"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR' }).format(1234.5)"
+        ),
+        "1.234,50\u{a0}€"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('ja', { style: 'currency', currency: 'JPY' }).format(1234.5)"
+        ),
+        "￥1,235"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'CHF' }).format(1)"
+        ),
+        "CHF\u{a0}1.00"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD', currencyDisplay: 'name' }).format(1)"
+        ),
+        "1.00 US dollars"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat('en', { signDisplay: 'always' }).format(5)"
+        ),
+        "+5"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Intl.NumberFormat('en', { style: 'currency' }) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn number_format_resolved_options() {
+    let mut engine = engine();
+    let init = r#"
+        var format = new Intl.NumberFormat(['tlh', 'de-CH-u-nu-latn'], { style: 'currency', currency: 'JPY' });
+        var options = format.resolvedOptions();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "options.locale"), "de-u-nu-latn");
+    assert_eq!(forward(&mut engine, "options.maximumFractionDigits"), "0");
+    assert_eq!(forward(&mut engine, "options.currencyDisplay"), "symbol");
+    assert_eq!(
+        forward(&mut engine, "format.format === format.format"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2].map(format.format).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.NumberFormat().resolvedOptions().locale"
+        ),
+        "en-US"
+    );
+}
+
+#[test]
+fn plural_rules() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(&mut engine, "new Intl.PluralRules('en').select(1)"),
+        "one"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Intl.PluralRules('en').select(2)"),
+        "other"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.PluralRules('en', { minimumFractionDigits: 1 }).select(1)"
+        ),
+        "other"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Intl.PluralRules('fr').select(1.5)"),
+        "one"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Intl.PluralRules('ja').select(1)"),
+        "other"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[1, 2, 3, 4, 11, 22].map(n => new Intl.PluralRules('en', { type: 'ordinal' }).select(n)).join()"
+        ),
+        "one,two,few,other,other,two"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.PluralRules('en').resolvedOptions().pluralCategories.join()"
+        ),
+        "one,other"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Intl.PluralRules() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn collator() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "['b', 'A', 'á', 'a', 'B', 'ä'].sort(new Intl.Collator('en').compare).join()"
+        ),
+        "a,A,á,ä,b,B"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "['b', 'A', 'a', 'B'].sort(new Intl.Collator('en', { caseFirst: 'upper' }).compare).join()"
+        ),
+        "A,a,B,b"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "['item10', 'item9', 'item1'].sort(new Intl.Collator('en', { numeric: true }).compare).join()"
+        ),
+        "item1,item9,item10"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('en', { sensitivity: 'base' }).compare('résumé', 'RESUME')"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('en', { sensitivity: 'accent' }).compare('a', 'á')"
+        ),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('de', { sensitivity: 'base' }).compare('Straße', 'strasse')"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('en', { ignorePunctuation: true }).compare('co-op', 'coop')"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('ja', { sensitivity: 'base' }).compare('カ', 'か')"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.Collator('en-u-kn', { numeric: false }).resolvedOptions().locale"
+        ),
+        "en"
+    );
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('B', 'en')"), "-1");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "'a'.localeCompare('A', 'en', { sensitivity: 'base' })"
+        ),
+        "0"
+    );
+}
+
+#[test]
+fn date_time_format() {
+    let mut engine = engine();
+    let init = r#"
+        var date = new Date(Date.UTC(2022, 1, 1, 18, 5, 9));
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "new Intl.DateTimeFormat('en-US').format(date)"),
+        "2/1/2022"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toLocaleString('en-GB')"),
+        "01/02/2022, 13:05:09"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toLocaleString('de')"),
+        "1.2.2022, 13:05:09"
+    );
+    assert_eq!(
+        forward(&mut engine, "date.toLocaleDateString('ja')"),
+        "2022/2/1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleTimeString('en', { timeZone: 'UTC' })"
+        ),
+        "6:05:09 PM"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleDateString('en', { weekday: 'long', year: 'numeric', month: 'long', day: 'numeric' })"
+        ),
+        "Tuesday, February 1, 2022"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleString('en', { dateStyle: 'medium', timeStyle: 'short' })"
+        ),
+        "Feb 1, 2022, 1:05 PM"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleDateString('de', { dateStyle: 'long' })"
+        ),
+        "1. Februar 2022"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleDateString('fr', { dateStyle: 'full' })"
+        ),
+        "mardi 1 février 2022"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleDateString('ja', { dateStyle: 'long' })"
+        ),
+        "2022年2月1日"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleTimeString('ja', { hour12: true })"
+        ),
+        "午後1:05:09"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date.toLocaleTimeString('en', { hour: '2-digit', minute: '2-digit', hourCycle: 'h23' })"
+        ),
+        "13:05"
+    );
+    assert_eq!(
+        forward(&mut engine, "Intl.DateTimeFormat('en').format()"),
+        "2/1/2022"
+    );
+}
+
+#[test]
+fn date_time_format_errors() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Intl.DateTimeFormat('en', { dateStyle: 'long', year: 'numeric' }) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Intl.DateTimeFormat('en', { timeZone: 'Mars/Olympus' }) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Intl.DateTimeFormat('en').format(NaN) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Date(NaN).toLocaleString('de')"),
+        "Invalid Date"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.DateTimeFormat('en', { timeZone: 'utc', hour: 'numeric' }).resolvedOptions().timeZone"
+        ),
+        "UTC"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Intl.DateTimeFormat('en', { hour: 'numeric' }).resolvedOptions().hourCycle"
+        ),
+        "h12"
+    );
+}
+
+#[test]
+fn locale_case_mapping() {
+    let mut engine = engine();
+    assert_eq!(
+        forward(&mut engine, "'straße'.toLocaleUpperCase('de')"),
+        "STRASSE"
+    );
+    assert_eq!(forward(&mut engine, "'ÉCOLE'.toLocaleLowerCase()"), "école");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.toLocaleUpperCase('not a tag') } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "[1234.5, null, 0.5].toLocaleString('de')"),
+        "1.234,5,,0,5"
+    );
+}
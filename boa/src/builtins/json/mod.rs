@@ -13,9 +13,17 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
-use crate::builtins::value::{to_value, ResultValue, Value, ValueData};
-use crate::exec::Interpreter;
-use serde_json::{self, Value as JSONValue};
+use crate::{
+    builtins::{
+        array::{construct_array, is_array_value, length_of_array_like, new_array},
+        map::iterator::IterationKind,
+        number::number_to_string,
+        object::enumerable_own_property_names,
+        value::{same_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use std::slice;
 
 #[cfg(test)]
 mod tests;
@@ -32,17 +40,289 @@ mod tests;
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-json.parse
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
-// TODO: implement optional revever argument.
-pub fn parse(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    match serde_json::from_str::<JSONValue>(
-        &args
-            .get(0)
-            .expect("cannot get argument for JSON.parse")
-            .clone()
-            .to_string(),
-    ) {
-        Ok(json) => Ok(to_value(json)),
-        Err(err) => Err(to_value(err.to_string())),
+pub fn parse(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let text = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
+    let unfiltered = JsonParser::new(&text).parse_text(ctx)?;
+
+    match args.get(1) {
+        Some(reviver) if reviver.is_function() => {
+            let root = ValueData::new_obj(Some(&ctx.realm.global_obj));
+            let root_name = to_value("");
+            ctx.create_data_property(&root, &root_name, unfiltered)?;
+            internalize_json_property(&root, root_name, reviver, ctx)
+        }
+        _ => Ok(unfiltered),
+    }
+}
+
+/// The abstract operation `InternalizeJSONProperty`, which walks the parsed value
+/// bottom-up, replacing every property by the result of the reviver.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+fn internalize_json_property(
+    holder: &Value,
+    name: Value,
+    reviver: &Value,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let val = ctx.get_property(holder, &name)?;
+    if val.is_object() {
+        let keys = if is_array_value(&val, ctx)? {
+            let len = length_of_array_like(&val, ctx)?;
+            (0..len).map(|index| to_value(index.to_string())).collect()
+        } else {
+            enumerable_own_property_names(&val, IterationKind::Key, ctx)?
+        };
+        for key in keys {
+            let new_element = internalize_json_property(&val, key.clone(), reviver, ctx)?;
+            if new_element.is_undefined() {
+                ctx.delete_property(&val, &key)?;
+            } else {
+                ctx.create_data_property(&val, &key, new_element)?;
+            }
+        }
+    }
+    ctx.call(reviver, &mut holder.clone(), &[name, val])
+}
+
+/// A parser of the JSON text grammar, over the code units of the text.
+///
+/// More information:
+///  - [JSON specification][json]
+///
+/// [json]: https://www.json.org/json-en.html
+struct JsonParser<'a> {
+    text: &'a [u16],
+    position: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a JsString) -> Self {
+        Self {
+            text: text.as_slice(),
+            position: 0,
+        }
+    }
+
+    /// Parses the whole text, which must be a single JSON value.
+    fn parse_text(&mut self, ctx: &mut Interpreter) -> ResultValue {
+        let value = self.parse_value(ctx)?;
+        self.skip_whitespace();
+        if self.position < self.text.len() {
+            return Err(self.unexpected(ctx));
+        }
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<u16> {
+        self.text.get(self.position).copied()
+    }
+
+    /// The next code unit, if it's an ASCII character.
+    fn peek_ascii(&self) -> Option<u8> {
+        self.peek()
+            .filter(|unit| *unit < 0x80)
+            .map(|unit| unit as u8)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(0x09) | Some(0x0A) | Some(0x0D) | Some(0x20) = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes `unit`, or throws a `SyntaxError` if it isn't next.
+    fn expect(&mut self, unit: u8, ctx: &mut Interpreter) -> Result<(), Value> {
+        if self.peek() == Some(u16::from(unit)) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(ctx))
+        }
+    }
+
+    /// Consumes the literal `word`, like `true`.
+    fn expect_word(&mut self, word: &str, ctx: &mut Interpreter) -> Result<(), Value> {
+        for unit in word.bytes() {
+            self.expect(unit, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Constructs the `SyntaxError` of the code unit at the current position.
+    fn unexpected(&self, ctx: &mut Interpreter) -> Value {
+        let message = match self.peek() {
+            Some(unit) => format!(
+                "JSON.parse: unexpected character '{}' at position {}",
+                String::from_utf16_lossy(&[unit]),
+                self.position
+            ),
+            None => "JSON.parse: unexpected end of JSON input".to_string(),
+        };
+        ctx.construct_syntax_error(message)
+    }
+
+    fn parse_value(&mut self, ctx: &mut Interpreter) -> ResultValue {
+        self.skip_whitespace();
+        match self.peek_ascii() {
+            Some(b'{') => self.parse_object(ctx),
+            Some(b'[') => self.parse_array(ctx),
+            Some(b'"') => Ok(to_value(self.parse_string(ctx)?)),
+            Some(b't') => {
+                self.expect_word("true", ctx)?;
+                Ok(to_value(true))
+            }
+            Some(b'f') => {
+                self.expect_word("false", ctx)?;
+                Ok(to_value(false))
+            }
+            Some(b'n') => {
+                self.expect_word("null", ctx)?;
+                Ok(Value::new(ValueData::Null))
+            }
+            _ => self.parse_number(ctx),
+        }
+    }
+
+    fn parse_object(&mut self, ctx: &mut Interpreter) -> ResultValue {
+        self.expect(b'{', ctx)?;
+        let object = ValueData::new_obj(Some(&ctx.realm.global_obj));
+        self.skip_whitespace();
+        if self.peek_ascii() == Some(b'}') {
+            self.position += 1;
+            return Ok(object);
+        }
+        loop {
+            self.skip_whitespace();
+            let key = to_value(self.parse_string(ctx)?);
+            self.skip_whitespace();
+            self.expect(b':', ctx)?;
+            let value = self.parse_value(ctx)?;
+            // Later duplicate keys overwrite earlier ones
+            ctx.create_data_property(&object, &key, value)?;
+            self.skip_whitespace();
+            if self.peek_ascii() == Some(b',') {
+                self.position += 1;
+            } else {
+                self.expect(b'}', ctx)?;
+                return Ok(object);
+            }
+        }
+    }
+
+    fn parse_array(&mut self, ctx: &mut Interpreter) -> ResultValue {
+        self.expect(b'[', ctx)?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek_ascii() == Some(b']') {
+            self.position += 1;
+        } else {
+            loop {
+                elements.push(self.parse_value(ctx)?);
+                self.skip_whitespace();
+                if self.peek_ascii() == Some(b',') {
+                    self.position += 1;
+                } else {
+                    self.expect(b']', ctx)?;
+                    break;
+                }
+            }
+        }
+        let array = new_array(ctx)?;
+        construct_array(&array, &elements)
+    }
+
+    fn parse_string(&mut self, ctx: &mut Interpreter) -> Result<JsString, Value> {
+        self.expect(b'"', ctx)?;
+        let mut units = Vec::new();
+        loop {
+            let unit = match self.peek() {
+                Some(unit) if unit >= 0x20 => unit,
+                // Control characters must be escaped
+                _ => return Err(self.unexpected(ctx)),
+            };
+            self.position += 1;
+            match unit {
+                0x22 => return Ok(JsString::from(units)),
+                0x5C => {
+                    let escaped = match self.peek_ascii() {
+                        Some(b'"') => 0x22,
+                        Some(b'\\') => 0x5C,
+                        Some(b'/') => 0x2F,
+                        Some(b'b') => 0x08,
+                        Some(b'f') => 0x0C,
+                        Some(b'n') => 0x0A,
+                        Some(b'r') => 0x0D,
+                        Some(b't') => 0x09,
+                        Some(b'u') => {
+                            let digits = self
+                                .text
+                                .get(self.position + 1..self.position + 5)
+                                .and_then(|digits| String::from_utf16(digits).ok())
+                                .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()));
+                            match digits {
+                                Some(digits) => {
+                                    self.position += 4;
+                                    u16::from_str_radix(&digits, 16).expect("hex digits")
+                                }
+                                None => return Err(self.unexpected(ctx)),
+                            }
+                        }
+                        _ => return Err(self.unexpected(ctx)),
+                    };
+                    self.position += 1;
+                    units.push(escaped);
+                }
+                _ => units.push(unit),
+            }
+        }
+    }
+
+    fn parse_number(&mut self, ctx: &mut Interpreter) -> ResultValue {
+        let start = self.position;
+        let is_digit = |unit: Option<u8>| matches!(unit, Some(b'0'..=b'9'));
+
+        if self.peek_ascii() == Some(b'-') {
+            self.position += 1;
+        }
+        match self.peek_ascii() {
+            Some(b'0') => self.position += 1,
+            unit if is_digit(unit) => {
+                while is_digit(self.peek_ascii()) {
+                    self.position += 1;
+                }
+            }
+            _ => return Err(self.unexpected(ctx)),
+        }
+        if self.peek_ascii() == Some(b'.') {
+            self.position += 1;
+            if !is_digit(self.peek_ascii()) {
+                return Err(self.unexpected(ctx));
+            }
+            while is_digit(self.peek_ascii()) {
+                self.position += 1;
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek_ascii() {
+            self.position += 1;
+            if let Some(b'+') | Some(b'-') = self.peek_ascii() {
+                self.position += 1;
+            }
+            if !is_digit(self.peek_ascii()) {
+                return Err(self.unexpected(ctx));
+            }
+            while is_digit(self.peek_ascii()) {
+                self.position += 1;
+            }
+        }
+
+        let literal = String::from_utf16_lossy(&self.text[start..self.position]);
+        Ok(to_value(
+            literal.parse::<f64>().expect("JSON number literal"),
+        ))
     }
 }
 
@@ -62,10 +342,272 @@ pub fn parse(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-json.stringify
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify
-pub fn stringify(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("cannot get argument for JSON.stringify");
-    let json = obj.to_json().to_string();
-    Ok(to_value(json))
+pub fn stringify(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    let replacer = args.get(1).cloned().unwrap_or_else(undefined);
+    let mut space = args.get(2).cloned().unwrap_or_else(undefined);
+
+    let mut state = JsonState::default();
+    if replacer.is_function() {
+        state.replacer_function = Some(replacer);
+    } else if is_array_value(&replacer, ctx)? {
+        let mut property_list: Vec<Value> = Vec::new();
+        let len = length_of_array_like(&replacer, ctx)?;
+        for index in 0..len {
+            let v = ctx.get_property(&replacer, &to_value(index.to_string()))?;
+            let item = match *v {
+                ValueData::String(_) => v.clone(),
+                ValueData::Rational(_) | ValueData::Integer(_) => ctx.to_string(&v),
+                ValueData::Object(_)
+                    if has_slot(&v, "StringData") || has_slot(&v, "NumberData") =>
+                {
+                    ctx.to_string(&v)
+                }
+                _ => continue,
+            };
+            if !property_list.contains(&item) {
+                property_list.push(item);
+            }
+        }
+        state.property_list = Some(property_list);
+    }
+
+    if has_slot(&space, "NumberData") {
+        space = to_value(ctx.to_number(&space)?);
+    } else if has_slot(&space, "StringData") {
+        space = ctx.to_string(&space);
+    }
+    state.gap = match *space {
+        ValueData::Rational(_) | ValueData::Integer(_) => {
+            let spaces = ctx.to_integer_or_infinity(&space).min(10.0);
+            if spaces < 1.0 {
+                Vec::new()
+            } else {
+                vec![u16::from(b' '); spaces as usize]
+            }
+        }
+        ValueData::String(ref string) => string.iter().copied().take(10).collect(),
+        _ => Vec::new(),
+    };
+
+    let wrapper = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    let empty = to_value("");
+    ctx.create_data_property(&wrapper, &empty, value)?;
+    match state.serialize_json_property(&empty, &wrapper, ctx)? {
+        Some(json) => Ok(to_value(JsString::from(json))),
+        None => Ok(undefined()),
+    }
+}
+
+/// The state of a `JSON.stringify` call.
+#[derive(Default)]
+struct JsonState {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<Value>>,
+    /// The objects being serialized, to detect cycles.
+    stack: Vec<Value>,
+    indent: Vec<u16>,
+    gap: Vec<u16>,
+}
+
+impl JsonState {
+    /// The abstract operation `SerializeJSONProperty`, which gives `None` for the values
+    /// which aren't serialized, like `undefined`, functions and symbols.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_json_property(
+        &mut self,
+        key: &Value,
+        holder: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<Option<Vec<u16>>, Value> {
+        let mut value = ctx.get_property(holder, key)?;
+        if value.is_object() {
+            let to_json = ctx.get_property(&value, &to_value("toJSON"))?;
+            if to_json.is_function() {
+                value = ctx.call(&to_json, &mut value.clone(), slice::from_ref(key))?;
+            }
+        }
+        if let Some(ref replacer) = self.replacer_function {
+            value = ctx.call(replacer, &mut holder.clone(), &[key.clone(), value])?;
+        }
+
+        if has_slot(&value, "NumberData") {
+            value = to_value(ctx.to_number(&value)?);
+        } else if has_slot(&value, "StringData") {
+            value = ctx.to_string(&value);
+        } else if has_slot(&value, "BooleanData") {
+            value = value.get_internal_slot("BooleanData");
+        }
+
+        let json = match *value {
+            ValueData::Null => "null".encode_utf16().collect(),
+            ValueData::Boolean(true) => "true".encode_utf16().collect(),
+            ValueData::Boolean(false) => "false".encode_utf16().collect(),
+            ValueData::String(ref string) => quote_json_string(string),
+            ValueData::Rational(number) if number.is_finite() => {
+                number_to_string(number).encode_utf16().collect()
+            }
+            ValueData::Rational(_) => "null".encode_utf16().collect(),
+            ValueData::Integer(number) => number.to_string().encode_utf16().collect(),
+            ValueData::Object(_) if !value.is_function() => {
+                if is_array_value(&value, ctx)? {
+                    self.serialize_json_array(&value, ctx)?
+                } else {
+                    self.serialize_json_object(&value, ctx)?
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(json))
+    }
+
+    /// Pushes `value` on the stack of the objects being serialized, or throws a
+    /// `TypeError` if it's already being serialized.
+    fn enter(&mut self, value: &Value, ctx: &mut Interpreter) -> Result<(), Value> {
+        if self
+            .stack
+            .iter()
+            .any(|entry| same_value(entry, value, true))
+        {
+            return Err(ctx.construct_type_error("Converting circular structure to JSON"));
+        }
+        self.stack.push(value.clone());
+        Ok(())
+    }
+
+    /// Joins the serialized members of an object or array between `open` and `close`,
+    /// putting each of them on its own line when there's a gap.
+    fn join(&self, partial: Vec<Vec<u16>>, open: u8, close: u8, stepback: &[u16]) -> Vec<u16> {
+        let mut json = vec![u16::from(open)];
+        if !partial.is_empty() {
+            let mut separator = vec![u16::from(b',')];
+            if !self.gap.is_empty() {
+                separator.push(u16::from(b'\n'));
+                separator.extend_from_slice(&self.indent);
+                json.extend_from_slice(&separator[1..]);
+            }
+            json.extend(partial.join(&separator[..]));
+            if !self.gap.is_empty() {
+                json.push(u16::from(b'\n'));
+                json.extend_from_slice(stepback);
+            }
+        }
+        json.push(u16::from(close));
+        json
+    }
+
+    /// The abstract operation `SerializeJSONObject`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
+    fn serialize_json_object(
+        &mut self,
+        value: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<Vec<u16>, Value> {
+        self.enter(value, ctx)?;
+        let stepback = self.indent.clone();
+        self.indent.extend_from_slice(&self.gap.clone());
+
+        let keys = match self.property_list {
+            Some(ref property_list) => property_list.clone(),
+            None => enumerable_own_property_names(value, IterationKind::Key, ctx)?,
+        };
+        let mut partial = Vec::new();
+        for key in keys {
+            if let Some(str_p) = self.serialize_json_property(&key, value, ctx)? {
+                let mut member = quote_json_string(&ctx.value_to_js_string(&key));
+                member.push(u16::from(b':'));
+                if !self.gap.is_empty() {
+                    member.push(u16::from(b' '));
+                }
+                member.extend(str_p);
+                partial.push(member);
+            }
+        }
+
+        let json = self.join(partial, b'{', b'}', &stepback);
+        self.stack.pop();
+        self.indent = stepback;
+        Ok(json)
+    }
+
+    /// The abstract operation `SerializeJSONArray`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
+    fn serialize_json_array(
+        &mut self,
+        value: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<Vec<u16>, Value> {
+        self.enter(value, ctx)?;
+        let stepback = self.indent.clone();
+        self.indent.extend_from_slice(&self.gap.clone());
+
+        let len = length_of_array_like(value, ctx)?;
+        let mut partial = Vec::with_capacity(len);
+        for index in 0..len {
+            let key = to_value(index.to_string());
+            match self.serialize_json_property(&key, value, ctx)? {
+                Some(str_p) => partial.push(str_p),
+                None => partial.push("null".encode_utf16().collect()),
+            }
+        }
+
+        let json = self.join(partial, b'[', b']', &stepback);
+        self.stack.pop();
+        self.indent = stepback;
+        Ok(json)
+    }
+}
+
+/// Checks whether `value` is an object with the internal slot `name`, like the `[[NumberData]]`
+/// of `Number` objects.
+fn has_slot(value: &Value, name: &str) -> bool {
+    value
+        .as_object()
+        .map_or(false, |obj| obj.internal_slots.contains_key(name))
+}
+
+/// The abstract operation `QuoteJSONString`, which escapes lone surrogates so the result is
+/// always well formed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
+fn quote_json_string(string: &JsString) -> Vec<u16> {
+    let mut product = vec![0x22];
+    let mut index = 0;
+    while let Some((code_point, size)) = string.code_point_at(index) {
+        let escape = match code_point {
+            0x08 => Some("\\b".to_string()),
+            0x09 => Some("\\t".to_string()),
+            0x0A => Some("\\n".to_string()),
+            0x0C => Some("\\f".to_string()),
+            0x0D => Some("\\r".to_string()),
+            0x22 => Some("\\\"".to_string()),
+            0x5C => Some("\\\\".to_string()),
+            0x00..=0x1F | 0xD800..=0xDFFF => Some(format!("\\u{:04x}", code_point)),
+            _ => None,
+        };
+        match escape {
+            Some(escape) => product.extend(escape.encode_utf16()),
+            None => product.extend_from_slice(&string[index..index + size]),
+        }
+        index += size;
+    }
+    product.push(0x22);
+    product
 }
 
 /// Create a new `JSON` object.
@@ -16,3 +16,193 @@ fn json_sanity() {
         "true"
     );
 }
+
+#[test]
+fn parse() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, r#"JSON.parse('[1, "a", true, null]').length"#),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"JSON.parse(' {"a": {"b": [1.5e2]}} ').a.b[0]"#
+        ),
+        "150"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"JSON.parse('"\\u0041\\n"') === 'A\n'"#),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"JSON.parse('{"a": 1, "a": 2}').a"#),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"Array.isArray(JSON.parse('[]'))"#),
+        "true"
+    );
+    for text in &["'[1,]'", "'01'", "'{a: 1}'", "'\"abc'", "''", "'1 2'"] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!("try {{ JSON.parse({}) }} catch (e) {{ e.name }}", text)
+            ),
+            "SyntaxError"
+        );
+    }
+}
+
+#[test]
+fn parse_with_reviver() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var keys = [];
+        var revived = JSON.parse('{"a": 1, "b": [2, 3], "c": 4}', function(key, value) {
+            keys.push(key);
+            if (key === 'c') {
+                return undefined;
+            }
+            return typeof value === 'number' ? value * 10 : value;
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "revived.a"), "10");
+    assert_eq!(forward(&mut engine, "revived.b.join()"), "20,30");
+    assert_eq!(forward(&mut engine, "'c' in revived"), "false");
+    assert_eq!(forward(&mut engine, "keys[keys.length - 1]"), "");
+}
+
+#[test]
+fn stringify() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"JSON.stringify({a: undefined, b: function() {}, c: Symbol(), d: [undefined, NaN]})"#
+        ),
+        r#"{"d":[null,null]}"#
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(undefined) === undefined"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"JSON.stringify('a"\n\u0001\ud800')"#),
+        r#""a\"\n\u0001\ud800""#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify([1e21, 1e-7, 0.1, -0, 123.456])"
+        ),
+        "[1e+21,1e-7,0.1,0,123.456]"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify([new Number(1), new String('s'), new Boolean(false)])"
+        ),
+        r#"[1,"s",false]"#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify({ toJSON: function(key) { return 'json' } })"
+        ),
+        r#""json""#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify(Object.defineProperty({}, 'a', { get: function() { return 1 }, enumerable: true }))"
+        ),
+        r#"{"a":1}"#
+    );
+}
+
+#[test]
+fn stringify_replacer_and_space() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify({a: [1, 'x']}, function(k, v) { return typeof v === 'number' ? v + 1 : v })"
+        ),
+        r#"{"a":[2,"x"]}"#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify({a: 1, b: 2, 1: 3}, ['b', 1, 'b'])"
+        ),
+        r#"{"b":2,"1":3}"#
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify({a: [1, {}, []]}, null, 2)"),
+        "{\n  \"a\": [\n    1,\n    {},\n    []\n  ]\n}"
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify([1], null, '--')"),
+        "[\n--1\n]"
+    );
+}
+
+#[test]
+fn stringify_property_order() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify({a: 1, b: 2, c: 3, zz: 4, y: 5})"
+        ),
+        r#"{"a":1,"b":2,"c":3,"zz":4,"y":5}"#
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify({b: 1, 10: 2, a: 3, 2: 4})"),
+        r#"{"2":4,"10":2,"b":1,"a":3}"#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var o = {a: 1, b: 2, c: 3}; delete o.a; o.a = 4; JSON.stringify(o)"
+        ),
+        r#"{"b":2,"c":3,"a":4}"#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify({zz: 1, a: 2, y: 3, b: 4}, ['y', 'zz', 'b'])"
+        ),
+        r#"{"y":3,"zz":1,"b":4}"#
+    );
+}
+
+#[test]
+fn stringify_cycle() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var cyclic = { a: [] };
+        cyclic.a.push(cyclic);
+        var shared = {};
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { JSON.stringify(cyclic) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify([shared, shared])"),
+        "[{},{}]"
+    );
+}
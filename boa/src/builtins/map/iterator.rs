@@ -0,0 +1,153 @@
+//! This module implements the iterators of `Map` and `Set` objects.
+//!
+//! They are returned by the `keys()`, `values()` and `entries()` methods, and walk the entries
+//! of the object in insertion order, even when it is modified during the iteration.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-map-iterator-objects
+
+use super::{ordered_map::MapCursor, with_entries_mut, with_entries_ref};
+use crate::{
+    builtins::{
+        array::{construct_array, new_array},
+        object::{Object, PROTOTYPE},
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// The internal slot of `Map.prototype` and `Set.prototype` holding the prototype of their iterators.
+pub(crate) const ITERATOR_PROTOTYPE: &str = "IteratorPrototype";
+
+/// What an iterator returns for each entry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IterationKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
+impl IterationKind {
+    /// Gets the name of the kind, as used in the spec.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Key => "key",
+            Self::Value => "value",
+            Self::KeyAndValue => "key+value",
+        }
+    }
+}
+
+/// Creates a new iterator over the entries of `map`, a `Map` or `Set` object.
+///
+/// `constructor` is the name of the constructor whose prototype holds the iterator prototype.
+pub(crate) fn create_iterator(
+    ctx: &Interpreter,
+    map: &Value,
+    kind: IterationKind,
+    constructor: &str,
+) -> Value {
+    let prototype = ctx
+        .realm
+        .global_obj
+        .get_field_slice(constructor)
+        .get_field_slice(PROTOTYPE)
+        .get_internal_slot(ITERATOR_PROTOTYPE);
+
+    let iterator = to_value(Object::create(prototype));
+    iterator.set_internal_slot("IteratedObject", map.clone());
+    iterator.set_internal_state(with_entries_mut(map, |map| map.cursor()));
+    iterator.set_internal_slot("IterationKind", to_value(kind.as_str()));
+    iterator
+}
+
+/// Creates an iterator result object, with the given `value` and `done` properties.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createiterresultobject
+pub(crate) fn create_iter_result(ctx: &Interpreter, value: Value, done: bool) -> Value {
+    let result = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    result.set_field_slice("value", value);
+    result.set_field_slice("done", to_value(done));
+    result
+}
+
+/// `%MapIteratorPrototype%.next()`
+///
+/// Returns the next entry of the iterated object, or a result with `done` set to `true`
+/// once all the entries were visited.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%mapiteratorprototype%.next
+pub(crate) fn next(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let kind = this.get_internal_slot("IterationKind");
+    let kind = match from_value::<String>(kind) {
+        Ok(ref kind) if kind == IterationKind::Key.as_str() => IterationKind::Key,
+        Ok(ref kind) if kind == IterationKind::Value.as_str() => IterationKind::Value,
+        Ok(ref kind) if kind == IterationKind::KeyAndValue.as_str() => IterationKind::KeyAndValue,
+        _ => {
+            return ctx.throw_type_error(format!(
+                "next method called on incompatible receiver {}",
+                this
+            ))
+        }
+    };
+    if !this.has_internal_state_of::<MapCursor>() {
+        return ctx.throw_type_error(format!(
+            "next method called on incompatible receiver {}",
+            this
+        ));
+    }
+
+    // The iterated object is removed once the iterator is done, so it stays done
+    let map = this.get_internal_slot("IteratedObject");
+    if map.is_undefined() {
+        return Ok(create_iter_result(ctx, undefined(), true));
+    }
+
+    let cursor = this.with_internal_state_ref(MapCursor::clone);
+    match with_entries_ref(&map, |map| map.next_entry(&cursor)) {
+        Some((key, value)) => {
+            let result = match kind {
+                IterationKind::Key => key,
+                IterationKind::Value => value,
+                IterationKind::KeyAndValue => {
+                    let entry = new_array(ctx)?;
+                    construct_array(&entry, &[key, value])?
+                }
+            };
+            Ok(create_iter_result(ctx, result, false))
+        }
+        None => {
+            this.set_internal_slot("IteratedObject", undefined());
+            Ok(create_iter_result(ctx, undefined(), true))
+        }
+    }
+}
+
+/// `%IteratorPrototype%[ @@iterator ]()`
+///
+/// Iterators are iterable themselves, so they can be used wherever an iterable is expected.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-@@iterator
+pub(crate) fn iterator(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+    Ok(this.clone())
+}
+
+/// Creates the prototype of the iterators of `Map` or `Set` objects.
+pub(crate) fn create_prototype(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    make_builtin_fn!(next, named "next", of prototype);
+    make_builtin_fn!(iterator, keyed well_known_symbol(global, "iterator"), of prototype);
+    prototype
+}
@@ -0,0 +1,384 @@
+//! This module implements the global `Map` object.
+//!
+//! The `Map` object holds key-value pairs and remembers the original insertion order of the keys.
+//! Any value (both objects and primitive values) may be used as either a key or a value.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-map-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) mod iterator;
+pub mod ordered_map;
+
+use self::{
+    iterator::{create_iterator, IterationKind, ITERATOR_PROTOTYPE},
+    ordered_map::OrderedMap,
+};
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        property::Property,
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Checks whether `value` is a `Map` object.
+pub(crate) fn is_map(value: &Value) -> bool {
+    match **value {
+        ValueData::Object(ref obj) => match obj.borrow().kind {
+            ObjectKind::Map(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Calls `f` with the entries of a `Map` or `Set` object.
+///
+/// The object is borrowed during the call, so `f` must not run any JavaScript code.
+///
+/// # Panics
+///
+/// Panics if `value` is not a `Map` or `Set` object.
+pub(crate) fn with_entries_ref<R, F: FnOnce(&OrderedMap) -> R>(value: &Value, f: F) -> R {
+    match **value {
+        ValueData::Object(ref obj) => match obj.borrow().kind {
+            ObjectKind::Map(ref map) | ObjectKind::Set(ref map) => f(map),
+            _ => panic!("Value is not a Map or Set object"),
+        },
+        _ => panic!("Value is not a Map or Set object"),
+    }
+}
+
+/// Calls `f` with the mutable entries of a `Map` or `Set` object.
+///
+/// The object is borrowed during the call, so `f` must not run any JavaScript code.
+///
+/// # Panics
+///
+/// Panics if `value` is not a `Map` or `Set` object.
+pub(crate) fn with_entries_mut<R, F: FnOnce(&mut OrderedMap) -> R>(value: &Value, f: F) -> R {
+    match **value {
+        ValueData::Object(ref obj) => match obj.borrow_mut().kind {
+            ObjectKind::Map(ref mut map) | ObjectKind::Set(ref mut map) => f(map),
+            _ => panic!("Value is not a Map or Set object"),
+        },
+        _ => panic!("Value is not a Map or Set object"),
+    }
+}
+
+/// Throws a `TypeError` if a `Map.prototype` method is called on something that is not a `Map` object.
+fn check_map(this: &Value, method: &str, ctx: &mut Interpreter) -> Result<(), Value> {
+    if is_map(this) {
+        Ok(())
+    } else {
+        ctx.throw_type_error(format!(
+            "Method Map.prototype.{} called on incompatible receiver {}",
+            method, this
+        ))
+        .map(|_| ())
+    }
+}
+
+/// Gets the entries of `iterable`, to add them to a new `Map` or `WeakMap` object.
+///
+/// There is no iteration protocol yet, so only arrays of `[key, value]` entries and
+/// other `Map` objects can be used.
+pub(crate) fn entries_of_iterable(
+    iterable: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Vec<(Value, Value)>, Value> {
+    if is_map(iterable) {
+        return Ok(with_entries_ref(iterable, |map| {
+            map.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        }));
+    }
+
+    match **iterable {
+        ValueData::Object(ref obj) if obj.borrow().kind == ObjectKind::Array => {
+            let length: i32 = from_value(iterable.get_field_slice("length"))
+                .map_err(|error| error.to_type_error(ctx))?;
+            let mut entries = Vec::with_capacity(length as usize);
+            for idx in 0..length {
+                let entry = iterable.get_field_slice(&idx.to_string());
+                if !entry.is_object() {
+                    return ctx
+                        .throw_type_error(format!(
+                            "Iterator value {} is not an entry object",
+                            entry
+                        ))
+                        .map(|_| Vec::new());
+                }
+                entries.push((entry.get_field_slice("0"), entry.get_field_slice("1")));
+            }
+            Ok(entries)
+        }
+        _ => ctx
+            .throw_type_error(format!("{} is not iterable", iterable))
+            .map(|_| Vec::new()),
+    }
+}
+
+/// Create a new `Map`.
+///
+/// The optional argument is an array of `[key, value]` entries, or another `Map`, to copy.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map-iterable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/Map
+pub fn make_map(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this.set_kind(ObjectKind::Map(OrderedMap::new()));
+
+    match args.get(0) {
+        Some(iterable) if !iterable.is_null_or_undefined() => {
+            let entries = entries_of_iterable(iterable, ctx)?;
+            with_entries_mut(this, |map| {
+                for (key, value) in entries {
+                    map.insert(key, value);
+                }
+            });
+        }
+        _ => {}
+    }
+
+    Ok(this.clone())
+}
+
+/// `Map` can only be called as a constructor.
+pub fn call_map(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor Map requires 'new'")
+}
+
+/// `Map.prototype.clear()`
+///
+/// The `clear()` method removes all elements from a `Map` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.clear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear
+pub fn clear(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "clear", ctx)?;
+    with_entries_mut(this, |map| map.clear());
+    Ok(undefined())
+}
+
+/// `Map.prototype.delete( key )`
+///
+/// The `delete()` method removes the specified element from a `Map` object.
+///
+/// Returns `true` if the element existed and has been removed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.delete
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete
+pub fn delete(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "delete", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let removed = with_entries_mut(this, |map| map.remove(&key));
+    Ok(to_value(removed))
+}
+
+/// `Map.prototype.entries()`
+///
+/// The `entries()` method returns a new iterator object that contains the `[key, value]` pairs
+/// for each element in the `Map` object in insertion order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.entries
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries
+pub fn entries(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "entries", ctx)?;
+    Ok(create_iterator(
+        ctx,
+        this,
+        IterationKind::KeyAndValue,
+        "Map",
+    ))
+}
+
+/// `Map.prototype.forEach( callbackFn [ , thisArg ] )`
+///
+/// The `forEach()` method executes a provided function once per each key/value pair in the
+/// `Map` object, in insertion order.
+///
+/// Entries added during the iteration are visited, deleted entries are skipped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.foreach
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/forEach
+pub fn for_each(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "forEach", ctx)?;
+    let callback = args.get(0).cloned().unwrap_or_else(undefined);
+    if !callback.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", callback));
+    }
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    let cursor = with_entries_mut(this, OrderedMap::cursor);
+    while let Some((key, value)) = with_entries_ref(this, |map| map.next_entry(&cursor)) {
+        ctx.call(&callback, &mut this_arg, &[value, key, this.clone()])?;
+    }
+
+    Ok(undefined())
+}
+
+/// `Map.prototype.get( key )`
+///
+/// The `get()` method returns a specified element from a `Map` object,
+/// or `undefined` if there is none.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.get
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get
+pub fn get(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "get", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let value = with_entries_ref(this, |map| map.get(&key));
+    Ok(value.unwrap_or_else(undefined))
+}
+
+/// `Map.prototype.has( key )`
+///
+/// The `has()` method returns a boolean indicating whether an element with the specified key exists or not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.has
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has
+pub fn has(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "has", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let has = with_entries_ref(this, |map| map.contains_key(&key));
+    Ok(to_value(has))
+}
+
+/// `Map.prototype.keys()`
+///
+/// The `keys()` method returns a new iterator object that contains the keys for each element
+/// in the `Map` object in insertion order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.keys
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys
+pub fn keys(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "keys", ctx)?;
+    Ok(create_iterator(ctx, this, IterationKind::Key, "Map"))
+}
+
+/// `Map.prototype.set( key, value )`
+///
+/// The `set()` method adds or updates an element with a specified key and a value to a `Map` object.
+///
+/// Returns the `Map` object, so calls can be chained.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.set
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set
+pub fn set(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "set", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let value = args.get(1).cloned().unwrap_or_else(undefined);
+    with_entries_mut(this, |map| map.insert(key, value));
+    Ok(this.clone())
+}
+
+/// `get Map.prototype.size`
+///
+/// The `size` accessor property returns the number of elements in a `Map` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-map.prototype.size
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size
+pub fn get_size(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "size", ctx)?;
+    Ok(to_value(with_entries_ref(this, OrderedMap::len)))
+}
+
+/// `Map.prototype.values()`
+///
+/// The `values()` method returns a new iterator object that contains the values for each element
+/// in the `Map` object in insertion order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-map.prototype.values
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values
+pub fn values(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_map(this, "values", ctx)?;
+    Ok(create_iterator(ctx, this, IterationKind::Value, "Map"))
+}
+
+/// Create a new `Map` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    prototype.set_internal_slot(ITERATOR_PROTOTYPE, iterator::create_prototype(global));
+
+    make_builtin_fn!(clear, named "clear", of prototype);
+    make_builtin_fn!(delete, named "delete", with length 1, of prototype);
+    make_builtin_fn!(entries, named "entries", of prototype);
+    make_builtin_fn!(for_each, named "forEach", with length 1, of prototype);
+    make_builtin_fn!(get, named "get", with length 1, of prototype);
+    make_builtin_fn!(has, named "has", with length 1, of prototype);
+    make_builtin_fn!(keys, named "keys", of prototype);
+    make_builtin_fn!(set, named "set", with length 2, of prototype);
+    make_builtin_fn!(values, named "values", of prototype);
+    make_getter_fn!(get_size, named "size", of prototype);
+    // `Map.prototype[Symbol.iterator]` is the same function as `entries`
+    prototype.set_symbol_prop(
+        &well_known_symbol(global, "iterator"),
+        Property::default()
+            .value(prototype.get_field_slice("entries"))
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    make_constructor_fn!(make_map, call_map, global, prototype)
+}
+
+/// Initialise the `Map` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Map", create(global));
+}
@@ -0,0 +1,268 @@
+//! This module implements the storage of `Map` and `Set` objects.
+//!
+//! The entries are kept in insertion order, and looked up through a hash map of their keys.
+//! Keys are compared with the [`SameValueZero`][spec] algorithm.
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-samevaluezero
+
+use crate::builtins::{
+    object::{InternalState, Object},
+    value::{to_value, JsString, Value, ValueData},
+};
+use gc::{unsafe_empty_trace, Finalize, GcCell, Trace};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::Cell,
+    rc::{Rc, Weak},
+};
+
+/// The entries are compacted once there are more holes than entries, and at least this many holes.
+const MIN_HOLES_TO_COMPACT: usize = 8;
+
+/// A key of an `OrderedMap`, hashed and compared like `SameValueZero` compares values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MapKey {
+    Undefined,
+    Null,
+    Boolean(bool),
+    /// The bits of the number, with `-0` stored as `+0` and all NaNs stored as the same NaN.
+    Number(u64),
+    String(JsString),
+    /// The address of the object, objects and symbols are compared by identity.
+    Object(usize),
+}
+
+impl MapKey {
+    /// Creates the key of the given value.
+    fn new(value: &Value) -> Self {
+        match **value {
+            ValueData::Undefined => Self::Undefined,
+            ValueData::Null => Self::Null,
+            ValueData::Boolean(boolean) => Self::Boolean(boolean),
+            ValueData::Integer(integer) => Self::number(f64::from(integer)),
+            ValueData::Rational(rational) => Self::number(rational),
+            ValueData::String(ref string) => Self::String(string.clone()),
+            ValueData::Object(ref object) | ValueData::Symbol(ref object) => {
+                Self::Object(&**object as *const GcCell<Object> as usize)
+            }
+        }
+    }
+
+    /// Creates the key of a number.
+    fn number(number: f64) -> Self {
+        let number = if number.is_nan() {
+            f64::NAN
+        } else if number == 0.0 {
+            0.0
+        } else {
+            number
+        };
+        Self::Number(number.to_bits())
+    }
+}
+
+impl Finalize for MapKey {}
+
+/// The keys only hold strings and addresses, the values themselves are traced through the entries.
+unsafe impl Trace for MapKey {
+    unsafe_empty_trace!();
+}
+
+/// Stores a `-0` key as `+0`, which is the key then seen by `keys()` and `forEach()`.
+fn normalize_key(key: Value, map_key: &MapKey) -> Value {
+    match map_key {
+        MapKey::Number(_) if key.to_number() == 0.0 => to_value(0),
+        _ => key,
+    }
+}
+
+/// The position of an iteration over an `OrderedMap`, the index of the next entry to visit.
+///
+/// The map updates the positions of its cursors when it moves its entries.
+#[derive(Debug, Clone)]
+pub(crate) struct MapCursor(Rc<Cell<usize>>);
+
+impl InternalState for MapCursor {}
+
+/// The entries of a `Map` or `Set` object, in insertion order.
+///
+/// Deleting an entry leaves a hole behind instead of moving the following entries, so an iterator
+/// can keep its position while the map is modified. Entries added during the iteration are visited,
+/// and deleted ones are skipped, as the spec requires.
+///
+/// Once the holes outnumber the entries, the entries are moved together and the running iterations
+/// are moved along with them, so a map used as a queue doesn't grow without bound.
+#[derive(Debug, Default, Trace, Finalize)]
+pub struct OrderedMap {
+    /// The entries, `None` for deleted ones.
+    entries: Vec<Option<(Value, Value)>>,
+
+    /// The index of the entry of each key.
+    indices: FxHashMap<MapKey, usize>,
+
+    /// The positions of the running iterations.
+    #[unsafe_ignore_trace]
+    cursors: Vec<Weak<Cell<usize>>>,
+}
+
+/// The iterations of a map don't walk its copies.
+impl Clone for OrderedMap {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            indices: self.indices.clone(),
+            cursors: Vec::new(),
+        }
+    }
+}
+
+impl OrderedMap {
+    /// Creates an empty `OrderedMap`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of entries in the map.
+    pub(crate) fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Gets the value associated to `key`.
+    pub(crate) fn get(&self, key: &Value) -> Option<Value> {
+        self.indices
+            .get(&MapKey::new(key))
+            .and_then(|&index| self.entries[index].as_ref())
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Checks if the map has an entry for `key`.
+    pub(crate) fn contains_key(&self, key: &Value) -> bool {
+        self.indices.contains_key(&MapKey::new(key))
+    }
+
+    /// Associates `value` to `key`.
+    ///
+    /// Existing keys keep their position, new keys are added at the end.
+    pub(crate) fn insert(&mut self, key: Value, value: Value) {
+        let map_key = MapKey::new(&key);
+        if let Some(&index) = self.indices.get(&map_key) {
+            if let Some((_, ref mut old_value)) = self.entries[index] {
+                *old_value = value;
+            }
+            return;
+        }
+
+        let key = normalize_key(key, &map_key);
+        self.indices.insert(map_key, self.entries.len());
+        self.entries.push(Some((key, value)));
+    }
+
+    /// Adds `value` to the map if it's not there yet, using it as both the key and the value.
+    ///
+    /// This is how `Set` objects store their values.
+    pub(crate) fn insert_value(&mut self, value: Value) {
+        let map_key = MapKey::new(&value);
+        if !self.indices.contains_key(&map_key) {
+            let value = normalize_key(value, &map_key);
+            self.indices.insert(map_key, self.entries.len());
+            self.entries.push(Some((value.clone(), value)));
+        }
+    }
+
+    /// Removes the entry of `key`, returning `true` if there was one.
+    pub(crate) fn remove(&mut self, key: &Value) -> bool {
+        match self.indices.remove(&MapKey::new(key)) {
+            Some(index) => {
+                self.entries[index] = None;
+                let holes = self.entries.len() - self.indices.len();
+                if holes >= MIN_HOLES_TO_COMPACT && holes > self.indices.len() {
+                    self.compact();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all the entries of the map.
+    ///
+    /// Running iterations continue with the entries added after this.
+    pub(crate) fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+        self.cursors.retain(|cursor| match cursor.upgrade() {
+            Some(cursor) => {
+                cursor.set(0);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Removes the holes left by the deleted entries, and moves the running iterations accordingly.
+    fn compact(&mut self) {
+        // The number of entries before each index, which is the new index of the next entry
+        let mut moved_indices = Vec::with_capacity(self.entries.len() + 1);
+        let mut count = 0;
+        for entry in &self.entries {
+            moved_indices.push(count);
+            if entry.is_some() {
+                count += 1;
+            }
+        }
+        moved_indices.push(count);
+
+        let end = self.entries.len();
+        self.cursors.retain(|cursor| match cursor.upgrade() {
+            Some(cursor) => {
+                cursor.set(moved_indices[cursor.get().min(end)]);
+                true
+            }
+            None => false,
+        });
+
+        self.entries.retain(Option::is_some);
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some((ref key, _)) = entry {
+                self.indices.insert(MapKey::new(key), index);
+            }
+        }
+    }
+
+    /// Starts a new iteration over the entries of the map, from the first one.
+    pub(crate) fn cursor(&mut self) -> MapCursor {
+        self.cursors.retain(|cursor| cursor.strong_count() > 0);
+        let cursor = Rc::new(Cell::new(0));
+        self.cursors.push(Rc::downgrade(&cursor));
+        MapCursor(cursor)
+    }
+
+    /// Gets the next entry of the iteration at `cursor`, and moves the cursor past it.
+    pub(crate) fn next_entry(&self, cursor: &MapCursor) -> Option<(Value, Value)> {
+        let (index, key, value) = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(cursor.0.get())
+            .find_map(|(index, entry)| {
+                entry
+                    .as_ref()
+                    .map(|(key, value)| (index, key.clone(), value.clone()))
+            })?;
+        cursor.0.set(index + 1);
+        Some((key, value))
+    }
+
+    /// Gets the number of entries and holes the map keeps.
+    #[cfg(test)]
+    pub(crate) fn backing_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Iterates over the entries of the map, in insertion order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&Value, &Value)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.as_ref().map(|(key, value)| (key, value)))
+    }
+}
@@ -0,0 +1,224 @@
+use super::with_entries_ref;
+use crate::exec::Executor;
+use crate::realm::Realm;
+use crate::{forward, forward_val};
+
+#[test]
+fn construct_and_get() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var empty = new Map();
+        var map = new Map([["a", 1], ["b", 2]]);
+        var copy = new Map(map);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "empty.size"), "0");
+    assert_eq!(forward(&mut engine, "map.size"), "2");
+    assert_eq!(forward(&mut engine, "map.get('a')"), "1");
+    assert_eq!(forward(&mut engine, "map.get('c')"), "undefined");
+    assert_eq!(forward(&mut engine, "copy.get('b')"), "2");
+    assert_eq!(
+        forward(&mut engine, "try { Map() } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn set_has_delete_clear() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map();
+        var chained = map.set('a', 1).set('b', 2).size;
+        map.set('a', 3);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "chained"), "2");
+    assert_eq!(forward(&mut engine, "map.size"), "2");
+    assert_eq!(forward(&mut engine, "map.get('a')"), "3");
+    assert_eq!(forward(&mut engine, "map.has('b')"), "true");
+    assert_eq!(forward(&mut engine, "map.delete('b')"), "true");
+    assert_eq!(forward(&mut engine, "map.delete('b')"), "false");
+    assert_eq!(forward(&mut engine, "map.has('b')"), "false");
+    assert_eq!(forward(&mut engine, "map.size"), "1");
+    forward(&mut engine, "map.clear()");
+    assert_eq!(forward(&mut engine, "map.size"), "0");
+    assert_eq!(forward(&mut engine, "map.has('a')"), "false");
+}
+
+/// Keys are compared with `SameValueZero`.
+#[test]
+fn same_value_zero_keys() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map();
+        var key = {};
+        map.set(NaN, 'nan');
+        map.set(-0, 'zero');
+        map.set(key, 'object');
+        map.set('__proto__', 'proto');
+        map.set(1, 'number');
+        var zeros = new Map([[-0, 'zero']]);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "map.get(NaN)"), "nan");
+    assert_eq!(forward(&mut engine, "map.get(0)"), "zero");
+    assert_eq!(
+        forward(&mut engine, "1 / zeros.keys().next().value"),
+        "Infinity"
+    );
+    assert_eq!(forward(&mut engine, "map.get(key)"), "object");
+    assert_eq!(forward(&mut engine, "map.get({})"), "undefined");
+    assert_eq!(forward(&mut engine, "map.get('__proto__')"), "proto");
+    assert_eq!(forward(&mut engine, "map.get(1.0)"), "number");
+    assert_eq!(forward(&mut engine, "map.get('1')"), "undefined");
+}
+
+#[test]
+fn iterators() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map([["a", 1], ["b", 2]]);
+        var keys = map.keys();
+        var values = map.values();
+        var entries = map.entries();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "keys.next().value"), "a");
+    assert_eq!(forward(&mut engine, "keys.next().value"), "b");
+    assert_eq!(forward(&mut engine, "keys.next().done"), "true");
+    assert_eq!(forward(&mut engine, "values.next().value"), "1");
+    assert_eq!(
+        forward(&mut engine, "entries.next().value.join('=')"),
+        "a=1"
+    );
+
+    // A finished iterator doesn't see new entries
+    forward(&mut engine, "map.set('c', 3)");
+    assert_eq!(forward(&mut engine, "keys.next().done"), "true");
+    assert_eq!(forward(&mut engine, "values.next().value"), "2");
+    assert_eq!(forward(&mut engine, "values.next().value"), "3");
+}
+
+/// Deleting and adding entries during the iteration behaves like in other engines.
+#[test]
+fn for_each_with_modifications() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map([["a", 1], ["b", 2], ["c", 3]]);
+        var visited = [];
+        map.forEach(function (value, key, m) {
+            visited.push(key + value);
+            if (key === 'a') {
+                m.delete('b');
+                m.set('d', 4);
+            }
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "visited.join()"), "a1,c3,d4");
+
+    let init = r#"
+        var cleared = new Map([["a", 1], ["b", 2]]);
+        var keys = cleared.keys();
+        keys.next();
+        cleared.clear();
+        cleared.set('c', 3);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "keys.next().value"), "c");
+}
+
+#[test]
+fn deleted_entries_are_compacted() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var queue = new Map([["first", 0]]);
+        var i = 0;
+        while (i < 1000) {
+            queue.set(i, i);
+            queue.delete(i);
+            i++;
+        }
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    let queue = forward_val(&mut engine, "queue").unwrap();
+    assert!(with_entries_ref(&queue, |map| map.backing_len()) < 20);
+    assert_eq!(forward(&mut engine, "queue.size"), "1");
+    assert_eq!(forward(&mut engine, "queue.get('first')"), "0");
+
+    let init = r#"
+        var map = new Map();
+        var j = 0;
+        while (j < 40) {
+            map.set(j, j);
+            j++;
+        }
+        var values = map.values();
+        values.next();
+        values.next();
+        j = 0;
+        while (j < 30) {
+            map.delete(j);
+            j++;
+        }
+        var rest = [];
+        var step = values.next();
+        while (!step.done) {
+            rest.push(step.value);
+            step = values.next();
+        }
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    let map = forward_val(&mut engine, "map").unwrap();
+    assert!(with_entries_ref(&map, |map| map.backing_len()) < 20);
+    assert_eq!(
+        forward(&mut engine, "rest.join()"),
+        "30,31,32,33,34,35,36,37,38,39"
+    );
+}
+
+#[test]
+fn incompatible_receiver() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var notMap = { get: new Map().get };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "try { notMap.get('a') } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn size_accessor() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new Map([["a", 1]]);
+        map.size = 5;
+        var descriptor = Object.getOwnPropertyDescriptor(Map.prototype, "size");
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "map.size"), "1");
+    assert_eq!(forward(&mut engine, "map.hasOwnProperty('size')"), "false");
+    assert_eq!(forward(&mut engine, "typeof descriptor.get"), "function");
+    assert_eq!(forward(&mut engine, "descriptor.get.name"), "get size");
+    assert_eq!(forward(&mut engine, "descriptor.set"), "undefined");
+    assert_eq!(forward(&mut engine, "descriptor.enumerable"), "false");
+    assert_eq!(forward(&mut engine, "descriptor.get.call(map)"), "1");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { descriptor.get.call(new Set()) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
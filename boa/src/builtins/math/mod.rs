@@ -12,15 +12,43 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math
 
 use crate::{
-    builtins::value::{from_value, to_value, ResultValue, Value, ValueData},
+    builtins::{
+        property::Property,
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
     exec::Interpreter,
 };
 use rand::random;
-use std::f64;
 
 #[cfg(test)]
 mod tests;
 
+/// Gets the argument at `index` converted to a number, `NaN` if it's missing.
+fn number_arg(args: &[Value], index: usize, ctx: &mut Interpreter) -> Result<f64, Value> {
+    ctx.to_number(&args.get(index).cloned().unwrap_or_else(undefined))
+}
+
+/// Converts all the arguments to numbers, in order.
+fn numbers_of(args: &[Value], ctx: &mut Interpreter) -> Result<Vec<f64>, Value> {
+    args.iter().map(|arg| ctx.to_number(arg)).collect()
+}
+
+/// Raises a number to a power, as the `**` operator does.
+///
+/// Unlike `powf`, a base of 1 or -1 with an infinite or `NaN` exponent gives `NaN`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate
+pub(crate) fn number_pow(base: f64, exponent: f64) -> f64 {
+    if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+        f64::NAN
+    } else {
+        base.powf(exponent)
+    }
+}
+
 /// Get the absolute value of a number.
 ///
 /// More information:
@@ -29,14 +57,8 @@ mod tests;
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.abs
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/abs
-pub fn abs(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .abs()
-    }))
+pub fn abs(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.abs()))
 }
 
 /// Get the arccos of a number.
@@ -47,14 +69,8 @@ pub fn abs(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.acos
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos
-pub fn acos(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .acos()
-    }))
+pub fn acos(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.acos()))
 }
 
 /// Get the hyperbolic arccos of a number.
@@ -65,14 +81,8 @@ pub fn acos(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.acosh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh
-pub fn acosh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .acosh()
-    }))
+pub fn acosh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.acosh()))
 }
 
 /// Get the arcsine of a number.
@@ -83,14 +93,8 @@ pub fn acosh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.asin
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin
-pub fn asin(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .asin()
-    }))
+pub fn asin(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.asin()))
 }
 
 /// Get the hyperbolic arcsine of a number.
@@ -101,14 +105,8 @@ pub fn asin(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.asinh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh
-pub fn asinh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .asinh()
-    }))
+pub fn asinh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.asinh()))
 }
 
 /// Get the arctangent of a number.
@@ -119,14 +117,8 @@ pub fn asinh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.atan
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan
-pub fn atan(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .atan()
-    }))
+pub fn atan(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.atan()))
 }
 
 /// Get the hyperbolic arctangent of a number.
@@ -137,14 +129,8 @@ pub fn atan(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.atanh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh
-pub fn atanh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .atanh()
-    }))
+pub fn atanh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.atanh()))
 }
 
 /// Get the arctangent of a numbers.
@@ -155,14 +141,10 @@ pub fn atanh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.atan2
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan2
-pub fn atan2(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .atan2(args.get(1).expect("Could not get argument").to_number())
-    }))
+pub fn atan2(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let y = number_arg(args, 0, ctx)?;
+    let x = number_arg(args, 1, ctx)?;
+    Ok(to_value(y.atan2(x)))
 }
 
 /// Get the cubic root of a number.
@@ -173,14 +155,8 @@ pub fn atan2(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.cbrt
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt
-pub fn cbrt(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .cbrt()
-    }))
+pub fn cbrt(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.cbrt()))
 }
 
 /// Get lowest integer above a number.
@@ -191,14 +167,21 @@ pub fn cbrt(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.ceil
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/ceil
-pub fn ceil(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .ceil()
-    }))
+pub fn ceil(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.ceil()))
+}
+
+/// Get the number of leading zero bits of a number, as a 32 bits unsigned integer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.clz32
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/clz32
+pub fn clz32(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let number = ctx.to_uint32(&args.get(0).cloned().unwrap_or_else(undefined));
+    Ok(to_value(number.leading_zeros() as i32))
 }
 
 /// Get the cosine of a number.
@@ -209,14 +192,8 @@ pub fn ceil(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.cos
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos
-pub fn cos(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .cos()
-    }))
+pub fn cos(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.cos()))
 }
 
 /// Get the hyperbolic cosine of a number.
@@ -227,14 +204,8 @@ pub fn cos(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.cosh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh
-pub fn cosh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .cosh()
-    }))
+pub fn cosh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.cosh()))
 }
 
 /// Get the power to raise the natural logarithm to get the number.
@@ -245,14 +216,22 @@ pub fn cosh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.exp
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp
-pub fn exp(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .exp()
-    }))
+pub fn exp(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.exp()))
+}
+
+/// Get the exponential of a number, minus 1.
+///
+/// This is precise for numbers close to 0, unlike `Math.exp(x) - 1`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.expm1
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1
+pub fn expm1(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.exp_m1()))
 }
 
 /// Get the highest integer below a number.
@@ -263,14 +242,55 @@ pub fn exp(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.floor
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/floor
-pub fn floor(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .floor()
-    }))
+pub fn floor(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.floor()))
+}
+
+/// Round a number to the nearest single precision float.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.fround
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/fround
+pub fn fround(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(f64::from(number_arg(args, 0, ctx)? as f32)))
+}
+
+/// Get the square root of the sum of squares of its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.hypot
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/hypot
+pub fn hypot(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let numbers = numbers_of(args, ctx)?;
+    // An infinite argument wins over NaN
+    if numbers.iter().any(|number| number.is_infinite()) {
+        return Ok(to_value(f64::INFINITY));
+    }
+    Ok(to_value(
+        numbers
+            .into_iter()
+            .fold(0.0, |sum: f64, number| sum.hypot(number)),
+    ))
+}
+
+/// Multiply two numbers as 32 bits integers, like C does.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.imul
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/imul
+pub fn imul(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let a = ctx.to_uint32(&args.get(0).cloned().unwrap_or_else(undefined));
+    let b = ctx.to_uint32(&args.get(1).cloned().unwrap_or_else(undefined));
+    Ok(to_value(a.wrapping_mul(b) as i32))
 }
 
 /// Get the natural logarithm of a number.
@@ -281,19 +301,9 @@ pub fn floor(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.log
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log
-pub fn log(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        let value = from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-
-        if value <= 0.0 {
-            f64::NAN
-        } else {
-            value.log(f64::consts::E)
-        }
-    }))
+pub fn log(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // The logarithm of 0 is -Infinity, and the one of a negative number is NaN
+    Ok(to_value(number_arg(args, 0, ctx)?.ln()))
 }
 
 /// Get the base 10 logarithm of the number.
@@ -304,19 +314,23 @@ pub fn log(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.log10
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10
-pub fn log10(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        let value = from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-
-        if value <= 0.0 {
-            f64::NAN
-        } else {
-            value.log10()
-        }
-    }))
+pub fn log10(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // The logarithm of 0 is -Infinity, and the one of a negative number is NaN
+    Ok(to_value(number_arg(args, 0, ctx)?.log10()))
+}
+
+/// Get the natural logarithm of 1 plus a number.
+///
+/// This is precise for numbers close to 0, unlike `Math.log(1 + x)`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-math.log1p
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p
+pub fn log1p(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.ln_1p()))
 }
 
 /// Get the base 2 logarithm of the number.
@@ -327,19 +341,9 @@ pub fn log10(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.log2
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2
-pub fn log2(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        let value = from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-
-        if value <= 0.0 {
-            f64::NAN
-        } else {
-            value.log2()
-        }
-    }))
+pub fn log2(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // The logarithm of 0 is -Infinity, and the one of a negative number is NaN
+    Ok(to_value(number_arg(args, 0, ctx)?.log2()))
 }
 
 /// Get the maximum of several numbers.
@@ -350,13 +354,23 @@ pub fn log2(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.max
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/max
-pub fn max(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let mut max = f64::NEG_INFINITY;
-    for arg in args {
-        let num = arg.to_number();
-        max = max.max(num);
-    }
-    Ok(to_value(max))
+pub fn max(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // All the arguments are converted before comparing them
+    let numbers = numbers_of(args, ctx)?;
+    Ok(to_value(numbers.into_iter().fold(
+        f64::NEG_INFINITY,
+        |highest, number| {
+            if highest.is_nan() || number.is_nan() {
+                f64::NAN
+            } else if number > highest
+                || (highest == 0.0 && number == 0.0 && number.is_sign_positive())
+            {
+                number
+            } else {
+                highest
+            }
+        },
+    )))
 }
 
 /// Get the minimum of several numbers.
@@ -367,13 +381,23 @@ pub fn max(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.min
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/min
-pub fn min(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let mut max = f64::INFINITY;
-    for arg in args {
-        let num = arg.to_number();
-        max = max.min(num);
-    }
-    Ok(to_value(max))
+pub fn min(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // All the arguments are converted before comparing them
+    let numbers = numbers_of(args, ctx)?;
+    Ok(to_value(numbers.into_iter().fold(
+        f64::INFINITY,
+        |lowest, number| {
+            if lowest.is_nan() || number.is_nan() {
+                f64::NAN
+            } else if number < lowest
+                || (lowest == 0.0 && number == 0.0 && number.is_sign_negative())
+            {
+                number
+            } else {
+                lowest
+            }
+        },
+    )))
 }
 
 /// Raise a number to a power.
@@ -384,16 +408,10 @@ pub fn min(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.pow
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow
-pub fn pow(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.len() >= 2 {
-        let num: f64 = from_value(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-        let power: f64 = from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-        num.powf(power)
-    } else {
-        f64::NAN
-    }))
+pub fn pow(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let base = number_arg(args, 0, ctx)?;
+    let exponent = number_arg(args, 1, ctx)?;
+    Ok(to_value(number_pow(base, exponent)))
 }
 
 /// Generate a random floating-point number between `0` and `1`.
@@ -416,13 +434,22 @@ pub fn _random(_: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.round
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/round
-pub fn round(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
+pub fn round(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let number = number_arg(args, 0, ctx)?;
+    if !number.is_finite() || number.fract() == 0.0 {
+        return Ok(to_value(number));
+    }
+    // Halves are rounded towards +Infinity, and the numbers in [-0.5, 0) are rounded to -0
+    let floor = number.floor();
+    let rounded = if number - floor >= 0.5 {
+        floor + 1.0
+    } else {
+        floor
+    };
+    Ok(to_value(if rounded == 0.0 && number < 0.0 {
+        -0.0
     } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .round()
+        rounded
     }))
 }
 
@@ -434,19 +461,10 @@ pub fn round(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.sign
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign
-pub fn sign(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        let value = from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64");
-
-        if value == 0.0 || value == -0.0 {
-            value
-        } else {
-            value.signum()
-        }
-    }))
+pub fn sign(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let value = number_arg(args, 0, ctx)?;
+    // The zeros keep their sign, and NaN stays NaN
+    Ok(to_value(if value == 0.0 { value } else { value.signum() }))
 }
 
 /// Get the sine of a number.
@@ -457,14 +475,8 @@ pub fn sign(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.sin
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin
-pub fn sin(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .sin()
-    }))
+pub fn sin(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.sin()))
 }
 
 /// Get the hyperbolic sine of a number.
@@ -475,14 +487,8 @@ pub fn sin(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.sinh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh
-pub fn sinh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .sinh()
-    }))
+pub fn sinh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.sinh()))
 }
 
 /// Get the square root of a number.
@@ -493,24 +499,12 @@ pub fn sinh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.sqrt
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sqrt
-pub fn sqrt(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .sqrt()
-    }))
+pub fn sqrt(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.sqrt()))
 }
 /// Get the tangent of a number
-pub fn tan(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .tan()
-    }))
+pub fn tan(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.tan()))
 }
 
 /// Get the hyperbolic tangent of a number.
@@ -521,14 +515,8 @@ pub fn tan(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.tanh
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh
-pub fn tanh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .tanh()
-    }))
+pub fn tanh(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.tanh()))
 }
 
 /// Get the integer part of a number.
@@ -539,28 +527,37 @@ pub fn tanh(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-math.trunc
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/trunc
-pub fn trunc(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    Ok(to_value(if args.is_empty() {
-        f64::NAN
-    } else {
-        from_value::<f64>(args.get(0).expect("Could not get argument").clone())
-            .expect("Could not convert argument to f64")
-            .trunc()
-    }))
+pub fn trunc(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(number_arg(args, 0, ctx)?.trunc()))
 }
 
 /// Create a new `Math` object
 pub fn create(global: &Value) -> Value {
     let math = ValueData::new_obj(Some(global));
 
-    math.set_field_slice("E", to_value(f64::consts::E));
-    math.set_field_slice("LN2", to_value(f64::consts::LN_2));
-    math.set_field_slice("LN10", to_value(f64::consts::LN_10));
-    math.set_field_slice("LOG2E", to_value(f64::consts::LOG2_E));
-    math.set_field_slice("LOG10E", to_value(f64::consts::LOG10_E));
-    math.set_field_slice("SQRT1_2", to_value(0.5_f64.sqrt()));
-    math.set_field_slice("SQRT2", to_value(f64::consts::SQRT_2));
-    math.set_field_slice("PI", to_value(f64::consts::PI));
+    // The constants can't be changed
+    for (name, value) in [
+        ("E", std::f64::consts::E),
+        ("LN10", std::f64::consts::LN_10),
+        ("LN2", std::f64::consts::LN_2),
+        ("LOG10E", std::f64::consts::LOG10_E),
+        ("LOG2E", std::f64::consts::LOG2_E),
+        ("PI", std::f64::consts::PI),
+        ("SQRT1_2", std::f64::consts::FRAC_1_SQRT_2),
+        ("SQRT2", std::f64::consts::SQRT_2),
+    ]
+    .iter()
+    {
+        math.set_prop_slice(
+            name,
+            Property::default()
+                .value(to_value(*value))
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+    }
+
     make_builtin_fn!(abs, named "abs", with length 1, of math);
     make_builtin_fn!(acos, named "acos", with length 1, of math);
     make_builtin_fn!(acosh, named "acosh", with length 1, of math);
@@ -571,12 +568,18 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(atan2, named "atan2", with length 2, of math);
     make_builtin_fn!(cbrt, named "cbrt", with length 1, of math);
     make_builtin_fn!(ceil, named "ceil", with length 1, of math);
+    make_builtin_fn!(clz32, named "clz32", with length 1, of math);
     make_builtin_fn!(cos,  named "cos", with length 1, of math);
     make_builtin_fn!(cosh,  named "cosh", with length 1, of math);
     make_builtin_fn!(exp, named "exp", with length 1, of math);
+    make_builtin_fn!(expm1, named "expm1", with length 1, of math);
     make_builtin_fn!(floor, named "floor", with length 1, of math);
+    make_builtin_fn!(fround, named "fround", with length 1, of math);
+    make_builtin_fn!(hypot, named "hypot", with length 2, of math);
+    make_builtin_fn!(imul, named "imul", with length 2, of math);
     make_builtin_fn!(log, named "log", with length 1, of math);
     make_builtin_fn!(log10, named "log10", with length 1, of math);
+    make_builtin_fn!(log1p, named "log1p", with length 1, of math);
     make_builtin_fn!(log2, named "log2", with length 1, of math);
     make_builtin_fn!(max, named "max", with length 2, of math);
     make_builtin_fn!(min, named "min", with length 2, of math);
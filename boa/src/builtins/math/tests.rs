@@ -541,3 +541,83 @@ fn trunc() {
     assert_eq!(a.to_number(), 13_f64);
     assert_eq!(b.to_number(), 0_f64);
 }
+
+#[test]
+fn to_number_coercion() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Math.abs('-3')"), "3");
+    assert_eq!(forward(&mut engine, "Math.abs()"), "NaN");
+    assert_eq!(forward(&mut engine, "Math.floor(null)"), "0");
+    assert_eq!(forward(&mut engine, "Math.max('7', 2)"), "7");
+    assert_eq!(forward(&mut engine, "Math.max(1, 'a', 3)"), "NaN");
+    assert_eq!(forward(&mut engine, "1 / Math.max(-0, 0)"), "Infinity");
+    assert_eq!(forward(&mut engine, "1 / Math.min(0, -0)"), "-Infinity");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Math.abs(Symbol()) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn round_and_pow_edge_cases() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Math.round(-20.5)"), "-20");
+    assert_eq!(forward(&mut engine, "Math.round(2.5)"), "3");
+    assert_eq!(forward(&mut engine, "Math.round(0.49999999999999994)"), "0");
+    assert_eq!(forward(&mut engine, "1 / Math.round(-0.4)"), "-Infinity");
+    assert_eq!(forward(&mut engine, "Math.log(0)"), "-Infinity");
+    assert_eq!(forward(&mut engine, "Math.pow(1, Infinity)"), "NaN");
+    assert_eq!(
+        forward(&mut engine, "Math.pow(2, 0.5) === Math.SQRT2"),
+        "true"
+    );
+}
+
+#[test]
+fn integer_and_float_functions() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Math.clz32(1)"), "31");
+    assert_eq!(forward(&mut engine, "Math.clz32(0)"), "32");
+    assert_eq!(forward(&mut engine, "Math.imul(3, 4)"), "12");
+    assert_eq!(forward(&mut engine, "Math.imul(0xffffffff, 5)"), "-5");
+    assert_eq!(forward(&mut engine, "Math.fround(5.5)"), "5.5");
+    assert_eq!(
+        forward(&mut engine, "Math.fround(5.05)"),
+        "5.050000190734863"
+    );
+    assert_eq!(forward(&mut engine, "Math.hypot(3, 4)"), "5");
+    assert_eq!(forward(&mut engine, "Math.hypot()"), "0");
+    assert_eq!(
+        forward(&mut engine, "Math.hypot(NaN, Infinity)"),
+        "Infinity"
+    );
+    assert_eq!(forward(&mut engine, "Math.expm1(0)"), "0");
+    assert_eq!(forward(&mut engine, "Math.log1p(0)"), "0");
+    assert_eq!(forward(&mut engine, "Math.log1p(-1)"), "-Infinity");
+}
+
+#[test]
+fn constants_are_not_writable() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Math, 'PI').writable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Math, 'E').enumerable"
+        ),
+        "false"
+    );
+}
@@ -3,8 +3,9 @@
 /// Macro to create a new member function of a prototype.
 ///
 /// If no length is provided, the length will be set to 0.
+/// Functions keyed by a symbol, like `[Symbol.iterator]`, are not enumerable.
 macro_rules! make_builtin_fn {
-    ($fn:ident, named $name:expr, with length $l:tt, of $p:ident) => {
+    (@function $fn:ident, named $name:expr, with length $l:tt) => {{
         let func = crate::builtins::function::Function::create_builtin(
             vec![],
             crate::builtins::function::FunctionBody::BuiltIn($fn),
@@ -13,12 +14,62 @@ macro_rules! make_builtin_fn {
         let mut new_func = crate::builtins::object::Object::function();
         new_func.set_call(func);
         let new_func_obj = to_value(new_func);
-        new_func_obj.set_field_slice("length", to_value($l));
-        $p.set_field_slice($name, new_func_obj);
+        crate::builtins::function::set_function_name_and_length(
+            &new_func_obj,
+            to_value($name),
+            f64::from($l),
+        );
+        new_func_obj
+    }};
+    ($fn:ident, named $name:expr, with length $l:tt, of $p:ident) => {
+        $p.set_field_slice($name, make_builtin_fn!(@function $fn, named $name, with length $l));
     };
     ($fn:ident, named $name:expr, of $p:ident) => {
         make_builtin_fn!($fn, named $name, with length 0, of $p);
     };
+    ($fn:ident, keyed $key:expr, with length $l:tt, of $p:ident) => {
+        $p.set_symbol_prop(
+            &$key,
+            crate::builtins::property::Property::default()
+                .value(make_builtin_fn!(
+                    @function $fn,
+                    named crate::builtins::function::symbol_function_name(&$key),
+                    with length $l
+                ))
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+    };
+    ($fn:ident, keyed $key:expr, of $p:ident) => {
+        make_builtin_fn!($fn, keyed $key, with length 0, of $p);
+    };
+}
+
+/// Macro to create a getter on a prototype, for an accessor property without setter.
+///
+/// Like the builtin accessors of the spec, the property is configurable but not enumerable.
+/// The property is keyed either by a name or by a symbol value, like `Symbol.species`.
+macro_rules! make_getter_fn {
+    (@property $fn:ident, named $name:expr) => {
+        crate::builtins::property::Property::default()
+            .get(make_builtin_fn!(@function $fn, named format!("get {}", $name), with length 0))
+            .set(crate::builtins::value::undefined())
+            .enumerable(false)
+            .configurable(true)
+    };
+    ($fn:ident, named $name:expr, of $p:ident) => {
+        $p.set_prop_slice($name, make_getter_fn!(@property $fn, named $name));
+    };
+    ($fn:ident, keyed $key:expr, of $p:ident) => {
+        $p.set_symbol_prop(
+            &$key,
+            make_getter_fn!(
+                @property $fn,
+                named crate::builtins::function::symbol_function_name(&$key)
+            ),
+        );
+    };
 }
 
 /// Macro to create a new constructor function
@@ -82,34 +133,68 @@ macro_rules! make_constructor_fn {
 }
 
 pub mod array;
+pub mod array_buffer;
 pub mod boolean;
+pub mod class;
 pub mod console;
+pub mod data_view;
+pub mod date;
 pub mod error;
+pub mod finalization_registry;
 pub mod function;
+pub mod global_object;
+#[cfg(feature = "intl")]
+pub mod intl;
 pub mod json;
+pub mod map;
 pub mod math;
 pub mod number;
 pub mod object;
 pub mod property;
+pub mod proxy;
+pub mod reflect;
 pub mod regexp;
+pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod value;
+pub mod weak_map;
+pub mod weak_ref;
+pub mod weak_set;
 
 use value::Value;
 
 /// Initializes builtin objects and functions
 #[inline]
 pub fn init(global: &Value) {
+    object::init(global);
+    function::init(global);
+    // The well-known symbols are used by the other builtins, so `Symbol` is created early
+    symbol::init(global);
+    // `Number` shares the parsing functions of the global object
+    global_object::init(global);
     array::init(global);
     boolean::init(global);
+    date::init(global);
     json::init(global);
     math::init(global);
     number::init(global);
-    object::init(global);
-    function::init(global);
+    array_buffer::init(global);
+    typed_array::init(global);
+    data_view::init(global);
+    error::init(global);
+    map::init(global);
+    proxy::init(global);
+    reflect::init(global);
     regexp::init(global);
+    set::init(global);
     string::init(global);
-    symbol::init(global);
+    weak_map::init(global);
+    weak_ref::init(global);
+    weak_set::init(global);
+    #[cfg(feature = "intl")]
+    intl::init(global);
+    finalization_registry::init(global);
     console::init(global);
 }
@@ -16,14 +16,18 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "intl")]
+use crate::builtins::intl::number_format;
 use crate::{
     builtins::{
         object::{internal_methods_trait::ObjectInternalMethods, Object, PROTOTYPE},
+        property::Property,
+        string::is_trimmable_whitespace,
         value::{to_value, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
-use std::{borrow::Borrow, f64, ops::Deref};
+use std::{borrow::Borrow, ops::Deref};
 
 /// Helper function that converts a Value to a Number.
 fn to_number(value: &Value) -> Value {
@@ -40,11 +44,97 @@ fn to_number(value: &Value) -> Value {
         ValueData::Object(ref o) => (o).deref().borrow().get_internal_slot("NumberData"),
         ValueData::Null => to_value(0),
         ValueData::Rational(n) => to_value(n),
-        ValueData::String(ref s) => match s.parse::<f64>() {
-            Ok(n) => to_value(n),
-            Err(_) => to_value(f64::NAN),
-        },
+        ValueData::String(ref s) => to_value(string_to_number(&s.to_string())),
+    }
+}
+
+/// Gets the length of the longest prefix of a string which is a `StrUnsignedDecimalLiteral`, without `Infinity`.
+///
+/// Returns 0 if the string doesn't start with a decimal literal.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-StrUnsignedDecimalLiteral
+pub(crate) fn unsigned_decimal_literal_len(string: &str) -> usize {
+    let bytes = string.as_bytes();
+    let digits_from = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let integer_digits = digits_from(0);
+    let mut len = integer_digits;
+    let mut fraction_digits = 0;
+    if bytes.get(len) == Some(&b'.') {
+        fraction_digits = digits_from(len + 1);
+        if integer_digits > 0 || fraction_digits > 0 {
+            len += 1 + fraction_digits;
+        }
+    }
+    if integer_digits == 0 && fraction_digits == 0 {
+        return 0;
+    }
+
+    // The exponent is only part of the literal if it has digits
+    if let Some(b'e') | Some(b'E') = bytes.get(len) {
+        let sign = match bytes.get(len + 1) {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        let exponent_digits = digits_from(len + 1 + sign);
+        if exponent_digits > 0 {
+            len += 1 + sign + exponent_digits;
+        }
+    }
+    len
+}
+
+/// Converts a string to a number, as `Number(string)` does.
+///
+/// Whitespace is trimmed, the empty string is `0`, and anything which isn't a numeric
+/// literal is `NaN`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-stringtonumber
+pub(crate) fn string_to_number(string: &str) -> f64 {
+    let string = string.trim_matches(is_trimmable_whitespace);
+    if string.is_empty() {
+        return 0.0;
+    }
+
+    let radix = match string.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => 0,
+    };
+    if radix != 0 {
+        let digits = &string[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return f64::NAN;
+        }
+        return digits.chars().fold(0.0, |number, digit| {
+            number * f64::from(radix) + f64::from(digit.to_digit(radix).expect("checked digit"))
+        });
     }
+
+    let (sign, unsigned) = match string.as_bytes()[0] {
+        b'-' => (-1.0, &string[1..]),
+        b'+' => (1.0, &string[1..]),
+        _ => (1.0, string),
+    };
+    if unsigned == "Infinity" {
+        return sign * f64::INFINITY;
+    }
+    if unsigned_decimal_literal_len(unsigned) != unsigned.len() {
+        return f64::NAN;
+    }
+    sign * unsigned.parse::<f64>().unwrap_or(f64::NAN)
 }
 
 /// Helper function that formats a float as a ES6-style exponential number string.
@@ -56,6 +146,56 @@ fn num_to_exponential(n: f64) -> String {
     }
 }
 
+/// Converts a number to its string representation, with the shortest digits which
+/// round-trip and the exponential notation of JavaScript.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+pub(crate) fn number_to_string(number: f64) -> String {
+    match number {
+        _ if number.is_nan() => return "NaN".to_string(),
+        _ if number == 0.0 => return "0".to_string(),
+        _ if number == f64::INFINITY => return "Infinity".to_string(),
+        _ if number == f64::NEG_INFINITY => return "-Infinity".to_string(),
+        _ => {}
+    }
+
+    // Rust formats the shortest round-tripping digits, like `1.2345e6`
+    let exponential = format!("{:e}", number.abs());
+    let (mantissa, exponent) = exponential.split_at(exponential.find('e').expect("exponent"));
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent[1..].parse::<i32>().expect("exponent") + 1;
+
+    let mut string = String::new();
+    if number < 0.0 {
+        string.push('-');
+    }
+    if k <= n && n <= 21 {
+        string.push_str(&digits);
+        string.extend((0..n - k).map(|_| '0'));
+    } else if 0 < n && n <= 21 {
+        string.push_str(&digits[..n as usize]);
+        string.push('.');
+        string.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        string.push_str("0.");
+        string.extend((0..-n).map(|_| '0'));
+        string.push_str(&digits);
+    } else {
+        string.push_str(&digits[..1]);
+        if k > 1 {
+            string.push('.');
+            string.push_str(&digits[1..]);
+        }
+        let sign = if n > 0 { '+' } else { '-' };
+        string.push_str(&format!("e{}{}", sign, (n - 1).abs()));
+    }
+    string
+}
+
 /// Create a new number `[[Construct]]`
 pub fn make_number(this: &mut Value, args: &[Value], _ctx: &mut Interpreter) -> ResultValue {
     let data = match args.get(0) {
@@ -103,11 +243,14 @@ pub fn to_exponential(this: &mut Value, _args: &[Value], _ctx: &mut Interpreter)
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tofixed
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toFixed
-pub fn to_fixed(this: &mut Value, args: &[Value], _ctx: &mut Interpreter) -> ResultValue {
+pub fn to_fixed(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let this_num = to_number(this).to_number();
     let precision = match args.get(0) {
         Some(n) => match n.to_integer() {
-            x if x > 0 => n.to_integer() as usize,
+            x if x > 100 => {
+                return ctx.throw_range_error("toFixed() digits argument must be between 0 and 100")
+            }
+            x if x > 0 => x as usize,
             _ => 0,
         },
         None => 0,
@@ -116,6 +259,25 @@ pub fn to_fixed(this: &mut Value, args: &[Value], _ctx: &mut Interpreter) -> Res
     Ok(to_value(this_fixed_num))
 }
 
+/// `Number.prototype.toLocaleString( [locales [, options]] )`
+///
+/// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
+///
+/// The number is formatted by an `Intl.NumberFormat` with the locales and options. Without the
+/// `intl` feature, this does no actual internationalization logic and ignores its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
+#[cfg(feature = "intl")]
+pub fn to_locale_string(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let this_num = to_number(this).to_number();
+    number_format::to_locale_string(this_num, args, ctx)
+}
+
 /// `Number.prototype.toLocaleString( [locales [, options]] )`
 ///
 /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
@@ -129,6 +291,7 @@ pub fn to_fixed(this: &mut Value, args: &[Value], _ctx: &mut Interpreter) -> Res
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
+#[cfg(not(feature = "intl"))]
 pub fn to_locale_string(this: &mut Value, _args: &[Value], _ctx: &mut Interpreter) -> ResultValue {
     let this_num = to_number(this).to_number();
     let this_str_num = format!("{}", this_num);
@@ -145,18 +308,32 @@ pub fn to_locale_string(this: &mut Value, _args: &[Value], _ctx: &mut Interprete
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.toexponential
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toPrecision
-pub fn to_precision(this: &mut Value, args: &[Value], _ctx: &mut Interpreter) -> ResultValue {
-    let this_num = to_number(this);
-    let _num_str_len = format!("{}", this_num.to_number()).len();
-    let _precision = match args.get(0) {
-        Some(n) => match n.to_integer() {
-            x if x > 0 => n.to_integer() as usize,
-            _ => 0,
-        },
-        None => 0,
+pub fn to_precision(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let this_num = to_number(this).to_number();
+    let precision = match args.get(0) {
+        Some(n) if !n.is_undefined() => n.to_number(),
+        _ => return Ok(to_value(format!("{}", this_num))),
     };
-    // TODO: Implement toPrecision
-    unimplemented!("TODO: Implement toPrecision");
+    if !this_num.is_finite() {
+        return Ok(to_value(format!("{}", this_num)));
+    }
+    if !(1.0..=100.0).contains(&precision.trunc()) {
+        return ctx.throw_range_error("toPrecision() argument must be between 1 and 100");
+    }
+    let precision = precision.trunc() as usize;
+
+    // Rust rounds the digits for us, we only need to know the resulting exponent
+    let exponential = format!("{:.*e}", precision - 1, this_num);
+    let (mantissa, exponent) = exponential.split_at(exponential.find('e').unwrap_or(0));
+    let exponent: i32 = exponent[1..].parse().unwrap_or(0);
+
+    if exponent < -6 || exponent >= precision as i32 {
+        let sign = if exponent < 0 { "-" } else { "+" };
+        Ok(to_value(format!("{}e{}{}", mantissa, sign, exponent.abs())))
+    } else {
+        let decimals = (precision as i32 - 1 - exponent) as usize;
+        Ok(to_value(format!("{:.*}", decimals, this_num)))
+    }
 }
 
 /// `Number.prototype.toString( [radix] )`
@@ -187,6 +364,90 @@ pub fn value_of(this: &mut Value, _args: &[Value], _ctx: &mut Interpreter) -> Re
     Ok(to_number(this))
 }
 
+/// The largest integer `n` such that `n` and `n + 1` are both exactly representable, `2^53 - 1`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Gets the number of a value, without any conversion.
+fn number_value(value: &Value) -> Option<f64> {
+    match **value {
+        ValueData::Rational(number) => Some(number),
+        ValueData::Integer(number) => Some(f64::from(number)),
+        _ => None,
+    }
+}
+
+/// Checks if a number is finite and has no fractional part.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isintegralnumber
+fn is_integral_number(number: f64) -> bool {
+    number.is_finite() && number.trunc() == number
+}
+
+/// `Number.isFinite( number )`
+///
+/// Checks if the argument is a finite number, unlike the global `isFinite` it doesn't convert it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.isfinite
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isFinite
+pub fn number_is_finite(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    let number = args.get(0).and_then(number_value);
+    Ok(to_value(number.map_or(false, f64::is_finite)))
+}
+
+/// `Number.isInteger( number )`
+///
+/// Checks if the argument is a number without a fractional part.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.isinteger
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isInteger
+pub fn is_integer(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    let number = args.get(0).and_then(number_value);
+    Ok(to_value(number.map_or(false, is_integral_number)))
+}
+
+/// `Number.isNaN( number )`
+///
+/// Checks if the argument is `NaN`, unlike the global `isNaN` it doesn't convert it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.isnan
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN
+pub fn number_is_nan(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    let number = args.get(0).and_then(number_value);
+    Ok(to_value(number.map_or(false, f64::is_nan)))
+}
+
+/// `Number.isSafeInteger( number )`
+///
+/// Checks if the argument is an integer which is exactly representable, along with its neighbours.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.issafeinteger
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger
+pub fn is_safe_integer(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    let number = args.get(0).and_then(number_value);
+    Ok(to_value(number.map_or(false, |number| {
+        is_integral_number(number) && number.abs() <= MAX_SAFE_INTEGER
+    })))
+}
+
 /// Create a new `Number` object
 pub fn create(global: &Value) -> Value {
     let prototype = ValueData::new_obj(Some(global));
@@ -199,7 +460,41 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(to_string, named "toString", with length 1, of prototype);
     make_builtin_fn!(value_of, named "valueOf", of prototype);
 
-    make_constructor_fn!(make_number, call_number, global, prototype)
+    let number = make_constructor_fn!(make_number, call_number, global, prototype);
+
+    // The constants can't be changed
+    for (name, value) in [
+        ("EPSILON", f64::EPSILON),
+        ("MAX_SAFE_INTEGER", MAX_SAFE_INTEGER),
+        ("MAX_VALUE", f64::MAX),
+        ("MIN_SAFE_INTEGER", -MAX_SAFE_INTEGER),
+        // The smallest positive number, which is denormalized
+        ("MIN_VALUE", f64::from_bits(1)),
+        ("NEGATIVE_INFINITY", f64::NEG_INFINITY),
+        ("NaN", f64::NAN),
+        ("POSITIVE_INFINITY", f64::INFINITY),
+    ]
+    .iter()
+    {
+        number.set_prop_slice(
+            name,
+            Property::default()
+                .value(to_value(*value))
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+    }
+
+    make_builtin_fn!(number_is_finite, named "isFinite", with length 1, of number);
+    make_builtin_fn!(is_integer, named "isInteger", with length 1, of number);
+    make_builtin_fn!(number_is_nan, named "isNaN", with length 1, of number);
+    make_builtin_fn!(is_safe_integer, named "isSafeInteger", with length 1, of number);
+
+    // These are the same function objects as the global `parseInt` and `parseFloat`
+    number.set_field_slice("parseFloat", global.get_field_slice("parseFloat"));
+    number.set_field_slice("parseInt", global.get_field_slice("parseInt"));
+    number
 }
 
 /// Initialise the `Number` object on the global object.
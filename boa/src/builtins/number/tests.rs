@@ -110,9 +110,7 @@ fn to_locale_string() {
         var neg_locale = Number(-25).toLocaleString();
         "#;
 
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
-
+    // The numbers are formatted in the default locale, `en-US`, which groups the digits by three
     eprintln!("{}", forward(&mut engine, init));
     let default_locale = forward(&mut engine, "default_locale");
     let small_locale = forward(&mut engine, "small_locale");
@@ -121,12 +119,15 @@ fn to_locale_string() {
 
     assert_eq!(default_locale, String::from("0"));
     assert_eq!(small_locale, String::from("5"));
-    assert_eq!(big_locale, String::from("345600"));
+    if cfg!(feature = "intl") {
+        assert_eq!(big_locale, String::from("345,600"));
+    } else {
+        assert_eq!(big_locale, String::from("345600"));
+    }
     assert_eq!(neg_locale, String::from("-25"));
 }
 
 #[test]
-#[ignore]
 fn to_precision() {
     let realm = Realm::create();
     let mut engine = Executor::new(realm);
@@ -211,3 +212,69 @@ fn value_of() {
     assert_eq!(exp_val.to_number(), 12_000_f64);
     assert_eq!(neg_val.to_number(), -12_000_f64);
 }
+
+#[test]
+fn number_statics() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Number.isInteger(5)"), "true");
+    assert_eq!(forward(&mut engine, "Number.isInteger(5.5)"), "false");
+    assert_eq!(forward(&mut engine, "Number.isInteger('5')"), "false");
+    assert_eq!(forward(&mut engine, "Number.isInteger(Infinity)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Number.isSafeInteger(Number.MAX_SAFE_INTEGER)"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Number.isSafeInteger(Number.MAX_SAFE_INTEGER + 1)"
+        ),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Number.isFinite('1')"), "false");
+    assert_eq!(forward(&mut engine, "Number.isFinite(1)"), "true");
+    assert_eq!(forward(&mut engine, "Number.isNaN('abc')"), "false");
+    assert_eq!(forward(&mut engine, "Number.isNaN(Number.NaN)"), "true");
+}
+
+#[test]
+fn number_constants() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Number.EPSILON > 0"), "true");
+    assert_eq!(forward(&mut engine, "1 + Number.EPSILON > 1"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Number.MIN_SAFE_INTEGER === -Number.MAX_SAFE_INTEGER"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Number.MIN_VALUE / 2"), "0");
+    assert_eq!(forward(&mut engine, "Number.MAX_VALUE * 2"), "Infinity");
+    assert_eq!(
+        forward(&mut engine, "Number.NEGATIVE_INFINITY"),
+        "-Infinity"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Number, 'EPSILON').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn string_to_number() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Number('  12  ')"), "12");
+    assert_eq!(forward(&mut engine, "Number('')"), "0");
+    assert_eq!(forward(&mut engine, "Number('0x1F')"), "31");
+    assert_eq!(forward(&mut engine, "Number('0b101')"), "5");
+    assert_eq!(forward(&mut engine, "Number('-Infinity')"), "-Infinity");
+    assert_eq!(forward(&mut engine, "Number('inf')"), "NaN");
+    assert_eq!(forward(&mut engine, "Number('12px')"), "NaN");
+}
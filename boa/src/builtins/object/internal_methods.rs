@@ -0,0 +1,563 @@
+//! This module implements the dispatch of the essential internal methods of objects.
+//!
+//! Every object holds a table of its internal methods, so exotic objects like `Proxy` can
+//! replace the ordinary behaviour of property access. The interpreter always goes through
+//! this table, using the abstract operations like [`Interpreter::get_property`][get].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-object-internal-methods-and-internal-slots
+//! [get]: ../../../exec/struct.Interpreter.html#method.get_property
+
+use crate::{
+    builtins::{
+        object::{
+            internal_methods_trait::ObjectInternalMethods, Object, ObjectKind, INSTANCE_PROTOTYPE,
+            PROTOTYPE,
+        },
+        property::Property,
+        value::{same_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use std::fmt::{self, Debug, Formatter};
+
+/// The signature of an exotic `[[Call]]`: the callee, the `this` value and the arguments.
+pub type CallInternalMethod = fn(&Value, &mut Value, &[Value], &mut Interpreter) -> ResultValue;
+
+/// The signature of an exotic `[[Construct]]`: the callee, the arguments and the new target.
+pub type ConstructInternalMethod = fn(&Value, &[Value], &Value, &mut Interpreter) -> ResultValue;
+
+/// The essential internal methods of an object.
+///
+/// `[[Call]]` and `[[Construct]]` are only present on the tables of exotic callable objects,
+/// the ordinary functions keep their body in [`Object::call`](struct.Object.html#structfield.call).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-essential-internal-methods
+#[derive(Clone, Copy)]
+pub struct InternalObjectMethods {
+    /// `[[GetPrototypeOf]]`, returning an object or `null`.
+    pub get_prototype_of: fn(&Value, &mut Interpreter) -> ResultValue,
+    /// `[[SetPrototypeOf]]`
+    pub set_prototype_of: fn(&Value, &Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[IsExtensible]]`
+    pub is_extensible: fn(&Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[PreventExtensions]]`
+    pub prevent_extensions: fn(&Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[GetOwnProperty]]`, returning `None` for a missing property.
+    pub get_own_property: fn(&Value, &Value, &mut Interpreter) -> Result<Option<Property>, Value>,
+    /// `[[DefineOwnProperty]]`
+    pub define_own_property: fn(&Value, &Value, Property, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[HasProperty]]`
+    pub has_property: fn(&Value, &Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[Get]]`, taking the key and the receiver.
+    pub get: fn(&Value, &Value, &Value, &mut Interpreter) -> ResultValue,
+    /// `[[Set]]`, taking the key, the value and the receiver.
+    pub set: fn(&Value, &Value, Value, &Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[Delete]]`
+    pub delete: fn(&Value, &Value, &mut Interpreter) -> Result<bool, Value>,
+    /// `[[OwnPropertyKeys]]`
+    pub own_property_keys: fn(&Value, &mut Interpreter) -> Result<Vec<Value>, Value>,
+    /// `[[Call]]`, taking the `this` value and the arguments.
+    pub call: Option<CallInternalMethod>,
+    /// `[[Construct]]`, taking the arguments and the new target.
+    pub construct: Option<ConstructInternalMethod>,
+}
+
+impl Debug for InternalObjectMethods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InternalObjectMethods")
+            .field("call", &self.call.is_some())
+            .field("construct", &self.construct.is_some())
+            .finish()
+    }
+}
+
+/// The internal methods of ordinary objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
+pub const ORDINARY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: ordinary_get_prototype_of,
+    set_prototype_of: ordinary_set_prototype_of,
+    is_extensible: ordinary_is_extensible,
+    prevent_extensions: ordinary_prevent_extensions,
+    get_own_property: ordinary_get_own_property,
+    define_own_property: ordinary_define_own_property,
+    has_property: ordinary_has_property,
+    get: ordinary_get,
+    set: ordinary_set,
+    delete: ordinary_delete,
+    own_property_keys: ordinary_own_property_keys,
+    call: None,
+    construct: None,
+};
+
+/// Gets the internal methods of `value`, the ordinary ones if it's not an object.
+pub fn internal_methods_of(value: &Value) -> &'static InternalObjectMethods {
+    match **value {
+        ValueData::Object(ref obj) => obj.borrow().internal_methods,
+        _ => &ORDINARY_INTERNAL_METHODS,
+    }
+}
+
+/// Gets the id under which the properties keyed by the symbol `key` are stored.
+pub(crate) fn symbol_id(key: &Value) -> Option<usize> {
+    match **key {
+        ValueData::Symbol(ref sym) => match sym.borrow().kind {
+            ObjectKind::Symbol(id) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Gets the string under which the properties keyed by the string `key` are stored.
+///
+/// String keys are used as they are, so keys which only differ by their lone surrogates stay apart.
+pub(crate) fn property_key_string(key: &Value) -> JsString {
+    match **key {
+        ValueData::String(ref string) => string.clone(),
+        _ => JsString::from(key.to_string()),
+    }
+}
+
+/// Gets the own property of `object` stored under `key`, as it is stored.
+fn stored_property(object: &Object, key: &Value) -> Option<Property> {
+    match symbol_id(key) {
+        Some(id) => object.sym_properties.get(&id).cloned(),
+        None => object.properties.get(&property_key_string(key)).cloned(),
+    }
+}
+
+/// Fills the attributes missing from a stored property.
+///
+/// Some builtins store properties without all of their attributes, these have
+/// always been writable, enumerable and configurable.
+fn fill_stored_attributes(mut property: Property) -> Property {
+    if property.is_accessor_descriptor() {
+        property.get = property.get.take().or_else(|| Some(undefined()));
+        property.set = property.set.take().or_else(|| Some(undefined()));
+    } else {
+        property.value = property.value.take().or_else(|| Some(undefined()));
+        property.writable = property.writable.or(Some(true));
+    }
+    property.enumerable = property.enumerable.or(Some(true));
+    property.configurable = property.configurable.or(Some(true));
+    property
+}
+
+/// Checks that a property descriptor can be applied over the `current` one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iscompatiblepropertydescriptor
+pub(crate) fn is_compatible_property_descriptor(
+    extensible: bool,
+    desc: &Property,
+    current: Option<&Property>,
+) -> bool {
+    let current = match current {
+        Some(current) => current,
+        None => return extensible,
+    };
+    if current.configurable != Some(false) {
+        return true;
+    }
+    if desc.configurable == Some(true) {
+        return false;
+    }
+    if desc.enumerable.is_some() && desc.enumerable != current.enumerable {
+        return false;
+    }
+    if desc.is_generic_descriptor() {
+        return true;
+    }
+    if desc.is_accessor_descriptor() != current.is_accessor_descriptor() {
+        return false;
+    }
+
+    let unchanged = |new: &Option<Value>, old: &Option<Value>| match new {
+        Some(new) => same_value(new, &old.clone().unwrap_or_else(undefined), false),
+        None => true,
+    };
+    if current.is_accessor_descriptor() {
+        unchanged(&desc.get, &current.get) && unchanged(&desc.set, &current.set)
+    } else if current.writable == Some(false) {
+        desc.writable != Some(true) && unchanged(&desc.value, &current.value)
+    } else {
+        true
+    }
+}
+
+/// Applies a validated property descriptor over the `current` one.
+fn apply_property_descriptor(mut desc: Property, current: Option<Property>) -> Property {
+    let mut property = match current {
+        None => return desc.complete(),
+        Some(current) => current,
+    };
+
+    // Switching between a data and an accessor property keeps the `configurable` and
+    // `enumerable` attributes, and resets the others to their defaults
+    if desc.is_accessor_descriptor() && property.is_data_descriptor() {
+        property.value = None;
+        property.writable = None;
+        property.get = Some(undefined());
+        property.set = Some(undefined());
+    } else if desc.is_data_descriptor() && property.is_accessor_descriptor() {
+        property.get = None;
+        property.set = None;
+        property.value = Some(undefined());
+        property.writable = Some(false);
+    }
+
+    Property {
+        configurable: desc.configurable.or(property.configurable),
+        enumerable: desc.enumerable.or(property.enumerable),
+        writable: desc.writable.or(property.writable),
+        value: desc.value.take().or_else(|| property.value.take()),
+        get: desc.get.take().or_else(|| property.get.take()),
+        set: desc.set.take().or_else(|| property.set.take()),
+    }
+}
+
+/// Gets the index of an array element from its property key.
+fn array_index(key: &Value) -> Option<u32> {
+    if !key.is_string() {
+        return None;
+    }
+    let key = key.to_string();
+    key.parse::<u32>()
+        .ok()
+        .filter(|index| *index != u32::MAX && index.to_string() == key)
+}
+
+/// `OrdinaryGetPrototypeOf`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarygetprototypeof
+fn ordinary_get_prototype_of(obj: &Value, ctx: &mut Interpreter) -> ResultValue {
+    let proto = match obj.as_object() {
+        // Built-in functions are created without access to the realm, so they fall back to `Function.prototype`
+        Some(ref obj)
+            if obj.is_callable()
+                && obj
+                    .internal_slots
+                    .get(INSTANCE_PROTOTYPE)
+                    .map_or(true, |proto| proto.is_undefined()) =>
+        {
+            ctx.realm
+                .global_obj
+                .get_field_slice("Function")
+                .get_field_slice(PROTOTYPE)
+        }
+        Some(obj) => obj.get_prototype_of(),
+        None => return Ok(Value::new(ValueData::Null)),
+    };
+    if proto.is_object() {
+        Ok(proto)
+    } else {
+        Ok(Value::new(ValueData::Null))
+    }
+}
+
+/// `OrdinarySetPrototypeOf`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarysetprototypeof
+fn ordinary_set_prototype_of(
+    obj: &Value,
+    proto: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let current = ordinary_get_prototype_of(obj, ctx)?;
+    if same_value(proto, &current, false) {
+        return Ok(true);
+    }
+    if !ordinary_is_extensible(obj, ctx)? {
+        return Ok(false);
+    }
+
+    // Refuse to create a cycle, stopping at the first exotic prototype which may not be ordinary
+    let mut p = proto.clone();
+    while p.is_object() {
+        if same_value(&p, obj, false) {
+            return Ok(false);
+        }
+        if let Some(ObjectKind::Proxy(_)) = p.as_object().map(|p| p.kind.clone()) {
+            break;
+        }
+        p = ordinary_get_prototype_of(&p, ctx)?;
+    }
+
+    if let ValueData::Object(ref obj) = **obj {
+        obj.borrow_mut()
+            .set_internal_slot(INSTANCE_PROTOTYPE, proto.clone());
+    }
+    Ok(true)
+}
+
+/// `OrdinaryIsExtensible`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryisextensible
+fn ordinary_is_extensible(obj: &Value, _: &mut Interpreter) -> Result<bool, Value> {
+    Ok(obj.as_object().map_or(false, |obj| obj.is_extensible()))
+}
+
+/// `OrdinaryPreventExtensions`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarypreventextensions
+fn ordinary_prevent_extensions(obj: &Value, _: &mut Interpreter) -> Result<bool, Value> {
+    if let ValueData::Object(ref obj) = **obj {
+        obj.borrow_mut().prevent_extensions();
+    }
+    Ok(true)
+}
+
+/// `OrdinaryGetOwnProperty`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarygetownproperty
+fn ordinary_get_own_property(
+    obj: &Value,
+    key: &Value,
+    _: &mut Interpreter,
+) -> Result<Option<Property>, Value> {
+    Ok(obj
+        .as_object()
+        .and_then(|obj| stored_property(&obj, key))
+        .map(fill_stored_attributes))
+}
+
+/// `OrdinaryDefineOwnProperty`
+///
+/// Defining an element past the end of an array also grows its `length`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarydefineownproperty
+fn ordinary_define_own_property(
+    obj: &Value,
+    key: &Value,
+    desc: Property,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let current = ordinary_get_own_property(obj, key, ctx)?;
+    let extensible = ordinary_is_extensible(obj, ctx)?;
+    if !is_compatible_property_descriptor(extensible, &desc, current.as_ref()) {
+        return Ok(false);
+    }
+
+    let property = apply_property_descriptor(desc, current);
+    if let ValueData::Object(ref obj) = **obj {
+        let mut obj = obj.borrow_mut();
+        match symbol_id(key) {
+            Some(id) => {
+                obj.sym_properties.insert(id, property);
+                obj.sym_keys.insert(id, key.clone());
+            }
+            None => {
+                obj.properties.insert(property_key_string(key), property);
+            }
+        }
+
+        if obj.kind == ObjectKind::Array {
+            if let Some(index) = array_index(key) {
+                if let Some(length) = obj.properties.get_mut(&JsString::from("length")) {
+                    let current = length.value.as_ref().map_or(0.0, |len| len.to_number());
+                    if f64::from(index) >= current {
+                        length.value = Some(to_value(u64::from(index) + 1));
+                    }
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// `OrdinaryHasProperty`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasproperty
+fn ordinary_has_property(obj: &Value, key: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    if (internal_methods_of(obj).get_own_property)(obj, key, ctx)?.is_some() {
+        return Ok(true);
+    }
+    let parent = ordinary_get_prototype_of(obj, ctx)?;
+    if parent.is_object() {
+        ctx.has_property(&parent, key)
+    } else {
+        Ok(false)
+    }
+}
+
+/// `OrdinaryGet`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryget
+fn ordinary_get(obj: &Value, key: &Value, receiver: &Value, ctx: &mut Interpreter) -> ResultValue {
+    let desc = match (internal_methods_of(obj).get_own_property)(obj, key, ctx)? {
+        Some(desc) => desc,
+        None => {
+            let parent = ordinary_get_prototype_of(obj, ctx)?;
+            if !parent.is_object() {
+                return Ok(undefined());
+            }
+            return (internal_methods_of(&parent).get)(&parent, key, receiver, ctx);
+        }
+    };
+
+    if desc.is_data_descriptor() {
+        return Ok(desc.value.clone().unwrap_or_else(undefined));
+    }
+    match desc.get {
+        Some(ref getter) if getter.is_function() => ctx.call(getter, &mut receiver.clone(), &[]),
+        _ => Ok(undefined()),
+    }
+}
+
+/// `OrdinarySet`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryset
+fn ordinary_set(
+    obj: &Value,
+    key: &Value,
+    value: Value,
+    receiver: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let own_desc = match (internal_methods_of(obj).get_own_property)(obj, key, ctx)? {
+        Some(desc) => desc,
+        None => {
+            let parent = ordinary_get_prototype_of(obj, ctx)?;
+            if parent.is_object() {
+                return (internal_methods_of(&parent).set)(&parent, key, value, receiver, ctx);
+            }
+            Property::new()
+                .value(undefined())
+                .writable(true)
+                .enumerable(true)
+                .configurable(true)
+        }
+    };
+
+    if own_desc.is_data_descriptor() {
+        if own_desc.writable == Some(false) || !receiver.is_object() {
+            return Ok(false);
+        }
+        return match ctx.get_own_property(receiver, key)? {
+            Some(existing) => {
+                if existing.is_accessor_descriptor() || existing.writable == Some(false) {
+                    return Ok(false);
+                }
+                ctx.define_own_property(receiver, key, Property::new().value(value))
+            }
+            None => ctx.create_data_property(receiver, key, value),
+        };
+    }
+
+    match own_desc.set {
+        Some(ref setter) if setter.is_function() => {
+            ctx.call(setter, &mut receiver.clone(), &[value])?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// `OrdinaryDelete`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarydelete
+fn ordinary_delete(obj: &Value, key: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    match (internal_methods_of(obj).get_own_property)(obj, key, ctx)? {
+        None => Ok(true),
+        Some(ref desc) if desc.configurable == Some(true) => {
+            if let ValueData::Object(ref obj) = **obj {
+                let mut obj = obj.borrow_mut();
+                match symbol_id(key) {
+                    Some(id) => {
+                        obj.sym_properties.remove(&id);
+                        obj.sym_keys.remove(&id);
+                    }
+                    None => {
+                        obj.properties.remove(&property_key_string(key));
+                    }
+                }
+            }
+            Ok(true)
+        }
+        Some(_) => Ok(false),
+    }
+}
+
+/// `OrdinaryOwnPropertyKeys`
+///
+/// Array indices come first in ascending order, followed by the other string keys
+/// and then the symbol keys, both in the order their properties were created.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+fn ordinary_own_property_keys(obj: &Value, _: &mut Interpreter) -> Result<Vec<Value>, Value> {
+    let obj = match obj.as_object() {
+        Some(obj) => obj,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut indices = Vec::new();
+    let mut strings = Vec::new();
+    for key in obj.properties.keys() {
+        let index = key.to_std_string().ok().and_then(|key| {
+            key.parse::<u32>()
+                .ok()
+                .filter(|index| *index != u32::MAX && index.to_string() == key)
+        });
+        match index {
+            Some(index) => indices.push(index),
+            None => strings.push(key.clone()),
+        }
+    }
+    indices.sort_unstable();
+
+    Ok(indices
+        .into_iter()
+        .map(|index| to_value(index.to_string()))
+        .chain(strings.into_iter().map(to_value))
+        .chain(
+            obj.sym_properties
+                .keys()
+                .filter_map(|id| obj.sym_keys.get(id).cloned()),
+        )
+        .collect())
+}
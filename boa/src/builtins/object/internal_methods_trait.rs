@@ -6,9 +6,9 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::builtins::{
-    object::{Object, INSTANCE_PROTOTYPE},
+    object::{internal_methods::property_key_string, Object, INSTANCE_PROTOTYPE},
     property::Property,
-    value::{same_value, to_value, Value, ValueData},
+    value::{same_value, to_value, JsString, Value, ValueData},
 };
 use gc::Gc;
 use std::borrow::Borrow;
@@ -87,7 +87,7 @@ pub trait ObjectInternalMethods {
             return true;
         }
         if desc.configurable.expect("unable to get value") {
-            self.remove_property(&prop_key.to_string());
+            self.remove_property(&property_key_string(prop_key));
             return true;
         }
 
@@ -107,7 +107,7 @@ pub trait ObjectInternalMethods {
         {
             // parent will either be null or an Object
             let parent = self.get_prototype_of();
-            if parent.is_null() {
+            if !parent.is_object() {
                 return Gc::new(ValueData::Undefined);
             }
 
@@ -156,7 +156,7 @@ pub trait ObjectInternalMethods {
 
             // Change value on the current descriptor
             own_desc = own_desc.value(val);
-            return self.define_own_property(field.to_string(), own_desc);
+            return self.define_own_property(property_key_string(&field), own_desc);
         }
         // [4]
         debug_assert!(own_desc.is_accessor_descriptor());
@@ -168,8 +168,8 @@ pub trait ObjectInternalMethods {
         }
     }
 
-    fn define_own_property(&mut self, property_key: String, desc: Property) -> bool {
-        let mut current = self.get_own_property(&to_value(property_key.to_string()));
+    fn define_own_property(&mut self, property_key: JsString, desc: Property) -> bool {
+        let mut current = self.get_own_property(&to_value(property_key.clone()));
         let extensible = self.is_extensible();
 
         // https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
@@ -296,7 +296,7 @@ pub trait ObjectInternalMethods {
 
     fn set_internal_slot(&mut self, name: &str, val: Value);
 
-    fn insert_property(&mut self, name: String, p: Property);
+    fn insert_property(&mut self, name: JsString, p: Property);
 
-    fn remove_property(&mut self, name: &str);
+    fn remove_property(&mut self, name: &JsString);
 }
@@ -1,19 +1,23 @@
 //! Implementations for storing normal rust structs inside any object as internal state.
 
 use std::{
-    any::Any,
+    any::{Any, TypeId},
+    cell::{BorrowMutError, Ref, RefCell, RefMut},
     fmt::{self, Debug},
-    ops::{Deref, DerefMut},
     rc::Rc,
 };
 
 use gc::{unsafe_empty_trace, Finalize, Trace};
 
-/// Wrapper around `Rc` to implement `Trace` and `Finalize`.
+/// Wrapper around `Rc<RefCell>` to implement `Trace` and `Finalize`.
+///
+/// The copies of a cell share the same state, which is borrowed like a `RefCell`.
 #[derive(Clone)]
 pub struct InternalStateCell {
     /// The internal state.
-    state: Rc<dyn Any>,
+    state: Rc<RefCell<dyn Any>>,
+    /// The type of the state, known even while it's borrowed.
+    type_id: TypeId,
 }
 
 impl Finalize for InternalStateCell {}
@@ -22,24 +26,14 @@ unsafe impl Trace for InternalStateCell {
     unsafe_empty_trace!();
 }
 
-impl Deref for InternalStateCell {
-    type Target = dyn Any;
-    fn deref(&self) -> &Self::Target {
-        Deref::deref(&self.state)
-    }
-}
-
-impl DerefMut for InternalStateCell {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        Rc::get_mut(&mut self.state).expect("failed to get mutable")
-    }
-}
-
 /// The derived version would print 'InternalStateCell { state: ... }', this custom implementation
 /// only prints the actual internal state.
 impl Debug for InternalStateCell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Debug::fmt(&self.state, f)
+        match self.state.try_borrow() {
+            Ok(state) => Debug::fmt(&*state, f),
+            Err(_) => f.write_str("<borrowed>"),
+        }
     }
 }
 
@@ -47,16 +41,54 @@ impl InternalStateCell {
     /// Create new `InternalStateCell` from a value.
     pub fn new<T: Any + InternalState>(value: T) -> Self {
         Self {
-            state: Rc::new(value),
+            state: Rc::new(RefCell::new(value)),
+            type_id: TypeId::of::<T>(),
         }
     }
+
+    /// Checks if the stored value is a `T`.
+    pub fn is<T: Any + InternalState>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
+
     /// Get a reference to the stored value and cast it to `T`.
-    pub fn downcast_ref<T: Any + InternalState>(&self) -> Option<&T> {
-        self.deref().downcast_ref::<T>()
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the value is mutably borrowed.
+    pub fn downcast_ref<T: Any + InternalState>(&self) -> Option<Ref<'_, T>> {
+        if !self.is::<T>() {
+            return None;
+        }
+        Some(Ref::map(self.state.borrow(), |state| {
+            state.downcast_ref().expect("checked the type of the state")
+        }))
     }
+
     /// Get a mutable reference to the stored value and cast it to `T`.
-    pub fn downcast_mut<T: Any + InternalState>(&mut self) -> Option<&mut T> {
-        self.deref_mut().downcast_mut::<T>()
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the value is already borrowed, see
+    /// [`try_downcast_mut`](#method.try_downcast_mut).
+    pub fn downcast_mut<T: Any + InternalState>(&self) -> Option<RefMut<'_, T>> {
+        self.try_downcast_mut()
+            .map(|state| state.expect("internal state already borrowed"))
+    }
+
+    /// Get a mutable reference to the stored value and cast it to `T`, or an error if the value
+    /// is already borrowed.
+    pub fn try_downcast_mut<T: Any + InternalState>(
+        &self,
+    ) -> Option<Result<RefMut<'_, T>, BorrowMutError>> {
+        if !self.is::<T>() {
+            return None;
+        }
+        Some(self.state.try_borrow_mut().map(|state| {
+            RefMut::map(state, |state| {
+                state.downcast_mut().expect("checked the type of the state")
+            })
+        }))
     }
 }
 
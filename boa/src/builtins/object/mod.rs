@@ -13,15 +13,29 @@
 //! [spec]: https://tc39.es/ecma262/#sec-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "intl")]
+use crate::builtins::intl::IntlData;
 use crate::{
     builtins::{
-        function::Function,
+        array::{construct_array, new_array},
+        array_buffer::ArrayBufferData,
+        class::ClassInstance,
+        data_view::DataViewData,
+        finalization_registry::FinalizationRegistry,
+        function::{BoundFunctionData, Function},
+        map::{entries_of_iterable, iterator::IterationKind, ordered_map::OrderedMap},
         property::Property,
-        value::{from_value, same_value, to_value, undefined, ResultValue, Value, ValueData},
+        proxy::ProxyData,
+        string::STRING_INTERNAL_METHODS,
+        typed_array::TypedArrayData,
+        value::{same_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
-use gc::{unsafe_empty_trace, Finalize, Gc, GcCell, Trace};
+use gc::{Finalize, Gc, Trace};
 use rustc_hash::FxHashMap;
 use std::{
     borrow::Borrow,
@@ -29,11 +43,17 @@ use std::{
     ops::Deref,
 };
 
+pub use internal_methods::{internal_methods_of, InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
 pub use internal_methods_trait::ObjectInternalMethods;
 pub use internal_state::{InternalState, InternalStateCell};
+pub use property_map::PropertyMap;
+pub use weak::{WeakCollection, WeakHandle, WeakTarget};
 
+pub mod internal_methods;
 pub mod internal_methods_trait;
 mod internal_state;
+mod property_map;
+mod weak;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
@@ -49,15 +69,22 @@ pub struct Object {
     /// Intfiernal Slots
     pub internal_slots: Box<FxHashMap<String, Value>>,
     /// Properties
-    pub properties: Box<FxHashMap<String, Property>>,
+    pub properties: Box<PropertyMap<JsString>>,
     /// Symbol Properties
-    pub sym_properties: Box<FxHashMap<i32, Property>>,
+    pub sym_properties: Box<PropertyMap<usize>>,
+    /// The symbols keying the symbol properties, by their id
+    pub sym_keys: Box<FxHashMap<usize, Value>>,
     /// Some rust object that stores internal state
     pub state: Option<Box<InternalStateCell>>,
+    /// The weak references to this object, and its `WeakMap` and `WeakSet` entries
+    pub weak: Option<Box<WeakTarget>>,
     /// [[Call]]
     pub call: Option<Function>,
     /// [[Construct]]
     pub construct: Option<Function>,
+    /// The table of internal methods, which exotic objects replace
+    #[unsafe_ignore_trace]
+    pub internal_methods: &'static InternalObjectMethods,
 }
 
 impl Debug for Object {
@@ -114,12 +141,12 @@ impl ObjectInternalMethods for Object {
     }
 
     /// Helper function for property insertion.
-    fn insert_property(&mut self, name: String, p: Property) {
+    fn insert_property(&mut self, name: JsString, p: Property) {
         self.properties.insert(name, p);
     }
 
     /// Helper function for property removal.
-    fn remove_property(&mut self, name: &str) {
+    fn remove_property(&mut self, name: &JsString) {
         self.properties.remove(name);
     }
 
@@ -170,12 +197,10 @@ impl ObjectInternalMethods for Object {
                 }
             }
             ValueData::Symbol(ref sym) => {
-                let sym_id = (**sym)
-                    .borrow()
-                    .get_internal_slot("SymbolData")
-                    .to_string()
-                    .parse::<i32>()
-                    .expect("Could not get Symbol ID");
+                let sym_id = match (**sym).borrow().kind {
+                    ObjectKind::Symbol(id) => id,
+                    _ => panic!("Could not get Symbol ID"),
+                };
                 match self.sym_properties.get(&sym_id) {
                     // If O does not have an own property with key P, return undefined.
                     // In this case we return a new empty Property
@@ -207,8 +232,8 @@ impl ObjectInternalMethods for Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-defineownproperty-p-desc
     #[allow(clippy::option_unwrap_used)]
-    fn define_own_property(&mut self, property_key: String, desc: Property) -> bool {
-        let mut current = self.get_own_property(&to_value(property_key.to_string()));
+    fn define_own_property(&mut self, property_key: JsString, desc: Property) -> bool {
+        let mut current = self.get_own_property(&to_value(property_key.clone()));
         let extensible = self.is_extensible();
 
         // https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
@@ -217,18 +242,7 @@ impl ObjectInternalMethods for Object {
             if !extensible {
                 return false;
             }
-            if desc.value.is_some() && desc.value.clone().unwrap().is_symbol() {
-                let sym_id = desc
-                    .value
-                    .clone()
-                    .unwrap()
-                    .to_string()
-                    .parse::<i32>()
-                    .expect("parsing failed");
-                self.sym_properties.insert(sym_id, desc);
-            } else {
-                self.properties.insert(property_key, desc);
-            }
+            self.properties.insert(property_key, desc);
             return true;
         }
         // If every field is absent we don't need to set anything
@@ -269,18 +283,7 @@ impl ObjectInternalMethods for Object {
                 current.set = None;
             }
 
-            if current.value.is_some() && current.value.clone().unwrap().is_symbol() {
-                let sym_id = current
-                    .value
-                    .clone()
-                    .unwrap()
-                    .to_string()
-                    .parse::<i32>()
-                    .expect("parsing failed");
-                self.sym_properties.insert(sym_id, current);
-            } else {
-                self.properties.insert(property_key.clone(), current);
-            }
+            self.properties.insert(property_key.clone(), current);
         // 7
         } else if current.is_data_descriptor() && desc.is_data_descriptor() {
             // a
@@ -339,11 +342,14 @@ impl Object {
         let mut object = Self {
             kind: ObjectKind::Ordinary,
             internal_slots: Box::new(FxHashMap::default()),
-            properties: Box::new(FxHashMap::default()),
-            sym_properties: Box::new(FxHashMap::default()),
+            properties: Box::new(PropertyMap::default()),
+            sym_properties: Box::new(PropertyMap::default()),
+            sym_keys: Box::new(FxHashMap::default()),
             state: None,
+            weak: None,
             call: None,
             construct: None,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
         };
 
         object.set_internal_slot("extensible", to_value(true));
@@ -355,11 +361,14 @@ impl Object {
         let mut object = Self {
             kind: ObjectKind::Function,
             internal_slots: Box::new(FxHashMap::default()),
-            properties: Box::new(FxHashMap::default()),
-            sym_properties: Box::new(FxHashMap::default()),
+            properties: Box::new(PropertyMap::default()),
+            sym_properties: Box::new(PropertyMap::default()),
+            sym_keys: Box::new(FxHashMap::default()),
             state: None,
+            weak: None,
             call: None,
             construct: None,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
         };
 
         object.set_internal_slot("extensible", to_value(true));
@@ -397,11 +406,14 @@ impl Object {
         let mut obj = Self {
             kind: ObjectKind::Boolean,
             internal_slots: Box::new(FxHashMap::default()),
-            properties: Box::new(FxHashMap::default()),
-            sym_properties: Box::new(FxHashMap::default()),
+            properties: Box::new(PropertyMap::default()),
+            sym_properties: Box::new(PropertyMap::default()),
+            sym_keys: Box::new(FxHashMap::default()),
             state: None,
+            weak: None,
             call: None,
             construct: None,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
         };
 
         obj.internal_slots
@@ -414,11 +426,14 @@ impl Object {
         let mut obj = Self {
             kind: ObjectKind::Number,
             internal_slots: Box::new(FxHashMap::default()),
-            properties: Box::new(FxHashMap::default()),
-            sym_properties: Box::new(FxHashMap::default()),
+            properties: Box::new(PropertyMap::default()),
+            sym_properties: Box::new(PropertyMap::default()),
+            sym_keys: Box::new(FxHashMap::default()),
             state: None,
+            weak: None,
             call: None,
             construct: None,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
         };
 
         obj.internal_slots
@@ -431,11 +446,14 @@ impl Object {
         let mut obj = Self {
             kind: ObjectKind::String,
             internal_slots: Box::new(FxHashMap::default()),
-            properties: Box::new(FxHashMap::default()),
-            sym_properties: Box::new(FxHashMap::default()),
+            properties: Box::new(PropertyMap::default()),
+            sym_properties: Box::new(PropertyMap::default()),
+            sym_keys: Box::new(FxHashMap::default()),
             state: None,
+            weak: None,
             call: None,
             construct: None,
+            internal_methods: &STRING_INTERNAL_METHODS,
         };
 
         obj.internal_slots
@@ -466,7 +484,7 @@ impl Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     pub fn is_callable(&self) -> bool {
-        self.call.is_some()
+        self.call.is_some() || self.internal_methods.call.is_some()
     }
 
     /// It determines if Object is a function object with a [[Construct]] internal method.
@@ -476,21 +494,55 @@ impl Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     pub fn is_constructor(&self) -> bool {
-        self.construct.is_some()
+        self.construct.is_some() || self.internal_methods.construct.is_some()
     }
 }
 
 /// Defines the different types of objects.
-#[derive(Finalize, Debug, Copy, Clone, Eq, PartialEq)]
+///
+/// Some kinds carry the data of the object, which is traced by the garbage collector.
+///
+/// The kind is also used by `console.log` and other routines to match an object to its JavaScript
+/// identifier (the name of its global constructor).
+#[derive(Trace, Finalize, Debug, Clone)]
 pub enum ObjectKind {
     Function,
     Array,
     String,
-    Symbol,
+    /// The unique id of the symbol, under which the properties it keys are stored.
+    Symbol(usize),
     Error,
     Ordinary,
     Boolean,
     Number,
+    Map(OrderedMap),
+    Set(OrderedMap),
+    /// The identity under which the entries of the map are stored on their keys.
+    WeakMap(WeakCollection),
+    /// The identity under which the membership is stored on the objects of the set.
+    WeakSet(WeakCollection),
+    WeakRef(WeakHandle),
+    FinalizationRegistry(FinalizationRegistry),
+    /// The time value of the date, in milliseconds since the epoch.
+    Date(f64),
+    /// The target and handler of the proxy, or `None` once it has been revoked.
+    Proxy(Option<ProxyData>),
+    BoundFunction(BoundFunctionData),
+    ArrayBuffer(ArrayBufferData),
+    TypedArray(TypedArrayData),
+    DataView(DataViewData),
+    /// An object of one of the `Intl` constructors, like `Intl.Collator`.
+    #[cfg(feature = "intl")]
+    Intl(IntlData),
+    /// An instance of a class implemented in Rust, see `Class`.
+    Class(ClassInstance),
+}
+
+/// Kinds are compared without their data, so `kind == ObjectKind::Array` checks the kind of an object.
+impl PartialEq for ObjectKind {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
 }
 
 impl Display for ObjectKind {
@@ -502,35 +554,36 @@ impl Display for ObjectKind {
                 Self::Function => "Function",
                 Self::Array => "Array",
                 Self::String => "String",
-                Self::Symbol => "Symbol",
+                Self::Symbol(_) => "Symbol",
                 Self::Error => "Error",
                 Self::Ordinary => "Ordinary",
                 Self::Boolean => "Boolean",
                 Self::Number => "Number",
+                Self::Map(_) => "Map",
+                Self::Set(_) => "Set",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
+                Self::WeakRef(_) => "WeakRef",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
+                Self::Date(_) => "Date",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
+                Self::TypedArray(ref data) => data.kind.name(),
+                Self::DataView(_) => "DataView",
+                #[cfg(feature = "intl")]
+                Self::Intl(ref data) => data.name(),
+                Self::Proxy(_) => "Proxy",
+                Self::BoundFunction(_) => "Function",
+                Self::Class(_) => "Object",
             }
         )
     }
 }
 
-/// `Trace` implementation for `ObjectKind`.
-///
-/// This is indeed safe, but we need to mark this as an empty trace because neither
-// `NativeFunctionData` nor Node hold any GC'd objects, but Gc doesn't know that. So we need to
-/// signal it manually. `rust-gc` does not have a `Trace` implementation for `fn(_, _, _)`.
-///
-/// <https://github.com/Manishearth/rust-gc/blob/master/gc/src/trace.rs>
-/// Waiting on <https://github.com/Manishearth/rust-gc/issues/87> until we can derive Copy
-unsafe impl Trace for ObjectKind {
-    unsafe_empty_trace!();
-}
-
 /// Create a new object.
 pub fn make_object(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     if let Some(arg) = args.get(0) {
         if !arg.is_null_or_undefined() {
-            return Ok(Gc::new(ValueData::Object(Box::new(GcCell::new(
-                Object::from(arg).unwrap(),
-            )))));
+            return ctx.to_object(arg);
         }
     }
     let global = &ctx.realm.global_obj;
@@ -541,28 +594,596 @@ pub fn make_object(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resu
 }
 
 /// Get the `prototype` of an object.
-pub fn get_prototype_of(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object");
-    Ok(obj.get_field_slice(INSTANCE_PROTOTYPE))
+pub fn get_prototype_of(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    ctx.get_prototype_of(&obj)
 }
 
 /// Set the `prototype` of an object.
-pub fn set_prototype_of(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object").clone();
-    let proto = args.get(1).expect("Cannot get object").clone();
-    obj.set_internal_slot(INSTANCE_PROTOTYPE, proto);
+pub fn set_prototype_of(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = args.get(0).cloned().unwrap_or_else(undefined);
+    let proto = args.get(1).cloned().unwrap_or_else(undefined);
+    if obj.is_null_or_undefined() {
+        return ctx.throw_type_error("Object.setPrototypeOf called on null or undefined");
+    }
+    if !proto.is_object() && !proto.is_null() {
+        return ctx.throw_type_error(format!(
+            "Object prototype may only be an Object or null: {}",
+            proto
+        ));
+    }
+    // Primitives don't have a prototype slot, they are returned as is
+    if obj.is_object() {
+        obj.set_internal_slot(INSTANCE_PROTOTYPE, proto);
+    }
+    Ok(obj)
+}
+
+/// Gets the argument at `index`, or `undefined`.
+fn arg(args: &[Value], index: usize) -> Value {
+    args.get(index).cloned().unwrap_or_else(undefined)
+}
+
+/// Gets the property key argument at `index`.
+fn key_arg(args: &[Value], index: usize, ctx: &mut Interpreter) -> Value {
+    ctx.to_property_key(&mut arg(args, index))
+}
+
+/// The abstract operation `DefinePropertyOrThrow`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-definepropertyorthrow
+fn define_property_or_throw(
+    obj: &Value,
+    key: &Value,
+    desc: Property,
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    if ctx.define_own_property(obj, key, desc)? {
+        Ok(())
+    } else {
+        Err(ctx.construct_type_error(format!("Cannot redefine property: {}", key)))
+    }
+}
+
+/// The abstract operation `ObjectDefineProperties`, shared by `Object.defineProperties` and `Object.create`.
+///
+/// Every descriptor is read before any property is defined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-objectdefineproperties
+fn object_define_properties(
+    obj: &Value,
+    properties: &Value,
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    let props = ctx.to_object(properties)?;
+    let mut descriptors = Vec::new();
+    for key in ctx.own_property_keys(&props)? {
+        if let Some(prop) = ctx.get_own_property(&props, &key)? {
+            if prop.enumerable == Some(true) {
+                let desc_obj = ctx.get_property(&props, &key)?;
+                descriptors.push((key, Property::from_object(&desc_obj, ctx)?));
+            }
+        }
+    }
+    for (key, desc) in descriptors {
+        define_property_or_throw(obj, &key, desc, ctx)?;
+    }
+    Ok(())
+}
+
+/// The abstract operation `EnumerableOwnPropertyNames`, listing the enumerable string keyed
+/// properties of `obj` as keys, values or `[key, value]` entries.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+pub(crate) fn enumerable_own_property_names(
+    obj: &Value,
+    kind: IterationKind,
+    ctx: &mut Interpreter,
+) -> Result<Vec<Value>, Value> {
+    let mut properties = Vec::new();
+    for key in ctx.own_property_keys(obj)? {
+        if key.is_symbol() {
+            continue;
+        }
+        match ctx.get_own_property(obj, &key)? {
+            Some(ref desc) if desc.enumerable == Some(true) => {}
+            _ => continue,
+        }
+        let property = match kind {
+            IterationKind::Key => key,
+            IterationKind::Value => ctx.get_property(obj, &key)?,
+            IterationKind::KeyAndValue => {
+                let value = ctx.get_property(obj, &key)?;
+                let entry = new_array(ctx)?;
+                construct_array(&entry, &[key, value])?;
+                entry
+            }
+        };
+        properties.push(property);
+    }
+    Ok(properties)
+}
+
+/// Creates an array of `values`.
+fn array_of(values: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let array = new_array(ctx)?;
+    construct_array(&array, values)?;
+    Ok(array)
+}
+
+/// The integrity levels of `Object.seal` and `Object.freeze`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IntegrityLevel {
+    Sealed,
+    Frozen,
+}
+
+/// The abstract operation `SetIntegrityLevel`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+fn set_integrity_level(
+    obj: &Value,
+    level: IntegrityLevel,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    if !ctx.prevent_extensions(obj)? {
+        return Ok(false);
+    }
+    for key in ctx.own_property_keys(obj)? {
+        let desc = match level {
+            IntegrityLevel::Sealed => Property::new().configurable(false),
+            IntegrityLevel::Frozen => match ctx.get_own_property(obj, &key)? {
+                Some(ref current) if current.is_accessor_descriptor() => {
+                    Property::new().configurable(false)
+                }
+                Some(_) => Property::new().configurable(false).writable(false),
+                None => continue,
+            },
+        };
+        define_property_or_throw(obj, &key, desc, ctx)?;
+    }
+    Ok(true)
+}
+
+/// The abstract operation `TestIntegrityLevel`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+fn test_integrity_level(
+    obj: &Value,
+    level: IntegrityLevel,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    if ctx.is_extensible(obj)? {
+        return Ok(false);
+    }
+    for key in ctx.own_property_keys(obj)? {
+        if let Some(desc) = ctx.get_own_property(obj, &key)? {
+            if desc.configurable == Some(true) {
+                return Ok(false);
+            }
+            if level == IntegrityLevel::Frozen
+                && desc.is_data_descriptor()
+                && desc.writable == Some(true)
+            {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// `Object.assign( target, ...sources )`
+///
+/// Copies the values of all enumerable own properties from the sources to the target object,
+/// and returns the target.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.assign
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign
+pub fn assign(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let to = ctx.to_object(&arg(args, 0))?;
+    for source in args.iter().skip(1) {
+        if source.is_null_or_undefined() {
+            continue;
+        }
+        let from = ctx.to_object(source)?;
+        for key in ctx.own_property_keys(&from)? {
+            match ctx.get_own_property(&from, &key)? {
+                Some(ref desc) if desc.enumerable == Some(true) => {}
+                _ => continue,
+            }
+            let value = ctx.get_property(&from, &key)?;
+            if !ctx.set_property(&to, &key, value)? {
+                return ctx.throw_type_error(format!(
+                    "Cannot assign to read only property '{}' of {}",
+                    key, to
+                ));
+            }
+        }
+    }
+    Ok(to)
+}
+
+/// `Object.create( proto [, propertiesObject ] )`
+///
+/// Creates a new object with the given prototype, and defines the properties described
+/// by `propertiesObject` on it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.create
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create
+pub fn object_create(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let proto = arg(args, 0);
+    if !proto.is_object() && !proto.is_null() {
+        return ctx.throw_type_error(format!(
+            "Object prototype may only be an Object or null: {}",
+            proto
+        ));
+    }
+    let obj = to_value(Object::create(proto));
+    let properties = arg(args, 1);
+    if !properties.is_undefined() {
+        object_define_properties(&obj, &properties, ctx)?;
+    }
+    Ok(obj)
+}
+
+/// `Object.defineProperty( obj, prop, descriptor )`
+///
+/// Defines a new property directly on an object, or modifies an existing property,
+/// and returns the object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.defineproperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
+pub fn define_property(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if !obj.is_object() {
+        return ctx.throw_type_error("Object.defineProperty called on non-object");
+    }
+    let key = key_arg(args, 1, ctx);
+    let desc = Property::from_object(&arg(args, 2), ctx)?;
+    define_property_or_throw(&obj, &key, desc, ctx)?;
+    Ok(obj)
+}
+
+/// `Object.defineProperties( obj, props )`
+///
+/// Defines new or modifies existing properties directly on an object, and returns the object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.defineproperties
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperties
+pub fn define_properties(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if !obj.is_object() {
+        return ctx.throw_type_error("Object.defineProperties called on non-object");
+    }
+    object_define_properties(&obj, &arg(args, 1), ctx)?;
+    Ok(obj)
+}
+
+/// `Object.entries( obj )`
+///
+/// Returns an array of the `[key, value]` pairs of the enumerable string keyed properties of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.entries
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+pub fn entries(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let entries = enumerable_own_property_names(&obj, IterationKind::KeyAndValue, ctx)?;
+    array_of(&entries, ctx)
+}
+
+/// `Object.freeze( obj )`
+///
+/// Freezes an object: no property can be added, removed or changed anymore.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+pub fn freeze(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if obj.is_object() && !set_integrity_level(&obj, IntegrityLevel::Frozen, ctx)? {
+        return ctx.throw_type_error("Cannot freeze object");
+    }
+    Ok(obj)
+}
+
+/// `Object.fromEntries( iterable )`
+///
+/// Creates an object from a list of `[key, value]` pairs, like an array or a `Map`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+pub fn from_entries(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let iterable = arg(args, 0);
+    if iterable.is_null_or_undefined() {
+        return ctx.throw_type_error(format!("{} is not iterable", iterable));
+    }
+    let obj = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    for (key, value) in entries_of_iterable(&iterable, ctx)? {
+        let key = ctx.to_property_key(&mut key.clone());
+        ctx.create_data_property(&obj, &key, value)?;
+    }
+    Ok(obj)
+}
+
+/// `Object.getOwnPropertyDescriptor( obj, prop )`
+///
+/// Returns an object describing an own property of an object, or `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertydescriptor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptor
+pub fn get_own_property_descriptor(
+    _: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let key = key_arg(args, 1, ctx);
+    match ctx.get_own_property(&obj, &key)? {
+        Some(desc) => Ok(desc.to_object(ctx)),
+        None => Ok(undefined()),
+    }
+}
+
+/// `Object.getOwnPropertyDescriptors( obj )`
+///
+/// Returns an object with the descriptors of all the own properties of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertydescriptors
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptors
+pub fn get_own_property_descriptors(
+    _: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let descriptors = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    for key in ctx.own_property_keys(&obj)? {
+        if let Some(desc) = ctx.get_own_property(&obj, &key)? {
+            let desc = desc.to_object(ctx);
+            ctx.create_data_property(&descriptors, &key, desc)?;
+        }
+    }
+    Ok(descriptors)
+}
+
+/// `Object.getOwnPropertyNames( obj )`
+///
+/// Returns an array of all the string keys of the own properties of an object,
+/// including the non-enumerable ones.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertynames
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames
+pub fn get_own_property_names(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let keys: Vec<Value> = ctx
+        .own_property_keys(&obj)?
+        .into_iter()
+        .filter(|key| !key.is_symbol())
+        .collect();
+    array_of(&keys, ctx)
+}
+
+/// `Object.getOwnPropertySymbols( obj )`
+///
+/// Returns an array of all the symbol keys of the own properties of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertysymbols
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols
+pub fn get_own_property_symbols(
+    _: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let keys: Vec<Value> = ctx
+        .own_property_keys(&obj)?
+        .into_iter()
+        .filter(|key| key.is_symbol())
+        .collect();
+    array_of(&keys, ctx)
+}
+
+/// `Object.is( value1, value2 )`
+///
+/// Determines whether two values are the same value, which unlike `===` distinguishes
+/// `+0` from `-0` and considers `NaN` equal to itself.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.is
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is
+pub fn is(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+    Ok(to_value(same_value(&arg(args, 0), &arg(args, 1), false)))
+}
+
+/// `Object.isExtensible( obj )`
+///
+/// Determines whether new properties can be added to an object, primitives never are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.isextensible
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible
+pub fn is_extensible(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if !obj.is_object() {
+        return Ok(to_value(false));
+    }
+    Ok(to_value(ctx.is_extensible(&obj)?))
+}
+
+/// `Object.isFrozen( obj )`
+///
+/// Determines whether an object is frozen, primitives always are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+pub fn is_frozen(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if !obj.is_object() {
+        return Ok(to_value(true));
+    }
+    Ok(to_value(test_integrity_level(
+        &obj,
+        IntegrityLevel::Frozen,
+        ctx,
+    )?))
+}
+
+/// `Object.isSealed( obj )`
+///
+/// Determines whether an object is sealed, primitives always are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed
+pub fn is_sealed(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if !obj.is_object() {
+        return Ok(to_value(true));
+    }
+    Ok(to_value(test_integrity_level(
+        &obj,
+        IntegrityLevel::Sealed,
+        ctx,
+    )?))
+}
+
+/// `Object.keys( obj )`
+///
+/// Returns an array of the keys of the enumerable string keyed properties of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.keys
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+pub fn keys(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let keys = enumerable_own_property_names(&obj, IterationKind::Key, ctx)?;
+    array_of(&keys, ctx)
+}
+
+/// `Object.preventExtensions( obj )`
+///
+/// Prevents new properties from ever being added to an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions
+pub fn prevent_extensions(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if obj.is_object() && !ctx.prevent_extensions(&obj)? {
+        return ctx.throw_type_error("Cannot prevent extensions");
+    }
     Ok(obj)
 }
 
-/// Define a property in an object
-pub fn define_property(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object");
-    let prop = from_value::<String>(args.get(1).expect("Cannot get object").clone())
-        .expect("Cannot get object");
-    let desc = from_value::<Property>(args.get(2).expect("Cannot get object").clone())
-        .expect("Cannot get object");
-    obj.set_prop(prop, desc);
-    Ok(undefined())
+/// `Object.seal( obj )`
+///
+/// Seals an object: no property can be added or removed, but the writable ones can still be changed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.seal
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal
+pub fn seal(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = arg(args, 0);
+    if obj.is_object() && !set_integrity_level(&obj, IntegrityLevel::Sealed, ctx)? {
+        return ctx.throw_type_error("Cannot seal object");
+    }
+    Ok(obj)
+}
+
+/// `Object.values( obj )`
+///
+/// Returns an array of the values of the enumerable string keyed properties of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.values
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+pub fn values(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&arg(args, 0))?;
+    let values = enumerable_own_property_names(&obj, IterationKind::Value, ctx)?;
+    array_of(&values, ctx)
 }
 
 /// `Object.prototype.toString()`
@@ -579,6 +1200,39 @@ pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultVa
     Ok(to_value(this.to_string()))
 }
 
+/// `Object.prototype.toLocaleString()`
+///
+/// Calls the `toString()` method of the object, for objects to override with a locale specific version.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.prototype.tolocalestring
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toLocaleString
+pub fn to_locale_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(this)?;
+    let to_string = ctx.get_property(&obj, &to_value("toString"))?;
+    if !to_string.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", to_string));
+    }
+    ctx.call(&to_string, this, &[])
+}
+
+/// `Object.prototype.valueOf()`
+///
+/// Returns the object itself, primitives are converted to their wrapper objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.prototype.valueof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf
+pub fn value_of(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.to_object(this)
+}
+
 /// `Object.prototype.hasOwnPrototype( property )`
 ///
 /// The method returns a boolean indicating whether the object has the specified property
@@ -590,15 +1244,60 @@ pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultVa
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.hasownproperty
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwnProperty
-pub fn has_own_property(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let prop = if args.is_empty() {
-        None
-    } else {
-        from_value::<String>(args.get(0).expect("Cannot get object").clone()).ok()
-    };
-    Ok(to_value(
-        prop.is_some() && this.get_prop(&prop.expect("Cannot get object")).is_some(),
-    ))
+pub fn has_own_property(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let key = ctx.to_property_key(&mut args.first().cloned().unwrap_or_else(undefined));
+    let obj = ctx.to_object(this)?;
+    Ok(to_value(ctx.get_own_property(&obj, &key)?.is_some()))
+}
+
+/// `Object.prototype.isPrototypeOf( object )`
+///
+/// Checks whether the object is in the prototype chain of another object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.prototype.isprototypeof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isPrototypeOf
+pub fn is_prototype_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut value = arg(args, 0);
+    if !value.is_object() {
+        return Ok(to_value(false));
+    }
+    let obj = ctx.to_object(this)?;
+    loop {
+        value = ctx.get_prototype_of(&value)?;
+        if !value.is_object() {
+            return Ok(to_value(false));
+        }
+        if same_value(&obj, &value, false) {
+            return Ok(to_value(true));
+        }
+    }
+}
+
+/// `Object.prototype.propertyIsEnumerable( prop )`
+///
+/// Checks whether the object has an own enumerable property with the given key.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.prototype.propertyisenumerable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/propertyIsEnumerable
+pub fn property_is_enumerable(
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let key = key_arg(args, 0, ctx);
+    let obj = ctx.to_object(this)?;
+    let enumerable = ctx
+        .get_own_property(&obj, &key)?
+        .map_or(false, |desc| desc.enumerable == Some(true));
+    Ok(to_value(enumerable))
 }
 
 /// Create a new `Object` object.
@@ -606,14 +1305,36 @@ pub fn create(global: &Value) -> Value {
     let prototype = ValueData::new_obj(None);
 
     make_builtin_fn!(has_own_property, named "hasOwnProperty", of prototype);
+    make_builtin_fn!(is_prototype_of, named "isPrototypeOf", with length 1, of prototype);
+    make_builtin_fn!(property_is_enumerable, named "propertyIsEnumerable", with length 1, of prototype);
+    make_builtin_fn!(to_locale_string, named "toLocaleString", of prototype);
     make_builtin_fn!(to_string, named "toString", of prototype);
+    make_builtin_fn!(value_of, named "valueOf", of prototype);
 
     let object = make_constructor_fn!(make_object, make_object, global, prototype);
 
     object.set_field_slice("length", to_value(1_i32));
-    make_builtin_fn!(set_prototype_of, named "setPrototypeOf", with length 2, of object);
-    make_builtin_fn!(get_prototype_of, named "getPrototypeOf", with length 1, of object);
+    make_builtin_fn!(assign, named "assign", with length 2, of object);
+    make_builtin_fn!(object_create, named "create", with length 2, of object);
+    make_builtin_fn!(define_properties, named "defineProperties", with length 2, of object);
     make_builtin_fn!(define_property, named "defineProperty", with length 3, of object);
+    make_builtin_fn!(entries, named "entries", with length 1, of object);
+    make_builtin_fn!(freeze, named "freeze", with length 1, of object);
+    make_builtin_fn!(from_entries, named "fromEntries", with length 1, of object);
+    make_builtin_fn!(get_own_property_descriptor, named "getOwnPropertyDescriptor", with length 2, of object);
+    make_builtin_fn!(get_own_property_descriptors, named "getOwnPropertyDescriptors", with length 1, of object);
+    make_builtin_fn!(get_own_property_names, named "getOwnPropertyNames", with length 1, of object);
+    make_builtin_fn!(get_own_property_symbols, named "getOwnPropertySymbols", with length 1, of object);
+    make_builtin_fn!(get_prototype_of, named "getPrototypeOf", with length 1, of object);
+    make_builtin_fn!(is, named "is", with length 2, of object);
+    make_builtin_fn!(is_extensible, named "isExtensible", with length 1, of object);
+    make_builtin_fn!(is_frozen, named "isFrozen", with length 1, of object);
+    make_builtin_fn!(is_sealed, named "isSealed", with length 1, of object);
+    make_builtin_fn!(keys, named "keys", with length 1, of object);
+    make_builtin_fn!(prevent_extensions, named "preventExtensions", with length 1, of object);
+    make_builtin_fn!(seal, named "seal", with length 1, of object);
+    make_builtin_fn!(set_prototype_of, named "setPrototypeOf", with length 2, of object);
+    make_builtin_fn!(values, named "values", with length 1, of object);
 
     object
 }
@@ -0,0 +1,55 @@
+//! The storage of the properties of an object.
+//!
+//! The properties are kept in the order they are created, which is the order in which
+//! `[[OwnPropertyKeys]]` lists the string and symbol keys of ordinary objects.
+
+use crate::builtins::property::Property;
+use gc::{custom_trace, Finalize, Trace};
+use indexmap::IndexMap;
+use rustc_hash::FxHasher;
+use std::{
+    hash::{BuildHasherDefault, Hash},
+    ops::{Deref, DerefMut},
+};
+
+/// The properties of an object, in creation order.
+#[derive(Debug, Clone)]
+pub struct PropertyMap<K: Hash + Eq>(IndexMap<K, Property, BuildHasherDefault<FxHasher>>);
+
+impl<K: Hash + Eq> PropertyMap<K> {
+    /// Removes the property of `key`, keeping the order of the others.
+    pub fn remove(&mut self, key: &K) -> Option<Property> {
+        self.0.shift_remove(key)
+    }
+}
+
+impl<K: Hash + Eq> Default for PropertyMap<K> {
+    fn default() -> Self {
+        Self(IndexMap::default())
+    }
+}
+
+impl<K: Hash + Eq> Deref for PropertyMap<K> {
+    type Target = IndexMap<K, Property, BuildHasherDefault<FxHasher>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K: Hash + Eq> DerefMut for PropertyMap<K> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<K: Hash + Eq> Finalize for PropertyMap<K> {}
+
+/// The keys are strings or symbol ids, only the properties hold garbage collected values.
+unsafe impl<K: Hash + Eq> Trace for PropertyMap<K> {
+    custom_trace!(this, {
+        for property in this.0.values() {
+            mark(property);
+        }
+    });
+}
@@ -0,0 +1,280 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn keys_values_entries() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var obj = { a: 1 };
+        Object.defineProperty(obj, 'hidden', { value: 2 });
+        var entries = Object.entries(obj);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "Object.keys(obj).join()"), "a");
+    assert_eq!(forward(&mut engine, "Object.values(obj).join()"), "1");
+    assert_eq!(forward(&mut engine, "entries.length"), "1");
+    assert_eq!(forward(&mut engine, "entries[0].join()"), "a,1");
+    assert_eq!(forward(&mut engine, "Object.keys([5, 6]).join()"), "0,1");
+    assert_eq!(forward(&mut engine, "Object.keys(1).length"), "0");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.keys(null) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn key_order() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var obj = { b: 1, 10: 2, a: 3, 2: 4 };
+        obj[Symbol.iterator] = 5;
+        obj.c = 6;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Object.keys(obj).join()"),
+        "2,10,b,a,c"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.entries(obj).map(function (e) { return e[0] + ':' + e[1] }).join()"
+        ),
+        "2:4,10:2,b:1,a:3,c:6"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.ownKeys(obj).length"), "6");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(obj)[5] === Symbol.iterator"),
+        "true"
+    );
+}
+
+#[test]
+fn string_object_keys() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var str = new String('ab');
+        str.extra = 1;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "Object.keys('ab').join()"), "0,1");
+    assert_eq!(forward(&mut engine, "Object.values('ab').join()"), "a,b");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames('ab').join()"),
+        "0,1,length"
+    );
+    assert_eq!(forward(&mut engine, "Object.keys(str).join()"), "0,1,extra");
+    assert_eq!(forward(&mut engine, "str[1] + str.length"), "b2");
+    assert_eq!(forward(&mut engine, "delete str[0]"), "false");
+}
+
+#[test]
+fn from_entries() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var obj = Object.fromEntries([['a', 1], ['b', 2]]);
+        var map = new Map([['c', 3]]);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "obj.a + obj.b"), "3");
+    assert_eq!(forward(&mut engine, "Object.fromEntries(map).c"), "3");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.fromEntries(Object.entries({ x: 4 })).x"
+        ),
+        "4"
+    );
+}
+
+#[test]
+fn assign() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = { a: 1 };
+        var result = Object.assign(target, { b: 2 }, null, { a: 3 });
+        var frozen = Object.freeze({ a: 1 });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "result === target"), "true");
+    assert_eq!(forward(&mut engine, "target.a"), "3");
+    assert_eq!(forward(&mut engine, "target.b"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.assign(frozen, { a: 2 }) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn create_and_define_properties() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var proto = { greet: 'hi' };
+        var obj = Object.create(proto, {
+            a: { value: 1, enumerable: true },
+            b: { value: 2, writable: true }
+        });
+        var bare = Object.create(null);
+        Object.defineProperties(obj, { c: { value: 3 } });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(obj) === proto"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "obj.greet"), "hi");
+    assert_eq!(forward(&mut engine, "obj.a + obj.b + obj.c"), "6");
+    assert_eq!(forward(&mut engine, "Object.keys(obj).join()"), "a");
+    assert_eq!(forward(&mut engine, "Object.getPrototypeOf(bare)"), "null");
+    assert_eq!(forward(&mut engine, "bare.toString"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "try { Object.create(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.defineProperty(obj, 'a', { value: 5 }) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn property_descriptors_and_names() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var sym = Symbol('s');
+        var obj = { a: 1 };
+        obj[sym] = 2;
+        Object.defineProperty(obj, 'hidden', { value: 3 });
+        var desc = Object.getOwnPropertyDescriptor(obj, 'a');
+        var descs = Object.getOwnPropertyDescriptors(obj);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "desc.value"), "1");
+    assert_eq!(forward(&mut engine, "desc.writable"), "true");
+    assert_eq!(forward(&mut engine, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut engine, "desc.configurable"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyDescriptor(obj, 'b')"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut engine, "descs.hidden.enumerable"), "false");
+    assert_eq!(forward(&mut engine, "descs[sym].value"), "2");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames(obj).sort().join()"),
+        "a,hidden"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj)[0] === sym"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "delete obj[sym]"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj).length"),
+        "0"
+    );
+}
+
+#[test]
+fn freeze_seal_prevent_extensions() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var frozen = Object.freeze({ a: 1 });
+        frozen.a = 2;
+        frozen.b = 3;
+        var sealed = Object.seal({ a: 1 });
+        sealed.a = 2;
+        sealed.b = 3;
+        delete sealed.a;
+        var closed = Object.preventExtensions({ a: 1 });
+        closed.b = 2;
+        delete closed.a;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "frozen.a"), "1");
+    assert_eq!(forward(&mut engine, "frozen.b"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(frozen)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isSealed(frozen)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isExtensible(frozen)"), "false");
+
+    assert_eq!(forward(&mut engine, "sealed.a"), "2");
+    assert_eq!(forward(&mut engine, "sealed.b"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isSealed(sealed)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(sealed)"), "false");
+
+    assert_eq!(forward(&mut engine, "closed.a"), "undefined");
+    assert_eq!(forward(&mut engine, "closed.b"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isExtensible(closed)"), "false");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(closed)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isExtensible({})"), "true");
+    assert_eq!(forward(&mut engine, "Object.isFrozen({})"), "false");
+
+    assert_eq!(forward(&mut engine, "Object.freeze(1)"), "1");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(1)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isExtensible(1)"), "false");
+}
+
+#[test]
+fn is() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Object.is(0, -0)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Object.is(Math.sqrt(-1), Math.sqrt(-1))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Object.is('a', 'a')"), "true");
+    assert_eq!(forward(&mut engine, "Object.is({}, {})"), "false");
+}
+
+#[test]
+fn prototype_methods() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var proto = { a: 1 };
+        var obj = Object.create(proto);
+        obj.b = 2;
+        Object.defineProperty(obj, 'hidden', { value: 3 });
+        var custom = { toString: function() { return 'custom'; } };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "proto.isPrototypeOf(obj)"), "true");
+    assert_eq!(forward(&mut engine, "obj.isPrototypeOf(proto)"), "false");
+    assert_eq!(forward(&mut engine, "proto.isPrototypeOf(1)"), "false");
+    assert_eq!(
+        forward(&mut engine, "obj.propertyIsEnumerable('b')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "obj.propertyIsEnumerable('hidden')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "obj.propertyIsEnumerable('a')"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "obj.valueOf() === obj"), "true");
+    assert_eq!(forward(&mut engine, "custom.toLocaleString()"), "custom");
+}
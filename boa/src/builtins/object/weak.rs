@@ -0,0 +1,210 @@
+//! Weak references to objects, on top of the garbage collector.
+//!
+//! The `gc` crate has no weak pointers, but it finalizes every unreachable object before freeing
+//! it. Objects that are the target of weak references carry a [`WeakTarget`], whose finalizer
+//! marks them as dead, so the [`WeakHandle`]s pointing to them stop handing them out.
+//!
+//! `WeakMap` and `WeakSet` entries are stored on their key instead of in the collection. That way
+//! a value is only traced while its key is reachable, which is how ephemerons behave. The entries
+//! of a collected [`WeakCollection`] are no longer traced, and are removed from their key the next
+//! time it's used by a weak collection.
+
+use crate::builtins::value::{Value, ValueData};
+use gc::{custom_trace, unsafe_empty_trace, Finalize, Gc, Trace};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::Cell,
+    fmt::{self, Debug, Formatter},
+    mem::ManuallyDrop,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The id of the next weak collection.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The identity of a `WeakMap` or `WeakSet` object, under which its entries are stored on its keys.
+#[derive(Debug, Clone)]
+pub struct WeakCollection {
+    /// The id of the collection.
+    id: usize,
+
+    /// Shared with the entries of the collection, set to `false` when it's collected.
+    alive: Rc<Cell<bool>>,
+}
+
+impl WeakCollection {
+    /// Creates the identity of a new, empty weak collection.
+    pub(crate) fn new() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            alive: Rc::new(Cell::new(true)),
+        }
+    }
+}
+
+/// Only called by the collector, right before the collection is freed.
+impl Finalize for WeakCollection {
+    fn finalize(&self) {
+        self.alive.set(false);
+    }
+}
+
+/// The entries are traced by their key.
+unsafe impl Trace for WeakCollection {
+    unsafe_empty_trace!();
+}
+
+/// A value associated to an object by a weak collection.
+#[derive(Debug)]
+struct WeakEntry {
+    /// Whether the collection is still alive.
+    collection: Rc<Cell<bool>>,
+
+    /// The value, only traced while the collection is alive.
+    value: Value,
+}
+
+/// The weak bookkeeping of an object.
+#[derive(Debug, Default)]
+pub struct WeakTarget {
+    /// Shared with the weak handles of the object, set to `false` when it's collected.
+    alive: Option<Rc<Cell<bool>>>,
+
+    /// The values associated to the object by `WeakMap` and `WeakSet` objects, by their id.
+    entries: FxHashMap<usize, WeakEntry>,
+}
+
+impl WeakTarget {
+    /// Gets the value associated to the object by `collection`.
+    pub(crate) fn get(&self, collection: &WeakCollection) -> Option<Value> {
+        self.entries
+            .get(&collection.id)
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Checks if `collection` has an entry for the object.
+    pub(crate) fn contains(&self, collection: &WeakCollection) -> bool {
+        self.entries.contains_key(&collection.id)
+    }
+
+    /// Associates `value` to the object in `collection`.
+    pub(crate) fn insert(&mut self, collection: &WeakCollection, value: Value) {
+        self.purge();
+        self.entries.insert(
+            collection.id,
+            WeakEntry {
+                collection: collection.alive.clone(),
+                value,
+            },
+        );
+    }
+
+    /// Removes the entry of `collection`, returning `true` if there was one.
+    pub(crate) fn remove(&mut self, collection: &WeakCollection) -> bool {
+        self.purge();
+        self.entries.remove(&collection.id).is_some()
+    }
+
+    /// Removes the entries of the collections which have been collected.
+    ///
+    /// Their values are not traced anymore, and may already be freed, so they are only dropped.
+    fn purge(&mut self) {
+        self.entries.retain(|_, entry| entry.collection.get());
+    }
+}
+
+/// A copy is a different object, so it starts without weak references or entries.
+impl Clone for WeakTarget {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// Only called by the collector, right before the object is freed.
+impl Finalize for WeakTarget {
+    fn finalize(&self) {
+        if let Some(ref alive) = self.alive {
+            alive.set(false);
+        }
+    }
+}
+
+unsafe impl Trace for WeakTarget {
+    custom_trace!(this, {
+        for entry in this.entries.values() {
+            if entry.collection.get() {
+                mark(&entry.value);
+            }
+        }
+    });
+}
+
+/// A reference to an object which doesn't keep it alive.
+#[derive(Clone)]
+pub struct WeakHandle {
+    /// The value of the object, only dereferenced while it's alive.
+    object: *const ValueData,
+
+    /// Whether the object is still alive.
+    alive: Rc<Cell<bool>>,
+}
+
+impl WeakHandle {
+    /// Creates a weak handle to `value`, or `None` if it's not an object.
+    pub fn new(value: &Value) -> Option<Self> {
+        match **value {
+            ValueData::Object(ref obj) => {
+                let mut obj = obj.borrow_mut();
+                let target = obj.weak.get_or_insert_with(Default::default);
+                let alive = target
+                    .alive
+                    .get_or_insert_with(|| Rc::new(Cell::new(true)))
+                    .clone();
+
+                Some(Self {
+                    object: &**value as *const ValueData,
+                    alive,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if the object has not been collected yet.
+    pub fn is_alive(&self) -> bool {
+        self.alive.get()
+    }
+
+    /// Checks if the handle points to `value`.
+    pub fn points_to(&self, value: &Value) -> bool {
+        self.is_alive() && self.object == &**value as *const ValueData
+    }
+
+    /// Gets the object, or `None` if it has been collected.
+    pub fn upgrade(&self) -> Option<Value> {
+        if self.is_alive() {
+            // The object is alive, so its allocation is still there. The pointer doesn't own a
+            // root, so it must not be dropped, only cloned into a rooted `Gc`.
+            let object = ManuallyDrop::new(unsafe { Gc::from_raw(self.object) });
+            Some(Gc::clone(&object))
+        } else {
+            None
+        }
+    }
+}
+
+impl Debug for WeakHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakHandle")
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+impl Finalize for WeakHandle {}
+
+/// The object is not traced, that's the point of the handle.
+unsafe impl Trace for WeakHandle {
+    unsafe_empty_trace!();
+}
@@ -14,7 +14,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
 //! [section]: https://tc39.es/ecma262/#sec-property-attributes
 
-use crate::builtins::value::{from_value, to_value, FromValue, ToValue, Value, ValueData};
+use crate::{
+    builtins::value::{
+        from_value, to_value, undefined, ConversionError, FromValue, ToValue, Value, ValueData,
+    },
+    exec::Interpreter,
+};
 use gc::{Finalize, Trace};
 
 /// This represents a Javascript Property AKA The Property Descriptor.
@@ -149,6 +154,94 @@ impl Property {
     pub fn is_generic_descriptor(&self) -> bool {
         !self.is_accessor_descriptor() && !self.is_data_descriptor()
     }
+
+    /// Fills the missing fields of a descriptor with their default values.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-completepropertydescriptor
+    pub fn complete(mut self) -> Self {
+        if self.is_accessor_descriptor() {
+            self.get = self.get.take().or_else(|| Some(undefined()));
+            self.set = self.set.take().or_else(|| Some(undefined()));
+        } else {
+            self.value = self.value.take().or_else(|| Some(undefined()));
+            self.writable = self.writable.or(Some(false));
+        }
+        self.enumerable = self.enumerable.or(Some(false));
+        self.configurable = self.configurable.or(Some(false));
+        self
+    }
+
+    /// Converts an object to a property descriptor, reading its fields through `[[Get]]`.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-topropertydescriptor
+    pub fn from_object(obj: &Value, ctx: &mut Interpreter) -> Result<Self, Value> {
+        if !obj.is_object() {
+            return Err(ctx
+                .construct_type_error(format!("Property description must be an object: {}", obj)));
+        }
+
+        let mut field = |name: &str| -> Result<Option<Value>, Value> {
+            let key = to_value(name);
+            if ctx.has_property(obj, &key)? {
+                Ok(Some(ctx.get_property(obj, &key)?))
+            } else {
+                Ok(None)
+            }
+        };
+        let desc = Self {
+            enumerable: field("enumerable")?.map(|value| value.is_true()),
+            configurable: field("configurable")?.map(|value| value.is_true()),
+            value: field("value")?,
+            writable: field("writable")?.map(|value| value.is_true()),
+            get: field("get")?,
+            set: field("set")?,
+        };
+
+        for accessor in [&desc.get, &desc.set].iter().copied().flatten() {
+            if !accessor.is_undefined() && !accessor.is_function() {
+                return Err(ctx.construct_type_error(format!(
+                    "Getter and setter must be functions: {}",
+                    accessor
+                )));
+            }
+        }
+        if desc.is_accessor_descriptor() && desc.is_data_descriptor() {
+            return Err(ctx.construct_type_error(
+                "Invalid property descriptor. Cannot both specify accessors and a value or writable attribute",
+            ));
+        }
+        Ok(desc)
+    }
+
+    /// Converts a property descriptor to an object, with only the fields it has.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
+    pub fn to_object(&self, ctx: &mut Interpreter) -> Value {
+        let obj = ValueData::new_obj(Some(&ctx.realm.global_obj));
+        let fields = [
+            ("value", self.value.clone()),
+            ("writable", self.writable.map(to_value)),
+            ("get", self.get.clone()),
+            ("set", self.set.clone()),
+            ("enumerable", self.enumerable.map(to_value)),
+            ("configurable", self.configurable.map(to_value)),
+        ];
+        for (name, value) in fields.iter() {
+            if let Some(value) = value {
+                obj.set_field_slice(name, value.clone());
+            }
+        }
+        obj
+    }
 }
 
 impl Default for Property {
@@ -186,7 +279,7 @@ impl ToValue for Property {
 impl FromValue for Property {
     /// Attempt to fetch values "configurable", "enumerable", "writable" from the value,
     /// if they're not there default to false
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(Self {
             configurable: {
                 match from_value::<bool>(v.get_field_slice("configurable")) {
@@ -219,7 +312,7 @@ mod test {
 
     #[test]
     fn is_property_key_test() {
-        let v = Value::new(ValueData::String(String::from("Boop")));
+        let v = Value::new(ValueData::String("Boop".into()));
         assert!(Property::is_property_key(&v));
 
         let v = Value::new(ValueData::Boolean(true));
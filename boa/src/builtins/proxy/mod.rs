@@ -0,0 +1,803 @@
+//! This module implements the global `Proxy` object.
+//!
+//! A `Proxy` wraps a target object, and lets a handler object intercept the
+//! fundamental operations on it, like property lookup, assignment or function calls.
+//! The handler's traps are checked against the target, so a proxy can't report
+//! properties that contradict the invariants of the target.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array,
+        function::{Function, FunctionBody},
+        object::{
+            internal_methods::is_compatible_property_descriptor, internal_methods_of,
+            InternalObjectMethods, Object, ObjectInternalMethods, ObjectKind,
+            ORDINARY_INTERNAL_METHODS, PROTOTYPE,
+        },
+        property::Property,
+        value::{same_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{Finalize, Trace};
+
+/// The `[[ProxyTarget]]` and `[[ProxyHandler]]` internal slots of a proxy.
+#[derive(Trace, Finalize, Debug, Clone)]
+pub struct ProxyData {
+    pub target: Value,
+    pub handler: Value,
+}
+
+/// The internal methods of proxies whose target isn't callable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots
+static PROXY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: proxy_get_prototype_of,
+    set_prototype_of: proxy_set_prototype_of,
+    is_extensible: proxy_is_extensible,
+    prevent_extensions: proxy_prevent_extensions,
+    get_own_property: proxy_get_own_property,
+    define_own_property: proxy_define_own_property,
+    has_property: proxy_has_property,
+    get: proxy_get,
+    set: proxy_set,
+    delete: proxy_delete,
+    own_property_keys: proxy_own_property_keys,
+    call: None,
+    construct: None,
+};
+
+/// The internal methods of proxies whose target is a function, but not a constructor.
+static CALLABLE_PROXY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    call: Some(proxy_call),
+    ..PROXY_INTERNAL_METHODS
+};
+
+/// The internal methods of proxies whose target is a constructor.
+static CONSTRUCTOR_PROXY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    call: Some(proxy_call),
+    construct: Some(proxy_construct),
+    ..PROXY_INTERNAL_METHODS
+};
+
+/// The internal methods of the revoke functions returned by `Proxy.revocable`.
+static REVOKER_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    call: Some(revoke),
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+/// The internal slot of a revoke function holding its proxy.
+const REVOCABLE_PROXY: &str = "RevocableProxy";
+
+/// `ProxyCreate`, creates a new proxy of `target` trapped by `handler`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+pub fn proxy_create(target: &Value, handler: &Value, ctx: &mut Interpreter) -> ResultValue {
+    if !target.is_object() || !handler.is_object() {
+        return ctx.throw_type_error("Cannot create proxy with a non-object as target or handler");
+    }
+
+    let mut proxy = Object::default();
+    proxy.internal_methods = match target.as_object() {
+        Some(ref target) if target.is_constructor() => &CONSTRUCTOR_PROXY_INTERNAL_METHODS,
+        Some(ref target) if target.is_callable() => &CALLABLE_PROXY_INTERNAL_METHODS,
+        _ => &PROXY_INTERNAL_METHODS,
+    };
+    proxy.kind = ObjectKind::Proxy(Some(ProxyData {
+        target: target.clone(),
+        handler: handler.clone(),
+    }));
+    Ok(to_value(proxy))
+}
+
+/// Gets the target and the handler of a proxy, or throws a `TypeError` if it has been revoked.
+fn proxy_data(
+    proxy: &Value,
+    operation: &str,
+    ctx: &mut Interpreter,
+) -> Result<(Value, Value), Value> {
+    if let ValueData::Object(ref obj) = **proxy {
+        if let ObjectKind::Proxy(Some(ref data)) = obj.borrow().kind {
+            return Ok((data.target.clone(), data.handler.clone()));
+        }
+    }
+    Err(ctx.construct_type_error(format!(
+        "Cannot perform '{}' on a proxy that has been revoked",
+        operation
+    )))
+}
+
+/// Gets the trap `name` of a handler, or `None` if it doesn't define one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getmethod
+fn get_trap(handler: &Value, name: &str, ctx: &mut Interpreter) -> Result<Option<Value>, Value> {
+    let trap = ctx.get_property(handler, &to_value(name))?;
+    if trap.is_null_or_undefined() {
+        Ok(None)
+    } else if trap.is_function() {
+        Ok(Some(trap))
+    } else {
+        Err(ctx.construct_type_error(format!("'{}' on proxy: trap is not a function", name)))
+    }
+}
+
+/// Calls a trap with the handler as `this`.
+fn call_trap(trap: &Value, handler: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.call(trap, &mut handler.clone(), args)
+}
+
+/// Builds the `TypeError` of a trap breaking an invariant of its target.
+fn invariant_error(trap: &str, message: &str, ctx: &mut Interpreter) -> Value {
+    ctx.construct_type_error(format!("'{}' on proxy: {}", trap, message))
+}
+
+/// `[[GetPrototypeOf]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+fn proxy_get_prototype_of(proxy: &Value, ctx: &mut Interpreter) -> ResultValue {
+    let (target, handler) = proxy_data(proxy, "getPrototypeOf", ctx)?;
+    let trap = match get_trap(&handler, "getPrototypeOf", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.get_prototype_of(&target),
+    };
+
+    let proto = call_trap(&trap, &handler, &[target.clone()], ctx)?;
+    if !proto.is_object() && !proto.is_null() {
+        return Err(invariant_error(
+            "getPrototypeOf",
+            "trap returned neither object nor null",
+            ctx,
+        ));
+    }
+    if ctx.is_extensible(&target)? {
+        return Ok(proto);
+    }
+    let target_proto = ctx.get_prototype_of(&target)?;
+    if !same_value(&proto, &target_proto, false) {
+        return Err(invariant_error(
+            "getPrototypeOf",
+            "proxy target is non-extensible but the trap did not return its actual prototype",
+            ctx,
+        ));
+    }
+    Ok(proto)
+}
+
+/// `[[SetPrototypeOf]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+fn proxy_set_prototype_of(
+    proxy: &Value,
+    proto: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "setPrototypeOf", ctx)?;
+    let trap = match get_trap(&handler, "setPrototypeOf", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.set_prototype_of(&target, proto),
+    };
+
+    if !call_trap(&trap, &handler, &[target.clone(), proto.clone()], ctx)?.is_true() {
+        return Ok(false);
+    }
+    if ctx.is_extensible(&target)? {
+        return Ok(true);
+    }
+    let target_proto = ctx.get_prototype_of(&target)?;
+    if !same_value(proto, &target_proto, false) {
+        return Err(invariant_error(
+            "setPrototypeOf",
+            "trap returned truish for setting a new prototype on the non-extensible proxy target",
+            ctx,
+        ));
+    }
+    Ok(true)
+}
+
+/// `[[IsExtensible]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+fn proxy_is_extensible(proxy: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "isExtensible", ctx)?;
+    let trap = match get_trap(&handler, "isExtensible", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.is_extensible(&target),
+    };
+
+    let result = call_trap(&trap, &handler, &[target.clone()], ctx)?.is_true();
+    if result != ctx.is_extensible(&target)? {
+        return Err(invariant_error(
+            "isExtensible",
+            "trap result does not reflect extensibility of proxy target",
+            ctx,
+        ));
+    }
+    Ok(result)
+}
+
+/// `[[PreventExtensions]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+fn proxy_prevent_extensions(proxy: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "preventExtensions", ctx)?;
+    let trap = match get_trap(&handler, "preventExtensions", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.prevent_extensions(&target),
+    };
+
+    let result = call_trap(&trap, &handler, &[target.clone()], ctx)?.is_true();
+    if result && ctx.is_extensible(&target)? {
+        return Err(invariant_error(
+            "preventExtensions",
+            "trap returned truish but the proxy target is extensible",
+            ctx,
+        ));
+    }
+    Ok(result)
+}
+
+/// `[[GetOwnProperty]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+fn proxy_get_own_property(
+    proxy: &Value,
+    key: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Option<Property>, Value> {
+    const TRAP: &str = "getOwnPropertyDescriptor";
+    let (target, handler) = proxy_data(proxy, TRAP, ctx)?;
+    let trap = match get_trap(&handler, TRAP, ctx)? {
+        Some(trap) => trap,
+        None => return ctx.get_own_property(&target, key),
+    };
+
+    let result = call_trap(&trap, &handler, &[target.clone(), key.clone()], ctx)?;
+    if !result.is_object() && !result.is_undefined() {
+        return Err(invariant_error(
+            TRAP,
+            "trap returned neither object nor undefined",
+            ctx,
+        ));
+    }
+    let target_desc = ctx.get_own_property(&target, key)?;
+
+    if result.is_undefined() {
+        return match target_desc {
+            None => Ok(None),
+            Some(ref desc) if desc.configurable == Some(false) => Err(invariant_error(
+                TRAP,
+                &format!(
+                    "trap returned undefined for property '{}' which is non-configurable in the proxy target",
+                    key
+                ),
+                ctx,
+            )),
+            Some(_) if !ctx.is_extensible(&target)? => Err(invariant_error(
+                TRAP,
+                &format!(
+                    "trap returned undefined for property '{}' which exists in the non-extensible proxy target",
+                    key
+                ),
+                ctx,
+            )),
+            Some(_) => Ok(None),
+        };
+    }
+
+    let extensible = ctx.is_extensible(&target)?;
+    let result_desc = Property::from_object(&result, ctx)?.complete();
+    if !is_compatible_property_descriptor(extensible, &result_desc, target_desc.as_ref()) {
+        return Err(invariant_error(
+            TRAP,
+            &format!(
+                "trap returned descriptor for property '{}' that is incompatible with the existing property in the proxy target",
+                key
+            ),
+            ctx,
+        ));
+    }
+    if result_desc.configurable == Some(false) {
+        let target_configurable = target_desc
+            .as_ref()
+            .map_or(true, |desc| desc.configurable == Some(true));
+        let target_writable = target_desc
+            .as_ref()
+            .map_or(false, |desc| desc.writable == Some(true));
+        if target_configurable || (result_desc.writable == Some(false) && target_writable) {
+            return Err(invariant_error(
+                TRAP,
+                &format!(
+                    "trap reported non-configurability for property '{}' which is either non-existent or configurable in the proxy target",
+                    key
+                ),
+                ctx,
+            ));
+        }
+    }
+    Ok(Some(result_desc))
+}
+
+/// `[[DefineOwnProperty]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+fn proxy_define_own_property(
+    proxy: &Value,
+    key: &Value,
+    desc: Property,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "defineProperty", ctx)?;
+    let trap = match get_trap(&handler, "defineProperty", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.define_own_property(&target, key, desc),
+    };
+
+    let desc_obj = desc.to_object(ctx);
+    let args = [target.clone(), key.clone(), desc_obj];
+    if !call_trap(&trap, &handler, &args, ctx)?.is_true() {
+        return Ok(false);
+    }
+
+    let target_desc = ctx.get_own_property(&target, key)?;
+    let extensible = ctx.is_extensible(&target)?;
+    let setting_config_false = desc.configurable == Some(false);
+    let valid = match target_desc {
+        None => extensible && !setting_config_false,
+        Some(ref target_desc) => {
+            is_compatible_property_descriptor(extensible, &desc, Some(target_desc))
+                && !(setting_config_false && target_desc.configurable == Some(true))
+                && !(target_desc.is_data_descriptor()
+                    && target_desc.configurable == Some(false)
+                    && target_desc.writable == Some(true)
+                    && desc.writable == Some(false))
+        }
+    };
+    if !valid {
+        return Err(invariant_error(
+            "defineProperty",
+            &format!(
+                "trap returned truish for defining property '{}' which is incompatible with the proxy target",
+                key
+            ),
+            ctx,
+        ));
+    }
+    Ok(true)
+}
+
+/// `[[HasProperty]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+fn proxy_has_property(proxy: &Value, key: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "has", ctx)?;
+    let trap = match get_trap(&handler, "has", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.has_property(&target, key),
+    };
+
+    let result = call_trap(&trap, &handler, &[target.clone(), key.clone()], ctx)?.is_true();
+    if !result {
+        if let Some(target_desc) = ctx.get_own_property(&target, key)? {
+            if target_desc.configurable == Some(false) || !ctx.is_extensible(&target)? {
+                return Err(invariant_error(
+                    "has",
+                    &format!(
+                        "trap returned falsish for property '{}' which exists in the proxy target as non-configurable or non-extensible",
+                        key
+                    ),
+                    ctx,
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// `[[Get]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+fn proxy_get(proxy: &Value, key: &Value, receiver: &Value, ctx: &mut Interpreter) -> ResultValue {
+    let (target, handler) = proxy_data(proxy, "get", ctx)?;
+    let trap = match get_trap(&handler, "get", ctx)? {
+        Some(trap) => trap,
+        None => return (internal_methods_of(&target).get)(&target, key, receiver, ctx),
+    };
+
+    let args = [target.clone(), key.clone(), receiver.clone()];
+    let result = call_trap(&trap, &handler, &args, ctx)?;
+    if let Some(target_desc) = ctx.get_own_property(&target, key)? {
+        if target_desc.configurable == Some(false) {
+            let valid = if target_desc.is_data_descriptor() {
+                target_desc.writable != Some(false)
+                    || same_value(
+                        &result,
+                        &target_desc.value.clone().unwrap_or_else(undefined),
+                        false,
+                    )
+            } else {
+                target_desc
+                    .get
+                    .as_ref()
+                    .map_or(true, |get| !get.is_undefined())
+                    || result.is_undefined()
+            };
+            if !valid {
+                return Err(invariant_error(
+                    "get",
+                    &format!(
+                        "property '{}' is a read-only and non-configurable property on the proxy target but the proxy did not return its actual value",
+                        key
+                    ),
+                    ctx,
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// `[[Set]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+fn proxy_set(
+    proxy: &Value,
+    key: &Value,
+    value: Value,
+    receiver: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "set", ctx)?;
+    let trap = match get_trap(&handler, "set", ctx)? {
+        Some(trap) => trap,
+        None => return (internal_methods_of(&target).set)(&target, key, value, receiver, ctx),
+    };
+
+    let args = [target.clone(), key.clone(), value.clone(), receiver.clone()];
+    if !call_trap(&trap, &handler, &args, ctx)?.is_true() {
+        return Ok(false);
+    }
+    if let Some(target_desc) = ctx.get_own_property(&target, key)? {
+        if target_desc.configurable == Some(false) {
+            let valid = if target_desc.is_data_descriptor() {
+                target_desc.writable != Some(false)
+                    || same_value(
+                        &value,
+                        &target_desc.value.clone().unwrap_or_else(undefined),
+                        false,
+                    )
+            } else {
+                target_desc
+                    .set
+                    .as_ref()
+                    .map_or(true, |set| !set.is_undefined())
+            };
+            if !valid {
+                return Err(invariant_error(
+                    "set",
+                    &format!(
+                        "trap returned truish for property '{}' which exists in the proxy target as a non-configurable and non-writable property",
+                        key
+                    ),
+                    ctx,
+                ));
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// `[[Delete]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+fn proxy_delete(proxy: &Value, key: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    let (target, handler) = proxy_data(proxy, "deleteProperty", ctx)?;
+    let trap = match get_trap(&handler, "deleteProperty", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.delete_property(&target, key),
+    };
+
+    if !call_trap(&trap, &handler, &[target.clone(), key.clone()], ctx)?.is_true() {
+        return Ok(false);
+    }
+    if let Some(target_desc) = ctx.get_own_property(&target, key)? {
+        if target_desc.configurable == Some(false) || !ctx.is_extensible(&target)? {
+            return Err(invariant_error(
+                "deleteProperty",
+                &format!(
+                    "trap returned truish for property '{}' which is non-configurable in the proxy target or the target is non-extensible",
+                    key
+                ),
+                ctx,
+            ));
+        }
+    }
+    Ok(true)
+}
+
+/// `[[OwnPropertyKeys]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+fn proxy_own_property_keys(proxy: &Value, ctx: &mut Interpreter) -> Result<Vec<Value>, Value> {
+    let (target, handler) = proxy_data(proxy, "ownKeys", ctx)?;
+    let trap = match get_trap(&handler, "ownKeys", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.own_property_keys(&target),
+    };
+
+    let result = call_trap(&trap, &handler, &[target.clone()], ctx)?;
+    let keys = ctx.create_list_from_array_like(&result)?;
+    for (index, key) in keys.iter().enumerate() {
+        if !Property::is_property_key(key) {
+            return Err(invariant_error(
+                "ownKeys",
+                &format!("{} is not a valid property name", key),
+                ctx,
+            ));
+        }
+        if keys[..index]
+            .iter()
+            .any(|other| same_value(key, other, false))
+        {
+            return Err(invariant_error(
+                "ownKeys",
+                &format!("trap returned duplicate entries: '{}'", key),
+                ctx,
+            ));
+        }
+    }
+
+    let extensible = ctx.is_extensible(&target)?;
+    let mut configurable_keys = Vec::new();
+    let mut non_configurable_keys = Vec::new();
+    for key in ctx.own_property_keys(&target)? {
+        match ctx.get_own_property(&target, &key)? {
+            Some(ref desc) if desc.configurable == Some(false) => non_configurable_keys.push(key),
+            _ => configurable_keys.push(key),
+        }
+    }
+    if extensible && non_configurable_keys.is_empty() {
+        return Ok(keys);
+    }
+
+    // Every non-configurable key of the target must be reported, and so must every key
+    // of a non-extensible target, which can't report anything else.
+    let mut unchecked = keys.clone();
+    let required = if extensible {
+        non_configurable_keys
+    } else {
+        non_configurable_keys
+            .into_iter()
+            .chain(configurable_keys)
+            .collect()
+    };
+    for key in required {
+        match unchecked
+            .iter()
+            .position(|other| same_value(&key, other, false))
+        {
+            Some(position) => {
+                unchecked.remove(position);
+            }
+            None => {
+                return Err(invariant_error(
+                    "ownKeys",
+                    &format!("trap result did not include '{}'", key),
+                    ctx,
+                ))
+            }
+        }
+    }
+    if !extensible && !unchecked.is_empty() {
+        return Err(invariant_error(
+            "ownKeys",
+            "trap returned extra keys but proxy target is non-extensible",
+            ctx,
+        ));
+    }
+    Ok(keys)
+}
+
+/// `[[Call]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+fn proxy_call(
+    proxy: &Value,
+    this: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let (target, handler) = proxy_data(proxy, "apply", ctx)?;
+    let trap = match get_trap(&handler, "apply", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.call(&target, this, args),
+    };
+
+    let arg_array = array::new_array(ctx)?;
+    array::add_to_array_object(&arg_array, args)?;
+    call_trap(&trap, &handler, &[target, this.clone(), arg_array], ctx)
+}
+
+/// `[[Construct]]` of proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+fn proxy_construct(
+    proxy: &Value,
+    args: &[Value],
+    new_target: &Value,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let (target, handler) = proxy_data(proxy, "construct", ctx)?;
+    let trap = match get_trap(&handler, "construct", ctx)? {
+        Some(trap) => trap,
+        None => return ctx.construct(&target, args, new_target),
+    };
+
+    let arg_array = array::new_array(ctx)?;
+    array::add_to_array_object(&arg_array, args)?;
+    let result = call_trap(
+        &trap,
+        &handler,
+        &[target, arg_array, new_target.clone()],
+        ctx,
+    )?;
+    if !result.is_object() {
+        return Err(invariant_error(
+            "construct",
+            &format!("trap returned non-object ('{}')", result),
+            ctx,
+        ));
+    }
+    Ok(result)
+}
+
+/// Create a new `Proxy`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+pub fn make_proxy(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    let handler = args.get(1).cloned().unwrap_or_else(undefined);
+    proxy_create(&target, &handler, ctx)
+}
+
+/// `Proxy` can only be called as a constructor.
+pub fn call_proxy(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor Proxy requires 'new'")
+}
+
+/// `Proxy.revocable( target, handler )`
+///
+/// The `Proxy.revocable()` method creates a proxy along with a function which revokes it.
+/// Any operation on a revoked proxy throws a `TypeError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+pub fn revocable(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    let handler = args.get(1).cloned().unwrap_or_else(undefined);
+    let proxy = proxy_create(&target, &handler, ctx)?;
+
+    let mut revoker = Object::function();
+    revoker.internal_methods = &REVOKER_INTERNAL_METHODS;
+    revoker.set_internal_slot(REVOCABLE_PROXY, proxy.clone());
+    let revoker = to_value(revoker);
+    revoker.set_field_slice("length", to_value(0));
+
+    let result = ValueData::new_obj(Some(&ctx.realm.global_obj));
+    result.set_field_slice("proxy", proxy);
+    result.set_field_slice("revoke", revoker);
+    Ok(result)
+}
+
+/// The revoke functions of `Proxy.revocable`, which clear the slots of their proxy.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-revocation-functions
+fn revoke(revoker: &Value, _: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+    let proxy = revoker.get_internal_slot(REVOCABLE_PROXY);
+    if proxy.is_object() {
+        revoker.set_internal_slot(REVOCABLE_PROXY, Value::new(ValueData::Null));
+        proxy.set_kind(ObjectKind::Proxy(None));
+    }
+    Ok(undefined())
+}
+
+/// Create a new `Proxy` object.
+///
+/// `Proxy` doesn't have a `prototype`, as proxies take the prototype of their target.
+pub fn create(global: &Value) -> Value {
+    let func_prototype = global
+        .get_field_slice("Function")
+        .get_field_slice(PROTOTYPE);
+
+    let mut proxy = Object::function();
+    proxy.set_construct(Function::create_builtin(
+        vec![],
+        FunctionBody::BuiltIn(make_proxy),
+    ));
+    proxy.set_call(Function::create_builtin(
+        vec![],
+        FunctionBody::BuiltIn(call_proxy),
+    ));
+    proxy.set_internal_slot("__proto__", func_prototype);
+    let proxy = to_value(proxy);
+    proxy.set_field_slice("length", to_value(2));
+
+    make_builtin_fn!(revocable, named "revocable", with length 2, of proxy);
+
+    proxy
+}
+
+/// Initialise the `Proxy` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Proxy", create(global));
+}
@@ -0,0 +1,296 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn forwards_to_the_target_without_traps() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "proxy.a"), "1");
+    assert_eq!(forward(&mut engine, "target.b"), "2");
+    assert_eq!(forward(&mut engine, "'b' in proxy"), "true");
+    assert_eq!(forward(&mut engine, "delete proxy.b"), "true");
+    assert_eq!(forward(&mut engine, "'b' in target"), "false");
+    assert_eq!(forward(&mut engine, "typeof proxy"), "object");
+    assert_eq!(
+        forward(&mut engine, "try { Proxy({}, {}) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new Proxy(1, {}) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+/// The use case of sandboxed scripts: every access to an object is recorded.
+#[test]
+fn access_logging() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var log = [];
+        var record = function (entry) { log[log.length] = entry; };
+        var handler = {
+            get: function (target, key, receiver) {
+                record('get ' + key);
+                return Reflect.get(target, key, receiver);
+            },
+            set: function (target, key, value, receiver) {
+                record('set ' + key + '=' + value);
+                return Reflect.set(target, key, value, receiver);
+            },
+            has: function (target, key) {
+                record('has ' + key);
+                return Reflect.has(target, key);
+            },
+            deleteProperty: function (target, key) {
+                record('delete ' + key);
+                return Reflect.deleteProperty(target, key);
+            }
+        };
+        var user = new Proxy({ name: 'ann' }, handler);
+        user.name;
+        user.age = 42;
+        'age' in user;
+        delete user.age;
+        user.age;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "log.join(', ')"),
+        "get name, set age=42, has age, delete age, get age"
+    );
+    assert_eq!(forward(&mut engine, "user.name"), "ann");
+    assert_eq!(forward(&mut engine, "user.age"), "undefined");
+}
+
+#[test]
+fn trap_arguments() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = {};
+        var seen;
+        var proxy = new Proxy(target, {
+            get: function (t, key, receiver) {
+                seen = [t === target, typeof key, receiver === proxy];
+                return 'got ' + key;
+            }
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "proxy.x"), "got x");
+    assert_eq!(forward(&mut engine, "proxy[1]"), "got 1");
+    assert_eq!(forward(&mut engine, "seen.join()"), "true,string,true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Proxy({}, { get: 1 }).x } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn proxies_in_the_prototype_chain() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var proto = new Proxy({}, {
+            get: function (target, key, receiver) { return 'inherited ' + key; }
+        });
+        var obj = { own: 'own' };
+        Reflect.setPrototypeOf(obj, proto);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "obj.own"), "own");
+    assert_eq!(forward(&mut engine, "obj.missing"), "inherited missing");
+}
+
+#[test]
+fn function_traps() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var add = function (a, b) { return a + b; };
+        var doubled = new Proxy(add, {
+            apply: function (target, thisArg, args) {
+                return Reflect.apply(target, thisArg, args) * 2;
+            }
+        });
+        var Logged = new Proxy(Map, {
+            construct: function (target, args, newTarget) {
+                var map = Reflect.construct(target, args, newTarget);
+                map.set('constructed', true);
+                return map;
+            }
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "typeof doubled"), "function");
+    assert_eq!(forward(&mut engine, "doubled(1, 2)"), "6");
+    assert_eq!(forward(&mut engine, "new Proxy(add, {})(2, 3)"), "5");
+    assert_eq!(
+        forward(&mut engine, "new Logged().get('constructed')"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Proxy(Map, {}).prototype === Map.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new doubled() } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new (new Proxy(Map, { construct: function () { return 1; } }))() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn revocable() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var pair = Proxy.revocable({ a: 1 }, {});
+        var proxy = pair.proxy;
+        var before = proxy.a;
+        pair.revoke();
+        pair.revoke();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "before"), "1");
+    assert_eq!(forward(&mut engine, "typeof pair.revoke"), "function");
+    assert_eq!(
+        forward(&mut engine, "try { proxy.a } catch (e) { e.message }"),
+        "Cannot perform 'get' on a proxy that has been revoked"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { 'a' in proxy } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Proxy(proxy, {}); 'ok' } catch (e) { e.name }"
+        ),
+        "ok"
+    );
+}
+
+#[test]
+fn invariants() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = {};
+        Reflect.defineProperty(target, 'fixed', { value: 1, writable: false, configurable: false });
+        var lying = new Proxy(target, {
+            get: function () { return 2; },
+            has: function () { return false; },
+            deleteProperty: function () { return true; },
+            getOwnPropertyDescriptor: function () { return undefined; },
+            ownKeys: function () { return []; },
+            isExtensible: function () { return false; },
+            defineProperty: function () { return true; }
+        });
+        var check = function (f) {
+            try { f(); return 'ok'; } catch (e) { return e.name; }
+        };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "check(function () { lying.fixed; })"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { lying.other; })"),
+        "ok"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { 'fixed' in lying; })"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { delete lying.fixed; })"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.getOwnPropertyDescriptor(lying, 'fixed'); })"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.ownKeys(lying); })"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.isExtensible(lying); })"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { Reflect.defineProperty(lying, 'new', { value: 1, configurable: false }); })"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { Reflect.defineProperty(lying, 'new', { value: 1, configurable: true }); })"),
+        "ok"
+    );
+
+    // Once the target is sealed, the proxy can't report anything else than its real shape
+    forward(&mut engine, "Reflect.preventExtensions(target)");
+    let frozen = r#"
+        var frozen = new Proxy(target, {
+            getPrototypeOf: function () { return Array.prototype; },
+            ownKeys: function () { return ['fixed', 'extra']; },
+            preventExtensions: function () { return true; }
+        });
+        "#;
+    forward(&mut engine, frozen);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.getPrototypeOf(frozen); })"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.ownKeys(frozen); })"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "check(function () { Reflect.preventExtensions(frozen); })"
+        ),
+        "ok"
+    );
+    assert_eq!(
+        forward(&mut engine, "check(function () { Reflect.preventExtensions(new Proxy({}, { preventExtensions: function () { return true; } })); })"),
+        "TypeError"
+    );
+}
@@ -0,0 +1,299 @@
+//! This module implements the global `Reflect` object.
+//!
+//! `Reflect` is a namespace with a function for each internal method of objects,
+//! taking the same arguments as the traps of `Proxy` handlers.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-reflect-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array,
+        object::internal_methods_of,
+        property::Property,
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Gets the target object of a `Reflect` function, or throws a `TypeError` if it's not an object.
+fn target(args: &[Value], function: &str, ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    if target.is_object() {
+        Ok(target)
+    } else {
+        ctx.throw_type_error(format!("Reflect.{} called on non-object", function))
+    }
+}
+
+/// Gets the property key argument at `index`.
+fn key(args: &[Value], index: usize, ctx: &mut Interpreter) -> Value {
+    ctx.to_property_key(&mut args.get(index).cloned().unwrap_or_else(undefined))
+}
+
+/// `Reflect.apply( target, thisArgument, argumentsList )`
+///
+/// Calls a function with the given `this` and arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
+pub fn apply(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    if !target.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", target));
+    }
+    let mut this = args.get(1).cloned().unwrap_or_else(undefined);
+    let arguments =
+        ctx.create_list_from_array_like(&args.get(2).cloned().unwrap_or_else(undefined))?;
+    ctx.call(&target, &mut this, &arguments)
+}
+
+/// `Reflect.construct( target, argumentsList [, newTarget ] )`
+///
+/// Constructs an object like the `new` operator, taking the prototype from `newTarget`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.construct
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct
+pub fn construct(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let is_constructor =
+        |value: &Value| value.as_object().map_or(false, |obj| obj.is_constructor());
+
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    if !is_constructor(&target) {
+        return ctx.throw_type_error(format!("{} is not a constructor", target));
+    }
+    let new_target = args.get(2).cloned().unwrap_or_else(|| target.clone());
+    if !is_constructor(&new_target) {
+        return ctx.throw_type_error(format!("{} is not a constructor", new_target));
+    }
+    let arguments =
+        ctx.create_list_from_array_like(&args.get(1).cloned().unwrap_or_else(undefined))?;
+    ctx.construct(&target, &arguments, &new_target)
+}
+
+/// `Reflect.defineProperty( target, propertyKey, attributes )`
+///
+/// Defines a property, returning whether it succeeded.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.defineproperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty
+pub fn define_property(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "defineProperty", ctx)?;
+    let key = key(args, 1, ctx);
+    let desc = Property::from_object(&args.get(2).cloned().unwrap_or_else(undefined), ctx)?;
+    Ok(to_value(ctx.define_own_property(&target, &key, desc)?))
+}
+
+/// `Reflect.deleteProperty( target, propertyKey )`
+///
+/// Deletes a property, returning whether it succeeded.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.deleteproperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty
+pub fn delete_property(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "deleteProperty", ctx)?;
+    let key = key(args, 1, ctx);
+    Ok(to_value(ctx.delete_property(&target, &key)?))
+}
+
+/// `Reflect.get( target, propertyKey [, receiver ] )`
+///
+/// Gets the value of a property, calling getters with `receiver` as `this`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.get
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get
+pub fn get(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "get", ctx)?;
+    let key = key(args, 1, ctx);
+    let receiver = args.get(2).cloned().unwrap_or_else(|| target.clone());
+    (internal_methods_of(&target).get)(&target, &key, &receiver, ctx)
+}
+
+/// `Reflect.getOwnPropertyDescriptor( target, propertyKey )`
+///
+/// Gets the descriptor of an own property, or `undefined` if there's no such property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.getownpropertydescriptor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor
+pub fn get_own_property_descriptor(
+    _: &mut Value,
+    args: &[Value],
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let target = target(args, "getOwnPropertyDescriptor", ctx)?;
+    let key = key(args, 1, ctx);
+    match ctx.get_own_property(&target, &key)? {
+        Some(desc) => Ok(desc.to_object(ctx)),
+        None => Ok(undefined()),
+    }
+}
+
+/// `Reflect.getPrototypeOf( target )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.getprototypeof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf
+pub fn get_prototype_of(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "getPrototypeOf", ctx)?;
+    ctx.get_prototype_of(&target)
+}
+
+/// `Reflect.has( target, propertyKey )`
+///
+/// Works like the `in` operator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.has
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
+pub fn has(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "has", ctx)?;
+    let key = key(args, 1, ctx);
+    Ok(to_value(ctx.has_property(&target, &key)?))
+}
+
+/// `Reflect.isExtensible( target )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.isextensible
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible
+pub fn is_extensible(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "isExtensible", ctx)?;
+    Ok(to_value(ctx.is_extensible(&target)?))
+}
+
+/// `Reflect.ownKeys( target )`
+///
+/// Returns an array of the own property keys of the target.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys
+pub fn own_keys(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "ownKeys", ctx)?;
+    let keys = ctx.own_property_keys(&target)?;
+    let array = array::new_array(ctx)?;
+    array::add_to_array_object(&array, &keys)?;
+    Ok(array)
+}
+
+/// `Reflect.preventExtensions( target )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.preventextensions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions
+pub fn prevent_extensions(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "preventExtensions", ctx)?;
+    Ok(to_value(ctx.prevent_extensions(&target)?))
+}
+
+/// `Reflect.set( target, propertyKey, V [, receiver ] )`
+///
+/// Sets the value of a property, returning whether it succeeded.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.set
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
+pub fn set(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "set", ctx)?;
+    let key = key(args, 1, ctx);
+    let value = args.get(2).cloned().unwrap_or_else(undefined);
+    let receiver = args.get(3).cloned().unwrap_or_else(|| target.clone());
+    let result = (internal_methods_of(&target).set)(&target, &key, value, &receiver, ctx)?;
+    Ok(to_value(result))
+}
+
+/// `Reflect.setPrototypeOf( target, prototype )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reflect.setprototypeof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf
+pub fn set_prototype_of(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = target(args, "setPrototypeOf", ctx)?;
+    let proto = args.get(1).cloned().unwrap_or_else(undefined);
+    if !proto.is_object() && !proto.is_null() {
+        return ctx.throw_type_error(format!(
+            "Object prototype may only be an Object or null: {}",
+            proto
+        ));
+    }
+    Ok(to_value(ctx.set_prototype_of(&target, &proto)?))
+}
+
+/// Create a new `Reflect` object.
+pub fn create(global: &Value) -> Value {
+    let reflect = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(apply, named "apply", with length 3, of reflect);
+    make_builtin_fn!(construct, named "construct", with length 2, of reflect);
+    make_builtin_fn!(define_property, named "defineProperty", with length 3, of reflect);
+    make_builtin_fn!(delete_property, named "deleteProperty", with length 2, of reflect);
+    make_builtin_fn!(get, named "get", with length 2, of reflect);
+    make_builtin_fn!(get_own_property_descriptor, named "getOwnPropertyDescriptor", with length 2, of reflect);
+    make_builtin_fn!(get_prototype_of, named "getPrototypeOf", with length 1, of reflect);
+    make_builtin_fn!(has, named "has", with length 2, of reflect);
+    make_builtin_fn!(is_extensible, named "isExtensible", with length 1, of reflect);
+    make_builtin_fn!(own_keys, named "ownKeys", with length 1, of reflect);
+    make_builtin_fn!(prevent_extensions, named "preventExtensions", with length 1, of reflect);
+    make_builtin_fn!(set, named "set", with length 3, of reflect);
+    make_builtin_fn!(set_prototype_of, named "setPrototypeOf", with length 2, of reflect);
+
+    reflect
+}
+
+/// Initialise the `Reflect` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Reflect", create(global));
+}
@@ -0,0 +1,192 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn property_methods() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var obj = { a: 1 };
+        Reflect.defineProperty(obj, 'fixed', { value: 2 });
+        var desc = Reflect.getOwnPropertyDescriptor(obj, 'fixed');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "Reflect.get(obj, 'a')"), "1");
+    assert_eq!(forward(&mut engine, "Reflect.has(obj, 'fixed')"), "true");
+    assert_eq!(forward(&mut engine, "Reflect.has(obj, 'toString')"), "true");
+    assert_eq!(forward(&mut engine, "desc.value"), "2");
+    assert_eq!(forward(&mut engine, "desc.writable"), "false");
+    assert_eq!(forward(&mut engine, "desc.configurable"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(obj, 'missing')"
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(obj, 'fixed', 3)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "obj.fixed"), "2");
+    assert_eq!(forward(&mut engine, "Reflect.set(obj, 'b', 3)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(obj, 'fixed')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(obj, 'b')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.has(obj, 'b')"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(obj, 'fixed', { value: 3 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.get(1, 'a') } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn accessors_and_receivers() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var stored = 1;
+        var obj = { a: 1 };
+        Reflect.defineProperty(obj, 'x', {
+            get: function () { return stored; },
+            set: function (value) { stored = value * 10; }
+        });
+        var other = {};
+        var proxy = new Proxy({}, {
+            get: function (target, key, receiver) { return receiver === other; }
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "obj.x"), "1");
+    forward(&mut engine, "obj.x = 2");
+    assert_eq!(forward(&mut engine, "Reflect.get(obj, 'x')"), "20");
+    assert_eq!(forward(&mut engine, "Reflect.set(obj, 'x', 3)"), "true");
+    assert_eq!(forward(&mut engine, "stored"), "30");
+    assert_eq!(
+        forward(&mut engine, "Reflect.get(proxy, 'x', other)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.get(proxy, 'x')"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(obj, 'a', 3, other)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "obj.a + ' ' + other.a"), "1 3");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.defineProperty(obj, 'y', { get: 1 }) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn own_keys() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var obj = { b: 1 };
+        obj[10] = 1;
+        obj[2] = 1;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(obj).join()"),
+        "2,10,b"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys([1]).join()"),
+        "0,length"
+    );
+}
+
+#[test]
+fn prototypes_and_extensibility() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var proto = { greet: function () { return 'hi'; } };
+        var obj = {};
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(obj, proto)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.getPrototypeOf(obj) === proto"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "obj.greet()"), "hi");
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(proto, obj)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(obj)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.preventExtensions(obj)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(obj)"), "false");
+    assert_eq!(forward(&mut engine, "Reflect.set(obj, 'a', 1)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(obj, null)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.setPrototypeOf(obj, 1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn apply_and_construct() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var add = function (a, b) { return a + b; };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "Reflect.apply(add, undefined, [1, 2])"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.construct(Map, [[['a', 1]]]).get('a')"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.apply(1, undefined, []) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.construct(add, []) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
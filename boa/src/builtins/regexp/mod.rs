@@ -16,9 +16,12 @@ use regex::Regex;
 
 use crate::{
     builtins::{
+        array::{construct_array, new_array},
         object::{InternalState, Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
         property::Property,
-        value::{from_value, to_value, undefined, FromValue, ResultValue, Value, ValueData},
+        string::get_substitution,
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
@@ -59,50 +62,77 @@ struct RegExp {
 
 impl InternalState for RegExp {}
 
-/// Helper function for getting an argument.
-fn get_argument<T: FromValue>(args: &[Value], idx: usize) -> Result<T, Value> {
-    match args.get(idx) {
-        Some(arg) => from_value(arg.clone()).map_err(to_value),
-        None => Err(to_value(format!("expected argument at index {}", idx))),
+/// Checks whether `value` is a `RegExp` object.
+pub(crate) fn is_regexp(value: &Value) -> bool {
+    value.has_internal_state_of::<RegExp>()
+}
+
+/// Gets the flags of `value`, if it's a `RegExp` object.
+pub(crate) fn flags_of(value: &Value) -> Option<String> {
+    if is_regexp(value) {
+        Some(value.with_internal_state_ref(|regex: &RegExp| regex.flags.clone()))
+    } else {
+        None
     }
 }
 
-/// Create a new `RegExp`
-pub fn make_regexp(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    if args.is_empty() {
-        return Err(undefined());
+/// Throws a `TypeError` if a `RegExp.prototype` method is called on something that is not a `RegExp` object.
+fn check_regexp(this: &Value, method: &str, ctx: &mut Interpreter) -> Result<(), Value> {
+    if is_regexp(this) {
+        Ok(())
+    } else {
+        ctx.throw_type_error(format!(
+            "RegExp.prototype.{} called on incompatible receiver {}",
+            method, this
+        ))
+        .map(|_| ())
     }
+}
+
+/// Helper function for getting an argument as a string, `undefined` when it's missing.
+fn get_argument(args: &[Value], idx: usize, ctx: &mut Interpreter) -> String {
+    ctx.value_to_rust_string(&args.get(idx).cloned().unwrap_or_else(undefined))
+}
+
+/// Create a new `RegExp`
+pub fn make_regexp(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     let mut regex_body = String::new();
     let mut regex_flags = String::new();
-    #[allow(clippy::indexing_slicing)] // length has been checked
-    match args[0].deref() {
-        ValueData::String(ref body) => {
+    match args.get(0).map(Deref::deref) {
+        // no pattern means the empty pattern
+        None | Some(ValueData::Undefined) => {}
+        Some(ValueData::String(ref body)) => {
             // first argument is a string -> use it as regex pattern
-            regex_body = body.into();
+            regex_body = body.to_string();
         }
-        ValueData::Object(ref obj) => {
+        Some(ValueData::Object(ref obj))
+            if obj.borrow().internal_slots.get("RegExpMatcher").is_some() =>
+        {
+            // first argument is another `RegExp` object, so copy its pattern and flags
             let slots = &*obj.borrow().internal_slots;
-            if slots.get("RegExpMatcher").is_some() {
-                // first argument is another `RegExp` object, so copy its pattern and flags
-                if let Some(body) = slots.get("OriginalSource") {
-                    regex_body =
-                        from_value(body.clone()).expect("Could not convert value to String");
-                }
-                if let Some(flags) = slots.get("OriginalFlags") {
-                    regex_flags =
-                        from_value(flags.clone()).expect("Could not convert value to String");
-                }
+            if let Some(body) = slots.get("OriginalSource") {
+                regex_body = body.to_string();
+            }
+            if let Some(flags) = slots.get("OriginalFlags") {
+                regex_flags = flags.to_string();
             }
         }
-        _ => return Err(undefined()),
+        Some(_) => regex_body = get_argument(args, 0, ctx),
     }
-    // if a second argument is given and it's a string, use it as flags
+    // if a second argument is given, use it as flags
     match args.get(1) {
         None => {}
-        Some(flags) => {
-            if let ValueData::String(flags) = flags.deref() {
-                regex_flags = flags.into();
-            }
+        Some(flags) if flags.is_undefined() => {}
+        Some(flags) => regex_flags = ctx.value_to_rust_string(flags),
+    }
+
+    // every flag must be known and appear at most once
+    for (i, flag) in regex_flags.char_indices() {
+        if !"gimsuy".contains(flag) || regex_flags[i + flag.len_utf8()..].contains(flag) {
+            return ctx.throw_syntax_error(format!(
+                "Invalid flags supplied to RegExp constructor '{}'",
+                regex_flags
+            ));
         }
     }
 
@@ -149,7 +179,15 @@ pub fn make_regexp(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Res
     }
     pattern.push_str(regex_body.as_str());
 
-    let matcher = Regex::new(pattern.as_str()).expect("failed to create matcher");
+    let matcher = match Regex::new(pattern.as_str()) {
+        Ok(matcher) => matcher,
+        Err(err) => {
+            return ctx.throw_syntax_error(format!(
+                "Invalid regular expression: /{}/: {}",
+                regex_body, err
+            ))
+        }
+    };
     let regexp = RegExp {
         matcher,
         use_last_index: global || sticky,
@@ -183,7 +221,8 @@ pub fn make_regexp(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Res
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.dotAll
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/dotAll
-fn get_dot_all(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_dot_all(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "dotAll", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.dot_all)))
 }
 
@@ -198,7 +237,8 @@ fn get_dot_all(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValu
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.flags
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags
 /// [flags]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions#Advanced_searching_with_flags_2
-fn get_flags(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_flags(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "flags", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.flags.clone())))
 }
 
@@ -212,7 +252,8 @@ fn get_flags(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.global
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/global
-fn get_global(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_global(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "global", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.global)))
 }
 
@@ -226,7 +267,8 @@ fn get_global(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.ignorecase
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/ignoreCase
-fn get_ignore_case(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_ignore_case(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "ignoreCase", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.ignore_case)))
 }
 
@@ -240,7 +282,8 @@ fn get_ignore_case(this: &mut Value, _: &[Value], _: &mut Interpreter) -> Result
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.multiline
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/multiline
-fn get_multiline(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_multiline(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "multiline", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.multiline)))
 }
 
@@ -269,7 +312,8 @@ fn get_source(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.sticky
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/sticky
-fn get_sticky(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_sticky(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "sticky", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.sticky)))
 }
 
@@ -284,7 +328,8 @@ fn get_sticky(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicode
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode
-fn get_unicode(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+fn get_unicode(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "unicode", ctx)?;
     this.with_internal_state_ref(|regex: &RegExp| Ok(to_value(regex.unicode)))
 }
 
@@ -300,10 +345,11 @@ fn get_unicode(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValu
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.test
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/test
-pub fn test(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let arg_str = get_argument::<String>(args, 0)?;
-    let mut last_index =
-        from_value::<usize>(this.get_field_slice("lastIndex")).map_err(to_value)?;
+pub fn test(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "test", ctx)?;
+    let arg_str = get_argument(args, 0, ctx);
+    let mut last_index = from_value::<usize>(this.get_field_slice("lastIndex"))
+        .map_err(|error| error.to_type_error(ctx))?;
     let result = this.with_internal_state_ref(|regex: &RegExp| {
         let result = if let Some(m) = regex.matcher.find_at(arg_str.as_str(), last_index) {
             if regex.use_last_index {
@@ -334,10 +380,11 @@ pub fn test(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValu
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.exec
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/exec
-pub fn exec(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let arg_str = get_argument::<String>(args, 0)?;
-    let mut last_index =
-        from_value::<usize>(this.get_field_slice("lastIndex")).map_err(to_value)?;
+pub fn exec(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "exec", ctx)?;
+    let arg_str = get_argument(args, 0, ctx);
+    let mut last_index = from_value::<usize>(this.get_field_slice("lastIndex"))
+        .map_err(|error| error.to_type_error(ctx))?;
     let result = this.with_internal_state_ref(|regex: &RegExp| {
         let mut locations = regex.matcher.capture_locations();
         let result = if let Some(m) =
@@ -411,8 +458,10 @@ pub fn r#match(this: &mut Value, arg: String, ctx: &mut Interpreter) -> ResultVa
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.tostring
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/toString
-pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    let body = from_value::<String>(this.get_internal_slot("OriginalSource")).map_err(to_value)?;
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "toString", ctx)?;
+    let body = from_value::<String>(this.get_internal_slot("OriginalSource"))
+        .map_err(|error| error.to_type_error(ctx))?;
     let flags = this.with_internal_state_ref(|regex: &RegExp| regex.flags.clone());
     Ok(to_value(format!("/{}/{}", body, flags)))
 }
@@ -468,6 +517,195 @@ pub fn match_all(this: &mut Value, arg_str: String) -> ResultValue {
     Ok(result)
 }
 
+/// Gets the number of UTF-16 code units before the byte offset `end` of `string`.
+///
+/// The matchers work on UTF-8, but the indices seen by scripts are in UTF-16 code units.
+fn utf16_index(string: &str, end: usize) -> usize {
+    string[..end].encode_utf16().count()
+}
+
+/// Gets the byte offset right after the character at byte offset `index` of `string`.
+///
+/// It's where the search continues after an empty match, so it's never stuck at the same index.
+fn advance_string_index(string: &str, index: usize) -> usize {
+    index + string[index..].chars().next().map_or(1, char::len_utf8)
+}
+
+/// `RegExp.prototype[ @@replace ]( string, replaceValue )`
+///
+/// Replaces the first match of the regular expression in `string`, or all of them if it's global.
+/// `replaceValue` is either a function called for each match, or a string with substitution
+/// patterns like `$&` or `$1`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@replace
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@replace
+pub fn replace(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "[Symbol.replace]", ctx)?;
+    let arg_str = get_argument(args, 0, ctx);
+    let replace_value = args.get(1).cloned().unwrap_or_else(undefined);
+    let functional_replace = replace_value.is_function();
+    let replace_string = if functional_replace {
+        JsString::new()
+    } else {
+        ctx.value_to_js_string(&replace_value)
+    };
+
+    let (matcher, global) =
+        this.with_internal_state_ref(|regex: &RegExp| (regex.matcher.clone(), regex.global));
+    if global {
+        this.set_field_slice("lastIndex", to_value(0));
+    }
+
+    let string = JsString::from(arg_str.as_str());
+    let mut locations = matcher.capture_locations();
+    let mut result = Vec::with_capacity(string.len());
+    let mut next_source_position = 0;
+    let mut search_position = 0;
+    while search_position <= arg_str.len() {
+        let mat = match matcher.captures_read_at(&mut locations, &arg_str, search_position) {
+            Some(mat) => mat,
+            None => break,
+        };
+
+        let matched = JsString::from(mat.as_str());
+        let position = utf16_index(&arg_str, mat.start());
+        let captures: Vec<Option<JsString>> = (1..locations.len())
+            .map(|i| {
+                locations
+                    .get(i)
+                    .map(|(start, end)| JsString::from(&arg_str[start..end]))
+            })
+            .collect();
+
+        let replacement = if functional_replace {
+            let mut replacer_args = Vec::with_capacity(captures.len() + 3);
+            replacer_args.push(to_value(matched));
+            for capture in captures {
+                replacer_args.push(capture.map_or_else(undefined, to_value));
+            }
+            replacer_args.push(to_value(position));
+            replacer_args.push(to_value(string.clone()));
+            let replacement = ctx.call(&replace_value, &mut undefined(), &replacer_args)?;
+            ctx.value_to_js_string(&replacement)
+        } else {
+            get_substitution(&matched, &string, position, &captures, &replace_string)
+        };
+
+        result.extend(arg_str[next_source_position..mat.start()].encode_utf16());
+        result.extend_from_slice(&replacement);
+        next_source_position = mat.end();
+        if !global {
+            break;
+        }
+        search_position = if mat.start() == mat.end() {
+            advance_string_index(&arg_str, mat.end())
+        } else {
+            mat.end()
+        };
+    }
+    result.extend(arg_str[next_source_position..].encode_utf16());
+
+    Ok(to_value(JsString::from(result)))
+}
+
+/// `RegExp.prototype[ @@search ]( string )`
+///
+/// Returns the index of the first match of the regular expression in `string`, or `-1`.
+/// The search always starts at the beginning of the string, whatever the `lastIndex`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@search
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@search
+pub fn search(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "[Symbol.search]", ctx)?;
+    let arg_str = get_argument(args, 0, ctx);
+    let start = this.with_internal_state_ref(|regex: &RegExp| {
+        regex.matcher.find(&arg_str).map(|mat| mat.start())
+    });
+
+    match start {
+        Some(start) => Ok(to_value(utf16_index(&arg_str, start))),
+        None => Ok(to_value(-1)),
+    }
+}
+
+/// `RegExp.prototype[ @@split ]( string [, limit ] )`
+///
+/// Splits `string` around the matches of the regular expression, into an array of at most
+/// `limit` strings. The captured groups of each match are inserted between the strings.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@split
+pub fn split(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_regexp(this, "[Symbol.split]", ctx)?;
+    let arg_str = get_argument(args, 0, ctx);
+    let limit = match args.get(1) {
+        Some(limit) if !limit.is_undefined() => ctx.to_uint32(limit) as usize,
+        _ => u32::MAX as usize,
+    };
+    let matcher = this.with_internal_state_ref(|regex: &RegExp| regex.matcher.clone());
+
+    let mut parts = Vec::new();
+    if limit == 0 {
+        // Nothing to split
+    } else if arg_str.is_empty() {
+        // The empty string is only split by a regular expression which doesn't match it
+        if !matcher.is_match(&arg_str) {
+            parts.push(to_value(arg_str));
+        }
+    } else {
+        let mut locations = matcher.capture_locations();
+        let mut part_start = 0;
+        let mut search_position = 0;
+        while search_position < arg_str.len() {
+            let mat = match matcher.captures_read_at(&mut locations, &arg_str, search_position) {
+                Some(mat) if mat.start() < arg_str.len() => mat,
+                _ => break,
+            };
+
+            // An empty match right at the start of the part doesn't split it
+            if mat.end() == part_start {
+                search_position = advance_string_index(&arg_str, mat.start());
+                continue;
+            }
+
+            parts.push(to_value(&arg_str[part_start..mat.start()]));
+            for i in 1..locations.len() {
+                if parts.len() == limit {
+                    break;
+                }
+                parts.push(
+                    locations
+                        .get(i)
+                        .map_or_else(undefined, |(start, end)| to_value(&arg_str[start..end])),
+                );
+            }
+            if parts.len() == limit {
+                break;
+            }
+            part_start = mat.end();
+            search_position = part_start;
+        }
+        if parts.len() < limit {
+            parts.push(to_value(&arg_str[part_start..]));
+        }
+    }
+
+    let array = new_array(ctx)?;
+    construct_array(&array, &parts)
+}
+
 /// Create a new `RegExp` object.
 pub fn create(global: &Value) -> Value {
     // Create prototype
@@ -485,6 +723,9 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(get_source, named "source", of prototype);
     make_builtin_fn!(get_sticky, named "sticky", of prototype);
     make_builtin_fn!(get_unicode, named "unicode", of prototype);
+    make_builtin_fn!(replace, keyed well_known_symbol(global, "replace"), with length 2, of prototype);
+    make_builtin_fn!(search, keyed well_known_symbol(global, "search"), with length 1, of prototype);
+    make_builtin_fn!(split, keyed well_known_symbol(global, "split"), with length 2, of prototype);
 
     make_constructor_fn!(make_regexp, make_regexp, global, prototype)
 }
@@ -109,3 +109,44 @@ fn to_string() {
     );
     assert_eq!(forward(&mut engine, "/\\n/g.toString()"), "/\\n/g");
 }
+
+#[test]
+fn symbol_methods() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var global = /o/g;
+        global.lastIndex = 3;
+        var replaced = global[Symbol.replace]('foo boo', '0');
+        var split = RegExp.prototype[Symbol.split];
+        var notRegExp = { split: split };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "replaced"), "f00 b00");
+    assert_eq!(forward(&mut engine, "global.lastIndex"), "0");
+    assert_eq!(
+        forward(&mut engine, "/(o)/[Symbol.replace]('foo', '[$1$&]')"),
+        "f[oo]o"
+    );
+    assert_eq!(
+        forward(&mut engine, "/x*/g[Symbol.replace]('ab', '-')"),
+        "-a-b-"
+    );
+    assert_eq!(forward(&mut engine, "/o/[Symbol.search]('foo')"), "1");
+    assert_eq!(forward(&mut engine, "/z/[Symbol.search]('foo')"), "-1");
+    assert_eq!(
+        forward(&mut engine, "/,/[Symbol.split]('a,b,c', 2).join('|')"),
+        "a|b"
+    );
+    assert_eq!(
+        forward(&mut engine, "/(-)|(,)/[Symbol.split]('a-b').length"),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { notRegExp.split('a') } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
@@ -0,0 +1,305 @@
+//! This module implements the global `Set` object.
+//!
+//! The `Set` object lets you store unique values of any type, whether primitive values or object references.
+//! Values are kept in insertion order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-set-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        map::{
+            iterator::{self, create_iterator, IterationKind, ITERATOR_PROTOTYPE},
+            ordered_map::OrderedMap,
+            with_entries_mut, with_entries_ref,
+        },
+        object::{Object, ObjectInternalMethods, ObjectKind, PROTOTYPE},
+        property::Property,
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Checks whether `value` is a `Set` object.
+pub(crate) fn is_set(value: &Value) -> bool {
+    match **value {
+        ValueData::Object(ref obj) => match obj.borrow().kind {
+            ObjectKind::Set(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Throws a `TypeError` if a `Set.prototype` method is called on something that is not a `Set` object.
+fn check_set(this: &Value, method: &str, ctx: &mut Interpreter) -> Result<(), Value> {
+    if is_set(this) {
+        Ok(())
+    } else {
+        ctx.throw_type_error(format!(
+            "Method Set.prototype.{} called on incompatible receiver {}",
+            method, this
+        ))
+        .map(|_| ())
+    }
+}
+
+/// Gets the values of `iterable`, to add them to a new `Set` or `WeakSet` object.
+///
+/// There is no iteration protocol yet, so only arrays and other `Set` objects can be used.
+pub(crate) fn values_of_iterable(
+    iterable: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Vec<Value>, Value> {
+    if is_set(iterable) {
+        return Ok(with_entries_ref(iterable, |set| {
+            set.iter().map(|(value, _)| value.clone()).collect()
+        }));
+    }
+
+    match **iterable {
+        ValueData::Object(ref obj) if obj.borrow().kind == ObjectKind::Array => {
+            let length: i32 = from_value(iterable.get_field_slice("length"))
+                .map_err(|error| error.to_type_error(ctx))?;
+            Ok((0..length)
+                .map(|idx| iterable.get_field_slice(&idx.to_string()))
+                .collect())
+        }
+        _ => ctx
+            .throw_type_error(format!("{} is not iterable", iterable))
+            .map(|_| Vec::new()),
+    }
+}
+
+/// Create a new `Set`.
+///
+/// The optional argument is an array, or another `Set`, whose values are copied.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-iterable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/Set
+pub fn make_set(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this.set_kind(ObjectKind::Set(OrderedMap::new()));
+
+    match args.get(0) {
+        Some(iterable) if !iterable.is_null_or_undefined() => {
+            let values = values_of_iterable(iterable, ctx)?;
+            with_entries_mut(this, |set| {
+                for value in values {
+                    set.insert_value(value);
+                }
+            });
+        }
+        _ => {}
+    }
+
+    Ok(this.clone())
+}
+
+/// `Set` can only be called as a constructor.
+pub fn call_set(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor Set requires 'new'")
+}
+
+/// `Set.prototype.add( value )`
+///
+/// The `add()` method appends a new element with a specified value to the end of a `Set` object,
+/// if it's not already in it.
+///
+/// Returns the `Set` object, so calls can be chained.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
+pub fn add(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "add", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    with_entries_mut(this, |set| set.insert_value(value));
+    Ok(this.clone())
+}
+
+/// `Set.prototype.clear()`
+///
+/// The `clear()` method removes all elements from a `Set` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.clear
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
+pub fn clear(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "clear", ctx)?;
+    with_entries_mut(this, |set| set.clear());
+    Ok(undefined())
+}
+
+/// `Set.prototype.delete( value )`
+///
+/// The `delete()` method removes the specified element from a `Set` object.
+///
+/// Returns `true` if the element existed and has been removed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.delete
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete
+pub fn delete(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "delete", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    let removed = with_entries_mut(this, |set| set.remove(&value));
+    Ok(to_value(removed))
+}
+
+/// `Set.prototype.entries()`
+///
+/// The `entries()` method returns a new iterator object that contains `[value, value]` pairs
+/// for each element in the `Set` object, in insertion order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.entries
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries
+pub fn entries(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "entries", ctx)?;
+    Ok(create_iterator(
+        ctx,
+        this,
+        IterationKind::KeyAndValue,
+        "Set",
+    ))
+}
+
+/// `Set.prototype.forEach( callbackFn [ , thisArg ] )`
+///
+/// The `forEach()` method executes a provided function once for each value in the `Set` object,
+/// in insertion order.
+///
+/// Values added during the iteration are visited, deleted values are skipped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.foreach
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach
+pub fn for_each(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "forEach", ctx)?;
+    let callback = args.get(0).cloned().unwrap_or_else(undefined);
+    if !callback.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", callback));
+    }
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    let cursor = with_entries_mut(this, OrderedMap::cursor);
+    while let Some((value, _)) = with_entries_ref(this, |set| set.next_entry(&cursor)) {
+        ctx.call(
+            &callback,
+            &mut this_arg,
+            &[value.clone(), value, this.clone()],
+        )?;
+    }
+
+    Ok(undefined())
+}
+
+/// `Set.prototype.has( value )`
+///
+/// The `has()` method returns a boolean indicating whether an element with the specified value exists or not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.has
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has
+pub fn has(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "has", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    let has = with_entries_ref(this, |set| set.contains_key(&value));
+    Ok(to_value(has))
+}
+
+/// `get Set.prototype.size`
+///
+/// The `size` accessor property returns the number of values in a `Set` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-set.prototype.size
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/size
+pub fn get_size(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "size", ctx)?;
+    Ok(to_value(with_entries_ref(this, OrderedMap::len)))
+}
+
+/// `Set.prototype.values()`
+///
+/// The `values()` method returns a new iterator object that contains the values for each element
+/// in the `Set` object, in insertion order.
+///
+/// `Set.prototype.keys` is the same function, as the keys of a set are its values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set.prototype.values
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values
+pub fn values(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    check_set(this, "values", ctx)?;
+    Ok(create_iterator(ctx, this, IterationKind::Value, "Set"))
+}
+
+/// Create a new `Set` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    prototype.set_internal_slot(ITERATOR_PROTOTYPE, iterator::create_prototype(global));
+
+    make_builtin_fn!(add, named "add", with length 1, of prototype);
+    make_builtin_fn!(clear, named "clear", of prototype);
+    make_builtin_fn!(delete, named "delete", with length 1, of prototype);
+    make_builtin_fn!(entries, named "entries", of prototype);
+    make_builtin_fn!(for_each, named "forEach", with length 1, of prototype);
+    make_builtin_fn!(has, named "has", with length 1, of prototype);
+    make_builtin_fn!(values, named "values", of prototype);
+    make_getter_fn!(get_size, named "size", of prototype);
+    prototype.set_field_slice("keys", prototype.get_field_slice("values"));
+    // `Set.prototype[Symbol.iterator]` is the same function as `values`
+    prototype.set_symbol_prop(
+        &well_known_symbol(global, "iterator"),
+        Property::default()
+            .value(prototype.get_field_slice("values"))
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    make_constructor_fn!(make_set, call_set, global, prototype)
+}
+
+/// Initialise the `Set` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("Set", create(global));
+}
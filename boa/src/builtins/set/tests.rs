@@ -0,0 +1,116 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct_and_add() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var empty = new Set();
+        var set = new Set([1, 2, 2, 3, 1]);
+        var copy = new Set(set);
+        var chained = set.add(4).add(1).size;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "empty.size"), "0");
+    assert_eq!(forward(&mut engine, "set.size"), "4");
+    assert_eq!(forward(&mut engine, "copy.size"), "3");
+    assert_eq!(forward(&mut engine, "chained"), "4");
+    assert_eq!(forward(&mut engine, "set.has(4)"), "true");
+    assert_eq!(forward(&mut engine, "copy.has(4)"), "false");
+    assert_eq!(
+        forward(&mut engine, "try { Set() } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn delete_and_clear() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var set = new Set(['a', 'b']);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "set.delete('a')"), "true");
+    assert_eq!(forward(&mut engine, "set.delete('a')"), "false");
+    assert_eq!(forward(&mut engine, "set.size"), "1");
+    forward(&mut engine, "set.clear()");
+    assert_eq!(forward(&mut engine, "set.size"), "0");
+    assert_eq!(forward(&mut engine, "set.has('b')"), "false");
+}
+
+/// Values are compared with `SameValueZero`.
+#[test]
+fn same_value_zero_values() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var set = new Set([NaN, NaN, -0, 0, {}, {}]);
+        var zeros = new Set([-0]);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "set.size"), "4");
+    assert_eq!(forward(&mut engine, "set.has(NaN)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(-0)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(
+        forward(&mut engine, "1 / zeros.values().next().value"),
+        "Infinity"
+    );
+}
+
+#[test]
+fn iterators_and_for_each() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var set = new Set(['a', 'b', 'c']);
+        var entries = set.entries();
+        var visited = [];
+        set.forEach(function (value, key, s) {
+            visited.push(value + key);
+            if (value === 'a') {
+                s.delete('b');
+                s.add('d');
+            }
+        });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "visited.join()"), "aa,cc,dd");
+    assert_eq!(forward(&mut engine, "entries.next().value.join()"), "a,a");
+    assert_eq!(forward(&mut engine, "entries.next().value.join()"), "c,c");
+    assert_eq!(forward(&mut engine, "set.keys().next().value"), "a");
+
+    let init = r#"
+        var values = set.values();
+        values.next();
+        values.next();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "values.next().value"), "d");
+    assert_eq!(forward(&mut engine, "values.next().done"), "true");
+}
+
+#[test]
+fn size_accessor() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var set = new Set([1, 2]);
+        set.size = 5;
+        var getter = Object.getOwnPropertyDescriptor(Set.prototype, "size").get;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "set.size"), "2");
+    assert_eq!(forward(&mut engine, "set.hasOwnProperty('size')"), "false");
+    assert_eq!(forward(&mut engine, "getter.call(set)"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { getter.call(new Map()) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
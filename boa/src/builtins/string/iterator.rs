@@ -0,0 +1,86 @@
+//! This module implements the iterators of strings.
+//!
+//! They are returned by `String.prototype[ @@iterator ]()`, and walk the code points of the string,
+//! so a surrogate pair is returned as a single string of two code units.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-string-iterator-objects
+
+use crate::{
+    builtins::{
+        map::iterator::{create_iter_result, iterator, ITERATOR_PROTOTYPE},
+        object::{Object, PROTOTYPE},
+        symbol::well_known_symbol,
+        value::{from_value, to_value, undefined, JsString, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Creates a new iterator over the code points of `string`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createstringiterator
+pub(crate) fn create_string_iterator(ctx: &Interpreter, string: JsString) -> Value {
+    let prototype = ctx
+        .realm
+        .global_obj
+        .get_field_slice("String")
+        .get_field_slice(PROTOTYPE)
+        .get_internal_slot(ITERATOR_PROTOTYPE);
+
+    let iterator = to_value(Object::create(prototype));
+    iterator.set_internal_slot("IteratedString", to_value(string));
+    iterator.set_internal_slot("StringNextIndex", to_value(0));
+    iterator
+}
+
+/// `%StringIteratorPrototype%.next()`
+///
+/// Returns the next code point of the iterated string, or a result with `done` set to `true`
+/// once all of them were visited.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%.next
+pub(crate) fn next(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let index = this.get_internal_slot("StringNextIndex");
+    if index.is_undefined() {
+        return ctx.throw_type_error(format!(
+            "next method called on incompatible receiver {}",
+            this
+        ));
+    }
+    let index = from_value::<usize>(index).map_err(|error| error.to_type_error(ctx))?;
+
+    // The iterated string is removed once the iterator is done, so it stays done
+    let string = match *this.get_internal_slot("IteratedString") {
+        ValueData::String(ref string) => string.clone(),
+        _ => return Ok(create_iter_result(ctx, undefined(), true)),
+    };
+
+    match string.code_point_at(index) {
+        Some((_, code_units)) => {
+            let end = index + code_units;
+            this.set_internal_slot("StringNextIndex", to_value(end));
+            let result = to_value(string.substring(index, end));
+            Ok(create_iter_result(ctx, result, false))
+        }
+        None => {
+            this.set_internal_slot("IteratedString", undefined());
+            Ok(create_iter_result(ctx, undefined(), true))
+        }
+    }
+}
+
+/// Creates the prototype of the iterators of strings.
+pub(crate) fn create_prototype(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+    make_builtin_fn!(next, named "next", of prototype);
+    make_builtin_fn!(iterator, keyed well_known_symbol(global, "iterator"), of prototype);
+    prototype
+}
@@ -9,29 +9,44 @@
 //! [spec]: https://tc39.es/ecma262/#sec-string-object
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String
 
+pub(crate) mod iterator;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "intl")]
+use crate::builtins::intl::{canonicalize_locale_list, collator};
 use crate::{
     builtins::{
-        object::{internal_methods_trait::ObjectInternalMethods, Object, ObjectKind, PROTOTYPE},
+        array::{construct_array, length_of_array_like, new_array},
+        map::iterator::ITERATOR_PROTOTYPE,
+        object::{
+            internal_methods::is_compatible_property_descriptor,
+            internal_methods_trait::ObjectInternalMethods, InternalObjectMethods, Object,
+            ObjectKind, ORDINARY_INTERNAL_METHODS, PROTOTYPE,
+        },
         property::Property,
-        regexp::{make_regexp, match_all as regexp_match_all, r#match as regexp_match},
-        value::{from_value, to_value, undefined, ResultValue, Value, ValueData},
+        regexp::{
+            flags_of, is_regexp, make_regexp, match_all as regexp_match_all,
+            r#match as regexp_match, search as regexp_search,
+        },
+        symbol::{self, well_known_symbol},
+        value::{to_value, undefined, JsString, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
 use gc::Gc;
-use regex::Regex;
+#[cfg(not(feature = "intl"))]
+use std::cmp::Ordering;
 use std::{
+    char::{decode_utf16, from_u32},
     cmp::{max, min},
     f64::NAN,
-    ops::Deref,
 };
+use unicode_normalization::UnicodeNormalization;
 
 /// Create new string [[Construct]]
 // This gets called when a new String() is created, it's called by exec:346
-pub fn make_string(this: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn make_string(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // If we're constructing a string, we should set the initial length
     // To do this we need to convert the string back to a Rust String, then get the .len()
     // let a: String = from_value(args.get(0).expect("failed to get argument for String method").clone()).unwrap();
@@ -40,36 +55,200 @@ pub fn make_string(this: &mut Value, args: &[Value], _: &mut Interpreter) -> Res
     // This value is used by console.log and other routines to match Obexpecty"failed to parse argument for String method"pe
     // to its Javascript Identifier (global constructor method name)
     this.set_kind(ObjectKind::String);
-    this.set_internal_slot(
-        "StringData",
-        args.get(0)
-            .expect("failed to get StringData for make_string()")
-            .clone(),
-    );
+    let string = match args.get(0) {
+        Some(value) => ctx.value_to_js_string(value),
+        None => JsString::new(),
+    };
+    this.set_internal_slot("StringData", to_value(string));
+    this.set_internal_methods(&STRING_INTERNAL_METHODS);
     Ok(this.clone())
 }
 
+/// The internal methods of `String` objects, which expose the code units of their string
+/// as read-only indexed properties, along with its `length`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects
+pub(crate) static STRING_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_own_property: string_get_own_property,
+    define_own_property: string_define_own_property,
+    own_property_keys: string_own_property_keys,
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+/// Gets the string of the `String` object `obj`.
+fn string_data(obj: &Value) -> JsString {
+    match *obj.get_internal_slot("StringData") {
+        ValueData::String(ref string) => string.clone(),
+        _ => JsString::new(),
+    }
+}
+
+/// Gets the array index `key` stands for, if any.
+fn array_index(key: &Value) -> Option<usize> {
+    let key = match **key {
+        ValueData::String(ref key) => key.to_std_string().ok()?,
+        _ => return None,
+    };
+    key.parse::<u32>()
+        .ok()
+        .filter(|index| *index != u32::MAX && index.to_string() == key)
+        .map(|index| index as usize)
+}
+
+/// `StringGetOwnProperty`, extended to the `length` of the string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+fn string_own_property(obj: &Value, key: &Value) -> Option<Property> {
+    let string = string_data(obj);
+    let value = match array_index(key) {
+        Some(index) => to_value(JsString::from_utf16(&[string.code_unit_at(index)?])),
+        None if key.is_string() && key.to_string() == "length" => to_value(string.len() as i32),
+        None => return None,
+    };
+    Some(
+        Property::new()
+            .value(value)
+            .writable(false)
+            .enumerable(array_index(key).is_some())
+            .configurable(false),
+    )
+}
+
+/// `[[GetOwnProperty]]` of `String` objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-getownproperty-p
+fn string_get_own_property(
+    obj: &Value,
+    key: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Option<Property>, Value> {
+    match string_own_property(obj, key) {
+        Some(desc) => Ok(Some(desc)),
+        None => (ORDINARY_INTERNAL_METHODS.get_own_property)(obj, key, ctx),
+    }
+}
+
+/// `[[DefineOwnProperty]]` of `String` objects.
+///
+/// The code units and the `length` can't be changed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-defineownproperty-p-desc
+fn string_define_own_property(
+    obj: &Value,
+    key: &Value,
+    desc: Property,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    match string_own_property(obj, key) {
+        Some(current) => Ok(is_compatible_property_descriptor(
+            false,
+            &desc,
+            Some(&current),
+        )),
+        None => (ORDINARY_INTERNAL_METHODS.define_own_property)(obj, key, desc, ctx),
+    }
+}
+
+/// `[[OwnPropertyKeys]]` of `String` objects.
+///
+/// The indices of the code units come first, then the other keys in the ordinary order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-ownpropertykeys
+fn string_own_property_keys(obj: &Value, ctx: &mut Interpreter) -> Result<Vec<Value>, Value> {
+    let mut keys: Vec<Value> = (0..string_data(obj).len())
+        .map(|index| to_value(index.to_string()))
+        .collect();
+    let own_keys = (ORDINARY_INTERNAL_METHODS.own_property_keys)(obj, ctx)?;
+    let indices = own_keys
+        .iter()
+        .take_while(|key| array_index(key).is_some())
+        .count();
+    let mut own_keys = own_keys.into_iter();
+    keys.extend(own_keys.by_ref().take(indices));
+    keys.push(to_value("length"));
+    keys.extend(own_keys);
+    Ok(keys)
+}
+
 /// Call new string [[Call]]
 ///
+/// Calling `String` as a function converts its argument to a string. Unlike the other conversions,
+/// a symbol is converted to its descriptive string, like `Symbol(description)`.
+///
 /// More information: [ECMAScript reference](https://tc39.es/ecma262/#sec-string-constructor-string-value)
-pub fn call_string(_: &mut Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let arg = match args.get(0) {
-        Some(v) => v.clone(),
-        None => undefined(),
-    };
+pub fn call_string(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    match args.get(0) {
+        None => Ok(to_value(JsString::new())),
+        Some(value) if value.is_symbol() => symbol::to_string(&mut value.clone(), &[], ctx),
+        Some(value) => Ok(to_value(ctx.value_to_js_string(value))),
+    }
+}
 
-    if arg.is_undefined() {
-        return Ok(to_value(""));
+/// The abstract operation `RequireObjectCoercible`, for the `this` value of a `String.prototype` method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-requireobjectcoercible
+fn require_object_coercible(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<(), Value> {
+    if this.is_null_or_undefined() {
+        return Err(ctx.construct_type_error(format!(
+            "String.prototype.{} called on null or undefined",
+            method
+        )));
     }
+    Ok(())
+}
 
-    Ok(to_value(arg.to_string()))
+/// The abstract operation `GetMethod`, for the well-known symbol `Symbol.<name>` of `value`.
+///
+/// Returns `None` if `value` has no such method, and throws a `TypeError` if it's not callable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getmethod
+fn get_symbol_method(
+    value: &Value,
+    name: &str,
+    ctx: &mut Interpreter,
+) -> Result<Option<Value>, Value> {
+    let key = well_known_symbol(&ctx.realm.global_obj, name);
+    let obj = ctx.to_object(value)?;
+    let method = ctx.get_property(&obj, &key)?;
+    if method.is_null_or_undefined() {
+        Ok(None)
+    } else if method.is_function() {
+        Ok(Some(method))
+    } else {
+        Err(ctx.construct_type_error(format!("Symbol.{} of {} is not a function", name, value)))
+    }
 }
 
 /// Get the string value to a primitive string
-pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // Get String from String Object and send it back as a new value
     let primitive_val = this.get_internal_slot("StringData");
-    Ok(to_value(format!("{}", primitive_val)))
+    Ok(to_value(ctx.value_to_js_string(&primitive_val)))
 }
 
 /// `String.prototype.charAt( index )`
@@ -91,31 +270,19 @@ pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultVa
 pub fn char_at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val = ctx.value_to_rust_string(this);
-    let pos: i32 = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let primitive_val = ctx.value_to_js_string(this);
+    let pos = integer_argument(args, 0, ctx)?;
 
-    // Calling .len() on a string would give the wrong result, as they are bytes not the number of
-    // unicode code points
-    // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of
-    // bytes is an O(1) operation.
-    let length = primitive_val.chars().count();
+    // Strings are indexed by UTF-16 code units, so this is an O(1) operation
+    let length = primitive_val.len();
 
     // We should return an empty string is pos is out of range
     if pos >= length as i32 || pos < 0 {
-        return Ok(to_value::<String>(String::new()));
+        return Ok(to_value(JsString::new()));
     }
 
-    Ok(to_value::<char>(
-        primitive_val
-            .chars()
-            .nth(pos as usize)
-            .expect("failed to get value"),
-    ))
+    let pos = pos as usize;
+    Ok(to_value(primitive_val.substring(pos, pos + 1)))
 }
 
 /// `String.prototype.charCodeAt( index )`
@@ -135,29 +302,18 @@ pub fn char_at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resul
 pub fn char_code_at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
-
-    // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-    // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-    let length = primitive_val.chars().count();
-    let pos: i32 = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let primitive_val = ctx.value_to_js_string(this);
+    let pos = integer_argument(args, 0, ctx)?;
 
-    if pos >= length as i32 || pos < 0 {
+    // If there is no element at that index, the result is NaN
+    if pos < 0 {
         return Ok(to_value(NAN));
     }
 
-    let utf16_val = primitive_val
-        .encode_utf16()
-        .nth(pos as usize)
-        .expect("failed to get utf16 value");
-    // If there is no element at that index, the result is NaN
-    // TODO: We currently don't have NaN
-    Ok(to_value(f64::from(utf16_val)))
+    match primitive_val.code_unit_at(pos as usize) {
+        Some(utf16_val) => Ok(to_value(f64::from(utf16_val))),
+        None => Ok(to_value(NAN)),
+    }
 }
 
 /// `String.prototype.concat( str1[, ...strN] )`
@@ -177,14 +333,14 @@ pub fn char_code_at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) ->
 pub fn concat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let mut new_str = ctx.value_to_rust_string(this);
+    let mut new_str = ctx.value_to_js_string(this).to_vec();
 
     for arg in args {
-        let concat_str: String = from_value(arg.clone()).expect("failed to get argument value");
-        new_str.push_str(&concat_str);
+        let concat_str = ctx.value_to_js_string(arg);
+        new_str.extend_from_slice(&concat_str);
     }
 
-    Ok(to_value(new_str))
+    Ok(to_value(JsString::from(new_str)))
 }
 
 /// `String.prototype.repeat( count )`
@@ -201,15 +357,23 @@ pub fn concat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Result
 pub fn repeat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
-    let repeat_times: usize = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
-    Ok(to_value(primitive_val.repeat(repeat_times)))
+    let repeat_times = args.get(0).map_or(0.0, |count| count.to_number());
+    if repeat_times < 0.0 || repeat_times.is_infinite() {
+        return ctx.throw_range_error(format!("Invalid count value: {}", repeat_times));
+    }
+    // NaN is treated as 0
+    let repeat_times = if repeat_times.is_nan() {
+        0
+    } else {
+        repeat_times as usize
+    };
+    if primitive_val.len().saturating_mul(repeat_times) > i32::max_value() as usize {
+        return ctx.throw_range_error("Invalid string length");
+    }
+
+    Ok(to_value(JsString::from(primitive_val.repeat(repeat_times))))
 }
 
 /// `String.prototype.slice( beginIndex [, endIndex] )`
@@ -225,24 +389,16 @@ pub fn repeat(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Result
 pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
-    let start: i32 = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
-    let end: i32 = from_value(
-        args.get(1)
-            .expect("failed to get argument in slice")
-            .clone(),
-    )
-    .expect("failed to parse argument");
+    let start = integer_argument(args, 0, ctx)?;
+    let length = primitive_val.len() as i32;
 
-    // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-    // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-    let length: i32 = primitive_val.chars().count() as i32;
+    // If the end is 'undefined', slice until the end of the string
+    let end = match args.get(1) {
+        Some(end) if !end.is_undefined() => integer_argument(args, 1, ctx)?,
+        _ => length,
+    };
 
     let from: i32 = if start < 0 {
         max(length.wrapping_add(start), 0)
@@ -257,16 +413,10 @@ pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultV
 
     let span = max(to.wrapping_sub(from), 0);
 
-    let mut new_str = String::new();
-    for i in from..from.wrapping_add(span) {
-        new_str.push(
-            primitive_val
-                .chars()
-                .nth(i as usize)
-                .expect("Could not get nth char"),
-        );
-    }
-    Ok(to_value(new_str))
+    Ok(to_value(primitive_val.substring(
+        from as usize,
+        from.wrapping_add(span) as usize,
+    )))
 }
 
 /// `String.prototype.startWith( searchString[, position] )`
@@ -282,25 +432,16 @@ pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultV
 pub fn starts_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
     // TODO: Should throw TypeError if pattern is regular expression
-    let search_string: String = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let search_string = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
 
-    let length: i32 = primitive_val.chars().count() as i32;
-    let search_length: i32 = search_string.chars().count() as i32;
+    let length = primitive_val.len() as i32;
+    let search_length = search_string.len() as i32;
 
-    // If less than 2 args specified, position is 'undefined', defaults to 0
-    let position: i32 = if args.len() < 2 {
-        0
-    } else {
-        from_value(args.get(1).expect("failed to get arg").clone()).expect("failed to get argument")
-    };
+    // An 'undefined' position defaults to 0
+    let position = integer_argument(args, 1, ctx)?;
 
     let start = min(max(position, 0), length);
     let end = start.wrapping_add(search_length);
@@ -309,7 +450,7 @@ pub fn starts_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> R
         Ok(to_value(false))
     } else {
         // Only use the part of the string from "start"
-        let this_string: String = primitive_val.chars().skip(start as usize).collect();
+        let this_string = &primitive_val[start as usize..];
         Ok(to_value(this_string.starts_with(&search_string)))
     }
 }
@@ -327,26 +468,19 @@ pub fn starts_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> R
 pub fn ends_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
     // TODO: Should throw TypeError if search_string is regular expression
-    let search_string: String = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let search_string = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
 
-    let length: i32 = primitive_val.chars().count() as i32;
-    let search_length: i32 = search_string.chars().count() as i32;
+    let length = primitive_val.len() as i32;
+    let search_length = search_string.len() as i32;
 
     // If less than 2 args specified, end_position is 'undefined', defaults to
     // length of this
-    let end_position: i32 = if args.len() < 2 {
-        length
-    } else {
-        from_value(args.get(1).expect("Could not get argumetn").clone())
-            .expect("Could not convert value to i32")
+    let end_position = match args.get(1) {
+        Some(end_position) if !end_position.is_undefined() => integer_argument(args, 1, ctx)?,
+        _ => length,
     };
 
     let end = min(max(end_position, 0), length);
@@ -356,7 +490,7 @@ pub fn ends_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
         Ok(to_value(false))
     } else {
         // Only use the part of the string up to "end"
-        let this_string: String = primitive_val.chars().take(end as usize).collect();
+        let this_string = &primitive_val[..end as usize];
         Ok(to_value(this_string.ends_with(&search_string)))
     }
 }
@@ -374,51 +508,150 @@ pub fn ends_with(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
 pub fn includes(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
     // TODO: Should throw TypeError if search_string is regular expression
-    let search_string: String = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let search_string = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
 
-    let length: i32 = primitive_val.chars().count() as i32;
+    let length = primitive_val.len() as i32;
 
-    // If less than 2 args specified, position is 'undefined', defaults to 0
-    let position: i32 = if args.len() < 2 {
-        0
-    } else {
-        from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("Could not convert value to i32")
-    };
+    // An 'undefined' position defaults to 0
+    let position = integer_argument(args, 1, ctx)?;
 
     let start = min(max(position, 0), length);
 
-    // Take the string from "this" and use only the part of it after "start"
-    let this_string: String = primitive_val.chars().skip(start as usize).collect();
-
-    Ok(to_value(this_string.contains(&search_string)))
+    Ok(to_value(
+        primitive_val
+            .index_of(&search_string, start as usize)
+            .is_some(),
+    ))
 }
 
-/// Return either the string itself or the string of the regex equivalent
-fn get_regex_string(value: &Value) -> String {
-    match value.deref() {
-        ValueData::String(ref body) => body.into(),
-        ValueData::Object(ref obj) => {
-            let slots = &*obj.borrow().internal_slots;
-            if slots.get("RegExpMatcher").is_some() {
-                // first argument is another `RegExp` object, so copy its pattern and flags
-                if let Some(body) = slots.get("OriginalSource") {
-                    return from_value(r#body.clone())
-                        .expect("unable to get body from regex value");
+/// The abstract operation `GetSubstitution`.
+///
+/// Expands the `$` patterns of `replacement` for a match of `matched` at `position` in `string`:
+/// `$$` is a dollar sign, `$&` the match, `` $` `` and `$'` the parts of the string before and after
+/// the match, and `$n` or `$nn` a capture. Any other `$` is kept as is.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+pub(crate) fn get_substitution(
+    matched: &JsString,
+    string: &JsString,
+    position: usize,
+    captures: &[Option<JsString>],
+    replacement: &JsString,
+) -> JsString {
+    let digit = |unit: Option<u16>| match unit {
+        Some(unit) if (u16::from(b'0')..=u16::from(b'9')).contains(&unit) => {
+            Some(usize::from(unit - u16::from(b'0')))
+        }
+        _ => None,
+    };
+
+    let mut result = Vec::with_capacity(replacement.len());
+    let mut i = 0;
+    while i < replacement.len() {
+        let unit = replacement[i];
+        if unit != u16::from(b'$') || i + 1 == replacement.len() {
+            result.push(unit);
+            i += 1;
+            continue;
+        }
+
+        let next = replacement[i + 1];
+        if next == u16::from(b'$') {
+            result.push(unit);
+        } else if next == u16::from(b'&') {
+            result.extend_from_slice(matched);
+        } else if next == u16::from(b'`') {
+            result.extend_from_slice(&string[..position.min(string.len())]);
+        } else if next == u16::from(b'\'') {
+            let tail = (position + matched.len()).min(string.len());
+            result.extend_from_slice(&string[tail..]);
+        } else if let Some(first) = digit(Some(next)) {
+            // A two digits index is preferred, as long as there are enough captures
+            let (index, length) = match digit(replacement.code_unit_at(i + 2)) {
+                Some(second) if (1..=captures.len()).contains(&(first * 10 + second)) => {
+                    (first * 10 + second, 3)
+                }
+                _ => (first, 2),
+            };
+            if (1..=captures.len()).contains(&index) {
+                if let Some(ref capture) = captures[index - 1] {
+                    result.extend_from_slice(capture);
                 }
+                i += length;
+            } else {
+                result.push(unit);
+                i += 1;
             }
-            "undefined".to_string()
+            continue;
+        } else {
+            result.push(unit);
+            i += 1;
+            continue;
         }
-        _ => "undefined".to_string(),
+        i += 2;
+    }
+    JsString::from(result)
+}
+
+/// Replaces the first occurrence of `search_string` in `string`, or all of them if `all` is set.
+///
+/// This is the part of `replace()` and `replaceAll()` which is done when the search value
+/// isn't a `RegExp`, or any other object with a `Symbol.replace` method.
+fn replace_string(
+    string: &JsString,
+    search_string: &JsString,
+    replace_value: &Value,
+    all: bool,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let functional_replace = replace_value.is_function();
+    let replace_string = if functional_replace {
+        JsString::new()
+    } else {
+        ctx.value_to_js_string(replace_value)
+    };
+
+    // An empty search string matches between all the code units
+    let advance_by = max(1, search_string.len());
+    let mut positions = Vec::new();
+    let mut next = string.index_of(search_string, 0);
+    while let Some(position) = next {
+        positions.push(position);
+        next = if all {
+            string.index_of(search_string, position + advance_by)
+        } else {
+            None
+        };
+    }
+
+    let mut result = Vec::with_capacity(string.len());
+    let mut end_of_last_match = 0;
+    for position in positions {
+        let replacement = if functional_replace {
+            let args = [
+                to_value(search_string.clone()),
+                to_value(position),
+                to_value(string.clone()),
+            ];
+            let replacement = ctx.call(replace_value, &mut undefined(), &args)?;
+            ctx.value_to_js_string(&replacement)
+        } else {
+            get_substitution(search_string, string, position, &[], &replace_string)
+        };
+        result.extend_from_slice(&string[end_of_last_match..position]);
+        result.extend_from_slice(&replacement);
+        end_of_last_match = position + search_string.len();
     }
+    if end_of_last_match < string.len() {
+        result.extend_from_slice(&string[end_of_last_match..]);
+    }
+    Ok(to_value(JsString::from(result)))
 }
 
 /// `String.prototype.replace( regexp|substr, newSubstr|function )`
@@ -437,93 +670,62 @@ fn get_regex_string(value: &Value) -> String {
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replace
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
 pub fn replace(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    // TODO: Support Symbol replacer
-    let primitive_val: String = ctx.value_to_rust_string(this);
-    if args.is_empty() {
-        return Ok(to_value(primitive_val));
+    require_object_coercible(this, "replace", ctx)?;
+    let search_value = args.get(0).cloned().unwrap_or_else(undefined);
+    let replace_value = args.get(1).cloned().unwrap_or_else(undefined);
+
+    // A `RegExp` pattern does the replacement itself
+    if !search_value.is_null_or_undefined() {
+        if let Some(replacer) = get_symbol_method(&search_value, "replace", ctx)? {
+            return ctx.call(
+                &replacer,
+                &mut search_value.clone(),
+                &[this.clone(), replace_value],
+            );
+        }
     }
 
-    let regex_body = get_regex_string(args.get(0).expect("Value needed"));
-    let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
-    let mat = re.find(&primitive_val).expect("unable to find value");
-    let caps = re
-        .captures(&primitive_val)
-        .expect("unable to get capture groups from text");
-
-    let replace_value = if args.len() > 1 {
-        // replace_object could be a string or function or not exist at all
-        let replace_object: &Value = args.get(1).expect("second argument expected");
-        match replace_object.deref() {
-            ValueData::String(val) => {
-                // https://tc39.es/ecma262/#table-45
-                let mut result: String = val.to_string();
-                let re = Regex::new(r"\$(\d)").unwrap();
-
-                if val.find("$$").is_some() {
-                    result = val.replace("$$", "$")
-                }
-
-                if val.find("$`").is_some() {
-                    let start_of_match = mat.start();
-                    let slice = &primitive_val[..start_of_match];
-                    result = val.replace("$`", slice);
-                }
-
-                if val.find("$'").is_some() {
-                    let end_of_match = mat.end();
-                    let slice = &primitive_val[end_of_match..];
-                    result = val.replace("$'", slice);
-                }
-
-                if val.find("$&").is_some() {
-                    // get matched value
-                    let matched = caps.get(0).expect("cannot get matched value");
-                    result = val.replace("$&", matched.as_str());
-                }
-
-                // Capture $1, $2, $3 etc
-                if re.is_match(&result) {
-                    let mat_caps = re.captures(&result).unwrap();
-                    let group_str = mat_caps.get(1).unwrap().as_str();
-                    let group_int = group_str.parse::<usize>().unwrap();
-                    result = re
-                        .replace(result.as_str(), caps.get(group_int).unwrap().as_str())
-                        .to_string()
-                }
+    let string = ctx.value_to_js_string(this);
+    let search_string = ctx.value_to_js_string(&search_value);
+    replace_string(&string, &search_string, &replace_value, false, ctx)
+}
 
-                result
-            }
-            ValueData::Object(_) => {
-                // This will return the matched substring first, then captured parenthesized groups later
-                let mut results: Vec<Value> = caps
-                    .iter()
-                    .map(|capture| to_value(capture.unwrap().as_str()))
-                    .collect();
-
-                // Returns the starting byte offset of the match
-                let start = caps
-                    .get(0)
-                    .expect("Unable to get Byte offset from string for match")
-                    .start();
-                results.push(to_value(start));
-                // Push the whole string being examined
-                results.push(to_value(primitive_val.to_string()));
-
-                let result = ctx.call(&replace_object, this, &results).unwrap();
-
-                ctx.value_to_rust_string(&result)
+/// `String.prototype.replaceAll( pattern, replacement )`
+///
+/// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+///
+/// The `pattern` can be a string or a `RegExp`, which must then have the global flag.
+/// The `replacement` can be a string or a function to be called for each match.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+pub fn replace_all(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "replaceAll", ctx)?;
+    let search_value = args.get(0).cloned().unwrap_or_else(undefined);
+    let replace_value = args.get(1).cloned().unwrap_or_else(undefined);
+
+    if !search_value.is_null_or_undefined() {
+        if let Some(flags) = flags_of(&search_value) {
+            if !flags.contains('g') {
+                return ctx.throw_type_error("replaceAll must be called with a global RegExp");
             }
-            _ => "undefined".to_string(),
         }
-    } else {
-        "undefined".to_string()
-    };
+        if let Some(replacer) = get_symbol_method(&search_value, "replace", ctx)? {
+            return ctx.call(
+                &replacer,
+                &mut search_value.clone(),
+                &[this.clone(), replace_value],
+            );
+        }
+    }
 
-    Ok(to_value(primitive_val.replacen(
-        &mat.as_str(),
-        &replace_value,
-        1,
-    )))
+    let string = ctx.value_to_js_string(this);
+    let search_string = ctx.value_to_js_string(&search_value);
+    replace_string(&string, &search_string, &replace_value, true, ctx)
 }
 
 /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -541,41 +743,23 @@ pub fn replace(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resul
 pub fn index_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
     // TODO: Should throw TypeError if search_string is regular expression
-    let search_string: String = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let search_string = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
 
-    let length: i32 = primitive_val.chars().count() as i32;
+    let length = primitive_val.len() as i32;
 
-    // If less than 2 args specified, position is 'undefined', defaults to 0
-    let position: i32 = if args.len() < 2 {
-        0
-    } else {
-        from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("Could not convert value to i32")
-    };
+    // An 'undefined' position defaults to 0
+    let position = integer_argument(args, 1, ctx)?;
 
     let start = min(max(position, 0), length);
 
-    // Here cannot use the &str method "find", because this returns the byte
-    // index: we need to return the char index in the JS String
-    // Instead, iterate over the part we're checking until the slice we're
-    // checking "starts with" the search string
-    for index in start..length {
-        let this_string: String = primitive_val.chars().skip(index as usize).collect();
-        if this_string.starts_with(&search_string) {
-            // Explicitly return early with the index value
-            return Ok(to_value(index));
-        }
+    // The returned index is in UTF-16 code units, -1 if there is no match
+    match primitive_val.index_of(&search_string, start as usize) {
+        Some(index) => Ok(to_value(index as i32)),
+        None => Ok(to_value(-1)),
     }
-    // Didn't find a match, so return -1
-    Ok(to_value(-1))
 }
 
 /// `String.prototype.lastIndexOf( searchValue[, fromIndex] )`
@@ -593,42 +777,25 @@ pub fn index_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resu
 pub fn last_index_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
 
     // TODO: Should throw TypeError if search_string is regular expression
-    let search_string: String = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
+    let search_string = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
 
-    let length: i32 = primitive_val.chars().count() as i32;
-
-    // If less than 2 args specified, position is 'undefined', defaults to 0
-    let position: i32 = if args.len() < 2 {
-        0
+    // A missing or NaN position searches the whole string
+    let position = ctx.to_number(&args.get(1).cloned().unwrap_or_else(undefined))?;
+    let position = if position.is_nan() {
+        f64::INFINITY
     } else {
-        from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("Could not convert value to i32")
+        position.trunc()
     };
 
-    let start = min(max(position, 0), length);
-
-    // Here cannot use the &str method "rfind", because this returns the last
-    // byte index: we need to return the last char index in the JS String
-    // Instead, iterate over the part we're checking keeping track of the higher
-    // index we found that "starts with" the search string
-    let mut highest_index: i32 = -1;
-    for index in start..length {
-        let this_string: String = primitive_val.chars().skip(index as usize).collect();
-        if this_string.starts_with(&search_string) {
-            highest_index = index;
-        }
+    // Only the matches starting at or before "start" are found, -1 if there is none
+    let start = position.max(0.0).min(primitive_val.len() as f64) as usize;
+    match primitive_val.last_index_of(&search_string, start) {
+        Some(index) => Ok(to_value(index as i32)),
+        None => Ok(to_value(-1)),
     }
-
-    // This will still be -1 if no matches were found, else with be >= 0
-    Ok(to_value(highest_index))
 }
 
 /// `String.prototype.match( regexp )`
@@ -643,7 +810,11 @@ pub fn last_index_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) ->
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match
 /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
 pub fn r#match(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let mut re = make_regexp(&mut to_value(Object::default()), &[args[0].clone()], ctx)?;
+    let mut re = make_regexp(
+        &mut to_value(Object::default()),
+        &[args.get(0).cloned().unwrap_or_else(undefined)],
+        ctx,
+    )?;
     regexp_match(&mut re, ctx.value_to_rust_string(this), ctx)
 }
 
@@ -652,9 +823,9 @@ pub fn r#match(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resul
 /// Performs the actual string padding for padStart/End.
 /// <https://tc39.es/ecma262/#sec-stringpad/>
 fn string_pad(
-    primitive: String,
+    primitive: JsString,
     max_length: i32,
-    fill_string: Option<String>,
+    fill_string: Option<JsString>,
     at_start: bool,
 ) -> ResultValue {
     let primitive_length = primitive.len() as i32;
@@ -665,26 +836,27 @@ fn string_pad(
 
     let filler = match fill_string {
         Some(filler) => filler,
-        None => String::from(" "),
+        None => JsString::from(" "),
     };
 
-    if filler == "" {
+    if filler.is_empty() {
         return Ok(to_value(primitive));
     }
 
-    let fill_len = max_length.wrapping_sub(primitive_length);
-    let mut fill_str = String::new();
-
-    while fill_str.len() < fill_len as usize {
-        fill_str.push_str(&filler);
-    }
-    // Cut to size max_length
-    let concat_fill_str: String = fill_str.chars().take(fill_len as usize).collect();
+    let fill_len = max_length.wrapping_sub(primitive_length) as usize;
+    // Repeat the filler and cut it to size max_length, in code units
+    let concat_fill_str: JsString = filler
+        .iter()
+        .copied()
+        .cycle()
+        .take(fill_len)
+        .collect::<Vec<u16>>()
+        .into();
 
     if at_start {
-        Ok(to_value(format!("{}{}", concat_fill_str, &primitive)))
+        Ok(to_value(concat_fill_str.concat(&primitive)))
     } else {
-        Ok(to_value(format!("{}{}", primitive, &concat_fill_str)))
+        Ok(to_value(primitive.concat(&concat_fill_str)))
     }
 }
 
@@ -701,22 +873,15 @@ fn string_pad(
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padend
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd
 pub fn pad_end(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
+    // An undefined maxLength is 0, so the string is returned as is
     if args.is_empty() {
-        return Err(to_value("padEnd requires maxLength argument"));
+        return Ok(to_value(primitive_val));
     }
-    let max_length = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
-    let fill_string: Option<String> = match args.len() {
+    let max_length = integer_argument(args, 0, ctx)?;
+    let fill_string = match args.len() {
         1 => None,
-        _ => Some(
-            from_value(args.get(1).expect("Could not get argument").clone())
-                .expect("Could not convert value to Option<String>"),
-        ),
+        _ => Some(ctx.value_to_js_string(&args.get(1).cloned().unwrap_or_else(undefined))),
     };
 
     string_pad(primitive_val, max_length, fill_string, false)
@@ -735,29 +900,34 @@ pub fn pad_end(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resul
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padstart
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart
 pub fn pad_start(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
+    // An undefined maxLength is 0, so the string is returned as is
     if args.is_empty() {
-        return Err(to_value("padStart requires maxLength argument"));
+        return Ok(to_value(primitive_val));
     }
-    let max_length = from_value(
-        args.get(0)
-            .expect("failed to get argument for String method")
-            .clone(),
-    )
-    .expect("failed to parse argument for String method");
-    let fill_string: Option<String> = match args.len() {
+    let max_length = integer_argument(args, 0, ctx)?;
+    let fill_string = match args.len() {
         1 => None,
-        _ => Some(
-            from_value(args.get(1).expect("Could not get argument").clone())
-                .expect("Could not convert value to Option<String>"),
-        ),
+        _ => Some(ctx.value_to_js_string(&args.get(1).cloned().unwrap_or_else(undefined))),
     };
 
     string_pad(primitive_val, max_length, fill_string, true)
 }
 
+/// Converts the argument `index` of a method to an integer with `ToIntegerOrInfinity`, clamped to
+/// the range of `i32`, which holds every position in a string.
+fn integer_argument(args: &[Value], index: usize, ctx: &mut Interpreter) -> Result<i32, Value> {
+    let number = ctx.to_number(&args.get(index).cloned().unwrap_or_else(undefined))?;
+    if number.is_nan() {
+        return Ok(0);
+    }
+    Ok(number
+        .trunc()
+        .clamp(f64::from(i32::MIN), f64::from(i32::MAX)) as i32)
+}
+
 /// Helper function to check if a `char` is trimmable.
-fn is_trimmable_whitespace(c: char) -> bool {
+pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
     // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
     //
     // Rust uses \p{White_Space} by default, which also includes:
@@ -775,6 +945,34 @@ fn is_trimmable_whitespace(c: char) -> bool {
     }
 }
 
+/// Helper function that removes the trimmable whitespace from the start and/or the end of a string.
+///
+/// All the trimmable whitespace is in the Basic Multilingual Plane, so it's enough to look at
+/// single code units, and surrogates are never trimmed.
+fn trim_code_units(string: &JsString, at_start: bool, at_end: bool) -> JsString {
+    let is_trimmable =
+        |unit: &u16| from_u32(u32::from(*unit)).map_or(false, is_trimmable_whitespace);
+
+    let start = if at_start {
+        string
+            .iter()
+            .position(|unit| !is_trimmable(unit))
+            .unwrap_or_else(|| string.len())
+    } else {
+        0
+    };
+    let end = if at_end {
+        string
+            .iter()
+            .rposition(|unit| !is_trimmable(unit))
+            .map_or(start, |index| index + 1)
+    } else {
+        string.len()
+    };
+
+    string.substring(start, end)
+}
+
 /// String.prototype.trim()
 ///
 /// The `trim()` method removes whitespace from both ends of a string.
@@ -788,8 +986,8 @@ fn is_trimmable_whitespace(c: char) -> bool {
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trim
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trim
 pub fn trim(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let this_str: String = ctx.value_to_rust_string(this);
-    Ok(to_value(this_str.trim_matches(is_trimmable_whitespace)))
+    let this_str = ctx.value_to_js_string(this);
+    Ok(to_value(trim_code_units(&this_str, true, true)))
 }
 
 /// `String.prototype.trimStart()`
@@ -805,10 +1003,8 @@ pub fn trim(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trimstart
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart
 pub fn trim_start(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let this_str: String = ctx.value_to_rust_string(this);
-    Ok(to_value(
-        this_str.trim_start_matches(is_trimmable_whitespace),
-    ))
+    let this_str = ctx.value_to_js_string(this);
+    Ok(to_value(trim_code_units(&this_str, true, false)))
 }
 
 /// String.prototype.trimEnd()
@@ -824,8 +1020,8 @@ pub fn trim_start(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> Resul
 /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trimend
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimEnd
 pub fn trim_end(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let this_str: String = ctx.value_to_rust_string(this);
-    Ok(to_value(this_str.trim_end_matches(is_trimmable_whitespace)))
+    let this_str = ctx.value_to_js_string(this);
+    Ok(to_value(trim_code_units(&this_str, false, true)))
 }
 
 /// `String.prototype.toLowerCase()`
@@ -868,6 +1064,54 @@ pub fn to_uppercase(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> Res
     Ok(to_value(this_str.to_uppercase()))
 }
 
+/// `String.prototype.toLocaleLowerCase( [locales] )`
+///
+/// The `toLocaleLowerCase()` method returns the calling string value converted to lower case,
+/// according to any locale-specific case mappings.
+///
+/// None of the embedded locales has specific case mappings, so this is `toLowerCase()` once the
+/// locales are validated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-string.prototype.tolocalelowercase
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleLowerCase
+pub fn to_locale_lowercase(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "toLocaleLowerCase", ctx)?;
+    let this_str = ctx.value_to_rust_string(this);
+    #[cfg(feature = "intl")]
+    canonicalize_locale_list(&args.get(0).cloned().unwrap_or_else(undefined), ctx)?;
+    #[cfg(not(feature = "intl"))]
+    let _ = args;
+    Ok(to_value(this_str.to_lowercase()))
+}
+
+/// `String.prototype.toLocaleUpperCase( [locales] )`
+///
+/// The `toLocaleUpperCase()` method returns the calling string value converted to upper case,
+/// according to any locale-specific case mappings.
+///
+/// None of the embedded locales has specific case mappings, so this is `toUpperCase()` once the
+/// locales are validated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-string.prototype.tolocaleuppercase
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleUpperCase
+pub fn to_locale_uppercase(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "toLocaleUpperCase", ctx)?;
+    let this_str = ctx.value_to_rust_string(this);
+    #[cfg(feature = "intl")]
+    canonicalize_locale_list(&args.get(0).cloned().unwrap_or_else(undefined), ctx)?;
+    #[cfg(not(feature = "intl"))]
+    let _ = args;
+    Ok(to_value(this_str.to_uppercase()))
+}
+
 /// `String.prototype.substring( indexStart[, indexEnd] )`
 ///
 /// The `substring()` method returns the part of the `string` between the start and end indexes, or to the end of the string.
@@ -881,25 +1125,14 @@ pub fn to_uppercase(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> Res
 pub fn substring(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
     // If no args are specified, start is 'undefined', defaults to 0
-    let start = if args.is_empty() {
-        0
-    } else {
-        from_value(
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .clone(),
-        )
-        .expect("failed to parse argument for String method")
-    };
-    let length: i32 = primitive_val.chars().count() as i32;
-    // If less than 2 args specified, end is the length of the this object converted to a String
-    let end = if args.len() < 2 {
-        length
-    } else {
-        from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("failed to parse argument for String method")
+    let start = integer_argument(args, 0, ctx)?;
+    let length = primitive_val.len() as i32;
+    // If the end is 'undefined', it's the length of the this object converted to a String
+    let end = match args.get(1) {
+        Some(end) if !end.is_undefined() => integer_argument(args, 1, ctx)?,
+        _ => length,
     };
     // Both start and end args replaced by 0 if they were negative
     // or by the length of the String if they were greater
@@ -910,12 +1143,7 @@ pub fn substring(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
     let to = max(final_start, final_end) as usize;
     // Extract the part of the string contained between the start index and the end index
     // where start is guaranteed to be smaller or equals to end
-    let extracted_string: String = primitive_val
-        .chars()
-        .skip(from)
-        .take(to.wrapping_sub(from))
-        .collect();
-    Ok(to_value(extracted_string))
+    Ok(to_value(primitive_val.substring(from, to)))
 }
 
 /// `String.prototype.substr( start[, length] )`
@@ -932,28 +1160,17 @@ pub fn substring(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
 pub fn substr(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
     // First we get it the actual string a private field stored on the object only the engine has access to.
     // Then we convert it into a Rust String by wrapping it in from_value
-    let primitive_val: String = ctx.value_to_rust_string(this);
+    let primitive_val = ctx.value_to_js_string(this);
     // If no args are specified, start is 'undefined', defaults to 0
-    let mut start = if args.is_empty() {
-        0
-    } else {
-        from_value(
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .clone(),
-        )
-        .expect("failed to parse argument for String method")
-    };
-    let length: i32 = primitive_val.chars().count() as i32;
-    // If less than 2 args specified, end is +infinity, the maximum number value.
-    // Using i32::max_value() should be safe because the final length used is at most
+    let mut start = integer_argument(args, 0, ctx)?;
+    let length = primitive_val.len() as i32;
+    // If the length is 'undefined', it's +infinity, the maximum number value.
+    // Using i32::MAX should be safe because the final length used is at most
     // the number of code units from start to the end of the string,
-    // which should always be smaller or equals to both +infinity and i32::max_value
-    let end = if args.len() < 2 {
-        i32::max_value()
-    } else {
-        from_value(args.get(1).expect("Could not get argument").clone())
-            .expect("failed to parse argument for String method")
+    // which should always be smaller or equals to both +infinity and i32::MAX
+    let end = match args.get(1) {
+        Some(end) if !end.is_undefined() => integer_argument(args, 1, ctx)?,
+        _ => i32::MAX,
     };
     // If start is negative it become the number of code units from the end of the string
     if start < 0 {
@@ -965,14 +1182,12 @@ pub fn substr(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Result
     // If length is negative we return an empty string
     // otherwise we extract the part of the string from start and is length code units long
     if result_length <= 0 {
-        Ok(to_value("".to_string()))
+        Ok(to_value(JsString::new()))
     } else {
-        let extracted_string: String = primitive_val
-            .chars()
-            .skip(start as usize)
-            .take(result_length as usize)
-            .collect();
-        Ok(to_value(extracted_string))
+        let start = start as usize;
+        Ok(to_value(
+            primitive_val.substring(start, start + result_length as usize),
+        ))
     }
 }
 
@@ -1022,8 +1237,14 @@ pub fn match_all(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
                     &[undefined(), to_value(String::from("g"))],
                     ctx,
                 )
+            } else if is_regexp(arg) {
+                Ok(arg.clone())
             } else {
-                from_value(arg.clone()).map_err(to_value)
+                make_regexp(
+                    &mut to_value(Object::default()),
+                    &[arg.clone(), to_value(String::from("g"))],
+                    ctx,
+                )
             }
         }
         None => make_regexp(
@@ -1036,6 +1257,362 @@ pub fn match_all(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Res
     regexp_match_all(&mut re, ctx.value_to_rust_string(this))
 }
 
+/// `String.prototype.codePointAt( pos )`
+///
+/// The `codePointAt()` method returns a non-negative integer that is the Unicode code point value
+/// starting at the given position. A surrogate pair is combined into a single code point.
+///
+/// Returns `undefined` if there is no element at `pos`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.codepointat
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/codePointAt
+pub fn code_point_at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "codePointAt", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    let position = ctx.to_integer_or_infinity(&args.get(0).cloned().unwrap_or_else(undefined));
+    if position < 0.0 || position >= string.len() as f64 {
+        return Ok(undefined());
+    }
+
+    match string.code_point_at(position as usize) {
+        Some((code_point, _)) => Ok(to_value(f64::from(code_point))),
+        None => Ok(undefined()),
+    }
+}
+
+/// `String.prototype.at( index )`
+///
+/// The `at()` method returns the UTF-16 code unit at the given index as a string,
+/// counting back from the end of the string for negative indices.
+///
+/// Returns `undefined` if the index is out of range.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.at
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
+pub fn at(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "at", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    let length = string.len() as f64;
+    let relative = ctx.to_integer_or_infinity(&args.get(0).cloned().unwrap_or_else(undefined));
+    let index = if relative >= 0.0 {
+        relative
+    } else {
+        length + relative
+    };
+    if index < 0.0 || index >= length {
+        return Ok(undefined());
+    }
+
+    let index = index as usize;
+    Ok(to_value(string.substring(index, index + 1)))
+}
+
+/// `String.prototype.normalize( [ form ] )`
+///
+/// The `normalize()` method returns the Unicode Normalization Form of the string,
+/// which is one of `"NFC"` (the default), `"NFD"`, `"NFKC"` or `"NFKD"`.
+///
+/// Lone surrogates are not characters, so they are kept as they are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.normalize
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
+pub fn normalize(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "normalize", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    let form = match args.get(0) {
+        Some(form) if !form.is_undefined() => ctx.value_to_rust_string(form),
+        _ => String::from("NFC"),
+    };
+
+    let normalize_chars: fn(&str) -> String = match form.as_str() {
+        "NFC" => |chars| chars.nfc().collect(),
+        "NFD" => |chars| chars.nfd().collect(),
+        "NFKC" => |chars| chars.nfkc().collect(),
+        "NFKD" => |chars| chars.nfkd().collect(),
+        _ => {
+            return ctx.throw_range_error(format!(
+                "The normalization form should be one of NFC, NFD, NFKC, NFKD, not {}",
+                form
+            ))
+        }
+    };
+    Ok(to_value(normalize_js_string(&string, normalize_chars)))
+}
+
+/// Applies `normalize_chars` to each run of characters of `string`, keeping the lone surrogates between them.
+fn normalize_js_string(string: &JsString, normalize_chars: fn(&str) -> String) -> JsString {
+    let mut result = Vec::with_capacity(string.len());
+    let mut chars = String::new();
+    for unit in decode_utf16(string.iter().copied()) {
+        match unit {
+            Ok(c) => chars.push(c),
+            Err(lone_surrogate) => {
+                result.extend(normalize_chars(&chars).encode_utf16());
+                chars.clear();
+                result.push(lone_surrogate.unpaired_surrogate());
+            }
+        }
+    }
+    result.extend(normalize_chars(&chars).encode_utf16());
+    JsString::from(result)
+}
+
+/// `String.prototype.localeCompare( that [, locales [, options]] )`
+///
+/// The `localeCompare()` method returns a negative number, zero or a positive number depending on
+/// whether the string comes before, is equivalent to, or comes after `that` in the sort order of
+/// the locale.
+///
+/// The strings are compared by an `Intl.Collator` with the locales and options.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma402/#sup-String.prototype.localeCompare
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+#[cfg(feature = "intl")]
+pub fn locale_compare(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "localeCompare", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    let that = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
+    collator::locale_compare(
+        &string.to_std_string_lossy(),
+        &that.to_std_string_lossy(),
+        args.get(1..).unwrap_or_default(),
+        ctx,
+    )
+}
+
+/// `String.prototype.localeCompare( compareString )`
+///
+/// The `localeCompare()` method returns a negative number, zero or a positive number depending on
+/// whether the string comes before, is equivalent to, or comes after `compareString`.
+///
+/// There is no locale support, so the strings are compared code unit by code unit, once they are
+/// normalized. This way canonically equivalent strings are equal, as required by the spec.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.localecompare
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+#[cfg(not(feature = "intl"))]
+pub fn locale_compare(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "localeCompare", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    let that = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
+
+    let nfc = |chars: &str| chars.nfc().collect();
+    let ordering = normalize_js_string(&string, nfc).cmp(&normalize_js_string(&that, nfc));
+    Ok(to_value(match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }))
+}
+
+/// `String.prototype.search( regexp )`
+///
+/// The `search()` method executes a search for a match between a regular expression and this `String` object.
+///
+/// Returns the index of the first match, or `-1`. A value which is not a `RegExp` is converted to one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.search
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search
+pub fn search(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "search", ctx)?;
+    let regexp = args.get(0).cloned().unwrap_or_else(undefined);
+    if !regexp.is_null_or_undefined() {
+        if let Some(searcher) = get_symbol_method(&regexp, "search", ctx)? {
+            return ctx.call(&searcher, &mut regexp.clone(), &[this.clone()]);
+        }
+    }
+
+    let string = ctx.value_to_js_string(this);
+    let mut re = make_regexp(&mut to_value(Object::default()), &[regexp], ctx)?;
+    regexp_search(&mut re, &[to_value(string)], ctx)
+}
+
+/// `String.prototype.split( [ separator [, limit ] ] )`
+///
+/// The `split()` method divides a string into an ordered list of substrings, puts these substrings
+/// into an array, and returns the array. The division is done by searching for a pattern,
+/// which can be a string or a `RegExp`.
+///
+/// An empty separator splits the string into its UTF-16 code units.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype.split
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
+pub fn split(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "split", ctx)?;
+    let separator = args.get(0).cloned().unwrap_or_else(undefined);
+    let limit = args.get(1).cloned().unwrap_or_else(undefined);
+
+    // A `RegExp` separator does the split itself
+    if !separator.is_null_or_undefined() {
+        if let Some(splitter) = get_symbol_method(&separator, "split", ctx)? {
+            return ctx.call(&splitter, &mut separator.clone(), &[this.clone(), limit]);
+        }
+    }
+
+    let string = ctx.value_to_js_string(this);
+    let limit = if limit.is_undefined() {
+        u32::MAX as usize
+    } else {
+        ctx.to_uint32(&limit) as usize
+    };
+    let separator_string = ctx.value_to_js_string(&separator);
+
+    let mut parts = Vec::new();
+    if limit == 0 {
+        // Nothing to split
+    } else if separator.is_undefined() {
+        parts.push(to_value(string));
+    } else if separator_string.is_empty() {
+        parts.extend(
+            string
+                .iter()
+                .take(limit)
+                .map(|unit| to_value(JsString::from_utf16(&[*unit]))),
+        );
+    } else {
+        let mut part_start = 0;
+        while let Some(position) = string.index_of(&separator_string, part_start) {
+            parts.push(to_value(string.substring(part_start, position)));
+            if parts.len() == limit {
+                break;
+            }
+            part_start = position + separator_string.len();
+        }
+        if parts.len() < limit {
+            parts.push(to_value(string.substring(part_start, string.len())));
+        }
+    }
+
+    let array = new_array(ctx)?;
+    construct_array(&array, &parts)
+}
+
+/// `String.prototype[ @@iterator ]()`
+///
+/// Returns an iterator over the code points of the string, each of them returned as a string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.prototype-@@iterator
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/@@iterator
+pub fn iterator(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    require_object_coercible(this, "[Symbol.iterator]", ctx)?;
+    let string = ctx.value_to_js_string(this);
+    Ok(iterator::create_string_iterator(ctx, string))
+}
+
+/// `String.fromCharCode( ...codeUnits )`
+///
+/// The static `String.fromCharCode()` method returns a string created from the specified sequence of
+/// UTF-16 code units. Each argument is converted to a number, modulo 2^16.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.fromcharcode
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode
+pub fn from_char_code(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let code_units: Vec<u16> = args
+        .iter()
+        .map(|code_unit| ctx.to_uint32(code_unit) as u16)
+        .collect();
+    Ok(to_value(JsString::from(code_units)))
+}
+
+/// `String.fromCodePoint( ...codePoints )`
+///
+/// The static `String.fromCodePoint()` method returns a string created from the specified sequence of
+/// code points. Code points outside of the Basic Multilingual Plane are encoded as surrogate pairs.
+///
+/// Throws a `RangeError` if an argument is not an integer between `0` and `0x10FFFF`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.fromcodepoint
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint
+pub fn from_code_point(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let mut code_units = Vec::with_capacity(args.len());
+    for arg in args {
+        let code_point = ctx.value_to_rust_number(arg);
+        if code_point.fract() != 0.0 || !(0.0..=1_114_111.0).contains(&code_point) {
+            return ctx.throw_range_error(format!("Invalid code point {}", arg));
+        }
+
+        // Surrogates are not chars, but they are valid code points for JavaScript strings
+        let code_point = code_point as u32;
+        match from_u32(code_point) {
+            Some(c) => code_units.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            None => code_units.push(code_point as u16),
+        }
+    }
+    Ok(to_value(JsString::from(code_units)))
+}
+
+/// `String.raw( template, ...substitutions )`
+///
+/// The static `String.raw()` method interleaves the strings of `template.raw` with the substitutions.
+/// It's the tag function of raw template literals.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string.raw
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+pub fn raw(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let cooked = ctx.to_object(&args.get(0).cloned().unwrap_or_else(undefined))?;
+    let raw = ctx.get_property(&cooked, &to_value("raw"))?;
+    let literals = ctx.to_object(&raw)?;
+    let literal_count = length_of_array_like(&literals, ctx)?;
+    let substitutions = args.get(1..).unwrap_or(&[]);
+
+    let mut result = Vec::new();
+    for index in 0..literal_count {
+        let literal = ctx.get_property(&literals, &to_value(index.to_string()))?;
+        result.extend_from_slice(&ctx.value_to_js_string(&literal));
+        if index + 1 == literal_count {
+            break;
+        }
+        if let Some(substitution) = substitutions.get(index) {
+            result.extend_from_slice(&ctx.value_to_js_string(substitution));
+        }
+    }
+    Ok(to_value(JsString::from(result)))
+}
+
 /// Create a new `String` object.
 pub fn create(global: &Value) -> Value {
     // Create prototype
@@ -1059,15 +1636,31 @@ pub fn create(global: &Value) -> Value {
     make_builtin_fn!(pad_start, named "padStart", with length 1, of prototype);
     make_builtin_fn!(trim, named "trim", of prototype);
     make_builtin_fn!(trim_start, named "trimStart", of prototype);
+    make_builtin_fn!(trim_end, named "trimEnd", of prototype);
     make_builtin_fn!(to_lowercase, named "toLowerCase", of prototype);
     make_builtin_fn!(to_uppercase, named "toUpperCase", of prototype);
+    make_builtin_fn!(to_locale_lowercase, named "toLocaleLowerCase", of prototype);
+    make_builtin_fn!(to_locale_uppercase, named "toLocaleUpperCase", of prototype);
     make_builtin_fn!(substring, named "substring", with length 2, of prototype);
     make_builtin_fn!(substr, named "substr", with length 2, of prototype);
     make_builtin_fn!(value_of, named "valueOf", of prototype);
     make_builtin_fn!(match_all, named "matchAll", with length 1, of prototype);
     make_builtin_fn!(replace, named "replace", with length 2, of prototype);
-
-    make_constructor_fn!(make_string, call_string, global, prototype)
+    make_builtin_fn!(replace_all, named "replaceAll", with length 2, of prototype);
+    make_builtin_fn!(code_point_at, named "codePointAt", with length 1, of prototype);
+    make_builtin_fn!(at, named "at", with length 1, of prototype);
+    make_builtin_fn!(normalize, named "normalize", of prototype);
+    make_builtin_fn!(locale_compare, named "localeCompare", with length 1, of prototype);
+    make_builtin_fn!(search, named "search", with length 1, of prototype);
+    make_builtin_fn!(split, named "split", with length 2, of prototype);
+    make_builtin_fn!(iterator, keyed well_known_symbol(global, "iterator"), of prototype);
+    prototype.set_internal_slot(ITERATOR_PROTOTYPE, iterator::create_prototype(global));
+
+    let string = make_constructor_fn!(make_string, call_string, global, prototype);
+    make_builtin_fn!(from_char_code, named "fromCharCode", with length 1, of string);
+    make_builtin_fn!(from_code_point, named "fromCodePoint", with length 1, of string);
+    make_builtin_fn!(raw, named "raw", with length 1, of string);
+    string
 }
 
 /// Initialise the `String` object on the global object.
@@ -304,3 +304,455 @@ fn test_match() {
     );
     assert_eq!(forward(&mut engine, "result4[0]"), "B");
 }
+
+#[test]
+fn utf16_code_unit_indexing() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var emoji = 'a😀b';
+        var emojiObject = new String('a😀b');
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "emoji.length"), "4");
+    assert_eq!(forward(&mut engine, "emoji.charAt(3)"), "b");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(1)"), "55357");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(2)"), "56832");
+    assert_eq!(forward(&mut engine, "emoji.slice(1, 3)"), "😀");
+    assert_eq!(forward(&mut engine, "emoji.slice(-1, 4)"), "b");
+    assert_eq!(forward(&mut engine, "emoji.substring(3, 1)"), "😀");
+    assert_eq!(forward(&mut engine, "emoji.substr(1, 2)"), "😀");
+    assert_eq!(forward(&mut engine, "emoji.indexOf('b')"), "3");
+    assert_eq!(forward(&mut engine, "emoji.includes('b', 3)"), "true");
+    assert_eq!(forward(&mut engine, "emojiObject.charAt(3)"), "b");
+    assert_eq!(forward(&mut engine, "emojiObject.charCodeAt(2)"), "56832");
+}
+
+#[test]
+fn lone_surrogates() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var high = '\uD83D';
+        var low = '\uDE00';
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "high.length"), "1");
+    assert_eq!(forward(&mut engine, "high.charCodeAt(0)"), "55357");
+    assert_eq!(forward(&mut engine, "high === low"), "false");
+    assert_eq!(forward(&mut engine, "high + low === '😀'"), "true");
+    assert_eq!(forward(&mut engine, "'😀'.charAt(0) === high"), "true");
+    assert_eq!(forward(&mut engine, "(high + low).length"), "2");
+}
+
+#[test]
+fn lone_surrogate_property_keys() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var o = {};
+        o['\uD800'] = 1;
+        var before = o['\uD801'];
+        o['\uD801'] = 2;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "before"), "undefined");
+    assert_eq!(forward(&mut engine, "o['\\uD800']"), "1");
+    assert_eq!(forward(&mut engine, "o['\\uD801']"), "2");
+    assert_eq!(forward(&mut engine, "Object.keys(o).length"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.keys(o)[0].charCodeAt(0) + Object.keys(o)[1].charCodeAt(0)"
+        ),
+        "110593"
+    );
+    assert_eq!(forward(&mut engine, "o.hasOwnProperty('\\uD801')"), "true");
+    assert_eq!(forward(&mut engine, "o.hasOwnProperty('\\uDBFF')"), "false");
+    assert_eq!(forward(&mut engine, "delete o['\\uD800']"), "true");
+    assert_eq!(forward(&mut engine, "o['\\uD801']"), "2");
+}
+
+#[test]
+fn last_index_of() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('b')"), "4");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('b', 2)"), "1");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('b', 4)"), "4");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('b', 0)"), "-1");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('a', -5)"), "0");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('b', NaN)"), "4");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('c', 100)"), "5");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('')"), "6");
+    assert_eq!(forward(&mut engine, "'abcabc'.lastIndexOf('', 2)"), "2");
+    assert_eq!(forward(&mut engine, "'a😀b😀'.lastIndexOf('😀', 3)"), "1");
+}
+
+#[test]
+fn call_string_converts() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "String()"), "");
+    assert_eq!(forward(&mut engine, "typeof String(5)"), "string");
+    assert_eq!(forward(&mut engine, "String(null)"), "null");
+    assert_eq!(forward(&mut engine, "String(undefined)"), "undefined");
+    assert_eq!(forward(&mut engine, "String([1, 2, 3])"), "1,2,3");
+    assert_eq!(forward(&mut engine, "String(Symbol('a'))"), "Symbol(a)");
+    assert_eq!(forward(&mut engine, "typeof new String('a')"), "object");
+}
+
+#[test]
+fn split() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'a,b,,c'.split(',').length"), "4");
+    assert_eq!(
+        forward(&mut engine, "'a,b,c'.split(',', 2).join('|')"),
+        "a|b"
+    );
+    assert_eq!(forward(&mut engine, "'a,b,c'.split(',', 0).length"), "0");
+    assert_eq!(forward(&mut engine, "'abc'.split().length"), "1");
+    assert_eq!(forward(&mut engine, "'abc'.split()[0]"), "abc");
+    assert_eq!(forward(&mut engine, "'abc'.split('').join('|')"), "a|b|c");
+    assert_eq!(forward(&mut engine, "'a😀'.split('').length"), "3");
+    assert_eq!(forward(&mut engine, "''.split(',').length"), "1");
+    assert_eq!(forward(&mut engine, "''.split('').length"), "0");
+    assert_eq!(
+        forward(&mut engine, "'a1b22c'.split(/\\d+/).join('|')"),
+        "a|b|c"
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b2c'.split(/(\\d)/).join('|')"),
+        "a|1|b|2|c"
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b2c'.split(/(\\d)/, 2).join('|')"),
+        "a|1"
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.split(/(?:)/).join('|')"),
+        "a|b|c"
+    );
+    assert_eq!(forward(&mut engine, "''.split(/x/).length"), "1");
+    assert_eq!(forward(&mut engine, "''.split(/(?:)/).length"), "0");
+}
+
+#[test]
+fn split_dispatches_to_symbol_split() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var separator = {};
+        separator[Symbol.split] = function (string, limit) {
+            return string + ':' + limit;
+        };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "'abc'.split(separator, 3)"), "abc:3");
+    assert_eq!(
+        forward(&mut engine, "typeof RegExp.prototype[Symbol.split]"),
+        "function"
+    );
+}
+
+#[test]
+fn replace_substitutions() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'abc'.replace('b', '[$&]')"), "a[b]c");
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace('b', '[$`|$\\']')"),
+        "a[a|c]c"
+    );
+    assert_eq!(forward(&mut engine, "'abc'.replace('b', '$$')"), "a$c");
+    assert_eq!(forward(&mut engine, "'abc'.replace('b', '$1')"), "a$1c");
+    assert_eq!(
+        forward(&mut engine, "'x-y'.replace(/(\\w)-(\\w)/, '$2-$1')"),
+        "y-x"
+    );
+    assert_eq!(forward(&mut engine, "'aaa'.replace(/a/g, 'b')"), "bbb");
+    assert_eq!(forward(&mut engine, "'a.b'.replace('.', '-')"), "a-b");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "'abc'.replace('b', function (match, position, string) { return match + position + string; })"
+        ),
+        "ab1abcc"
+    );
+}
+
+#[test]
+fn replace_all() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "'aXbXc'.replaceAll('X', '-')"),
+        "a-b-c"
+    );
+    assert_eq!(forward(&mut engine, "'aaa'.replaceAll('aa', 'b')"), "ba");
+    assert_eq!(forward(&mut engine, "'abc'.replaceAll('', '_')"), "_a_b_c_");
+    assert_eq!(
+        forward(&mut engine, "'a.b.c'.replaceAll('.', '$&$&')"),
+        "a..b..c"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "'a1b2'.replaceAll(/\\d/g, function (d) { return d * 2; })"
+        ),
+        "a2b4"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.replaceAll(/a/, 'b') } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var replacer = {}; replacer[Symbol.replace] = function (s, r) { return r + s; }; 'a'.replaceAll(replacer, 'b')"
+        ),
+        "ba"
+    );
+}
+
+#[test]
+fn search() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'hello world'.search(/o/)"), "4");
+    assert_eq!(forward(&mut engine, "'hello'.search('l+')"), "2");
+    assert_eq!(forward(&mut engine, "'hello'.search(/z/)"), "-1");
+    assert_eq!(forward(&mut engine, "'😀a'.search(/a/)"), "2");
+    assert_eq!(forward(&mut engine, "'abc'.search()"), "0");
+}
+
+#[test]
+fn code_point_at() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'a😀b'.codePointAt(0)"), "97");
+    assert_eq!(forward(&mut engine, "'a😀b'.codePointAt(1)"), "128512");
+    assert_eq!(forward(&mut engine, "'a😀b'.codePointAt(2)"), "56832");
+    assert_eq!(forward(&mut engine, "'\\uD83D'.codePointAt(0)"), "55357");
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(-1)"), "undefined");
+}
+
+#[test]
+fn at() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'abc'.at(0)"), "a");
+    assert_eq!(forward(&mut engine, "'abc'.at(-1)"), "c");
+    assert_eq!(forward(&mut engine, "'abc'.at(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.at(-4)"), "undefined");
+    assert_eq!(forward(&mut engine, "'😀'.at(0).length"), "1");
+}
+
+#[test]
+fn normalize() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var composed = 'Å';
+        var decomposed = 'Å';
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "decomposed.normalize() === composed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "composed.normalize('NFD') === decomposed"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "'\\uFB01'.normalize('NFKD')"), "fi");
+    assert_eq!(
+        forward(&mut engine, "'\\uFB01'.normalize('NFC')"),
+        "\u{FB01}"
+    );
+    assert_eq!(
+        forward(&mut engine, "('\\uD83D' + decomposed).normalize().length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.normalize('nfc') } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn locale_compare() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut engine, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('a')"), "0");
+    assert_eq!(
+        forward(&mut engine, "'\\u00C5'.localeCompare('A\\u030A')"),
+        "0"
+    );
+}
+
+#[test]
+fn trim_end() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "' \\t a \\n'.trimEnd()"), " \t a");
+}
+
+#[test]
+fn iterator() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var iterator = 'a😀\uD83D'[Symbol.iterator]();
+        var first = iterator.next();
+        var second = iterator.next();
+        var third = iterator.next();
+        var last = iterator.next();
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "first.value"), "a");
+    assert_eq!(forward(&mut engine, "second.value === '😀'"), "true");
+    assert_eq!(forward(&mut engine, "third.value === '\\uD83D'"), "true");
+    assert_eq!(forward(&mut engine, "third.done"), "false");
+    assert_eq!(forward(&mut engine, "last.done"), "true");
+    assert_eq!(forward(&mut engine, "iterator.next().done"), "true");
+    assert_eq!(forward(&mut engine, "Array.from('a😀b').length"), "3");
+}
+
+#[test]
+fn from_char_code() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "String.fromCharCode(72, 105)"), "Hi");
+    assert_eq!(forward(&mut engine, "String.fromCharCode(65601)"), "A");
+    assert_eq!(forward(&mut engine, "String.fromCharCode()"), "");
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(0xD83D, 0xDE00) === '😀'"),
+        "true"
+    );
+}
+
+#[test]
+fn from_code_point() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "String.fromCodePoint(72, 105)"), "Hi");
+    assert_eq!(
+        forward(&mut engine, "String.fromCodePoint(128512) === '😀'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "String.fromCodePoint(0xD83D).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(-1) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(1.5) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(0x110000) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn raw() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "String.raw({ raw: ['a', 'b', 'c'] }, 1, 2, 3)"),
+        "a1b2c"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "String.raw({ raw: { length: 2, 0: 'x', 1: 'y' } }, '-', '+')"
+        ),
+        "x-y"
+    );
+    assert_eq!(forward(&mut engine, "String.raw({ raw: [] }, 1)"), "");
+    assert_eq!(
+        forward(&mut engine, "try { String.raw() } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn integer_arguments() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    forward(
+        &mut engine,
+        "var one = { valueOf: function () { return 1 } };",
+    );
+    let scenarios = [
+        ("'abc'.charAt(one)", "b"),
+        ("'abc'.charCodeAt(one)", "98"),
+        ("'abc'.slice(one)", "bc"),
+        ("'abc'.slice(0, one)", "a"),
+        ("'abc'.startsWith('b', one)", "true"),
+        ("'abc'.endsWith('a', one)", "true"),
+        ("'abc'.includes('a', one)", "false"),
+        ("'abc'.indexOf('c', one)", "2"),
+        ("'abc'.substring(one)", "bc"),
+        ("'abc'.substr(one, one)", "b"),
+        ("'abc'.padEnd(4, 'x')", "abcx"),
+        ("'abc'.charAt(1.7)", "b"),
+        ("'abc'.slice(-1e20, 1e20)", "abc"),
+        ("'abc'.charAt(NaN)", "a"),
+    ];
+    for (src, expected) in scenarios.iter() {
+        assert_eq!(forward(&mut engine, src), *expected, "{}", src);
+    }
+    for method in &[
+        "charAt",
+        "charCodeAt",
+        "slice",
+        "substring",
+        "substr",
+        "padStart",
+    ] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!(
+                    "try {{ 'abc'.{}(Symbol()) }} catch (e) {{ e.name }}",
+                    method
+                )
+            ),
+            "TypeError",
+            "{}",
+            method
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'abc'.startsWith('a', Symbol()) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
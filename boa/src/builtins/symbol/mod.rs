@@ -21,15 +21,41 @@ mod tests;
 use crate::{
     builtins::{
         object::{
-            internal_methods_trait::ObjectInternalMethods, Object, ObjectKind, INSTANCE_PROTOTYPE,
-            PROTOTYPE,
+            internal_methods::symbol_id, internal_methods_trait::ObjectInternalMethods, Object,
+            ObjectKind, INSTANCE_PROTOTYPE, PROTOTYPE,
         },
+        property::Property,
         value::{to_value, undefined, ResultValue, Value, ValueData},
     },
     exec::Interpreter,
 };
 use gc::{Gc, GcCell};
-use rand::random;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The id of the next symbol.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The names of the well-known symbols, which are properties of the `Symbol` constructor.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-well-known-symbols
+const WELL_KNOWN_SYMBOLS: [&str; 13] = [
+    "asyncIterator",
+    "hasInstance",
+    "isConcatSpreadable",
+    "iterator",
+    "match",
+    "matchAll",
+    "replace",
+    "search",
+    "species",
+    "split",
+    "toPrimitive",
+    "toStringTag",
+    "unscopables",
+];
 
 /// Creates Symbol instances.
 ///
@@ -42,33 +68,136 @@ use rand::random;
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-symbol-description
 pub fn call_symbol(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    // Set description which should either be undefined or a string
+    let description = match args.get(0) {
+        Some(value) if !value.is_undefined() => to_value(ctx.value_to_js_string(value)),
+        _ => undefined(),
+    };
+    Ok(new_symbol(symbol_prototype(ctx), description))
+}
+
+/// `new Symbol()`, which throws a `TypeError` since symbols are primitives.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol-description
+pub fn construct_symbol(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Symbol is not a constructor")
+}
+
+/// Gets `Symbol.prototype`.
+fn symbol_prototype(ctx: &Interpreter) -> Value {
+    ctx.realm
+        .global_obj
+        .get_field_slice("Symbol")
+        .get_field_slice(PROTOTYPE)
+}
+
+/// Creates a new symbol value with the given prototype and description.
+///
+/// Every symbol gets a new id, so it's different from all the other symbols.
+fn new_symbol(prototype: Value, description: Value) -> Value {
     // From an implementation and specificaition perspective Symbols are similar to Objects.
     // They have internal slots to hold the SymbolData and Description, they also have methods and a prototype.
     // So we start by creating an Object
-    // TODO: Set prototype to Symbol.prototype (by changing to Object::create(), use interpreter to get Symbol.prototype)
     let mut sym_instance = Object::default();
-    sym_instance.kind = ObjectKind::Symbol;
-
-    // Set description which should either be undefined or a string
-    let desc_string = match args.get(0) {
-        Some(value) => to_value(value.to_string()),
-        None => undefined(),
-    };
+    sym_instance.kind = ObjectKind::Symbol(NEXT_ID.fetch_add(1, Ordering::Relaxed));
 
-    sym_instance.set_internal_slot("Description", desc_string);
-    sym_instance.set_internal_slot("SymbolData", to_value(random::<i32>()));
+    sym_instance.set_internal_slot("Description", description);
 
     // Set __proto__ internal slot
-    let proto = ctx
-        .realm
-        .global_obj
-        .get_field_slice("Symbol")
-        .get_field_slice(PROTOTYPE);
-    sym_instance.set_internal_slot(INSTANCE_PROTOTYPE, proto);
+    sym_instance.set_internal_slot(INSTANCE_PROTOTYPE, prototype);
 
-    Ok(Gc::new(ValueData::Symbol(Box::new(GcCell::new(
-        sym_instance,
-    )))))
+    Gc::new(ValueData::Symbol(Box::new(GcCell::new(sym_instance))))
+}
+
+/// Gets the well-known symbol `Symbol.<name>`, like `Symbol.iterator`.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-well-known-symbols
+pub(crate) fn well_known_symbol(global: &Value, name: &str) -> Value {
+    global.get_field_slice("Symbol").get_field_slice(name)
+}
+
+/// Gets the symbol value of `this`, or throws a `TypeError` if it's not a symbol.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-thissymbolvalue
+fn this_symbol_value(this: &Value, method: &str, ctx: &mut Interpreter) -> ResultValue {
+    if this.is_symbol() {
+        Ok(this.clone())
+    } else {
+        ctx.throw_type_error(format!(
+            "Symbol.prototype.{} requires that 'this' be a Symbol",
+            method
+        ))
+    }
+}
+
+/// `Symbol.for( key )`
+///
+/// Gets the symbol of the global registry with the given key, creating it if needed.
+///
+/// More information:
+/// - [MDN documentation][mdn]
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+pub fn for_(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let key = ctx.value_to_js_string(&args.get(0).cloned().unwrap_or_else(undefined));
+    if let Some(symbol) = ctx.symbol_registry().get(&key) {
+        return Ok(symbol.clone());
+    }
+
+    let symbol = new_symbol(symbol_prototype(ctx), to_value(key.clone()));
+    ctx.symbol_registry().insert(key, symbol.clone());
+    Ok(symbol)
+}
+
+/// `Symbol.keyFor( sym )`
+///
+/// Gets the key of a symbol in the global registry, or `undefined` if it isn't registered.
+///
+/// More information:
+/// - [MDN documentation][mdn]
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+pub fn key_for(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let symbol = args.get(0).cloned().unwrap_or_else(undefined);
+    if !symbol.is_symbol() {
+        return ctx.throw_type_error(format!("{} is not a symbol", symbol));
+    }
+
+    let id = symbol_id(&symbol);
+    let key = ctx
+        .symbol_registry()
+        .iter()
+        .find(|(_, registered)| symbol_id(registered) == id)
+        .map(|(key, _)| key.clone());
+    Ok(key.map_or_else(undefined, to_value))
+}
+
+/// `get Symbol.prototype.description`
+///
+/// Gets the description of the symbol, or `undefined` if it was created without one.
+///
+/// More information:
+/// - [MDN documentation][mdn]
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.description
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/description
+pub fn get_description(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let symbol = this_symbol_value(this, "description", ctx)?;
+    Ok(symbol.get_internal_slot("Description"))
 }
 
 /// `Symbol.prototype.toString()`
@@ -81,10 +210,29 @@ pub fn call_symbol(_: &mut Value, args: &[Value], ctx: &mut Interpreter) -> Resu
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.tostring
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString
-pub fn to_string(this: &mut Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    let s: Value = this.get_internal_slot("Description");
-    let full_string = format!(r#"Symbol({})"#, s.to_string());
-    Ok(to_value(full_string))
+pub fn to_string(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let symbol = this_symbol_value(this, "toString", ctx)?;
+    let description = symbol.get_internal_slot("Description");
+    let description = if description.is_undefined() {
+        String::new()
+    } else {
+        description.to_string()
+    };
+    Ok(to_value(format!("Symbol({})", description)))
+}
+
+/// `Symbol.prototype.valueOf()`
+///
+/// Returns the symbol itself.
+///
+/// More information:
+/// - [MDN documentation][mdn]
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.valueof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf
+pub fn value_of(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this_symbol_value(this, "valueOf", ctx)
 }
 
 /// Create a new `Symbol` object.
@@ -92,7 +240,27 @@ pub fn create(global: &Value) -> Value {
     // Create prototype object
     let prototype = ValueData::new_obj(Some(global));
     make_builtin_fn!(to_string, named "toString", of prototype);
-    make_constructor_fn!(call_symbol, call_symbol, global, prototype)
+    make_builtin_fn!(value_of, named "valueOf", of prototype);
+    make_getter_fn!(get_description, named "description", of prototype);
+    let symbol = make_constructor_fn!(construct_symbol, call_symbol, global, prototype);
+    make_builtin_fn!(for_, named "for", with length 1, of symbol);
+    make_builtin_fn!(key_for, named "keyFor", with length 1, of symbol);
+
+    // The well-known symbols are shared by all the builtins, they can't be changed
+    let prototype = symbol.get_field_slice(PROTOTYPE);
+    for name in WELL_KNOWN_SYMBOLS.iter() {
+        let description = to_value(format!("Symbol.{}", name));
+        let well_known = new_symbol(prototype.clone(), description);
+        symbol.set_prop_slice(
+            name,
+            Property::default()
+                .value(well_known)
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+    }
+    symbol
 }
 
 /// Initialise the `Symbol` object on the global object.
@@ -33,3 +33,101 @@ fn print_symbol_expect_description() {
     let sym = forward_val(&mut engine, "sym.toString()").unwrap();
     assert_eq!(sym.to_string(), "Symbol(Hello)");
 }
+
+#[test]
+fn symbols_are_unique() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var a = Symbol("same");
+        var b = Symbol("same");
+        var obj = {};
+        obj[a] = 1;
+        obj[b] = 2;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "a === b"), "false");
+    assert_eq!(forward(&mut engine, "a === a"), "true");
+    assert_eq!(forward(&mut engine, "obj[a]"), "1");
+    assert_eq!(forward(&mut engine, "obj[b]"), "2");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj).length"),
+        "2"
+    );
+}
+
+#[test]
+fn symbol_registry() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "Symbol.for('app') === Symbol.for('app')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.for('app') === Symbol('app')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor(Symbol.for('app'))"),
+        "app"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor(Symbol('app'))"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Symbol.keyFor('app') } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn description_and_well_known_symbols() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(forward(&mut engine, "Symbol('desc').description"), "desc");
+    assert_eq!(forward(&mut engine, "Symbol().description"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "Symbol(undefined).toString()"),
+        "Symbol()"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.asyncIterator.description"),
+        "Symbol.asyncIterator"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.toPrimitive.toString()"),
+        "Symbol(Symbol.toPrimitive)"
+    );
+    assert_eq!(forward(&mut engine, "typeof Symbol.hasInstance"), "symbol");
+    assert_eq!(forward(&mut engine, "typeof Symbol.unscopables"), "symbol");
+    assert_eq!(
+        forward(&mut engine, "Symbol.match === Symbol.matchAll"),
+        "false"
+    );
+}
+
+#[test]
+fn symbol_is_not_a_constructor() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "try { new Symbol() } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Symbol.prototype.toString.call('a') } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
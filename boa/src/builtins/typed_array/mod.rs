@@ -0,0 +1,1281 @@
+//! This module implements the `%TypedArray%` intrinsic object and the typed array constructors.
+//!
+//! A typed array is an array-like view of the binary data of an `ArrayBuffer`,
+//! whose elements all have the same numeric type. The constructors (`Int8Array`, `Uint8Array`...)
+//! inherit from the `%TypedArray%` intrinsic, which holds the methods shared by all of them.
+//!
+//! Typed arrays are integer-indexed exotic objects: the properties with a numeric key
+//! are the elements of the array, stored in the buffer, and never ordinary properties.
+//! Elements are stored in little-endian order, whatever the platform the engine runs on.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array::merge_sort,
+        array_buffer::{self, with_array_buffer_bytes},
+        function::NativeFunctionData,
+        object::{
+            InternalObjectMethods, Object, ObjectInternalMethods, ObjectKind,
+            ORDINARY_INTERNAL_METHODS, PROTOTYPE,
+        },
+        property::Property,
+        value::{same_value, to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::cmp::Ordering;
+
+/// The element types of typed arrays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+}
+
+impl Finalize for TypedArrayKind {}
+
+/// This is indeed safe, the kind of a typed array doesn't hold any GC'd objects.
+unsafe impl Trace for TypedArrayKind {
+    unsafe_empty_trace!();
+}
+
+impl TypedArrayKind {
+    /// All the element types, in the order of the table of the spec.
+    pub const ALL: [Self; 9] = [
+        Self::Int8,
+        Self::Uint8,
+        Self::Uint8Clamped,
+        Self::Int16,
+        Self::Uint16,
+        Self::Int32,
+        Self::Uint32,
+        Self::Float32,
+        Self::Float64,
+    ];
+
+    /// The name of the constructor of the typed arrays of this kind.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+        }
+    }
+
+    /// The size of an element in bytes.
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 => 8,
+        }
+    }
+
+    /// Reads an element from the first bytes of `bytes`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn read(self, bytes: &[u8], little_endian: bool) -> f64 {
+        let size = self.element_size();
+        let mut raw = [0; 8];
+        raw[..size].copy_from_slice(&bytes[..size]);
+        if !little_endian {
+            raw[..size].reverse();
+        }
+
+        let [b0, b1, b2, b3, ..] = raw;
+        match self {
+            Self::Int8 => f64::from(b0 as i8),
+            Self::Uint8 | Self::Uint8Clamped => f64::from(b0),
+            Self::Int16 => f64::from(i16::from_le_bytes([b0, b1])),
+            Self::Uint16 => f64::from(u16::from_le_bytes([b0, b1])),
+            Self::Int32 => f64::from(i32::from_le_bytes([b0, b1, b2, b3])),
+            Self::Uint32 => f64::from(u32::from_le_bytes([b0, b1, b2, b3])),
+            Self::Float32 => f64::from(f32::from_le_bytes([b0, b1, b2, b3])),
+            Self::Float64 => f64::from_le_bytes(raw),
+        }
+    }
+
+    /// Writes `value`, converted to the element type, to the first bytes of `bytes`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn write(self, bytes: &mut [u8], value: f64, little_endian: bool) {
+        let size = self.element_size();
+        let bits = to_uint32_bits(value);
+        let mut raw = [0; 8];
+        match self {
+            Self::Int8 | Self::Uint8 => raw[0] = bits as u8,
+            Self::Uint8Clamped => raw[0] = to_uint8_clamp(value),
+            Self::Int16 | Self::Uint16 => raw[..2].copy_from_slice(&(bits as u16).to_le_bytes()),
+            Self::Int32 | Self::Uint32 => raw[..4].copy_from_slice(&bits.to_le_bytes()),
+            Self::Float32 => raw[..4].copy_from_slice(&(value as f32).to_le_bytes()),
+            Self::Float64 => raw.copy_from_slice(&value.to_le_bytes()),
+        }
+
+        if !little_endian {
+            raw[..size].reverse();
+        }
+        bytes[..size].copy_from_slice(&raw[..size]);
+    }
+}
+
+/// Wraps an integral number modulo 2^32, the first step of the `ToInt32` family of conversions.
+fn to_uint32_bits(number: f64) -> u32 {
+    if !number.is_finite() {
+        return 0;
+    }
+    let modulo = number.trunc() % 4_294_967_296.0;
+    if modulo < 0.0 {
+        (modulo + 4_294_967_296.0) as u32
+    } else {
+        modulo as u32
+    }
+}
+
+/// Clamps a number to a byte, rounding halves to even.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+fn to_uint8_clamp(number: f64) -> u8 {
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+    if number >= 255.0 {
+        return 255;
+    }
+
+    let floor = number.floor();
+    let floor_byte = floor as u8;
+    if floor + 0.5 < number || (floor + 0.5 == number && floor_byte % 2 == 1) {
+        floor_byte + 1
+    } else {
+        floor_byte
+    }
+}
+
+/// The internal slots of a typed array.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct TypedArrayData {
+    /// The type of the elements.
+    pub kind: TypedArrayKind,
+    /// The `ArrayBuffer` holding the elements.
+    pub buffer: Value,
+    /// The offset of the first element in the buffer, in bytes.
+    pub byte_offset: usize,
+    /// The number of elements, or `None` when the array tracks the length of a resizable buffer.
+    pub array_length: Option<usize>,
+}
+
+impl TypedArrayData {
+    /// The current number of elements, 0 when the array is out of the bounds of its buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
+    pub fn length(&self) -> usize {
+        let buffer_length = array_buffer::byte_length_of(&self.buffer);
+        let size = self.kind.element_size();
+        match self.array_length {
+            _ if self.byte_offset > buffer_length => 0,
+            None => (buffer_length - self.byte_offset) / size,
+            Some(length) if self.byte_offset + length * size > buffer_length => 0,
+            Some(length) => length,
+        }
+    }
+
+    /// Reads the element at `index`, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        if index >= self.length() {
+            return None;
+        }
+        let start = self.byte_offset + index * self.kind.element_size();
+        with_array_buffer_bytes(&self.buffer, |bytes| self.kind.read(&bytes[start..], true))
+    }
+
+    /// Writes the element at `index`, doing nothing if the index is out of bounds.
+    pub fn set(&self, index: usize, value: f64) {
+        if index >= self.length() {
+            return;
+        }
+        let start = self.byte_offset + index * self.kind.element_size();
+        with_array_buffer_bytes(&self.buffer, |bytes| {
+            self.kind.write(&mut bytes[start..], value, true)
+        });
+    }
+
+    /// Reads all the elements.
+    pub fn values(&self) -> Vec<f64> {
+        (0..self.length())
+            .filter_map(|index| self.get(index))
+            .collect()
+    }
+}
+
+/// The internal methods of typed arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+static TYPED_ARRAY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_own_property: typed_array_get_own_property,
+    define_own_property: typed_array_define_own_property,
+    has_property: typed_array_has_property,
+    get: typed_array_get,
+    set: typed_array_set,
+    delete: typed_array_delete,
+    own_property_keys: typed_array_own_property_keys,
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+/// Gets the internal slots of the typed array `value`, or `None` if it's not a typed array.
+pub fn typed_array_data(value: &Value) -> Option<TypedArrayData> {
+    match value.as_object()?.kind {
+        ObjectKind::TypedArray(ref data) => Some(data.clone()),
+        _ => None,
+    }
+}
+
+/// Calls `f` with the bytes viewed by the typed array `value`,
+/// or returns `None` if it's not a typed array.
+///
+/// The buffer is borrowed during the call, so `f` must not run any JavaScript code.
+pub fn with_typed_array_bytes<R, F: FnOnce(&mut [u8]) -> R>(value: &Value, f: F) -> Option<R> {
+    let data = typed_array_data(value)?;
+    let start = data.byte_offset;
+    let end = start + data.length() * data.kind.element_size();
+    with_array_buffer_bytes(&data.buffer, |bytes| f(&mut bytes[start..end]))
+}
+
+/// Gets the internal slots of a typed array, or throws a `TypeError` if `this` is not one.
+fn this_typed_array(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<TypedArrayData, Value> {
+    match typed_array_data(this) {
+        Some(data) => Ok(data),
+        None => Err(ctx.construct_type_error(format!(
+            "Method %TypedArray%.prototype.{} called on incompatible receiver {}",
+            method, this
+        ))),
+    }
+}
+
+/// Converts a value to a number, `undefined` becoming `NaN`.
+fn to_number(value: &Value, ctx: &mut Interpreter) -> f64 {
+    if value.is_undefined() {
+        f64::NAN
+    } else {
+        ctx.value_to_rust_number(value)
+    }
+}
+
+/// Returns the numeric value of a number value, integers included, without any conversion.
+fn number_value(value: &Value) -> Option<f64> {
+    match **value {
+        ValueData::Rational(_) | ValueData::Integer(_) => Some(value.to_number()),
+        _ => None,
+    }
+}
+
+/// Creates a new typed array of `kind` with the `TypedArray( ...args )` constructor of its kind.
+fn typed_array_create(kind: TypedArrayKind, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let constructor = ctx.realm.global_obj.get_field_slice(kind.name());
+    ctx.construct(&constructor, args, &constructor)
+}
+
+/// Creates a new typed array with `constructor`, checking that it has at least `length` elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#typedarray-create
+fn typed_array_create_from(
+    constructor: &Value,
+    length: usize,
+    ctx: &mut Interpreter,
+) -> Result<(Value, TypedArrayData), Value> {
+    if !constructor
+        .as_object()
+        .map_or(false, |obj| obj.is_constructor())
+    {
+        return Err(ctx.construct_type_error(format!("{} is not a constructor", constructor)));
+    }
+
+    let array = ctx.construct(constructor, &[to_value(length)], constructor)?;
+    match typed_array_data(&array) {
+        Some(ref data) if data.length() >= length => Ok((array.clone(), data.clone())),
+        Some(_) => Err(ctx.construct_type_error(
+            "Derived TypedArray constructor created an array which was too small",
+        )),
+        None => {
+            Err(ctx
+                .construct_type_error("Derived TypedArray constructor did not return a TypedArray"))
+        }
+    }
+}
+
+/// Gets the index of an element from a property key.
+///
+/// Returns `None` when the key isn't a canonical numeric string, so the property is ordinary,
+/// and `Some(None)` when it's numeric but not the index of an element.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+fn element_index(obj: &Value, key: &Value) -> Option<Option<usize>> {
+    if !key.is_string() {
+        return None;
+    }
+    let key = key.to_string();
+    let number = if key == "-0" {
+        -0.0
+    } else {
+        let number = to_value(key.as_str()).to_number();
+        if to_value(number).to_string() != key {
+            return None;
+        }
+        number
+    };
+
+    // `IsValidIntegerIndex`
+    let length = typed_array_data(obj).map_or(0, |data| data.length());
+    if number.fract() != 0.0
+        || (number == 0.0 && number.is_sign_negative())
+        || number < 0.0
+        || number >= length as f64
+    {
+        return Some(None);
+    }
+    Some(Some(number as usize))
+}
+
+/// Builds the property descriptor of an element.
+fn element_property(value: f64) -> Property {
+    Property::default()
+        .value(to_value(value))
+        .writable(true)
+        .enumerable(true)
+        .configurable(true)
+}
+
+/// `[[GetOwnProperty]]` of typed arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-getownproperty
+fn typed_array_get_own_property(
+    obj: &Value,
+    key: &Value,
+    ctx: &mut Interpreter,
+) -> Result<Option<Property>, Value> {
+    match element_index(obj, key) {
+        Some(index) => Ok(index
+            .and_then(|index| typed_array_data(obj)?.get(index))
+            .map(element_property)),
+        None => (ORDINARY_INTERNAL_METHODS.get_own_property)(obj, key, ctx),
+    }
+}
+
+/// `[[HasProperty]]` of typed arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-hasproperty
+fn typed_array_has_property(
+    obj: &Value,
+    key: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    match element_index(obj, key) {
+        Some(index) => Ok(index.is_some()),
+        None => (ORDINARY_INTERNAL_METHODS.has_property)(obj, key, ctx),
+    }
+}
+
+/// `[[DefineOwnProperty]]` of typed arrays.
+///
+/// Elements can only be redefined as writable, enumerable and configurable data properties.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-defineownproperty
+fn typed_array_define_own_property(
+    obj: &Value,
+    key: &Value,
+    desc: Property,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    let index = match element_index(obj, key) {
+        Some(Some(index)) => index,
+        Some(None) => return Ok(false),
+        None => return (ORDINARY_INTERNAL_METHODS.define_own_property)(obj, key, desc, ctx),
+    };
+
+    if desc.configurable == Some(false)
+        || desc.enumerable == Some(false)
+        || desc.is_accessor_descriptor()
+        || desc.writable == Some(false)
+    {
+        return Ok(false);
+    }
+    if let Some(ref value) = desc.value {
+        let value = to_number(value, ctx);
+        if let Some(data) = typed_array_data(obj) {
+            data.set(index, value);
+        }
+    }
+    Ok(true)
+}
+
+/// `[[Get]]` of typed arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-get
+fn typed_array_get(
+    obj: &Value,
+    key: &Value,
+    receiver: &Value,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    match element_index(obj, key) {
+        Some(index) => Ok(index
+            .and_then(|index| typed_array_data(obj)?.get(index))
+            .map_or_else(undefined, to_value)),
+        None => (ORDINARY_INTERNAL_METHODS.get)(obj, key, receiver, ctx),
+    }
+}
+
+/// `[[Set]]` of typed arrays.
+///
+/// Assigning an element out of bounds is ignored.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-set
+fn typed_array_set(
+    obj: &Value,
+    key: &Value,
+    value: Value,
+    receiver: &Value,
+    ctx: &mut Interpreter,
+) -> Result<bool, Value> {
+    match element_index(obj, key) {
+        Some(index) if same_value(obj, receiver, false) => {
+            // The value is converted before the index is checked again, as it can resize the buffer
+            let value = to_number(&value, ctx);
+            if let (Some(index), Some(data)) = (index, typed_array_data(obj)) {
+                data.set(index, value);
+            }
+            Ok(true)
+        }
+        Some(None) => Ok(true),
+        _ => (ORDINARY_INTERNAL_METHODS.set)(obj, key, value, receiver, ctx),
+    }
+}
+
+/// `[[Delete]]` of typed arrays, elements can't be deleted.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-delete
+fn typed_array_delete(obj: &Value, key: &Value, ctx: &mut Interpreter) -> Result<bool, Value> {
+    match element_index(obj, key) {
+        Some(index) => Ok(index.is_none()),
+        None => (ORDINARY_INTERNAL_METHODS.delete)(obj, key, ctx),
+    }
+}
+
+/// `[[OwnPropertyKeys]]` of typed arrays, the indices of the elements come first.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-ownpropertykeys
+fn typed_array_own_property_keys(obj: &Value, ctx: &mut Interpreter) -> Result<Vec<Value>, Value> {
+    let length = typed_array_data(obj).map_or(0, |data| data.length());
+    let mut keys: Vec<Value> = (0..length)
+        .map(|index| to_value(index.to_string()))
+        .collect();
+    keys.extend((ORDINARY_INTERNAL_METHODS.own_property_keys)(obj, ctx)?);
+    Ok(keys)
+}
+
+/// Initialises `this` as a typed array of `kind`, with the arguments of a typed array constructor.
+///
+/// The first argument is either a length, an `ArrayBuffer` to view (followed by the byte offset
+/// and the length of the view), or a typed array or array-like object whose elements are copied.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/TypedArray
+fn initialize_typed_array(
+    this: &mut Value,
+    args: &[Value],
+    kind: TypedArrayKind,
+    ctx: &mut Interpreter,
+) -> ResultValue {
+    let size = kind.element_size();
+    let first = args.get(0).cloned().unwrap_or_else(undefined);
+
+    let buffer_info = first.as_object().and_then(|obj| match obj.kind {
+        ObjectKind::ArrayBuffer(ref data) => {
+            Some((data.bytes.len(), data.max_byte_length.is_some()))
+        }
+        _ => None,
+    });
+
+    let data = if !first.is_object() {
+        let length = ctx.to_index(&first)?;
+        TypedArrayData {
+            kind,
+            buffer: array_buffer::allocate_array_buffer(length.saturating_mul(size), ctx)?,
+            byte_offset: 0,
+            array_length: Some(length),
+        }
+    } else if let Some((_, resizable)) = buffer_info {
+        let byte_offset = ctx.to_index(&args.get(1).cloned().unwrap_or_else(undefined))?;
+        if byte_offset % size != 0 {
+            return ctx.throw_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                kind.name(),
+                size
+            ));
+        }
+
+        let length = args.get(2).cloned().unwrap_or_else(undefined);
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(ctx.to_index(&length)?)
+        };
+
+        // Reading the arguments may have resized the buffer
+        let buffer_length = array_buffer::byte_length_of(&first);
+        let array_length = match new_length {
+            Some(length) => {
+                if byte_offset + length.saturating_mul(size) > buffer_length {
+                    return ctx
+                        .throw_range_error(format!("Invalid typed array length: {}", length));
+                }
+                Some(length)
+            }
+            None if byte_offset > buffer_length => {
+                return ctx.throw_range_error(format!(
+                    "Start offset {} is outside the bounds of the buffer",
+                    byte_offset
+                ));
+            }
+            None if resizable => None,
+            None => {
+                if buffer_length % size != 0 {
+                    return ctx.throw_range_error(format!(
+                        "byte length of {} should be a multiple of {}",
+                        kind.name(),
+                        size
+                    ));
+                }
+                Some((buffer_length - byte_offset) / size)
+            }
+        };
+
+        TypedArrayData {
+            kind,
+            buffer: first.clone(),
+            byte_offset,
+            array_length,
+        }
+    } else {
+        let values = match typed_array_data(&first) {
+            Some(source) => source.values(),
+            None => {
+                let values = ctx.create_list_from_array_like(&first)?;
+                values.iter().map(|value| to_number(value, ctx)).collect()
+            }
+        };
+
+        let data = TypedArrayData {
+            kind,
+            buffer: array_buffer::allocate_array_buffer(values.len() * size, ctx)?,
+            byte_offset: 0,
+            array_length: Some(values.len()),
+        };
+        for (index, value) in values.into_iter().enumerate() {
+            data.set(index, value);
+        }
+        data
+    };
+
+    this.set_kind(ObjectKind::TypedArray(data));
+    this.set_internal_methods(&TYPED_ARRAY_INTERNAL_METHODS);
+    Ok(this.clone())
+}
+
+/// Defines the constructor functions of a kind of typed array.
+macro_rules! typed_array_constructor {
+    ($make:ident, $call:ident, $kind:expr) => {
+        /// Create a new typed array.
+        pub fn $make(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+            initialize_typed_array(this, args, $kind, ctx)
+        }
+
+        /// Typed array constructors can only be called as constructors.
+        pub fn $call(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+            ctx.throw_type_error(format!("Constructor {} requires 'new'", $kind.name()))
+        }
+    };
+}
+
+typed_array_constructor!(make_int8_array, call_int8_array, TypedArrayKind::Int8);
+typed_array_constructor!(make_uint8_array, call_uint8_array, TypedArrayKind::Uint8);
+typed_array_constructor!(
+    make_uint8_clamped_array,
+    call_uint8_clamped_array,
+    TypedArrayKind::Uint8Clamped
+);
+typed_array_constructor!(make_int16_array, call_int16_array, TypedArrayKind::Int16);
+typed_array_constructor!(make_uint16_array, call_uint16_array, TypedArrayKind::Uint16);
+typed_array_constructor!(make_int32_array, call_int32_array, TypedArrayKind::Int32);
+typed_array_constructor!(make_uint32_array, call_uint32_array, TypedArrayKind::Uint32);
+typed_array_constructor!(
+    make_float32_array,
+    call_float32_array,
+    TypedArrayKind::Float32
+);
+typed_array_constructor!(
+    make_float64_array,
+    call_float64_array,
+    TypedArrayKind::Float64
+);
+
+/// The `%TypedArray%` intrinsic can't be constructed or called, only its subclasses.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+pub fn make_typed_array(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Abstract class TypedArray not directly constructable")
+}
+
+/// `%TypedArray%.from( source [, mapFn [, thisArg ] ] )`
+///
+/// The `from()` method creates a new typed array from an array-like object,
+/// optionally mapping its elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+pub fn from(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let source = args.get(0).cloned().unwrap_or_else(undefined);
+    let map_fn = args.get(1).cloned().unwrap_or_else(undefined);
+    if !map_fn.is_undefined() && !map_fn.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", map_fn));
+    }
+    let mut this_arg = args.get(2).cloned().unwrap_or_else(undefined);
+
+    let values = match typed_array_data(&source) {
+        Some(data) => data.values().into_iter().map(to_value).collect(),
+        None => {
+            let source = ctx.to_object(&source)?;
+            ctx.create_list_from_array_like(&source)?
+        }
+    };
+
+    let (array, _) = typed_array_create_from(this, values.len(), ctx)?;
+    for (index, value) in values.into_iter().enumerate() {
+        let value = if map_fn.is_undefined() {
+            value
+        } else {
+            ctx.call(&map_fn, &mut this_arg, &[value, to_value(index)])?
+        };
+        ctx.set_property(&array, &to_value(index.to_string()), value)?;
+    }
+    Ok(array)
+}
+
+/// `%TypedArray%.of( ...items )`
+///
+/// The `of()` method creates a new typed array from its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+pub fn of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let (array, _) = typed_array_create_from(this, args.len(), ctx)?;
+    for (index, value) in args.iter().enumerate() {
+        ctx.set_property(&array, &to_value(index.to_string()), value.clone())?;
+    }
+    Ok(array)
+}
+
+/// `get %TypedArray%.prototype.buffer`
+///
+/// The `buffer` accessor property represents the `ArrayBuffer` viewed by the typed array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer
+pub fn buffer(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(this_typed_array(this, "buffer", ctx)?.buffer.clone())
+}
+
+/// `get %TypedArray%.prototype.byteLength`
+///
+/// The `byteLength` accessor property represents the length of the typed array in bytes.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteLength
+pub fn byte_length(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "byteLength", ctx)?;
+    Ok(to_value(data.length() * data.kind.element_size()))
+}
+
+/// `get %TypedArray%.prototype.byteOffset`
+///
+/// The `byteOffset` accessor property represents the offset of the typed array in its buffer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteOffset
+pub fn byte_offset(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "byteOffset", ctx)?;
+    if data.byte_offset > array_buffer::byte_length_of(&data.buffer) {
+        return Ok(to_value(0));
+    }
+    Ok(to_value(data.byte_offset))
+}
+
+/// `get %TypedArray%.prototype.length`
+///
+/// The `length` accessor property represents the number of elements of the typed array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length
+pub fn length(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    Ok(to_value(this_typed_array(this, "length", ctx)?.length()))
+}
+
+/// `%TypedArray%.prototype.fill( value [, start [, end ] ] )`
+///
+/// The `fill()` method sets the elements from `start` up to `end`, exclusive, to `value`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill
+pub fn fill(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "fill", ctx)?;
+    let len = data.length();
+    let value = to_number(&args.get(0).cloned().unwrap_or_else(undefined), ctx);
+    let start = array_buffer::relative_index(args.get(1), 0, len, ctx);
+    let end = array_buffer::relative_index(args.get(2), len, len, ctx);
+
+    for index in start..end {
+        data.set(index, value);
+    }
+    Ok(this.clone())
+}
+
+/// `%TypedArray%.prototype.includes( searchElement [, fromIndex ] )`
+///
+/// The `includes()` method determines whether the typed array contains an element,
+/// `NaN` being found like any other value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/includes
+pub fn includes(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "includes", ctx)?;
+    let len = data.length();
+    let search = args.get(0).cloned().unwrap_or_else(undefined);
+    let start = array_buffer::relative_index(args.get(1), 0, len, ctx);
+    let search = match number_value(&search) {
+        Some(search) => search,
+        None => return Ok(to_value(false)),
+    };
+    let found = data
+        .values()
+        .into_iter()
+        .skip(start)
+        .any(|value| value == search || (value.is_nan() && search.is_nan()));
+    Ok(to_value(found))
+}
+
+/// `%TypedArray%.prototype.indexOf( searchElement [, fromIndex ] )`
+///
+/// The `indexOf()` method returns the first index at which an element is found, or -1.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/indexOf
+pub fn index_of(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "indexOf", ctx)?;
+    let len = data.length();
+    let search = args.get(0).cloned().unwrap_or_else(undefined);
+    let start = array_buffer::relative_index(args.get(1), 0, len, ctx);
+    let search = match number_value(&search) {
+        Some(search) => search,
+        None => return Ok(to_value(-1)),
+    };
+    match data
+        .values()
+        .into_iter()
+        .skip(start)
+        .position(|value| value == search)
+    {
+        Some(position) => Ok(to_value(start + position)),
+        None => Ok(to_value(-1)),
+    }
+}
+
+/// `%TypedArray%.prototype.join( [separator] )`
+///
+/// The `join()` method joins the elements into a string, separated by commas or `separator`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/join
+pub fn join(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "join", ctx)?;
+    let separator = match args.get(0) {
+        Some(separator) if !separator.is_undefined() => ctx.value_to_rust_string(separator),
+        _ => String::from(","),
+    };
+
+    let joined = data
+        .values()
+        .into_iter()
+        .map(|value| to_value(value).to_string())
+        .collect::<Vec<String>>()
+        .join(&separator);
+    Ok(to_value(joined))
+}
+
+/// `%TypedArray%.prototype.forEach( callbackFn [, thisArg ] )`
+///
+/// The `forEach()` method calls a function with each element of the typed array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/forEach
+pub fn for_each(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "forEach", ctx)?;
+    let callback = args.get(0).cloned().unwrap_or_else(undefined);
+    if !callback.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", callback));
+    }
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    for index in 0..data.length() {
+        let value = data.get(index).map_or_else(undefined, to_value);
+        ctx.call(
+            &callback,
+            &mut this_arg,
+            &[value, to_value(index), this.clone()],
+        )?;
+    }
+    Ok(undefined())
+}
+
+/// `%TypedArray%.prototype.map( callbackFn [, thisArg ] )`
+///
+/// The `map()` method creates a new typed array of the same type with the results
+/// of calling a function with each element.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/map
+pub fn map(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "map", ctx)?;
+    let callback = args.get(0).cloned().unwrap_or_else(undefined);
+    if !callback.is_function() {
+        return ctx.throw_type_error(format!("{} is not a function", callback));
+    }
+    let mut this_arg = args.get(1).cloned().unwrap_or_else(undefined);
+
+    let len = data.length();
+    let mapped = typed_array_create(data.kind, &[to_value(len)], ctx)?;
+    for index in 0..len {
+        let value = data.get(index).map_or_else(undefined, to_value);
+        let result = ctx.call(
+            &callback,
+            &mut this_arg,
+            &[value, to_value(index), this.clone()],
+        )?;
+        ctx.set_property(&mapped, &to_value(index.to_string()), result)?;
+    }
+    Ok(mapped)
+}
+
+/// `%TypedArray%.prototype.reverse()`
+///
+/// The `reverse()` method reverses the elements of the typed array in place.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reverse
+pub fn reverse(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "reverse", ctx)?;
+    for (index, value) in data.values().into_iter().rev().enumerate() {
+        data.set(index, value);
+    }
+    Ok(this.clone())
+}
+
+/// `%TypedArray%.prototype.sort( comparefn )`
+///
+/// The `sort()` method sorts the elements of the typed array in place, and returns it.
+/// Without `comparefn`, the elements are sorted numerically, `-0` before `+0` and `NaN` last.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/sort
+pub fn sort(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let compare_fn = args.first().cloned().unwrap_or_else(undefined);
+    if !compare_fn.is_undefined() && !compare_fn.is_function() {
+        return ctx
+            .throw_type_error("The comparison function must be either a function or undefined");
+    }
+    let data = this_typed_array(this, "sort", ctx)?;
+
+    let sorted = if compare_fn.is_undefined() {
+        let mut values = data.values();
+        values.sort_by(|x, y| compare_numbers(*x, *y));
+        values
+    } else {
+        let values = data.values().into_iter().map(to_value).collect();
+        merge_sort(values, &mut |x, y| {
+            let result = ctx.call(&compare_fn, &mut undefined(), &[x.clone(), y.clone()])?;
+            let result = ctx.value_to_rust_number(&result);
+            // A `NaN` result is treated like `+0`
+            Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+        })?
+        .iter()
+        .map(|value| value.to_number())
+        .collect()
+    };
+
+    // The comparison function may have shrunk the buffer, the elements out of it are dropped
+    for (index, value) in sorted.into_iter().enumerate() {
+        data.set(index, value);
+    }
+    Ok(this.clone())
+}
+
+/// The abstract operation `CompareTypedArrayElements` without a comparison function,
+/// ordering `-0` before `+0` and `NaN` last.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+fn compare_numbers(x: f64, y: f64) -> Ordering {
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) if x == 0.0 && y == 0.0 => y.is_sign_negative().cmp(&x.is_sign_negative()),
+        (false, false) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// `%TypedArray%.prototype.set( source [, offset ] )`
+///
+/// The `set()` method copies the elements of a typed array or an array-like object
+/// into the typed array, starting at `offset`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+pub fn set(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "set", ctx)?;
+    let source = args.get(0).cloned().unwrap_or_else(undefined);
+    let offset = ctx.to_integer_or_infinity(&args.get(1).cloned().unwrap_or_else(undefined));
+    if offset < 0.0 {
+        return ctx.throw_range_error("offset is out of bounds");
+    }
+
+    // The values are read before being written, in case both arrays share their buffer
+    let values = match typed_array_data(&source) {
+        Some(source) => source.values(),
+        None => {
+            let source = ctx.to_object(&source)?;
+            let values = ctx.create_list_from_array_like(&source)?;
+            values.iter().map(|value| to_number(value, ctx)).collect()
+        }
+    };
+
+    if offset + values.len() as f64 > data.length() as f64 {
+        return ctx.throw_range_error("offset is out of bounds");
+    }
+    for (index, value) in values.into_iter().enumerate() {
+        data.set(offset as usize + index, value);
+    }
+    Ok(undefined())
+}
+
+/// `%TypedArray%.prototype.slice( [start [, end ] ] )`
+///
+/// The `slice()` method returns a new typed array with a copy of the elements
+/// from `start` up to `end`, exclusive.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/slice
+pub fn slice(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "slice", ctx)?;
+    let len = data.length();
+    let start = array_buffer::relative_index(args.get(0), 0, len, ctx);
+    let end = array_buffer::relative_index(args.get(1), len, len, ctx);
+    let count = end.saturating_sub(start);
+
+    let sliced = typed_array_create(data.kind, &[to_value(count)], ctx)?;
+    if let Some(target) = typed_array_data(&sliced) {
+        for (index, value) in data
+            .values()
+            .into_iter()
+            .skip(start)
+            .take(count)
+            .enumerate()
+        {
+            target.set(index, value);
+        }
+    }
+    Ok(sliced)
+}
+
+/// `%TypedArray%.prototype.subarray( [begin [, end ] ] )`
+///
+/// The `subarray()` method returns a new typed array viewing the same buffer,
+/// from the element `begin` up to `end`, exclusive.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+pub fn subarray(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let data = this_typed_array(this, "subarray", ctx)?;
+    let len = data.length();
+    let begin = array_buffer::relative_index(args.get(0), 0, len, ctx);
+    let begin_byte_offset = to_value(data.byte_offset + begin * data.kind.element_size());
+
+    let end = args.get(1).cloned().unwrap_or_else(undefined);
+    if data.array_length.is_none() && end.is_undefined() {
+        return typed_array_create(data.kind, &[data.buffer.clone(), begin_byte_offset], ctx);
+    }
+
+    let end = array_buffer::relative_index(Some(&end), len, len, ctx);
+    let new_length = to_value(end.saturating_sub(begin));
+    typed_array_create(
+        data.kind,
+        &[data.buffer.clone(), begin_byte_offset, new_length],
+        ctx,
+    )
+}
+
+/// Creates the `%TypedArray%` intrinsic object, which isn't a global.
+fn create_intrinsic(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_getter_fn!(buffer, named "buffer", of prototype);
+    make_getter_fn!(byte_length, named "byteLength", of prototype);
+    make_getter_fn!(byte_offset, named "byteOffset", of prototype);
+    make_getter_fn!(length, named "length", of prototype);
+    make_builtin_fn!(fill, named "fill", with length 1, of prototype);
+    make_builtin_fn!(for_each, named "forEach", with length 1, of prototype);
+    make_builtin_fn!(includes, named "includes", with length 1, of prototype);
+    make_builtin_fn!(index_of, named "indexOf", with length 1, of prototype);
+    make_builtin_fn!(join, named "join", with length 1, of prototype);
+    make_builtin_fn!(map, named "map", with length 1, of prototype);
+    make_builtin_fn!(reverse, named "reverse", of prototype);
+    make_builtin_fn!(set, named "set", with length 1, of prototype);
+    make_builtin_fn!(slice, named "slice", with length 2, of prototype);
+    make_builtin_fn!(sort, named "sort", with length 1, of prototype);
+    make_builtin_fn!(subarray, named "subarray", with length 2, of prototype);
+    make_builtin_fn!(join, named "toString", of prototype);
+
+    let typed_array = make_constructor_fn!(make_typed_array, make_typed_array, global, prototype);
+    make_builtin_fn!(from, named "from", with length 1, of typed_array);
+    make_builtin_fn!(of, named "of", of typed_array);
+    typed_array
+}
+
+/// Creates the constructor of a kind of typed array, inheriting from `%TypedArray%`,
+/// and sets it on the global object.
+fn init_constructor(
+    global: &Value,
+    intrinsic: &Value,
+    kind: TypedArrayKind,
+    make: NativeFunctionData,
+    call: NativeFunctionData,
+) {
+    let prototype = to_value(Object::create(intrinsic.get_field_slice(PROTOTYPE)));
+    let bytes_per_element = to_value(kind.element_size());
+    prototype.set_field_slice("BYTES_PER_ELEMENT", bytes_per_element.clone());
+
+    let constructor = make_constructor_fn!(make, call, global, prototype);
+    constructor.set_field_slice("length", to_value(3_i32));
+    constructor.set_field_slice("BYTES_PER_ELEMENT", bytes_per_element);
+    constructor.set_internal_slot("__proto__", intrinsic.clone());
+    global.set_field_slice(kind.name(), constructor);
+}
+
+/// Initialise the typed array constructors on the global object.
+pub fn init(global: &Value) {
+    use TypedArrayKind::*;
+
+    let intrinsic = create_intrinsic(global);
+    init_constructor(global, &intrinsic, Int8, make_int8_array, call_int8_array);
+    init_constructor(
+        global,
+        &intrinsic,
+        Uint8,
+        make_uint8_array,
+        call_uint8_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Uint8Clamped,
+        make_uint8_clamped_array,
+        call_uint8_clamped_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Int16,
+        make_int16_array,
+        call_int16_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Uint16,
+        make_uint16_array,
+        call_uint16_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Int32,
+        make_int32_array,
+        call_int32_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Uint32,
+        make_uint32_array,
+        call_uint32_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Float32,
+        make_float32_array,
+        call_float32_array,
+    );
+    init_constructor(
+        global,
+        &intrinsic,
+        Float64,
+        make_float64_array,
+        call_float64_array,
+    );
+}
@@ -0,0 +1,306 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var zeroed = new Int32Array(3);
+        var copied = new Uint8Array([1, 2, 300]);
+        var converted = new Float64Array(copied);
+        var buffer = new ArrayBuffer(8);
+        var view = new Int16Array(buffer, 2, 2);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "zeroed.length"), "3");
+    assert_eq!(forward(&mut engine, "zeroed.byteLength"), "12");
+    assert_eq!(forward(&mut engine, "zeroed.join()"), "0,0,0");
+    assert_eq!(forward(&mut engine, "copied.join()"), "1,2,44");
+    assert_eq!(forward(&mut engine, "converted.join()"), "1,2,44");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "view.length"), "2");
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "new Int16Array(buffer).length"), "4");
+    assert_eq!(forward(&mut engine, "Float32Array.BYTES_PER_ELEMENT"), "4");
+    assert_eq!(forward(&mut engine, "view.BYTES_PER_ELEMENT"), "2");
+    assert_eq!(
+        forward(&mut engine, "try { Uint8Array(2) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int16Array(buffer, 1) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int16Array(buffer, 2, 4) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int16Array(new ArrayBuffer(3)) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var values = [-1, 255.5, 256, 1.5, 2.5, -129, 70000, 0 / 0];
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(
+        forward(&mut engine, "new Int8Array(values).join()"),
+        "-1,-1,0,1,2,127,112,0"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(values).join()"),
+        "255,255,0,1,2,127,112,0"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8ClampedArray(values).join()"),
+        "0,255,255,2,2,0,255,0"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Int16Array(values).join()"),
+        "-1,255,256,1,2,-129,4464,0"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint32Array([-1, 4294967297]).join()"),
+        "4294967295,1"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Float32Array([0.5, 0.1])[1]"),
+        "0.10000000149011612"
+    );
+    assert_eq!(forward(&mut engine, "new Float64Array([0.1])[0]"), "0.1");
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = new Uint8Array(2);
+        array[0] = 10;
+        array[5] = 1;
+        array['-0'] = 1;
+        array[1.5] = 1;
+        array.named = 'kept';
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "array[0]"), "10");
+    assert_eq!(forward(&mut engine, "array[5]"), "undefined");
+    assert_eq!(forward(&mut engine, "array['-0']"), "undefined");
+    assert_eq!(forward(&mut engine, "array.named"), "kept");
+    assert_eq!(forward(&mut engine, "0 in array"), "true");
+    assert_eq!(forward(&mut engine, "2 in array"), "false");
+    assert_eq!(forward(&mut engine, "'-0' in array"), "false");
+    assert_eq!(forward(&mut engine, "delete array[0]"), "false");
+    assert_eq!(forward(&mut engine, "delete array[7]"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(array).join()"),
+        "0,1,named"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, '1', { value: 3 })"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "array[1]"), "3");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, '1', { value: 3, writable: false })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, '2', { value: 3 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(array, '0').writable"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn methods() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var array = new Int16Array([1, 2, 3, 4]);
+        var sub = array.subarray(1, 3);
+        var copy = array.slice(-2);
+        var log = [];
+        var nan = 0 / 0;
+        var floats = new Float32Array([nan]);
+        array.forEach(function (value, index) { log[log.length] = index + ':' + value; });
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "log.join()"), "0:1,1:2,2:3,3:4");
+    assert_eq!(forward(&mut engine, "sub.join()"), "2,3");
+    assert_eq!(forward(&mut engine, "sub.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "copy.join()"), "3,4");
+
+    // The subarray shares the buffer, the slice doesn't
+    forward(&mut engine, "array[1] = 20; array[3] = 40;");
+    assert_eq!(forward(&mut engine, "sub[0]"), "20");
+    assert_eq!(forward(&mut engine, "copy[1]"), "4");
+
+    assert_eq!(
+        forward(
+            &mut engine,
+            "array.map(function (x) { return x * 2; }).join('-')"
+        ),
+        "2-40-6-80"
+    );
+    assert_eq!(forward(&mut engine, "array.indexOf(3)"), "2");
+    assert_eq!(forward(&mut engine, "array.indexOf(3, 3)"), "-1");
+    assert_eq!(forward(&mut engine, "array.includes(40)"), "true");
+    assert_eq!(forward(&mut engine, "array.includes('40')"), "false");
+    assert_eq!(forward(&mut engine, "floats.includes(nan)"), "true");
+    assert_eq!(forward(&mut engine, "floats.indexOf(nan)"), "-1");
+    assert_eq!(forward(&mut engine, "array.reverse().join()"), "40,3,20,1");
+    assert_eq!(
+        forward(&mut engine, "array.fill(9, 1, -1).join()"),
+        "40,9,9,1"
+    );
+    assert_eq!(forward(&mut engine, "array.toString()"), "40,9,9,1");
+
+    forward(&mut engine, "array.set([7, 8], 2)");
+    assert_eq!(forward(&mut engine, "array.join()"), "40,9,7,8");
+    forward(&mut engine, "array.set(array.subarray(0, 2), 1)");
+    assert_eq!(forward(&mut engine, "array.join()"), "40,40,9,8");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { array.set([1, 2], 3) } catch (e) { e.name }"
+        ),
+        "RangeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Int8Array.prototype.join.call([]) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn sort() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var nan = 0 / 0;
+        var floats = new Float64Array([3, nan, -1, 10, 2]);
+        var sorted = floats.sort();
+        var zeros = new Float32Array([0, nan, -0, 0, -0]).sort();
+        var ints = new Int8Array([10, 9, 1, -5]);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "sorted === floats"), "true");
+    assert_eq!(forward(&mut engine, "floats.join()"), "-1,2,3,10,NaN");
+    assert_eq!(forward(&mut engine, "1 / zeros[0]"), "-Infinity");
+    assert_eq!(forward(&mut engine, "1 / zeros[1]"), "-Infinity");
+    assert_eq!(forward(&mut engine, "1 / zeros[2]"), "Infinity");
+    assert_eq!(forward(&mut engine, "1 / zeros[3]"), "Infinity");
+    assert_eq!(forward(&mut engine, "zeros[4]"), "NaN");
+    assert_eq!(forward(&mut engine, "ints.sort().join()"), "-5,1,9,10");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "ints.sort(function (a, b) { return b - a; }).join()"
+        ),
+        "10,9,1,-5"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { ints.sort(function () { throw new Error('stop'); }) } catch (e) { e.message }"
+        ),
+        "stop"
+    );
+    assert_eq!(forward(&mut engine, "ints.join()"), "10,9,1,-5");
+    assert_eq!(
+        forward(&mut engine, "try { ints.sort(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn from_and_of() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "Uint8Array.of(1, 2, 3).join()"),
+        "1,2,3"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Int8Array.from([1, 2], function (x, i) { return x * 10 + i; }).join()"
+        ),
+        "10,21"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Float64Array.from(new Int8Array([-1, 1])).join()"
+        ),
+        "-1,1"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(Int8Array) === Object.getPrototypeOf(Uint8Array)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new (Object.getPrototypeOf(Int8Array))() } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+#[test]
+fn display() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array([1, 2])"),
+        "Uint8Array(2) [ 1, 2 ]"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Float32Array(0)"),
+        "Float32Array(0) []"
+    );
+    assert_eq!(
+        forward(&mut engine, "new ArrayBuffer(3)"),
+        "ArrayBuffer { byteLength: 3 }"
+    );
+}
@@ -1,18 +1,106 @@
 use super::*;
+use crate::{
+    builtins::{array::new_array, value::inspect::describe},
+    exec::Interpreter,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+    error,
+    hash::BuildHasher,
+};
+
+/// The largest integer a `Number` holds exactly, `2^53 - 1`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
 
 /// Conversion to Javascript values from Rust values
+///
+/// The arrays and objects created by `to_value` have no prototype, `to_value_in` gives them the
+/// prototypes of the realm of the interpreter, like the arrays and objects of scripts.
 pub trait ToValue {
     /// Convert this value to a Rust value
     fn to_value(&self) -> Value;
+
+    /// Convert this value to a Javascript value of the realm of `ctx`
+    fn to_value_in(&self, _ctx: &Interpreter) -> Value {
+        self.to_value()
+    }
 }
 /// Conversion to Rust values from Javascript values
 pub trait FromValue {
     /// Convert this value to a Javascript value
-    fn from_value(value: Value) -> Result<Self, &'static str>
+    fn from_value(value: Value) -> Result<Self, ConversionError>
     where
         Self: Sized;
 }
 
+/// Conversion to the completion of a Javascript function from a Rust result, where `Err` is thrown
+pub trait ToResultValue {
+    /// Convert this result to a completion
+    fn to_result_value(&self) -> ResultValue;
+}
+
+/// The error of a conversion of a Javascript value to a Rust value.
+///
+/// Scripts get it as a `TypeError`, see `to_type_error`. The error keeps the path of the value
+/// which failed to convert inside the converted one, like `points[1].x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    message: String,
+    /// The keys leading to the value which failed to convert, the innermost first
+    path: Vec<String>,
+}
+
+impl ConversionError {
+    /// Create a new error with the given message
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            message: message.into(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Create an error for a value which isn't what the conversion expected
+    pub fn expected(expected: &str, value: &Value) -> Self {
+        Self::new(format!("expected {}, got {}", expected, describe(value)))
+    }
+
+    /// The message of the error, without the path
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The error happened when converting the property `key` of the value
+    pub fn at_key(mut self, key: &str) -> Self {
+        self.path.push(format!(".{}", key));
+        self
+    }
+
+    /// The error happened when converting the element `index` of the value
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.path.push(format!("[{}]", index));
+        self
+    }
+
+    /// Construct the `TypeError` thrown for this error
+    pub fn to_type_error(&self, ctx: &mut Interpreter) -> Value {
+        ctx.construct_type_error(self.to_string())
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if !self.path.is_empty() {
+            let path: String = self.path.iter().rev().map(String::as_str).collect();
+            write!(f, " at {}", path.trim_start_matches('.'))?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ConversionError {}
+
 impl ToValue for Value {
     fn to_value(&self) -> Value {
         self.clone()
@@ -20,42 +108,55 @@ impl ToValue for Value {
 }
 
 impl FromValue for Value {
-    fn from_value(value: Value) -> Result<Self, &'static str> {
+    fn from_value(value: Value) -> Result<Self, ConversionError> {
         Ok(value)
     }
 }
 
-impl ToValue for String {
+impl ToValue for JsString {
     fn to_value(&self) -> Value {
         Gc::new(ValueData::String(self.clone()))
     }
 }
 
+impl FromValue for JsString {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        match *v {
+            ValueData::String(ref s) => Ok(s.clone()),
+            _ => Ok(Self::from(v.to_string())),
+        }
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Gc::new(ValueData::String(JsString::from(self)))
+    }
+}
+
 impl FromValue for String {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.to_string())
     }
 }
 
 impl<'s> ToValue for &'s str {
     fn to_value(&self) -> Value {
-        Gc::new(ValueData::String(
-            String::from_str(*self).expect("Could not convert string to self to String"),
-        ))
+        Gc::new(ValueData::String(JsString::from(*self)))
     }
 }
 
 impl ToValue for char {
     fn to_value(&self) -> Value {
-        Gc::new(ValueData::String(self.to_string()))
+        Gc::new(ValueData::String(JsString::from(*self)))
     }
 }
 impl FromValue for char {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
-        Ok(v.to_string()
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        v.to_string()
             .chars()
             .next()
-            .expect("Could not get next char"))
+            .ok_or_else(|| ConversionError::expected("a non-empty string", &v))
     }
 }
 
@@ -65,18 +166,29 @@ impl ToValue for f64 {
     }
 }
 impl FromValue for f64 {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.to_number())
     }
 }
 
+impl ToValue for f32 {
+    fn to_value(&self) -> Value {
+        Gc::new(ValueData::Rational(f64::from(*self)))
+    }
+}
+impl FromValue for f32 {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        Ok(v.to_number() as Self)
+    }
+}
+
 impl ToValue for i32 {
     fn to_value(&self) -> Value {
         Gc::new(ValueData::Integer(*self))
     }
 }
 impl FromValue for i32 {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.to_integer())
     }
 }
@@ -87,51 +199,330 @@ impl ToValue for usize {
     }
 }
 impl FromValue for usize {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.to_integer() as Self)
     }
 }
 
+/// Converts an integer to an `Integer` value if it fits, or to a `Rational` one.
+fn integer_to_value<I>(integer: I, as_f64: f64) -> Value
+where
+    i32: TryFrom<I>,
+{
+    match i32::try_from(integer) {
+        Ok(integer) => Gc::new(ValueData::Integer(integer)),
+        Err(_) => Gc::new(ValueData::Rational(as_f64)),
+    }
+}
+
+/// Converts a value to a number which is an integer in `min..max`, for the integer type `name`.
+fn integer_from_value(v: &Value, name: &str, min: f64, max: f64) -> Result<f64, ConversionError> {
+    let number = v.to_number();
+    if number.fract() == 0.0 && number >= min && number < max {
+        Ok(number)
+    } else {
+        Err(ConversionError::expected(
+            &format!("an integer in the range of {}", name),
+            v,
+        ))
+    }
+}
+
+macro_rules! integer_conversions {
+    ($($type:ty, $name:expr, $min:expr, $max:expr);* $(;)?) => {
+        $(
+            impl ToValue for $type {
+                fn to_value(&self) -> Value {
+                    integer_to_value(*self, *self as f64)
+                }
+            }
+            impl FromValue for $type {
+                fn from_value(v: Value) -> Result<Self, ConversionError> {
+                    integer_from_value(&v, $name, $min, $max).map(|number| number as Self)
+                }
+            }
+        )*
+    };
+}
+
+integer_conversions! {
+    u8, "u8", 0.0, 256.0;
+    u32, "u32", 0.0, 4_294_967_296.0;
+    i64, "i64", -9_223_372_036_854_775_808.0, 9_223_372_036_854_775_808.0;
+    u64, "u64", 0.0, 18_446_744_073_709_551_616.0;
+}
+
 impl ToValue for bool {
     fn to_value(&self) -> Value {
         Gc::new(ValueData::Boolean(*self))
     }
 }
 impl FromValue for bool {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.is_true())
     }
 }
 
+/// Convert a value with `to_value_in` when there is an interpreter, or `to_value` otherwise
+pub fn to_value_with<T: ToValue + ?Sized>(value: &T, ctx: Option<&Interpreter>) -> Value {
+    match ctx {
+        Some(ctx) => value.to_value_in(ctx),
+        None => value.to_value(),
+    }
+}
+
+/// Create an array with the given elements
+///
+/// With an interpreter, the array is created by the `Array` constructor of its realm, without one
+/// the array has no prototype.
+pub fn array_from_values<I: IntoIterator<Item = Value>>(
+    ctx: Option<&Interpreter>,
+    values: I,
+) -> Value {
+    let array = if let Some(ctx) = ctx {
+        new_array(ctx).expect("creating an empty array doesn't throw")
+    } else {
+        let array = ValueData::new_obj(None);
+        array.set_kind(ObjectKind::Array);
+        array
+    };
+    let mut length = 0;
+    for (i, item) in values.into_iter().enumerate() {
+        array.set_prop(i.to_string(), Property::default().value(item));
+        length = i + 1;
+    }
+    array.set_prop_slice(
+        "length",
+        Property::new()
+            .value(to_value(length))
+            .writable(true)
+            .configurable(false)
+            .enumerable(false),
+    );
+    array
+}
+
+/// Create an object with the given properties
+///
+/// With an interpreter, the object inherits from the `Object.prototype` of its realm, without one
+/// it has no prototype.
+pub fn object_from_entries<K, I>(ctx: Option<&Interpreter>, entries: I) -> Value
+where
+    K: Into<String>,
+    I: IntoIterator<Item = (K, Value)>,
+{
+    let object = ValueData::new_obj(ctx.map(|ctx| &ctx.get_realm().global_obj));
+    for (key, value) in entries {
+        object.set_prop(key.into(), Property::default().value(value));
+    }
+    object
+}
+
+/// Convert the property `key` of an object, the error has the key in its path
+pub fn field_from_value<T: FromValue>(object: &Value, key: &str) -> Result<T, ConversionError> {
+    from_value(object.get_field_slice(key)).map_err(|error| error.at_key(key))
+}
+
+/// Convert the element `index` of an array, the error has the index in its path
+pub fn element_from_value<T: FromValue>(array: &Value, index: usize) -> Result<T, ConversionError> {
+    from_value(array.get_field_slice(&index.to_string())).map_err(|error| error.at_index(index))
+}
+
+/// Get the own enumerable keys of an object, or an error if the value isn't an object
+pub fn object_keys(v: &Value) -> Result<Vec<String>, ConversionError> {
+    match **v {
+        ValueData::Object(ref obj) => Ok(obj
+            .borrow()
+            .properties
+            .iter()
+            .filter(|(_, property)| property.enumerable != Some(false))
+            .map(|(key, _)| key.to_string())
+            .collect()),
+        _ => Err(ConversionError::expected("an object", v)),
+    }
+}
+
+/// Split the value of an enum variant into its name and its content
+///
+/// Unit variants are their name, the others an object with the name as its only key and the
+/// content as its value, like `{ Circle: { radius: 1 } }`.
+pub fn variant_from_value(v: &Value) -> Result<(String, Option<Value>), ConversionError> {
+    if let ValueData::String(ref name) = **v {
+        return Ok((name.to_string(), None));
+    }
+    match *object_keys(v)
+        .map_err(|_| ConversionError::expected("a string or an object with a single key", v))?
+    {
+        [ref key] => Ok((key.clone(), Some(v.get_field_slice(key)))),
+        _ => Err(ConversionError::expected(
+            "a string or an object with a single key",
+            v,
+        )),
+    }
+}
+
+/// Convert the elements of a slice to an array
+fn slice_to_value<T: ToValue>(slice: &[T], ctx: Option<&Interpreter>) -> Value {
+    array_from_values(ctx, slice.iter().map(|item| to_value_with(item, ctx)))
+}
+
 impl<'s, T: ToValue> ToValue for &'s [T] {
     fn to_value(&self) -> Value {
-        let mut arr = Object::default();
-        for (i, item) in self.iter().enumerate() {
-            arr.properties
-                .insert(i.to_string(), Property::default().value(item.to_value()));
-        }
-        to_value(arr)
+        slice_to_value(self, None)
+    }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        slice_to_value(self, Some(ctx))
     }
 }
 impl<T: ToValue> ToValue for Vec<T> {
     fn to_value(&self) -> Value {
-        let mut arr = Object::default();
-        for (i, item) in self.iter().enumerate() {
-            arr.properties
-                .insert(i.to_string(), Property::default().value(item.to_value()));
-        }
-        to_value(arr)
+        slice_to_value(self, None)
     }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        slice_to_value(self, Some(ctx))
+    }
+}
+
+/// Get the `length` of an array-like value with the abstract operation `ToLength`
+///
+/// Lengths which are objects or symbols, and lengths which don't fit in `usize`, are errors.
+fn length_from_value(v: &Value) -> Result<usize, ConversionError> {
+    let length = v.get_field_slice("length");
+    if length.is_object() || length.is_symbol() {
+        return Err(ConversionError::expected("a number", &length).at_key("length"));
+    }
+    let number = length.to_number();
+    let clamped = if number.is_nan() {
+        0.0
+    } else {
+        number.trunc().clamp(0.0, MAX_SAFE_INTEGER)
+    };
+    // The length comes from the script, it can't exceed the number of elements which could be
+    // present, or the vector would grow until the allocator gives up.
+    let present = match **v {
+        ValueData::Object(ref obj) => obj.borrow().properties.len(),
+        ValueData::String(ref string) => string.as_slice().len(),
+        _ => 0,
+    };
+    if clamped > present as f64 {
+        return Err(ConversionError::expected(
+            &format!("a length of at most {}, the number of properties", present),
+            &length,
+        )
+        .at_key("length"));
+    }
+    Ok(clamped as usize)
 }
 
 impl<T: FromValue> FromValue for Vec<T> {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
-        let len = v.get_field_slice("length").to_integer();
-        let mut vec = Self::with_capacity(len as usize);
-        for i in 0..len {
-            vec.push(from_value(v.get_field_slice(&i.to_string()))?)
-        }
-        Ok(vec)
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        (0..length_from_value(&v)?)
+            .map(|i| element_from_value(&v, i))
+            .collect()
+    }
+}
+
+impl<T: ToValue> ToValue for Box<[T]> {
+    fn to_value(&self) -> Value {
+        slice_to_value(self, None)
+    }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        slice_to_value(self, Some(ctx))
+    }
+}
+impl<T: FromValue> FromValue for Box<[T]> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        Vec::from_value(v).map(Vec::into_boxed_slice)
+    }
+}
+
+macro_rules! tuple_conversions {
+    ($(($($type:ident $index:tt),+) with length $len:expr;)*) => {
+        $(
+            impl<$($type: ToValue),+> ToValue for ($($type,)+) {
+                fn to_value(&self) -> Value {
+                    array_from_values(None, vec![$(self.$index.to_value()),+])
+                }
+
+                fn to_value_in(&self, ctx: &Interpreter) -> Value {
+                    array_from_values(Some(ctx), vec![$(self.$index.to_value_in(ctx)),+])
+                }
+            }
+            impl<$($type: FromValue),+> FromValue for ($($type,)+) {
+                fn from_value(v: Value) -> Result<Self, ConversionError> {
+                    if !v.is_object() || v.get_field_slice("length").to_integer() != $len {
+                        return Err(ConversionError::expected(
+                            concat!("an array of ", $len, " elements"),
+                            &v,
+                        ));
+                    }
+                    Ok(($(element_from_value::<$type>(&v, $index)?,)+))
+                }
+            }
+        )*
+    };
+}
+
+tuple_conversions! {
+    (A 0) with length 1;
+    (A 0, B 1) with length 2;
+    (A 0, B 1, C 2) with length 3;
+    (A 0, B 1, C 2, D 3) with length 4;
+    (A 0, B 1, C 2, D 3, E 4) with length 5;
+    (A 0, B 1, C 2, D 3, E 4, F 5) with length 6;
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6) with length 7;
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7) with length 8;
+}
+
+/// Convert the entries of a map to an object
+fn map_to_value<'m, T, I>(map: I, ctx: Option<&Interpreter>) -> Value
+where
+    T: ToValue + 'm,
+    I: IntoIterator<Item = (&'m String, &'m T)>,
+{
+    object_from_entries(
+        ctx,
+        map.into_iter()
+            .map(|(key, value)| (key.clone(), to_value_with(value, ctx))),
+    )
+}
+
+impl<T: ToValue, S: BuildHasher> ToValue for HashMap<String, T, S> {
+    fn to_value(&self) -> Value {
+        map_to_value(self, None)
+    }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        map_to_value(self, Some(ctx))
+    }
+}
+impl<T: FromValue, S: BuildHasher + Default> FromValue for HashMap<String, T, S> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        object_keys(&v)?
+            .into_iter()
+            .map(|key| field_from_value(&v, &key).map(|value| (key, value)))
+            .collect()
+    }
+}
+
+impl<T: ToValue> ToValue for BTreeMap<String, T> {
+    fn to_value(&self) -> Value {
+        map_to_value(self, None)
+    }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        map_to_value(self, Some(ctx))
+    }
+}
+impl<T: FromValue> FromValue for BTreeMap<String, T> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
+        object_keys(&v)?
+            .into_iter()
+            .map(|key| field_from_value(&v, &key).map(|value| (key, value)))
+            .collect()
     }
 }
 
@@ -142,10 +533,10 @@ impl ToValue for Object {
 }
 
 impl FromValue for Object {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         match *v {
             ValueData::Object(ref obj) => Ok(obj.clone().into_inner()),
-            _ => Err("Value is not a valid object"),
+            _ => Err(ConversionError::expected("an object", &v)),
         }
     }
 }
@@ -157,7 +548,7 @@ impl ToValue for JSONValue {
 }
 
 impl FromValue for JSONValue {
-    fn from_value(v: Value) -> Result<Self, &'static str> {
+    fn from_value(v: Value) -> Result<Self, ConversionError> {
         Ok(v.to_json())
     }
 }
@@ -168,7 +559,7 @@ impl ToValue for () {
     }
 }
 impl FromValue for () {
-    fn from_value(_: Value) -> Result<(), &'static str> {
+    fn from_value(_: Value) -> Result<(), ConversionError> {
         Ok(())
     }
 }
@@ -180,9 +571,16 @@ impl<T: ToValue> ToValue for Option<T> {
             None => Gc::new(ValueData::Null),
         }
     }
+
+    fn to_value_in(&self, ctx: &Interpreter) -> Value {
+        match *self {
+            Some(ref v) => v.to_value_in(ctx),
+            None => Gc::new(ValueData::Null),
+        }
+    }
 }
 impl<T: FromValue> FromValue for Option<T> {
-    fn from_value(value: Value) -> Result<Self, &'static str> {
+    fn from_value(value: Value) -> Result<Self, ConversionError> {
         Ok(if value.is_null_or_undefined() {
             None
         } else {
@@ -191,8 +589,17 @@ impl<T: FromValue> FromValue for Option<T> {
     }
 }
 
+impl<T: ToValue, E: ToValue> ToResultValue for Result<T, E> {
+    fn to_result_value(&self) -> ResultValue {
+        match *self {
+            Ok(ref v) => Ok(v.to_value()),
+            Err(ref e) => Err(e.to_value()),
+        }
+    }
+}
+
 /// A utility function that just calls `FromValue::from_value`
-pub fn from_value<A: FromValue>(v: Value) -> Result<A, &'static str> {
+pub fn from_value<A: FromValue>(v: Value) -> Result<A, ConversionError> {
     FromValue::from_value(v)
 }
 
@@ -0,0 +1,684 @@
+//! This module implements `inspect`, which prints values for humans, like `util.inspect` of Node.js.
+//!
+//! Unlike the `Display` implementation of values, it quotes the strings nested in objects, names
+//! the constructors of objects, renders the holes of arrays, marks the cycles between objects and
+//! stops at a depth limit. It is used by `console.log`, `console.dir` and the REPL of the CLI.
+//!
+//! The objects are read without running any code: accessor properties are printed as `[Getter]`
+//! or `[Setter]` and proxies are printed as their target.
+//!
+//! More information:
+//!  - [Node.js documentation][node]
+//!
+//! [node]: https://nodejs.org/api/util.html#util_util_inspect_object_options
+
+use crate::builtins::{
+    date,
+    object::{Object, ObjectKind, INSTANCE_PROTOTYPE},
+    property::Property,
+    value::{JsString, Value, ValueData},
+};
+use gc::GcCell;
+use rustc_hash::FxHashMap;
+
+/// The options of `inspect`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InspectOptions {
+    /// How many levels of nested objects are printed, `None` for no limit.
+    ///
+    /// Defaults to `Some(2)`, the deeper objects are printed like `[Object]`.
+    pub depth: Option<usize>,
+    /// Whether to style the output with ANSI color codes. Defaults to `false`.
+    pub colors: bool,
+    /// The maximum number of elements printed for arrays, typed arrays, maps and sets, `None` for
+    /// no limit. Defaults to `Some(100)`.
+    pub max_array_length: Option<usize>,
+    /// Whether to sort the keys of objects, which are otherwise in the order of the object.
+    /// Defaults to `false`.
+    pub sorted: bool,
+    /// Whether to print the non-enumerable properties of objects too. Defaults to `false`.
+    pub show_hidden: bool,
+    /// The width up to which the entries of an object are printed on a single line. Defaults to
+    /// `80`.
+    pub break_length: usize,
+}
+
+impl Default for InspectOptions {
+    fn default() -> Self {
+        Self {
+            depth: Some(2),
+            colors: false,
+            max_array_length: Some(100),
+            sorted: false,
+            show_hidden: false,
+            break_length: 80,
+        }
+    }
+}
+
+/// Prints `value` for humans.
+pub fn inspect(value: &Value, options: &InspectOptions) -> String {
+    Inspector {
+        options,
+        seen: Vec::new(),
+        circular: FxHashMap::default(),
+    }
+    .format_value(value, 0, 0)
+}
+
+/// Describes a value in an error message, without the properties of objects.
+pub(crate) fn describe(value: &Value) -> String {
+    inspect(
+        value,
+        &InspectOptions {
+            depth: Some(0),
+            ..InspectOptions::default()
+        },
+    )
+}
+
+/// The styles of the parts of the output, with the ANSI color codes of Node.js.
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Number,
+    String,
+    Null,
+    Undefined,
+    Special,
+    Date,
+    RegExp,
+}
+
+impl Style {
+    /// The codes starting and ending the style.
+    fn codes(self) -> (u8, u8) {
+        match self {
+            Self::Number => (33, 39),
+            Self::String => (32, 39),
+            Self::Null => (1, 22),
+            Self::Undefined => (90, 39),
+            Self::Special => (36, 39),
+            Self::Date => (35, 39),
+            Self::RegExp => (31, 39),
+        }
+    }
+}
+
+/// The length of `text` on the screen, without its color codes.
+fn visible_length(text: &str) -> usize {
+    let mut length = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            chars.by_ref().find(|c| *c == 'm');
+        } else {
+            length += 1;
+        }
+    }
+    length
+}
+
+/// Quotes a string, with single quotes unless it contains some.
+fn quote(string: &str) -> String {
+    let quote = if !string.contains('\'') {
+        '\''
+    } else if !string.contains('"') {
+        '"'
+    } else if !string.contains('`') && !string.contains("${") {
+        '`'
+    } else {
+        '\''
+    };
+
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push(quote);
+    for c in string.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\u{b}' => quoted.push_str("\\v"),
+            '\\' => quoted.push_str("\\\\"),
+            c if c == quote => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c if c.is_control() => quoted.push_str(&format!("\\x{:02X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push(quote);
+    quoted
+}
+
+/// Checks if `key` can be printed without quotes.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Checks if `key` is an array index.
+fn is_index(key: &str) -> bool {
+    match key.parse::<u32>() {
+        Ok(index) => index != u32::MAX && index.to_string() == key,
+        Err(_) => false,
+    }
+}
+
+/// Gets the data value of an own property, without running getters.
+fn own_value(object: &Object, key: &str) -> Option<Value> {
+    object
+        .properties
+        .get(&JsString::from(key))
+        .and_then(|prop| prop.value.clone())
+}
+
+/// Gets the name of the constructor of an object, from the `constructor` property of its
+/// prototypes, or `None` for objects without prototype.
+///
+/// The built-in constructors have no `name` property, so the name is empty when no named
+/// constructor is found before the end of the prototype chain.
+fn constructor_name(object: &Object) -> Option<String> {
+    let mut proto = match object.internal_slots.get(INSTANCE_PROTOTYPE) {
+        Some(proto) if proto.is_null() => return None,
+        proto => proto.cloned(),
+    };
+    while let Some(value) = proto {
+        let proto_object = match *value {
+            ValueData::Object(ref proto_object) => proto_object.borrow(),
+            _ => break,
+        };
+        if let Some(constructor) = own_value(&proto_object, "constructor") {
+            if let ValueData::Object(ref constructor) = *constructor {
+                if let Some(name) = own_value(&constructor.borrow(), "name") {
+                    if let ValueData::String(ref name) = *name {
+                        if !name.is_empty() {
+                            return Some(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        proto = proto_object.internal_slots.get(INSTANCE_PROTOTYPE).cloned();
+    }
+    Some(String::new())
+}
+
+/// Gets an internal slot of an object as a string.
+fn slot(object: &Object, name: &str) -> String {
+    object
+        .internal_slots
+        .get(name)
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Formats a number, keeping the sign of `-0`.
+fn format_number(value: &ValueData) -> String {
+    match *value {
+        ValueData::Rational(number) if number == 0.0 && number.is_sign_negative() => {
+            String::from("-0")
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// The state of a call to `inspect`.
+struct Inspector<'a> {
+    options: &'a InspectOptions,
+    /// The addresses of the objects being printed, from the outermost one.
+    seen: Vec<usize>,
+    /// The reference numbers of the objects found in cycles, by their address.
+    circular: FxHashMap<usize, usize>,
+}
+
+impl Inspector<'_> {
+    /// Styles `text` if colors are enabled.
+    fn stylize(&self, text: String, style: Style) -> String {
+        if self.options.colors {
+            let (start, end) = style.codes();
+            format!("\u{1b}[{}m{}\u{1b}[{}m", start, text, end)
+        } else {
+            text
+        }
+    }
+
+    /// Formats any value, `recurse_times` levels deep and printed after `indentation` spaces.
+    fn format_value(&mut self, value: &Value, recurse_times: usize, indentation: usize) -> String {
+        match **value {
+            ValueData::Undefined => self.stylize(String::from("undefined"), Style::Undefined),
+            ValueData::Null => self.stylize(String::from("null"), Style::Null),
+            ValueData::Boolean(boolean) => self.stylize(boolean.to_string(), Style::Number),
+            ValueData::Integer(_) | ValueData::Rational(_) => {
+                self.stylize(format_number(value), Style::Number)
+            }
+            ValueData::String(ref string) => {
+                self.stylize(quote(&string.to_string()), Style::String)
+            }
+            ValueData::Symbol(_) => self.stylize(value.to_string(), Style::String),
+            ValueData::Object(ref object) => self.format_object(object, recurse_times, indentation),
+        }
+    }
+
+    /// Formats an object, or a reference to it when it is part of a cycle.
+    fn format_object(
+        &mut self,
+        object: &GcCell<Object>,
+        recurse_times: usize,
+        indentation: usize,
+    ) -> String {
+        let address = object as *const GcCell<Object> as usize;
+        if self.seen.contains(&address) {
+            let next = self.circular.len() + 1;
+            let id = *self.circular.entry(address).or_insert(next);
+            return self.stylize(format!("[Circular *{}]", id), Style::Special);
+        }
+
+        self.seen.push(address);
+        let formatted = self.format_raw(object, recurse_times, indentation);
+        self.seen.pop();
+
+        match self.circular.get(&address) {
+            Some(id) => format!(
+                "{} {}",
+                self.stylize(format!("<ref *{}>", id), Style::Special),
+                formatted
+            ),
+            None => formatted,
+        }
+    }
+
+    /// Formats an object, with its kind, its constructor and its properties.
+    fn format_raw(
+        &mut self,
+        object: &GcCell<Object>,
+        recurse_times: usize,
+        indentation: usize,
+    ) -> String {
+        let obj = object.borrow();
+        let constructor = constructor_name(&obj);
+        let too_deep = self
+            .options
+            .depth
+            .map_or(false, |depth| recurse_times > depth);
+
+        // The text printed before the entries, or instead of them when there are none
+        let mut base = String::new();
+        // The text printed before the braces
+        let mut prefix = String::new();
+        let mut braces = ("{", "}");
+        let mut entries = Vec::new();
+        // Whether the keys of the own properties are the keys of the entries
+        let mut skip_key: fn(&str) -> bool = |_| false;
+
+        match obj.kind {
+            ObjectKind::Proxy(Some(ref data)) => {
+                return self.format_value(&data.target, recurse_times, indentation)
+            }
+            ObjectKind::Proxy(None) => {
+                return self.stylize(String::from("<Revoked Proxy>"), Style::Special)
+            }
+            _ if obj.is_callable() => {
+                let name = match own_value(&obj, "name") {
+                    Some(ref name) if name.is_string() && !name.to_string().is_empty() => {
+                        format!("[Function: {}]", name)
+                    }
+                    _ => String::from("[Function (anonymous)]"),
+                };
+                base = self.stylize(name, Style::Special);
+            }
+            ObjectKind::Array => {
+                if too_deep {
+                    return self.stylize(String::from("[Array]"), Style::Special);
+                }
+                braces = ("[", "]");
+                let len = own_value(&obj, "length").map_or(0, |len| len.to_integer() as usize);
+                match constructor {
+                    Some(ref name) if name.is_empty() || name == "Array" => {}
+                    Some(ref name) => prefix = format!("{}({})", name, len),
+                    None => prefix = format!("[Array({}): null prototype]", len),
+                }
+                entries = self.format_array(&obj, len, recurse_times, indentation);
+                skip_key = is_index;
+            }
+            ObjectKind::TypedArray(ref data) => {
+                if too_deep {
+                    return self.stylize(format!("[{}]", data.kind.name()), Style::Special);
+                }
+                braces = ("[", "]");
+                let values = data.values();
+                prefix = format!("{}({})", data.kind.name(), values.len());
+                let shown = self.options.max_array_length.unwrap_or(usize::MAX);
+                for value in values.iter().take(shown) {
+                    entries.push(
+                        self.stylize(format_number(&ValueData::Rational(*value)), Style::Number),
+                    );
+                }
+                self.push_more_items(&mut entries, values.len(), shown);
+                skip_key = is_index;
+            }
+            ObjectKind::Map(ref map) | ObjectKind::Set(ref map) => {
+                let is_map = matches!(obj.kind, ObjectKind::Map(_));
+                let name = if is_map { "Map" } else { "Set" };
+                if too_deep {
+                    return self.stylize(format!("[{}]", name), Style::Special);
+                }
+                prefix = format!("{}({})", name, map.len());
+                let shown = self.options.max_array_length.unwrap_or(usize::MAX);
+                for (key, value) in map.iter().take(shown) {
+                    let value = self.format_value(value, recurse_times + 1, indentation + 2);
+                    entries.push(if is_map {
+                        let key = self.format_value(key, recurse_times + 1, indentation + 2);
+                        format!("{} => {}", key, value)
+                    } else {
+                        value
+                    });
+                }
+                self.push_more_items(&mut entries, map.len(), shown);
+            }
+            ObjectKind::WeakMap(_) | ObjectKind::WeakSet(_) => {
+                prefix = obj.kind.to_string();
+                entries.push(self.stylize(String::from("<items unknown>"), Style::Special));
+            }
+            ObjectKind::WeakRef(ref handle) => {
+                prefix = String::from("WeakRef");
+                entries.push(match handle.upgrade() {
+                    Some(target) => self.format_value(&target, recurse_times + 1, indentation + 2),
+                    None => self.stylize(String::from("<cleared>"), Style::Special),
+                });
+            }
+            ObjectKind::ArrayBuffer(ref data) => {
+                if too_deep {
+                    return self.stylize(String::from("[ArrayBuffer]"), Style::Special);
+                }
+                prefix = String::from("ArrayBuffer");
+                let shown = self.options.max_array_length.unwrap_or(usize::MAX);
+                let mut contents: Vec<String> = data
+                    .bytes
+                    .iter()
+                    .take(shown)
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect();
+                if data.bytes.len() > shown {
+                    contents.push(format!("... {} more bytes", data.bytes.len() - shown));
+                }
+                entries.push(format!("[Uint8Contents]: <{}>", contents.join(" ")));
+                entries.push(format!(
+                    "byteLength: {}",
+                    self.stylize(data.bytes.len().to_string(), Style::Number)
+                ));
+            }
+            ObjectKind::Date(t) => {
+                let date = date::iso_string(t).unwrap_or_else(|| String::from("Invalid Date"));
+                base = self.stylize(date, Style::Date);
+            }
+            ObjectKind::Error => {
+                base = match own_value(&obj, "stack") {
+                    Some(ref stack) if stack.is_string() => stack.to_string(),
+                    _ => {
+                        let name = self
+                            .lookup(&obj, "name")
+                            .unwrap_or_else(|| String::from("Error"));
+                        match self.lookup(&obj, "message") {
+                            Some(ref message) if !message.is_empty() => {
+                                format!("{}: {}", name, message)
+                            }
+                            _ => name,
+                        }
+                    }
+                };
+                if indentation > 0 {
+                    base = base.replace('\n', &format!("\n{}", " ".repeat(indentation)));
+                }
+            }
+            ObjectKind::String => {
+                let string = slot(&obj, "StringData");
+                base = self.stylize(format!("[String: {}]", quote(&string)), Style::String);
+                skip_key = is_index;
+            }
+            _ if obj.internal_slots.contains_key("NumberData") => {
+                let data = obj
+                    .internal_slots
+                    .get("NumberData")
+                    .map(|data| format_number(data));
+                let number = format!("[Number: {}]", data.unwrap_or_default());
+                base = self.stylize(number, Style::Number);
+            }
+            _ if obj.internal_slots.contains_key("BooleanData") => {
+                let boolean = format!("[Boolean: {}]", slot(&obj, "BooleanData"));
+                base = self.stylize(boolean, Style::Number);
+            }
+            _ if obj.internal_slots.contains_key("RegExpMatcher") => {
+                let source = slot(&obj, "OriginalSource");
+                let flags = slot(&obj, "OriginalFlags");
+                base = self.stylize(format!("/{}/{}", source, flags), Style::RegExp);
+            }
+            _ if obj.internal_slots.contains_key("SymbolData") => {
+                let symbol = slot(&obj, "SymbolData");
+                base = self.stylize(format!("[Symbol: {}]", symbol), Style::String);
+            }
+            _ => {
+                let name = match constructor {
+                    Some(ref name) if name.is_empty() => "Object",
+                    Some(ref name) => name,
+                    None => "Object: null prototype",
+                };
+                if too_deep {
+                    return self.stylize(format!("[{}]", name), Style::Special);
+                }
+                prefix = match constructor {
+                    Some(ref name) if name.is_empty() || name == "Object" => String::new(),
+                    Some(ref name) => name.clone(),
+                    None => format!("[{}]", name),
+                };
+            }
+        }
+
+        if too_deep {
+            return base;
+        }
+
+        entries.extend(self.format_properties(&obj, skip_key, recurse_times, indentation));
+        self.reduce_to_single_string(&base, &prefix, braces, entries, indentation)
+    }
+
+    /// Looks up a string property in the prototype chain of an object, without running getters.
+    fn lookup(&self, object: &Object, key: &str) -> Option<String> {
+        if let Some(value) = own_value(object, key) {
+            return Some(value.to_string());
+        }
+        match object
+            .internal_slots
+            .get(INSTANCE_PROTOTYPE)
+            .map(|proto| &**proto)
+        {
+            Some(ValueData::Object(ref proto)) => self.lookup(&proto.borrow(), key),
+            _ => None,
+        }
+    }
+
+    /// Formats the elements of an array, grouping its holes.
+    fn format_array(
+        &mut self,
+        object: &Object,
+        len: usize,
+        recurse_times: usize,
+        indentation: usize,
+    ) -> Vec<String> {
+        let shown = self.options.max_array_length.unwrap_or(usize::MAX);
+        let mut entries = Vec::new();
+        let mut index = 0;
+        while index < len && entries.len() < shown {
+            if let Some(prop) = object.properties.get(&JsString::from(index.to_string())) {
+                entries.push(self.format_property_value(prop, recurse_times, indentation));
+                index += 1;
+            } else {
+                let start = index;
+                while index < len
+                    && !object
+                        .properties
+                        .contains_key(&JsString::from(index.to_string()))
+                {
+                    index += 1;
+                }
+                let holes = index - start;
+                let plural = if holes == 1 { "" } else { "s" };
+                entries.push(self.stylize(
+                    format!("<{} empty item{}>", holes, plural),
+                    Style::Undefined,
+                ));
+            }
+        }
+        if index < len {
+            let remaining = len - index;
+            let plural = if remaining == 1 { "" } else { "s" };
+            entries.push(format!("... {} more item{}", remaining, plural));
+        }
+        entries
+    }
+
+    /// Adds the count of the elements which aren't printed.
+    fn push_more_items(&self, entries: &mut Vec<String>, len: usize, shown: usize) {
+        if len > shown {
+            let remaining = len - shown;
+            let plural = if remaining == 1 { "" } else { "s" };
+            entries.push(format!("... {} more item{}", remaining, plural));
+        }
+    }
+
+    /// Formats the value of a property, or its accessors.
+    fn format_property_value(
+        &mut self,
+        prop: &Property,
+        recurse_times: usize,
+        indentation: usize,
+    ) -> String {
+        if let Some(ref value) = prop.value {
+            return self.format_value(value, recurse_times + 1, indentation + 2);
+        }
+        let has_getter = prop.get.as_ref().map_or(false, |get| !get.is_undefined());
+        let has_setter = prop.set.as_ref().map_or(false, |set| !set.is_undefined());
+        let accessors = match (has_getter, has_setter) {
+            (true, true) => "[Getter/Setter]",
+            (true, false) => "[Getter]",
+            (false, true) => "[Setter]",
+            (false, false) => "undefined",
+        };
+        self.stylize(String::from(accessors), Style::Special)
+    }
+
+    /// Formats the own properties of an object as `key: value` entries.
+    fn format_properties(
+        &mut self,
+        object: &Object,
+        skip_key: fn(&str) -> bool,
+        recurse_times: usize,
+        indentation: usize,
+    ) -> Vec<String> {
+        let visible = |prop: &Property| self.options.show_hidden || prop.enumerable == Some(true);
+
+        let mut indices = Vec::new();
+        let mut strings = Vec::new();
+        for (key, prop) in object.properties.iter() {
+            let key = key.to_string();
+            if skip_key(&key) || !visible(prop) {
+                continue;
+            }
+            match key.parse::<u32>() {
+                Ok(index) if is_index(&key) => indices.push((index, key, prop)),
+                _ => strings.push((key, prop)),
+            }
+        }
+        indices.sort_unstable_by_key(|(index, _, _)| *index);
+        let mut symbols: Vec<(String, &Property, bool)> = object
+            .sym_properties
+            .iter()
+            .filter(|(_, prop)| visible(prop))
+            .filter_map(|(id, prop)| {
+                let symbol = object.sym_keys.get(id)?;
+                Some((symbol.to_string(), prop, prop.enumerable == Some(true)))
+            })
+            .collect();
+        if self.options.sorted {
+            strings.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            symbols.sort_unstable_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        }
+
+        let mut entries = Vec::new();
+        let keys = indices
+            .into_iter()
+            .map(|(_, key, prop)| (key, prop))
+            .chain(strings);
+        for (key, prop) in keys {
+            let mut name = if is_identifier(&key) {
+                key
+            } else {
+                self.stylize(quote(&key), Style::String)
+            };
+            if prop.enumerable != Some(true) {
+                name = format!("[{}]", name);
+            }
+            let value = self.format_property_value(prop, recurse_times, indentation);
+            entries.push(format!("{}: {}", name, value));
+        }
+        for (symbol, prop, enumerable) in symbols {
+            let mut name = format!("[{}]", self.stylize(symbol, Style::String));
+            if !enumerable {
+                name = format!("[{}]", name);
+            }
+            let value = self.format_property_value(prop, recurse_times, indentation);
+            entries.push(format!("{}: {}", name, value));
+        }
+        entries
+    }
+
+    /// Joins the entries of an object on a single line when they fit in the break length, or on
+    /// one line each otherwise.
+    fn reduce_to_single_string(
+        &self,
+        base: &str,
+        prefix: &str,
+        braces: (&str, &str),
+        entries: Vec<String>,
+        indentation: usize,
+    ) -> String {
+        let (open, close) = braces;
+        let start = match (base.is_empty(), prefix.is_empty()) {
+            (false, _) if entries.is_empty() => return base.to_string(),
+            (false, _) => format!("{} {}", base, open),
+            (true, true) => open.to_string(),
+            (true, false) => format!("{} {}", prefix, open),
+        };
+        if entries.is_empty() {
+            return format!("{}{}", start, close);
+        }
+
+        let total_length = entries
+            .iter()
+            .map(|entry| visible_length(entry) + 2)
+            .sum::<usize>()
+            + visible_length(&start)
+            + indentation;
+        if total_length < self.options.break_length
+            && entries.iter().all(|entry| !entry.contains('\n'))
+        {
+            return format!("{} {} {}", start, entries.join(", "), close);
+        }
+
+        let padding = " ".repeat(indentation + 2);
+        format!(
+            "{}\n{}{}\n{}{}",
+            start,
+            padding,
+            entries.join(&format!(",\n{}", padding)),
+            " ".repeat(indentation),
+            close
+        )
+    }
+}
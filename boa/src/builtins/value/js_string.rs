@@ -0,0 +1,265 @@
+//! This module implements the `JsString` type, the representation of a JavaScript string value.
+//!
+//! JavaScript strings are sequences of 16-bit unsigned integers (UTF-16 code units). They are
+//! not required to be valid UTF-16, so lone surrogates are representable, and every index a
+//! script can observe (`length`, `charAt`, `slice`...) is measured in code units.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-types-string-type
+
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    borrow::Borrow,
+    char::{decode_utf16, REPLACEMENT_CHARACTER},
+    fmt::{self, Debug, Display},
+    ops::Deref,
+    rc::Rc,
+    string::FromUtf16Error,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An immutable, reference counted JavaScript string.
+///
+/// Cloning a `JsString` only increments a reference count, the code units are shared.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsString {
+    inner: Rc<[u16]>,
+}
+
+impl JsString {
+    /// Creates a new, empty `JsString`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_utf16(&[])
+    }
+
+    /// Creates a `JsString` from a slice of UTF-16 code units.
+    ///
+    /// The code units don't need to be valid UTF-16.
+    #[inline]
+    pub fn from_utf16(units: &[u16]) -> Self {
+        Self {
+            inner: Rc::from(units),
+        }
+    }
+
+    /// Returns the code units of the string.
+    #[inline]
+    pub fn as_slice(&self) -> &[u16] {
+        &self.inner
+    }
+
+    /// Returns the code unit at position `index`, if any.
+    #[inline]
+    pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+        self.inner.get(index).copied()
+    }
+
+    /// Returns the code point starting at position `index` and the number of code units it spans.
+    ///
+    /// A lone surrogate is returned as is, as a code point of one code unit.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-codepointat
+    pub fn code_point_at(&self, index: usize) -> Option<(u32, usize)> {
+        let first = self.code_unit_at(index)?;
+        if let (0xD800..=0xDBFF, Some(second @ 0xDC00..=0xDFFF)) =
+            (first, self.code_unit_at(index + 1))
+        {
+            let code_point =
+                0x10000 + ((u32::from(first) - 0xD800) << 10) + (u32::from(second) - 0xDC00);
+            return Some((code_point, 2));
+        }
+        Some((u32::from(first), 1))
+    }
+
+    /// Returns a new string holding the code units in `start..end`.
+    ///
+    /// Both bounds are clamped to the length of the string.
+    pub fn substring(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        let start = start.min(end);
+        Self::from_utf16(&self.inner[start..end])
+    }
+
+    /// Returns a new string which is `self` followed by `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut units = Vec::with_capacity(self.len() + other.len());
+        units.extend_from_slice(self);
+        units.extend_from_slice(other);
+        Self::from(units)
+    }
+
+    /// Returns the code unit index of the first occurrence of `search`, starting at `from`.
+    pub fn index_of(&self, search: &Self, from: usize) -> Option<usize> {
+        if search.is_empty() {
+            return if from <= self.len() { Some(from) } else { None };
+        }
+
+        (from..=self.len().checked_sub(search.len())?)
+            .find(|&i| self.inner[i..].starts_with(search))
+    }
+
+    /// Returns the code unit index of the last occurrence of `search`, starting at or before `from`.
+    pub fn last_index_of(&self, search: &Self, from: usize) -> Option<usize> {
+        let last = self.len().checked_sub(search.len())?;
+
+        (0..=from.min(last))
+            .rev()
+            .find(|&i| self.inner[i..].starts_with(search))
+    }
+
+    /// Converts the string into a Rust `String`, failing if it contains lone surrogates.
+    pub fn to_std_string(&self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(&self.inner)
+    }
+
+    /// Converts the string into a Rust `String`, replacing lone surrogates with
+    /// [`REPLACEMENT_CHARACTER`](std::char::REPLACEMENT_CHARACTER).
+    pub fn to_std_string_lossy(&self) -> String {
+        String::from_utf16_lossy(&self.inner)
+    }
+}
+
+impl Default for JsString {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for JsString {
+    type Target = [u16];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl AsRef<[u16]> for JsString {
+    #[inline]
+    fn as_ref(&self) -> &[u16] {
+        &self.inner
+    }
+}
+
+impl Borrow<[u16]> for JsString {
+    #[inline]
+    fn borrow(&self) -> &[u16] {
+        &self.inner
+    }
+}
+
+impl From<&str> for JsString {
+    fn from(s: &str) -> Self {
+        Self::from(s.encode_utf16().collect::<Vec<u16>>())
+    }
+}
+
+impl From<&String> for JsString {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for JsString {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<char> for JsString {
+    fn from(c: char) -> Self {
+        let mut buf = [0; 2];
+        Self::from_utf16(c.encode_utf16(&mut buf))
+    }
+}
+
+impl From<&[u16]> for JsString {
+    fn from(units: &[u16]) -> Self {
+        Self::from_utf16(units)
+    }
+}
+
+impl From<Vec<u16>> for JsString {
+    fn from(units: Vec<u16>) -> Self {
+        Self {
+            inner: Rc::from(units),
+        }
+    }
+}
+
+impl PartialEq<str> for JsString {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for JsString {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<JsString> for str {
+    fn eq(&self, other: &JsString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<JsString> for &str {
+    fn eq(&self, other: &JsString) -> bool {
+        other == *self
+    }
+}
+
+impl Display for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in decode_utf16(self.iter().copied()) {
+            write!(f, "{}", c.unwrap_or(REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_std_string_lossy(), f)
+    }
+}
+
+impl Finalize for JsString {}
+
+// The code units never hold garbage collected values.
+unsafe impl Trace for JsString {
+    unsafe_empty_trace!();
+}
+
+/// Strings are serialized as Rust strings, lone surrogates are replaced by
+/// [`REPLACEMENT_CHARACTER`](std::char::REPLACEMENT_CHARACTER).
+#[cfg(feature = "serde")]
+impl Serialize for JsString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_std_string_lossy())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for JsString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -6,10 +6,13 @@
 mod tests;
 
 use crate::builtins::{
-    function::Function,
+    date,
+    function::{set_function_name_and_length, Function, FunctionBody},
+    math,
     object::{
-        internal_methods_trait::ObjectInternalMethods, InternalState, InternalStateCell, Object,
-        ObjectKind, INSTANCE_PROTOTYPE, PROTOTYPE,
+        internal_methods::symbol_id, internal_methods_trait::ObjectInternalMethods,
+        InternalObjectMethods, InternalState, InternalStateCell, Object, ObjectKind,
+        INSTANCE_PROTOTYPE, PROTOTYPE,
     },
     property::Property,
 };
@@ -25,8 +28,13 @@ use std::{
 };
 
 pub mod conversions;
+pub mod inspect;
+pub mod js_string;
 pub mod operations;
+#[cfg(feature = "derive")]
+pub use boa_derive::{FromValue, ToValue};
 pub use conversions::*;
+pub use js_string::JsString;
 pub use operations::*;
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
@@ -50,8 +58,8 @@ pub enum ValueData {
     Undefined,
     /// `boolean` - A `true` / `false` value, for if a certain criteria is met
     Boolean(bool),
-    /// `String` - A UTF-8 string, such as `"Hello, world"`
-    String(String),
+    /// `String` - A UTF-16 string, such as `"Hello, world"`
+    String(JsString),
     /// `Number` - A 64-bit floating point number, such as `3.1415`
     Rational(f64),
     /// `Number` - A 32-bit integer, such as `42`
@@ -88,17 +96,18 @@ impl ValueData {
         Gc::new(Self::Object(Box::new(GcCell::new(obj))))
     }
 
-    /// This will tell us if we can exten an object or not, not properly implemented yet
+    /// Returns true if new properties can be added to the value.
     ///
-    /// For now always returns true.
+    /// Primitives are never extensible, objects are until `Object.preventExtensions`,
+    /// `Object.seal` or `Object.freeze` is called on them.
     ///
-    /// For scalar types it should be false, for objects check the private field for extensibilaty.
-    /// By default true.
-    ///
-    /// <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal would turn extensible to false/>
-    /// <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze would also turn extensible to false/>
+    /// This reads the state of ordinary objects, exotic objects like `Proxy` should be asked
+    /// through [`Interpreter::is_extensible`](../../exec/struct.Interpreter.html#method.is_extensible).
     pub fn is_extensible(&self) -> bool {
-        true
+        match *self {
+            Self::Object(ref obj) => obj.borrow().is_extensible(),
+            _ => false,
+        }
     }
 
     /// Returns true if the value is an object
@@ -213,7 +222,7 @@ impl ValueData {
     pub fn to_number(&self) -> f64 {
         match *self {
             Self::Object(_) | Self::Symbol(_) | Self::Undefined => NAN,
-            Self::String(ref str) => match FromStr::from_str(str) {
+            Self::String(ref str) => match FromStr::from_str(&str.to_std_string_lossy()) {
                 Ok(num) => num,
                 Err(_) => NAN,
             },
@@ -232,7 +241,7 @@ impl ValueData {
             | Self::Symbol(_)
             | Self::Null
             | Self::Boolean(false) => 0,
-            Self::String(ref str) => match FromStr::from_str(str) {
+            Self::String(ref str) => match FromStr::from_str(&str.to_std_string_lossy()) {
                 Ok(num) => num,
                 Err(_) => 0,
             },
@@ -254,7 +263,11 @@ impl ValueData {
     /// It will return a boolean based on if the value was removed, if there was no value to remove false is returned
     pub fn remove_prop(&self, field: &str) {
         match *self {
-            Self::Object(ref obj) => obj.borrow_mut().deref_mut().properties.remove(field),
+            Self::Object(ref obj) => obj
+                .borrow_mut()
+                .deref_mut()
+                .properties
+                .remove(&JsString::from(field)),
             _ => None,
         };
     }
@@ -263,6 +276,13 @@ impl ValueData {
     ///
     /// A copy of the Property is returned.
     pub fn get_prop(&self, field: &str) -> Option<Property> {
+        self.get_string_prop(&JsString::from(field))
+    }
+
+    /// Resolve the property keyed by the string `field` in the object.
+    ///
+    /// A copy of the Property is returned.
+    pub fn get_string_prop(&self, field: &JsString) -> Option<Property> {
         // Spidermonkey has its own GetLengthProperty: https://searchfox.org/mozilla-central/source/js/src/vm/Interpreter-inl.h#154
         // This is only for primitive strings, String() objects have their lengths calculated in string.rs
         if self.is_string() && field == "length" {
@@ -292,7 +312,7 @@ impl ValueData {
         match obj.properties.get(field) {
             Some(val) => Some(val.clone()),
             None => match obj.internal_slots.get(&INSTANCE_PROTOTYPE.to_string()) {
-                Some(value) => value.get_prop(field),
+                Some(value) => value.get_string_prop(field),
                 None => None,
             },
         }
@@ -316,7 +336,7 @@ impl ValueData {
 
         if let Some(mut obj_data) = obj {
             // Use value, or walk up the prototype chain
-            if let Some(ref mut prop) = obj_data.properties.get_mut(field) {
+            if let Some(ref mut prop) = obj_data.properties.get_mut(&JsString::from(field)) {
                 prop.value = value;
                 prop.enumerable = enumerable;
                 prop.writable = writable;
@@ -354,7 +374,7 @@ impl ValueData {
         match *field {
             // Our field will either be a String or a Symbol
             Self::String(ref s) => {
-                match self.get_prop(s) {
+                match self.get_string_prop(s) {
                     Some(prop) => {
                         // If the Property has [[Get]] set to a function, we should run that and return the Value
                         let prop_getter = match prop.get {
@@ -366,17 +386,17 @@ impl ValueData {
                         if let Some(val) = prop_getter {
                             val
                         } else {
-                            let val = prop
-                                .value
-                                .as_ref()
-                                .expect("Could not get property as reference");
-                            val.clone()
+                            prop.value.clone().unwrap_or_else(undefined)
                         }
                     }
                     None => Gc::new(Self::Undefined),
                 }
             }
-            Self::Symbol(_) => unimplemented!(),
+            // Symbol keyed properties live in their own bucket
+            Self::Symbol(_) => match *self {
+                Self::Object(ref obj) => obj.borrow().get(&field),
+                _ => Gc::new(Self::Undefined),
+            },
             _ => Gc::new(Self::Undefined),
         }
     }
@@ -390,6 +410,21 @@ impl ValueData {
         }
     }
 
+    /// Check whether an object has an internal state of the concrete type `S`.
+    ///
+    /// Builtins use this to reject receivers of the wrong type before calling
+    /// [`with_internal_state_ref`](#method.with_internal_state_ref), which panics.
+    pub fn has_internal_state_of<S: Any + InternalState>(&self) -> bool {
+        if let Self::Object(ref obj) = *self {
+            obj.borrow()
+                .state
+                .as_ref()
+                .map_or(false, |state| state.is::<S>())
+        } else {
+            false
+        }
+    }
+
     /// Get the internal state of an object.
     pub fn get_internal_state(&self) -> Option<InternalStateCell> {
         if let Self::Object(ref obj) = *self {
@@ -406,8 +441,8 @@ impl ValueData {
     ///
     /// # Panics
     ///
-    /// This will panic if this value doesn't have an internal state or if the internal state doesn't
-    /// have the concrete type `S`.
+    /// This will panic if this value doesn't have an internal state, if the internal state doesn't
+    /// have the concrete type `S` or if it's mutably borrowed.
     pub fn with_internal_state_ref<S: Any + InternalState, R, F: FnOnce(&S) -> R>(
         &self,
         f: F,
@@ -420,7 +455,7 @@ impl ValueData {
                 .expect("no state")
                 .downcast_ref()
                 .expect("wrong state type");
-            f(state)
+            f(&state)
         } else {
             panic!("not an object");
         }
@@ -430,21 +465,21 @@ impl ValueData {
     ///
     /// # Panics
     ///
-    /// This will panic if this value doesn't have an internal state or if the internal state doesn't
-    /// have the concrete type `S`.
+    /// This will panic if this value doesn't have an internal state, if the internal state doesn't
+    /// have the concrete type `S` or if it's already borrowed.
     pub fn with_internal_state_mut<S: Any + InternalState, R, F: FnOnce(&mut S) -> R>(
         &self,
         f: F,
     ) -> R {
         if let Self::Object(ref obj) = *self {
-            let mut o = obj.borrow_mut();
-            let state = o
+            let o = obj.borrow();
+            let mut state = o
                 .state
-                .as_mut()
+                .as_ref()
                 .expect("no state")
                 .downcast_mut()
                 .expect("wrong state type");
-            f(state)
+            f(&mut state)
         } else {
             panic!("not an object");
         }
@@ -459,7 +494,7 @@ impl ValueData {
     pub fn get_field_slice(&self, field: &str) -> Value {
         // get_field used to accept strings, but now Symbols accept it needs to accept a value
         // So this function will now need to Box strings back into values (at least for now)
-        let f = Gc::new(Self::String(field.to_string()));
+        let f = Gc::new(Self::String(JsString::from(field)));
         self.get_field(f)
     }
 
@@ -468,19 +503,17 @@ impl ValueData {
     pub fn set_field(&self, field: Value, val: Value) -> Value {
         if let Self::Object(ref obj) = *self {
             if obj.borrow().kind == ObjectKind::Array {
-                if let Ok(num) = field.to_string().parse::<usize>() {
-                    if num > 0 {
-                        let len: i32 = from_value(self.get_field_slice("length"))
-                            .expect("Could not convert argument to i32");
-                        if len < (num + 1) as i32 {
-                            self.set_field_slice("length", to_value(num + 1));
-                        }
+                if let Ok(num) = field.to_string().parse::<u32>() {
+                    if num != u32::MAX
+                        && self.get_field_slice("length").to_number() <= f64::from(num)
+                    {
+                        self.set_field_slice("length", to_value(u64::from(num) + 1));
                     }
                 }
             }
 
-            // Symbols get saved into a different bucket to general properties
-            if field.is_symbol() {
+            // Symbols get saved into a different bucket to general properties, and string keys are kept as they are
+            if field.is_symbol() || field.is_string() {
                 obj.borrow_mut().set(field, val.clone());
             } else {
                 obj.borrow_mut()
@@ -495,7 +528,7 @@ impl ValueData {
     pub fn set_field_slice(&self, field: &str, val: Value) -> Value {
         // set_field used to accept strings, but now Symbols accept it needs to accept a value
         // So this function will now need to Box strings back into values (at least for now)
-        let f = Gc::new(Self::String(field.to_string()));
+        let f = Gc::new(Self::String(JsString::from(field)));
         self.set_field(f, val)
     }
 
@@ -516,10 +549,19 @@ impl ValueData {
         }
     }
 
+    /// Replace the internal methods of an object, to make it an exotic object
+    pub fn set_internal_methods(&self, internal_methods: &'static InternalObjectMethods) {
+        if let Self::Object(ref obj) = *self {
+            obj.borrow_mut().internal_methods = internal_methods;
+        }
+    }
+
     /// Set the property in the value
     pub fn set_prop(&self, field: String, prop: Property) -> Property {
         if let Self::Object(ref obj) = *self {
-            obj.borrow_mut().properties.insert(field, prop.clone());
+            obj.borrow_mut()
+                .properties
+                .insert(JsString::from(field), prop.clone());
         }
         prop
     }
@@ -529,6 +571,16 @@ impl ValueData {
         self.set_prop(field.to_string(), prop)
     }
 
+    /// Set the property keyed by the symbol `key` in the value
+    pub fn set_symbol_prop(&self, key: &Value, prop: Property) -> Property {
+        if let (Self::Object(ref obj), Some(id)) = (self, symbol_id(key)) {
+            let mut obj = obj.borrow_mut();
+            obj.sym_properties.insert(id, prop.clone());
+            obj.sym_keys.insert(id, key.clone());
+        }
+        prop
+    }
+
     /// Set internal state of an Object. Discards the previous state if it was set.
     pub fn set_internal_state<T: Any + InternalState>(&self, state: T) {
         if let Self::Object(ref obj) = *self {
@@ -553,24 +605,41 @@ impl ValueData {
         new_func_val
     }
 
+    /// Create a built-in function with the given body, `name` and `length`, like the methods of
+    /// the built-in objects
+    ///
+    /// The body can be a native function or a closure, see `FunctionBody::closure`.
+    pub fn new_builtin_func(name: &str, length: usize, body: FunctionBody) -> Value {
+        let mut new_func = Object::function();
+        new_func.set_call(Function::create_builtin(vec![], body));
+        let new_func_val = to_value(new_func);
+        set_function_name_and_length(&new_func_val, to_value(name), length as f64);
+        new_func_val
+    }
+
+    /// Set a method in the value, a built-in function with the given body named after the field
+    pub fn set_method(&self, name: &str, length: usize, body: FunctionBody) -> Value {
+        self.set_field_slice(name, Self::new_builtin_func(name, length, body))
+    }
+
     /// Convert from a JSON value to a JS value
     pub fn from_json(json: JSONValue) -> Self {
         match json {
             JSONValue::Number(v) => {
                 Self::Rational(v.as_f64().expect("Could not convert value to f64"))
             }
-            JSONValue::String(v) => Self::String(v),
+            JSONValue::String(v) => Self::String(JsString::from(v)),
             JSONValue::Bool(v) => Self::Boolean(v),
             JSONValue::Array(vs) => {
                 let mut new_obj = Object::default();
                 for (idx, json) in vs.iter().enumerate() {
                     new_obj.properties.insert(
-                        idx.to_string(),
+                        JsString::from(idx.to_string()),
                         Property::default().value(to_value(json.clone())),
                     );
                 }
                 new_obj.properties.insert(
-                    "length".to_string(),
+                    JsString::from("length"),
                     Property::default().value(to_value(vs.len() as i32)),
                 );
                 Self::Object(Box::new(GcCell::new(new_obj)))
@@ -579,7 +648,7 @@ impl ValueData {
                 let mut new_obj = Object::default();
                 for (key, json) in obj.iter() {
                     new_obj.properties.insert(
-                        key.clone(),
+                        JsString::from(key),
                         Property::default().value(to_value(json.clone())),
                     );
                 }
@@ -600,14 +669,15 @@ impl ValueData {
                     .borrow()
                     .properties
                     .iter()
-                    .map(|(k, _)| (k.clone(), self.get_field_slice(k).to_json()))
+                    .map(|(k, _)| (k.to_string(), self.get_field(to_value(k.clone())).to_json()))
                     .collect::<Map<String, JSONValue>>();
                 JSONValue::Object(new_obj)
             }
-            Self::String(ref str) => JSONValue::String(str.clone()),
-            Self::Rational(num) => JSONValue::Number(
-                JSONNumber::from_f64(num).expect("Could not convert to JSONNumber"),
-            ),
+            Self::String(ref str) => JSONValue::String(str.to_std_string_lossy()),
+            // `NaN` and the infinities have no JSON representation
+            Self::Rational(num) => {
+                JSONNumber::from_f64(num).map_or(JSONValue::Null, JSONValue::Number)
+            }
             Self::Integer(val) => JSONValue::Number(JSONNumber::from(val)),
         }
     }
@@ -634,7 +704,7 @@ impl ValueData {
     }
 
     pub fn as_num_to_power(&self, other: Self) -> Self {
-        Self::Rational(self.to_number().powf(other.to_number()))
+        Self::Rational(math::number_pow(self.to_number(), other.to_number()))
     }
 }
 
@@ -678,18 +748,19 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl properties, $obj, |(key, val)| {
-            let v = &val
-                .value
-                .as_ref()
-                .expect("Could not get the property's value");
-
-            format!(
-                "{}{}: {}",
-                String::from_utf8(vec![b' '; $indent])
-                                .expect("Could not create indentation string"),
-                key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals)
-            )
+            let indent = String::from_utf8(vec![b' '; $indent])
+                .expect("Could not create indentation string");
+
+            // Accessor properties are printed without calling their getter
+            match val.value {
+                Some(ref v) => format!(
+                    "{}{}: {}",
+                    indent,
+                    key,
+                    $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals)
+                ),
+                None => format!("{}{}: [Accessor]", indent, key),
+            }
         })
     };
 
@@ -720,6 +791,9 @@ pub(crate) fn log_string_from(x: &ValueData, print_internals: bool) -> String {
                         .clone(),
                 )
                 .expect("Cannot clone primitive value from String"),
+                ObjectKind::Date(t) => {
+                    date::iso_string(t).unwrap_or_else(|| String::from("Invalid Date"))
+                }
                 ObjectKind::Boolean => {
                     let bool_data = v.borrow().get_internal_slot("BooleanData").to_string();
 
@@ -729,7 +803,7 @@ pub(crate) fn log_string_from(x: &ValueData, print_internals: bool) -> String {
                     let len: i32 = from_value(
                         v.borrow()
                             .properties
-                            .get("length")
+                            .get(&JsString::from("length"))
                             .unwrap()
                             .value
                             .clone()
@@ -744,23 +818,66 @@ pub(crate) fn log_string_from(x: &ValueData, print_internals: bool) -> String {
                     let arr = (0..len)
                         .map(|i| {
                             // Introduce recursive call to stringify any objects
-                            // which are part of the Array
-                            log_string_from(
-                                &v.borrow()
-                                    .properties
-                                    .get(&i.to_string())
-                                    .unwrap()
-                                    .value
-                                    .clone()
-                                    .expect("Could not borrow value"),
-                                print_internals,
-                            )
+                            // which are part of the Array, holes have no property
+                            match v.borrow().properties.get(&JsString::from(i.to_string())) {
+                                Some(prop) => log_string_from(
+                                    &prop.value.clone().expect("Could not borrow value"),
+                                    print_internals,
+                                ),
+                                None => String::from("<empty>"),
+                            }
                         })
                         .collect::<Vec<String>>()
                         .join(", ");
 
                     format!("[ {} ]", arr)
                 }
+                ObjectKind::Map(ref map) | ObjectKind::Set(ref map) => {
+                    let object = v.borrow();
+                    let entries = map
+                        .iter()
+                        .map(|(key, value)| {
+                            // Introduce recursive call to stringify any objects
+                            // which are part of the Map or Set
+                            if let ObjectKind::Map(_) = object.kind {
+                                format!(
+                                    "{} => {}",
+                                    log_string_from(key, print_internals),
+                                    log_string_from(value, print_internals)
+                                )
+                            } else {
+                                log_string_from(value, print_internals)
+                            }
+                        })
+                        .collect::<Vec<String>>();
+
+                    if entries.is_empty() {
+                        format!("{} {{}}", object.kind)
+                    } else {
+                        format!("{} {{ {} }}", object.kind, entries.join(", "))
+                    }
+                }
+                ObjectKind::ArrayBuffer(ref data) => {
+                    format!("ArrayBuffer {{ byteLength: {} }}", data.bytes.len())
+                }
+                ObjectKind::TypedArray(ref data) => {
+                    let values = data
+                        .values()
+                        .into_iter()
+                        .map(|value| format!("{}", ValueData::Rational(value)))
+                        .collect::<Vec<String>>();
+
+                    if values.is_empty() {
+                        format!("{}(0) []", data.kind.name())
+                    } else {
+                        format!(
+                            "{}({}) [ {} ]",
+                            data.kind.name(),
+                            values.len(),
+                            values.join(", ")
+                        )
+                    }
+                }
                 _ => display_obj(&x, print_internals),
             }
         }
@@ -2,10 +2,17 @@ use super::*;
 
 impl PartialEq for ValueData {
     fn eq(&self, other: &Self) -> bool {
+        // Objects and symbols are compared by identity
+        match (self, other) {
+            (Self::Object(ref a), Self::Object(ref b))
+            | (Self::Symbol(ref a), Self::Symbol(ref b)) => return std::ptr::eq(&**a, &**b),
+            _ => {}
+        }
         match (self.clone(), other.clone()) {
             // TODO: fix this
             // _ if self.ptr.to_inner() == &other.ptr.to_inner() => true,
             _ if self.is_null_or_undefined() && other.is_null_or_undefined() => true,
+            (Self::String(ref a), Self::String(ref b)) => a == b,
             (Self::String(_), _) | (_, Self::String(_)) => self.to_string() == other.to_string(),
             (Self::Boolean(a), Self::Boolean(b)) if a == b => true,
             (Self::Rational(a), Self::Rational(b)) if a == b && !a.is_nan() && !b.is_nan() => true,
@@ -21,10 +28,9 @@ impl Add for ValueData {
     type Output = Self;
     fn add(self, other: Self) -> Self {
         match (self, other) {
-            (Self::String(ref s), ref o) => {
-                Self::String(format!("{}{}", s.clone(), &o.to_string()))
-            }
-            (ref s, Self::String(ref o)) => Self::String(format!("{}{}", s.to_string(), o)),
+            (Self::String(ref s), Self::String(ref o)) => Self::String(s.concat(o)),
+            (Self::String(ref s), ref o) => Self::String(s.concat(&JsString::from(o.to_string()))),
+            (ref s, Self::String(ref o)) => Self::String(JsString::from(s.to_string()).concat(o)),
             (ref s, ref o) => Self::Rational(s.to_number() + o.to_number()),
         }
     }
@@ -111,7 +117,10 @@ pub fn same_value(x: &Value, y: &Value, strict: bool) -> bool {
     if x.get_type() == "number" {
         let native_x: f64 = from_value(x.clone()).expect("failed to get value");
         let native_y: f64 = from_value(y.clone()).expect("failed to get value");
-        return native_x.abs() - native_y.abs() == 0.0;
+        if native_x.is_nan() && native_y.is_nan() {
+            return true;
+        }
+        return native_x == native_y && native_x.is_sign_negative() == native_y.is_sign_negative();
     }
 
     same_value_non_number(x, y)
@@ -122,17 +131,18 @@ pub fn same_value_non_number(x: &Value, y: &Value) -> bool {
     match x.get_type() {
         "undefined" => true,
         "null" => true,
-        "string" => {
-            if x.to_string() == y.to_string() {
-                return true;
-            }
-            false
-        }
+        // Strings are compared code unit by code unit
+        "string" => *x == *y,
         "boolean" => {
             from_value::<bool>(x.clone()).expect("failed to get value")
                 == from_value::<bool>(y.clone()).expect("failed to get value")
         }
-        "object" => *x == *y,
+        // Objects and symbols are only the same if they are the same instance
+        "object" | "symbol" | "function" => match (&**x, &**y) {
+            (ValueData::Object(ref a), ValueData::Object(ref b))
+            | (ValueData::Symbol(ref a), ValueData::Symbol(ref b)) => std::ptr::eq(&**a, &**b),
+            _ => *x == *y,
+        },
         _ => false,
     }
 }
@@ -46,3 +46,119 @@ fn check_number_is_true() {
     assert_eq!((-1.0).to_value().is_true(), true);
     assert_eq!(NAN.to_value().is_true(), false);
 }
+
+fn inspect_src(src: &str, options: &inspect::InspectOptions) -> String {
+    use crate::{exec::Executor, forward_val, realm::Realm};
+
+    let realm = Realm::create();
+    let mut engine: crate::exec::Interpreter = Executor::new(realm);
+    let value = forward_val(&mut engine, src).expect("the script threw");
+    inspect::inspect(&value, options)
+}
+
+#[test]
+fn inspect_primitives_and_containers() {
+    let options = inspect::InspectOptions::default();
+    assert_eq!(inspect_src("'a'", &options), "'a'");
+    assert_eq!(inspect_src("\"it's\"", &options), "\"it's\"");
+    assert_eq!(inspect_src("-0", &options), "-0");
+    assert_eq!(inspect_src("Symbol('s')", &options), "Symbol(s)");
+    assert_eq!(
+        inspect_src("var a = []; a[2] = 'x'; a.k = null; a", &options),
+        "[ <2 empty items>, 'x', k: null ]"
+    );
+    assert_eq!(
+        inspect_src("({ 'a-b': [undefined, true] })", &options),
+        "{ 'a-b': [ undefined, true ] }"
+    );
+    assert_eq!(
+        inspect_src("new Map([['k', new Set([1])]])", &options),
+        "Map(1) { 'k' => Set(1) { 1 } }"
+    );
+    assert_eq!(
+        inspect_src(
+            "[function f() {}, function () {}, /a/g, new Number(1)]",
+            &options
+        ),
+        "[ [Function: f], [Function (anonymous)], /a/g, [Number: 1] ]"
+    );
+    assert_eq!(
+        inspect_src("new Error('boom')", &options),
+        "Error: boom\n    at <anonymous>:1:1"
+    );
+    assert_eq!(
+        inspect_src("Object.create(null)", &options),
+        "[Object: null prototype] {}"
+    );
+}
+
+#[test]
+fn inspect_cycles_and_depth() {
+    let options = inspect::InspectOptions::default();
+    assert_eq!(
+        inspect_src("var a = { b: {} }; a.b.a = a; a", &options),
+        "<ref *1> { b: { a: [Circular *1] } }"
+    );
+    assert_eq!(
+        inspect_src("({ a: { b: { c: { d: [1] } } } })", &options),
+        "{ a: { b: { c: [Object] } } }"
+    );
+    let unlimited = inspect::InspectOptions {
+        depth: None,
+        ..options
+    };
+    assert_eq!(
+        inspect_src("({ a: { b: { c: { d: [1] } } } })", &unlimited),
+        "{ a: { b: { c: { d: [ 1 ] } } } }"
+    );
+}
+
+#[test]
+fn inspect_options() {
+    let options = inspect::InspectOptions::default();
+    let short = inspect::InspectOptions {
+        max_array_length: Some(2),
+        ..options
+    };
+    assert_eq!(
+        inspect_src("[1, 2, 3, 4]", &short),
+        "[ 1, 2, ... 2 more items ]"
+    );
+    let sorted = inspect::InspectOptions {
+        sorted: true,
+        ..options
+    };
+    assert_eq!(
+        inspect_src("({ c: 1, a: 2, b: 3 })", &sorted),
+        "{ a: 2, b: 3, c: 1 }"
+    );
+    let hidden = inspect::InspectOptions {
+        show_hidden: true,
+        ..options
+    };
+    assert_eq!(
+        inspect_src(
+            "var o = {}; Object.defineProperty(o, 'h', { get: function () {} }); o",
+            &hidden
+        ),
+        "{ [h]: [Getter] }"
+    );
+    assert_eq!(inspect_src("[1, 2]", &hidden), "[ 1, 2, [length]: 2 ]");
+    let colors = inspect::InspectOptions {
+        colors: true,
+        ..options
+    };
+    assert_eq!(
+        inspect_src("[1, 'a', null]", &colors),
+        "[ \u{1b}[33m1\u{1b}[39m, \u{1b}[32m'a'\u{1b}[39m, \u{1b}[1mnull\u{1b}[22m ]"
+    );
+    assert_eq!(
+        inspect_src(
+            "var a = []; var i = 0; while (i < 30) { a.push('item'); i++ } var o = { a: a }; o",
+            &options
+        )
+        .lines()
+        .count(),
+        34
+    );
+}
@@ -0,0 +1,184 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! The `WeakMap` object is a collection of key/value pairs in which the keys are weakly referenced.
+//! The keys must be objects and the values can be arbitrary values.
+//!
+//! The entries are stored on the key objects, by the id of the map, instead of in the map.
+//! A value is then only traced while its key is reachable, and the map doesn't keep its keys alive.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        map,
+        object::{
+            Object, ObjectInternalMethods, ObjectKind, WeakCollection, WeakTarget, PROTOTYPE,
+        },
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Calls `f` with the weak entries of `key`, or returns `None` if it's not an object.
+///
+/// The object is borrowed during the call, so `f` must not run any JavaScript code.
+pub(crate) fn with_weak_target<R, F: FnOnce(&mut WeakTarget) -> R>(key: &Value, f: F) -> Option<R> {
+    match **key {
+        ValueData::Object(ref obj) => {
+            let mut obj = obj.borrow_mut();
+            Some(f(obj.weak.get_or_insert_with(Default::default)))
+        }
+        _ => None,
+    }
+}
+
+/// Gets the identity of a `WeakMap` object, or throws a `TypeError` if `this` is not one.
+fn this_weak_map(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<WeakCollection, Value> {
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::WeakMap(ref collection) = obj.borrow().kind {
+            return Ok(collection.clone());
+        }
+    }
+
+    Err(ctx.construct_type_error(format!(
+        "Method WeakMap.prototype.{} called on incompatible receiver {}",
+        method, this
+    )))
+}
+
+/// Create a new `WeakMap`.
+///
+/// The optional argument is an array of `[key, value]` entries, or a `Map`, to copy.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+pub fn make_weak_map(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this.set_kind(ObjectKind::WeakMap(WeakCollection::new()));
+
+    match args.get(0) {
+        Some(iterable) if !iterable.is_null_or_undefined() => {
+            for (key, value) in map::entries_of_iterable(iterable, ctx)? {
+                set(this, &[key, value], ctx)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(this.clone())
+}
+
+/// `WeakMap` can only be called as a constructor.
+pub fn call_weak_map(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor WeakMap requires 'new'")
+}
+
+/// `WeakMap.prototype.delete( key )`
+///
+/// The `delete()` method removes the specified element from a `WeakMap` object.
+///
+/// Returns `true` if the element existed and has been removed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+pub fn delete(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_map(this, "delete", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let removed = with_weak_target(&key, |target| target.remove(&collection)).unwrap_or(false);
+    Ok(to_value(removed))
+}
+
+/// `WeakMap.prototype.get( key )`
+///
+/// The `get()` method returns the value associated to `key`, or `undefined` if there is none.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+pub fn get(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_map(this, "get", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    Ok(with_weak_target(&key, |target| target.get(&collection))
+        .and_then(|value| value)
+        .unwrap_or_else(undefined))
+}
+
+/// `WeakMap.prototype.has( key )`
+///
+/// The `has()` method returns a boolean indicating whether an element with the specified key exists or not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+pub fn has(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_map(this, "has", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let has = with_weak_target(&key, |target| target.contains(&collection)).unwrap_or(false);
+    Ok(to_value(has))
+}
+
+/// `WeakMap.prototype.set( key, value )`
+///
+/// The `set()` method adds or updates an element with a specified key and value to a `WeakMap` object.
+/// The key must be an object.
+///
+/// Returns the `WeakMap` object, so calls can be chained.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+pub fn set(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_map(this, "set", ctx)?;
+    let key = args.get(0).cloned().unwrap_or_else(undefined);
+    let value = args.get(1).cloned().unwrap_or_else(undefined);
+    match with_weak_target(&key, |target| target.insert(&collection, value)) {
+        Some(()) => Ok(this.clone()),
+        None => ctx.throw_type_error(format!("Invalid value used as weak map key: {}", key)),
+    }
+}
+
+/// Create a new `WeakMap` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(delete, named "delete", with length 1, of prototype);
+    make_builtin_fn!(get, named "get", with length 1, of prototype);
+    make_builtin_fn!(has, named "has", with length 1, of prototype);
+    make_builtin_fn!(set, named "set", with length 2, of prototype);
+
+    make_constructor_fn!(make_weak_map, call_weak_map, global, prototype)
+}
+
+/// Initialise the `WeakMap` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("WeakMap", create(global));
+}
@@ -0,0 +1,102 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct_set_get_has_delete() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var a = {};
+        var b = {};
+        var map = new WeakMap([[a, 1]]);
+        var other = new WeakMap();
+        var chained = map.set(b, 2).get(b);
+        var notWeakMap = { get: map.get };
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "chained"), "2");
+    assert_eq!(forward(&mut engine, "map.get(a)"), "1");
+    assert_eq!(forward(&mut engine, "map.get(b)"), "2");
+    assert_eq!(forward(&mut engine, "map.get({})"), "undefined");
+    assert_eq!(forward(&mut engine, "map.get(1)"), "undefined");
+    assert_eq!(forward(&mut engine, "map.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "other.has(a)"), "false");
+    assert_eq!(forward(&mut engine, "map.delete(a)"), "true");
+    assert_eq!(forward(&mut engine, "map.delete(a)"), "false");
+    assert_eq!(forward(&mut engine, "map.has(a)"), "false");
+    assert_eq!(forward(&mut engine, "map.has(b)"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { map.set('key', 1) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { WeakMap() } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { notWeakMap.get(a) } catch (e) { e.name }"
+        ),
+        "TypeError"
+    );
+}
+
+/// A value stays alive as long as its key, even if the map is the only one holding it.
+#[test]
+fn values_live_as_long_as_their_key() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var map = new WeakMap();
+        var key = {};
+        var keyRef = new WeakRef(key);
+        var value = { name: 'value', key: key };
+        var valueRef = new WeakRef(value);
+        map.set(key, value);
+        value = null;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "map.get(key).name"), "value");
+    assert_eq!(forward(&mut engine, "valueRef.deref().name"), "value");
+
+    // The value references the key, which doesn't keep them alive
+    forward(&mut engine, "key = null");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "keyRef.deref()"), "undefined");
+    assert_eq!(forward(&mut engine, "valueRef.deref()"), "undefined");
+}
+
+/// The values of a collected map are released, even if their key is still alive.
+#[test]
+fn values_live_as_long_as_their_map() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var key = {};
+        var map = new WeakMap();
+        var value = { name: 'value' };
+        var valueRef = new WeakRef(value);
+        map.set(key, value);
+        value = null;
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "valueRef.deref().name"), "value");
+
+    forward(&mut engine, "map = null");
+    gc::force_collect();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "valueRef.deref()"), "undefined");
+
+    // The key can still be used by other maps
+    assert_eq!(
+        forward(&mut engine, "new WeakMap().set(key, 1).get(key)"),
+        "1"
+    );
+}
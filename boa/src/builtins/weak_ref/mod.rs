@@ -0,0 +1,99 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` object lets you hold a weak reference to another object,
+//! without preventing that object from getting garbage-collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, WeakHandle, PROTOTYPE},
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+    },
+    exec::Interpreter,
+};
+
+/// Create a new `WeakRef`.
+///
+/// The target is kept alive until the current job is done, so it can't be collected
+/// before the script had a chance to use it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+pub fn make_weak_ref(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let target = args.get(0).cloned().unwrap_or_else(undefined);
+    let handle = match WeakHandle::new(&target) {
+        Some(handle) => handle,
+        None => return ctx.throw_type_error("WeakRef: target must be an object"),
+    };
+
+    this.set_kind(ObjectKind::WeakRef(handle));
+    ctx.keep_alive(target);
+    Ok(this.clone())
+}
+
+/// `WeakRef` can only be called as a constructor.
+pub fn call_weak_ref(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor WeakRef requires 'new'")
+}
+
+/// `WeakRef.prototype.deref()`
+///
+/// The `deref()` method returns the target object, or `undefined` if it has been garbage-collected.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+pub fn deref(this: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let handle = match **this {
+        ValueData::Object(ref obj) => match obj.borrow().kind {
+            ObjectKind::WeakRef(ref handle) => Some(handle.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match handle {
+        Some(handle) => match handle.upgrade() {
+            Some(target) => {
+                ctx.keep_alive(target.clone());
+                Ok(target)
+            }
+            None => Ok(undefined()),
+        },
+        None => ctx.throw_type_error(format!(
+            "Method WeakRef.prototype.deref called on incompatible receiver {}",
+            this
+        )),
+    }
+}
+
+/// Create a new `WeakRef` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(deref, named "deref", of prototype);
+
+    make_constructor_fn!(make_weak_ref, call_weak_ref, global, prototype)
+}
+
+/// Initialise the `WeakRef` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("WeakRef", create(global));
+}
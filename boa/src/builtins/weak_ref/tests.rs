@@ -0,0 +1,42 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn deref_live_target() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = { name: 'target' };
+        var ref = new WeakRef(target);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "ref.deref().name"), "target");
+    assert_eq!(
+        forward(&mut engine, "try { new WeakRef(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { WeakRef({}) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+/// The target is collected once it's only reachable through the `WeakRef`.
+#[test]
+fn deref_collected_target() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var target = {};
+        var ref = new WeakRef(target);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "typeof ref.deref()"), "object");
+
+    forward(&mut engine, "target = null");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "ref.deref()"), "undefined");
+}
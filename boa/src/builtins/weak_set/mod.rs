@@ -0,0 +1,148 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! The `WeakSet` object lets you store weakly held objects in a collection.
+//!
+//! Like the entries of `WeakMap` objects, the membership is stored on the objects themselves,
+//! so the set doesn't keep them alive.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        object::{Object, ObjectInternalMethods, ObjectKind, WeakCollection, PROTOTYPE},
+        set,
+        value::{to_value, undefined, ResultValue, Value, ValueData},
+        weak_map::with_weak_target,
+    },
+    exec::Interpreter,
+};
+
+/// Gets the identity of a `WeakSet` object, or throws a `TypeError` if `this` is not one.
+fn this_weak_set(
+    this: &Value,
+    method: &str,
+    ctx: &mut Interpreter,
+) -> Result<WeakCollection, Value> {
+    if let ValueData::Object(ref obj) = **this {
+        if let ObjectKind::WeakSet(ref collection) = obj.borrow().kind {
+            return Ok(collection.clone());
+        }
+    }
+
+    Err(ctx.construct_type_error(format!(
+        "Method WeakSet.prototype.{} called on incompatible receiver {}",
+        method, this
+    )))
+}
+
+/// Create a new `WeakSet`.
+///
+/// The optional argument is an array of objects, or a `Set`, to copy.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+pub fn make_weak_set(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    this.set_kind(ObjectKind::WeakSet(WeakCollection::new()));
+
+    match args.get(0) {
+        Some(iterable) if !iterable.is_null_or_undefined() => {
+            for value in set::values_of_iterable(iterable, ctx)? {
+                add(this, &[value], ctx)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(this.clone())
+}
+
+/// `WeakSet` can only be called as a constructor.
+pub fn call_weak_set(_: &mut Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    ctx.throw_type_error("Constructor WeakSet requires 'new'")
+}
+
+/// `WeakSet.prototype.add( value )`
+///
+/// The `add()` method appends a new object to the end of a `WeakSet` object.
+///
+/// Returns the `WeakSet` object, so calls can be chained.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+pub fn add(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_set(this, "add", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    match with_weak_target(&value, |target| target.insert(&collection, undefined())) {
+        Some(()) => Ok(this.clone()),
+        None => ctx.throw_type_error(format!("Invalid value used in weak set: {}", value)),
+    }
+}
+
+/// `WeakSet.prototype.delete( value )`
+///
+/// The `delete()` method removes the specified element from a `WeakSet` object.
+///
+/// Returns `true` if the element existed and has been removed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+pub fn delete(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_set(this, "delete", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    let removed = with_weak_target(&value, |target| target.remove(&collection)).unwrap_or(false);
+    Ok(to_value(removed))
+}
+
+/// `WeakSet.prototype.has( value )`
+///
+/// The `has()` method returns a boolean indicating whether an object exists in a `WeakSet` or not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+pub fn has(this: &mut Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let collection = this_weak_set(this, "has", ctx)?;
+    let value = args.get(0).cloned().unwrap_or_else(undefined);
+    let has = with_weak_target(&value, |target| target.contains(&collection)).unwrap_or(false);
+    Ok(to_value(has))
+}
+
+/// Create a new `WeakSet` object.
+pub fn create(global: &Value) -> Value {
+    let prototype = ValueData::new_obj(Some(global));
+
+    make_builtin_fn!(add, named "add", with length 1, of prototype);
+    make_builtin_fn!(delete, named "delete", with length 1, of prototype);
+    make_builtin_fn!(has, named "has", with length 1, of prototype);
+
+    make_constructor_fn!(make_weak_set, call_weak_set, global, prototype)
+}
+
+/// Initialise the `WeakSet` object on the global object.
+#[inline]
+pub fn init(global: &Value) {
+    global.set_field_slice("WeakSet", create(global));
+}
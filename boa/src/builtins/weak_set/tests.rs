@@ -0,0 +1,42 @@
+use crate::exec::Executor;
+use crate::forward;
+use crate::realm::Realm;
+
+#[test]
+fn construct_add_has_delete() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var a = {};
+        var b = {};
+        var set = new WeakSet([a]);
+        var chained = set.add(b).has(b);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    assert_eq!(forward(&mut engine, "chained"), "true");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "new WeakSet().has(a)"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "false");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "false");
+    assert_eq!(
+        forward(&mut engine, "try { set.add(1) } catch (e) { e.name }"),
+        "TypeError"
+    );
+}
+
+#[test]
+fn does_not_keep_values_alive() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let init = r#"
+        var value = {};
+        var ref = new WeakRef(value);
+        var set = new WeakSet([value]);
+        "#;
+    eprintln!("{}", forward(&mut engine, init));
+    forward(&mut engine, "value = null");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "ref.deref()"), "undefined");
+}
@@ -0,0 +1,235 @@
+//! This module implements the `Context`, the entry point to embed the engine in an application.
+//!
+//! A `Context` owns a realm and the interpreter running scripts in it. Unlike `forward` and
+//! `forward_val`, its methods report the failures of scripts with a typed `JsError`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::value::{
+        inspect::{inspect, InspectOptions},
+        Value,
+    },
+    exec::{Executor, Interpreter},
+    realm::Realm,
+    syntax::{ast::node::Node, ast::pos::Position, lexer::Lexer, parser::Parser},
+};
+use gc::Gc;
+use std::{error, fmt};
+
+/// The maximum number of nested calls of a new `Context`.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 400;
+
+/// The maximum size in bytes of the host stack used by the nested calls of a new `Context`.
+///
+/// A call of the interpreter takes a few kilobytes of the host stack in release builds, and
+/// tens of kilobytes in debug builds. Half of the 2 MiB of spawned Rust threads leaves room for
+/// the frames of the application, in every build.
+pub const DEFAULT_MAX_STACK_SIZE: usize = 1024 * 1024;
+
+/// The reason a script failed.
+#[derive(Debug, Clone)]
+pub enum JsError {
+    /// The source code isn't valid JavaScript.
+    Syntax {
+        /// The description of the error.
+        message: String,
+        /// The position in the source code where the error occurred, if it's known.
+        position: Option<Position>,
+    },
+    /// A value was thrown by the script and wasn't caught.
+    Thrown(Value),
+    /// The script exceeded a limit of the engine.
+    Limit(EngineLimit),
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { message, .. } => write!(f, "SyntaxError: {}", message),
+            Self::Thrown(value) => {
+                write!(f, "Uncaught {}", inspect(value, &InspectOptions::default()))
+            }
+            Self::Limit(limit) => write!(f, "{}", limit),
+        }
+    }
+}
+
+impl error::Error for JsError {}
+
+/// A limit of the engine, see `JsError::Limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineLimit {
+    /// The calls were nested deeper than the maximum call depth, which is given.
+    CallDepth(usize),
+    /// The calls used more of the host stack than the maximum stack size, which is given.
+    StackSize(usize),
+}
+
+impl fmt::Display for EngineLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CallDepth(max) => write!(f, "Maximum call depth of {} exceeded", max),
+            Self::StackSize(max) => write!(f, "Maximum stack size of {} bytes exceeded", max),
+        }
+    }
+}
+
+/// Parses a script, reporting the lexing and parsing errors as a `JsError::Syntax`.
+pub(crate) fn parse(src: &str) -> Result<Node, JsError> {
+    let mut lexer = Lexer::new(src);
+    if let Err(error) = lexer.lex() {
+        let position = lexer.position();
+        return Err(JsError::Syntax {
+            message: format!(
+                "{} at line {}, col {}",
+                error, position.line_number, position.column_number
+            ),
+            position: Some(position),
+        });
+    }
+    Parser::new(&lexer.tokens)
+        .parse_all()
+        .map_err(|error| JsError::Syntax {
+            message: error.to_string(),
+            position: error.position(),
+        })
+}
+
+/// A realm, with the interpreter running scripts in it.
+#[derive(Debug)]
+pub struct Context {
+    interpreter: Interpreter,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Context {
+    /// Creates a context with a new realm, which has the built-in objects.
+    pub fn new() -> Self {
+        Self::with_realm(Realm::create())
+    }
+
+    /// Creates a context for the given realm, like one with registered globals.
+    pub fn with_realm(realm: Realm) -> Self {
+        let mut interpreter = Interpreter::new(realm);
+        interpreter.set_max_call_depth(Some(DEFAULT_MAX_CALL_DEPTH));
+        interpreter.set_max_stack_size(Some(DEFAULT_MAX_STACK_SIZE));
+        Self { interpreter }
+    }
+
+    /// Gets the interpreter.
+    pub fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
+
+    /// Gets the interpreter, to configure it or to call the native functions of the engine.
+    pub fn interpreter_mut(&mut self) -> &mut Interpreter {
+        &mut self.interpreter
+    }
+
+    /// Gets the realm.
+    pub fn realm(&self) -> &Realm {
+        &self.interpreter.realm
+    }
+
+    /// Sets the maximum number of nested calls, beyond which scripts fail with
+    /// `JsError::Limit(EngineLimit::CallDepth)` if they don't catch the `RangeError` thrown.
+    ///
+    /// Defaults to `DEFAULT_MAX_CALL_DEPTH`, `None` removes the limit.
+    pub fn set_max_call_depth(&mut self, max_call_depth: Option<usize>) {
+        self.interpreter.set_max_call_depth(max_call_depth);
+    }
+
+    /// Sets the maximum size in bytes of the host stack used by nested calls, beyond which
+    /// scripts fail with `JsError::Limit(EngineLimit::StackSize)` if they don't catch the
+    /// `RangeError` thrown.
+    ///
+    /// Defaults to `DEFAULT_MAX_STACK_SIZE`, `None` removes the limit.
+    pub fn set_max_stack_size(&mut self, max_stack_size: Option<usize>) {
+        self.interpreter.set_max_stack_size(max_stack_size);
+    }
+
+    /// Sets the name of the scripts, like their file name, as shown in the stack traces of errors.
+    ///
+    /// Defaults to `<anonymous>`.
+    pub fn set_script_name<N>(&mut self, name: N)
+    where
+        N: Into<String>,
+    {
+        self.interpreter.set_script_name(name);
+    }
+
+    /// Runs a script, and returns the value of its last statement.
+    ///
+    /// The top-level declarations of the script, `let` and `const` included, are globals of the
+    /// following scripts. The jobs queued by the script, like the cleanup callbacks of
+    /// `FinalizationRegistry` objects, run before this returns.
+    pub fn eval(&mut self, src: &str) -> Result<Value, JsError> {
+        let script = parse(src)?;
+        let _ = self.interpreter.take_limit_error();
+        self.interpreter.set_source_text(src);
+        let result = self.interpreter.run_script(&script);
+        self.finish(result)
+    }
+
+    /// Calls a function with the given `this` value and arguments.
+    ///
+    /// Calling a value which isn't a function fails with a thrown `TypeError`.
+    pub fn call(
+        &mut self,
+        function: &Value,
+        this: &Value,
+        args: &[Value],
+    ) -> Result<Value, JsError> {
+        let _ = self.interpreter.take_limit_error();
+        let result = self.interpreter.call(function, &mut this.clone(), args);
+        self.finish(result)
+    }
+
+    /// Gets the value of a global variable, or `None` if it isn't declared.
+    pub fn global(&self, name: &str) -> Option<Value> {
+        let environment = &self.interpreter.realm.environment;
+        if environment.has_binding(name) {
+            Some(environment.get_binding_value(name))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the value of a global variable, declaring it on the global object if it isn't declared.
+    ///
+    /// Setting a `const` fails with a thrown `TypeError`, like an assignment in a script.
+    pub fn set_global(&mut self, name: &str, value: Value) -> Result<(), JsError> {
+        let realm = &mut self.interpreter.realm;
+        if realm.environment.is_immutable_binding(name) {
+            let error = self
+                .interpreter
+                .construct_type_error("Assignment to constant variable.");
+            return Err(JsError::Thrown(error));
+        }
+        if realm.environment.has_binding(name) {
+            realm.environment.set_mutable_binding(name, value, false);
+        } else {
+            realm.global_obj.set_field_slice(name, value);
+        }
+        Ok(())
+    }
+
+    /// Runs the queued jobs after a script or a call, and reports its errors.
+    fn finish(&mut self, result: Result<Value, Value>) -> Result<Value, JsError> {
+        result
+            .and_then(|value| self.interpreter.run_jobs().map(|_| value))
+            .map_err(|error| match self.interpreter.take_limit_error() {
+                Some((ref limit_error, limit)) if Gc::ptr_eq(limit_error, &error) => {
+                    JsError::Limit(limit)
+                }
+                _ => JsError::Thrown(error),
+            })
+    }
+}
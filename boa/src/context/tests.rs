@@ -0,0 +1,184 @@
+use super::{Context, EngineLimit, JsError, DEFAULT_MAX_CALL_DEPTH, DEFAULT_MAX_STACK_SIZE};
+use crate::{
+    builtins::value::{to_value, undefined},
+    exec::Executor,
+    forward_val,
+    realm::Realm,
+};
+
+#[test]
+fn eval_values_and_thrown_errors() {
+    let mut context = Context::new();
+    let value = context.eval("var x = 20; x + 22").expect("no error");
+    assert_eq!(value.to_string(), "42");
+
+    match context.eval("throw new TypeError('bad')") {
+        Err(JsError::Thrown(error)) => {
+            assert_eq!(error.get_field_slice("name").to_string(), "TypeError");
+            assert_eq!(error.get_field_slice("message").to_string(), "bad");
+        }
+        result => panic!("expected a thrown error, got {:?}", result),
+    }
+    match context.eval("throw 1") {
+        Err(error @ JsError::Thrown(_)) => assert_eq!(error.to_string(), "Uncaught 1"),
+        result => panic!("expected a thrown value, got {:?}", result),
+    }
+    // The context can still be used after an error.
+    assert_eq!(context.eval("x").expect("no error").to_string(), "20");
+}
+
+#[test]
+fn syntax_errors() {
+    let mut context = Context::new();
+    match context.eval("var a = 1;\nvar = 2;") {
+        Err(JsError::Syntax { message, position }) => {
+            let position = position.expect("the position of the error");
+            assert_eq!(position.line_number, 2);
+            assert!(message.contains("line 2"), "{}", message);
+        }
+        result => panic!("expected a syntax error, got {:?}", result),
+    }
+    match context.eval("var s = 'unterminated") {
+        Err(JsError::Syntax { position, .. }) => assert!(position.is_some()),
+        result => panic!("expected a syntax error, got {:?}", result),
+    }
+    // The script wasn't run.
+    assert!(context.global("a").is_none());
+}
+
+#[test]
+fn forward_val_syntax_error() {
+    let mut engine = Executor::new(Realm::create());
+    let error = forward_val(&mut engine, "var = 2;").expect_err("a syntax error");
+    assert_eq!(error.get_field_slice("name").to_string(), "SyntaxError");
+}
+
+#[test]
+fn globals() {
+    let mut context = Context::new();
+    context
+        .set_global("answer", to_value(42))
+        .expect("a new global");
+    assert_eq!(
+        context.eval("answer + 1").expect("no error").to_string(),
+        "43"
+    );
+    context.eval("var declared = 'script'").expect("no error");
+    assert_eq!(
+        context
+            .global("declared")
+            .expect("a declared global")
+            .to_string(),
+        "script"
+    );
+    context
+        .set_global("declared", to_value("host"))
+        .expect("a variable");
+    assert_eq!(
+        context.eval("declared").expect("no error").to_string(),
+        "host"
+    );
+    context.eval("const constant = 1").expect("no error");
+    match context.set_global("constant", to_value(2)) {
+        Err(JsError::Thrown(error)) => {
+            assert_eq!(error.get_field_slice("name").to_string(), "TypeError")
+        }
+        result => panic!("expected a thrown error, got {:?}", result),
+    }
+    assert_eq!(context.eval("constant").expect("no error").to_string(), "1");
+    assert!(context.global("missing").is_none());
+    assert!(context.global("Math").is_some());
+}
+
+#[test]
+fn call_functions() {
+    let mut context = Context::new();
+    context
+        .eval("function add(a, b) { return a + b }")
+        .expect("no error");
+    let add = context.global("add").expect("a function");
+    let sum = context
+        .call(&add, &undefined(), &[to_value(1), to_value(2)])
+        .expect("no error");
+    assert_eq!(sum.to_string(), "3");
+
+    match context.call(&to_value(1), &undefined(), &[]) {
+        Err(JsError::Thrown(error)) => {
+            assert_eq!(error.get_field_slice("name").to_string(), "TypeError")
+        }
+        result => panic!("expected a thrown error, got {:?}", result),
+    }
+}
+
+#[test]
+fn call_depth_limit() {
+    let mut context = Context::new();
+    // Debug builds would reach the stack size limit first.
+    context.set_max_stack_size(None);
+    context.set_max_call_depth(Some(16));
+    context
+        .eval("function deep(n) { return n == 0 ? 0 : deep(n - 1) + 1 }")
+        .expect("no error");
+    assert_eq!(
+        context.eval("deep(10)").expect("no error").to_string(),
+        "10"
+    );
+    match context.eval("deep(100)") {
+        Err(JsError::Limit(limit)) => assert_eq!(limit, EngineLimit::CallDepth(16)),
+        result => panic!("expected the call depth limit, got {:?}", result),
+    }
+    // Scripts can catch the `RangeError`, like in browsers.
+    assert_eq!(
+        context
+            .eval("try { deep(100) } catch (e) { e.name }")
+            .expect("no error")
+            .to_string(),
+        "RangeError"
+    );
+    let deep = context.global("deep").expect("a function");
+    match context.call(&deep, &undefined(), &[to_value(100)]) {
+        Err(JsError::Limit(EngineLimit::CallDepth(16))) => {}
+        result => panic!("expected the call depth limit, got {:?}", result),
+    }
+}
+
+#[test]
+fn script_name_in_stack_traces() {
+    let mut context = Context::new();
+    context.set_script_name("app.js");
+    match context.eval("var o;\no.x") {
+        Err(JsError::Thrown(error)) => assert_eq!(
+            error.get_field_slice("stack").to_string(),
+            "TypeError: Cannot read property 'x' of undefined\n    at app.js:2:1"
+        ),
+        result => panic!("expected a thrown error, got {:?}", result),
+    }
+}
+
+#[test]
+fn default_limits() {
+    // The test threads have the default stack of spawned threads.
+    let mut context = Context::new();
+    match context.eval("function r(n) { return r(n + 1) } r(0)") {
+        Err(JsError::Limit(EngineLimit::StackSize(size))) => {
+            assert_eq!(size, DEFAULT_MAX_STACK_SIZE)
+        }
+        Err(JsError::Limit(EngineLimit::CallDepth(depth))) => {
+            assert_eq!(depth, DEFAULT_MAX_CALL_DEPTH)
+        }
+        result => panic!("expected a limit, got {:?}", result),
+    }
+    assert_eq!(
+        context
+            .eval("try { r(0) } catch (e) { e.name }")
+            .expect("no error")
+            .to_string(),
+        "RangeError"
+    );
+
+    context.set_max_stack_size(Some(64 * 1024));
+    match context.eval("r(0)") {
+        Err(JsError::Limit(limit)) => assert_eq!(limit, EngineLimit::StackSize(64 * 1024)),
+        result => panic!("expected the stack size limit, got {:?}", result),
+    }
+}
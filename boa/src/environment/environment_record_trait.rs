@@ -23,6 +23,18 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Determine if an Environment Record has a binding for the String value N. Return true if it does and false if it does not.
     fn has_binding(&self, name: &str) -> bool;
 
+    /// Determine if a declaration already created a binding for `name` in this Environment Record,
+    /// so creating it again fails. The properties of the global object aren't declarations.
+    fn has_declaration(&self, name: &str) -> bool {
+        self.has_binding(name)
+    }
+
+    /// Determine if the binding `name` of this Environment Record is immutable, like the binding of
+    /// a `const` declaration.
+    fn is_immutable_binding(&self, _name: &str) -> bool {
+        false
+    }
+
     /// Create a new but uninitialized mutable binding in an Environment Record. The String value N is the text of the bound name.
     /// If the Boolean argument deletion is true the binding may be subsequently deleted.
     fn create_mutable_binding(&mut self, name: String, deletion: bool);
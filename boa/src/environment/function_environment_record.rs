@@ -98,6 +98,10 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         self.env_rec.contains_key(name)
     }
 
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        matches!(self.env_rec.get(name), Some(binding) if !binding.mutable)
+    }
+
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
         if self.env_rec.contains_key(&name) {
             // TODO: change this when error handling comes into play
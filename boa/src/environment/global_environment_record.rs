@@ -97,6 +97,14 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.has_binding(name)
     }
 
+    fn has_declaration(&self, name: &str) -> bool {
+        self.declarative_record.has_binding(name)
+    }
+
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        self.declarative_record.is_immutable_binding(name)
+    }
+
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
         if self.declarative_record.has_binding(&name) {
             // TODO: change to exception
@@ -105,6 +105,13 @@ impl LexicalEnvironment {
         })
     }
 
+    /// Gets the global environment, which is the outermost environment.
+    pub fn get_global_environment(&self) -> &Environment {
+        self.environment_stack
+            .get(0)
+            .expect("the global environment is always on the stack")
+    }
+
     pub fn get_global_object(&self) -> Option<Value> {
         self.environment_stack
             .get(0)
@@ -202,6 +209,35 @@ impl LexicalEnvironment {
             .expect("Could not get mutable reference to back object")
     }
 
+    /// Checks if a declaration of the given scope would redeclare `name` in the environment it
+    /// goes to, see `EnvironmentRecordTrait::has_declaration`.
+    pub fn has_declaration(&self, name: &str, scope: VariableScope) -> bool {
+        let env = match scope {
+            VariableScope::Block => Some(self.get_current_environment_ref().clone()),
+            VariableScope::Function => self.environments().find(|env| {
+                matches!(
+                    env.borrow().get_environment_type(),
+                    EnvironmentType::Function | EnvironmentType::Global
+                )
+            }),
+        };
+        match env {
+            Some(env) => env.borrow().has_declaration(name),
+            None => false,
+        }
+    }
+
+    /// Checks if the binding `name` is immutable in the first environment which has it.
+    pub fn is_immutable_binding(&self, name: &str) -> bool {
+        match self
+            .environments()
+            .find(|env| env.borrow().has_binding(name))
+        {
+            Some(env) => env.borrow().is_immutable_binding(name),
+            None => false,
+        }
+    }
+
     pub fn has_binding(&self, name: &str) -> bool {
         self.environments()
             .any(|env| env.borrow().has_binding(name))
@@ -289,7 +325,7 @@ mod tests {
           {
             let bar = "bar";
           }
-          bar == undefined;
+          typeof bar == "undefined";
         "#;
 
         assert_eq!(&exec(scenario), "true");
@@ -301,7 +337,7 @@ mod tests {
           {
             const bar = "bar";
           }
-          bar == undefined;
+          typeof bar == "undefined";
         "#;
 
         assert_eq!(&exec(scenario), "true");
@@ -62,11 +62,9 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         self.set_mutable_binding(name, value, false)
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
-        debug_assert!(value.is_object() || value.is_function());
-
-        let bindings = &mut self.bindings;
-        bindings.update_prop(name, Some(value), None, None, Some(strict));
+    fn set_mutable_binding(&mut self, name: &str, value: Value, _strict: bool) {
+        // Like an assignment to the property, which doesn't change read-only properties.
+        self.bindings.set_field_slice(name, value);
     }
 
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
@@ -0,0 +1,100 @@
+//! This module implements the `CallFrame` structure, which records the active function calls.
+//!
+//! The interpreter keeps a stack of frames so it can report where an error was created,
+//! in the same `at fn (file:line:column)` format as V8.
+
+use crate::syntax::ast::{
+    node::{Node, NodeKind},
+    pos::Position,
+};
+
+/// The maximum number of frames reported in a stack trace, like V8's default `Error.stackTraceLimit`.
+pub const STACK_TRACE_LIMIT: usize = 10;
+
+/// A function call that is currently being executed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    /// The name of the called function, `None` for anonymous functions.
+    function_name: Option<String>,
+
+    /// The position of the call site, `None` if the function was called by the engine.
+    position: Option<Position>,
+}
+
+impl CallFrame {
+    /// Creates a new `CallFrame`.
+    pub fn new<N>(function_name: Option<N>, position: Option<Position>) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            function_name: function_name.map(N::into),
+            position,
+        }
+    }
+
+    /// Creates the frame of a call written in the source code, naming it after the callee expression.
+    pub(crate) fn from_call_site(callee: &Node, position: Position) -> Self {
+        Self::new(callee_name(callee), Some(position))
+    }
+
+    /// Creates the frame of a `new` expression written in the source code.
+    pub(crate) fn from_construct_site(callee: &Node, position: Position) -> Self {
+        Self::new(
+            callee_name(callee).map(|name| format!("new {}", name)),
+            Some(position),
+        )
+    }
+
+    /// Gets the name of the called function.
+    pub fn function_name(&self) -> Option<&str> {
+        self.function_name.as_deref()
+    }
+
+    /// Gets the position of the call site.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+/// Gets a readable name for the function called by the `callee` expression.
+///
+/// Functions don't have a `name` property yet, so we use the expression instead:
+/// `foo()` is named `foo`, and `console.log()` is named `console.log`.
+fn callee_name(callee: &Node) -> Option<String> {
+    match callee.kind {
+        NodeKind::Local(ref name) => Some(name.clone()),
+        NodeKind::GetConstField(ref obj, ref field) => match obj.kind {
+            NodeKind::Local(ref obj) => Some(format!("{}.{}", obj, field)),
+            _ => Some(field.clone()),
+        },
+        _ => None,
+    }
+}
+
+/// Formats the frames of a stack trace, innermost first.
+///
+/// Each frame is reported at the call site of the frame above it, so the frame of the
+/// error constructor itself gives the position where the error was created.
+pub(crate) fn format_stack_trace(frames: &[CallFrame], script_name: &str) -> String {
+    let mut trace = String::new();
+    for (index, frame) in frames.iter().enumerate().rev().take(STACK_TRACE_LIMIT) {
+        let location = match frame.position {
+            Some(pos) => format!("{}:{}:{}", script_name, pos.line_number, pos.column_number),
+            None => script_name.to_string(),
+        };
+
+        let caller = index
+            .checked_sub(1)
+            .and_then(|caller| frames[caller].function_name());
+
+        if !trace.is_empty() {
+            trace.push('\n');
+        }
+        match caller {
+            Some(name) => trace.push_str(&format!("    at {} ({})", name, location)),
+            None => trace.push_str(&format!("    at {}", location)),
+        }
+    }
+    trace
+}
@@ -0,0 +1,41 @@
+//! This module implements the `Job` structure, a call queued to run once the current script is done.
+//!
+//! The interpreter runs its queued jobs, in order, when the embedder calls `Interpreter::run_jobs`.
+//! `forward` and `forward_val` do so after each script.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use super::Interpreter;
+use crate::builtins::value::{ResultValue, Value};
+
+/// A function call waiting in the job queue.
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// The function to call.
+    function: Value,
+
+    /// The `this` value of the call.
+    this: Value,
+
+    /// The arguments of the call.
+    arguments: Vec<Value>,
+}
+
+impl Job {
+    /// Creates a new `Job`, which calls `function` with the given `this` value and arguments.
+    pub fn new(function: Value, this: Value, arguments: Vec<Value>) -> Self {
+        Self {
+            function,
+            this,
+            arguments,
+        }
+    }
+
+    /// Runs the job.
+    pub(crate) fn run(mut self, ctx: &mut Interpreter) -> ResultValue {
+        ctx.call(&self.function, &mut self.this, &self.arguments)
+    }
+}
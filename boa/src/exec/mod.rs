@@ -1,31 +1,46 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+pub mod call_frame;
+pub mod job;
 #[cfg(test)]
 mod tests;
 
+pub use self::{call_frame::CallFrame, job::Job};
+
 use crate::{
     builtins::{
         array,
-        function::{Function as FunctionObject, FunctionBody, ThisMode},
-        object::{
-            internal_methods_trait::ObjectInternalMethods, Object, ObjectKind, INSTANCE_PROTOTYPE,
-            PROTOTYPE,
+        console::sink::{ConsoleSink, StdoutSink},
+        date::{
+            self,
+            clock::{Clock, SystemClock},
+            time_zone::{self, TimeZone},
         },
+        error, finalization_registry,
+        function::{self, ThisMode},
+        number,
+        object::{internal_methods_of, ObjectKind, WeakHandle, INSTANCE_PROTOTYPE, PROTOTYPE},
         property::Property,
-        value::{from_value, to_value, ResultValue, Value, ValueData},
+        string::STRING_INTERNAL_METHODS,
+        value::{to_value, JsString, ResultValue, Value, ValueData},
     },
+    context::EngineLimit,
     environment::lexical_environment::{new_declarative_environment, VariableScope},
     realm::Realm,
     syntax::ast::{
         constant::Const,
-        node::{MethodDefinitionKind, Node, PropertyDefinition},
+        node::{MethodDefinitionKind, Node, NodeKind, PropertyDefinition},
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
+        pos::Position,
     },
 };
 use gc::Gc;
+use rustc_hash::FxHashMap;
 use std::{
     borrow::{Borrow, BorrowMut},
+    collections::VecDeque,
     ops::Deref,
+    rc::Rc,
 };
 
 /// An execution engine
@@ -42,6 +57,43 @@ pub struct Interpreter {
     is_return: bool,
     /// realm holds both the global object and the environment
     pub realm: Realm,
+    /// The functions currently being called, outermost first
+    call_frames: Vec<CallFrame>,
+    /// The maximum number of nested calls, `None` for no limit
+    max_call_depth: Option<usize>,
+    /// The maximum size in bytes of the host stack used by nested calls, `None` for no limit
+    max_stack_size: Option<usize>,
+    /// The position of the host stack at the outermost call
+    stack_base: usize,
+    /// The `RangeError` thrown when a limit was last exceeded, with the limit
+    limit_error: Option<(Value, EngineLimit)>,
+    /// The name of the running script, used in stack traces
+    script_name: String,
+    /// The position of the innermost node being evaluated, where the engine creates its errors
+    position: Option<Position>,
+    /// The jobs waiting to run once the current script is done
+    jobs: VecDeque<Job>,
+    /// The objects kept alive until the current job is done, after being targeted by a `WeakRef`
+    kept_alive: Vec<Value>,
+    /// The `FinalizationRegistry` objects, checked for collected targets between jobs
+    finalization_registries: Vec<WeakHandle>,
+    /// The source of the current time, for `Date` objects
+    clock: Box<dyn Clock>,
+    /// The local time zone, for `Date` objects
+    time_zone: Box<dyn TimeZone>,
+    /// The receiver of the messages of the `console` object
+    console_sink: Box<dyn ConsoleSink>,
+    /// The source code of the running script, the functions it creates keep it for `toString()`
+    source_text: Option<Rc<str>>,
+    /// The global symbol registry of `Symbol.for`, the symbols by their key
+    symbol_registry: FxHashMap<JsString, Value>,
+}
+
+/// Gets the current position of the host stack, the address of a local of a new frame.
+#[inline(never)]
+fn stack_position() -> usize {
+    let marker = 0_u8;
+    &marker as *const u8 as usize
 }
 
 fn exec_assign_op(op: &AssignOp, v_a: ValueData, v_b: ValueData) -> Value {
@@ -65,86 +117,142 @@ impl Executor for Interpreter {
         Self {
             realm,
             is_return: false,
+            call_frames: Vec::new(),
+            max_call_depth: None,
+            max_stack_size: None,
+            stack_base: 0,
+            limit_error: None,
+            script_name: String::from("<anonymous>"),
+            position: None,
+            jobs: VecDeque::new(),
+            kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            clock: Box::new(SystemClock),
+            time_zone: time_zone::from_env(),
+            console_sink: Box::new(StdoutSink),
+            source_text: None,
+            symbol_registry: FxHashMap::default(),
         }
     }
 
-    #[allow(clippy::match_same_arms)]
     fn run(&mut self, node: &Node) -> ResultValue {
-        match *node {
-            Node::Const(Const::Null) => Ok(to_value(None::<()>)),
-            Node::Const(Const::Undefined) => Ok(Gc::new(ValueData::Undefined)),
-            Node::Const(Const::Num(num)) => Ok(to_value(num)),
-            Node::Const(Const::Int(num)) => Ok(to_value(num)),
+        // The errors created by the engine are reported at the innermost node being evaluated
+        let outer_position = self.position;
+        if node.span.start.line_number != 0 {
+            self.position = Some(node.span.start);
+        }
+        let result = self.evaluate(node);
+        self.position = outer_position;
+        result
+    }
+}
+
+impl Interpreter {
+    /// Evaluates `node`, this is the body of [`Executor::run`](trait.Executor.html#tymethod.run).
+    #[allow(clippy::match_same_arms)]
+    fn evaluate(&mut self, node: &Node) -> ResultValue {
+        match node.kind {
+            NodeKind::Const(Const::Null) => Ok(to_value(None::<()>)),
+            NodeKind::Const(Const::Undefined) => Ok(Gc::new(ValueData::Undefined)),
+            NodeKind::Const(Const::Num(num)) => Ok(to_value(num)),
+            NodeKind::Const(Const::Int(num)) => Ok(to_value(num)),
             // we can't move String from Const into value, because const is a garbage collected value
             // Which means Drop() get's called on Const, but str will be gone at that point.
             // Do Const values need to be garbage collected? We no longer need them once we've generated Values
-            Node::Const(Const::String(ref str)) => Ok(to_value(str.to_owned())),
-            Node::Const(Const::Bool(val)) => Ok(to_value(val)),
-            Node::Block(ref es) => {
-                {
-                    let env = &mut self.realm.environment;
-                    env.push(new_declarative_environment(Some(
-                        env.get_current_environment_ref().clone(),
-                    )));
-                }
-
-                let mut obj = to_value(None::<()>);
-                for e in es.iter() {
-                    let val = self.run(e)?;
-                    // early return
-                    if self.is_return {
-                        obj = val;
-                        break;
-                    }
-                    if e == es.last().expect("unable to get last value") {
-                        obj = val;
-                    }
+            NodeKind::Const(Const::String(ref str)) => Ok(to_value(str.clone())),
+            NodeKind::Const(Const::Bool(val)) => Ok(to_value(val)),
+            NodeKind::Block(ref es) => self.run_in_block_scope(es),
+            NodeKind::Local(ref name) => {
+                if !self.realm.environment.has_binding(name) {
+                    return self.throw_reference_error(format!("{} is not defined", name));
                 }
-
-                // pop the block env
-                let _ = self.realm.environment.pop();
-
-                Ok(obj)
+                Ok(self.realm.environment.get_binding_value(name))
             }
-            Node::Local(ref name) => {
-                let val = self.realm.environment.get_binding_value(name);
-                Ok(val)
-            }
-            Node::GetConstField(ref obj, ref field) => {
+            NodeKind::GetConstField(ref obj, ref field) => {
                 let val_obj = self.run(obj)?;
+                if val_obj.is_null_or_undefined() {
+                    return self.throw_type_error(format!(
+                        "Cannot read property '{}' of {}",
+                        field, val_obj
+                    ));
+                }
+                if val_obj.is_object() {
+                    return self.get_property(&val_obj, &to_value(field.clone()));
+                }
+                if val_obj.is_symbol() {
+                    return self.get_symbol_property(&val_obj, &to_value(field.clone()));
+                }
                 Ok(val_obj.borrow().get_field_slice(field))
             }
-            Node::GetField(ref obj, ref field) => {
+            NodeKind::GetField(ref obj, ref field) => {
                 let val_obj = self.run(obj)?;
                 let val_field = self.run(field)?;
-                Ok(val_obj
-                    .borrow()
-                    .get_field_slice(&val_field.borrow().to_string()))
+                if val_obj.is_null_or_undefined() {
+                    return self.throw_type_error(format!(
+                        "Cannot read property '{}' of {}",
+                        val_field, val_obj
+                    ));
+                }
+                let key = self.to_property_key(&mut val_field.clone());
+                if val_obj.is_object() {
+                    return self.get_property(&val_obj, &key);
+                }
+                if val_obj.is_symbol() {
+                    return self.get_symbol_property(&val_obj, &key);
+                }
+                // Symbol keyed properties of primitives, like `[Symbol.iterator]`, are on their prototype
+                if key.is_symbol() {
+                    let obj = self.to_object(&val_obj)?;
+                    return self.get_property(&obj, &key);
+                }
+                Ok(val_obj.get_field(key))
             }
-            Node::Call(ref callee, ref args) => {
-                let (mut this, func) = match callee.deref() {
-                    Node::GetConstField(ref obj, ref field) => {
+            NodeKind::Call(ref callee, ref args, position) => {
+                let (mut this, func) = match callee.kind {
+                    NodeKind::GetConstField(ref obj, ref field) => {
                         let mut obj = self.run(obj)?;
                         if obj.get_type() != "object" || obj.get_type() != "symbol" {
-                            obj = self.to_object(&obj).expect("failed to convert to object");
+                            obj = self.to_object(&obj)?;
                         }
-                        (obj.clone(), obj.borrow().get_field_slice(field))
+                        let func = if obj.is_object() {
+                            self.get_property(&obj, &to_value(field.clone()))?
+                        } else {
+                            obj.borrow().get_field_slice(field)
+                        };
+                        (obj, func)
                     }
-                    Node::GetField(ref obj, ref field) => {
+                    NodeKind::GetField(ref obj, ref field) => {
                         let obj = self.run(obj)?;
                         let field = self.run(field)?;
-                        (
-                            obj.clone(),
-                            obj.borrow().get_field_slice(&field.borrow().to_string()),
-                        )
+                        if obj.is_null_or_undefined() {
+                            return self.throw_type_error(format!(
+                                "Cannot read property '{}' of {}",
+                                field, obj
+                            ));
+                        }
+                        let key = self.to_property_key(&mut field.clone());
+                        let func = if obj.is_object() {
+                            self.get_property(&obj, &key)?
+                        } else if key.is_symbol() {
+                            let proto_obj = self.to_object(&obj)?;
+                            self.get_property(&proto_obj, &key)?
+                        } else {
+                            obj.get_field(key)
+                        };
+                        (obj, func)
                     }
                     _ => (self.realm.global_obj.clone(), self.run(&callee.clone())?), // 'this' binding should come from the function's self-contained environment
                 };
                 let mut v_args = Vec::with_capacity(args.len());
                 for arg in args.iter() {
-                    if let Node::Spread(ref x) = arg.deref() {
+                    if let NodeKind::Spread(ref x) = arg.kind {
                         let val = self.run(x)?;
-                        let mut vals = self.extract_array_properties(&val).unwrap();
+                        let mut vals = match self.extract_array_properties(&val) {
+                            Ok(vals) => vals,
+                            Err(()) => {
+                                return self.throw_type_error(format!("{} is not iterable", val))
+                            }
+                        };
                         v_args.append(&mut vals);
                         break; // after spread we don't accept any new arguments
                     }
@@ -152,28 +260,29 @@ impl Executor for Interpreter {
                 }
 
                 // execute the function call itself
-                let fnct_result = self.call(&func, &mut this, &v_args);
+                let frame = CallFrame::from_call_site(callee, position);
+                let fnct_result = self.call_in_frame(frame, &func, &mut this, &v_args);
 
                 // unset the early return flag
                 self.is_return = false;
 
                 fnct_result
             }
-            Node::WhileLoop(ref cond, ref expr) => {
+            NodeKind::WhileLoop(ref cond, ref expr) => {
                 let mut result = Gc::new(ValueData::Undefined);
                 while self.run(cond)?.borrow().is_true() {
                     result = self.run(expr)?;
                 }
                 Ok(result)
             }
-            Node::DoWhileLoop(ref body, ref cond) => {
+            NodeKind::DoWhileLoop(ref body, ref cond) => {
                 let mut result = self.run(body)?;
                 while self.run(cond)?.borrow().is_true() {
                     result = self.run(body)?;
                 }
                 Ok(result)
             }
-            Node::ForLoop(ref init, ref cond, ref step, ref body) => {
+            NodeKind::ForLoop(ref init, ref cond, ref step, ref body) => {
                 if let Some(init) = init {
                     self.run(init)?;
                 }
@@ -191,19 +300,19 @@ impl Executor for Interpreter {
 
                 Ok(Gc::new(ValueData::Undefined))
             }
-            Node::If(ref cond, ref expr, None) => Ok(if self.run(cond)?.borrow().is_true() {
+            NodeKind::If(ref cond, ref expr, None) => Ok(if self.run(cond)?.borrow().is_true() {
                 self.run(expr)?
             } else {
                 Gc::new(ValueData::Undefined)
             }),
-            Node::If(ref cond, ref expr, Some(ref else_e)) => {
+            NodeKind::If(ref cond, ref expr, Some(ref else_e)) => {
                 Ok(if self.run(cond)?.borrow().is_true() {
                     self.run(expr)?
                 } else {
                     self.run(else_e)?
                 })
             }
-            Node::Switch(ref val_e, ref vals, ref default) => {
+            NodeKind::Switch(ref val_e, ref vals, ref default) => {
                 let val = self.run(val_e)?;
                 let mut result = Gc::new(ValueData::Null);
                 let mut matched = false;
@@ -212,12 +321,8 @@ impl Executor for Interpreter {
                     let block = &tup.1;
                     if val == self.run(cond)? {
                         matched = true;
-                        let last_expr = block.last().expect("Block has no expressions");
                         for expr in block.iter() {
-                            let e_result = self.run(expr)?;
-                            if expr == last_expr {
-                                result = e_result;
-                            }
+                            result = self.run(expr)?;
                         }
                     }
                 }
@@ -230,7 +335,7 @@ impl Executor for Interpreter {
                 }
                 Ok(result)
             }
-            Node::Object(ref properties) => {
+            NodeKind::Object(ref properties) => {
                 let global_val = &self
                     .realm
                     .environment
@@ -249,22 +354,34 @@ impl Executor for Interpreter {
                                 obj.borrow().set_field_slice(&name.clone(), self.run(func)?);
                             } else {
                                 // TODO: Implement other types of MethodDefinitionKinds.
-                                unimplemented!("other types of property method definitions.");
+                                return self.throw_syntax_error(
+                                    "getters and setters in object literals are not supported yet",
+                                );
                             }
                         }
-                        i => unimplemented!("{:?} type of property", i),
+                        i => {
+                            return self.throw_syntax_error(format!(
+                                "{:?} type of property is not supported yet",
+                                i
+                            ))
+                        }
                     }
                 }
 
                 Ok(obj)
             }
-            Node::ArrayDecl(ref arr) => {
+            NodeKind::ArrayDecl(ref arr) => {
                 let array = array::new_array(self)?;
                 let mut elements: Vec<Value> = vec![];
                 for elem in arr.iter() {
-                    if let Node::Spread(ref x) = elem.deref() {
+                    if let NodeKind::Spread(ref x) = elem.kind {
                         let val = self.run(x)?;
-                        let mut vals = self.extract_array_properties(&val).unwrap();
+                        let mut vals = match self.extract_array_properties(&val) {
+                            Ok(vals) => vals,
+                            Err(()) => {
+                                return self.throw_type_error(format!("{} is not iterable", val))
+                            }
+                        };
                         elements.append(&mut vals);
                         continue; // Don't push array after spread
                     }
@@ -274,71 +391,41 @@ impl Executor for Interpreter {
                 Ok(array)
             }
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
-            Node::FunctionDecl(ref name, ref args, ref expr) => {
-                // Todo: Function.prototype doesn't exist yet, so the prototype right now is the Object.prototype
-                // let proto = &self
-                //     .realm
-                //     .environment
-                //     .get_global_object()
-                //     .expect("Could not get the global object")
-                //     .get_field_slice("Object")
-                //     .get_field_slice("Prototype");
-
-                let func = FunctionObject::create_ordinary(
-                    args.clone(), // TODO: args shouldn't need to be a reference it should be passed by value
-                    self.realm.environment.get_current_environment().clone(),
-                    FunctionBody::Ordinary(*expr.clone()),
+            NodeKind::FunctionDecl(ref name, ref args, ref expr) => {
+                let scope = self.realm.environment.get_current_environment().clone();
+                let val = function::create_function_object(
+                    self,
+                    args,
+                    expr,
                     ThisMode::NonLexical,
+                    name.as_deref().unwrap_or(""),
+                    scope,
+                    node.span,
                 );
 
-                let mut new_func = Object::function();
-                new_func.set_call(func);
-                let val = to_value(new_func);
-                val.set_field_slice("length", to_value(args.len()));
-
                 // Set the name and assign it in the current environment
-                if name.is_some() {
-                    self.realm.environment.create_mutable_binding(
-                        name.clone().expect("No name was supplied"),
-                        false,
-                        VariableScope::Function,
-                    );
-
-                    self.realm.environment.initialize_binding(
-                        name.as_ref().expect("Could not get name as reference"),
-                        val.clone(),
-                    )
+                if let Some(ref name) = *name {
+                    self.declare_var(name, val.clone());
                 }
 
                 Ok(val)
             }
-            Node::ArrowFunctionDecl(ref args, ref expr) => {
-                // Todo: Function.prototype doesn't exist yet, so the prototype right now is the Object.prototype
-                // let proto = &self
-                //     .realm
-                //     .environment
-                //     .get_global_object()
-                //     .expect("Could not get the global object")
-                //     .get_field_slice("Object")
-                //     .get_field_slice("Prototype");
-
-                let func = FunctionObject::create_ordinary(
-                    args.clone(), // TODO: args shouldn't need to be a reference it should be passed by value
-                    self.realm.environment.get_current_environment().clone(),
-                    FunctionBody::Ordinary(*expr.clone()),
+            NodeKind::ArrowFunctionDecl(ref args, ref expr) => {
+                let scope = self.realm.environment.get_current_environment().clone();
+                Ok(function::create_function_object(
+                    self,
+                    args,
+                    expr,
                     ThisMode::Lexical,
-                );
-
-                let mut new_func = Object::function();
-                new_func.set_call(func);
-                let val = to_value(new_func);
-                val.set_field_slice("length", to_value(args.len()));
-
-                Ok(val)
+                    "",
+                    scope,
+                    node.span,
+                ))
             }
-            Node::BinOp(BinOp::Num(ref op), ref a, ref b) => {
+            NodeKind::BinOp(BinOp::Num(ref op), ref a, ref b) => {
                 let v_r_a = self.run(a)?;
                 let v_r_b = self.run(b)?;
+                self.check_symbol_operands(&v_r_a, &v_r_b, *op == NumOp::Add)?;
                 let v_a = (*v_r_a).clone();
                 let v_b = (*v_r_b).clone();
                 Ok(Gc::new(match *op {
@@ -350,7 +437,24 @@ impl Executor for Interpreter {
                     NumOp::Mod => v_a % v_b,
                 }))
             }
-            Node::UnaryOp(ref op, ref a) => {
+            NodeKind::UnaryOp(UnaryOp::Delete, ref a) => match a.kind {
+                NodeKind::GetConstField(ref obj, ref field) => {
+                    let obj = self.run(obj)?;
+                    self.delete_field_of(&obj, to_value(field.clone()))
+                }
+                NodeKind::GetField(ref obj, ref field) => {
+                    let obj = self.run(obj)?;
+                    let field = self.run(field)?;
+                    self.delete_field_of(&obj, field)
+                }
+                // Declared variables can't be deleted
+                NodeKind::Local(_) => Ok(to_value(false)),
+                _ => {
+                    self.run(a)?;
+                    Ok(to_value(true))
+                }
+            },
+            NodeKind::UnaryOp(ref op, ref a) => {
                 let v_r_a = self.run(a)?;
                 let v_a = (*v_r_a).clone();
                 Ok(match op {
@@ -380,10 +484,16 @@ impl Executor for Interpreter {
                             !(num_v_a as i32)
                         })
                     }
-                    _ => unimplemented!(),
+                    UnaryOp::Void => Gc::new(ValueData::Undefined),
+                    _ => {
+                        return self.throw_syntax_error(format!(
+                            "the {} operator is not supported yet",
+                            op
+                        ))
+                    }
                 })
             }
-            Node::BinOp(BinOp::Bit(ref op), ref a, ref b) => {
+            NodeKind::BinOp(BinOp::Bit(ref op), ref a, ref b) => {
                 let v_r_a = self.run(a)?;
                 let v_r_b = self.run(b)?;
                 let v_a = (*v_r_a).clone();
@@ -398,11 +508,11 @@ impl Executor for Interpreter {
                     BitOp::UShr => v_a >> v_b,
                 }))
             }
-            Node::BinOp(BinOp::Comp(ref op), ref a, ref b) => {
+            NodeKind::BinOp(BinOp::Comp(ref op), ref a, ref b) => {
                 let mut v_r_a = self.run(a)?;
                 let mut v_r_b = self.run(b)?;
                 let mut v_a = v_r_a.borrow_mut();
-                let mut v_b = v_r_b.borrow_mut();
+                let v_b = v_r_b.borrow_mut();
                 Ok(to_value(match *op {
                     CompOp::Equal if v_a.is_object() => v_r_a == v_r_b,
                     CompOp::Equal => v_a == v_b,
@@ -418,48 +528,54 @@ impl Executor for Interpreter {
                     CompOp::LessThanOrEqual => v_a.to_number() <= v_b.to_number(),
                     CompOp::In => {
                         if !v_b.is_object() {
-                            panic!("TypeError: {} is not an Object.", v_b);
+                            return self.throw_type_error(format!(
+                                "Cannot use 'in' operator to search for '{}' in {}",
+                                v_a, v_b
+                            ));
                         }
                         let key = self.to_property_key(&mut v_a);
-                        self.has_property(&mut v_b, &key)
+                        self.has_property(&v_b, &key)?
                     }
                 }))
             }
-            Node::BinOp(BinOp::Log(ref op), ref a, ref b) => {
+            NodeKind::BinOp(BinOp::Log(ref op), ref a, ref b) => {
                 // turn a `Value` into a `bool`
-                let to_bool =
-                    |val| from_value::<bool>(val).expect("Could not convert JS value to bool");
+                let to_bool = |val: Value| val.is_true();
                 Ok(match *op {
                     LogOp::And => to_value(to_bool(self.run(a)?) && to_bool(self.run(b)?)),
                     LogOp::Or => to_value(to_bool(self.run(a)?) || to_bool(self.run(b)?)),
                 })
             }
-            Node::BinOp(BinOp::Assign(ref op), ref a, ref b) => match a.deref() {
-                Node::Local(ref name) => {
-                    let v_a = (*self.realm.environment.get_binding_value(&name)).clone();
-                    let v_b = (*self.run(b)?).clone();
-                    let value = exec_assign_op(op, v_a, v_b);
-                    self.realm
-                        .environment
-                        .set_mutable_binding(&name, value.clone(), true);
+            NodeKind::BinOp(BinOp::Assign(ref op), ref a, ref b) => match a.kind {
+                NodeKind::Local(ref name) => {
+                    if !self.realm.environment.has_binding(name) {
+                        return self.throw_reference_error(format!("{} is not defined", name));
+                    }
+                    let v_r_a = self.realm.environment.get_binding_value(name);
+                    let v_r_b = self.run(b)?;
+                    self.check_symbol_operands(&v_r_a, &v_r_b, *op == AssignOp::Add)?;
+                    let value = exec_assign_op(op, (*v_r_a).clone(), (*v_r_b).clone());
+                    self.set_binding(name, value.clone())?;
                     Ok(value)
                 }
-                Node::GetConstField(ref obj, ref field) => {
+                NodeKind::GetConstField(ref obj, ref field) => {
                     let v_r_a = self.run(obj)?;
-                    let v_a = (*v_r_a.borrow().get_field_slice(field)).clone();
-                    let v_b = (*self.run(b)?).clone();
-                    let value = exec_assign_op(op, v_a, v_b);
-                    v_r_a
-                        .borrow()
-                        .set_field_slice(&field.clone(), value.clone());
+                    let key = to_value(field.clone());
+                    let v_a = self.get_field_of(&v_r_a, &key)?;
+                    let v_b = self.run(b)?;
+                    self.check_symbol_operands(&v_a, &v_b, *op == AssignOp::Add)?;
+                    let value = exec_assign_op(op, (*v_a).clone(), (*v_b).clone());
+                    self.set_field_of(&v_r_a, key, value.clone())?;
                     Ok(value)
                 }
                 _ => Ok(Gc::new(ValueData::Undefined)),
             },
-            Node::New(ref call) => {
-                let (callee, args) = match call.as_ref() {
-                    Node::Call(callee, args) => (callee, args),
-                    _ => unreachable!("Node::New(ref call): 'call' must only be Node::Call type."),
+            NodeKind::New(ref call) => {
+                let (callee, args, position) = match call.kind {
+                    NodeKind::Call(ref callee, ref args, position) => (callee, args, position),
+                    _ => unreachable!(
+                        "NodeKind::New(ref call): 'call' must only be NodeKind::Call type."
+                    ),
                 };
 
                 let func_object = self.run(callee)?;
@@ -467,23 +583,19 @@ impl Executor for Interpreter {
                 for arg in args.iter() {
                     v_args.push(self.run(arg)?);
                 }
-                let mut this = ValueData::new_obj(None);
-                // Create a blank object, then set its __proto__ property to the [Constructor].prototype
-                this.borrow().set_internal_slot(
-                    INSTANCE_PROTOTYPE,
-                    func_object.borrow().get_field_slice(PROTOTYPE),
-                );
 
-                match *(func_object.borrow()).deref() {
-                    ValueData::Object(ref o) => (*o.deref().clone().borrow_mut())
-                        .construct
-                        .as_ref()
-                        .unwrap()
-                        .construct(&mut func_object.clone(), &v_args, self, &mut this),
-                    _ => Ok(Gc::new(ValueData::Undefined)),
+                if !func_object
+                    .as_object()
+                    .map_or(false, |obj| obj.is_constructor())
+                {
+                    return self.throw_type_error(format!("{} is not a constructor", callee));
                 }
+                self.push_call_frame(CallFrame::from_construct_site(callee, position))?;
+                let result = self.construct(&func_object, &v_args, &func_object);
+                let _ = self.call_frames.pop();
+                result
             }
-            Node::Return(ref ret) => {
+            NodeKind::Return(ref ret) => {
                 let result = match *ret {
                     Some(ref v) => self.run(v),
                     None => Ok(Gc::new(ValueData::Undefined)),
@@ -492,16 +604,14 @@ impl Executor for Interpreter {
                 self.is_return = true;
                 result
             }
-            Node::Throw(ref ex) => Err(self.run(ex)?),
-            Node::Assign(ref ref_e, ref val_e) => {
+            NodeKind::Throw(ref ex) => Err(self.run(ex)?),
+            NodeKind::Assign(ref ref_e, ref val_e) => {
                 let val = self.run(val_e)?;
-                match ref_e.deref() {
-                    Node::Local(ref name) => {
+                match ref_e.kind {
+                    NodeKind::Local(ref name) => {
                         if self.realm.environment.has_binding(name) {
                             // Binding already exists
-                            self.realm
-                                .environment
-                                .set_mutable_binding(&name, val.clone(), true);
+                            self.set_binding(name, val.clone())?;
                         } else {
                             self.realm.environment.create_mutable_binding(
                                 name.clone(),
@@ -511,40 +621,40 @@ impl Executor for Interpreter {
                             self.realm.environment.initialize_binding(name, val.clone());
                         }
                     }
-                    Node::GetConstField(ref obj, ref field) => {
+                    NodeKind::GetConstField(ref obj, ref field) => {
                         let val_obj = self.run(obj)?;
-                        val_obj
-                            .borrow()
-                            .set_field_slice(&field.clone(), val.clone());
+                        self.set_field_of(&val_obj, to_value(field.clone()), val.clone())?;
                     }
-                    Node::GetField(ref obj, ref field) => {
+                    NodeKind::GetField(ref obj, ref field) => {
                         let val_obj = self.run(obj)?;
                         let val_field = self.run(field)?;
-                        val_obj.borrow().set_field(val_field, val.clone());
+                        self.set_field_of(&val_obj, val_field, val.clone())?;
                     }
                     _ => (),
                 }
                 Ok(val)
             }
-            Node::VarDecl(ref vars) => {
-                for var in vars.iter() {
-                    let (name, value) = var.clone();
-                    let val = match value {
-                        Some(v) => self.run(&v)?,
-                        None => Gc::new(ValueData::Undefined),
-                    };
-                    self.realm.environment.create_mutable_binding(
-                        name.clone(),
-                        false,
-                        VariableScope::Function,
-                    );
-                    self.realm.environment.initialize_binding(&name, val);
+            NodeKind::VarDecl(ref vars) => {
+                for (name, value) in vars.iter() {
+                    match value {
+                        Some(value) => {
+                            let value = self.run(value)?;
+                            self.declare_var(name, value);
+                        }
+                        // Redeclaring a variable without a value keeps its value.
+                        None if self
+                            .realm
+                            .environment
+                            .has_declaration(name, VariableScope::Function) => {}
+                        None => self.declare_var(name, Gc::new(ValueData::Undefined)),
+                    }
                 }
                 Ok(Gc::new(ValueData::Undefined))
             }
-            Node::LetDecl(ref vars) => {
+            NodeKind::LetDecl(ref vars) => {
                 for var in vars.iter() {
                     let (name, value) = var.clone();
+                    self.check_lexical_declaration(&name)?;
                     let val = match value {
                         Some(v) => self.run(&v)?,
                         None => Gc::new(ValueData::Undefined),
@@ -558,8 +668,9 @@ impl Executor for Interpreter {
                 }
                 Ok(Gc::new(ValueData::Undefined))
             }
-            Node::ConstDecl(ref vars) => {
+            NodeKind::ConstDecl(ref vars) => {
                 for (name, value) in vars.iter() {
+                    self.check_lexical_declaration(name)?;
                     self.realm.environment.create_immutable_binding(
                         name.clone(),
                         false,
@@ -570,8 +681,14 @@ impl Executor for Interpreter {
                 }
                 Ok(Gc::new(ValueData::Undefined))
             }
-            Node::TypeOf(ref val_e) => {
-                let val = self.run(val_e)?;
+            NodeKind::TypeOf(ref val_e) => {
+                let val = match val_e.kind {
+                    // `typeof` doesn't throw on undeclared variables
+                    NodeKind::Local(ref name) if !self.realm.environment.has_binding(name) => {
+                        Gc::new(ValueData::Undefined)
+                    }
+                    _ => self.run(val_e)?,
+                };
                 Ok(to_value(match *val {
                     ValueData::Undefined => "undefined",
                     ValueData::Symbol(_) => "symbol",
@@ -588,62 +705,397 @@ impl Executor for Interpreter {
                     }
                 }))
             }
-            Node::StatementList(ref list) => {
-                {
-                    let env = &mut self.realm.environment;
-                    env.push(new_declarative_environment(Some(
-                        env.get_current_environment_ref().clone(),
-                    )));
+            NodeKind::StatementList(ref list) => self.run_in_block_scope(list),
+            NodeKind::Spread(ref node) => {
+                // TODO: for now we can do nothing but return the value as-is
+                Ok(Gc::new((*self.run(node)?).clone()))
+            }
+            NodeKind::ConditionalOp(ref cond, ref if_true, ref if_false) => {
+                if self.run(cond)?.borrow().is_true() {
+                    self.run(if_true)
+                } else {
+                    self.run(if_false)
                 }
+            }
+            NodeKind::Try(ref try_block, ref catch, ref param, ref finally) => {
+                let result = match (self.run(try_block), catch) {
+                    (Err(error), Some(catch)) => {
+                        {
+                            let env = &mut self.realm.environment;
+                            env.push(new_declarative_environment(Some(
+                                env.get_current_environment_ref().clone(),
+                            )));
+                        }
 
-                let mut obj = to_value(None::<()>);
-                for (i, item) in list.iter().enumerate() {
-                    let val = self.run(item)?;
-                    // early return
-                    if self.is_return {
-                        obj = val;
-                        break;
+                        if let Some(NodeKind::Local(ref name)) =
+                            param.as_ref().map(|param| &param.kind)
+                        {
+                            self.realm.environment.create_mutable_binding(
+                                name.clone(),
+                                false,
+                                VariableScope::Block,
+                            );
+                            self.realm.environment.initialize_binding(name, error);
+                        }
+
+                        let result = self.run(catch);
+
+                        // pop the catch env
+                        let _ = self.realm.environment.pop();
+
+                        result
                     }
-                    if i + 1 == list.len() {
-                        obj = val;
+                    (result, _) => result,
+                };
+
+                if let Some(finally) = finally {
+                    // A `return` inside the finally block overrides the completion of the try block
+                    let is_return = std::mem::replace(&mut self.is_return, false);
+                    let finally_result = self.run(finally)?;
+                    if self.is_return {
+                        return Ok(finally_result);
                     }
+                    self.is_return = is_return;
                 }
 
-                // pop the block env
-                let _ = self.realm.environment.pop();
+                result
+            }
+            ref i => self.throw_syntax_error(format!("{} is not supported yet", i)),
+        }
+    }
+
+    /// Runs a list of statements in a new block scope.
+    ///
+    /// The scope is popped even if one of the statements throws, so a caught exception
+    /// doesn't leave the environment stack unbalanced.
+    fn run_in_block_scope(&mut self, list: &[Node]) -> ResultValue {
+        {
+            let env = &mut self.realm.environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+        }
 
-                Ok(obj)
+        let result = self.run_statements(list);
+
+        // pop the block env
+        let _ = self.realm.environment.pop();
+
+        result
+    }
+
+    /// Runs a parsed script, whose top-level declarations stay in the global scope for the
+    /// following scripts.
+    pub(crate) fn run_script(&mut self, script: &Node) -> ResultValue {
+        match script.kind {
+            NodeKind::StatementList(ref list) => self.run_statements(list),
+            _ => self.run(script),
+        }
+    }
+
+    /// Runs a list of statements, returning the value of the last one.
+    fn run_statements(&mut self, list: &[Node]) -> ResultValue {
+        let mut obj = to_value(None::<()>);
+        for (i, item) in list.iter().enumerate() {
+            let val = self.run(item)?;
+            // early return
+            if self.is_return {
+                obj = val;
+                break;
             }
-            Node::Spread(ref node) => {
-                // TODO: for now we can do nothing but return the value as-is
-                Ok(Gc::new((*self.run(node)?).clone()))
+            if i + 1 == list.len() {
+                obj = val;
             }
-            ref i => unimplemented!("{}", i),
         }
+
+        Ok(obj)
     }
-}
 
-impl Interpreter {
     /// Get the Interpreter's realm
     pub(crate) fn get_realm(&self) -> &Realm {
         &self.realm
     }
 
+    /// Gets the functions currently being called, outermost first.
+    ///
+    /// Functions called by the engine itself, like the callback of `Array.prototype.map`,
+    /// have an anonymous frame without a position.
+    pub fn call_frames(&self) -> &[CallFrame] {
+        &self.call_frames
+    }
+
+    /// Pushes the frame of a call, or throws a `RangeError` if the maximum call depth or stack
+    /// size is reached.
+    fn push_call_frame(&mut self, frame: CallFrame) -> Result<(), Value> {
+        let position = stack_position();
+        if self.call_frames.is_empty() {
+            self.stack_base = position;
+        }
+        // The stack grows downwards on most platforms, but not all of them.
+        let stack_size = self.stack_base.max(position) - self.stack_base.min(position);
+        let limit = match (self.max_call_depth, self.max_stack_size) {
+            (Some(max), _) if self.call_frames.len() >= max => Some(EngineLimit::CallDepth(max)),
+            (_, Some(max)) if stack_size >= max => Some(EngineLimit::StackSize(max)),
+            _ => None,
+        };
+        if let Some(limit) = limit {
+            let error = self.construct_range_error("Maximum call stack size exceeded");
+            self.limit_error = Some((error.clone(), limit));
+            return Err(error);
+        }
+        self.call_frames.push(frame);
+        Ok(())
+    }
+
+    /// Gets the maximum number of nested calls, `None` for no limit.
+    pub fn max_call_depth(&self) -> Option<usize> {
+        self.max_call_depth
+    }
+
+    /// Sets the maximum number of nested calls, beyond which calls throw a `RangeError`.
+    ///
+    /// Defaults to `None`, in which case a deep recursion overflows the stack of the host thread.
+    pub fn set_max_call_depth(&mut self, max_call_depth: Option<usize>) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    /// Gets the maximum size in bytes of the host stack used by nested calls, `None` for no limit.
+    pub fn max_stack_size(&self) -> Option<usize> {
+        self.max_stack_size
+    }
+
+    /// Sets the maximum size in bytes of the host stack used by nested calls, from the outermost
+    /// one, beyond which calls throw a `RangeError`.
+    ///
+    /// Defaults to `None`, like the maximum call depth.
+    pub fn set_max_stack_size(&mut self, max_stack_size: Option<usize>) {
+        self.max_stack_size = max_stack_size;
+    }
+
+    /// Takes the `RangeError` thrown when a limit was last exceeded, with the limit.
+    pub(crate) fn take_limit_error(&mut self) -> Option<(Value, EngineLimit)> {
+        self.limit_error.take()
+    }
+
+    /// Gets the current stack trace, in the V8 `    at fn (file:line:column)` format.
+    pub fn stack_trace(&self) -> String {
+        call_frame::format_stack_trace(&self.call_frames, &self.script_name)
+    }
+
+    /// Gets the name of the running script, as shown in stack traces.
+    pub fn script_name(&self) -> &str {
+        &self.script_name
+    }
+
+    /// Sets the name of the running script, as shown in stack traces.
+    ///
+    /// Defaults to `<anonymous>`.
+    pub fn set_script_name<N>(&mut self, name: N)
+    where
+        N: Into<String>,
+    {
+        self.script_name = name.into();
+    }
+
+    /// Sets the source code of the script about to run.
+    ///
+    /// The functions created by the script keep it, so `Function.prototype.toString()`
+    /// can return their source text.
+    pub fn set_source_text(&mut self, source: &str) {
+        self.source_text = Some(Rc::from(source));
+    }
+
+    /// Gets the source code of the running script or function, if it's known.
+    pub(crate) fn source_text(&self) -> Option<Rc<str>> {
+        self.source_text.clone()
+    }
+
+    /// Replaces the source code of the running code, returning the previous one.
+    ///
+    /// Functions run with the source they were created from, so the functions they
+    /// create in turn keep the right source text.
+    pub(crate) fn replace_source_text(&mut self, source: Option<Rc<str>>) -> Option<Rc<str>> {
+        std::mem::replace(&mut self.source_text, source)
+    }
+
+    /// Gets the global symbol registry, shared by `Symbol.for` and `Symbol.keyFor`.
+    pub(crate) fn symbol_registry(&mut self) -> &mut FxHashMap<JsString, Value> {
+        &mut self.symbol_registry
+    }
+
+    /// Adds a job at the end of the job queue.
+    pub fn enqueue_job(&mut self, job: Job) {
+        self.jobs.push_back(job);
+    }
+
+    /// Runs the queued jobs, until the queue is empty.
+    ///
+    /// The cleanup callbacks of `FinalizationRegistry` objects whose targets have been collected are
+    /// queued before each job. If a job throws, the remaining jobs stay queued and the error is returned.
+    pub fn run_jobs(&mut self) -> Result<(), Value> {
+        loop {
+            self.kept_alive.clear();
+            finalization_registry::enqueue_cleanup_jobs(self);
+
+            match self.jobs.pop_front() {
+                Some(job) => {
+                    job.run(self)?;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Keeps `value` alive until the current job is done, so a new `WeakRef` doesn't lose its target
+    /// before the script could use it.
+    pub(crate) fn keep_alive(&mut self, value: Value) {
+        self.kept_alive.push(value);
+    }
+
+    /// Registers a `FinalizationRegistry` object, whose cleanup callbacks are queued by `run_jobs`.
+    pub(crate) fn register_finalization_registry(&mut self, registry: WeakHandle) {
+        self.finalization_registries.push(registry);
+    }
+
+    /// Gets the `FinalizationRegistry` objects that are still alive, forgetting the collected ones.
+    pub(crate) fn finalization_registries(&mut self) -> Vec<Value> {
+        self.finalization_registries
+            .retain(|registry| registry.is_alive());
+        self.finalization_registries
+            .iter()
+            .filter_map(WeakHandle::upgrade)
+            .collect()
+    }
+
+    /// Gets the source of the current time, used by `Date` objects.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Sets the source of the current time, used by `Date` objects.
+    ///
+    /// Defaults to the `SystemClock`.
+    pub fn set_clock<C>(&mut self, clock: C)
+    where
+        C: Clock + 'static,
+    {
+        self.clock = Box::new(clock);
+    }
+
+    /// Gets the local time zone, used by `Date` objects.
+    pub fn time_zone(&self) -> &dyn TimeZone {
+        self.time_zone.as_ref()
+    }
+
+    /// Sets the local time zone, used by `Date` objects.
+    ///
+    /// Defaults to the time zone of the system, as found by `time_zone::from_env`.
+    pub fn set_time_zone<Z>(&mut self, time_zone: Z)
+    where
+        Z: TimeZone + 'static,
+    {
+        self.time_zone = Box::new(time_zone);
+    }
+
+    /// Gets the receiver of the messages of the `console` object.
+    pub fn console_sink(&mut self) -> &mut dyn ConsoleSink {
+        self.console_sink.as_mut()
+    }
+
+    /// Sets the receiver of the messages of the `console` object.
+    ///
+    /// Defaults to the `StdoutSink`.
+    pub fn set_console_sink<S>(&mut self, sink: S)
+    where
+        S: ConsoleSink + 'static,
+    {
+        self.console_sink = Box::new(sink);
+    }
+
     /// https://tc39.es/ecma262/#sec-call
     pub(crate) fn call(
         &mut self,
         f: &Value,
         this: &mut Value,
         arguments_list: &[Value],
+    ) -> ResultValue {
+        self.call_in_frame(
+            CallFrame::new(None::<String>, None),
+            f,
+            this,
+            arguments_list,
+        )
+    }
+
+    /// Calls `f`, with `frame` on top of the call stack for the duration of the call.
+    fn call_in_frame(
+        &mut self,
+        frame: CallFrame,
+        f: &Value,
+        this: &mut Value,
+        arguments_list: &[Value],
     ) -> ResultValue {
         // All functions should be objects, and eventually will be.
         // During this transition call will support both native functions and function objects
-        match (*f).deref() {
-            ValueData::Object(ref obj) => match (*obj).deref().borrow().call {
-                Some(ref func) => func.call(&mut f.clone(), arguments_list, self, this),
-                None => panic!("Expected function"),
-            },
-            _ => Err(Gc::new(ValueData::Undefined)),
+        let (exotic_call, func) = match (*f).deref() {
+            ValueData::Object(ref obj) => {
+                let obj = obj.deref().borrow();
+                (obj.internal_methods.call, obj.call.clone())
+            }
+            _ => (None, None),
+        };
+
+        if let Some(call) = exotic_call {
+            self.push_call_frame(frame)?;
+            let result = call(f, this, arguments_list, self);
+            let _ = self.call_frames.pop();
+            return result;
+        }
+        match func {
+            Some(func) => {
+                self.push_call_frame(frame)?;
+                let result = func.call(&mut f.clone(), arguments_list, self, this);
+                let _ = self.call_frames.pop();
+
+                // a `return` statement only ends the function it's in, not the caller
+                self.is_return = false;
+                result
+            }
+            None => self.throw_type_error(format!("{} is not a function", f)),
+        }
+    }
+
+    /// Constructs an object with the constructor `f`, taking the prototype of `new_target`.
+    ///
+    /// https://tc39.es/ecma262/#sec-construct
+    pub(crate) fn construct(
+        &mut self,
+        f: &Value,
+        arguments_list: &[Value],
+        new_target: &Value,
+    ) -> ResultValue {
+        let (exotic_construct, construct) = match (*f).deref() {
+            ValueData::Object(ref obj) => {
+                let obj = obj.deref().borrow();
+                (obj.internal_methods.construct, obj.construct.clone())
+            }
+            _ => (None, None),
+        };
+
+        if let Some(construct) = exotic_construct {
+            return construct(f, arguments_list, new_target, self);
+        }
+        match construct {
+            Some(construct) => {
+                // Create a blank object, then set its __proto__ property to the [Constructor].prototype
+                let mut this = ValueData::new_obj(None);
+                let proto = self.get_property(new_target, &to_value(PROTOTYPE))?;
+                this.set_internal_slot(INSTANCE_PROTOTYPE, proto);
+                let result = construct.construct(&mut f.clone(), arguments_list, self, &mut this);
+                self.is_return = false;
+                result
+            }
+            None => self.throw_type_error(format!("{} is not a constructor", f)),
         }
     }
 
@@ -695,8 +1147,13 @@ impl Interpreter {
 
                 // Skip d, e we don't support Symbols yet
                 // TODO: add when symbols are supported
+                // Until then, this is what `Date.prototype[Symbol.toPrimitive]` does
                 if hint == "default" {
-                    hint = "number";
+                    hint = if date::time_value_of(input).is_some() {
+                        "string"
+                    } else {
+                        "number"
+                    };
                 };
 
                 self.ordinary_to_primitive(input, hint)
@@ -735,26 +1192,107 @@ impl Interpreter {
         }
     }
 
+    /// `[[GetPrototypeOf]]` of `obj`, which must be an object.
+    pub fn get_prototype_of(&mut self, obj: &Value) -> ResultValue {
+        (internal_methods_of(obj).get_prototype_of)(obj, self)
+    }
+
+    /// `[[SetPrototypeOf]]` of `obj`, which must be an object.
+    pub fn set_prototype_of(&mut self, obj: &Value, proto: &Value) -> Result<bool, Value> {
+        (internal_methods_of(obj).set_prototype_of)(obj, proto, self)
+    }
+
+    /// https://tc39.es/ecma262/#sec-isextensible-o
+    pub fn is_extensible(&mut self, obj: &Value) -> Result<bool, Value> {
+        (internal_methods_of(obj).is_extensible)(obj, self)
+    }
+
+    /// `[[PreventExtensions]]` of `obj`, which must be an object.
+    pub fn prevent_extensions(&mut self, obj: &Value) -> Result<bool, Value> {
+        (internal_methods_of(obj).prevent_extensions)(obj, self)
+    }
+
+    /// `[[GetOwnProperty]]` of `obj`, which must be an object.
+    pub fn get_own_property(
+        &mut self,
+        obj: &Value,
+        key: &Value,
+    ) -> Result<Option<Property>, Value> {
+        (internal_methods_of(obj).get_own_property)(obj, key, self)
+    }
+
+    /// `[[DefineOwnProperty]]` of `obj`, which must be an object.
+    pub fn define_own_property(
+        &mut self,
+        obj: &Value,
+        key: &Value,
+        desc: Property,
+    ) -> Result<bool, Value> {
+        (internal_methods_of(obj).define_own_property)(obj, key, desc, self)
+    }
+
+    /// https://tc39.es/ecma262/#sec-createdataproperty
+    pub fn create_data_property(
+        &mut self,
+        obj: &Value,
+        key: &Value,
+        value: Value,
+    ) -> Result<bool, Value> {
+        let desc = Property::new()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true);
+        self.define_own_property(obj, key, desc)
+    }
+
     /// https://tc39.es/ecma262/#sec-hasproperty
-    pub fn has_property(&self, obj: &mut Value, key: &Value) -> bool {
-        if let Some(obj) = obj.as_object() {
-            if !Property::is_property_key(key) {
-                false
-            } else {
-                obj.has_property(key)
-            }
+    pub fn has_property(&mut self, obj: &Value, key: &Value) -> Result<bool, Value> {
+        if obj.is_object() && Property::is_property_key(key) {
+            (internal_methods_of(obj).has_property)(obj, key, self)
         } else {
-            false
+            Ok(false)
         }
     }
 
+    /// https://tc39.es/ecma262/#sec-get-o-p
+    pub fn get_property(&mut self, obj: &Value, key: &Value) -> ResultValue {
+        (internal_methods_of(obj).get)(obj, key, obj, self)
+    }
+
+    /// `GetV` of a symbol, which looks `key` up on `Symbol.prototype` with the symbol as the receiver.
+    fn get_symbol_property(&mut self, symbol: &Value, key: &Value) -> ResultValue {
+        let proto = symbol.get_internal_slot(INSTANCE_PROTOTYPE);
+        if !proto.is_object() {
+            return Ok(Gc::new(ValueData::Undefined));
+        }
+        (internal_methods_of(&proto).get)(&proto, key, symbol, self)
+    }
+
+    /// https://tc39.es/ecma262/#sec-set-o-p-v-throw
+    ///
+    /// Returns whether the assignment succeeded, sloppy mode code ignores failures.
+    pub fn set_property(&mut self, obj: &Value, key: &Value, value: Value) -> Result<bool, Value> {
+        (internal_methods_of(obj).set)(obj, key, value, obj, self)
+    }
+
+    /// `[[Delete]]` of `obj`, which must be an object.
+    pub fn delete_property(&mut self, obj: &Value, key: &Value) -> Result<bool, Value> {
+        (internal_methods_of(obj).delete)(obj, key, self)
+    }
+
+    /// `[[OwnPropertyKeys]]` of `obj`, which must be an object.
+    pub fn own_property_keys(&mut self, obj: &Value) -> Result<Vec<Value>, Value> {
+        (internal_methods_of(obj).own_property_keys)(obj, self)
+    }
+
     /// The abstract operation ToObject converts argument to a value of type Object
     /// https://tc39.es/ecma262/#sec-toobject
     #[allow(clippy::wrong_self_convention)]
     pub fn to_object(&mut self, value: &Value) -> ResultValue {
         match *value.deref().borrow() {
-            ValueData::Undefined | ValueData::Integer(_) | ValueData::Null => {
-                Err(Gc::new(ValueData::Undefined))
+            ValueData::Undefined | ValueData::Null => {
+                self.throw_type_error("Cannot convert undefined or null to object")
             }
             ValueData::Boolean(_) => {
                 let proto = self
@@ -767,7 +1305,7 @@ impl Interpreter {
                 bool_obj.set_internal_slot("BooleanData", value.clone());
                 Ok(bool_obj)
             }
-            ValueData::Rational(_) => {
+            ValueData::Rational(_) | ValueData::Integer(_) => {
                 let proto = self
                     .realm
                     .environment
@@ -785,6 +1323,7 @@ impl Interpreter {
                     .get_field_slice(PROTOTYPE);
                 let string_obj = ValueData::new_obj_from_prototype(proto, ObjectKind::String);
                 string_obj.set_internal_slot("StringData", value.clone());
+                string_obj.set_internal_methods(&STRING_INTERNAL_METHODS);
                 Ok(string_obj)
             }
             ValueData::Object(_) | ValueData::Symbol(_) => Ok(value.clone()),
@@ -798,7 +1337,7 @@ impl Interpreter {
             ValueData::Boolean(ref boolean) => boolean.to_string(),
             ValueData::Rational(ref num) => num.to_string(),
             ValueData::Integer(ref num) => num.to_string(),
-            ValueData::String(ref string) => string.clone(),
+            ValueData::String(ref string) => string.to_string(),
             ValueData::Object(_) => {
                 let prim_value = self.to_primitive(&mut (value.clone()), Some("string"));
                 self.to_string(&prim_value).to_string()
@@ -807,6 +1346,18 @@ impl Interpreter {
         }
     }
 
+    /// value_to_js_string() converts a value into a JavaScript string, keeping its UTF-16 code units
+    pub fn value_to_js_string(&mut self, value: &Value) -> JsString {
+        match *value.deref().borrow() {
+            ValueData::String(ref string) => string.clone(),
+            ValueData::Object(_) => {
+                let prim_value = self.to_primitive(&mut (value.clone()), Some("string"));
+                self.value_to_js_string(&prim_value)
+            }
+            _ => JsString::from(self.value_to_rust_string(value)),
+        }
+    }
+
     pub fn value_to_rust_number(&mut self, value: &Value) -> f64 {
         match *value.deref().borrow() {
             ValueData::Null => f64::from(0),
@@ -819,18 +1370,63 @@ impl Interpreter {
             }
             ValueData::Rational(num) => num,
             ValueData::Integer(num) => f64::from(num),
-            ValueData::String(ref string) => string.parse::<f64>().unwrap(),
+            ValueData::String(ref string) => number::string_to_number(&string.to_string()),
             ValueData::Object(_) => {
                 let prim_value = self.to_primitive(&mut (value.clone()), Some("number"));
-                self.to_string(&prim_value)
-                    .to_string()
-                    .parse::<f64>()
-                    .expect("cannot parse valur to x64")
-            }
-            _ => {
-                // TODO: Make undefined?
-                f64::from(0)
+                self.value_to_rust_number(&prim_value)
             }
+            ValueData::Undefined | ValueData::Symbol(_) => f64::NAN,
+        }
+    }
+
+    /// Converts a value to a number, throwing a `TypeError` for symbols.
+    ///
+    /// https://tc39.es/ecma262/#sec-tonumber
+    pub fn to_number(&mut self, value: &Value) -> Result<f64, Value> {
+        if value.is_symbol() {
+            return Err(self.construct_type_error("Cannot convert a Symbol value to a number"));
+        }
+        Ok(self.value_to_rust_number(value))
+    }
+
+    /// Converts a value to an integral number, keeping infinities and mapping `NaN` to 0.
+    ///
+    /// https://tc39.es/ecma262/#sec-tointegerorinfinity
+    pub fn to_integer_or_infinity(&mut self, value: &Value) -> f64 {
+        let number = self.value_to_rust_number(value);
+        if number.is_nan() {
+            0.0
+        } else {
+            number.trunc() + 0.0
+        }
+    }
+
+    /// Converts a value to an index, as used for the lengths and offsets of binary data.
+    ///
+    /// Throws a `RangeError` if the value is negative or too big.
+    ///
+    /// https://tc39.es/ecma262/#sec-toindex
+    pub fn to_index(&mut self, value: &Value) -> Result<usize, Value> {
+        let integer = self.to_integer_or_infinity(value);
+        if integer < 0.0 || integer > 9_007_199_254_740_991.0 {
+            return Err(self.construct_range_error(format!("Invalid index: {}", value)));
+        }
+        Ok(integer as usize)
+    }
+
+    /// Converts a value to an unsigned 32 bits integer, wrapping it modulo 2^32.
+    ///
+    /// https://tc39.es/ecma262/#sec-touint32
+    pub fn to_uint32(&mut self, value: &Value) -> u32 {
+        let number = self.value_to_rust_number(value);
+        if !number.is_finite() {
+            return 0;
+        }
+        let modulo = number.trunc() % 4_294_967_296.0;
+        if modulo < 0.0 {
+            (modulo + 4_294_967_296.0) as u32
+        } else {
+            modulo as u32
         }
     }
 
@@ -854,21 +1450,222 @@ impl Interpreter {
         Err(())
     }
 
+    /// Reads the `field` of `obj` for a property access in the code.
+    ///
+    /// Objects go through their `[[Get]]` internal method, primitives read the fields of their wrapper.
+    fn get_field_of(&mut self, obj: &Value, field: &Value) -> ResultValue {
+        let key = self.to_property_key(&mut field.clone());
+        if obj.is_object() {
+            self.get_property(obj, &key)
+        } else {
+            Ok(obj.get_field(key))
+        }
+    }
+
+    /// Assigns the `field` of `obj` for an assignment in the code, returning the assigned value.
+    ///
+    /// Objects go through their `[[Set]]` internal method, assignments to primitives are ignored.
+    fn set_field_of(&mut self, obj: &Value, field: Value, value: Value) -> ResultValue {
+        if obj.is_object() {
+            let key = self.to_property_key(&mut field.clone());
+            self.set_property(obj, &key, value.clone())?;
+        }
+        Ok(value)
+    }
+
+    /// Deletes the `field` of `obj` for a `delete` expression, returning whether it succeeded.
+    fn delete_field_of(&mut self, obj: &Value, field: Value) -> ResultValue {
+        let obj = self.to_object(obj)?;
+        let key = self.to_property_key(&mut field.clone());
+        if obj.is_object() {
+            Ok(to_value(self.delete_property(&obj, &key)?))
+        } else {
+            Ok(to_value(true))
+        }
+    }
+
+    /// Reads the elements of an array-like object, up to its `length`.
+    ///
+    /// https://tc39.es/ecma262/#sec-createlistfromarraylike
+    pub(crate) fn create_list_from_array_like(
+        &mut self,
+        value: &Value,
+    ) -> Result<Vec<Value>, Value> {
+        if !value.is_object() {
+            return Err(self.construct_type_error(format!(
+                "CreateListFromArrayLike called on non-object: {}",
+                value
+            )));
+        }
+        let length = self.get_property(value, &to_value("length"))?;
+        let length = self.value_to_rust_number(&length);
+        let length = if length > 0.0 { length as usize } else { 0 };
+        (0..length)
+            .map(|index| self.get_property(value, &to_value(index.to_string())))
+            .collect()
+    }
+
+    /// Declares the variable or function `name` in the current function, or assigns `value` to it if
+    /// it's already declared.
+    fn declare_var(&mut self, name: &str, value: Value) {
+        let environment = &mut self.realm.environment;
+        if environment.has_declaration(name, VariableScope::Function) {
+            environment.set_mutable_binding(name, value, false);
+        } else {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Function);
+            environment.initialize_binding(name, value);
+        }
+    }
+
+    /// Throws a `SyntaxError` if a `let` or `const` declaration of `name` would redeclare it in the
+    /// current block.
+    fn check_lexical_declaration(&mut self, name: &str) -> Result<(), Value> {
+        if self
+            .realm
+            .environment
+            .has_declaration(name, VariableScope::Block)
+        {
+            self.throw_syntax_error(format!("Identifier '{}' has already been declared", name))?;
+        }
+        Ok(())
+    }
+
+    /// Assigns `value` to the existing binding `name`, or throws a `TypeError` if it's a constant.
+    fn set_binding(&mut self, name: &str, value: Value) -> Result<(), Value> {
+        if self.realm.environment.is_immutable_binding(name) {
+            self.throw_type_error("Assignment to constant variable.")?;
+        }
+        self.realm
+            .environment
+            .set_mutable_binding(name, value, true);
+        Ok(())
+    }
+
+    /// Throws a `TypeError` if one of the operands of an arithmetic operator is a symbol, symbols
+    /// can't be converted to numbers, nor to strings for an `add` with a string operand.
+    fn check_symbol_operands(&mut self, a: &Value, b: &Value, add: bool) -> Result<(), Value> {
+        if a.is_symbol() || b.is_symbol() {
+            let target = if add && (a.is_string() || b.is_string()) {
+                "string"
+            } else {
+                "number"
+            };
+            self.throw_type_error(format!("Cannot convert a Symbol value to a {}", target))?;
+        }
+        Ok(())
+    }
+
     fn set_value(&mut self, node: &Node, value: Value) -> ResultValue {
-        match node {
-            Node::Local(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name, value.clone(), true);
+        match node.kind {
+            NodeKind::Local(ref name) => {
+                if !self.realm.environment.has_binding(name) {
+                    return self.throw_reference_error(format!("{} is not defined", name));
+                }
+                self.set_binding(name, value.clone())?;
                 Ok(value)
             }
-            Node::GetConstField(ref obj, ref field) => {
-                Ok(self.run(obj)?.set_field_slice(field, value))
+            NodeKind::GetConstField(ref obj, ref field) => {
+                let obj = self.run(obj)?;
+                self.set_field_of(&obj, to_value(field.clone()), value)
             }
-            Node::GetField(ref obj, ref field) => {
-                Ok(self.run(obj)?.set_field(self.run(field)?, value))
+            NodeKind::GetField(ref obj, ref field) => {
+                let obj = self.run(obj)?;
+                let field = self.run(field)?;
+                self.set_field_of(&obj, field, value)
             }
-            _ => panic!("TypeError: invalid assignment to {}", node),
+            _ => self
+                .throw_reference_error(format!("Invalid left-hand side in assignment: {}", node)),
         }
     }
+
+    /// Constructs a native error object of the given kind, as if by `new <name>(message)`.
+    fn construct_native_error(&mut self, name: &str, message: String) -> Value {
+        // The innermost frame is reported at the node being evaluated, if it comes from the source code
+        self.call_frames
+            .push(CallFrame::new(Some(name), self.position));
+        let error = error::new_error_object(self, name);
+        error::initialize_error(&error, Some(&to_value(message)), None, self);
+        let _ = self.call_frames.pop();
+        error
+    }
+
+    /// Constructs a `TypeError` with the specified message.
+    pub fn construct_type_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        self.construct_native_error("TypeError", message.into())
+    }
+
+    /// Throws a `TypeError` with the specified message.
+    pub fn throw_type_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_type_error(message))
+    }
+
+    /// Constructs a `RangeError` with the specified message.
+    pub fn construct_range_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        self.construct_native_error("RangeError", message.into())
+    }
+
+    /// Throws a `RangeError` with the specified message.
+    pub fn throw_range_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_range_error(message))
+    }
+
+    /// Constructs a `ReferenceError` with the specified message.
+    pub fn construct_reference_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        self.construct_native_error("ReferenceError", message.into())
+    }
+
+    /// Throws a `ReferenceError` with the specified message.
+    pub fn throw_reference_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_reference_error(message))
+    }
+
+    /// Constructs a `SyntaxError` with the specified message.
+    pub fn construct_syntax_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        self.construct_native_error("SyntaxError", message.into())
+    }
+
+    /// Throws a `SyntaxError` with the specified message.
+    pub fn throw_syntax_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_syntax_error(message))
+    }
+
+    /// Constructs a `URIError` with the specified message.
+    pub fn construct_uri_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        self.construct_native_error("URIError", message.into())
+    }
+
+    /// Throws a `URIError` with the specified message.
+    pub fn throw_uri_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_uri_error(message))
+    }
 }
@@ -343,7 +343,7 @@ fn test_for_loop() {
     let inner_scope = r#"
         for (let i = 0;false;) {}
 
-        i
+        typeof i
         "#;
     assert_eq!(exec(inner_scope), String::from("undefined"));
 }
@@ -494,11 +494,154 @@ mod in_operator {
     }
 
     #[test]
-    #[should_panic(expected = "TypeError: undefined is not an Object.")]
     fn should_type_error_when_rhs_not_object() {
         let scenario = r#"
-            'fail' in undefined
+            var x = false;
+            try {
+                'fail' in undefined
+            } catch(e) {
+                x = true;
+            }
+            x
         "#;
-        exec(scenario);
+        assert_eq!(exec(scenario), String::from("true"));
+    }
+}
+
+#[test]
+fn conditional_op() {
+    assert_eq!(exec("true ? 'yes' : 'no'"), String::from("yes"));
+    assert_eq!(exec("0 ? 'yes' : 'no'"), String::from("no"));
+}
+
+#[test]
+fn try_catch_finally() {
+    let catch_thrown = r#"
+        let a;
+        try {
+            throw 'boom';
+        } catch (e) {
+            a = e;
+        }
+        a;
+    "#;
+    assert_eq!(exec(catch_thrown), String::from("boom"));
+
+    let finally_runs = r#"
+        let a = 1;
+        try {
+            a = 2;
+        } finally {
+            a = a + 1;
+        }
+        a;
+    "#;
+    assert_eq!(exec(finally_runs), String::from("3"));
+
+    let param_is_scoped = r#"
+        try {
+            throw 'boom';
+        } catch (err) {}
+        typeof err;
+    "#;
+    assert_eq!(exec(param_is_scoped), String::from("undefined"));
+}
+
+/// Runs `src` and returns the name of the error it throws.
+fn thrown_error_name(engine: &mut exec::Interpreter, src: &str) -> String {
+    forward(
+        engine,
+        &format!("try {{ {}; 'no error'; }} catch (e) {{ e.name; }}", src),
+    )
+}
+
+#[test]
+fn malformed_calls_throw() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let scenarios = [
+        ("Object.defineProperty()", "TypeError"),
+        ("Object.defineProperty({}, 'a')", "TypeError"),
+        ("Object.defineProperty(1, 'a', {})", "TypeError"),
+        ("Object.getPrototypeOf()", "TypeError"),
+        ("Object.setPrototypeOf({}, 1)", "TypeError"),
+        ("undefined.x", "TypeError"),
+        ("null.foo", "TypeError"),
+        ("undefined.x()", "TypeError"),
+        ("null['foo']()", "TypeError"),
+        ("var notAFunction = 1; notAFunction()", "TypeError"),
+        (
+            "var notAConstructor = 1; new notAConstructor()",
+            "TypeError",
+        ),
+        ("'a' in 1", "TypeError"),
+        ("[...1]", "TypeError"),
+        ("[].forEach()", "TypeError"),
+        ("[].map()", "TypeError"),
+        ("RegExp.prototype.test('a')", "TypeError"),
+        ("new Array(1.5)", "RangeError"),
+        ("new Array(-1)", "RangeError"),
+        ("'abc'.repeat(-1)", "RangeError"),
+        ("Number(1).toFixed(101)", "RangeError"),
+        ("Number(1).toPrecision(0)", "RangeError"),
+        ("new RegExp('(')", "SyntaxError"),
+        ("new RegExp('a', 'gg')", "SyntaxError"),
+        ("JSON.parse('{')", "SyntaxError"),
+        ("undeclaredVariable++", "ReferenceError"),
+        ("undeclaredVariable += 1", "ReferenceError"),
+        ("let b; let b;", "SyntaxError"),
+        ("let e; const e = 1;", "SyntaxError"),
+        ("const c = 1; c = 2;", "TypeError"),
+        ("const d = 1; d += 1;", "TypeError"),
+        ("undeclaredVariable", "ReferenceError"),
+        ("undeclaredVariable.x", "ReferenceError"),
+        ("Symbol() + ''", "TypeError"),
+        ("Symbol() * 2", "TypeError"),
+        ("var s = Symbol(); s += 1", "TypeError"),
+        ("var o = { s: Symbol() }; o.s -= 1", "TypeError"),
+    ];
+
+    for (src, name) in scenarios.iter() {
+        assert_eq!(thrown_error_name(&mut engine, src), *name, "{}", src);
+    }
+}
+
+#[test]
+fn malformed_calls_do_not_throw() {
+    let realm = Realm::create();
+    let mut engine = Executor::new(realm);
+    let scenarios = [
+        ("Math.atan2(1)", "NaN"),
+        ("new String().toString()", ""),
+        ("'abc'.slice(1)", "bc"),
+        ("'abc'.replace('z', 'y')", "abc"),
+        ("'a(b'.replace('(', '-')", "a-b"),
+        ("'abc'.padEnd()", "abc"),
+        ("'abc'.includes()", "false"),
+        ("JSON.stringify()", "undefined"),
+        ("JSON.stringify(NaN)", "null"),
+        ("new RegExp().test('a')", "true"),
+        ("function f(a, b) { return b; } f(1)", "undefined"),
+        ("function g(a, b, ...c) { return c.length; } g(1)", "0"),
+        (
+            "var log = console.log; log('detached console method')",
+            "undefined",
+        ),
+        ("var a = 1; var a = 2; a", "2"),
+        ("var a; a", "2"),
+        ("function h() { var x = 1; var x; return x; } h()", "1"),
+        ("function k(p) { var p; return p; } k(3)", "3"),
+        (
+            "function r() { return 1; } function r() { return 2; } r()",
+            "2",
+        ),
+        ("NaN = 1; NaN", "NaN"),
+        ("var m = Math; Math = 1; var n = Math; Math = m; n", "1"),
+        ("typeof undeclaredVariable", "undefined"),
+        ("String(Symbol('s')) + ''", "Symbol(s)"),
+    ];
+
+    for (src, expected) in scenarios.iter() {
+        assert_eq!(forward(&mut engine, src), *expected, "{}", src);
     }
 }
@@ -34,10 +34,13 @@
 )]
 
 pub mod builtins;
+pub mod context;
 pub mod environment;
 pub mod exec;
 pub mod realm;
 pub mod syntax;
+pub use crate::context::{Context, EngineLimit, JsError};
+
 use crate::{
     builtins::value::ResultValue,
     exec::{Executor, Interpreter},
@@ -64,7 +67,10 @@ pub fn forward(engine: &mut Interpreter, src: &str) -> String {
             return error_string;
         }
     };
-    let result = engine.run(&expr);
+    engine.set_source_text(src);
+    let result = engine
+        .run_script(&expr)
+        .and_then(|value| engine.run_jobs().map(|_| value));
     match result {
         Ok(v) => v.to_string(),
         Err(v) => format!("{}: {}", "Error", v.to_string()),
@@ -74,15 +80,18 @@ pub fn forward(engine: &mut Interpreter, src: &str) -> String {
 /// Execute the code using an existing Interpreter.
 /// The str is consumed and the state of the Interpreter is changed
 /// Similar to `forward`, except the current value is returned instad of the string
-/// If the interpreter fails parsing an error value is returned instead (error object)
+/// If the interpreter fails parsing, a `SyntaxError` object is returned as the error
 pub fn forward_val(engine: &mut Interpreter, src: &str) -> ResultValue {
     // Setup executor
-    match parser_expr(src) {
-        Ok(expr) => engine.run(&expr),
-        Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
+    match context::parse(src) {
+        Ok(expr) => {
+            engine.set_source_text(src);
+            engine
+                .run_script(&expr)
+                .and_then(|value| engine.run_jobs().map(|_| value))
         }
+        Err(JsError::Syntax { message, .. }) => Err(engine.construct_syntax_error(message)),
+        Err(_) => unreachable!("parsing only fails with syntax errors"),
     }
 }
 
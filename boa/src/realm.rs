@@ -7,8 +7,9 @@
 use crate::{
     builtins::{
         self,
-        function::NativeFunctionData,
-        value::{ToValue, Value, ValueData},
+        class::{self, Class},
+        function::{Function, FunctionBody, NativeFunctionData},
+        value::{ResultValue, ToValue, Value, ValueData},
     },
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
@@ -16,9 +17,11 @@ use crate::{
         lexical_environment::LexicalEnvironment,
         object_environment_record::ObjectEnvironmentRecord,
     },
+    exec::Interpreter,
 };
-use gc::{Gc, GcCell};
+use gc::{Gc, GcCell, Trace};
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::any::TypeId;
 
 /// Representation of a Realm.
 ///
@@ -28,6 +31,8 @@ pub struct Realm {
     pub global_obj: Value,
     pub global_env: Gc<GcCell<Box<GlobalEnvironmentRecord>>>,
     pub environment: LexicalEnvironment,
+    /// The prototypes of the instances of the registered Rust classes, by the type of the class
+    class_prototypes: FxHashMap<TypeId, Value>,
 }
 
 impl Realm {
@@ -42,6 +47,7 @@ impl Realm {
             global_obj: global.clone(),
             global_env,
             environment: LexicalEnvironment::new(global),
+            class_prototypes: FxHashMap::default(),
         };
 
         // Add new builtIns to Realm
@@ -60,10 +66,37 @@ impl Realm {
 
     /// Utility to add a function to the global object
     pub fn register_global_func(self, func_name: &str, func: NativeFunctionData) -> Self {
-        let func = crate::builtins::function::Function::create_builtin(
-            vec![],
-            crate::builtins::function::FunctionBody::BuiltIn(func),
-        );
+        self.register_global_body(func_name, FunctionBody::BuiltIn(func))
+    }
+
+    /// Utility to add a closure to the global object, with the values it captures
+    ///
+    /// See `FunctionBody::closure` for what the closure can capture.
+    pub fn register_global_closure<F, T>(self, func_name: &str, func: F, captures: T) -> Self
+    where
+        F: Fn(&mut Value, &[Value], &T, &mut Interpreter) -> ResultValue + Copy + 'static,
+        T: Trace + 'static,
+    {
+        self.register_global_body(func_name, FunctionBody::closure(func, captures))
+    }
+
+    /// Utility to add a Rust type to the global object as a class, see `Class`
+    pub fn register_global_class<T: Class>(mut self) -> Self {
+        let (constructor, prototype) = class::create::<T>(&self.global_obj);
+        self.global_obj.set_field_slice(T::NAME, constructor);
+        self.class_prototypes.insert(TypeId::of::<T>(), prototype);
+
+        self
+    }
+
+    /// Gets the prototype of the instances of a registered class
+    pub fn class_prototype<T: Class>(&self) -> Option<Value> {
+        self.class_prototypes.get(&TypeId::of::<T>()).cloned()
+    }
+
+    /// Adds a built-in function with the given body to the global object
+    fn register_global_body(self, func_name: &str, body: FunctionBody) -> Self {
+        let func = Function::create_builtin(vec![], body);
         self.global_obj
             .set_field(func_name.to_value(), ValueData::from_func(func));
 
@@ -7,6 +7,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-primary-expression-literals
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Literals
 
+use crate::builtins::value::JsString;
 use gc::{Finalize, Trace};
 use std::fmt::{Display, Formatter, Result};
 
@@ -39,7 +40,7 @@ pub enum Const {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-string-value
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#String_literals
-    String(String),
+    String(JsString),
 
     /// A floating-point number literal.
     ///
@@ -109,12 +110,18 @@ impl From<&str> for Const {
 
 impl From<&String> for Const {
     fn from(s: &String) -> Self {
-        Self::String(s.clone())
+        Self::String(s.into())
     }
 }
 
 impl From<String> for Const {
     fn from(s: String) -> Self {
+        Self::String(s.into())
+    }
+}
+
+impl From<JsString> for Const {
+    fn from(s: JsString) -> Self {
         Self::String(s)
     }
 }
@@ -3,6 +3,7 @@
 use crate::syntax::ast::{
     constant::Const,
     op::{BinOp, Operator, UnaryOp},
+    pos::{Position, Span},
 };
 use gc::{Finalize, Trace};
 use std::fmt;
@@ -11,9 +12,38 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 /// A Javascript AST Node.
+///
+/// Every node knows the span of source code it was parsed from.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct Node {
+    /// The node kind, which contains the actual data of the node.
+    pub kind: NodeKind,
+
+    /// The node span in the original source code.
+    pub span: Span,
+}
+
+/// Nodes are compared by structure only, so a parsed tree equals the same tree built by hand.
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl From<NodeKind> for Node {
+    fn from(kind: NodeKind) -> Self {
+        Self {
+            kind,
+            span: Span::default(),
+        }
+    }
+}
+
+/// The different kinds of Javascript AST nodes.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
-pub enum Node {
+pub enum NodeKind {
     /// An array is an ordered collection of data (either primitive or object depending upon the language).
     ///
     /// Arrays are used to store multiple values in a single variable.
@@ -104,7 +134,9 @@ pub enum Node {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-CallExpression
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
-    Call(Box<Node>, Box<[Node]>),
+    ///
+    /// The position is the location of the call site, used when reporting stack traces.
+    Call(Box<Node>, Box<[Node]>, Position),
 
     /// The `conditional` (ternary) operator is the only JavaScript operator that takes three operands.
     ///
@@ -382,7 +414,7 @@ pub enum Node {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Spread_syntax
     Spread(Box<Node>),
 
-    /// Similar to `Node::Block` but without the braces
+    /// Similar to `NodeKind::Block` but without the braces
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -493,7 +525,7 @@ pub enum Node {
     WhileLoop(Box<Node>, Box<Node>),
 }
 
-impl Operator for Node {
+impl Operator for NodeKind {
     fn get_assoc(&self) -> bool {
         match *self {
             Self::UnaryOp(_, _) | Self::TypeOf(_) | Self::If(_, _, _) | Self::Assign(_, _) => false,
@@ -504,7 +536,7 @@ impl Operator for Node {
     fn get_precedence(&self) -> u64 {
         match self {
             Self::GetField(_, _) | Self::GetConstField(_, _) => 1,
-            Self::Call(_, _) => 2,
+            Self::Call(_, _, _) => 2,
             Self::UnaryOp(UnaryOp::IncrementPost, _)
             | Self::UnaryOp(UnaryOp::IncrementPre, _)
             | Self::UnaryOp(UnaryOp::DecrementPost, _)
@@ -522,19 +554,41 @@ impl Operator for Node {
     }
 }
 
+impl Operator for Node {
+    fn get_assoc(&self) -> bool {
+        self.kind.get_assoc()
+    }
+
+    fn get_precedence(&self) -> u64 {
+        self.kind.get_precedence()
+    }
+}
+
 impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.display(f, 0)
+    }
+}
+
+impl fmt::Display for NodeKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
     }
 }
 
 impl Node {
+    /// Sets the span of the node in the original source code.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
     /// Creates an `ArrayDecl` AST node.
     pub fn array_decl<N>(nodes: N) -> Self
     where
         N: Into<Box<[Self]>>,
     {
-        Self::ArrayDecl(nodes.into())
+        NodeKind::ArrayDecl(nodes.into()).into()
     }
 
     /// Creates an `ArraowFunctionDecl` AST node.
@@ -543,7 +597,7 @@ impl Node {
         P: Into<Box<[FormalParameter]>>,
         B: Into<Box<Self>>,
     {
-        Self::ArrowFunctionDecl(params.into(), body.into())
+        NodeKind::ArrowFunctionDecl(params.into(), body.into()).into()
     }
 
     /// Creates an `Assign` AST node.
@@ -552,7 +606,7 @@ impl Node {
         L: Into<Box<Self>>,
         R: Into<Box<Self>>,
     {
-        Self::Assign(lhs.into(), rhs.into())
+        NodeKind::Assign(lhs.into(), rhs.into()).into()
     }
 
     /// Creates a `BinOp` AST node.
@@ -562,7 +616,7 @@ impl Node {
         L: Into<Box<Self>>,
         R: Into<Box<Self>>,
     {
-        Self::BinOp(op.into(), lhs.into(), rhs.into())
+        NodeKind::BinOp(op.into(), lhs.into(), rhs.into()).into()
     }
 
     /// Creates a `Block` AST node.
@@ -570,7 +624,7 @@ impl Node {
     where
         N: Into<Box<[Self]>>,
     {
-        Self::Block(nodes.into())
+        NodeKind::Block(nodes.into()).into()
     }
 
     /// Creates a `Break` AST node.
@@ -579,16 +633,16 @@ impl Node {
         L: Into<String>,
         OL: Into<Option<L>>,
     {
-        Self::Break(label.into().map(L::into))
+        NodeKind::Break(label.into().map(L::into)).into()
     }
 
     /// Creates a `Call` AST node.
-    pub fn call<F, P>(function: F, params: P) -> Self
+    pub fn call<F, P>(function: F, params: P, position: Position) -> Self
     where
         F: Into<Box<Self>>,
         P: Into<Box<[Self]>>,
     {
-        Self::Call(function.into(), params.into())
+        NodeKind::Call(function.into(), params.into(), position).into()
     }
 
     /// Creates a `ConditionalOp` AST node.
@@ -598,7 +652,7 @@ impl Node {
         T: Into<Box<Self>>,
         F: Into<Box<Self>>,
     {
-        Self::ConditionalOp(condition.into(), if_true.into(), if_false.into())
+        NodeKind::ConditionalOp(condition.into(), if_true.into(), if_false.into()).into()
     }
 
     /// Creates a `Const` AST node.
@@ -606,7 +660,7 @@ impl Node {
     where
         C: Into<Const>,
     {
-        Self::Const(node.into())
+        NodeKind::Const(node.into()).into()
     }
 
     /// Creates a `ConstDecl` AST node.
@@ -614,7 +668,7 @@ impl Node {
     where
         D: Into<Box<[(String, Self)]>>,
     {
-        Self::ConstDecl(decl.into())
+        NodeKind::ConstDecl(decl.into()).into()
     }
 
     /// Creates a `Continue` AST node.
@@ -623,7 +677,7 @@ impl Node {
         L: Into<String>,
         OL: Into<Option<L>>,
     {
-        Self::Continue(label.into().map(L::into))
+        NodeKind::Continue(label.into().map(L::into)).into()
     }
 
     /// Creates a `DoWhileLoop` AST node.
@@ -632,7 +686,7 @@ impl Node {
         B: Into<Box<Self>>,
         C: Into<Box<Self>>,
     {
-        Self::DoWhileLoop(body.into(), condition.into())
+        NodeKind::DoWhileLoop(body.into(), condition.into()).into()
     }
 
     /// Creates a `FunctionDecl` AST node.
@@ -643,7 +697,7 @@ impl Node {
         P: Into<Box<[FormalParameter]>>,
         B: Into<Box<Self>>,
     {
-        Self::FunctionDecl(name.into().map(N::into), params.into(), body.into())
+        NodeKind::FunctionDecl(name.into().map(N::into), params.into(), body.into()).into()
     }
 
     /// Creates a `GetConstField` AST node.
@@ -652,7 +706,7 @@ impl Node {
         V: Into<Box<Self>>,
         L: Into<String>,
     {
-        Self::GetConstField(value.into(), label.into())
+        NodeKind::GetConstField(value.into(), label.into()).into()
     }
 
     /// Creates a `GetField` AST node.
@@ -661,7 +715,7 @@ impl Node {
         V: Into<Box<Self>>,
         F: Into<Box<Self>>,
     {
-        Self::GetField(value.into(), field.into())
+        NodeKind::GetField(value.into(), field.into()).into()
     }
 
     /// Creates a `ForLoop` AST node.
@@ -675,12 +729,13 @@ impl Node {
         S: Into<Box<Self>>,
         B: Into<Box<Self>>,
     {
-        Self::ForLoop(
+        NodeKind::ForLoop(
             init.into().map(I::into),
             condition.into().map(C::into),
             step.into().map(S::into),
             body.into(),
         )
+        .into()
     }
 
     /// Creates an `If` AST node.
@@ -691,7 +746,7 @@ impl Node {
         E: Into<Box<Self>>,
         OE: Into<Option<E>>,
     {
-        Self::If(condition.into(), body.into(), else_node.into().map(E::into))
+        NodeKind::If(condition.into(), body.into(), else_node.into().map(E::into)).into()
     }
 
     /// Creates a `LetDecl` AST node.
@@ -699,7 +754,7 @@ impl Node {
     where
         I: Into<Box<[(String, Option<Self>)]>>,
     {
-        Self::LetDecl(init.into())
+        NodeKind::LetDecl(init.into()).into()
     }
 
     /// Creates a `Local` AST node.
@@ -707,7 +762,7 @@ impl Node {
     where
         N: Into<String>,
     {
-        Self::Local(name.into())
+        NodeKind::Local(name.into()).into()
     }
 
     /// Creates a `New` AST node.
@@ -715,7 +770,7 @@ impl Node {
     where
         N: Into<Box<Self>>,
     {
-        Self::New(node.into())
+        NodeKind::New(node.into()).into()
     }
 
     /// Creates an `Object` AST node.
@@ -723,7 +778,7 @@ impl Node {
     where
         D: Into<Box<[PropertyDefinition]>>,
     {
-        Self::Object(def.into())
+        NodeKind::Object(def.into()).into()
     }
 
     /// Creates a `Return` AST node.
@@ -732,7 +787,7 @@ impl Node {
         E: Into<Box<Self>>,
         OE: Into<Option<E>>,
     {
-        Self::Return(expr.into().map(E::into))
+        NodeKind::Return(expr.into().map(E::into)).into()
     }
 
     /// Creates a `Switch` AST node.
@@ -743,7 +798,7 @@ impl Node {
         OD: Into<Option<D>>,
         D: Into<Box<Self>>,
     {
-        Self::Switch(val.into(), cases.into(), default.into().map(D::into))
+        NodeKind::Switch(val.into(), cases.into(), default.into().map(D::into)).into()
     }
 
     /// Creates a `Spread` AST node.
@@ -751,7 +806,7 @@ impl Node {
     where
         V: Into<Box<Self>>,
     {
-        Self::Spread(val.into())
+        NodeKind::Spread(val.into()).into()
     }
 
     /// Creates a `StatementList` AST node.
@@ -759,7 +814,7 @@ impl Node {
     where
         L: Into<Box<[Self]>>,
     {
-        Self::StatementList(list.into())
+        NodeKind::StatementList(list.into()).into()
     }
 
     /// Creates a `Throw` AST node.
@@ -767,7 +822,7 @@ impl Node {
     where
         V: Into<Box<Self>>,
     {
-        Self::Throw(val.into())
+        NodeKind::Throw(val.into()).into()
     }
 
     /// Creates a `TypeOf` AST node.
@@ -775,7 +830,7 @@ impl Node {
     where
         E: Into<Box<Self>>,
     {
-        Self::TypeOf(expr.into())
+        NodeKind::TypeOf(expr.into()).into()
     }
 
     /// Creates a `Try` AST node.
@@ -797,12 +852,12 @@ impl Node {
             "try/catch must have a catch or a finally block"
         );
 
-        Self::Try(try_node.into(), catch, param.into().map(P::into), finally)
+        NodeKind::Try(try_node.into(), catch, param.into().map(P::into), finally).into()
     }
 
     /// Creates a `This` AST node.
     pub fn this() -> Self {
-        Self::This
+        NodeKind::This.into()
     }
 
     /// Creates a `UnaryOp` AST node.
@@ -810,7 +865,7 @@ impl Node {
     where
         V: Into<Box<Self>>,
     {
-        Self::UnaryOp(op, val.into())
+        NodeKind::UnaryOp(op, val.into()).into()
     }
 
     /// Creates a `VarDecl` AST node.
@@ -818,7 +873,7 @@ impl Node {
     where
         I: Into<Box<[(String, Option<Self>)]>>,
     {
-        Self::VarDecl(init.into())
+        NodeKind::VarDecl(init.into()).into()
     }
 
     /// Creates a `WhileLoop` AST node.
@@ -827,9 +882,11 @@ impl Node {
         C: Into<Box<Self>>,
         B: Into<Box<Self>>,
     {
-        Self::WhileLoop(condition.into(), body.into())
+        NodeKind::WhileLoop(condition.into(), body.into()).into()
     }
+}
 
+impl NodeKind {
     /// Implements the display formatting with indentation.
     fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         let indent = "    ".repeat(indentation);
@@ -868,9 +925,9 @@ impl Node {
             Self::Block(ref block) => {
                 writeln!(f, "{{")?;
                 for node in block.iter() {
-                    node.display(f, indentation + 1)?;
+                    node.kind.display(f, indentation + 1)?;
 
-                    match node {
+                    match node.kind {
                         Self::Block(_)
                         | Self::If(_, _, _)
                         | Self::Switch(_, _, _)
@@ -885,9 +942,9 @@ impl Node {
             }
             Self::StatementList(ref list) => {
                 for node in list.iter() {
-                    node.display(f, indentation + 1)?;
+                    node.kind.display(f, indentation + 1)?;
 
-                    match node {
+                    match node.kind {
                         Self::Block(_)
                         | Self::If(_, _, _)
                         | Self::Switch(_, _, _)
@@ -903,15 +960,17 @@ impl Node {
             Self::Local(ref s) => write!(f, "{}", s),
             Self::GetConstField(ref ex, ref field) => write!(f, "{}.{}", ex, field),
             Self::GetField(ref ex, ref field) => write!(f, "{}[{}]", ex, field),
-            Self::Call(ref ex, ref args) => {
+            Self::Call(ref ex, ref args, _) => {
                 write!(f, "{}(", ex)?;
                 let arg_strs: Box<[String]> = args.iter().map(ToString::to_string).collect();
                 write!(f, "{})", arg_strs.join(", "))
             }
             Self::New(ref call) => {
-                let (func, args) = match call.as_ref() {
-                    Self::Call(func, args) => (func, args),
-                    _ => unreachable!("Node::New(ref call): 'call' must only be Node::Call type."),
+                let (func, args) = match call.kind {
+                    Self::Call(ref func, ref args, _) => (func, args),
+                    _ => unreachable!(
+                        "NodeKind::New(ref call): 'call' must only be NodeKind::Call type."
+                    ),
                 };
 
                 write!(f, "new {}", func)?;
@@ -928,22 +987,22 @@ impl Node {
             }
             Self::WhileLoop(ref cond, ref node) => {
                 write!(f, "while ({}) ", cond)?;
-                node.display(f, indentation)
+                node.kind.display(f, indentation)
             }
             Self::DoWhileLoop(ref node, ref cond) => {
                 write!(f, "do")?;
-                node.display(f, indentation)?;
+                node.kind.display(f, indentation)?;
                 write!(f, "while ({})", cond)
             }
             Self::If(ref cond, ref node, None) => {
                 write!(f, "if ({}) ", cond)?;
-                node.display(f, indentation)
+                node.kind.display(f, indentation)
             }
             Self::If(ref cond, ref node, Some(ref else_e)) => {
                 write!(f, "if ({}) ", cond)?;
-                node.display(f, indentation)?;
+                node.kind.display(f, indentation)?;
                 f.write_str(" else ")?;
-                else_e.display(f, indentation)
+                else_e.kind.display(f, indentation)
             }
             Self::Switch(ref val, ref vals, None) => {
                 writeln!(f, "switch ({}) {{", val)?;
@@ -960,7 +1019,7 @@ impl Node {
                     join_nodes(f, &e.1)?;
                 }
                 writeln!(f, "{}default:", indent)?;
-                def.display(f, indentation + 1)?;
+                def.kind.display(f, indentation + 1)?;
                 write!(f, "{}}}", indent)
             }
             Self::Object(ref properties) => {
@@ -997,13 +1056,13 @@ impl Node {
                 write!(f, "{{")?;
                 //join_nodes(f, args)?; TODO: port
                 f.write_str("} ")?;
-                node.display(f, indentation + 1)
+                node.kind.display(f, indentation + 1)
             }
             Self::ArrowFunctionDecl(ref _args, ref node) => {
                 write!(f, "(")?;
                 //join_nodes(f, args)?; TODO: port
                 f.write_str(") => ")?;
-                node.display(f, indentation)
+                node.kind.display(f, indentation)
             }
             Self::BinOp(ref op, ref a, ref b) => write!(f, "{} {} {}", a, op, b),
             Self::UnaryOp(ref op, ref a) => write!(f, "{}{}", op, a),
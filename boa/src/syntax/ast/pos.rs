@@ -1,4 +1,6 @@
-//! This module implements the `Pos` structure, which represents a position in the source code.
+//! This module implements the `Position` and `Span` structures, which represent locations in the source code.
+
+use gc::{unsafe_empty_trace, Finalize, Trace};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// ## Similar Implementations
 /// [V8: Location](https://cs.chromium.org/chromium/src/v8/src/parsing/scanner.h?type=cs&q=isValid+Location&g=0&l=216)
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub struct Position {
     // Column number
     pub column_number: u64,
@@ -34,3 +36,43 @@ impl Position {
         }
     }
 }
+
+impl Finalize for Position {}
+
+// Positions only hold plain numbers, so there's nothing to trace.
+unsafe impl Trace for Position {
+    unsafe_empty_trace!();
+}
+
+/// A range in the Javascript source code.
+///
+/// Stores the position of the first character and the position just past the last character.
+/// Nodes that don't come from the source code, like the ones built by hand in tests,
+/// have an empty span at line 0, column 0.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Span {
+    // Start position
+    pub start: Position,
+    // End position, exclusive
+    pub end: Position,
+}
+
+impl Span {
+    /// Creates a new `Span`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The position of the first character
+    /// * `end` - The position just past the last character
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Finalize for Span {}
+
+// Spans only hold positions, so there's nothing to trace.
+unsafe impl Trace for Span {
+    unsafe_empty_trace!();
+}
@@ -5,7 +5,14 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-tokens
 
-use crate::syntax::ast::{keyword::Keyword, pos::Position, punc::Punctuator};
+use crate::{
+    builtins::value::JsString,
+    syntax::ast::{
+        keyword::Keyword,
+        pos::{Position, Span},
+        punc::Punctuator,
+    },
+};
 use std::fmt::{Debug, Display, Formatter, Result};
 
 #[cfg(feature = "serde")]
@@ -25,16 +32,28 @@ pub struct Token {
 
     /// The token position from origina source code.
     pub pos: Position,
+
+    /// The position just past the end of the token in the original source code.
+    pub end: Position,
 }
 
 impl Token {
     /// Create a new detailed token from the token data, line number and column number
+    ///
+    /// The end of the token is set to its start, the lexer moves it once the whole token has been read.
     pub fn new(kind: TokenKind, line_number: u64, column_number: u64) -> Self {
+        let pos = Position::new(line_number, column_number);
         Self {
             kind,
-            pos: Position::new(line_number, column_number),
+            pos,
+            end: pos,
         }
     }
+
+    /// Gets the span of the token in the original source code.
+    pub fn span(&self) -> Span {
+        Span::new(self.pos, self.end)
+    }
 }
 
 impl Display for Token {
@@ -110,7 +129,7 @@ pub enum TokenKind {
     Punctuator(Punctuator),
 
     /// A string literal.
-    StringLiteral(String),
+    StringLiteral(JsString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(String, String),
@@ -177,7 +196,7 @@ impl TokenKind {
     /// Creates a `StringLiteral` token type.
     pub fn string_literal<S>(lit: S) -> Self
     where
-        S: Into<String>,
+        S: Into<JsString>,
     {
         Self::StringLiteral(lit.into())
     }
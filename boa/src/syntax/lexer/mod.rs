@@ -6,12 +6,16 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::ast::{
-    punc::Punctuator,
-    token::{NumericLiteral, Token, TokenKind},
+use crate::{
+    builtins::value::JsString,
+    syntax::ast::{
+        pos::Position,
+        punc::Punctuator,
+        token::{NumericLiteral, Token, TokenKind},
+    },
 };
 use std::{
-    char::{decode_utf16, from_u32},
+    char::from_u32,
     error, fmt,
     iter::Peekable,
     str::{Chars, FromStr},
@@ -142,6 +146,11 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Gets the current position in the script, where a lexing error occurred.
+    pub fn position(&self) -> Position {
+        Position::new(self.line_number, self.column_number)
+    }
+
     /// Push a token onto the token queue.
     fn push_token(&mut self, tk: TokenKind) {
         self.tokens
@@ -411,6 +420,12 @@ impl<'a> Lexer<'a> {
                         NumericLiteral::Integer(
                             num
                         )
+                    } else if base == 10 {
+                        // Accumulating the digits would round at each step
+                        NumericLiteral::Rational(
+                            f64::from_str(&buf)
+                                .map_err(|_| LexerError::new("Could not convert value to f64"))?,
+                        )
                     } else {
                         let b = f64::from(base);
                         let mut result = 0.0_f64;
@@ -451,9 +466,11 @@ impl<'a> Lexer<'a> {
             }
             self.column_number += 1;
             let ch = self.next();
+            let token_count = self.tokens.len();
             match ch {
                 '"' | '\'' => {
-                    let mut buf = String::new();
+                    // String values are sequences of UTF-16 code units, so escapes can produce lone surrogates
+                    let mut buf: Vec<u16> = Vec::new();
                     loop {
                         if self.preview_next().is_none() {
                             return Err(LexerError::new("Unterminated String"));
@@ -471,7 +488,7 @@ impl<'a> Lexer<'a> {
                                 }
                                 let escape = self.next();
                                 if escape != '\n' {
-                                    let escaped_ch = match escape {
+                                    let escaped_ch: char = match escape {
                                         'n' => '\n',
                                         'r' => '\r',
                                         't' => '\t',
@@ -516,7 +533,9 @@ impl<'a> Lexer<'a> {
                                                     Ok(v) => v,
                                                     Err(_) => 0,
                                                 };
-                                                let c = from_u32(as_num).ok_or_else(|| LexerError::new("Invalid Unicode escape sequence"))?;
+                                                if as_num > 0x10_FFFF {
+                                                    return Err(LexerError::new("Invalid Unicode escape sequence"));
+                                                }
 
                                                 if self.preview_next().is_none() {
                                                     return Err(LexerError::new("Unterminated String"));
@@ -524,7 +543,15 @@ impl<'a> Lexer<'a> {
                                                 self.next(); // '}'
                                                 self.column_number +=
                                                     (s.len() as u64).wrapping_add(3);
-                                                c
+
+                                                match from_u32(as_num) {
+                                                    Some(c) => c,
+                                                    None => {
+                                                        // A surrogate code point is kept as a single code unit
+                                                        buf.push(as_num as u16);
+                                                        continue;
+                                                    }
+                                                }
                                             } else {
                                                 let mut codepoints: Vec<u16> = vec![];
                                                 loop {
@@ -550,12 +577,9 @@ impl<'a> Lexer<'a> {
                                                     break;
                                                 }
 
-                                                // codepoints length should either be 1 (unicode codepoint) or 2 (surrogate codepoint).
-                                                // Rust's decode_utf16 will deal with it regardless
-                                                decode_utf16(codepoints.iter().cloned())
-                                                    .next()
-                                                    .expect("Could not get next codepoint")
-                                                    .expect("Could not get next codepoint")
+                                                // The code units are kept as they are, so a lone surrogate stays a lone surrogate
+                                                buf.extend(codepoints);
+                                                continue;
                                             }
                                         }
                                         '\'' | '"' | '\\' => escape,
@@ -564,14 +588,14 @@ impl<'a> Lexer<'a> {
                                             return Err(LexerError { details });
                                         }
                                     };
-                                    buf.push(escaped_ch);
+                                    buf.extend(escaped_ch.encode_utf16(&mut [0; 2]).iter());
                                 }
                             }
-                            next_ch => buf.push(next_ch),
+                            next_ch => buf.extend(next_ch.encode_utf16(&mut [0; 2]).iter()),
                         }
                     }
                     let str_length = buf.len() as u64;
-                    self.push_token(TokenKind::StringLiteral(buf));
+                    self.push_token(TokenKind::StringLiteral(JsString::from(buf)));
                     // Why +1? Quotation marks are not included,
                     // So technically it would be +2, (for both " ") but we want to be 1 less
                     // to compensate for the incrementing at the top
@@ -795,6 +819,16 @@ impl<'a> Lexer<'a> {
                     return Err(LexerError { details });
                 },
             }
+
+            // The column now points at the last character of the token we just read
+            if self.tokens.len() > token_count {
+                let end = Position::new(self.line_number, self.column_number + 1);
+                if let Some(token) = self.tokens.last_mut() {
+                    if token.kind != TokenKind::LineTerminator {
+                        token.end = end;
+                    }
+                }
+            }
         }
     }
 }
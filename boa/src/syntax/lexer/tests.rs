@@ -2,7 +2,7 @@
 #![allow(clippy::indexing_slicing)]
 
 use super::*;
-use crate::syntax::ast::keyword::Keyword;
+use crate::syntax::ast::{keyword::Keyword, pos::Span};
 
 #[test]
 fn check_single_line_comment() {
@@ -321,6 +321,28 @@ fn check_positions() {
     assert_eq!(lexer.tokens[6].pos.line_number, 1);
 }
 
+#[test]
+fn check_token_ends() {
+    let s = "console.log(\"hello world\");\nx";
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    // Ends are exclusive, so `console` (columns 1 to 7) ends at column 8
+    assert_eq!(lexer.tokens[0].end, Position::new(1, 8));
+    assert_eq!(lexer.tokens[1].end, Position::new(1, 9));
+    assert_eq!(lexer.tokens[2].end, Position::new(1, 12));
+    assert_eq!(lexer.tokens[3].end, Position::new(1, 13));
+    // The string token includes its quotes
+    assert_eq!(lexer.tokens[4].end, Position::new(1, 26));
+    assert_eq!(lexer.tokens[5].end, Position::new(1, 27));
+    assert_eq!(lexer.tokens[6].end, Position::new(1, 28));
+    // Line terminators are empty
+    assert_eq!(lexer.tokens[7].end, lexer.tokens[7].pos);
+    assert_eq!(
+        lexer.tokens[8].span(),
+        Span::new(Position::new(2, 1), Position::new(2, 2))
+    );
+}
+
 #[test]
 #[ignore]
 fn two_divisions_in_expression() {
@@ -516,3 +538,16 @@ fn addition_no_spaces_e_number() {
         TokenKind::numeric_literal(100_000_000_000.0)
     );
 }
+
+#[test]
+fn check_string_lone_surrogate() {
+    let s = r#"'\uD83D' '😀' '\u{1F600}'"#;
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    assert_eq!(
+        lexer.tokens[0].kind,
+        TokenKind::string_literal(JsString::from(&[0xD83D][..]))
+    );
+    assert_eq!(lexer.tokens[1].kind, TokenKind::string_literal("😀"));
+    assert_eq!(lexer.tokens[2].kind, TokenKind::string_literal("😀"));
+}
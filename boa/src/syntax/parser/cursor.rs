@@ -2,6 +2,7 @@
 
 use super::ParseError;
 use crate::syntax::ast::{
+    pos::{Position, Span},
     punc::Punctuator,
     token::{Token, TokenKind},
 };
@@ -38,6 +39,29 @@ impl<'a> Cursor<'a> {
         self.pos = pos
     }
 
+    /// Gets the position where the next token starts.
+    ///
+    /// At the end of the input, this is the end of the last token.
+    pub(super) fn next_pos(&self) -> Position {
+        self.peek(0)
+            .map(|tk| tk.pos)
+            .or_else(|| self.peek_prev().map(|tk| tk.end))
+            .unwrap_or_default()
+    }
+
+    /// Gets the span from `start` to the end of the last token the cursor moved past.
+    ///
+    /// This is used by the parsers to get the span of the node they just parsed.
+    pub(super) fn span_from(&self, start: Position) -> Span {
+        let end = self.peek_prev().map_or(start, |tk| tk.end);
+        // Nothing was parsed since `start`, for example in an empty function body
+        if (end.line_number, end.column_number) < (start.line_number, start.column_number) {
+            Span::new(start, start)
+        } else {
+            Span::new(start, end)
+        }
+    }
+
     /// Moves the cursor to the next token and returns the token.
     pub(super) fn next(&mut self) -> Option<&'a Token> {
         loop {
@@ -98,22 +122,10 @@ impl<'a> Cursor<'a> {
 
     /// Peeks the previous token without moving the cursor.
     pub(super) fn peek_prev(&self) -> Option<&'a Token> {
-        if self.pos == 0 {
-            None
-        } else {
-            let mut back = 1;
-            let mut tok = self.tokens.get(self.pos - back).expect("token disappeared");
-            while self.pos >= back && tok.kind == TokenKind::LineTerminator {
-                back += 1;
-                tok = self.tokens.get(self.pos - back).expect("token disappeared");
-            }
-
-            if back == self.pos {
-                None
-            } else {
-                Some(tok)
-            }
-        }
+        self.tokens[..self.pos]
+            .iter()
+            .rev()
+            .find(|tk| tk.kind != TokenKind::LineTerminator)
     }
 
     /// Returns an error if the next token is not of kind `kind`.
@@ -29,6 +29,18 @@ pub enum ParseError {
     General(&'static str, Option<Position>),
 }
 
+impl ParseError {
+    /// Gets the position in the source code where the error occurred, if it's known.
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected(_, token, _) | Self::Unexpected(token, _) => Some(token.pos),
+            Self::ExpectedExpr(_, _, pos) | Self::UnexpectedKeyword(_, pos) => Some(*pos),
+            Self::General(_, pos) => *pos,
+            Self::AbruptEnd | Self::RangeError => None,
+        }
+    }
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -89,7 +89,7 @@ impl TokenParser for ArrowFunction {
 
         let body = ConciseBody::new(self.allow_in).parse(cursor)?;
 
-        Ok(Node::arrow_function_decl(params, body))
+        Ok(Node::arrow_function_decl(params, body).with_span(cursor.span_from(next_token.pos)))
     }
 }
 
@@ -118,15 +118,20 @@ impl TokenParser for ConciseBody {
         match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
+                let body_start = cursor.next_pos();
                 let body = FunctionBody::new(false, false)
                     .parse(cursor)
-                    .map(Node::statement_list)?;
+                    .map(Node::statement_list)?
+                    .with_span(cursor.span_from(body_start));
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(Node::return_node(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-            )),
+            _ => {
+                // The implicit `return` covers the whole expression
+                let expr = ExpressionBody::new(self.allow_in, false).parse(cursor)?;
+                let span = expr.span;
+                Ok(Node::return_node(expr).with_span(span))
+            }
         }
     }
 }
@@ -68,7 +68,9 @@ impl TokenParser for ConditionalExpression {
                 let else_clause =
                     AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
-                return Ok(Node::conditional_op(lhs, then_clause, else_clause));
+                let start = lhs.span.start;
+                return Ok(Node::conditional_op(lhs, then_clause, else_clause)
+                    .with_span(cursor.span_from(start)));
             } else {
                 cursor.back();
             }
@@ -80,11 +80,10 @@ impl TokenParser for ExponentiationExpression {
         let lhs = UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.next() {
             if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind {
-                return Ok(Node::bin_op(
-                    BinOp::Num(NumOp::Exp),
-                    lhs,
-                    self.parse(cursor)?,
-                ));
+                let start = lhs.span.start;
+                let rhs = self.parse(cursor)?;
+                return Ok(Node::bin_op(BinOp::Num(NumOp::Exp), lhs, rhs)
+                    .with_span(cursor.span_from(start)));
             } else {
                 cursor.back();
             }
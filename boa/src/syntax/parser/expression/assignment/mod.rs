@@ -99,6 +99,7 @@ impl TokenParser for AssignmentExpression {
             _ => {}
         }
 
+        let start = next_token.pos;
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
         // let mut lhs = self.read_block()?;
@@ -106,12 +107,12 @@ impl TokenParser for AssignmentExpression {
         if let Some(tok) = cursor.next() {
             match tok.kind {
                 TokenKind::Punctuator(Punctuator::Assign) => {
-                    lhs = Node::assign(lhs, self.parse(cursor)?)
+                    lhs = Node::assign(lhs, self.parse(cursor)?).with_span(cursor.span_from(start))
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() => {
                     let expr = self.parse(cursor)?;
                     let binop = p.as_binop().expect("binop disappeared");
-                    lhs = Node::bin_op(binop, lhs, expr);
+                    lhs = Node::bin_op(binop, lhs, expr).with_span(cursor.span_from(start));
                 }
                 _ => {
                     cursor.back();
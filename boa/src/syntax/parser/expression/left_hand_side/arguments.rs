@@ -77,11 +77,10 @@ impl TokenParser for Arguments {
                 }
             }
 
-            if cursor.next_if(Punctuator::Spread).is_some() {
-                args.push(Node::spread(
-                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                ));
+            if let Some(spread) = cursor.next_if(Punctuator::Spread) {
+                let arg = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                args.push(Node::spread(arg).with_span(cursor.span_from(spread.pos)));
             } else {
                 args.push(
                     AssignmentExpression::new(true, self.allow_yield, self.allow_await)
@@ -9,7 +9,12 @@
 
 use super::arguments::Arguments;
 use crate::syntax::{
-    ast::{node::Node, punc::Punctuator, token::TokenKind},
+    ast::{
+        node::Node,
+        pos::Position,
+        punc::Punctuator,
+        token::{Token, TokenKind},
+    },
     parser::{
         expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
         TokenParser,
@@ -48,10 +53,13 @@ impl TokenParser for CallExpression {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = self.first_member_expr.span.start;
         let mut lhs = match cursor.peek(0) {
             Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::OpenParen) => {
+                let position = call_site(cursor, tk);
                 let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-                Node::call(self.first_member_expr, args)
+                Node::call(self.first_member_expr, args, position)
+                    .with_span(cursor.span_from(start))
             }
             _ => {
                 let next_token = cursor.next().ok_or(ParseError::AbruptEnd)?;
@@ -66,17 +74,20 @@ impl TokenParser for CallExpression {
         while let Some(tok) = cursor.peek(0) {
             match tok.kind {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let position = call_site(cursor, tok);
                     let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-                    lhs = Node::call(lhs, args);
+                    lhs = Node::call(lhs, args, position).with_span(cursor.span_from(start));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     let _ = cursor.next().ok_or(ParseError::AbruptEnd)?; // We move the cursor.
                     match &cursor.next().ok_or(ParseError::AbruptEnd)?.kind {
                         TokenKind::Identifier(name) => {
-                            lhs = Node::get_const_field(lhs, name);
+                            lhs =
+                                Node::get_const_field(lhs, name).with_span(cursor.span_from(start));
                         }
                         TokenKind::Keyword(kw) => {
-                            lhs = Node::get_const_field(lhs, kw.to_string());
+                            lhs = Node::get_const_field(lhs, kw.to_string())
+                                .with_span(cursor.span_from(start));
                         }
                         _ => {
                             return Err(ParseError::Expected(
@@ -92,7 +103,7 @@ impl TokenParser for CallExpression {
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
-                    lhs = Node::get_field(lhs, idx);
+                    lhs = Node::get_field(lhs, idx).with_span(cursor.span_from(start));
                 }
                 _ => break,
             }
@@ -100,3 +111,10 @@ impl TokenParser for CallExpression {
         Ok(lhs)
     }
 }
+
+/// Gets the position of the call site of a call whose arguments start at `open_paren`.
+///
+/// Like V8, this is the position of the last token of the callee, so `a.b()` is reported at `b`.
+fn call_site(cursor: &Cursor<'_>, open_paren: &Token) -> Position {
+    cursor.peek_prev().unwrap_or(open_paren).pos
+}
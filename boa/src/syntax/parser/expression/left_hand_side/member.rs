@@ -44,15 +44,14 @@ impl TokenParser for MemberExpression {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
-        let mut lhs = if cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind
-            == TokenKind::Keyword(Keyword::New)
-        {
+        let first = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let mut lhs = if first.kind == TokenKind::Keyword(Keyword::New) {
             let _ = cursor.next().expect("keyword disappeared");
             let lhs = self.parse(cursor)?;
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            let call_node = Node::call(lhs, args);
+            let call_node = Node::call(lhs, args, first.pos).with_span(cursor.span_from(first.pos));
 
-            Node::new(call_node)
+            Node::new(call_node).with_span(cursor.span_from(first.pos))
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
@@ -61,8 +60,14 @@ impl TokenParser for MemberExpression {
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     let _ = cursor.next().ok_or(ParseError::AbruptEnd)?; // We move the cursor forward.
                     match &cursor.next().ok_or(ParseError::AbruptEnd)?.kind {
-                        TokenKind::Identifier(name) => lhs = Node::get_const_field(lhs, name),
-                        TokenKind::Keyword(kw) => lhs = Node::get_const_field(lhs, kw.to_string()),
+                        TokenKind::Identifier(name) => {
+                            lhs = Node::get_const_field(lhs, name)
+                                .with_span(cursor.span_from(first.pos))
+                        }
+                        TokenKind::Keyword(kw) => {
+                            lhs = Node::get_const_field(lhs, kw.to_string())
+                                .with_span(cursor.span_from(first.pos))
+                        }
                         _ => {
                             return Err(ParseError::Expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -77,7 +82,7 @@ impl TokenParser for MemberExpression {
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
-                    lhs = Node::get_field(lhs, idx);
+                    lhs = Node::get_field(lhs, idx).with_span(cursor.span_from(first.pos));
                 }
                 _ => break,
             }
@@ -48,6 +48,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
         type Output = Node;
 
         fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+            let start = cursor.next_pos();
             let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
             while let Some(tok) = cursor.peek(0) {
                 match tok.kind {
@@ -57,7 +58,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
-                        )
+                        ).with_span(cursor.span_from(start))
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
@@ -65,7 +66,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
-                        )
+                        ).with_span(cursor.span_from(start))
                     }
                     _ => break
                 }
@@ -11,7 +11,7 @@
 mod tests;
 
 use crate::syntax::{
-    ast::{constant::Const, node::Node, punc::Punctuator},
+    ast::{constant::Const, node::Node, pos::Span, punc::Punctuator},
     parser::{
         expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
         TokenParser,
@@ -54,8 +54,10 @@ impl TokenParser for ArrayLiteral {
 
         loop {
             // TODO: Support all features.
-            while cursor.next_if(Punctuator::Comma).is_some() {
-                elements.push(Node::Const(Const::Undefined));
+            while let Some(comma) = cursor.next_if(Punctuator::Comma) {
+                // Holes are empty, they are located just before their comma
+                let hole = Span::new(comma.pos, comma.pos);
+                elements.push(Node::const_node(Const::Undefined).with_span(hole));
             }
 
             if cursor.next_if(Punctuator::CloseBracket).is_some() {
@@ -64,10 +66,10 @@ impl TokenParser for ArrayLiteral {
 
             let _ = cursor.peek(0).ok_or(ParseError::AbruptEnd)?; // Check that there are more tokens to read.
 
-            if cursor.next_if(Punctuator::Spread).is_some() {
+            if let Some(spread) = cursor.next_if(Punctuator::Spread) {
                 let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
-                elements.push(Node::spread(node));
+                elements.push(Node::spread(node).with_span(cursor.span_from(spread.pos)));
             } else {
                 elements.push(
                     AssignmentExpression::new(true, self.allow_yield, self.allow_await)
@@ -16,7 +16,7 @@ fn check_empty() {
 fn check_empty_slot() {
     check_parser(
         "[,]",
-        vec![Node::array_decl(vec![Node::Const(Const::Undefined)])],
+        vec![Node::array_decl(vec![Node::const_node(Const::Undefined)])],
     );
 }
 
@@ -54,7 +54,7 @@ fn check_numeric_array_elision() {
         vec![Node::array_decl(vec![
             Node::const_node(1),
             Node::const_node(2),
-            Node::Const(Const::Undefined),
+            Node::const_node(Const::Undefined),
             Node::const_node(3),
         ])],
     );
@@ -68,8 +68,8 @@ fn check_numeric_array_repeated_elision() {
         vec![Node::array_decl(vec![
             Node::const_node(1),
             Node::const_node(2),
-            Node::Const(Const::Undefined),
-            Node::Const(Const::Undefined),
+            Node::const_node(Const::Undefined),
+            Node::const_node(Const::Undefined),
             Node::const_node(3),
         ])],
     );
@@ -49,9 +49,11 @@ impl TokenParser for FunctionExpression {
         cursor.expect(Punctuator::CloseParen, "function expression")?;
         cursor.expect(Punctuator::OpenBlock, "function expression")?;
 
+        let body_start = cursor.next_pos();
         let body = FunctionBody::new(false, false)
             .parse(cursor)
-            .map(Node::statement_list)?;
+            .map(Node::statement_list)?
+            .with_span(cursor.span_from(body_start));
 
         cursor.expect(Punctuator::CloseBlock, "function expression")?;
 
@@ -61,38 +61,53 @@ impl TokenParser for PrimaryExpression {
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
 
-        match &tok.kind {
-            TokenKind::Keyword(Keyword::This) => Ok(Node::This),
+        let node = match &tok.kind {
+            TokenKind::Keyword(Keyword::This) => Node::this(),
             // TokenKind::Keyword(Keyword::Arguments) => Ok(Node::new(NodeBase::Arguments, tok.pos)),
-            TokenKind::Keyword(Keyword::Function) => FunctionExpression.parse(cursor),
+            TokenKind::Keyword(Keyword::Function) => FunctionExpression.parse(cursor)?,
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 let expr =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "primary expression")?;
-                Ok(expr)
+                return Ok(expr);
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
-                ArrayLiteral::new(self.allow_yield, self.allow_await).parse(cursor)
+                ArrayLiteral::new(self.allow_yield, self.allow_await).parse(cursor)?
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
-                ObjectLiteral::new(self.allow_yield, self.allow_await).parse(cursor)
+                ObjectLiteral::new(self.allow_yield, self.allow_await).parse(cursor)?
             }
-            TokenKind::BooleanLiteral(boolean) => Ok(Node::const_node(*boolean)),
+            TokenKind::BooleanLiteral(boolean) => Node::const_node(*boolean),
             // TODO: ADD TokenKind::UndefinedLiteral
-            TokenKind::Identifier(ref i) if i == "undefined" => Ok(Node::Const(Const::Undefined)),
-            TokenKind::NullLiteral => Ok(Node::Const(Const::Null)),
-            TokenKind::Identifier(ident) => Ok(Node::local(ident)),
-            TokenKind::StringLiteral(s) => Ok(Node::const_node(s)),
-            TokenKind::NumericLiteral(NumericLiteral::Integer(num)) => Ok(Node::const_node(*num)),
-            TokenKind::NumericLiteral(NumericLiteral::Rational(num)) => Ok(Node::const_node(*num)),
-            TokenKind::RegularExpressionLiteral(body, flags) => Ok(Node::new(Node::call(
-                Node::local("RegExp"),
-                vec![Node::const_node(body), Node::const_node(flags)],
-            ))),
-            _ => Err(ParseError::Unexpected(
-                tok.clone(),
-                Some("primary expression"),
-            )),
-        }
+            TokenKind::Identifier(ref i) if i == "undefined" => Node::const_node(Const::Undefined),
+            TokenKind::NullLiteral => Node::const_node(Const::Null),
+            TokenKind::Identifier(ident) => Node::local(ident),
+            TokenKind::StringLiteral(s) => Node::const_node(s.clone()),
+            TokenKind::NumericLiteral(NumericLiteral::Integer(num)) => Node::const_node(*num),
+            TokenKind::NumericLiteral(NumericLiteral::Rational(num)) => Node::const_node(*num),
+            TokenKind::RegularExpressionLiteral(body, flags) => {
+                // The literal is parsed as `new RegExp(body, flags)`, every node comes from the literal token
+                let span = tok.span();
+                Node::new(
+                    Node::call(
+                        Node::local("RegExp").with_span(span),
+                        vec![
+                            Node::const_node(body).with_span(span),
+                            Node::const_node(flags).with_span(span),
+                        ],
+                        tok.pos,
+                    )
+                    .with_span(span),
+                )
+            }
+            _ => {
+                return Err(ParseError::Unexpected(
+                    tok.clone(),
+                    Some("primary expression"),
+                ))
+            }
+        };
+
+        Ok(node.with_span(cursor.span_from(tok.pos)))
     }
 }
@@ -13,6 +13,7 @@ mod tests;
 use crate::syntax::{
     ast::{
         node::{self, MethodDefinitionKind, Node},
+        pos::Position,
         punc::Punctuator,
         token::{Token, TokenKind},
     },
@@ -116,6 +117,7 @@ impl TokenParser for PropertyDefinition {
     type Output = node::PropertyDefinition;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let start = cursor.next_pos();
         if cursor.next_if(Punctuator::Spread).is_some() {
             let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -137,7 +139,7 @@ impl TokenParser for PropertyDefinition {
             .is_some()
             || ["get", "set"].contains(&prop_name.as_str())
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
+            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name, start)
                 .parse(cursor);
         }
 
@@ -163,11 +165,14 @@ struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     identifier: String,
+    start: Position,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    ///
+    /// The `start` position is where the method definition begins, before its name.
+    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I, start: Position) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -177,6 +182,7 @@ impl MethodDefinition {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             identifier: identifier.into(),
+            start,
         }
     }
 }
@@ -231,9 +237,11 @@ impl TokenParser for MethodDefinition {
             TokenKind::Punctuator(Punctuator::OpenBlock),
             "property method definition",
         )?;
+        let body_start = cursor.next_pos();
         let body = FunctionBody::new(false, false)
             .parse(cursor)
-            .map(Node::statement_list)?;
+            .map(Node::statement_list)?
+            .with_span(cursor.span_from(body_start));
         cursor.expect(
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "property method definition",
@@ -242,7 +250,8 @@ impl TokenParser for MethodDefinition {
         Ok(node::PropertyDefinition::MethodDefinition(
             methodkind,
             prop_name,
-            Node::function_decl::<_, String, _, _>(None, params, body),
+            Node::function_decl::<_, String, _, _>(None, params, body)
+                .with_span(cursor.span_from(self.start)),
         ))
     }
 }
@@ -57,10 +57,10 @@ fn check_object_short_function_arguments() {
         PropertyDefinition::method_definition(
             MethodDefinitionKind::Ordinary,
             "b",
-            Node::FunctionDecl(
+            Node::function_decl::<_, String, _, _>(
                 None,
-                Box::new([FormalParameter::new("test", None, false)]),
-                Box::new(Node::StatementList(Box::new([]))),
+                vec![FormalParameter::new("test", None, false)],
+                Node::statement_list(Vec::new()),
             ),
         ),
     ];
@@ -85,10 +85,10 @@ fn check_object_getter() {
         PropertyDefinition::method_definition(
             MethodDefinitionKind::Get,
             "b",
-            Node::FunctionDecl(
+            Node::function_decl::<_, String, _, _>(
                 None,
-                Box::new([]),
-                Box::new(Node::statement_list(Vec::new())),
+                Vec::new(),
+                Node::statement_list(Vec::new()),
             ),
         ),
     ];
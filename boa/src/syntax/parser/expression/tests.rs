@@ -298,40 +298,40 @@ fn check_relational_operations() {
         "a < b",
         vec![Node::bin_op(
             BinOp::Comp(CompOp::LessThan),
-            Node::Local(String::from("a")),
-            Node::Local(String::from("b")),
+            Node::local("a"),
+            Node::local("b"),
         )],
     );
     check_parser(
         "a > b",
         vec![Node::bin_op(
             BinOp::Comp(CompOp::GreaterThan),
-            Node::Local(String::from("a")),
-            Node::Local(String::from("b")),
+            Node::local("a"),
+            Node::local("b"),
         )],
     );
     check_parser(
         "a <= b",
         vec![Node::bin_op(
             BinOp::Comp(CompOp::LessThanOrEqual),
-            Node::Local(String::from("a")),
-            Node::Local(String::from("b")),
+            Node::local("a"),
+            Node::local("b"),
         )],
     );
     check_parser(
         "a >= b",
         vec![Node::bin_op(
             BinOp::Comp(CompOp::GreaterThanOrEqual),
-            Node::Local(String::from("a")),
-            Node::Local(String::from("b")),
+            Node::local("a"),
+            Node::local("b"),
         )],
     );
     check_parser(
         "p in o",
         vec![Node::bin_op(
             BinOp::Comp(CompOp::In),
-            Node::Local(String::from("p")),
-            Node::Local(String::from("o")),
+            Node::local("p"),
+            Node::local("o"),
         )],
     );
 }
@@ -48,32 +48,26 @@ impl TokenParser for UnaryExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
-        match tok.kind {
-            TokenKind::Keyword(Keyword::Delete) => {
-                Ok(Node::unary_op(UnaryOp::Delete, self.parse(cursor)?))
-            }
-            TokenKind::Keyword(Keyword::Void) => {
-                Ok(Node::unary_op(UnaryOp::Void, self.parse(cursor)?))
-            }
-            TokenKind::Keyword(Keyword::TypeOf) => {
-                Ok(Node::unary_op(UnaryOp::TypeOf, self.parse(cursor)?))
-            }
-            TokenKind::Punctuator(Punctuator::Add) => {
-                Ok(Node::unary_op(UnaryOp::Plus, self.parse(cursor)?))
-            }
-            TokenKind::Punctuator(Punctuator::Sub) => {
-                Ok(Node::unary_op(UnaryOp::Minus, self.parse(cursor)?))
-            }
-            TokenKind::Punctuator(Punctuator::Neg) => {
-                Ok(Node::unary_op(UnaryOp::Tilde, self.parse(cursor)?))
-            }
-            TokenKind::Punctuator(Punctuator::Not) => {
-                Ok(Node::unary_op(UnaryOp::Not, self.parse(cursor)?))
-            }
+        let op = match tok.kind {
+            TokenKind::Keyword(Keyword::Delete) => UnaryOp::Delete,
+            TokenKind::Keyword(Keyword::Void) => UnaryOp::Void,
+            TokenKind::Keyword(Keyword::TypeOf) => UnaryOp::TypeOf,
+            TokenKind::Punctuator(Punctuator::Add) => UnaryOp::Plus,
+            TokenKind::Punctuator(Punctuator::Sub) => UnaryOp::Minus,
+            TokenKind::Punctuator(Punctuator::Neg) => UnaryOp::Tilde,
+            TokenKind::Punctuator(Punctuator::Not) => UnaryOp::Not,
             _ => {
                 cursor.back();
-                UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)
+                return UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor);
             }
-        }
+        };
+
+        let target = self.parse(cursor)?;
+        let node = match op {
+            // `typeof` has its own node, as it doesn't evaluate its operand like other operators.
+            UnaryOp::TypeOf => Node::type_of(target),
+            op => Node::unary_op(op, target),
+        };
+        Ok(node.with_span(cursor.span_from(tok.pos)))
     }
 }
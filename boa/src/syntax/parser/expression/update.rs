@@ -42,38 +42,28 @@ impl TokenParser for UpdateExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
-        match tok.kind {
-            TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next().expect("token disappeared");
-                return Ok(Node::unary_op(
-                    UnaryOp::IncrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                ));
-            }
-            TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next().expect("token disappeared");
-                return Ok(Node::unary_op(
-                    UnaryOp::DecrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                ));
-            }
-            _ => {}
+        let prefix_op = match tok.kind {
+            TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPre),
+            TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPre),
+            _ => None,
+        };
+        if let Some(op) = prefix_op {
+            cursor.next().expect("token disappeared");
+            let target =
+                LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            return Ok(Node::unary_op(op, target).with_span(cursor.span_from(tok.pos)));
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        if let Some(tok) = cursor.peek(0) {
-            match tok.kind {
-                TokenKind::Punctuator(Punctuator::Inc) => {
-                    cursor.next().expect("token disappeared");
-                    return Ok(Node::unary_op(UnaryOp::IncrementPost, lhs));
-                }
-                TokenKind::Punctuator(Punctuator::Dec) => {
-                    cursor.next().expect("token disappeared");
-                    return Ok(Node::unary_op(UnaryOp::DecrementPost, lhs));
-                }
-                _ => {}
+        if let Some(next) = cursor.peek(0) {
+            let postfix_op = match next.kind {
+                TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPost),
+                TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPost),
+                _ => None,
+            };
+            if let Some(op) = postfix_op {
+                cursor.next().expect("token disappeared");
+                return Ok(Node::unary_op(op, lhs).with_span(cursor.span_from(tok.pos)));
             }
         }
 
@@ -38,7 +38,7 @@ fn check_empty_return() {
         vec![Node::function_decl(
             "foo",
             vec![FormalParameter::new("a", None, false)],
-            Node::statement_list(vec![Node::Return(None)]),
+            Node::statement_list(vec![Node::return_node::<Node, _>(None)]),
         )],
     );
 }
@@ -51,7 +51,7 @@ fn check_empty_return_semicolon_insertion() {
         vec![Node::function_decl(
             "foo",
             vec![FormalParameter::new("a", None, false)],
-            Node::statement_list(vec![Node::Return(None)]),
+            Node::statement_list(vec![Node::return_node::<Node, _>(None)]),
         )],
     );
 }
@@ -67,7 +67,7 @@ fn check_rest_operator() {
                 FormalParameter::new("a", None, false),
                 FormalParameter::new("b", None, true),
             ],
-            Node::StatementList(Box::new([])),
+            Node::statement_list(Vec::new()),
         )],
     );
 }
@@ -79,7 +79,7 @@ fn check_arrow_only_rest() {
         "(...a) => {}",
         vec![Node::arrow_function_decl(
             vec![FormalParameter::new("a", None, true)],
-            Node::StatementList(Box::new([])),
+            Node::statement_list(Vec::new()),
         )],
     );
 }
@@ -95,7 +95,7 @@ fn check_arrow_rest() {
                 FormalParameter::new("b", None, false),
                 FormalParameter::new("c", None, true),
             ],
-            Node::StatementList(Box::new([])),
+            Node::statement_list(Vec::new()),
         )],
     );
 }
@@ -148,7 +148,7 @@ fn check_arrow_epty_return() {
                 FormalParameter::new("a", None, false),
                 FormalParameter::new("b", None, false),
             ],
-            Node::statement_list(vec![Node::Return(None)]),
+            Node::statement_list(vec![Node::return_node::<Node, _>(None)]),
         )],
     );
 }
@@ -163,7 +163,7 @@ fn check_arrow_empty_return_semicolon_insertion() {
                 FormalParameter::new("a", None, false),
                 FormalParameter::new("b", None, false),
             ],
-            Node::statement_list(vec![Node::Return(None)]),
+            Node::statement_list(vec![Node::return_node::<Node, _>(None)]),
         )],
     );
 }
@@ -104,7 +104,9 @@ impl<'a> Parser<'a> {
 
     /// Parse all expressions in the token array
     pub fn parse_all(&mut self) -> ParseResult {
-        Script.parse(&mut self.cursor).map(Node::statement_list)
+        let start = self.cursor.next_pos();
+        let list = Script.parse(&mut self.cursor)?;
+        Ok(Node::statement_list(list).with_span(self.cursor.span_from(start)))
     }
 }
 
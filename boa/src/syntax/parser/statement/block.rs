@@ -56,21 +56,21 @@ impl TokenParser for Block {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Punctuator::OpenBlock, "block")?;
         if let Some(tk) = cursor.peek(0) {
             if tk.kind == TokenKind::Punctuator(Punctuator::CloseBlock) {
                 cursor.next();
-                return Ok(Node::Block(Box::new([])));
+                return Ok(Node::block(Vec::new()).with_span(cursor.span_from(start)));
             }
         }
 
         let statement_list =
             StatementList::new(self.allow_yield, self.allow_await, self.allow_return, true)
-                .parse(cursor)
-                .map(Node::block)?;
+                .parse(cursor)?;
         cursor.expect(Punctuator::CloseBlock, "block")?;
 
-        Ok(statement_list)
+        Ok(Node::block(statement_list).with_span(cursor.span_from(start)))
     }
 }
 
@@ -47,6 +47,7 @@ impl TokenParser for BreakStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Break, "break statement")?;
 
         if let (true, tok) = cursor.peek_semicolon(false) {
@@ -57,7 +58,7 @@ impl TokenParser for BreakStatement {
                 _ => {}
             }
 
-            return Ok(Node::Break(None));
+            return Ok(Node::break_node::<_, String>(None).with_span(cursor.span_from(start)));
         }
 
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
@@ -73,6 +74,6 @@ impl TokenParser for BreakStatement {
 
         cursor.expect_semicolon(false, "break statement")?;
 
-        Ok(node)
+        Ok(node.with_span(cursor.span_from(start)))
     }
 }
@@ -4,7 +4,10 @@ use crate::syntax::{ast::node::Node, parser::tests::check_parser};
 fn check_inline() {
     check_parser(
         "while (true) break;",
-        vec![Node::while_loop(Node::const_node(true), Node::Break(None))],
+        vec![Node::while_loop(
+            Node::const_node(true),
+            Node::break_node::<_, String>(None),
+        )],
     );
 }
 
@@ -13,7 +16,10 @@ fn check_new_line() {
     check_parser(
         "while (true)
             break;",
-        vec![Node::while_loop(Node::const_node(true), Node::Break(None))],
+        vec![Node::while_loop(
+            Node::const_node(true),
+            Node::break_node::<_, String>(None),
+        )],
     );
 }
 
@@ -23,7 +29,7 @@ fn check_inline_block_semicolon_insertion() {
         "while (true) {break}",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Break(None)]),
+            Node::block(vec![Node::break_node::<_, String>(None)]),
         )],
     );
 }
@@ -47,7 +53,7 @@ fn check_inline_block() {
         "while (true) {break;}",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Break(None)]),
+            Node::block(vec![Node::break_node::<_, String>(None)]),
         )],
     );
 }
@@ -73,7 +79,7 @@ fn check_new_line_block_empty() {
         }",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Break(None)]),
+            Node::block(vec![Node::break_node::<_, String>(None)]),
         )],
     );
 }
@@ -86,7 +92,7 @@ fn check_new_line_block_empty_semicolon_insertion() {
         }",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Break(None)]),
+            Node::block(vec![Node::break_node::<_, String>(None)]),
         )],
     );
 }
@@ -47,6 +47,7 @@ impl TokenParser for ContinueStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Continue, "continue statement")?;
 
         if let (true, tok) = cursor.peek_semicolon(false) {
@@ -57,7 +58,7 @@ impl TokenParser for ContinueStatement {
                 _ => {}
             }
 
-            return Ok(Node::Continue(None));
+            return Ok(Node::continue_node::<_, String>(None).with_span(cursor.span_from(start)));
         }
 
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
@@ -73,6 +74,6 @@ impl TokenParser for ContinueStatement {
 
         cursor.expect_semicolon(false, "continue statement")?;
 
-        Ok(node)
+        Ok(node.with_span(cursor.span_from(start)))
     }
 }
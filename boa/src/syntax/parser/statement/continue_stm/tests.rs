@@ -6,7 +6,7 @@ fn check_inline() {
         "while (true) continue;",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::Continue(None),
+            Node::continue_node::<_, String>(None),
         )],
     );
 }
@@ -18,7 +18,7 @@ fn check_new_line() {
             continue;",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::Continue(None),
+            Node::continue_node::<_, String>(None),
         )],
     );
 }
@@ -29,7 +29,7 @@ fn check_inline_block_semicolon_insertion() {
         "while (true) {continue}",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Continue(None)]),
+            Node::block(vec![Node::continue_node::<_, String>(None)]),
         )],
     );
 }
@@ -53,7 +53,7 @@ fn check_inline_block() {
         "while (true) {continue;}",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Continue(None)]),
+            Node::block(vec![Node::continue_node::<_, String>(None)]),
         )],
     );
 }
@@ -79,7 +79,7 @@ fn check_new_line_block_empty() {
         }",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Continue(None)]),
+            Node::block(vec![Node::continue_node::<_, String>(None)]),
         )],
     );
 }
@@ -92,7 +92,7 @@ fn check_new_line_block_empty_semicolon_insertion() {
         }",
         vec![Node::while_loop(
             Node::const_node(true),
-            Node::block(vec![Node::Continue(None)]),
+            Node::block(vec![Node::continue_node::<_, String>(None)]),
         )],
     );
 }
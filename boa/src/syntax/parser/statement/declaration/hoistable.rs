@@ -87,6 +87,7 @@ impl TokenParser for FunctionDeclaration {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Function, "function declaration")?;
 
         let token = cursor.next().ok_or(ParseError::AbruptEnd)?;
@@ -105,14 +106,14 @@ impl TokenParser for FunctionDeclaration {
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
+        let body_start = cursor.next_pos();
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Node::statement_list)?;
+        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
+        let body = Node::statement_list(body).with_span(cursor.span_from(body_start));
 
-        Ok(Node::function_decl(name, params, body))
+        Ok(Node::function_decl(name, params, body).with_span(cursor.span_from(start)))
     }
 }
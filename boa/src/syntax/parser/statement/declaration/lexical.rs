@@ -50,17 +50,19 @@ impl TokenParser for LexicalDeclaration {
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
 
-        match tok.kind {
+        let decl = match tok.kind {
             TokenKind::Keyword(Keyword::Const) => {
                 BindingList::new(self.allow_in, self.allow_yield, self.allow_await, true)
-                    .parse(cursor)
+                    .parse(cursor)?
             }
             TokenKind::Keyword(Keyword::Let) => {
                 BindingList::new(self.allow_in, self.allow_yield, self.allow_await, false)
-                    .parse(cursor)
+                    .parse(cursor)?
             }
             _ => unreachable!("unknown token found"),
-        }
+        };
+
+        Ok(decl.with_span(cursor.span_from(tok.pos)))
     }
 }
 
@@ -47,6 +47,7 @@ impl TokenParser for IfStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::If, "if statement")?;
         cursor.expect(Punctuator::OpenParen, "if statement")?;
 
@@ -68,6 +69,7 @@ impl TokenParser for IfStatement {
             }
         };
 
-        Ok(Node::if_node::<_, _, Node, _>(cond, then_stm, else_stm))
+        Ok(Node::if_node::<_, _, Node, _>(cond, then_stm, else_stm)
+            .with_span(cursor.span_from(start)))
     }
 }
@@ -54,6 +54,7 @@ impl TokenParser for DoWhileStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Do, "do while statement")?;
 
         let body =
@@ -77,6 +78,6 @@ impl TokenParser for DoWhileStatement {
         cursor.expect(Punctuator::CloseParen, "do while statement")?;
         cursor.expect_semicolon(true, "do while statement")?;
 
-        Ok(Node::do_while_loop(body, cond))
+        Ok(Node::do_while_loop(body, cond).with_span(cursor.span_from(start)))
     }
 }
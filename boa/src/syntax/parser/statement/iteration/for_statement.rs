@@ -56,6 +56,7 @@ impl TokenParser for ForStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::For, "for statement")?;
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
@@ -95,8 +96,10 @@ impl TokenParser for ForStatement {
         let body =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        let for_node = Node::for_loop::<_, _, _, Node, Node, Node, _>(init, cond, step, body);
+        let span = cursor.span_from(start);
+        let for_node =
+            Node::for_loop::<_, _, _, Node, Node, Node, _>(init, cond, step, body).with_span(span);
 
-        Ok(Node::Block(Box::new([for_node])))
+        Ok(Node::block(vec![for_node]).with_span(span))
     }
 }
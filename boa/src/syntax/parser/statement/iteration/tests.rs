@@ -1,6 +1,7 @@
 use crate::syntax::{
     ast::node::Node,
     ast::op::{AssignOp, BinOp, CompOp, UnaryOp},
+    ast::pos::Position,
     parser::tests::check_parser,
 };
 
@@ -34,6 +35,7 @@ fn check_do_while_semicolon_insertion() {
                 Node::block(vec![Node::call(
                     Node::get_const_field(Node::local("console"), "log"),
                     vec![Node::const_node("hello")],
+                    Position::new(2, 21),
                 )]),
                 Node::bin_op(
                     BinOp::Comp(CompOp::LessThan),
@@ -44,6 +46,7 @@ fn check_do_while_semicolon_insertion() {
             Node::call(
                 Node::get_const_field(Node::local("console"), "log"),
                 vec![Node::const_node("end")],
+                Position::new(2, 60),
             ),
         ],
     );
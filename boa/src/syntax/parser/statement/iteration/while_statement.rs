@@ -45,6 +45,7 @@ impl TokenParser for WhileStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::While, "while statement")?;
         cursor.expect(Punctuator::OpenParen, "while statement")?;
 
@@ -55,6 +56,6 @@ impl TokenParser for WhileStatement {
         let body =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        Ok(Node::while_loop(cond, body))
+        Ok(Node::while_loop(cond, body).with_span(cursor.span_from(start)))
     }
 }
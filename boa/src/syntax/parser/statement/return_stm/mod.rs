@@ -38,6 +38,7 @@ impl TokenParser for ReturnStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Return, "return statement")?;
 
         if let (true, tok) = cursor.peek_semicolon(false) {
@@ -51,13 +52,13 @@ impl TokenParser for ReturnStatement {
                 _ => {}
             }
 
-            return Ok(Node::Return(None));
+            return Ok(Node::return_node::<Node, _>(None).with_span(cursor.span_from(start)));
         }
 
         let expr = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
 
         cursor.expect_semicolon(false, "return statement")?;
 
-        Ok(Node::return_node(expr))
+        Ok(Node::return_node(expr).with_span(cursor.span_from(start)))
     }
 }
@@ -44,6 +44,7 @@ impl TokenParser for SwitchStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Switch, "switch statement")?;
         cursor.expect(Punctuator::OpenParen, "switch statement")?;
 
@@ -54,7 +55,8 @@ impl TokenParser for SwitchStatement {
         let (cases, default) =
             CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        Ok(Node::switch::<_, _, _, Node>(condition, cases, default))
+        Ok(Node::switch::<_, _, _, Node>(condition, cases, default)
+            .with_span(cursor.span_from(start)))
     }
 }
 
@@ -38,6 +38,7 @@ impl TokenParser for ThrowStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Throw, "throw statement")?;
 
         cursor.peek_expect_no_lineterminator(0, "throw statement")?;
@@ -49,6 +50,6 @@ impl TokenParser for ThrowStatement {
             }
         }
 
-        Ok(Node::throw(expr))
+        Ok(Node::throw(expr).with_span(cursor.span_from(start)))
     }
 }
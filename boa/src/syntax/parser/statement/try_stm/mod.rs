@@ -43,6 +43,7 @@ impl TokenParser for TryStatement {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         // TRY
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Try, "try statement")?;
 
         let try_clause =
@@ -64,13 +65,13 @@ impl TokenParser for TryStatement {
         }
 
         // CATCH
-        let (catch, param) = if next_token.kind == TokenKind::Keyword(Keyword::Catch) {
+        let (catch, param) = if cursor.next_if(Keyword::Catch).is_some() {
             // Catch binding
             cursor.expect(Punctuator::OpenParen, "catch in try statement")?;
             // TODO: should accept BindingPattern
             let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
             let catch_param = if let TokenKind::Identifier(s) = &tok.kind {
-                Node::local(s)
+                Node::local(s).with_span(tok.span())
             } else {
                 return Err(ParseError::Expected(
                     vec![TokenKind::identifier("identifier")],
@@ -99,11 +100,9 @@ impl TokenParser for TryStatement {
             None
         };
 
-        Ok(Node::try_node::<_, _, _, _, Node, Node, Node>(
-            try_clause,
-            catch,
-            param,
-            finally_block,
-        ))
+        Ok(
+            Node::try_node::<_, _, _, _, Node, Node, Node>(try_clause, catch, param, finally_block)
+                .with_span(cursor.span_from(start)),
+        )
     }
 }
@@ -41,6 +41,7 @@ impl TokenParser for VariableStatement {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         cursor.expect(Keyword::Var, "variable statement")?;
 
         let decl_list =
@@ -48,7 +49,7 @@ impl TokenParser for VariableStatement {
 
         cursor.expect_semicolon(false, "variable statement")?;
 
-        Ok(decl_list)
+        Ok(decl_list.with_span(cursor.span_from(start)))
     }
 }
 
@@ -91,6 +92,7 @@ impl TokenParser for VariableDeclarationList {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let start = cursor.next_pos();
         let mut list = Vec::new();
 
         loop {
@@ -117,7 +119,7 @@ impl TokenParser for VariableDeclarationList {
             }
         }
 
-        Ok(Node::var_decl(list))
+        Ok(Node::var_decl(list).with_span(cursor.span_from(start)))
     }
 }
 
@@ -1,7 +1,12 @@
 //! Tests for the parser.
 
 use super::Parser;
-use crate::syntax::{ast::node::Node, ast::op::NumOp, lexer::Lexer};
+use crate::syntax::{
+    ast::node::{Node, NodeKind},
+    ast::op::NumOp,
+    ast::pos::{Position, Span},
+    lexer::Lexer,
+};
 
 #[allow(clippy::result_unwrap_used)]
 pub(super) fn check_parser<L>(js: &str, expr: L)
@@ -33,10 +38,15 @@ fn check_construct_call_precedence() {
         "new Date().getTime()",
         vec![Node::call(
             Node::get_const_field(
-                Node::new(Node::call(Node::local("Date"), Vec::new())),
+                Node::new(Node::call(
+                    Node::local("Date"),
+                    Vec::new(),
+                    Position::new(1, 1),
+                )),
                 "getTime",
             ),
             Vec::new(),
+            Position::new(1, 12),
         )],
     );
 }
@@ -51,3 +61,44 @@ fn assign_operator_precedence() {
         )],
     );
 }
+
+/// Checks that the parsed nodes know where they are in the source code.
+#[test]
+fn check_node_spans() {
+    let mut lexer = Lexer::new("a + b;\nfoo(1)");
+    lexer.lex().expect("failed to lex");
+    let script = Parser::new(&lexer.tokens)
+        .parse_all()
+        .expect("failed to parse");
+
+    assert_eq!(
+        script.span,
+        Span::new(Position::new(1, 1), Position::new(2, 7))
+    );
+    let statements = match script.kind {
+        NodeKind::StatementList(ref statements) => statements,
+        _ => panic!("expected a statement list, got {:?}", script),
+    };
+
+    assert_eq!(
+        statements[0].span,
+        Span::new(Position::new(1, 1), Position::new(1, 6))
+    );
+    assert_eq!(
+        statements[1].span,
+        Span::new(Position::new(2, 1), Position::new(2, 7))
+    );
+    match statements[1].kind {
+        NodeKind::Call(ref callee, ref args, _) => {
+            assert_eq!(
+                callee.span,
+                Span::new(Position::new(2, 1), Position::new(2, 4))
+            );
+            assert_eq!(
+                args[0].span,
+                Span::new(Position::new(2, 5), Position::new(2, 6))
+            );
+        }
+        _ => panic!("expected a call, got {:?}", statements[1]),
+    }
+}
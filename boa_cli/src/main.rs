@@ -26,8 +26,11 @@
 )]
 
 use boa::{
-    builtins::console::log,
-    exec::Executor,
+    builtins::{
+        console::log,
+        value::inspect::{inspect, InspectOptions},
+    },
+    exec::{Executor, Interpreter},
     forward_val,
     realm::Realm,
     syntax::ast::{node::Node, token::Token},
@@ -177,7 +180,7 @@ pub fn main() -> Result<(), std::io::Error> {
 
     let realm = Realm::create().register_global_func("print", log);
 
-    let mut engine = Executor::new(realm);
+    let mut engine: Interpreter = Executor::new(realm);
 
     for file in &args.files {
         let buffer = read_to_string(file)?;
@@ -188,9 +191,10 @@ pub fn main() -> Result<(), std::io::Error> {
                 Err(e) => eprintln!("{}", e),
             }
         } else {
+            engine.set_script_name(file.display().to_string());
             match forward_val(&mut engine, &buffer) {
                 Ok(v) => print!("{}", v.to_string()),
-                Err(v) => eprint!("{}", v.to_string()),
+                Err(v) => eprintln!("Uncaught {}", inspect(&v, &InspectOptions::default())),
             }
         }
     }
@@ -208,8 +212,8 @@ pub fn main() -> Result<(), std::io::Error> {
                 }
             } else {
                 match forward_val(&mut engine, buffer.trim_end()) {
-                    Ok(v) => println!("{}", v.to_string()),
-                    Err(v) => eprintln!("{}", v.to_string()),
+                    Ok(v) => println!("{}", inspect(&v, &InspectOptions::default())),
+                    Err(v) => eprintln!("Uncaught {}", inspect(&v, &InspectOptions::default())),
                 }
             }
 
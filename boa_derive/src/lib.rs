@@ -0,0 +1,419 @@
+//! Derive macros for the `ToValue` and `FromValue` conversion traits of Boa.
+//!
+//! Structs with named fields convert to objects, tuple structs to arrays, newtype structs to the
+//! value of their field and unit structs to `null`. The unit variants of enums convert to their
+//! name, and the other variants to an object with the name as its only key, like
+//! `{ "Circle": { "radius": 1 } }`.
+//!
+//! The names of the properties can be changed with `#[boa(rename = "name")]` on fields and
+//! variants, or with `#[boa(rename_all = "camelCase")]` on the type.
+
+#![deny(
+    unused_qualifications,
+    clippy::all,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Error,
+    Fields, Generics, Ident, Index, Lit, Meta, NestedMeta, Result,
+};
+
+/// Derives `ToValue`, see the crate documentation for the shape of the values.
+#[proc_macro_derive(ToValue, attributes(boa))]
+pub fn derive_to_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_to_value(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `FromValue`, see the crate documentation for the shape of the values.
+#[proc_macro_derive(FromValue, attributes(boa))]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_value(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// How the names of the fields or variants of a type are changed.
+#[derive(Debug, Clone, Copy)]
+enum RenameRule {
+    /// `first_name` and `FirstName` become `firstName`.
+    CamelCase,
+}
+
+impl RenameRule {
+    fn apply(self, name: &str) -> String {
+        match self {
+            Self::CamelCase => {
+                let mut renamed = String::with_capacity(name.len());
+                for (i, part) in name.split('_').filter(|part| !part.is_empty()).enumerate() {
+                    let mut chars = part.chars();
+                    if let Some(first) = chars.next() {
+                        if i == 0 {
+                            renamed.extend(first.to_lowercase());
+                        } else {
+                            renamed.extend(first.to_uppercase());
+                        }
+                        renamed.push_str(chars.as_str());
+                    }
+                }
+                renamed
+            }
+        }
+    }
+}
+
+/// The options of a `#[boa(...)]` attribute.
+#[derive(Debug, Default)]
+struct Options {
+    rename: Option<String>,
+    rename_all: Option<RenameRule>,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("boa")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "expected `#[boa(...)]`")),
+            };
+            for nested in list.nested {
+                let pair = match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                    nested => return Err(Error::new_spanned(nested, "unknown boa option")),
+                };
+                let value = match pair.lit {
+                    Lit::Str(ref value) => value.value(),
+                    ref lit => return Err(Error::new_spanned(lit, "expected a string")),
+                };
+                if pair.path.is_ident("rename") {
+                    options.rename = Some(value);
+                } else if pair.path.is_ident("rename_all") {
+                    options.rename_all = match value.as_str() {
+                        "camelCase" => Some(RenameRule::CamelCase),
+                        _ => {
+                            return Err(Error::new_spanned(
+                                pair.lit,
+                                "the only supported rule is \"camelCase\"",
+                            ))
+                        }
+                    };
+                } else {
+                    return Err(Error::new_spanned(pair.path, "unknown boa option"));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// The name of a field or variant in the values, given the options of its type.
+    fn name(&self, ident: &Ident, type_options: &Self) -> String {
+        let name = ident.unraw().to_string();
+        match (&self.rename, type_options.rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply(&name),
+            (None, None) => name,
+        }
+    }
+}
+
+/// The fields of a struct or variant, as the bindings used to access them and their names.
+#[derive(Debug)]
+enum Shape {
+    Named(Vec<(Ident, String)>),
+    Tuple(usize),
+    Newtype,
+    Unit,
+}
+
+impl Shape {
+    fn of(fields: &Fields, type_options: &Options) -> Result<Self> {
+        Ok(match fields {
+            Fields::Named(fields) => Self::Named(
+                fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let ident = field.ident.clone().expect("named fields have a name");
+                        let name = Options::parse(&field.attrs)?.name(&ident, type_options);
+                        Ok((ident, name))
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Self::Newtype,
+            Fields::Unnamed(fields) => Self::Tuple(fields.unnamed.len()),
+            Fields::Unit => Self::Unit,
+        })
+    }
+
+    /// The bindings of the fields of a variant, in a pattern.
+    fn bindings(&self) -> Vec<Ident> {
+        match self {
+            Self::Named(fields) => fields.iter().map(|(ident, _)| ident.clone()).collect(),
+            Self::Tuple(len) => (0..*len).map(binding).collect(),
+            Self::Newtype => vec![binding(0)],
+            Self::Unit => Vec::new(),
+        }
+    }
+
+    /// The pattern matching a variant, binding its fields by reference.
+    fn pattern(&self, path: &TokenStream2) -> TokenStream2 {
+        let bindings = self.bindings();
+        match self {
+            Self::Named(_) => quote!(#path { #(ref #bindings),* }),
+            Self::Tuple(_) | Self::Newtype => quote!(#path(#(ref #bindings),*)),
+            Self::Unit => quote!(#path),
+        }
+    }
+
+    /// Converts the fields to a value, given expressions referencing them.
+    fn write_value(&self, fields: &[TokenStream2]) -> TokenStream2 {
+        let ctx = context();
+        match self {
+            Self::Named(named) => {
+                let names = named.iter().map(|(_, name)| name);
+                quote! {
+                    ::boa::builtins::value::object_from_entries(#ctx, {
+                        let entries: ::std::vec::Vec<(&str, ::boa::builtins::value::Value)> =
+                            vec![#((#names, ::boa::builtins::value::to_value_with(#fields, #ctx))),*];
+                        entries
+                    })
+                }
+            }
+            Self::Tuple(_) => quote! {
+                ::boa::builtins::value::array_from_values(#ctx, {
+                    let elements: ::std::vec::Vec<::boa::builtins::value::Value> =
+                        vec![#(::boa::builtins::value::to_value_with(#fields, #ctx)),*];
+                    elements
+                })
+            },
+            Self::Newtype => {
+                let field = &fields[0];
+                quote!(::boa::builtins::value::to_value_with(#field, #ctx))
+            }
+            Self::Unit => quote!(::boa::builtins::value::ToValue::to_value(&())),
+        }
+    }
+
+    /// Converts the value `value` to the fields, then builds the struct or variant at `path`.
+    fn read_value(&self, path: &TokenStream2, value: &Ident, type_name: &str) -> TokenStream2 {
+        match self {
+            Self::Named(named) => {
+                let idents = named.iter().map(|(ident, _)| ident);
+                let names = named.iter().map(|(_, name)| name);
+                let expected = format!("an object for `{}`", type_name);
+                quote! {{
+                    if !#value.is_object() {
+                        return Err(::boa::builtins::value::ConversionError::expected(#expected, &#value));
+                    }
+                    #path {
+                        #(#idents: ::boa::builtins::value::field_from_value(&#value, #names)?),*
+                    }
+                }}
+            }
+            Self::Tuple(len) => {
+                let indexes = 0..*len;
+                let expected = format!("an array for `{}`", type_name);
+                quote! {{
+                    if !#value.is_object() {
+                        return Err(::boa::builtins::value::ConversionError::expected(#expected, &#value));
+                    }
+                    #path(#(::boa::builtins::value::element_from_value(&#value, #indexes)?),*)
+                }}
+            }
+            Self::Newtype => quote!(#path(::boa::builtins::value::FromValue::from_value(#value)?)),
+            Self::Unit => quote!(#path),
+        }
+    }
+}
+
+/// The name of the binding of the unnamed field `index`.
+fn binding(index: usize) -> Ident {
+    Ident::new(&format!("field{}", index), Span::call_site())
+}
+
+/// The binding of the interpreter converting a value, if there is one.
+fn context() -> Ident {
+    Ident::new("__boa_context", Span::call_site())
+}
+
+/// Adds the `bound` to the type parameters of the generics.
+fn bounded(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn expand_to_value(input: &DeriveInput) -> Result<TokenStream2> {
+    let options = Options::parse(&input.attrs)?;
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            let shape = Shape::of(&data.fields, &options)?;
+            let fields: Vec<_> = match data.fields {
+                Fields::Named(_) => shape
+                    .bindings()
+                    .into_iter()
+                    .map(|ident| quote!(&self.#ident))
+                    .collect(),
+                _ => (0..data.fields.len())
+                    .map(|index| {
+                        let index = Index::from(index);
+                        quote!(&self.#index)
+                    })
+                    .collect(),
+            };
+            shape.write_value(&fields)
+        }
+        Data::Enum(ref data) => enum_to_value(data, &options)?,
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "unions can't be converted to values",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let generics = bounded(&input.generics, quote!(::boa::builtins::value::ToValue));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let ctx = context();
+    Ok(quote! {
+        impl #impl_generics ::boa::builtins::value::ToValue for #name #type_generics #where_clause {
+            fn to_value(&self) -> ::boa::builtins::value::Value {
+                let #ctx: ::std::option::Option<&::boa::exec::Interpreter> = None;
+                #body
+            }
+
+            fn to_value_in(
+                &self,
+                #ctx: &::boa::exec::Interpreter,
+            ) -> ::boa::builtins::value::Value {
+                let #ctx = Some(#ctx);
+                #body
+            }
+        }
+    })
+}
+
+fn enum_to_value(data: &DataEnum, options: &Options) -> Result<TokenStream2> {
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let name = Options::parse(&variant.attrs)?.name(ident, options);
+        let shape = Shape::of(&variant.fields, options)?;
+        let pattern = shape.pattern(&quote!(Self::#ident));
+        let value = match shape {
+            Shape::Unit => quote!(::boa::builtins::value::ToValue::to_value(&#name)),
+            _ => {
+                let fields: Vec<_> = shape
+                    .bindings()
+                    .into_iter()
+                    .map(|binding| quote!(#binding))
+                    .collect();
+                let content = shape.write_value(&fields);
+                let ctx = context();
+                quote! {
+                    ::boa::builtins::value::object_from_entries(
+                        #ctx,
+                        ::std::iter::once((#name, #content))
+                    )
+                }
+            }
+        };
+        arms.push(quote!(#pattern => #value));
+    }
+    Ok(quote! {
+        match *self {
+            #(#arms,)*
+        }
+    })
+}
+
+fn expand_from_value(input: &DeriveInput) -> Result<TokenStream2> {
+    let options = Options::parse(&input.attrs)?;
+    let name = &input.ident;
+    let type_name = name.unraw().to_string();
+    let value = Ident::new("value", Span::call_site());
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            let built =
+                Shape::of(&data.fields, &options)?.read_value(&quote!(Self), &value, &type_name);
+            quote!(Ok(#built))
+        }
+        Data::Enum(ref data) => enum_from_value(data, &options, &value, &type_name)?,
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "unions can't be converted from values",
+            ))
+        }
+    };
+
+    let generics = bounded(&input.generics, quote!(::boa::builtins::value::FromValue));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::boa::builtins::value::FromValue for #name #type_generics #where_clause {
+            fn from_value(
+                #value: ::boa::builtins::value::Value,
+            ) -> ::std::result::Result<Self, ::boa::builtins::value::ConversionError> {
+                #body
+            }
+        }
+    })
+}
+
+fn enum_from_value(
+    data: &DataEnum,
+    options: &Options,
+    value: &Ident,
+    type_name: &str,
+) -> Result<TokenStream2> {
+    let content = Ident::new("content", Span::call_site());
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let name = Options::parse(&variant.attrs)?.name(ident, options);
+        let shape = Shape::of(&variant.fields, options)?;
+        let arm = match shape {
+            Shape::Unit => quote!((#name, None) => Ok(Self::#ident)),
+            _ => {
+                let built = shape.read_value(&quote!(Self::#ident), &content, type_name);
+                quote! {
+                    (#name, Some(#content)) => (|| -> ::std::result::Result<
+                        Self,
+                        ::boa::builtins::value::ConversionError,
+                    > {
+                        Ok(#built)
+                    })()
+                    .map_err(|error| error.at_key(#name))
+                }
+            }
+        };
+        arms.push(arm);
+    }
+    let unknown = format!("expected a variant of `{}`, got `{{}}`", type_name);
+    Ok(quote! {
+        let (variant, content) = ::boa::builtins::value::variant_from_value(&#value)?;
+        match (variant.as_str(), content) {
+            #(#arms,)*
+            _ => Err(::boa::builtins::value::ConversionError::new(format!(#unknown, variant))),
+        }
+    })
+}
@@ -0,0 +1,229 @@
+use boa::{
+    builtins::{
+        object::INSTANCE_PROTOTYPE,
+        value::{from_value, to_value, FromValue, ToValue, Value},
+    },
+    Context,
+};
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+#[boa(rename_all = "camelCase")]
+struct Person {
+    first_name: String,
+    age: u8,
+    #[boa(rename = "mail")]
+    email: Option<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+struct Point(f64, f64);
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+struct Meters(f64);
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+struct Marker;
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+enum Shape {
+    Empty,
+    Circle {
+        radius: f64,
+    },
+    Rectangle(f64, f64),
+    #[boa(rename = "square")]
+    Square(f64),
+}
+
+#[derive(Debug, PartialEq, ToValue, FromValue)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+fn person() -> Person {
+    Person {
+        first_name: "Ada".to_string(),
+        age: 36,
+        email: None,
+        tags: vec!["math".to_string(), "engines".to_string()],
+    }
+}
+
+/// Converts `value` in a fresh context and evaluates `src` with it as the global `value`.
+fn eval_with<T: ToValue>(value: T, src: &str) -> String {
+    let mut context = Context::new();
+    let value = value.to_value_in(context.interpreter());
+    context.set_global("value", value).expect("a new global");
+    context
+        .eval(src)
+        .unwrap_or_else(|error| panic!("{}", error))
+        .to_string()
+}
+
+#[test]
+fn structs_round_trip() {
+    let value = person().to_value();
+    assert_eq!(from_value::<Person>(value).unwrap(), person());
+    let point = Point(1.5, -2.0);
+    assert_eq!(from_value::<Point>(point.to_value()).unwrap(), point);
+    assert_eq!(
+        from_value::<Meters>(to_value(Meters(3.0))).unwrap(),
+        Meters(3.0)
+    );
+    assert!(to_value(Marker).is_null());
+    assert_eq!(from_value::<Marker>(to_value(1)).unwrap(), Marker);
+    let wrapper = Wrapper { inner: point };
+    assert_eq!(
+        from_value::<Wrapper<Point>>(wrapper.to_value()).unwrap(),
+        wrapper
+    );
+}
+
+#[test]
+fn structs_in_scripts() {
+    assert_eq!(
+        eval_with(
+            person(),
+            "value.firstName + ' ' + value.age + ' ' + value.mail + ' ' + value.tags.length"
+        ),
+        "Ada 36 null 2"
+    );
+    assert_eq!(eval_with(Point(1.0, 2.0), "value[0] + value[1]"), "3");
+    assert_eq!(eval_with(Meters(3.5), "value * 2"), "7");
+
+    let mut context = Context::new();
+    let value = context
+        .eval("({ firstName: 'Grace', age: 85, tags: ['navy'] })")
+        .unwrap();
+    let person = from_value::<Person>(value).unwrap();
+    assert_eq!(person.first_name, "Grace");
+    assert_eq!(person.email, None);
+    assert_eq!(person.tags, vec!["navy".to_string()]);
+}
+
+#[test]
+fn prototypes_of_the_realm() {
+    assert_eq!(
+        eval_with(
+            person(),
+            "Object.getPrototypeOf(value) === Object.prototype && value.hasOwnProperty('age')"
+        ),
+        "true"
+    );
+    assert_eq!(eval_with(person(), "Array.isArray(value.tags)"), "true");
+    assert_eq!(eval_with(person(), "value.tags.join('+')"), "math+engines");
+    assert_eq!(
+        eval_with(
+            Point(1.0, 2.0),
+            "Object.getPrototypeOf(value) === Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        eval_with(
+            Shape::Circle { radius: 2.0 },
+            "value.Circle.hasOwnProperty('radius')"
+        ),
+        "true"
+    );
+    // Without an interpreter, there is no realm to take the prototypes from.
+    assert!(person()
+        .to_value()
+        .get_internal_slot(INSTANCE_PROTOTYPE)
+        .is_undefined());
+}
+
+#[test]
+fn enums() {
+    let shapes = vec![
+        Shape::Empty,
+        Shape::Circle { radius: 1.0 },
+        Shape::Rectangle(2.0, 3.0),
+        Shape::Square(4.0),
+    ];
+    for shape in shapes {
+        let value = shape.to_value();
+        assert_eq!(Shape::from_value(value).unwrap(), shape);
+    }
+    assert_eq!(eval_with(Shape::Empty, "value"), "Empty");
+    assert_eq!(
+        eval_with(Shape::Circle { radius: 2.0 }, "value.Circle.radius"),
+        "2"
+    );
+    assert_eq!(eval_with(Shape::Square(4.0), "value.square"), "4");
+}
+
+#[test]
+fn descriptive_errors() {
+    let mut context = Context::new();
+    let value = context
+        .eval("({ firstName: 'Ada', age: 300, tags: [] })")
+        .unwrap();
+    assert_eq!(
+        from_value::<Person>(value).unwrap_err().to_string(),
+        "expected an integer in the range of u8, got 300 at age"
+    );
+
+    let value = context
+        .eval("({ Circle: { radius: 1 }, Square: 2 })")
+        .unwrap();
+    // The properties of objects are unordered.
+    assert!(from_value::<Shape>(value)
+        .unwrap_err()
+        .message()
+        .starts_with("expected a string or an object with a single key, got {"));
+    assert_eq!(
+        from_value::<Shape>(to_value("Triangle"))
+            .unwrap_err()
+            .to_string(),
+        "expected a variant of `Shape`, got `Triangle`"
+    );
+    assert_eq!(
+        from_value::<Point>(to_value(1)).unwrap_err().to_string(),
+        "expected an array for `Point`, got 1"
+    );
+
+    let value = context.eval("({ inner: [1, 2] })").unwrap();
+    let error = from_value::<Wrapper<(i64, Wrapper<u32>)>>(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected an object for `Wrapper`, got 2 at inner[1]"
+    );
+    let value = context.eval("({ inner: [1, { inner: -1 }] })").unwrap();
+    assert_eq!(
+        from_value::<Wrapper<(i64, Wrapper<u32>)>>(value)
+            .unwrap_err()
+            .to_string(),
+        "expected an integer in the range of u32, got -1 at inner[1].inner"
+    );
+    let value = context.eval("({ length: 2.5, 0: 1, 1: 2, 2: 3 })").unwrap();
+    assert_eq!(from_value::<Vec<u32>>(value).unwrap(), vec![1, 2]);
+    let value = context.eval("({ length: -1, 0: 1 })").unwrap();
+    assert_eq!(from_value::<Vec<u32>>(value).unwrap(), Vec::<u32>::new());
+    // The length can't exceed the number of properties, even for elements which may be missing.
+    let value = context.eval("({ length: 1e15, 0: 1 })").unwrap();
+    assert_eq!(
+        from_value::<Vec<Value>>(value).unwrap_err().to_string(),
+        "expected a length of at most 2, the number of properties, got 1000000000000000 at length"
+    );
+    let value = context.eval("({ length: 1e300 })").unwrap();
+    assert!(from_value::<Vec<Option<u32>>>(value).is_err());
+    let value = context.eval("({ length: 2, 0: 1 })").unwrap();
+    assert_eq!(
+        from_value::<Vec<Option<u32>>>(value).unwrap(),
+        vec![Some(1), None]
+    );
+    let value = context.eval("({ length: 3, 0: 1 })").unwrap();
+    assert_eq!(
+        from_value::<Vec<u32>>(value).unwrap_err().to_string(),
+        "expected a length of at most 2, the number of properties, got 3 at length"
+    );
+    let value = context.eval("({ length: Symbol() })").unwrap();
+    assert_eq!(
+        from_value::<Vec<u32>>(value).unwrap_err().to_string(),
+        "expected a number, got Symbol() at length"
+    );
+    let thrown = error.to_type_error(context.interpreter_mut());
+    assert_eq!(thrown.get_field_slice("name").to_string(), "TypeError");
+}
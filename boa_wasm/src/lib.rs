@@ -1,4 +1,5 @@
 use boa::{
+    builtins::console::sink::{indent_lines, ConsoleSink, LogLevel},
     exec::{Executor, Interpreter},
     realm::Realm,
     syntax::{ast::node::Node, lexer::Lexer, parser::Parser},
@@ -12,6 +13,35 @@ extern "C" {
     // `log(..)`
     #[wasm_bindgen(js_namespace = console)]
     fn log(s: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = debug)]
+    fn log_debug(s: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = info)]
+    fn log_info(s: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = warn)]
+    fn log_warn(s: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn log_error(s: &str);
+}
+
+/// Forwards the messages of the script's `console` to the browser's `console`.
+#[derive(Debug)]
+struct BrowserConsoleSink;
+
+impl ConsoleSink for BrowserConsoleSink {
+    fn write(&mut self, level: LogLevel, indent: usize, message: &str) {
+        let message = indent_lines(indent, message);
+        match level {
+            LogLevel::Debug => log_debug(&message),
+            LogLevel::Log => log(&message),
+            LogLevel::Info => log_info(&message),
+            LogLevel::Warn => log_warn(&message),
+            LogLevel::Error => log_error(&message),
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -39,6 +69,8 @@ pub fn evaluate(src: &str) -> String {
     // Create new Realm
     let realm = Realm::create();
     let mut engine: Interpreter = Executor::new(realm);
+    engine.set_source_text(src);
+    engine.set_console_sink(BrowserConsoleSink);
     let result = engine.run(&node);
     match result {
         Ok(v) => v.to_string(),